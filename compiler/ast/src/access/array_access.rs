@@ -17,11 +17,13 @@
 use crate::{Expression, Node, NodeID};
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// An array access expression, e.g., `foo[index]`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ArrayAccess {
     /// An expression evaluating to some array type, e.g., `[false, true]`.
     pub array: Box<Expression>,
@@ -17,11 +17,13 @@
 use crate::{Identifier, Node, NodeID, Type};
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// An access expression to an struct constant., e.g. `u8::MAX`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssociatedConstant {
     /// The inner struct type.
     pub ty: Type,
@@ -17,11 +17,13 @@
 use crate::{Expression, Identifier, Node, NodeID, Type};
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// An access expression to an associated function in a struct, e.g.`Pedersen64::hash()`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssociatedFunction {
     /// The inner struct type.
     pub ty: Type,
@@ -17,11 +17,13 @@
 use crate::{Expression, Identifier, Node, NodeID};
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A struct member access expression `inner.name` to some structure with *named members*.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MemberAccess {
     /// The inner struct that is being accessed.
     pub inner: Box<Expression>,
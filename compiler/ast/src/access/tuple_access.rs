@@ -17,11 +17,13 @@
 use crate::{Expression, Node, NodeID, NonNegativeNumber};
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A tuple access expression, e.g., `tuple.index`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TupleAccess {
     /// An expression evaluating to some tuple type, e.g., `(5, 2)`.
     pub tuple: Box<Expression>,
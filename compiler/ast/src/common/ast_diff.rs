@@ -0,0 +1,343 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Ast;
+
+use leo_errors::Result;
+use leo_span::Span;
+
+use serde_json::{Map, Value};
+use std::{collections::BTreeMap, fmt};
+
+/// The fields that are not compared, since passes give new IDs and spans to the nodes they rebuild.
+const IGNORED_KEYS: [&str; 2] = ["id", "span"];
+
+/// The kind of a change between two ASTs, along with the nodes involved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AstChangeKind {
+    /// A node was added.
+    Added(Value),
+    /// A node was removed.
+    Removed(Value),
+    /// A node was replaced, e.g. a literal by another literal, or a statement by a different kind of statement.
+    Changed { before: Value, after: Value },
+}
+
+/// A change between two ASTs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AstChange {
+    /// The path of the changed node in the serialized AST, e.g. `program_scopes.test.functions[0][1].block`.
+    pub path: String,
+    /// The span of the changed node, or of the closest node enclosing it that has a span.
+    /// The span of a removed node is from the first AST, and that of any other node from the second AST.
+    pub span: Option<Span>,
+    /// The kind of the change.
+    pub kind: AstChangeKind,
+}
+
+impl fmt::Display for AstChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (sign, nodes) = match &self.kind {
+            AstChangeKind::Added(node) => ('+', node.to_string()),
+            AstChangeKind::Removed(node) => ('-', node.to_string()),
+            AstChangeKind::Changed { before, after } => ('~', format!("{before} -> {after}")),
+        };
+        match self.span.filter(|span| !span.is_dummy()) {
+            Some(span) => write!(f, "{sign} {} @ {span}: {nodes}", self.path),
+            None => write!(f, "{sign} {}: {nodes}", self.path),
+        }
+    }
+}
+
+/// The structural difference between two serialized ASTs, e.g. before and after a compiler pass.
+/// The elements of arrays, e.g. the statements of a block, are aligned by a longest common subsequence,
+/// so that an inserted statement is reported as an addition rather than as a change of every statement after it.
+/// The `id` and `span` fields of nodes are not compared.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AstDiff {
+    changes: Vec<AstChange>,
+}
+
+impl AstDiff {
+    /// Returns the difference between the serialized ASTs `before` and `after`.
+    pub fn new(before: &Value, after: &Value) -> Self {
+        let mut diff = Self::default();
+        diff.diff(String::new(), before, after, (None, None));
+        diff
+    }
+
+    /// Returns the difference between the ASTs `before` and `after`.
+    pub fn between(before: &Ast, after: &Ast) -> Result<Self> {
+        Ok(Self::new(&before.to_json_value()?, &after.to_json_value()?))
+    }
+
+    /// Returns the changes, in the order of the nodes they are in.
+    pub fn changes(&self) -> &[AstChange] {
+        &self.changes
+    }
+
+    /// Returns `true` if the ASTs are the same.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Records the changes between the nodes `before` and `after` at `path`.
+    /// `spans` are the spans of the closest nodes enclosing `before` and `after`.
+    fn diff(&mut self, path: String, before: &Value, after: &Value, spans: (Option<Span>, Option<Span>)) {
+        let spans = (node_span(before).or(spans.0), node_span(after).or(spans.1));
+        match (before, after) {
+            // Variants of different kinds, e.g. a definition replaced by an assignment, are a single change.
+            (Value::Object(b), Value::Object(a)) if b.len() == 1 && a.len() == 1 && b.keys().ne(a.keys()) => {
+                self.changed(path, before, after, spans.1)
+            }
+            (Value::Object(b), Value::Object(a)) => self.diff_objects(&path, b, a, spans),
+            (Value::Array(b), Value::Array(a)) => self.diff_arrays(&path, b, a, spans),
+            _ if !same(before, after) => self.changed(path, before, after, spans.1),
+            _ => {}
+        }
+    }
+
+    fn diff_objects(
+        &mut self,
+        path: &str,
+        before: &Map<String, Value>,
+        after: &Map<String, Value>,
+        spans: (Option<Span>, Option<Span>),
+    ) {
+        for (key, value) in before.iter().filter(|(key, _)| !IGNORED_KEYS.contains(&key.as_str())) {
+            match after.get(key) {
+                Some(after) => self.diff(field_path(path, key), value, after, spans),
+                None => self.removed(field_path(path, key), value, spans.0),
+            }
+        }
+        for (key, value) in after.iter().filter(|(key, _)| !IGNORED_KEYS.contains(&key.as_str())) {
+            if !before.contains_key(key) {
+                self.added(field_path(path, key), value, spans.1);
+            }
+        }
+    }
+
+    /// Records the changes between two arrays, whose elements are aligned by a longest common subsequence.
+    /// The elements in between the aligned ones are paired up as changes, and the rest are additions or removals.
+    fn diff_arrays(&mut self, path: &str, before: &[Value], after: &[Value], spans: (Option<Span>, Option<Span>)) {
+        // `lengths[i][j]` is the length of the longest common subsequence of `before[i..]` and `after[j..]`.
+        let mut lengths = vec![vec![0usize; after.len() + 1]; before.len() + 1];
+        for i in (0..before.len()).rev() {
+            for j in (0..after.len()).rev() {
+                lengths[i][j] = match same(&before[i], &after[j]) {
+                    true => lengths[i + 1][j + 1] + 1,
+                    false => lengths[i + 1][j].max(lengths[i][j + 1]),
+                };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        let (mut removed, mut added) = (Vec::new(), Vec::new());
+        while i < before.len() || j < after.len() {
+            if i < before.len() && j < after.len() && same(&before[i], &after[j]) {
+                self.diff_unaligned(path, before, after, (&removed, &added), spans);
+                removed.clear();
+                added.clear();
+                i += 1;
+                j += 1;
+            } else if j < after.len() && (i == before.len() || lengths[i][j + 1] >= lengths[i + 1][j]) {
+                added.push(j);
+                j += 1;
+            } else {
+                removed.push(i);
+                i += 1;
+            }
+        }
+        self.diff_unaligned(path, before, after, (&removed, &added), spans);
+    }
+
+    /// Records the changes between the elements of two arrays in between two aligned elements.
+    fn diff_unaligned(
+        &mut self,
+        path: &str,
+        before: &[Value],
+        after: &[Value],
+        (removed, added): (&[usize], &[usize]),
+        spans: (Option<Span>, Option<Span>),
+    ) {
+        for (&i, &j) in removed.iter().zip(added) {
+            self.diff(format!("{path}[{j}]"), &before[i], &after[j], spans);
+        }
+        for &i in removed.iter().skip(added.len()) {
+            self.removed(format!("{path}[{i}]"), &before[i], spans.0);
+        }
+        for &j in added.iter().skip(removed.len()) {
+            self.added(format!("{path}[{j}]"), &after[j], spans.1);
+        }
+    }
+
+    fn added(&mut self, path: String, node: &Value, span: Option<Span>) {
+        let span = node_span(node).or(span);
+        self.changes.push(AstChange { path, span, kind: AstChangeKind::Added(node.clone()) });
+    }
+
+    fn removed(&mut self, path: String, node: &Value, span: Option<Span>) {
+        let span = node_span(node).or(span);
+        self.changes.push(AstChange { path, span, kind: AstChangeKind::Removed(node.clone()) });
+    }
+
+    fn changed(&mut self, path: String, before: &Value, after: &Value, span: Option<Span>) {
+        let kind = AstChangeKind::Changed { before: before.clone(), after: after.clone() };
+        self.changes.push(AstChange { path, span, kind });
+    }
+}
+
+impl fmt::Display for AstDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the path of the field `key` of the node at `path`.
+fn field_path(path: &str, key: &str) -> String {
+    match path.is_empty() {
+        true => key.to_string(),
+        false => format!("{path}.{key}"),
+    }
+}
+
+/// Returns the span of `node`, if it is a node with a span.
+/// A node wrapped in a variant, e.g. `{"Return": {..., "span": ...}}`, has the span of the node it wraps.
+fn node_span(node: &Value) -> Option<Span> {
+    let map = match node {
+        Value::Object(map) => map,
+        _ => return serde_json::from_str(identifier(node)?.get("span")?).ok(),
+    };
+    match map.get("span") {
+        Some(span) => serde_json::from_value(span.clone()).ok(),
+        None if map.len() == 1 => map.values().next().and_then(node_span),
+        None => None,
+    }
+}
+
+/// Returns `true` if the nodes are the same, apart from their `id` and `span` fields.
+fn same(before: &Value, after: &Value) -> bool {
+    match (before, after) {
+        (Value::Object(b), Value::Object(a)) => {
+            let fields = |map: &'_ Map<String, Value>| {
+                map.iter().filter(|(key, _)| !IGNORED_KEYS.contains(&key.as_str())).count()
+            };
+            fields(b) == fields(a)
+                && b.iter()
+                    .filter(|(key, _)| !IGNORED_KEYS.contains(&key.as_str()))
+                    .all(|(key, value)| a.get(key).map_or(false, |other| same(value, other)))
+        }
+        (Value::Array(b), Value::Array(a)) => b.len() == a.len() && b.iter().zip(a).all(|(b, a)| same(b, a)),
+        _ => match (identifier(before), identifier(after)) {
+            (Some(b), Some(a)) => b.get("name") == a.get("name"),
+            _ => before == after,
+        },
+    }
+}
+
+/// Returns the fields of `value` if it is an identifier, which is serialized as a string holding a JSON object with
+/// its `name`, `span`, and `id`.
+fn identifier(value: &Value) -> Option<BTreeMap<String, String>> {
+    match value {
+        Value::String(string) if string.starts_with('{') => serde_json::from_str(string).ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_span::{span::BytePos, symbol::create_session_if_not_set_then};
+    use serde_json::json;
+
+    fn statement(kind: &str, value: u32, lo: u32) -> Value {
+        json!({ kind: { "value": value, "span": { "lo": lo, "hi": lo + 4 }, "id": lo } })
+    }
+
+    fn block(statements: Vec<Value>) -> Value {
+        json!({ "block": { "statements": statements, "span": { "lo": 0, "hi": 100 }, "id": 0 } })
+    }
+
+    #[test]
+    fn test_diff() {
+        let before = block(vec![statement("Assign", 1, 10), statement("Assign", 2, 20), statement("Return", 3, 30)]);
+        // The IDs and spans of the statements differ, but are not compared.
+        let after = block(vec![
+            statement("Assign", 1, 11),
+            statement("Definition", 5, 15),
+            statement("Assign", 2, 21),
+            statement("Return", 4, 31),
+        ]);
+
+        let diff = AstDiff::new(&before, &after);
+        let span = |lo| Some(Span::new(BytePos(lo), BytePos(lo + 4)));
+        assert_eq!(diff.changes(), [
+            AstChange {
+                path: "block.statements[1]".to_string(),
+                span: span(15),
+                kind: AstChangeKind::Added(statement("Definition", 5, 15)),
+            },
+            AstChange {
+                path: "block.statements[3].Return.value".to_string(),
+                span: span(31),
+                kind: AstChangeKind::Changed { before: json!(3), after: json!(4) },
+            },
+        ]);
+
+        let diff = AstDiff::new(&after, &before);
+        assert_eq!(diff.changes()[0].path, "block.statements[1]");
+        assert_eq!(diff.changes()[0].kind, AstChangeKind::Removed(statement("Definition", 5, 15)));
+
+        assert!(AstDiff::new(&before, &before).is_empty());
+    }
+
+    #[test]
+    fn test_diff_identifiers() {
+        let identifier = |name: &str, lo: u32| {
+            let span = format!(r#"{{"lo":{lo},"hi":{}}}"#, lo + 1);
+            json!({ "Identifier": json!({ "id": lo.to_string(), "name": name, "span": span }).to_string() })
+        };
+        // Identifiers with different IDs and spans are the same.
+        assert!(AstDiff::new(&identifier("a", 1), &identifier("a", 2)).is_empty());
+
+        let diff = AstDiff::new(&identifier("a", 1), &identifier("b", 2));
+        assert_eq!(diff.changes().len(), 1);
+        assert_eq!(diff.changes()[0].path, "Identifier");
+        assert_eq!(diff.changes()[0].span, Some(Span::new(BytePos(2), BytePos(3))));
+    }
+
+    #[test]
+    fn test_diff_variants() {
+        let before = block(vec![statement("Assign", 1, 10)]);
+        let after = block(vec![statement("Definition", 1, 10)]);
+        let diff = AstDiff::new(&before, &after);
+        assert_eq!(diff.changes(), [AstChange {
+            path: "block.statements[0]".to_string(),
+            span: Some(Span::new(BytePos(10), BytePos(14))),
+            kind: AstChangeKind::Changed { before: statement("Assign", 1, 10), after: statement("Definition", 1, 10) },
+        }]);
+
+        create_session_if_not_set_then(|_| {
+            let lines = diff.to_string();
+            assert!(lines.starts_with("~ block.statements[0] @ "), "{lines}");
+            assert!(lines.contains(r#"{"Assign":"#), "{lines}");
+        });
+    }
+}
@@ -14,10 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "serde")]
 use leo_errors::Result;
 use leo_span::{Span, Symbol};
 
 use crate::{simple_node_impl, Node, NodeID};
+#[cfg(feature = "serde")]
 use serde::{
     de::{
         Visitor,
@@ -28,8 +30,9 @@ use serde::{
     Serialize,
     Serializer,
 };
+#[cfg(feature = "serde")]
+use std::collections::BTreeMap;
 use std::{
-    collections::BTreeMap,
     fmt,
     hash::{Hash, Hasher},
 };
@@ -89,6 +92,7 @@ impl Hash for Identifier {
     }
 }
 
+#[cfg(feature = "serde")]
 impl Serialize for Identifier {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         // Converts an element that implements Serialize into a string.
@@ -107,6 +111,7 @@ impl Serialize for Identifier {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Identifier {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         struct IdentifierVisitor;
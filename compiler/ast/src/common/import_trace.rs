@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(feature = "serde")]
+use leo_errors::{AstError, Result};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, path::PathBuf};
+
+/// A step of import resolution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "step", rename_all = "snake_case"))]
+pub enum ImportStep {
+    /// A path at which the file of an import was looked for.
+    CandidatePath { import: String, path: PathBuf, exists: bool },
+    /// The file of an import was read and parsed, defining the given program scopes.
+    Loaded { import: String, path: PathBuf, programs: Vec<String> },
+    /// The program parsed from the file of an import in an earlier build was reused, since the file is unchanged.
+    Reused { import: String, path: PathBuf },
+    /// An item of an imported program was added to the symbol table.
+    SymbolInjected { import: String, kind: String, name: String },
+    /// An item was rejected, since its name is already defined by an imported program.
+    ShadowingRejected { import: String, kind: String, name: String },
+    /// An import was rejected, since it is already being loaded, along with the imports forming the cycle.
+    CycleDetected { import: String, cycle: Vec<String> },
+}
+
+/// A log of the steps of import resolution, recorded when tracing is enabled.
+/// This makes it possible to find out why an import was not found, or why one of its items is unavailable.
+#[derive(Clone, Debug, Default)]
+pub struct ImportTrace {
+    /// Whether steps are recorded.
+    enabled: bool,
+    /// The recorded steps, in the order in which they were taken.
+    /// `RefCell` is used here to avoid `&mut` all over the compiler.
+    steps: RefCell<Vec<ImportStep>>,
+}
+
+impl ImportTrace {
+    /// Returns a new trace, which only records steps if it is `enabled`.
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled, steps: Default::default() }
+    }
+
+    /// Returns `true` if the trace records steps.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Records the step constructed by `step`, if tracing is enabled.
+    pub fn record(&self, step: impl FnOnce() -> ImportStep) {
+        if self.enabled {
+            self.steps.borrow_mut().push(step());
+        }
+    }
+
+    /// Returns the recorded steps.
+    pub fn steps(&self) -> Vec<ImportStep> {
+        self.steps.borrow().clone()
+    }
+
+    /// Serializes the recorded steps into a JSON file.
+    #[cfg(feature = "serde")]
+    pub fn to_json_file(&self, mut path: PathBuf, file_name: &str) -> Result<()> {
+        path.push(file_name);
+        let file =
+            std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_import_trace_json_file(&path, &e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(serde_json::to_writer_pretty(writer, &*self.steps.borrow())
+            .map_err(|e| AstError::failed_to_write_import_trace_to_json_file(&path, &e))?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn injected(name: &str) -> ImportStep {
+        ImportStep::SymbolInjected { import: "foo".into(), kind: "struct".into(), name: name.into() }
+    }
+
+    #[test]
+    fn test_record() {
+        let disabled = ImportTrace::default();
+        disabled.record(|| injected("Bar"));
+        assert!(disabled.steps().is_empty());
+
+        let trace = ImportTrace::new(true);
+        trace.record(|| injected("Bar"));
+        trace.record(|| injected("Baz"));
+        assert_eq!(trace.steps(), [injected("Bar"), injected("Baz")]);
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_json::to_string(&trace.steps()[0]).unwrap(),
+            r#"{"step":"symbol_injected","import":"foo","kind":"struct","name":"Bar"}"#
+        );
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::Symbol;
+
+use std::fmt::Debug;
+
+/// A source item that a name introduced by the compiler was derived from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Demangled {
+    /// The function that the method `method` of the struct `struct_name` is lowered to.
+    Method { struct_name: Symbol, method: Symbol },
+    /// A copy of the source variable `name`, e.g. one of its versions in SSA form or an inlined copy of it.
+    Renamed { name: Symbol, index: usize },
+    /// A variable introduced by the compiler that has no counterpart in the source, e.g. the result of a subexpression.
+    Temporary { index: usize },
+}
+
+/// Decides how the items introduced while lowering a program are named, and maps those names back to source items.
+/// Mangled names must not collide with source identifiers, and `demangle` must invert `method_name` and `fresh_name`.
+pub trait Mangler: Debug {
+    /// Returns the name of the function that the method `method` of `struct_name` is lowered to.
+    fn method_name(&self, struct_name: Symbol, method: Symbol) -> Symbol;
+
+    /// Returns the `index`-th fresh name derived from `name`, which is empty for temporaries.
+    /// The `separator` distinguishes the different kinds of names a pass introduces, e.g. `$` or `$index$0$`.
+    fn fresh_name(&self, name: &str, separator: &str, index: usize) -> Symbol;
+
+    /// Returns the source item that `name` was derived from, or `None` if `name` is not mangled.
+    fn demangle(&self, name: &str) -> Option<Demangled>;
+}
+
+/// The naming scheme used by the compiler unless configured otherwise.
+/// Methods are named `Struct::method`, and fresh names are `name$index` or `$kind$index`.
+/// Neither `:` nor `$` may appear in a source identifier, so these names never collide with source items.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultMangler;
+
+impl Mangler for DefaultMangler {
+    fn method_name(&self, struct_name: Symbol, method: Symbol) -> Symbol {
+        Symbol::intern(&format!("{struct_name}::{method}"))
+    }
+
+    fn fresh_name(&self, name: &str, separator: &str, index: usize) -> Symbol {
+        Symbol::intern(&format!("{name}{separator}{index}"))
+    }
+
+    fn demangle(&self, name: &str) -> Option<Demangled> {
+        if let Some((struct_name, method)) = name.split_once("::") {
+            return Some(Demangled::Method {
+                struct_name: Symbol::intern(struct_name),
+                method: Symbol::intern(method),
+            });
+        }
+        let (base, rest) = name.split_once('$')?;
+        // The index is the last component, since separators may themselves contain `$`.
+        let index = rest.rsplit('$').next()?.parse().ok()?;
+        Some(match base {
+            "" => Demangled::Temporary { index },
+            base => Demangled::Renamed { name: Symbol::intern(base), index },
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_default_mangler_round_trip() {
+        create_session_if_not_set_then(|_| {
+            let mangler = DefaultMangler;
+            let (point, sum, x) = (Symbol::intern("Point"), Symbol::intern("sum"), Symbol::intern("x"));
+
+            let method = mangler.method_name(point, sum);
+            assert_eq!(method.to_string(), "Point::sum");
+            assert_eq!(
+                mangler.demangle(&method.to_string()),
+                Some(Demangled::Method { struct_name: point, method: sum })
+            );
+
+            let renamed = mangler.fresh_name("x", "$", 3);
+            assert_eq!(renamed.to_string(), "x$3");
+            assert_eq!(mangler.demangle(&renamed.to_string()), Some(Demangled::Renamed { name: x, index: 3 }));
+
+            let element = mangler.fresh_name("x", "$index$1$", 4);
+            assert_eq!(mangler.demangle(&element.to_string()), Some(Demangled::Renamed { name: x, index: 4 }));
+
+            let temporary = mangler.fresh_name("$var", "$", 7);
+            assert_eq!(mangler.demangle(&temporary.to_string()), Some(Demangled::Temporary { index: 7 }));
+
+            assert_eq!(mangler.demangle("x"), None);
+            assert_eq!(mangler.demangle("$self"), None);
+        })
+    }
+}
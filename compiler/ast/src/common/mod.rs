@@ -14,12 +14,25 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "serde")]
+pub mod ast_diff;
+#[cfg(feature = "serde")]
+pub use ast_diff::*;
+
 pub mod identifier;
 pub use identifier::*;
 
+pub mod import_trace;
+pub use import_trace::*;
+
+#[cfg(feature = "serde")]
 pub mod imported_modules;
+#[cfg(feature = "serde")]
 pub use imported_modules::*;
 
+pub mod mangler;
+pub use mangler::*;
+
 pub mod positive_number;
 pub use positive_number::*;
 
@@ -28,6 +41,15 @@ pub mod node;
 pub mod node_builder;
 pub use node_builder::*;
 
+pub mod source_edit;
+pub use source_edit::*;
+
 pub mod static_string;
 
 pub use static_string::*;
+
+pub mod trivia;
+pub use trivia::*;
+
+pub mod visibility;
+pub use visibility::*;
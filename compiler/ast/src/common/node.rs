@@ -22,9 +22,7 @@ use leo_span::Span;
 pub type NodeID = usize;
 
 /// A node in the AST.
-pub trait Node:
-    std::fmt::Debug + std::fmt::Display + Clone + PartialEq + Eq + serde::Serialize + serde::de::DeserializeOwned
-{
+pub trait Node: std::fmt::Debug + std::fmt::Display + Clone + PartialEq + Eq {
     /// Returns the span of the node.
     fn span(&self) -> Span;
 
@@ -0,0 +1,242 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_errors::{AstError, Result};
+use leo_span::{span::BytePos, Span};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A replacement of the text in a span of a source file.
+/// An insertion is a replacement of an empty span, and a deletion is a replacement with the empty string.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SourceEdit {
+    /// The span of the replaced text.
+    pub span: Span,
+    /// The text the span is replaced with.
+    pub replacement: String,
+}
+
+impl SourceEdit {
+    /// Returns an edit replacing the text in `span` with `replacement`.
+    pub fn replace(span: Span, replacement: impl Into<String>) -> Self {
+        Self { span, replacement: replacement.into() }
+    }
+
+    /// Returns an edit inserting `text` at `pos`.
+    pub fn insert(pos: BytePos, text: impl Into<String>) -> Self {
+        Self::replace(Span::new(pos, pos), text)
+    }
+
+    /// Returns an edit deleting the text in `span`.
+    pub fn delete(span: Span) -> Self {
+        Self::replace(span, String::new())
+    }
+}
+
+/// A batch of edits to a source file, which are applied at once.
+/// The spans of the edits refer to the source before any of them is applied, so the edits may be given in any order.
+/// Insertions at the same position are applied in the order they are given.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SourceEdits {
+    edits: Vec<SourceEdit>,
+}
+
+impl SourceEdits {
+    /// Returns a new batch of edits.
+    pub fn new(edits: Vec<SourceEdit>) -> Self {
+        Self { edits }
+    }
+
+    /// Adds an edit to the batch.
+    pub fn push(&mut self, edit: SourceEdit) {
+        self.edits.push(edit);
+    }
+
+    /// Returns `true` if the batch contains no edits.
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    /// Applies the edits to `source`, which starts at `start_pos` in the source map.
+    /// Returns an error if an edit is out of the bounds of `source`, or if two edits overlap.
+    pub fn apply(&self, source: &str, start_pos: BytePos) -> Result<EditedSource> {
+        let mut edits = self.edits.iter().collect::<Vec<_>>();
+        edits.sort_by_key(|edit| edit.span.lo);
+
+        let mut text = String::with_capacity(source.len());
+        let mut mapping = SpanMapping { old_start: start_pos, new_start: start_pos, edits: Vec::new() };
+        // The offset in `source` up to which the text has been copied.
+        let mut copied = 0;
+        let mut previous: Option<Span> = None;
+        for edit in edits {
+            let (lo, hi) = match edit.span.lo >= start_pos && edit.span.lo <= edit.span.hi {
+                true => ((edit.span.lo.0 - start_pos.0) as usize, (edit.span.hi.0 - start_pos.0) as usize),
+                false => return Err(AstError::source_edit_out_of_bounds(edit.span.lo.0, edit.span.hi.0).into()),
+            };
+            if hi > source.len() || !source.is_char_boundary(lo) || !source.is_char_boundary(hi) {
+                return Err(AstError::source_edit_out_of_bounds(edit.span.lo.0, edit.span.hi.0).into());
+            }
+            if let Some(previous) = previous.filter(|previous| previous.hi > edit.span.lo) {
+                let (first, second) = (previous.lo.0..previous.hi.0, edit.span.lo.0..edit.span.hi.0);
+                return Err(AstError::overlapping_source_edits(format!("{first:?}"), format!("{second:?}")).into());
+            }
+            previous = Some(edit.span);
+
+            text.push_str(&source[copied..lo]);
+            text.push_str(&edit.replacement);
+            copied = hi;
+            mapping.edits.push(MappedEdit { lo: lo as u32, hi: hi as u32, len: edit.replacement.len() as u32 });
+        }
+        text.push_str(&source[copied..]);
+
+        Ok(EditedSource { text, mapping })
+    }
+}
+
+/// The text of a source file after a batch of edits, and the mapping from its old spans to its new spans.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EditedSource {
+    /// The edited text.
+    pub text: String,
+    /// The mapping from spans in the old text to spans in the edited text.
+    pub mapping: SpanMapping,
+}
+
+/// An applied edit, in offsets relative to the start of the source.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct MappedEdit {
+    /// The start of the replaced text in the old source.
+    lo: u32,
+    /// The end of the replaced text in the old source.
+    hi: u32,
+    /// The length of the replacement.
+    len: u32,
+}
+
+/// Maps the spans of a source file to the spans of the same text after a batch of edits.
+/// This keeps diagnostics and indexes valid after edits, without reparsing the source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpanMapping {
+    /// The start of the old source in the source map.
+    old_start: BytePos,
+    /// The start of the edited source in the source map.
+    new_start: BytePos,
+    /// The applied edits, sorted by position.
+    edits: Vec<MappedEdit>,
+}
+
+impl SpanMapping {
+    /// Sets the start of the edited source in the source map, e.g. after it is registered as a new source.
+    /// By default, the edited source is assumed to start where the old source started.
+    pub fn rebase(&mut self, new_start: BytePos) {
+        self.new_start = new_start;
+    }
+
+    /// Returns the span of the text in `span` after the edits, or `None` if the text is not preserved.
+    /// A span containing an edit grows or shrinks with it, and the span of a replaced text maps to its replacement.
+    /// A span that partially overlaps an edit, or that lies strictly inside a replaced text, has no counterpart.
+    pub fn map_span(&self, span: Span) -> Option<Span> {
+        if span.lo < self.old_start || span.hi < span.lo {
+            return None;
+        }
+        let (lo, hi) = (span.lo.0 - self.old_start.0, span.hi.0 - self.old_start.0);
+
+        // The changes in length before the span, and inside the span.
+        let (mut before, mut inside) = (0i64, 0i64);
+        for edit in &self.edits {
+            let delta = edit.len as i64 - (edit.hi - edit.lo) as i64;
+            if edit.hi <= lo {
+                // Note that an insertion at the start of the span precedes it.
+                before += delta;
+            } else if edit.lo >= hi {
+                break;
+            } else if lo <= edit.lo && edit.hi <= hi {
+                inside += delta;
+            } else {
+                return None;
+            }
+        }
+
+        let new_lo = self.new_start.0 as i64 + lo as i64 + before;
+        let new_hi = new_lo + (hi - lo) as i64 + inside;
+        Some(Span::new(BytePos(new_lo as u32), BytePos(new_hi as u32)))
+    }
+
+    /// Returns the position of `pos` after the edits, or `None` if it lies strictly inside a replaced text.
+    /// A position at which text is inserted maps to the position after the insertion.
+    pub fn map_pos(&self, pos: BytePos) -> Option<BytePos> {
+        self.map_span(Span::new(pos, pos)).map(|span| span.lo)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn span(lo: u32, hi: u32) -> Span {
+        Span::new(BytePos(lo), BytePos(hi))
+    }
+
+    #[test]
+    fn test_apply_and_map() {
+        // The source starts at position 10 of the source map.
+        let source = "let a: u8 = b + c;";
+        let edits = SourceEdits::new(vec![
+            SourceEdit::replace(span(26, 27), "count"),
+            SourceEdit::replace(span(14, 15), "total"),
+            SourceEdit::insert(BytePos(27), " * 2u8"),
+            SourceEdit::delete(span(15, 19)),
+        ]);
+        let edited = edits.apply(source, BytePos(10)).unwrap();
+        assert_eq!(edited.text, "let total = b + count * 2u8;");
+
+        let mapping = edited.mapping;
+        // `let` is unchanged, and `b` is shifted by the edits before it.
+        assert_eq!(mapping.map_span(span(10, 13)), Some(span(10, 13)));
+        assert_eq!(mapping.map_span(span(22, 23)), Some(span(22, 23)));
+        // A replaced identifier maps to its replacement.
+        assert_eq!(mapping.map_span(span(14, 15)), Some(span(14, 19)));
+        // The whole statement grows with the edits inside it.
+        assert_eq!(mapping.map_span(span(10, 28)), Some(span(10, 38)));
+        // The semicolon follows the insertion.
+        assert_eq!(mapping.map_pos(BytePos(27)), Some(BytePos(37)));
+        // Spans that partially overlap an edit are not preserved.
+        assert_eq!(mapping.map_span(span(16, 20)), None);
+        assert_eq!(mapping.map_pos(BytePos(17)), None);
+    }
+
+    #[test]
+    fn test_rebase() {
+        let edits = SourceEdits::new(vec![SourceEdit::replace(span(4, 5), "xy")]);
+        let mut mapping = edits.apply("let a = 1u8;", BytePos(0)).unwrap().mapping;
+        mapping.rebase(BytePos(100));
+        assert_eq!(mapping.map_span(span(0, 3)), Some(span(100, 103)));
+        assert_eq!(mapping.map_span(span(8, 11)), Some(span(109, 112)));
+    }
+
+    #[test]
+    fn test_invalid_edits() {
+        let source = "let a = 1u8;";
+        let apply = |edits: Vec<SourceEdit>| SourceEdits::new(edits).apply(source, BytePos(5));
+        assert!(apply(vec![SourceEdit::delete(span(4, 6))]).is_err());
+        assert!(apply(vec![SourceEdit::delete(span(10, 18))]).is_err());
+        assert!(apply(vec![SourceEdit::delete(span(6, 9)), SourceEdit::delete(span(8, 10))]).is_err());
+        assert!(apply(vec![SourceEdit::delete(span(6, 9)), SourceEdit::insert(BytePos(9), " ")]).is_ok());
+    }
+}
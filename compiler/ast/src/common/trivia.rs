@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::NodeID;
+
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A line or block comment in the source code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Comment {
+    /// The text of the comment, including its delimiters, but not the line break that ends a line comment.
+    pub text: String,
+    /// The span of the text of the comment.
+    pub span: Span,
+    /// true if the comment is separated from whatever precedes it by a blank line.
+    pub blank_line_before: bool,
+}
+
+impl Comment {
+    /// Returns `true` if the comment is a line comment, after which nothing can be written on the same line.
+    pub fn is_line_comment(&self) -> bool {
+        self.text.starts_with("//")
+    }
+}
+
+/// The comments and blank lines around a node, which do not affect the meaning of a program.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Trivia {
+    /// The comments before the node, each on its own line.
+    pub leading: Vec<Comment>,
+    /// true if the node is separated from its leading comments, or from the node before it, by a blank line.
+    pub blank_line_before: bool,
+    /// The comments within the node that are not attached to any of its children,
+    /// and the comments after the node on the line it ends on.
+    pub trailing: Vec<Comment>,
+    /// The comments after the last child of the node, e.g. at the end of a block.
+    pub dangling: Vec<Comment>,
+}
+
+impl Trivia {
+    /// Returns `true` if there are neither comments nor blank lines around the node.
+    pub fn is_empty(&self) -> bool {
+        self.leading.is_empty() && !self.blank_line_before && self.trailing.is_empty() && self.dangling.is_empty()
+    }
+}
+
+/// The trivia of the nodes of a program, keyed by the ids of the nodes.
+/// Imports have no ids, and are keyed by the name of the imported program instead.
+/// A program scope is keyed by the id of its name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TriviaMap {
+    nodes: IndexMap<NodeID, Trivia>,
+    imports: IndexMap<Symbol, Trivia>,
+    end_of_file: Vec<Comment>,
+}
+
+/// The trivia of a node without comments or blank lines around it.
+static NO_TRIVIA: Trivia =
+    Trivia { leading: Vec::new(), blank_line_before: false, trailing: Vec::new(), dangling: Vec::new() };
+
+impl TriviaMap {
+    /// Returns the trivia of the node with the given id.
+    pub fn node(&self, id: NodeID) -> &Trivia {
+        self.nodes.get(&id).unwrap_or(&NO_TRIVIA)
+    }
+
+    /// Returns the trivia of the import of the program `name`.
+    pub fn import(&self, name: Symbol) -> &Trivia {
+        self.imports.get(&name).unwrap_or(&NO_TRIVIA)
+    }
+
+    /// Returns the comments after the last item of the program.
+    pub fn end_of_file(&self) -> &[Comment] {
+        &self.end_of_file
+    }
+
+    /// Returns a mutable reference to the trivia of the node with the given id.
+    pub fn node_mut(&mut self, id: NodeID) -> &mut Trivia {
+        self.nodes.entry(id).or_default()
+    }
+
+    /// Returns a mutable reference to the trivia of the import of the program `name`.
+    pub fn import_mut(&mut self, name: Symbol) -> &mut Trivia {
+        self.imports.entry(name).or_default()
+    }
+
+    /// Sets the comments after the last item of the program.
+    pub fn set_end_of_file(&mut self, comments: Vec<Comment>) {
+        self.end_of_file = comments;
+    }
+
+    /// Returns all the comments of the program, in no particular order.
+    pub fn comments(&self) -> impl Iterator<Item = &Comment> {
+        self.nodes
+            .values()
+            .chain(self.imports.values())
+            .flat_map(|trivia| trivia.leading.iter().chain(&trivia.trailing).chain(&trivia.dangling))
+            .chain(&self.end_of_file)
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The visibility of a struct or function to the programs that import it, e.g. `public struct Foo { ... }`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Visibility {
+    /// No visibility was given.
+    /// Transitions and records are then public, since they form the interface of the program, and other items private.
+    #[default]
+    Default,
+    /// The item was declared `private`.
+    Private,
+    /// The item was declared `public`.
+    Public,
+}
+
+impl Visibility {
+    /// Returns `true` if no visibility was given.
+    pub fn is_default(&self) -> bool {
+        *self == Self::Default
+    }
+
+    /// Returns `true` if an item with this visibility is visible to importing programs,
+    /// where `public_by_default` is whether the item is public if no visibility was given.
+    pub fn is_public(&self, public_by_default: bool) -> bool {
+        match self {
+            Self::Default => public_by_default,
+            Self::Private => false,
+            Self::Public => true,
+        }
+    }
+}
+
+impl fmt::Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => write!(f, ""),
+            Self::Private => write!(f, "private "),
+            Self::Public => write!(f, "public "),
+        }
+    }
+}
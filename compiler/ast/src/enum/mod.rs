@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, IntegerType, Node, NodeID};
+use leo_span::{Span, Symbol};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An enum definition, e.g., `enum Color { Red, Green, Blue }`.
+/// Each variant is represented by its index in the definition, as an integer of type [`Enum::REPRESENTATION`],
+/// e.g. `Color::Green` is represented by `1u8`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Enum {
+    /// The name of the enum.
+    pub identifier: Identifier,
+    /// The variants of the enum, in the order they are declared in.
+    pub variants: Vec<Identifier>,
+    /// The entire span of the enum definition.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl Enum {
+    /// The integer type that represents the variants of an enum.
+    pub const REPRESENTATION: IntegerType = IntegerType::U8;
+
+    /// Returns the enum name as a Symbol.
+    pub fn name(&self) -> Symbol {
+        self.identifier.name
+    }
+
+    /// Returns the index of the variant `variant`, which is the value that represents it.
+    pub fn variant_index(&self, variant: Symbol) -> Option<usize> {
+        self.variants.iter().position(|identifier| identifier.name == variant)
+    }
+}
+
+impl fmt::Display for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "enum {} {{ ", self.identifier)?;
+        for variant in self.variants.iter() {
+            writeln!(f, "    {variant},")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+crate::simple_node_impl!(Enum);
@@ -17,11 +17,13 @@
 use crate::{access::*, Node, NodeID};
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// An access expressions, extracting a smaller part out of a whole.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AccessExpression {
     /// An `array[index]` expression.
     Array(ArrayAccess),
@@ -17,7 +17,8 @@
 use super::*;
 
 /// An array expression, e.g., `[true, false, true, false]`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ArrayExpression {
     /// The elements of the array.
     pub elements: Vec<Expression>,
@@ -20,7 +20,8 @@ use leo_span::{sym, Symbol};
 /// A binary operator.
 ///
 /// Precedence is defined in the parser.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BinaryOperation {
     /// Addition, i.e. `+`, `.add()`.
     Add,
@@ -161,7 +162,8 @@ impl BinaryOperation {
 
 /// A binary expression `left op right` of two operands separated by some operator.
 /// For example, `foo + bar`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BinaryExpression {
     /// The left operand of the expression.
     pub left: Box<Expression>,
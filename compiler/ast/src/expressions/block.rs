@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Statement;
+
+/// A block expression, that is, `{ statements; value }`.
+/// The statements are executed in order, and the expression evaluates to the trailing `value`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BlockExpression {
+    /// The statements executed before the value is evaluated.
+    pub statements: Vec<Statement>,
+    /// The trailing expression the block evaluates to.
+    pub value: Box<Expression>,
+    /// The span from `{` to `}`.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for BlockExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{{")?;
+        self.statements.iter().try_for_each(|statement| writeln!(f, "\t{statement}"))?;
+        writeln!(f, "\t{}", self.value)?;
+        write!(f, "}}")
+    }
+}
+
+crate::simple_node_impl!(BlockExpression);
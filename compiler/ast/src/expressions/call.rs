@@ -15,9 +15,11 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use leo_span::{sym, Symbol};
 
 /// A function call expression, e.g.`foo(args)` or `Foo::bar(args)`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CallExpression {
     /// An expression evaluating to a callable function,
     /// either a member of a structure or a free function.
@@ -26,17 +28,27 @@ pub struct CallExpression {
     pub arguments: Vec<Expression>,
     /// The name of the external program call, e.g.`bar` in `bar.leo`.
     pub external: Option<Box<Expression>>,
+    /// The network of the external program if it is named as a deployed program, e.g. `aleo` in `bar.aleo`.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub network: Option<Identifier>,
     /// Span of the entire call `function(arguments)`.
     pub span: Span,
     /// The ID of the node.
     pub id: NodeID,
 }
 
+impl CallExpression {
+    /// Returns the suffix of the name of the external program, i.e. its network if it is deployed, and otherwise `leo`.
+    pub fn program_suffix(&self) -> Symbol {
+        self.network.map_or(sym::leo, |network| network.name)
+    }
+}
+
 impl fmt::Display for CallExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self.external {
             Some(external) => {
-                write!(f, "{external}.leo/{}(", self.function)?;
+                write!(f, "{external}.{}/{}(", self.program_suffix(), self.function)?;
             }
             None => {
                 write!(f, "{}(", self.function)?;
@@ -19,12 +19,16 @@ use super::*;
 use crate::Type;
 
 /// A cast expression, e.g. `42u8 as u16`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CastExpression {
     /// The expression to be casted, e.g.`42u8` in `42u8 as u16`.
     pub expression: Box<Expression>,
     /// The type to be casted to, e.g. `u16` in `42u8 as u16`.
     pub type_: Type,
+    /// Whether the cast wraps values that do not fit in the target type, e.g. `300u16 as.wrapped u8`.
+    /// Otherwise, the cast halts on such values.
+    pub wrapped: bool,
     /// Span of the entire cast `42u8 as u16`.
     pub span: Span,
     /// The ID of the node.
@@ -33,7 +37,10 @@ pub struct CastExpression {
 
 impl fmt::Display for CastExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({} as {})", self.expression, self.type_)
+        match self.wrapped {
+            true => write!(f, "({} as.wrapped {})", self.expression, self.type_),
+            false => write!(f, "({} as {})", self.expression, self.type_),
+        }
     }
 }
 
@@ -17,7 +17,8 @@
 use super::*;
 
 /// Represents a syntactically invalid expression.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ErrExpression {
     /// The span of the invalid expression.
     pub span: Span,
@@ -14,31 +14,98 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{GroupLiteral, IntegerType};
+use crate::{GroupLiteral, IntegerType, Type};
 
 use super::*;
 
 /// A literal.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Literal {
     // todo: deserialize values here
     /// An address literal, e.g., `aleo1qnr4dkkvkgfqph0vzc3y6z2eu975wnpz2925ntjccd5cfqxtyu8s7pyjh9`.
-    Address(String, #[serde(with = "leo_span::span_json")] Span, NodeID),
+    Address(String, #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))] Span, NodeID),
     /// A boolean literal, either `true` or `false`.
-    Boolean(bool, #[serde(with = "leo_span::span_json")] Span, NodeID),
+    Boolean(bool, #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))] Span, NodeID),
     /// A field literal, e.g., `42field`.
     /// A signed number followed by the keyword `field`.
-    Field(String, #[serde(with = "leo_span::span_json")] Span, NodeID),
+    Field(String, #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))] Span, NodeID),
     /// A group literal, either product or affine.
     /// For example, `42group` or `(12, 52)group`.
     Group(Box<GroupLiteral>),
     /// An integer literal, e.g., `42`.
-    Integer(IntegerType, String, #[serde(with = "leo_span::span_json")] Span, NodeID),
+    Integer(IntegerType, String, #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))] Span, NodeID),
     /// A scalar literal, e.g. `1scalar`.
     /// An unsigned number followed by the keyword `scalar`.
-    Scalar(String, #[serde(with = "leo_span::span_json")] Span, NodeID),
+    Scalar(String, #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))] Span, NodeID),
     /// A string literal, e.g., `"foobar"`.
-    String(String, #[serde(with = "leo_span::span_json")] Span, NodeID),
+    String(String, #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))] Span, NodeID),
+    /// An integer literal without a type suffix, e.g., `42`.
+    /// Its integer type is inferred from the context it is used in.
+    Unsuffixed(String, #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))] Span, NodeID),
+}
+
+impl Literal {
+    /// Returns the literal as an integer literal of type `type_`, if it is unsuffixed and `type_` is an integer type.
+    /// Otherwise, returns the literal unchanged.
+    pub fn with_inferred_type(self, type_: &Type) -> Self {
+        match (self, type_) {
+            (Self::Unsuffixed(value, span, id), Type::Integer(integer_type)) => {
+                Self::Integer(*integer_type, value, span, id)
+            }
+            (literal, _) => literal,
+        }
+    }
+
+    /// Returns the negation of the literal, e.g. `-1i8` for `1i8` and `1i8` for `-1i8`, with the span `span`.
+    /// Returns `None` if the literal is not a number, or is a group literal given by its coordinates.
+    pub fn negate(&self, span: Span) -> Option<Self> {
+        let negate = |string: &String| match string.strip_prefix('-') {
+            Some(magnitude) => magnitude.to_string(),
+            None => format!("-{string}"),
+        };
+        match self {
+            Self::Field(string, _, id) => Some(Self::Field(negate(string), span, *id)),
+            Self::Group(group) => match group.as_ref() {
+                GroupLiteral::Single(string, _, id) => {
+                    Some(Self::Group(Box::new(GroupLiteral::Single(negate(string), span, *id))))
+                }
+                GroupLiteral::Tuple(_) => None,
+            },
+            Self::Integer(type_, string, _, id) => Some(Self::Integer(*type_, negate(string), span, *id)),
+            Self::Scalar(string, _, id) => Some(Self::Scalar(negate(string), span, *id)),
+            Self::Unsuffixed(string, _, id) => Some(Self::Unsuffixed(negate(string), span, *id)),
+            Self::Address(..) | Self::Boolean(..) | Self::String(..) => None,
+        }
+    }
+
+    /// Splits the raw string of an integer literal into its digits and radix, removing its `_` separators.
+    /// The sign of a negative literal is kept, e.g. `-0x8_0` has the digits `-80` in radix 16.
+    pub fn integer_digits(raw: &str) -> (String, u32) {
+        let (sign, magnitude) = match raw.strip_prefix('-') {
+            Some(magnitude) => ("-", magnitude),
+            None => ("", raw),
+        };
+        let (digits, radix) = match magnitude.get(..2) {
+            Some("0x") => (&magnitude[2..], 16),
+            Some("0o") => (&magnitude[2..], 8),
+            Some("0b") => (&magnitude[2..], 2),
+            _ => (magnitude, 10),
+        };
+        (format!("{sign}{}", digits.replace('_', "")), radix)
+    }
+
+    /// Returns the raw string of an integer literal in decimal, e.g. `255` for `0xff`.
+    /// Decimal literals, and literals too large for an `i128` or `u128`, are returned unchanged.
+    pub fn integer_to_decimal(raw: &str) -> String {
+        match Self::integer_digits(raw) {
+            (_, 10) => raw.to_string(),
+            (digits, radix) => i128::from_str_radix(&digits, radix)
+                .map(|value| value.to_string())
+                .or_else(|_| u128::from_str_radix(&digits, radix).map(|value| value.to_string()))
+                .unwrap_or_else(|_| raw.to_string()),
+        }
+    }
 }
 
 impl fmt::Display for Literal {
@@ -51,6 +118,7 @@ impl fmt::Display for Literal {
             Self::Integer(type_, value, _, _) => write!(f, "{value}{type_}"),
             Self::Scalar(scalar, _, _) => write!(f, "{scalar}scalar"),
             Self::String(string, _, _) => write!(f, "\"{string}\""),
+            Self::Unsuffixed(value, _, _) => write!(f, "{value}"),
         }
     }
 }
@@ -63,7 +131,8 @@ impl Node for Literal {
             | Self::Field(_, span, _)
             | Self::Integer(_, _, span, _)
             | Self::Scalar(_, span, _)
-            | Self::String(_, span, _) => *span,
+            | Self::String(_, span, _)
+            | Self::Unsuffixed(_, span, _) => *span,
             Self::Group(group) => *group.span(),
         }
     }
@@ -75,7 +144,8 @@ impl Node for Literal {
             | Self::Field(_, span, _)
             | Self::Integer(_, _, span, _)
             | Self::Scalar(_, span, _)
-            | Self::String(_, span, _) => *span = new_span,
+            | Self::String(_, span, _)
+            | Self::Unsuffixed(_, span, _) => *span = new_span,
             Self::Group(group) => group.set_span(new_span),
         }
     }
@@ -87,7 +157,8 @@ impl Node for Literal {
             | Self::Field(_, _, id)
             | Self::Integer(_, _, _, id)
             | Self::Scalar(_, _, id)
-            | Self::String(_, _, id) => *id,
+            | Self::String(_, _, id)
+            | Self::Unsuffixed(_, _, id) => *id,
             Self::Group(group) => *group.id(),
         }
     }
@@ -99,7 +170,8 @@ impl Node for Literal {
             | Self::Field(_, _, old_id)
             | Self::Integer(_, _, _, old_id)
             | Self::Scalar(_, _, old_id)
-            | Self::String(_, _, old_id) => *old_id = id,
+            | Self::String(_, _, old_id)
+            | Self::Unsuffixed(_, _, old_id) => *old_id = id,
             Self::Group(group) => group.set_id(id),
         }
     }
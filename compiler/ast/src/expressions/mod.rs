@@ -14,9 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Identifier, Node, NodeID};
+use crate::{ConstArgument, Identifier, Node, NodeID};
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -29,6 +30,9 @@ pub use array::*;
 mod binary;
 pub use binary::*;
 
+mod block;
+pub use block::*;
+
 mod call;
 pub use call::*;
 
@@ -57,7 +61,8 @@ mod literal;
 pub use literal::*;
 
 /// Expression that evaluates to a value.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Expression {
     /// A struct access expression, e.g. `Foo.bar`.
     Access(AccessExpression),
@@ -65,6 +70,8 @@ pub enum Expression {
     Array(ArrayExpression),
     /// A binary expression, e.g., `42 + 24`.
     Binary(BinaryExpression),
+    /// A block expression, e.g., `{ let x: u8 = 1u8; x + x }`.
+    Block(BlockExpression),
     /// A call expression, e.g., `my_fun(args)`.
     Call(CallExpression),
     /// A cast expression, e.g., `42u32 as u8`.
@@ -95,6 +102,7 @@ impl Node for Expression {
             Access(n) => n.span(),
             Array(n) => n.span(),
             Binary(n) => n.span(),
+            Block(n) => n.span(),
             Call(n) => n.span(),
             Cast(n) => n.span(),
             Struct(n) => n.span(),
@@ -114,6 +122,7 @@ impl Node for Expression {
             Access(n) => n.set_span(span),
             Array(n) => n.set_span(span),
             Binary(n) => n.set_span(span),
+            Block(n) => n.set_span(span),
             Call(n) => n.set_span(span),
             Cast(n) => n.set_span(span),
             Struct(n) => n.set_span(span),
@@ -133,6 +142,7 @@ impl Node for Expression {
             Access(n) => n.id(),
             Array(n) => n.id(),
             Binary(n) => n.id(),
+            Block(n) => n.id(),
             Call(n) => n.id(),
             Cast(n) => n.id(),
             Struct(n) => n.id(),
@@ -152,6 +162,7 @@ impl Node for Expression {
             Access(n) => n.set_id(id),
             Array(n) => n.set_id(id),
             Binary(n) => n.set_id(id),
+            Block(n) => n.set_id(id),
             Call(n) => n.set_id(id),
             Cast(n) => n.set_id(id),
             Struct(n) => n.set_id(id),
@@ -173,6 +184,7 @@ impl fmt::Display for Expression {
             Access(n) => n.fmt(f),
             Array(n) => n.fmt(f),
             Binary(n) => n.fmt(f),
+            Block(n) => n.fmt(f),
             Call(n) => n.fmt(f),
             Cast(n) => n.fmt(f),
             Struct(n) => n.fmt(f),
@@ -19,7 +19,8 @@ use leo_span::sym;
 
 /// An initializer for a single field / variable of a struct initializer expression.
 /// That is, in `Foo { bar: 42, baz }`, this is either `bar: 42`, or `baz`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StructVariableInitializer {
     /// The name of the field / variable to be initialized.
     pub identifier: Identifier,
@@ -45,10 +46,14 @@ impl fmt::Display for StructVariableInitializer {
 }
 
 /// A struct initialization expression, e.g., `Foo { bar: 42, baz }`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StructExpression {
     /// The name of the structure type to initialize.
     pub name: Identifier,
+    /// The constant arguments of a generic struct, e.g. `3` in `Matrix::<3> { ... }`.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub const_arguments: Vec<ConstArgument>,
     /// Initializer expressions for each of the fields in the struct.
     ///
     /// N.B. Any functions or member constants in the struct definition
@@ -17,7 +17,8 @@
 use super::*;
 
 /// A ternary conditional expression, that is, `condition ? if_true : if_false`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TernaryExpression {
     /// The condition determining which branch to pick.
     pub condition: Box<Expression>,
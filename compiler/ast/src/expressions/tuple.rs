@@ -19,7 +19,8 @@ use super::*;
 // TODO: Consider a restricted interface for constructing a tuple expression.
 
 /// A tuple expression, e.g., `(foo, false, 42)`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TupleExpression {
     /// The elements of the tuple.
     /// In the example above, it would be `foo`, `false`, and `42`.
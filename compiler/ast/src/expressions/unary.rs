@@ -18,7 +18,8 @@ use super::*;
 use leo_span::{sym, Symbol};
 
 /// A unary operator for a unary expression.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UnaryOperation {
     /// Absolute value checking for overflow, i.e. `.abs()`.
     Abs,
@@ -61,7 +62,7 @@ impl UnaryOperation {
     }
 
     /// Represents the opera.tor as a string.
-    fn as_str(self) -> &'static str {
+    pub fn as_str(self) -> &'static str {
         match self {
             Self::Abs => "abs",
             Self::AbsWrapped => "abs_wrapped",
@@ -78,7 +79,8 @@ impl UnaryOperation {
 }
 
 /// An unary expression applying an operator to an inner expression.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UnaryExpression {
     /// The inner expression `op` is applied to.
     pub receiver: Box<Expression>,
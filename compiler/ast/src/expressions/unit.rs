@@ -17,7 +17,8 @@
 use super::*;
 
 /// Represents a unit expression.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UnitExpression {
     /// The span of the unit expression.
     pub span: Span,
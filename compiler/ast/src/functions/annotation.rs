@@ -16,17 +16,50 @@
 
 use crate::{simple_node_impl, Identifier, Node, NodeID};
 
-use leo_span::Span;
+use leo_span::{sym, Span, Symbol};
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-/// An annotation, e.g. @program.
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+/// The value of an annotation of the form `@key(value)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AnnotationValue {
+    /// An identifier, e.g. `value` in `@key(value)`.
+    Identifier(Identifier),
+    /// A string, e.g. `"use transfer_v2"` in `@deprecated("use transfer_v2")`.
+    String(String, Span),
+}
+
+impl AnnotationValue {
+    /// Returns the span of the value.
+    pub fn span(&self) -> Span {
+        match self {
+            AnnotationValue::Identifier(identifier) => identifier.span,
+            AnnotationValue::String(_, span) => *span,
+        }
+    }
+}
+
+impl fmt::Display for AnnotationValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnnotationValue::Identifier(identifier) => write!(f, "{identifier}"),
+            AnnotationValue::String(string, _) => write!(f, "\"{string}\""),
+        }
+    }
+}
+
+/// An annotation, e.g. `@program`, `@test`, `@deprecated("use transfer_v2")`, or `@key(value)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Annotation {
     // TODO: Consider using a symbol instead of an identifier.
     /// The name of the annotation.
     pub identifier: Identifier,
+    /// The value of the annotation, if it is of the form `@key(value)`.
+    pub value: Option<AnnotationValue>,
     /// A span locating where the annotation occurred in the source.
     pub span: Span,
     /// The ID of the node.
@@ -35,8 +68,63 @@ pub struct Annotation {
 
 simple_node_impl!(Annotation);
 
+impl Annotation {
+    /// The names of the annotations that Leo supports.
+    pub const KNOWN: [Symbol; 3] = [sym::program, sym::test, sym::deprecated];
+
+    /// The names of the annotations of a program scope, which set the level of a lint, e.g. `@deny(mixed_signedness)`.
+    pub const LINT_LEVELS: [Symbol; 2] = [sym::warn, sym::deny];
+
+    /// The names of the lints whose level can be set by the annotations of a program scope.
+    pub const LINTS: [Symbol; 1] = [sym::mixed_signedness];
+
+    /// Returns the name of the annotation.
+    pub fn name(&self) -> Symbol {
+        self.identifier.name
+    }
+
+    /// Returns `true` if the annotation is `@test`, which marks a function that is only compiled into test builds.
+    pub fn is_test(&self) -> bool {
+        self.name() == sym::test
+    }
+
+    /// Returns `true` if the annotation is `@wrapping`, which makes the arithmetic of a block wrap on overflow.
+    pub fn is_wrapping(&self) -> bool {
+        self.name() == sym::wrapping
+    }
+
+    /// Returns `true` if the annotation is `@deprecated`, which makes each use of the item it annotates warn.
+    pub fn is_deprecated(&self) -> bool {
+        self.name() == sym::deprecated
+    }
+
+    /// Returns `true` if the annotation sets the level of a lint, e.g. `@warn(mixed_signedness)`.
+    pub fn is_lint_level(&self) -> bool {
+        Self::LINT_LEVELS.contains(&self.name())
+    }
+
+    /// Returns the name of the lint whose level is set by the annotation, if it is of the form `@level(lint)`.
+    pub fn lint(&self) -> Option<Symbol> {
+        match &self.value {
+            Some(AnnotationValue::Identifier(lint)) if self.is_lint_level() => Some(lint.name),
+            _ => None,
+        }
+    }
+
+    /// Returns the message of the annotation, if its value is a string, e.g. `@deprecated("use transfer_v2")`.
+    pub fn message(&self) -> Option<&str> {
+        match &self.value {
+            Some(AnnotationValue::String(message, _)) => Some(message),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for Annotation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "@{}", self.identifier)
+        match &self.value {
+            Some(value) => write!(f, "@{}({value})", self.identifier),
+            None => write!(f, "@{}", self.identifier),
+        }
     }
 }
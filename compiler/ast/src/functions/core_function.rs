@@ -14,8 +14,43 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{ArrayType, IntegerType, NonNegativeNumber, Type};
+
 use leo_span::{sym, Symbol};
 
+/// The expected input at an argument position of a core function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoreArgument {
+    /// A value of any type other than a mapping, tuple, or unit.
+    Hashable,
+    /// A hashable value that is not an integer wider than 32 bits.
+    Pedersen64Input,
+    /// A hashable value that is not an integer wider than 64 bits.
+    Pedersen128Input,
+    Address,
+    Field,
+    Group,
+    Scalar,
+    /// The randomness of a commitment or encryption, which is a scalar.
+    Randomness,
+    Signature,
+    Integer(IntegerType),
+    UnsignedInteger,
+    Array,
+    /// The little-endian bits of a value of the given number of bytes, grouped by byte, i.e. `[[bool; 8]; n]`.
+    BitsLe(usize),
+    /// The little-endian bytes of a value of the given number of bytes, i.e. `[u8; n]`.
+    BytesLe(usize),
+    Mapping,
+    /// A value of the key type of the mapping in the first argument.
+    MappingKey,
+    /// A value of the value type of the mapping in the first argument.
+    MappingValue,
+    /// An array of inputs, each of which is checked against the inner argument.
+    /// The inputs of an array literal are checked individually.
+    Variadic(&'static CoreArgument),
+}
+
 /// A core instruction that maps directly to an AVM bytecode instruction.
 #[derive(Clone, PartialEq, Eq)]
 pub enum CoreFunction {
@@ -202,6 +237,7 @@ pub enum CoreFunction {
     Poseidon2HashToU64,
     Poseidon2HashToU128,
     Poseidon2HashToScalar,
+    Poseidon2HashManyToField,
 
     Poseidon4HashToAddress,
     Poseidon4HashToField,
@@ -217,6 +253,7 @@ pub enum CoreFunction {
     Poseidon4HashToU64,
     Poseidon4HashToU128,
     Poseidon4HashToScalar,
+    Poseidon4HashManyToField,
 
     Poseidon8HashToAddress,
     Poseidon8HashToField,
@@ -232,6 +269,7 @@ pub enum CoreFunction {
     Poseidon8HashToU64,
     Poseidon8HashToU128,
     Poseidon8HashToScalar,
+    Poseidon8HashManyToField,
 
     SHA3_256HashToAddress,
     SHA3_256HashToField,
@@ -284,16 +322,111 @@ pub enum CoreFunction {
     MappingRemove,
     MappingContains,
 
+    ArrayLookup,
+
     GroupToXCoordinate,
     GroupToYCoordinate,
 
+    FieldFromI8,
+    FieldFromI16,
+    FieldFromI32,
+    FieldFromI64,
+    FieldFromI128,
+    FieldFromU8,
+    FieldFromU16,
+    FieldFromU32,
+    FieldFromU64,
+    FieldFromU128,
+
+    I8TryFromField,
+    I16TryFromField,
+    I32TryFromField,
+    I64TryFromField,
+    I128TryFromField,
+    U8TryFromField,
+    U16TryFromField,
+    U32TryFromField,
+    U64TryFromField,
+    U128TryFromField,
+
+    I8FromFieldWrapped,
+    I16FromFieldWrapped,
+    I32FromFieldWrapped,
+    I64FromFieldWrapped,
+    I128FromFieldWrapped,
+    U8FromFieldWrapped,
+    U16FromFieldWrapped,
+    U32FromFieldWrapped,
+    U64FromFieldWrapped,
+    U128FromFieldWrapped,
+
+    I8ToBitsLe,
+    I16ToBitsLe,
+    I32ToBitsLe,
+    I64ToBitsLe,
+    I128ToBitsLe,
+    U8ToBitsLe,
+    U16ToBitsLe,
+    U32ToBitsLe,
+    U64ToBitsLe,
+    U128ToBitsLe,
+    FieldToBitsLe,
+    GroupToBitsLe,
+
+    I8ToBytesLe,
+    I16ToBytesLe,
+    I32ToBytesLe,
+    I64ToBytesLe,
+    I128ToBytesLe,
+    U8ToBytesLe,
+    U16ToBytesLe,
+    U32ToBytesLe,
+    U64ToBytesLe,
+    U128ToBytesLe,
+    FieldToBytesLe,
+    GroupToBytesLe,
+
+    I8FromBitsLe,
+    I16FromBitsLe,
+    I32FromBitsLe,
+    I64FromBitsLe,
+    I128FromBitsLe,
+    U8FromBitsLe,
+    U16FromBitsLe,
+    U32FromBitsLe,
+    U64FromBitsLe,
+    U128FromBitsLe,
+    FieldFromBitsLe,
+    GroupFromBitsLe,
+
+    I8FromBytesLe,
+    I16FromBytesLe,
+    I32FromBytesLe,
+    I64FromBytesLe,
+    I128FromBytesLe,
+    U8FromBytesLe,
+    U16FromBytesLe,
+    U32FromBytesLe,
+    U64FromBytesLe,
+    U128FromBytesLe,
+    FieldFromBytesLe,
+    GroupFromBytesLe,
+
     SignatureVerify,
+
+    AddressFromField,
+    AddressFromGroup,
+    AddressToField,
+    AddressToGroup,
+
+    ElGamalEncrypt,
 }
 
 impl CoreFunction {
     /// Returns a `CoreFunction` from the given module and method symbols.
     pub fn from_symbols(module: Symbol, function: Symbol) -> Option<Self> {
         Some(match (module, function) {
+            (sym::BHP256, sym::commit) => Self::BHP256CommitToField,
             (sym::BHP256, sym::commit_to_address) => Self::BHP256CommitToAddress,
             (sym::BHP256, sym::commit_to_field) => Self::BHP256CommitToField,
             (sym::BHP256, sym::commit_to_group) => Self::BHP256CommitToGroup,
@@ -313,6 +446,7 @@ impl CoreFunction {
             (sym::BHP256, sym::hash_to_u128) => Self::BHP256HashToU128,
             (sym::BHP256, sym::hash_to_scalar) => Self::BHP256HashToScalar,
 
+            (sym::BHP512, sym::commit) => Self::BHP512CommitToField,
             (sym::BHP512, sym::commit_to_address) => Self::BHP512CommitToAddress,
             (sym::BHP512, sym::commit_to_field) => Self::BHP512CommitToField,
             (sym::BHP512, sym::commit_to_group) => Self::BHP512CommitToGroup,
@@ -331,6 +465,7 @@ impl CoreFunction {
             (sym::BHP512, sym::hash_to_u128) => Self::BHP512HashToU128,
             (sym::BHP512, sym::hash_to_scalar) => Self::BHP512HashToScalar,
 
+            (sym::BHP768, sym::commit) => Self::BHP768CommitToField,
             (sym::BHP768, sym::commit_to_address) => Self::BHP768CommitToAddress,
             (sym::BHP768, sym::commit_to_field) => Self::BHP768CommitToField,
             (sym::BHP768, sym::commit_to_group) => Self::BHP768CommitToGroup,
@@ -349,6 +484,7 @@ impl CoreFunction {
             (sym::BHP768, sym::hash_to_u128) => Self::BHP768HashToU128,
             (sym::BHP768, sym::hash_to_scalar) => Self::BHP768HashToScalar,
 
+            (sym::BHP1024, sym::commit) => Self::BHP1024CommitToField,
             (sym::BHP1024, sym::commit_to_address) => Self::BHP1024CommitToAddress,
             (sym::BHP1024, sym::commit_to_field) => Self::BHP1024CommitToField,
             (sym::BHP1024, sym::commit_to_group) => Self::BHP1024CommitToGroup,
@@ -428,6 +564,7 @@ impl CoreFunction {
             (sym::Keccak512, sym::hash_to_u128) => Self::Keccak512HashToU128,
             (sym::Keccak512, sym::hash_to_scalar) => Self::Keccak512HashToScalar,
 
+            (sym::Pedersen64, sym::commit) => Self::Pedersen64CommitToField,
             (sym::Pedersen64, sym::commit_to_address) => Self::Pedersen64CommitToAddress,
             (sym::Pedersen64, sym::commit_to_field) => Self::Pedersen64CommitToField,
             (sym::Pedersen64, sym::commit_to_group) => Self::Pedersen64CommitToGroup,
@@ -446,6 +583,7 @@ impl CoreFunction {
             (sym::Pedersen64, sym::hash_to_u128) => Self::Pedersen64HashToU128,
             (sym::Pedersen64, sym::hash_to_scalar) => Self::Pedersen64HashToScalar,
 
+            (sym::Pedersen128, sym::commit) => Self::Pedersen128CommitToField,
             (sym::Pedersen128, sym::commit_to_address) => Self::Pedersen128CommitToAddress,
             (sym::Pedersen128, sym::commit_to_field) => Self::Pedersen128CommitToField,
             (sym::Pedersen128, sym::commit_to_group) => Self::Pedersen128CommitToGroup,
@@ -478,6 +616,7 @@ impl CoreFunction {
             (sym::Poseidon2, sym::hash_to_u64) => Self::Poseidon2HashToU64,
             (sym::Poseidon2, sym::hash_to_u128) => Self::Poseidon2HashToU128,
             (sym::Poseidon2, sym::hash_to_scalar) => Self::Poseidon2HashToScalar,
+            (sym::Poseidon2, sym::hash_many_to_field) => Self::Poseidon2HashManyToField,
 
             (sym::Poseidon4, sym::hash_to_address) => Self::Poseidon4HashToAddress,
             (sym::Poseidon4, sym::hash_to_field) => Self::Poseidon4HashToField,
@@ -493,6 +632,7 @@ impl CoreFunction {
             (sym::Poseidon4, sym::hash_to_u64) => Self::Poseidon4HashToU64,
             (sym::Poseidon4, sym::hash_to_u128) => Self::Poseidon4HashToU128,
             (sym::Poseidon4, sym::hash_to_scalar) => Self::Poseidon4HashToScalar,
+            (sym::Poseidon4, sym::hash_many_to_field) => Self::Poseidon4HashManyToField,
 
             (sym::Poseidon8, sym::hash_to_address) => Self::Poseidon8HashToAddress,
             (sym::Poseidon8, sym::hash_to_field) => Self::Poseidon8HashToField,
@@ -508,6 +648,7 @@ impl CoreFunction {
             (sym::Poseidon8, sym::hash_to_u64) => Self::Poseidon8HashToU64,
             (sym::Poseidon8, sym::hash_to_u128) => Self::Poseidon8HashToU128,
             (sym::Poseidon8, sym::hash_to_scalar) => Self::Poseidon8HashToScalar,
+            (sym::Poseidon8, sym::hash_many_to_field) => Self::Poseidon8HashManyToField,
 
             (sym::SHA3_256, sym::hash_to_address) => Self::SHA3_256HashToAddress,
             (sym::SHA3_256, sym::hash_to_field) => Self::SHA3_256HashToField,
@@ -560,290 +701,478 @@ impl CoreFunction {
             (sym::Mapping, sym::remove) => Self::MappingRemove,
             (sym::Mapping, sym::contains) => Self::MappingContains,
 
+            (sym::Array, sym::lookup) => Self::ArrayLookup,
+
             (sym::group, sym::to_x_coordinate) => Self::GroupToXCoordinate,
             (sym::group, sym::to_y_coordinate) => Self::GroupToYCoordinate,
 
+            (sym::field, sym::from_i8) => Self::FieldFromI8,
+            (sym::field, sym::from_i16) => Self::FieldFromI16,
+            (sym::field, sym::from_i32) => Self::FieldFromI32,
+            (sym::field, sym::from_i64) => Self::FieldFromI64,
+            (sym::field, sym::from_i128) => Self::FieldFromI128,
+            (sym::field, sym::from_u8) => Self::FieldFromU8,
+            (sym::field, sym::from_u16) => Self::FieldFromU16,
+            (sym::field, sym::from_u32) => Self::FieldFromU32,
+            (sym::field, sym::from_u64) => Self::FieldFromU64,
+            (sym::field, sym::from_u128) => Self::FieldFromU128,
+
+            (sym::i8, sym::try_from_field) => Self::I8TryFromField,
+            (sym::i16, sym::try_from_field) => Self::I16TryFromField,
+            (sym::i32, sym::try_from_field) => Self::I32TryFromField,
+            (sym::i64, sym::try_from_field) => Self::I64TryFromField,
+            (sym::i128, sym::try_from_field) => Self::I128TryFromField,
+            (sym::u8, sym::try_from_field) => Self::U8TryFromField,
+            (sym::u16, sym::try_from_field) => Self::U16TryFromField,
+            (sym::u32, sym::try_from_field) => Self::U32TryFromField,
+            (sym::u64, sym::try_from_field) => Self::U64TryFromField,
+            (sym::u128, sym::try_from_field) => Self::U128TryFromField,
+
+            (sym::i8, sym::from_field_wrapped) => Self::I8FromFieldWrapped,
+            (sym::i16, sym::from_field_wrapped) => Self::I16FromFieldWrapped,
+            (sym::i32, sym::from_field_wrapped) => Self::I32FromFieldWrapped,
+            (sym::i64, sym::from_field_wrapped) => Self::I64FromFieldWrapped,
+            (sym::i128, sym::from_field_wrapped) => Self::I128FromFieldWrapped,
+            (sym::u8, sym::from_field_wrapped) => Self::U8FromFieldWrapped,
+            (sym::u16, sym::from_field_wrapped) => Self::U16FromFieldWrapped,
+            (sym::u32, sym::from_field_wrapped) => Self::U32FromFieldWrapped,
+            (sym::u64, sym::from_field_wrapped) => Self::U64FromFieldWrapped,
+            (sym::u128, sym::from_field_wrapped) => Self::U128FromFieldWrapped,
+
+            (sym::i8, sym::to_bits_le) => Self::I8ToBitsLe,
+            (sym::i16, sym::to_bits_le) => Self::I16ToBitsLe,
+            (sym::i32, sym::to_bits_le) => Self::I32ToBitsLe,
+            (sym::i64, sym::to_bits_le) => Self::I64ToBitsLe,
+            (sym::i128, sym::to_bits_le) => Self::I128ToBitsLe,
+            (sym::u8, sym::to_bits_le) => Self::U8ToBitsLe,
+            (sym::u16, sym::to_bits_le) => Self::U16ToBitsLe,
+            (sym::u32, sym::to_bits_le) => Self::U32ToBitsLe,
+            (sym::u64, sym::to_bits_le) => Self::U64ToBitsLe,
+            (sym::u128, sym::to_bits_le) => Self::U128ToBitsLe,
+            (sym::field, sym::to_bits_le) => Self::FieldToBitsLe,
+            (sym::group, sym::to_bits_le) => Self::GroupToBitsLe,
+
+            (sym::i8, sym::to_bytes_le) => Self::I8ToBytesLe,
+            (sym::i16, sym::to_bytes_le) => Self::I16ToBytesLe,
+            (sym::i32, sym::to_bytes_le) => Self::I32ToBytesLe,
+            (sym::i64, sym::to_bytes_le) => Self::I64ToBytesLe,
+            (sym::i128, sym::to_bytes_le) => Self::I128ToBytesLe,
+            (sym::u8, sym::to_bytes_le) => Self::U8ToBytesLe,
+            (sym::u16, sym::to_bytes_le) => Self::U16ToBytesLe,
+            (sym::u32, sym::to_bytes_le) => Self::U32ToBytesLe,
+            (sym::u64, sym::to_bytes_le) => Self::U64ToBytesLe,
+            (sym::u128, sym::to_bytes_le) => Self::U128ToBytesLe,
+            (sym::field, sym::to_bytes_le) => Self::FieldToBytesLe,
+            (sym::group, sym::to_bytes_le) => Self::GroupToBytesLe,
+
+            (sym::i8, sym::from_bits_le) => Self::I8FromBitsLe,
+            (sym::i16, sym::from_bits_le) => Self::I16FromBitsLe,
+            (sym::i32, sym::from_bits_le) => Self::I32FromBitsLe,
+            (sym::i64, sym::from_bits_le) => Self::I64FromBitsLe,
+            (sym::i128, sym::from_bits_le) => Self::I128FromBitsLe,
+            (sym::u8, sym::from_bits_le) => Self::U8FromBitsLe,
+            (sym::u16, sym::from_bits_le) => Self::U16FromBitsLe,
+            (sym::u32, sym::from_bits_le) => Self::U32FromBitsLe,
+            (sym::u64, sym::from_bits_le) => Self::U64FromBitsLe,
+            (sym::u128, sym::from_bits_le) => Self::U128FromBitsLe,
+            (sym::field, sym::from_bits_le) => Self::FieldFromBitsLe,
+            (sym::group, sym::from_bits_le) => Self::GroupFromBitsLe,
+
+            (sym::i8, sym::from_bytes_le) => Self::I8FromBytesLe,
+            (sym::i16, sym::from_bytes_le) => Self::I16FromBytesLe,
+            (sym::i32, sym::from_bytes_le) => Self::I32FromBytesLe,
+            (sym::i64, sym::from_bytes_le) => Self::I64FromBytesLe,
+            (sym::i128, sym::from_bytes_le) => Self::I128FromBytesLe,
+            (sym::u8, sym::from_bytes_le) => Self::U8FromBytesLe,
+            (sym::u16, sym::from_bytes_le) => Self::U16FromBytesLe,
+            (sym::u32, sym::from_bytes_le) => Self::U32FromBytesLe,
+            (sym::u64, sym::from_bytes_le) => Self::U64FromBytesLe,
+            (sym::u128, sym::from_bytes_le) => Self::U128FromBytesLe,
+            (sym::field, sym::from_bytes_le) => Self::FieldFromBytesLe,
+            (sym::group, sym::from_bytes_le) => Self::GroupFromBytesLe,
+
             (sym::signature, sym::verify) => Self::SignatureVerify,
+
+            (sym::address, sym::from_field) => Self::AddressFromField,
+            (sym::address, sym::from_group) => Self::AddressFromGroup,
+            (sym::address, sym::to_field) => Self::AddressToField,
+            (sym::address, sym::to_group) => Self::AddressToGroup,
+
+            (sym::ElGamal, sym::encrypt) => Self::ElGamalEncrypt,
             _ => return None,
         })
     }
 
-    /// Returns the number of arguments required by the instruction.
-    pub fn num_args(&self) -> usize {
+    /// Returns the expected input at each argument position of the instruction.
+    pub fn arguments(&self) -> &'static [CoreArgument] {
+        use CoreArgument::*;
         match self {
-            Self::BHP256CommitToAddress => 2,
-            Self::BHP256CommitToField => 2,
-            Self::BHP256CommitToGroup => 2,
-
-            Self::BHP256HashToAddress => 1,
-            Self::BHP256HashToField => 1,
-            Self::BHP256HashToGroup => 1,
-            Self::BHP256HashToI8 => 1,
-            Self::BHP256HashToI16 => 1,
-            Self::BHP256HashToI32 => 1,
-            Self::BHP256HashToI64 => 1,
-            Self::BHP256HashToI128 => 1,
-            Self::BHP256HashToU8 => 1,
-            Self::BHP256HashToU16 => 1,
-            Self::BHP256HashToU32 => 1,
-            Self::BHP256HashToU64 => 1,
-            Self::BHP256HashToU128 => 1,
-            Self::BHP256HashToScalar => 1,
-
-            Self::BHP512CommitToAddress => 2,
-            Self::BHP512CommitToField => 2,
-            Self::BHP512CommitToGroup => 2,
-            Self::BHP512HashToAddress => 1,
-            Self::BHP512HashToField => 1,
-            Self::BHP512HashToGroup => 1,
-            Self::BHP512HashToI8 => 1,
-            Self::BHP512HashToI16 => 1,
-            Self::BHP512HashToI32 => 1,
-            Self::BHP512HashToI64 => 1,
-            Self::BHP512HashToI128 => 1,
-            Self::BHP512HashToU8 => 1,
-            Self::BHP512HashToU16 => 1,
-            Self::BHP512HashToU32 => 1,
-            Self::BHP512HashToU64 => 1,
-            Self::BHP512HashToU128 => 1,
-            Self::BHP512HashToScalar => 1,
-
-            Self::BHP768CommitToAddress => 2,
-            Self::BHP768CommitToField => 2,
-            Self::BHP768CommitToGroup => 2,
-            Self::BHP768HashToAddress => 1,
-            Self::BHP768HashToField => 1,
-            Self::BHP768HashToGroup => 1,
-            Self::BHP768HashToI8 => 1,
-            Self::BHP768HashToI16 => 1,
-            Self::BHP768HashToI32 => 1,
-            Self::BHP768HashToI64 => 1,
-            Self::BHP768HashToI128 => 1,
-            Self::BHP768HashToU8 => 1,
-            Self::BHP768HashToU16 => 1,
-            Self::BHP768HashToU32 => 1,
-            Self::BHP768HashToU64 => 1,
-            Self::BHP768HashToU128 => 1,
-            Self::BHP768HashToScalar => 1,
-
-            Self::BHP1024CommitToAddress => 2,
-            Self::BHP1024CommitToField => 2,
-            Self::BHP1024CommitToGroup => 2,
-            Self::BHP1024HashToAddress => 1,
-            Self::BHP1024HashToField => 1,
-            Self::BHP1024HashToGroup => 1,
-            Self::BHP1024HashToI8 => 1,
-            Self::BHP1024HashToI16 => 1,
-            Self::BHP1024HashToI32 => 1,
-            Self::BHP1024HashToI64 => 1,
-            Self::BHP1024HashToI128 => 1,
-            Self::BHP1024HashToU8 => 1,
-            Self::BHP1024HashToU16 => 1,
-            Self::BHP1024HashToU32 => 1,
-            Self::BHP1024HashToU64 => 1,
-            Self::BHP1024HashToU128 => 1,
-            Self::BHP1024HashToScalar => 1,
-
-            Self::ChaChaRandAddress => 0,
-            Self::ChaChaRandBool => 0,
-            Self::ChaChaRandField => 0,
-            Self::ChaChaRandGroup => 0,
-            Self::ChaChaRandI8 => 0,
-            Self::ChaChaRandI16 => 0,
-            Self::ChaChaRandI32 => 0,
-            Self::ChaChaRandI64 => 0,
-            Self::ChaChaRandI128 => 0,
-            Self::ChaChaRandU8 => 0,
-            Self::ChaChaRandU16 => 0,
-            Self::ChaChaRandU32 => 0,
-            Self::ChaChaRandU64 => 0,
-            Self::ChaChaRandU128 => 0,
-            Self::ChaChaRandScalar => 0,
-
-            Self::Keccak256HashToAddress => 1,
-            Self::Keccak256HashToField => 1,
-            Self::Keccak256HashToGroup => 1,
-            Self::Keccak256HashToI8 => 1,
-            Self::Keccak256HashToI16 => 1,
-            Self::Keccak256HashToI32 => 1,
-            Self::Keccak256HashToI64 => 1,
-            Self::Keccak256HashToI128 => 1,
-            Self::Keccak256HashToU8 => 1,
-            Self::Keccak256HashToU16 => 1,
-            Self::Keccak256HashToU32 => 1,
-            Self::Keccak256HashToU64 => 1,
-            Self::Keccak256HashToU128 => 1,
-            Self::Keccak256HashToScalar => 1,
-
-            Self::Keccak384HashToAddress => 1,
-            Self::Keccak384HashToField => 1,
-            Self::Keccak384HashToGroup => 1,
-            Self::Keccak384HashToI8 => 1,
-            Self::Keccak384HashToI16 => 1,
-            Self::Keccak384HashToI32 => 1,
-            Self::Keccak384HashToI64 => 1,
-            Self::Keccak384HashToI128 => 1,
-            Self::Keccak384HashToU8 => 1,
-            Self::Keccak384HashToU16 => 1,
-            Self::Keccak384HashToU32 => 1,
-            Self::Keccak384HashToU64 => 1,
-            Self::Keccak384HashToU128 => 1,
-            Self::Keccak384HashToScalar => 1,
-
-            Self::Keccak512HashToAddress => 1,
-            Self::Keccak512HashToField => 1,
-            Self::Keccak512HashToGroup => 1,
-            Self::Keccak512HashToI8 => 1,
-            Self::Keccak512HashToI16 => 1,
-            Self::Keccak512HashToI32 => 1,
-            Self::Keccak512HashToI64 => 1,
-            Self::Keccak512HashToI128 => 1,
-            Self::Keccak512HashToU8 => 1,
-            Self::Keccak512HashToU16 => 1,
-            Self::Keccak512HashToU32 => 1,
-            Self::Keccak512HashToU64 => 1,
-            Self::Keccak512HashToU128 => 1,
-            Self::Keccak512HashToScalar => 1,
-
-            Self::Pedersen64CommitToAddress => 2,
-            Self::Pedersen64CommitToField => 2,
-            Self::Pedersen64CommitToGroup => 2,
-            Self::Pedersen64HashToAddress => 1,
-            Self::Pedersen64HashToField => 1,
-            Self::Pedersen64HashToGroup => 1,
-            Self::Pedersen64HashToI8 => 1,
-            Self::Pedersen64HashToI16 => 1,
-            Self::Pedersen64HashToI32 => 1,
-            Self::Pedersen64HashToI64 => 1,
-            Self::Pedersen64HashToI128 => 1,
-            Self::Pedersen64HashToU8 => 1,
-            Self::Pedersen64HashToU16 => 1,
-            Self::Pedersen64HashToU32 => 1,
-            Self::Pedersen64HashToU64 => 1,
-            Self::Pedersen64HashToU128 => 1,
-            Self::Pedersen64HashToScalar => 1,
-
-            Self::Pedersen128CommitToAddress => 2,
-            Self::Pedersen128CommitToField => 2,
-            Self::Pedersen128CommitToGroup => 2,
-            Self::Pedersen128HashToAddress => 1,
-            Self::Pedersen128HashToField => 1,
-            Self::Pedersen128HashToGroup => 1,
-            Self::Pedersen128HashToI8 => 1,
-            Self::Pedersen128HashToI16 => 1,
-            Self::Pedersen128HashToI32 => 1,
-            Self::Pedersen128HashToI64 => 1,
-            Self::Pedersen128HashToI128 => 1,
-            Self::Pedersen128HashToU8 => 1,
-            Self::Pedersen128HashToU16 => 1,
-            Self::Pedersen128HashToU32 => 1,
-            Self::Pedersen128HashToU64 => 1,
-            Self::Pedersen128HashToU128 => 1,
-            Self::Pedersen128HashToScalar => 1,
-
-            Self::Poseidon2HashToAddress => 1,
-            Self::Poseidon2HashToField => 1,
-            Self::Poseidon2HashToGroup => 1,
-            Self::Poseidon2HashToI8 => 1,
-            Self::Poseidon2HashToI16 => 1,
-            Self::Poseidon2HashToI32 => 1,
-            Self::Poseidon2HashToI64 => 1,
-            Self::Poseidon2HashToI128 => 1,
-            Self::Poseidon2HashToU8 => 1,
-            Self::Poseidon2HashToU16 => 1,
-            Self::Poseidon2HashToU32 => 1,
-            Self::Poseidon2HashToU64 => 1,
-            Self::Poseidon2HashToU128 => 1,
-            Self::Poseidon2HashToScalar => 1,
-
-            Self::Poseidon4HashToAddress => 1,
-            Self::Poseidon4HashToField => 1,
-            Self::Poseidon4HashToGroup => 1,
-            Self::Poseidon4HashToI8 => 1,
-            Self::Poseidon4HashToI16 => 1,
-            Self::Poseidon4HashToI32 => 1,
-            Self::Poseidon4HashToI64 => 1,
-            Self::Poseidon4HashToI128 => 1,
-            Self::Poseidon4HashToU8 => 1,
-            Self::Poseidon4HashToU16 => 1,
-            Self::Poseidon4HashToU32 => 1,
-            Self::Poseidon4HashToU64 => 1,
-            Self::Poseidon4HashToU128 => 1,
-            Self::Poseidon4HashToScalar => 1,
-
-            Self::Poseidon8HashToAddress => 1,
-            Self::Poseidon8HashToField => 1,
-            Self::Poseidon8HashToGroup => 1,
-            Self::Poseidon8HashToI8 => 1,
-            Self::Poseidon8HashToI16 => 1,
-            Self::Poseidon8HashToI32 => 1,
-            Self::Poseidon8HashToI64 => 1,
-            Self::Poseidon8HashToI128 => 1,
-            Self::Poseidon8HashToU8 => 1,
-            Self::Poseidon8HashToU16 => 1,
-            Self::Poseidon8HashToU32 => 1,
-            Self::Poseidon8HashToU64 => 1,
-            Self::Poseidon8HashToU128 => 1,
-            Self::Poseidon8HashToScalar => 1,
-
-            Self::SHA3_256HashToAddress => 1,
-            Self::SHA3_256HashToField => 1,
-            Self::SHA3_256HashToGroup => 1,
-            Self::SHA3_256HashToI8 => 1,
-            Self::SHA3_256HashToI16 => 1,
-            Self::SHA3_256HashToI32 => 1,
-            Self::SHA3_256HashToI64 => 1,
-            Self::SHA3_256HashToI128 => 1,
-            Self::SHA3_256HashToU8 => 1,
-            Self::SHA3_256HashToU16 => 1,
-            Self::SHA3_256HashToU32 => 1,
-            Self::SHA3_256HashToU64 => 1,
-            Self::SHA3_256HashToU128 => 1,
-            Self::SHA3_256HashToScalar => 1,
-
-            Self::SHA3_384HashToAddress => 1,
-            Self::SHA3_384HashToField => 1,
-            Self::SHA3_384HashToGroup => 1,
-            Self::SHA3_384HashToI8 => 1,
-            Self::SHA3_384HashToI16 => 1,
-            Self::SHA3_384HashToI32 => 1,
-            Self::SHA3_384HashToI64 => 1,
-            Self::SHA3_384HashToI128 => 1,
-            Self::SHA3_384HashToU8 => 1,
-            Self::SHA3_384HashToU16 => 1,
-            Self::SHA3_384HashToU32 => 1,
-            Self::SHA3_384HashToU64 => 1,
-            Self::SHA3_384HashToU128 => 1,
-            Self::SHA3_384HashToScalar => 1,
-
-            Self::SHA3_512HashToAddress => 1,
-            Self::SHA3_512HashToField => 1,
-            Self::SHA3_512HashToGroup => 1,
-            Self::SHA3_512HashToI8 => 1,
-            Self::SHA3_512HashToI16 => 1,
-            Self::SHA3_512HashToI32 => 1,
-            Self::SHA3_512HashToI64 => 1,
-            Self::SHA3_512HashToI128 => 1,
-            Self::SHA3_512HashToU8 => 1,
-            Self::SHA3_512HashToU16 => 1,
-            Self::SHA3_512HashToU32 => 1,
-            Self::SHA3_512HashToU64 => 1,
-            Self::SHA3_512HashToU128 => 1,
-            Self::SHA3_512HashToScalar => 1,
-
-            Self::MappingGet => 2,
-            Self::MappingGetOrUse => 3,
-            Self::MappingSet => 3,
-            Self::MappingRemove => 2,
-            Self::MappingContains => 2,
-
-            Self::GroupToXCoordinate => 1,
-            Self::GroupToYCoordinate => 1,
-
-            Self::SignatureVerify => 3,
+            Self::BHP256CommitToAddress
+            | Self::BHP256CommitToField
+            | Self::BHP256CommitToGroup
+            | Self::BHP512CommitToAddress
+            | Self::BHP512CommitToField
+            | Self::BHP512CommitToGroup
+            | Self::BHP768CommitToAddress
+            | Self::BHP768CommitToField
+            | Self::BHP768CommitToGroup
+            | Self::BHP1024CommitToAddress
+            | Self::BHP1024CommitToField
+            | Self::BHP1024CommitToGroup => &[Hashable, Randomness],
+            Self::BHP256HashToAddress
+            | Self::BHP256HashToField
+            | Self::BHP256HashToGroup
+            | Self::BHP256HashToI8
+            | Self::BHP256HashToI16
+            | Self::BHP256HashToI32
+            | Self::BHP256HashToI64
+            | Self::BHP256HashToI128
+            | Self::BHP256HashToU8
+            | Self::BHP256HashToU16
+            | Self::BHP256HashToU32
+            | Self::BHP256HashToU64
+            | Self::BHP256HashToU128
+            | Self::BHP256HashToScalar
+            | Self::BHP512HashToAddress
+            | Self::BHP512HashToField
+            | Self::BHP512HashToGroup
+            | Self::BHP512HashToI8
+            | Self::BHP512HashToI16
+            | Self::BHP512HashToI32
+            | Self::BHP512HashToI64
+            | Self::BHP512HashToI128
+            | Self::BHP512HashToU8
+            | Self::BHP512HashToU16
+            | Self::BHP512HashToU32
+            | Self::BHP512HashToU64
+            | Self::BHP512HashToU128
+            | Self::BHP512HashToScalar
+            | Self::BHP768HashToAddress
+            | Self::BHP768HashToField
+            | Self::BHP768HashToGroup
+            | Self::BHP768HashToI8
+            | Self::BHP768HashToI16
+            | Self::BHP768HashToI32
+            | Self::BHP768HashToI64
+            | Self::BHP768HashToI128
+            | Self::BHP768HashToU8
+            | Self::BHP768HashToU16
+            | Self::BHP768HashToU32
+            | Self::BHP768HashToU64
+            | Self::BHP768HashToU128
+            | Self::BHP768HashToScalar
+            | Self::BHP1024HashToAddress
+            | Self::BHP1024HashToField
+            | Self::BHP1024HashToGroup
+            | Self::BHP1024HashToI8
+            | Self::BHP1024HashToI16
+            | Self::BHP1024HashToI32
+            | Self::BHP1024HashToI64
+            | Self::BHP1024HashToI128
+            | Self::BHP1024HashToU8
+            | Self::BHP1024HashToU16
+            | Self::BHP1024HashToU32
+            | Self::BHP1024HashToU64
+            | Self::BHP1024HashToU128
+            | Self::BHP1024HashToScalar
+            | Self::Keccak256HashToAddress
+            | Self::Keccak256HashToField
+            | Self::Keccak256HashToGroup
+            | Self::Keccak256HashToI8
+            | Self::Keccak256HashToI16
+            | Self::Keccak256HashToI32
+            | Self::Keccak256HashToI64
+            | Self::Keccak256HashToI128
+            | Self::Keccak256HashToU8
+            | Self::Keccak256HashToU16
+            | Self::Keccak256HashToU32
+            | Self::Keccak256HashToU64
+            | Self::Keccak256HashToU128
+            | Self::Keccak256HashToScalar
+            | Self::Keccak384HashToAddress
+            | Self::Keccak384HashToField
+            | Self::Keccak384HashToGroup
+            | Self::Keccak384HashToI8
+            | Self::Keccak384HashToI16
+            | Self::Keccak384HashToI32
+            | Self::Keccak384HashToI64
+            | Self::Keccak384HashToI128
+            | Self::Keccak384HashToU8
+            | Self::Keccak384HashToU16
+            | Self::Keccak384HashToU32
+            | Self::Keccak384HashToU64
+            | Self::Keccak384HashToU128
+            | Self::Keccak384HashToScalar
+            | Self::Keccak512HashToAddress
+            | Self::Keccak512HashToField
+            | Self::Keccak512HashToGroup
+            | Self::Keccak512HashToI8
+            | Self::Keccak512HashToI16
+            | Self::Keccak512HashToI32
+            | Self::Keccak512HashToI64
+            | Self::Keccak512HashToI128
+            | Self::Keccak512HashToU8
+            | Self::Keccak512HashToU16
+            | Self::Keccak512HashToU32
+            | Self::Keccak512HashToU64
+            | Self::Keccak512HashToU128
+            | Self::Keccak512HashToScalar
+            | Self::Poseidon2HashToAddress
+            | Self::Poseidon2HashToField
+            | Self::Poseidon2HashToGroup
+            | Self::Poseidon2HashToI8
+            | Self::Poseidon2HashToI16
+            | Self::Poseidon2HashToI32
+            | Self::Poseidon2HashToI64
+            | Self::Poseidon2HashToI128
+            | Self::Poseidon2HashToU8
+            | Self::Poseidon2HashToU16
+            | Self::Poseidon2HashToU32
+            | Self::Poseidon2HashToU64
+            | Self::Poseidon2HashToU128
+            | Self::Poseidon2HashToScalar
+            | Self::Poseidon4HashToAddress
+            | Self::Poseidon4HashToField
+            | Self::Poseidon4HashToGroup
+            | Self::Poseidon4HashToI8
+            | Self::Poseidon4HashToI16
+            | Self::Poseidon4HashToI32
+            | Self::Poseidon4HashToI64
+            | Self::Poseidon4HashToI128
+            | Self::Poseidon4HashToU8
+            | Self::Poseidon4HashToU16
+            | Self::Poseidon4HashToU32
+            | Self::Poseidon4HashToU64
+            | Self::Poseidon4HashToU128
+            | Self::Poseidon4HashToScalar
+            | Self::Poseidon8HashToAddress
+            | Self::Poseidon8HashToField
+            | Self::Poseidon8HashToGroup
+            | Self::Poseidon8HashToI8
+            | Self::Poseidon8HashToI16
+            | Self::Poseidon8HashToI32
+            | Self::Poseidon8HashToI64
+            | Self::Poseidon8HashToI128
+            | Self::Poseidon8HashToU8
+            | Self::Poseidon8HashToU16
+            | Self::Poseidon8HashToU32
+            | Self::Poseidon8HashToU64
+            | Self::Poseidon8HashToU128
+            | Self::Poseidon8HashToScalar
+            | Self::SHA3_256HashToAddress
+            | Self::SHA3_256HashToField
+            | Self::SHA3_256HashToGroup
+            | Self::SHA3_256HashToI8
+            | Self::SHA3_256HashToI16
+            | Self::SHA3_256HashToI32
+            | Self::SHA3_256HashToI64
+            | Self::SHA3_256HashToI128
+            | Self::SHA3_256HashToU8
+            | Self::SHA3_256HashToU16
+            | Self::SHA3_256HashToU32
+            | Self::SHA3_256HashToU64
+            | Self::SHA3_256HashToU128
+            | Self::SHA3_256HashToScalar
+            | Self::SHA3_384HashToAddress
+            | Self::SHA3_384HashToField
+            | Self::SHA3_384HashToGroup
+            | Self::SHA3_384HashToI8
+            | Self::SHA3_384HashToI16
+            | Self::SHA3_384HashToI32
+            | Self::SHA3_384HashToI64
+            | Self::SHA3_384HashToI128
+            | Self::SHA3_384HashToU8
+            | Self::SHA3_384HashToU16
+            | Self::SHA3_384HashToU32
+            | Self::SHA3_384HashToU64
+            | Self::SHA3_384HashToU128
+            | Self::SHA3_384HashToScalar
+            | Self::SHA3_512HashToAddress
+            | Self::SHA3_512HashToField
+            | Self::SHA3_512HashToGroup
+            | Self::SHA3_512HashToI8
+            | Self::SHA3_512HashToI16
+            | Self::SHA3_512HashToI32
+            | Self::SHA3_512HashToI64
+            | Self::SHA3_512HashToI128
+            | Self::SHA3_512HashToU8
+            | Self::SHA3_512HashToU16
+            | Self::SHA3_512HashToU32
+            | Self::SHA3_512HashToU64
+            | Self::SHA3_512HashToU128
+            | Self::SHA3_512HashToScalar => &[Hashable],
+            Self::ChaChaRandAddress
+            | Self::ChaChaRandBool
+            | Self::ChaChaRandField
+            | Self::ChaChaRandGroup
+            | Self::ChaChaRandI8
+            | Self::ChaChaRandI16
+            | Self::ChaChaRandI32
+            | Self::ChaChaRandI64
+            | Self::ChaChaRandI128
+            | Self::ChaChaRandU8
+            | Self::ChaChaRandU16
+            | Self::ChaChaRandU32
+            | Self::ChaChaRandU64
+            | Self::ChaChaRandU128
+            | Self::ChaChaRandScalar => &[],
+            Self::Pedersen64CommitToAddress | Self::Pedersen64CommitToField | Self::Pedersen64CommitToGroup => {
+                &[Pedersen64Input, Randomness]
+            }
+            Self::Pedersen64HashToAddress
+            | Self::Pedersen64HashToField
+            | Self::Pedersen64HashToGroup
+            | Self::Pedersen64HashToI8
+            | Self::Pedersen64HashToI16
+            | Self::Pedersen64HashToI32
+            | Self::Pedersen64HashToI64
+            | Self::Pedersen64HashToI128
+            | Self::Pedersen64HashToU8
+            | Self::Pedersen64HashToU16
+            | Self::Pedersen64HashToU32
+            | Self::Pedersen64HashToU64
+            | Self::Pedersen64HashToU128
+            | Self::Pedersen64HashToScalar => &[Pedersen64Input],
+            Self::Pedersen128CommitToAddress | Self::Pedersen128CommitToField | Self::Pedersen128CommitToGroup => {
+                &[Pedersen128Input, Randomness]
+            }
+            Self::Pedersen128HashToAddress
+            | Self::Pedersen128HashToField
+            | Self::Pedersen128HashToGroup
+            | Self::Pedersen128HashToI8
+            | Self::Pedersen128HashToI16
+            | Self::Pedersen128HashToI32
+            | Self::Pedersen128HashToI64
+            | Self::Pedersen128HashToI128
+            | Self::Pedersen128HashToU8
+            | Self::Pedersen128HashToU16
+            | Self::Pedersen128HashToU32
+            | Self::Pedersen128HashToU64
+            | Self::Pedersen128HashToU128
+            | Self::Pedersen128HashToScalar => &[Pedersen128Input],
+            Self::Poseidon2HashManyToField | Self::Poseidon4HashManyToField | Self::Poseidon8HashManyToField => {
+                &[Variadic(&Hashable)]
+            }
+            Self::MappingGet | Self::MappingRemove | Self::MappingContains => &[Mapping, MappingKey],
+            Self::MappingGetOrUse | Self::MappingSet => &[Mapping, MappingKey, MappingValue],
+            Self::ArrayLookup => &[Array, UnsignedInteger],
+            Self::GroupToXCoordinate | Self::GroupToYCoordinate | Self::AddressFromGroup => &[Group],
+            Self::FieldFromI8 => &[Integer(IntegerType::I8)],
+            Self::FieldFromI16 => &[Integer(IntegerType::I16)],
+            Self::FieldFromI32 => &[Integer(IntegerType::I32)],
+            Self::FieldFromI64 => &[Integer(IntegerType::I64)],
+            Self::FieldFromI128 => &[Integer(IntegerType::I128)],
+            Self::FieldFromU8 => &[Integer(IntegerType::U8)],
+            Self::FieldFromU16 => &[Integer(IntegerType::U16)],
+            Self::FieldFromU32 => &[Integer(IntegerType::U32)],
+            Self::FieldFromU64 => &[Integer(IntegerType::U64)],
+            Self::FieldFromU128 => &[Integer(IntegerType::U128)],
+            Self::I8TryFromField
+            | Self::I16TryFromField
+            | Self::I32TryFromField
+            | Self::I64TryFromField
+            | Self::I128TryFromField
+            | Self::U8TryFromField
+            | Self::U16TryFromField
+            | Self::U32TryFromField
+            | Self::U64TryFromField
+            | Self::U128TryFromField
+            | Self::I8FromFieldWrapped
+            | Self::I16FromFieldWrapped
+            | Self::I32FromFieldWrapped
+            | Self::I64FromFieldWrapped
+            | Self::I128FromFieldWrapped
+            | Self::U8FromFieldWrapped
+            | Self::U16FromFieldWrapped
+            | Self::U32FromFieldWrapped
+            | Self::U64FromFieldWrapped
+            | Self::U128FromFieldWrapped
+            | Self::AddressFromField => &[Field],
+            Self::I8ToBitsLe | Self::I8ToBytesLe => &[Integer(IntegerType::I8)],
+            Self::I16ToBitsLe | Self::I16ToBytesLe => &[Integer(IntegerType::I16)],
+            Self::I32ToBitsLe | Self::I32ToBytesLe => &[Integer(IntegerType::I32)],
+            Self::I64ToBitsLe | Self::I64ToBytesLe => &[Integer(IntegerType::I64)],
+            Self::I128ToBitsLe | Self::I128ToBytesLe => &[Integer(IntegerType::I128)],
+            Self::U8ToBitsLe | Self::U8ToBytesLe => &[Integer(IntegerType::U8)],
+            Self::U16ToBitsLe | Self::U16ToBytesLe => &[Integer(IntegerType::U16)],
+            Self::U32ToBitsLe | Self::U32ToBytesLe => &[Integer(IntegerType::U32)],
+            Self::U64ToBitsLe | Self::U64ToBytesLe => &[Integer(IntegerType::U64)],
+            Self::U128ToBitsLe | Self::U128ToBytesLe => &[Integer(IntegerType::U128)],
+            Self::FieldToBitsLe | Self::FieldToBytesLe => &[Field],
+            Self::GroupToBitsLe | Self::GroupToBytesLe => &[Group],
+            Self::I8FromBitsLe => &[BitsLe(1)],
+            Self::I16FromBitsLe => &[BitsLe(2)],
+            Self::I32FromBitsLe => &[BitsLe(4)],
+            Self::I64FromBitsLe => &[BitsLe(8)],
+            Self::I128FromBitsLe => &[BitsLe(16)],
+            Self::U8FromBitsLe => &[BitsLe(1)],
+            Self::U16FromBitsLe => &[BitsLe(2)],
+            Self::U32FromBitsLe => &[BitsLe(4)],
+            Self::U64FromBitsLe => &[BitsLe(8)],
+            Self::U128FromBitsLe => &[BitsLe(16)],
+            Self::FieldFromBitsLe => &[BitsLe(32)],
+            Self::GroupFromBitsLe => &[BitsLe(32)],
+            Self::I8FromBytesLe => &[BytesLe(1)],
+            Self::I16FromBytesLe => &[BytesLe(2)],
+            Self::I32FromBytesLe => &[BytesLe(4)],
+            Self::I64FromBytesLe => &[BytesLe(8)],
+            Self::I128FromBytesLe => &[BytesLe(16)],
+            Self::U8FromBytesLe => &[BytesLe(1)],
+            Self::U16FromBytesLe => &[BytesLe(2)],
+            Self::U32FromBytesLe => &[BytesLe(4)],
+            Self::U64FromBytesLe => &[BytesLe(8)],
+            Self::U128FromBytesLe => &[BytesLe(16)],
+            Self::FieldFromBytesLe => &[BytesLe(32)],
+            Self::GroupFromBytesLe => &[BytesLe(32)],
+            Self::SignatureVerify => &[Signature, Address, Hashable],
+            Self::AddressToField | Self::AddressToGroup => &[Address],
+            Self::ElGamalEncrypt => &[Group, Group, Randomness],
         }
     }
 
+    /// Returns the type of the little-endian bits of a value of `bytes` bytes.
+    /// The bits are grouped by byte, i.e. `[[bool; 8]; bytes]`, so that the bits of a field or a `u128` fit in arrays
+    /// of at most 32 elements.
+    pub fn bits_le_type(bytes: usize) -> Type {
+        let byte = Type::Array(ArrayType::new(Type::Boolean, NonNegativeNumber::from(8)));
+        Type::Array(ArrayType::new(byte, NonNegativeNumber::from(bytes)))
+    }
+
+    /// Returns the type of the little-endian bytes of a value of `bytes` bytes, i.e. `[u8; bytes]`.
+    pub fn bytes_le_type(bytes: usize) -> Type {
+        Type::Array(ArrayType::new(Type::Integer(IntegerType::U8), NonNegativeNumber::from(bytes)))
+    }
+
+    /// Returns the number of arguments required by the instruction.
+    pub fn num_args(&self) -> usize {
+        self.arguments().len()
+    }
+
+    /// Returns `true` if the instruction has an effect besides its result, i.e. it writes to a mapping, or it halts
+    /// the program on some inputs, e.g. a missing key, an index out of bounds, a field out of the integer range, or bits
+    /// that are not the x-coordinate of a group element.
+    pub fn has_side_effects(&self) -> bool {
+        matches!(
+            self,
+            CoreFunction::MappingGet
+                | CoreFunction::MappingSet
+                | CoreFunction::MappingRemove
+                | CoreFunction::ArrayLookup
+                | CoreFunction::I8TryFromField
+                | CoreFunction::I16TryFromField
+                | CoreFunction::I32TryFromField
+                | CoreFunction::I64TryFromField
+                | CoreFunction::I128TryFromField
+                | CoreFunction::U8TryFromField
+                | CoreFunction::U16TryFromField
+                | CoreFunction::U32TryFromField
+                | CoreFunction::U64TryFromField
+                | CoreFunction::U128TryFromField
+                | CoreFunction::GroupFromBitsLe
+                | CoreFunction::GroupFromBytesLe
+        )
+    }
+
     /// Returns whether or not this function is finalize command.
     pub fn is_finalize_command(&self) -> bool {
         match self {
@@ -1025,6 +1354,7 @@ impl CoreFunction {
             | CoreFunction::Poseidon2HashToU64
             | CoreFunction::Poseidon2HashToU128
             | CoreFunction::Poseidon2HashToScalar
+            | CoreFunction::Poseidon2HashManyToField
             | CoreFunction::Poseidon4HashToAddress
             | CoreFunction::Poseidon4HashToField
             | CoreFunction::Poseidon4HashToGroup
@@ -1039,6 +1369,7 @@ impl CoreFunction {
             | CoreFunction::Poseidon4HashToU64
             | CoreFunction::Poseidon4HashToU128
             | CoreFunction::Poseidon4HashToScalar
+            | CoreFunction::Poseidon4HashManyToField
             | CoreFunction::Poseidon8HashToAddress
             | CoreFunction::Poseidon8HashToField
             | CoreFunction::Poseidon8HashToGroup
@@ -1053,6 +1384,7 @@ impl CoreFunction {
             | CoreFunction::Poseidon8HashToU64
             | CoreFunction::Poseidon8HashToU128
             | CoreFunction::Poseidon8HashToScalar
+            | CoreFunction::Poseidon8HashManyToField
             | CoreFunction::SHA3_256HashToAddress
             | CoreFunction::SHA3_256HashToField
             | CoreFunction::SHA3_256HashToGroup
@@ -1095,9 +1427,93 @@ impl CoreFunction {
             | CoreFunction::SHA3_512HashToU64
             | CoreFunction::SHA3_512HashToU128
             | CoreFunction::SHA3_512HashToScalar
+            | CoreFunction::ArrayLookup
             | CoreFunction::GroupToXCoordinate
             | CoreFunction::GroupToYCoordinate
-            | CoreFunction::SignatureVerify => false,
+            | CoreFunction::FieldFromI8
+            | CoreFunction::FieldFromI16
+            | CoreFunction::FieldFromI32
+            | CoreFunction::FieldFromI64
+            | CoreFunction::FieldFromI128
+            | CoreFunction::FieldFromU8
+            | CoreFunction::FieldFromU16
+            | CoreFunction::FieldFromU32
+            | CoreFunction::FieldFromU64
+            | CoreFunction::FieldFromU128
+            | CoreFunction::I8TryFromField
+            | CoreFunction::I16TryFromField
+            | CoreFunction::I32TryFromField
+            | CoreFunction::I64TryFromField
+            | CoreFunction::I128TryFromField
+            | CoreFunction::U8TryFromField
+            | CoreFunction::U16TryFromField
+            | CoreFunction::U32TryFromField
+            | CoreFunction::U64TryFromField
+            | CoreFunction::U128TryFromField
+            | CoreFunction::I8FromFieldWrapped
+            | CoreFunction::I16FromFieldWrapped
+            | CoreFunction::I32FromFieldWrapped
+            | CoreFunction::I64FromFieldWrapped
+            | CoreFunction::I128FromFieldWrapped
+            | CoreFunction::U8FromFieldWrapped
+            | CoreFunction::U16FromFieldWrapped
+            | CoreFunction::U32FromFieldWrapped
+            | CoreFunction::U64FromFieldWrapped
+            | CoreFunction::U128FromFieldWrapped
+            | CoreFunction::I8ToBitsLe
+            | CoreFunction::I16ToBitsLe
+            | CoreFunction::I32ToBitsLe
+            | CoreFunction::I64ToBitsLe
+            | CoreFunction::I128ToBitsLe
+            | CoreFunction::U8ToBitsLe
+            | CoreFunction::U16ToBitsLe
+            | CoreFunction::U32ToBitsLe
+            | CoreFunction::U64ToBitsLe
+            | CoreFunction::U128ToBitsLe
+            | CoreFunction::FieldToBitsLe
+            | CoreFunction::GroupToBitsLe
+            | CoreFunction::I8ToBytesLe
+            | CoreFunction::I16ToBytesLe
+            | CoreFunction::I32ToBytesLe
+            | CoreFunction::I64ToBytesLe
+            | CoreFunction::I128ToBytesLe
+            | CoreFunction::U8ToBytesLe
+            | CoreFunction::U16ToBytesLe
+            | CoreFunction::U32ToBytesLe
+            | CoreFunction::U64ToBytesLe
+            | CoreFunction::U128ToBytesLe
+            | CoreFunction::FieldToBytesLe
+            | CoreFunction::GroupToBytesLe
+            | CoreFunction::I8FromBitsLe
+            | CoreFunction::I16FromBitsLe
+            | CoreFunction::I32FromBitsLe
+            | CoreFunction::I64FromBitsLe
+            | CoreFunction::I128FromBitsLe
+            | CoreFunction::U8FromBitsLe
+            | CoreFunction::U16FromBitsLe
+            | CoreFunction::U32FromBitsLe
+            | CoreFunction::U64FromBitsLe
+            | CoreFunction::U128FromBitsLe
+            | CoreFunction::FieldFromBitsLe
+            | CoreFunction::GroupFromBitsLe
+            | CoreFunction::I8FromBytesLe
+            | CoreFunction::I16FromBytesLe
+            | CoreFunction::I32FromBytesLe
+            | CoreFunction::I64FromBytesLe
+            | CoreFunction::I128FromBytesLe
+            | CoreFunction::U8FromBytesLe
+            | CoreFunction::U16FromBytesLe
+            | CoreFunction::U32FromBytesLe
+            | CoreFunction::U64FromBytesLe
+            | CoreFunction::U128FromBytesLe
+            | CoreFunction::FieldFromBytesLe
+            | CoreFunction::GroupFromBytesLe
+            | CoreFunction::SignatureVerify
+            | CoreFunction::AddressFromField
+            | CoreFunction::AddressFromGroup
+            | CoreFunction::AddressToField
+            | CoreFunction::AddressToGroup
+            | CoreFunction::ElGamalEncrypt => false,
         }
     }
 }
@@ -15,18 +15,23 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{Identifier, Node, NodeID, Type};
-use leo_span::Span;
+use leo_span::{sym, Span, Symbol};
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A function output from an external program with type record.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct External {
     /// The name the parameter is accessible as in the function's body.
     pub identifier: Identifier,
     /// The name of the external program.
     pub program_name: Identifier,
+    /// The network of the external program if it is named as a deployed program, e.g. `aleo` in `bar.aleo`.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub network: Option<Identifier>,
     /// The name of the external record type.
     pub record: Identifier,
     /// The parameters span from any annotations to its type.
@@ -39,11 +44,16 @@ impl External {
     pub fn type_(&self) -> Type {
         Type::Identifier(self.record)
     }
+
+    /// Returns the suffix of the name of the external program, i.e. its network if it is deployed, and otherwise `leo`.
+    pub fn program_suffix(&self) -> Symbol {
+        self.network.map_or(sym::leo, |network| network.name)
+    }
 }
 
 impl fmt::Display for External {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}: {}.leo/{}.record", self.identifier, self.program_name, self.record)
+        write!(f, "{}: {}.{}/{}.record", self.identifier, self.program_name, self.program_suffix(), self.record)
     }
 }
 
@@ -19,10 +19,12 @@ use crate::{Block, Identifier, Input, Node, NodeID, Output, TupleType, Type};
 use leo_span::Span;
 
 use core::fmt;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// A finalize block.
-#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Finalize {
     /// The finalize identifier.
     pub identifier: Identifier,
@@ -38,19 +38,27 @@ pub use output::*;
 pub mod mode;
 pub use mode::*;
 
-use crate::{Block, Identifier, Node, NodeID, TupleType, Type};
+use crate::{Block, Identifier, Node, NodeID, TupleType, Type, Visibility};
 use leo_span::{sym, Span, Symbol};
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A function definition.
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Function {
+    /// The doc comment of the function, i.e. the text of the `///` comments before it.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub doc: Option<String>,
     /// Annotations on the function.
     pub annotations: Vec<Annotation>,
     /// Is this function a transition, inlined, or a regular function?.
     pub variant: Variant,
+    /// The visibility of the function to importing programs.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Visibility::is_default"))]
+    pub visibility: Visibility,
     /// The function identifier, e.g., `foo` in `function foo(...) { ... }`.
     pub identifier: Identifier,
     /// The function's input parameters.
@@ -82,6 +90,7 @@ impl Function {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         annotations: Vec<Annotation>,
+        visibility: Visibility,
         variant: Variant,
         identifier: Identifier,
         input: Vec<Input>,
@@ -103,7 +112,20 @@ impl Function {
             _ => Type::Tuple(TupleType::new(output.iter().map(get_output_type).collect())),
         };
 
-        Function { annotations, variant, identifier, input, output, output_type, block, finalize, span, id }
+        Function {
+            doc: None,
+            annotations,
+            variant,
+            visibility,
+            identifier,
+            input,
+            output,
+            output_type,
+            block,
+            finalize,
+            span,
+            id,
+        }
     }
 
     /// Returns function name.
@@ -111,6 +133,22 @@ impl Function {
         self.identifier.name
     }
 
+    /// Returns `true` if the function is visible to importing programs.
+    /// Transitions are public unless declared otherwise.
+    pub fn is_public(&self) -> bool {
+        self.visibility.is_public(self.variant == Variant::Transition)
+    }
+
+    /// Returns `true` if the function is annotated with `@test`.
+    pub fn is_test(&self) -> bool {
+        self.annotations.iter().any(Annotation::is_test)
+    }
+
+    /// Returns the `@deprecated` annotation of the function, if it has one.
+    pub fn deprecation(&self) -> Option<&Annotation> {
+        self.annotations.iter().find(|annotation| annotation.is_deprecated())
+    }
+
     /// Returns `true` if the function name is `main`.
     pub fn is_main(&self) -> bool {
         self.name() == sym::main
@@ -120,6 +158,7 @@ impl Function {
     /// Private formatting method used for optimizing [fmt::Debug] and [fmt::Display] implementations.
     ///
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.visibility)?;
         match self.variant {
             Variant::Inline => write!(f, "inline ")?,
             Variant::Standard => write!(f, "function ")?,
@@ -17,10 +17,12 @@
 use crate::{External, Mode, Node, NodeID, Type};
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Output {
     Internal(FunctionOutput),
     External(External),
@@ -87,7 +89,8 @@ impl Node for Output {
 }
 
 /// A function output.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FunctionOutput {
     /// The mode of the function output.
     pub mode: Mode,
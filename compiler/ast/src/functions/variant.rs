@@ -14,13 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Functions are always one of three variants.
 /// A transition function is permitted the ability to manipulate records.
 /// A regular function is not permitted to manipulate records.
 /// An inline function is directly copied at the call site.
-#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Variant {
     Inline,
     Standard,
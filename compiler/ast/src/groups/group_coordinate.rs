@@ -16,14 +16,16 @@
 
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A coordinate in a affine group literal.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GroupCoordinate {
     /// A number, e.g., `42`.
-    Number(String, #[serde(with = "leo_span::span_json")] Span),
+    Number(String, #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))] Span),
     /// A sign high recovery, i.e. `+`.
     SignHigh,
     /// A sign low recovery, i.e., `-`.
@@ -18,14 +18,16 @@ use crate::{groups::GroupCoordinate, NodeID};
 
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A group literal.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GroupLiteral {
     /// Product group literal, e.g., `42group`.
-    Single(String, #[serde(with = "leo_span::span_json")] Span, NodeID),
+    Single(String, #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))] Span, NodeID),
     /// An affine group literal with (x, y) coordinates.
     Tuple(GroupTuple),
 }
@@ -70,7 +72,8 @@ impl fmt::Display for GroupLiteral {
 }
 
 /// An affine group literal, e.g., `(42, 24)group`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GroupTuple {
     /// The left component of the type, e.g., `42` in the case above.
     pub x: GroupCoordinate,
@@ -19,7 +19,8 @@ use crate::{Expression, Identifier, Mode, Type};
 
 /// A single definition inside a section in a state or an input file.
 /// Definitions should be structured as: `<name>: <type_> = <value>;`
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Definition {
     pub mode: Mode,
     pub type_: Type,
@@ -14,17 +14,22 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{normalize_json_value, remove_key_from_json, Expression, Struct, Type};
+#[cfg(feature = "serde")]
+use crate::{normalize_json_value, remove_key_from_json};
+use crate::{Expression, Struct, Type};
 
 use super::*;
+#[cfg(feature = "serde")]
 use leo_errors::{AstError, Result};
 
 /// Input data which includes [`ProgramInput`].
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InputData {
     pub program_input: ProgramInput,
 }
 
+#[cfg(feature = "serde")]
 impl InputData {
     /// Serializes the ast into a JSON string.
     pub fn to_json_string(&self) -> Result<String> {
@@ -34,7 +39,8 @@ impl InputData {
 
 /// A raw unprocessed input or state file data. Used for future conversion
 /// into [`ProgramInput`].
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InputAst {
     pub sections: Vec<Section>,
 }
@@ -72,11 +78,13 @@ impl InputAst {
     }
 
     /// Serializes the `Input` into a JSON Value.
+    #[cfg(feature = "serde")]
     pub fn to_json_value(&self) -> Result<serde_json::Value> {
         Ok(serde_json::to_value(self).map_err(|e| AstError::failed_to_convert_ast_to_json_value(&e))?)
     }
 
     /// Serializes the input into a JSON file.
+    #[cfg(feature = "serde")]
     pub fn to_json_file(&self, mut path: std::path::PathBuf, file_name: &str) -> Result<()> {
         path.push(file_name);
         let file = std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_ast_json_file(&path, &e))?;
@@ -86,6 +94,7 @@ impl InputAst {
     }
 
     /// Serializes the `Input` into a JSON value and removes keys from object mappings before writing to a file.
+    #[cfg(feature = "serde")]
     pub fn to_json_file_without_keys(
         &self,
         mut path: std::path::PathBuf,
@@ -17,10 +17,12 @@
 use crate::{Expression, GroupLiteral, IntegerType, Literal, Node, Type, UnaryOperation};
 use leo_errors::{InputError, LeoError, Result};
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum InputValue {
     Address(String),
     Boolean(bool),
@@ -46,12 +48,20 @@ impl TryFrom<(Type, Expression)> for InputValue {
                         return Err(InputError::unexpected_type(expected.to_string(), actual, span).into());
                     }
                 }
+                (Type::Integer(expected), Literal::Unsuffixed(value, _, _)) => Self::Integer(expected, value),
                 (x, y) => {
                     return Err(InputError::unexpected_type(x, &y, y.span()).into());
                 }
             },
             (type_, Expression::Unary(unary)) if unary.op == UnaryOperation::Negate => {
-                InputValue::try_from((type_, *unary.receiver))?
+                let negated = match unary.receiver.as_ref() {
+                    Expression::Literal(literal) => literal.negate(unary.span),
+                    _ => None,
+                };
+                match negated {
+                    Some(literal) => InputValue::try_from((type_, Expression::Literal(literal)))?,
+                    None => return Err(InputError::illegal_expression(&unary, unary.span).into()),
+                }
             }
             (_type_, expr) => return Err(InputError::illegal_expression(&expr, expr.span()).into()),
         })
@@ -65,7 +75,7 @@ impl fmt::Display for InputValue {
             InputValue::Boolean(ref boolean) => write!(f, "{boolean}"),
             InputValue::Group(ref group) => write!(f, "{group}"),
             InputValue::Field(ref field) => write!(f, "{field}"),
-            InputValue::Integer(ref type_, ref number) => write!(f, "{number}{type_:?}"),
+            InputValue::Integer(ref type_, ref number) => write!(f, "{}{type_:?}", Literal::integer_to_decimal(number)),
         }
     }
 }
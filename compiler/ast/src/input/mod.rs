@@ -29,9 +29,13 @@ pub use program_input::*;
 pub mod section;
 pub use section::*;
 
+pub mod typed_input_ast;
+pub use typed_input_ast::*;
+
 use indexmap::IndexMap;
 use leo_errors::{InputError, LeoError, Result};
 use leo_span::{sym, Span, Symbol};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 type Definitions = IndexMap<Symbol, InputValue>;
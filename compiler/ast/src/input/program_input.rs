@@ -17,7 +17,8 @@
 use super::*;
 
 /// Processed Program input.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProgramInput {
     pub main: Definitions,
 }
@@ -18,7 +18,8 @@ use super::*;
 
 /// A single section in an input or a state file.
 /// An example of a section would be: `[main]`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Section {
     pub name: Symbol,
     pub definitions: Vec<Definition>,
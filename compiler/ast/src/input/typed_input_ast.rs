@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Expression, Identifier, Mode, Type};
+
+use super::*;
+
+/// An input file whose sections have been checked against the parameters of the functions they name.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TypedInputAst {
+    /// The inputs of each function named by a section, in the order of the function's parameters.
+    pub functions: IndexMap<Symbol, Vec<TypedInput>>,
+}
+
+/// A value of an input file, bound to the parameter of the function it is given for.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TypedInput {
+    /// The name of the parameter.
+    pub name: Identifier,
+    /// The mode of the input, as written in the input file.
+    pub mode: Mode,
+    /// The type of the parameter.
+    pub type_: Type,
+    /// The value given for the parameter.
+    pub value: Expression,
+    /// Whether the value is a record, which is written out with the visibility of its members.
+    pub is_record: bool,
+}
+
+impl TypedInput {
+    /// Returns the value written as an Aleo value.
+    pub fn to_aleo_value(&self) -> String {
+        match &self.value {
+            Expression::Struct(struct_expression) if self.is_record => struct_expression.to_record_string(),
+            value => value.to_string(),
+        }
+    }
+}
+
+impl TypedInputAst {
+    /// Returns the inputs of `function` written as Aleo values, for `leo run` or the interpreter.
+    /// Returns `None` if the input file has no section for `function`.
+    pub fn function_inputs(&self, function: Symbol) -> Option<Vec<String>> {
+        self.functions.get(&function).map(|inputs| inputs.iter().map(TypedInput::to_aleo_value).collect())
+    }
+}
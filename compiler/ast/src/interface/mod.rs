@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, Member, Node, NodeID};
+use leo_span::{Span, Symbol};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An interface definition, e.g., `interface Shape { width: u32, height: u32 }`.
+/// An interface lists the members that a struct or record must provide to conform to it,
+/// e.g. `struct Rectangle: Shape { ... }`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Interface {
+    /// The name of the interface.
+    pub identifier: Identifier,
+    /// The members required by the interface.
+    pub members: Vec<Member>,
+    /// The entire span of the interface definition.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl PartialEq for Interface {
+    fn eq(&self, other: &Self) -> bool {
+        self.identifier == other.identifier
+    }
+}
+
+impl Eq for Interface {}
+
+impl Interface {
+    /// Returns the interface name as a Symbol.
+    pub fn name(&self) -> Symbol {
+        self.identifier.name
+    }
+}
+
+impl fmt::Debug for Interface {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        <Self as fmt::Display>::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Interface {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "interface {} {{ ", self.identifier)?;
+        for member in self.members.iter() {
+            writeln!(f, "    {member}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+crate::simple_node_impl!(Interface);
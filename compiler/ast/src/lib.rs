@@ -19,6 +19,9 @@
 //! This module contains the [`Ast`] type, a wrapper around the [`Program`] type.
 //! The [`Ast`] type is intended to be parsed and modified by different passes
 //! of the Leo compiler. The Leo compiler can generate a set of R1CS constraints from any [`Ast`].
+//!
+//! Serializing the AST, e.g. to JSON, requires the `serde` feature, which is enabled by default.
+//! Embedders that only build and traverse ASTs can disable it to avoid compiling `serde` and `serde_json`.
 
 #![allow(ambiguous_glob_reexports)]
 
@@ -31,6 +34,9 @@ pub use self::r#struct::*;
 pub mod common;
 pub use self::common::*;
 
+pub mod r#enum;
+pub use self::r#enum::*;
+
 pub mod expressions;
 pub use self::expressions::*;
 
@@ -43,6 +49,9 @@ pub use self::groups::*;
 pub mod input;
 pub use self::input::*;
 
+pub mod interface;
+pub use self::interface::*;
+
 pub mod mapping;
 pub use self::mapping::*;
 
@@ -55,6 +64,9 @@ pub use self::program::*;
 pub mod statement;
 pub use self::statement::*;
 
+pub mod typed;
+pub use self::typed::*;
+
 pub mod types;
 pub use self::types::*;
 
@@ -63,6 +75,7 @@ pub use self::value::*;
 
 pub use common::node::*;
 
+#[cfg(feature = "serde")]
 use leo_errors::{AstError, Result};
 
 /// The abstract syntax tree (AST) for a Leo program.
@@ -90,6 +103,7 @@ impl Ast {
     }
 
     /// Serializes the ast into a JSON string.
+    #[cfg(feature = "serde")]
     pub fn to_json_string(&self) -> Result<String> {
         Ok(serde_json::to_string_pretty(&self.ast).map_err(|e| AstError::failed_to_convert_ast_to_json_string(&e))?)
     }
@@ -98,11 +112,13 @@ impl Ast {
     // Note that there is no corresponding `from_json_value` function
     // since we modify JSON values leaving them unable to be converted
     // back into Programs.
+    #[cfg(feature = "serde")]
     pub fn to_json_value(&self) -> Result<serde_json::Value> {
         Ok(serde_json::to_value(&self.ast).map_err(|e| AstError::failed_to_convert_ast_to_json_value(&e))?)
     }
 
     /// Serializes the ast into a JSON file.
+    #[cfg(feature = "serde")]
     pub fn to_json_file(&self, mut path: std::path::PathBuf, file_name: &str) -> Result<()> {
         path.push(file_name);
         let file = std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_ast_json_file(&path, &e))?;
@@ -112,6 +128,7 @@ impl Ast {
     }
 
     /// Serializes the ast into a JSON value and removes keys from object mappings before writing to a file.
+    #[cfg(feature = "serde")]
     pub fn to_json_file_without_keys(
         &self,
         mut path: std::path::PathBuf,
@@ -132,13 +149,40 @@ impl Ast {
             .map_err(|e| AstError::failed_to_write_ast_to_json_file(&path, &e))?)
     }
 
+    /// Serializes the ast into a canonical JSON string, whose object keys are sorted.
+    /// Equivalent programs serialize to the same string, so it may be hashed, e.g. for caching.
+    /// If `strip_spans` is set, the spans are removed, including those of identifiers.
+    #[cfg(feature = "serde")]
+    pub fn to_canonical_json_string(&self, strip_spans: bool) -> Result<String> {
+        let value = canonicalize_json_value(self.to_json_value()?, strip_spans);
+        Ok(serde_json::to_string_pretty(&value).map_err(|e| AstError::failed_to_convert_ast_to_json_string(&e))?)
+    }
+
+    /// Serializes the ast into a canonical JSON file. See `to_canonical_json_string`.
+    #[cfg(feature = "serde")]
+    pub fn to_canonical_json_file(
+        &self,
+        mut path: std::path::PathBuf,
+        file_name: &str,
+        strip_spans: bool,
+    ) -> Result<()> {
+        path.push(file_name);
+        let file = std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_ast_json_file(&path, &e))?;
+        let writer = std::io::BufWriter::new(file);
+        let value = canonicalize_json_value(self.to_json_value()?, strip_spans);
+        Ok(serde_json::to_writer_pretty(writer, &value)
+            .map_err(|e| AstError::failed_to_write_ast_to_json_file(&path, &e))?)
+    }
+
     /// Deserializes the JSON string into a ast.
+    #[cfg(feature = "serde")]
     pub fn from_json_string(json: &str) -> Result<Self> {
         let ast: Program = serde_json::from_str(json).map_err(|e| AstError::failed_to_read_json_string_to_ast(&e))?;
         Ok(Self { ast })
     }
 
     /// Deserializes the JSON string into a ast from a file.
+    #[cfg(feature = "serde")]
     pub fn from_json_file(path: std::path::PathBuf) -> Result<Self> {
         let data = std::fs::read_to_string(&path).map_err(|e| AstError::failed_to_read_json_file(&path, &e))?;
         Self::from_json_string(&data)
@@ -152,6 +196,7 @@ impl AsRef<Program> for Ast {
 }
 
 /// Helper function to recursively filter keys from AST JSON
+#[cfg(feature = "serde")]
 pub fn remove_key_from_json(value: serde_json::Value, key: &str) -> serde_json::Value {
     match value {
         serde_json::Value::Object(map) => serde_json::Value::Object(
@@ -170,6 +215,7 @@ pub fn remove_key_from_json(value: serde_json::Value, key: &str) -> serde_json::
 /// 1. Remove empty object mappings from JSON arrays
 /// 2. If there are two elements in a JSON array and one is an empty object
 ///     mapping and the other is not, then lift up the one that isn't
+#[cfg(feature = "serde")]
 pub fn normalize_json_value(value: serde_json::Value) -> serde_json::Value {
     match value {
         serde_json::Value::Array(vec) => {
@@ -192,3 +238,34 @@ pub fn normalize_json_value(value: serde_json::Value) -> serde_json::Value {
         _ => value,
     }
 }
+
+/// Helper function to canonicalize AST JSON, so that equivalent values serialize identically.
+/// The keys of object mappings are sorted, while arrays keep their order, since it is that of the source.
+/// Identifiers serialize as JSON strings, so strings holding JSON objects or strings are canonicalized as well.
+/// If `strip_spans` is set, the `span` and `_span` keys are removed.
+#[cfg(feature = "serde")]
+pub fn canonicalize_json_value(value: serde_json::Value, strip_spans: bool) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = map
+                .into_iter()
+                .filter(|(k, _)| !strip_spans || (k != "span" && k != "_span"))
+                .map(|(k, v)| (k, canonicalize_json_value(v, strip_spans)))
+                .collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            serde_json::Value::Object(entries.into_iter().collect())
+        }
+        serde_json::Value::Array(values) => {
+            serde_json::Value::Array(values.into_iter().map(|v| canonicalize_json_value(v, strip_spans)).collect())
+        }
+        serde_json::Value::String(string) if string.starts_with('{') || string.starts_with('"') => {
+            match serde_json::from_str::<serde_json::Value>(&string) {
+                Ok(inner @ (serde_json::Value::Object(_) | serde_json::Value::String(_))) => {
+                    serde_json::Value::String(canonicalize_json_value(inner, strip_spans).to_string())
+                }
+                _ => serde_json::Value::String(string),
+            }
+        }
+        _ => value,
+    }
+}
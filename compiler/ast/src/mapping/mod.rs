@@ -18,11 +18,13 @@ use crate::{Identifier, Node, NodeID, Type};
 
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A mapping declaration, e.g `mapping balances: address => u128`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Mapping {
     /// The name of the mapping.
     pub identifier: Identifier,
@@ -28,6 +28,7 @@ pub trait ExpressionConsumer {
             Expression::Access(access) => self.consume_access(access),
             Expression::Array(array) => self.consume_array(array),
             Expression::Binary(binary) => self.consume_binary(binary),
+            Expression::Block(block) => self.consume_block_expression(block),
             Expression::Call(call) => self.consume_call(call),
             Expression::Cast(cast) => self.consume_cast(cast),
             Expression::Struct(struct_) => self.consume_struct_init(struct_),
@@ -47,6 +48,8 @@ pub trait ExpressionConsumer {
 
     fn consume_binary(&mut self, _input: BinaryExpression) -> Self::Output;
 
+    fn consume_block_expression(&mut self, _input: BlockExpression) -> Self::Output;
+
     fn consume_call(&mut self, _input: CallExpression) -> Self::Output;
 
     fn consume_cast(&mut self, _input: CastExpression) -> Self::Output;
@@ -82,9 +85,12 @@ pub trait StatementConsumer {
             Statement::Conditional(stmt) => self.consume_conditional(stmt),
             Statement::Console(stmt) => self.consume_console(stmt),
             Statement::Const(stmt) => self.consume_const(stmt),
+            Statement::Declaration(stmt) => self.consume_declaration(stmt),
             Statement::Definition(stmt) => self.consume_definition(stmt),
+            Statement::Err(stmt) => self.consume_err_statement(stmt),
             Statement::Expression(stmt) => self.consume_expression_statement(stmt),
             Statement::Iteration(stmt) => self.consume_iteration(*stmt),
+            Statement::Match(stmt) => self.consume_match(stmt),
             Statement::Return(stmt) => self.consume_return(stmt),
         }
     }
@@ -101,12 +107,22 @@ pub trait StatementConsumer {
 
     fn consume_const(&mut self, input: ConstDeclaration) -> Self::Output;
 
+    fn consume_declaration(&mut self, input: DeclarationStatement) -> Self::Output;
+
     fn consume_definition(&mut self, input: DefinitionStatement) -> Self::Output;
 
+    fn consume_err_statement(&mut self, _input: ErrStatement) -> Self::Output {
+        unreachable!("`ErrStatement`s should not be in the AST at this phase of compilation.")
+    }
+
     fn consume_expression_statement(&mut self, input: ExpressionStatement) -> Self::Output;
 
     fn consume_iteration(&mut self, input: IterationStatement) -> Self::Output;
 
+    fn consume_match(&mut self, _input: MatchStatement) -> Self::Output {
+        unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.")
+    }
+
     fn consume_return(&mut self, input: ReturnStatement) -> Self::Output;
 }
 
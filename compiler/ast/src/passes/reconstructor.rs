@@ -17,11 +17,16 @@
 //! This module contains a Reconstructor trait for the AST.
 //! It implements default methods for each node to be made
 //! given the information of the old node.
+//!
+//! Passes that rewrite the AST, such as loop unrolling, flattening and desugaring,
+//! implement `ProgramReconstructor` and override only the nodes they transform;
+//! the default methods rebuild every other node from its reconstructed children.
 
 use crate::*;
 
 /// A Reconstructor trait for expressions in the AST.
 pub trait ExpressionReconstructor {
+    /// Information a pass returns alongside each reconstructed node, e.g. the statements an expression was lowered to.
     type AdditionalOutput: Default;
 
     fn reconstruct_expression(&mut self, input: Expression) -> (Expression, Self::AdditionalOutput) {
@@ -29,6 +34,7 @@ pub trait ExpressionReconstructor {
             Expression::Access(access) => self.reconstruct_access(access),
             Expression::Array(array) => self.reconstruct_array(array),
             Expression::Binary(binary) => self.reconstruct_binary(binary),
+            Expression::Block(block) => self.reconstruct_block_expression(block),
             Expression::Call(call) => self.reconstruct_call(call),
             Expression::Cast(cast) => self.reconstruct_cast(cast),
             Expression::Struct(struct_) => self.reconstruct_struct_init(struct_),
@@ -137,12 +143,27 @@ pub trait ExpressionReconstructor {
         )
     }
 
+    /// Note that only the value of the block is reconstructed by default.
+    /// Reconstructors of statements should also reconstruct the statements of the block.
+    fn reconstruct_block_expression(&mut self, input: BlockExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Block(BlockExpression {
+                statements: input.statements,
+                value: Box::new(self.reconstruct_expression(*input.value).0),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_call(&mut self, input: CallExpression) -> (Expression, Self::AdditionalOutput) {
         (
             Expression::Call(CallExpression {
                 function: Box::new(self.reconstruct_expression(*input.function).0),
                 arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
                 external: input.external,
+                network: input.network,
                 span: input.span,
                 id: input.id,
             }),
@@ -155,6 +176,7 @@ pub trait ExpressionReconstructor {
             Expression::Cast(CastExpression {
                 expression: Box::new(self.reconstruct_expression(*input.expression).0),
                 type_: input.type_,
+                wrapped: input.wrapped,
                 span: input.span,
                 id: input.id,
             }),
@@ -166,19 +188,8 @@ pub trait ExpressionReconstructor {
         (
             Expression::Struct(StructExpression {
                 name: input.name,
-                members: input
-                    .members
-                    .into_iter()
-                    .map(|member| StructVariableInitializer {
-                        identifier: member.identifier,
-                        expression: match member.expression {
-                            Some(expression) => Some(self.reconstruct_expression(expression).0),
-                            None => Some(self.reconstruct_expression(Expression::Identifier(member.identifier)).0),
-                        },
-                        span: member.span,
-                        id: member.id,
-                    })
-                    .collect(),
+                const_arguments: input.const_arguments,
+                members: input.members.into_iter().map(|member| self.reconstruct_struct_member(member)).collect(),
                 span: input.span,
                 id: input.id,
             }),
@@ -186,6 +197,19 @@ pub trait ExpressionReconstructor {
         )
     }
 
+    /// Reconstructs a member of a struct expression. A shorthand member, e.g. `x` in `Foo { x }`, stays shorthand
+    /// unless reconstructing its identifier rewrites it, e.g. to a renamed variable or a constant.
+    fn reconstruct_struct_member(&mut self, member: StructVariableInitializer) -> StructVariableInitializer {
+        let expression = match member.expression {
+            Some(expression) => Some(self.reconstruct_expression(expression).0),
+            None => match self.reconstruct_expression(Expression::Identifier(member.identifier)).0 {
+                Expression::Identifier(identifier) if identifier.name == member.identifier.name => None,
+                expression => Some(expression),
+            },
+        };
+        StructVariableInitializer { expression, ..member }
+    }
+
     fn reconstruct_err(&mut self, _input: ErrExpression) -> (Expression, Self::AdditionalOutput) {
         unreachable!("`ErrExpression`s should not be in the AST at this phase of compilation.")
     }
@@ -252,9 +276,12 @@ pub trait StatementReconstructor: ExpressionReconstructor {
             Statement::Conditional(stmt) => self.reconstruct_conditional(stmt),
             Statement::Console(stmt) => self.reconstruct_console(stmt),
             Statement::Const(stmt) => self.reconstruct_const(stmt),
+            Statement::Declaration(stmt) => self.reconstruct_declaration(stmt),
             Statement::Definition(stmt) => self.reconstruct_definition(stmt),
+            Statement::Err(stmt) => self.reconstruct_err_statement(stmt),
             Statement::Expression(stmt) => self.reconstruct_expression_statement(stmt),
             Statement::Iteration(stmt) => self.reconstruct_iteration(*stmt),
+            Statement::Match(stmt) => self.reconstruct_match(stmt),
             Statement::Return(stmt) => self.reconstruct_return(stmt),
         }
     }
@@ -295,6 +322,7 @@ pub trait StatementReconstructor: ExpressionReconstructor {
     fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
         (
             Block {
+                annotations: input.annotations,
                 statements: input.statements.into_iter().map(|s| self.reconstruct_statement(s).0).collect(),
                 span: input.span,
                 id: input.id,
@@ -329,6 +357,15 @@ pub trait StatementReconstructor: ExpressionReconstructor {
                         self.reconstruct_expression(left).0,
                         self.reconstruct_expression(right).0,
                     ),
+                    ConsoleFunction::Log(args) => ConsoleFunction::Log(ConsoleArgs {
+                        string: args.string,
+                        parameters: args
+                            .parameters
+                            .into_iter()
+                            .map(|parameter| self.reconstruct_expression(parameter).0)
+                            .collect(),
+                        span: args.span,
+                    }),
                 },
                 span: input.span,
                 id: input.id,
@@ -340,6 +377,7 @@ pub trait StatementReconstructor: ExpressionReconstructor {
     fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
         (
             Statement::Const(ConstDeclaration {
+                doc: input.doc,
                 place: input.place,
                 type_: input.type_,
                 value: self.reconstruct_expression(input.value).0,
@@ -350,6 +388,10 @@ pub trait StatementReconstructor: ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_declaration(&mut self, input: DeclarationStatement) -> (Statement, Self::AdditionalOutput) {
+        (Statement::Declaration(input), Default::default())
+    }
+
     fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
         (
             Statement::Definition(DefinitionStatement {
@@ -364,6 +406,10 @@ pub trait StatementReconstructor: ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_err_statement(&mut self, _input: ErrStatement) -> (Statement, Self::AdditionalOutput) {
+        unreachable!("`ErrStatement`s should not be in the AST at this phase of compilation.")
+    }
+
     fn reconstruct_expression_statement(&mut self, input: ExpressionStatement) -> (Statement, Self::AdditionalOutput) {
         (
             Statement::Expression(ExpressionStatement {
@@ -393,6 +439,27 @@ pub trait StatementReconstructor: ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_match(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Match(MatchStatement {
+                value: self.reconstruct_expression(input.value).0,
+                arms: input
+                    .arms
+                    .into_iter()
+                    .map(|arm| MatchArm {
+                        pattern: arm.pattern.map(|pattern| self.reconstruct_expression(pattern).0),
+                        block: self.reconstruct_block(arm.block).0,
+                        span: arm.span,
+                        id: arm.id,
+                    })
+                    .collect(),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
         (
             Statement::Return(ReturnStatement {
@@ -417,6 +484,7 @@ pub trait ProgramReconstructor: StatementReconstructor {
                 .into_iter()
                 .map(|(id, import)| (id, (self.reconstruct_import(import.0), import.1)))
                 .collect(),
+            stubs: input.stubs,
             program_scopes: input
                 .program_scopes
                 .into_iter()
@@ -428,7 +496,11 @@ pub trait ProgramReconstructor: StatementReconstructor {
     fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
         ProgramScope {
             program_id: input.program_id,
+            annotations: input.annotations,
+            modules: input.modules,
+            interfaces: input.interfaces.into_iter().map(|(i, c)| (i, self.reconstruct_interface(c))).collect(),
             structs: input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect(),
+            enums: input.enums.into_iter().map(|(i, c)| (i, self.reconstruct_enum(c))).collect(),
             mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
             functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
             consts: input
@@ -445,31 +517,45 @@ pub trait ProgramReconstructor: StatementReconstructor {
 
     fn reconstruct_function(&mut self, input: Function) -> Function {
         Function {
+            doc: input.doc,
             annotations: input.annotations,
             variant: input.variant,
+            visibility: input.visibility,
+            identifier: input.identifier,
+            input: input.input,
+            output: input.output,
+            output_type: input.output_type,
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| self.reconstruct_finalize(finalize)),
+            span: input.span,
+            id: input.id,
+        }
+    }
+
+    fn reconstruct_finalize(&mut self, input: Finalize) -> Finalize {
+        Finalize {
             identifier: input.identifier,
             input: input.input,
             output: input.output,
             output_type: input.output_type,
             block: self.reconstruct_block(input.block).0,
-            finalize: input.finalize.map(|finalize| Finalize {
-                identifier: finalize.identifier,
-                input: finalize.input,
-                output: finalize.output,
-                output_type: finalize.output_type,
-                block: self.reconstruct_block(finalize.block).0,
-                span: finalize.span,
-                id: finalize.id,
-            }),
             span: input.span,
             id: input.id,
         }
     }
 
+    fn reconstruct_interface(&mut self, input: Interface) -> Interface {
+        input
+    }
+
     fn reconstruct_struct(&mut self, input: Struct) -> Struct {
         input
     }
 
+    fn reconstruct_enum(&mut self, input: Enum) -> Enum {
+        input
+    }
+
     fn reconstruct_import(&mut self, input: Program) -> Program {
         self.reconstruct_program(input)
     }
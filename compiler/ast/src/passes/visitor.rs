@@ -30,6 +30,7 @@ pub trait ExpressionVisitor<'a> {
             Expression::Access(access) => self.visit_access(access, additional),
             Expression::Array(array) => self.visit_array(array, additional),
             Expression::Binary(binary) => self.visit_binary(binary, additional),
+            Expression::Block(block) => self.visit_block_expression(block, additional),
             Expression::Call(call) => self.visit_call(call, additional),
             Expression::Cast(cast) => self.visit_cast(cast, additional),
             Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
@@ -79,6 +80,17 @@ pub trait ExpressionVisitor<'a> {
         Default::default()
     }
 
+    /// Note that only the value of the block is visited by default.
+    /// Visitors of statements should also visit the statements of the block.
+    fn visit_block_expression(
+        &mut self,
+        input: &'a BlockExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        self.visit_expression(&input.value, additional);
+        Default::default()
+    }
+
     fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
         input.arguments.iter().for_each(|expr| {
             self.visit_expression(expr, additional);
@@ -141,9 +153,12 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
             Statement::Conditional(stmt) => self.visit_conditional(stmt),
             Statement::Console(stmt) => self.visit_console(stmt),
             Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Declaration(stmt) => self.visit_declaration(stmt),
             Statement::Definition(stmt) => self.visit_definition(stmt),
+            Statement::Err(stmt) => self.visit_err_statement(stmt),
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(stmt) => self.visit_match(stmt),
             Statement::Return(stmt) => self.visit_return(stmt),
         }
     }
@@ -187,6 +202,11 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
                 self.visit_expression(left, &Default::default());
                 self.visit_expression(right, &Default::default());
             }
+            ConsoleFunction::Log(args) => {
+                args.parameters.iter().for_each(|parameter| {
+                    self.visit_expression(parameter, &Default::default());
+                });
+            }
         };
     }
 
@@ -194,10 +214,16 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
         self.visit_expression(&input.value, &Default::default());
     }
 
+    fn visit_declaration(&mut self, _input: &'a DeclarationStatement) {}
+
     fn visit_definition(&mut self, input: &'a DefinitionStatement) {
         self.visit_expression(&input.value, &Default::default());
     }
 
+    fn visit_err_statement(&mut self, _input: &'a ErrStatement) {
+        unreachable!("`ErrStatement`s should not be in the AST at this phase of compilation.")
+    }
+
     fn visit_expression_statement(&mut self, input: &'a ExpressionStatement) {
         self.visit_expression(&input.expression, &Default::default());
     }
@@ -208,6 +234,16 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
         self.visit_block(&input.block);
     }
 
+    fn visit_match(&mut self, input: &'a MatchStatement) {
+        self.visit_expression(&input.value, &Default::default());
+        input.arms.iter().for_each(|arm| {
+            if let Some(pattern) = &arm.pattern {
+                self.visit_expression(pattern, &Default::default());
+            }
+            self.visit_block(&arm.block);
+        });
+    }
+
     fn visit_return(&mut self, input: &'a ReturnStatement) {
         self.visit_expression(&input.expression, &Default::default());
         if let Some(arguments) = &input.finalize_arguments {
@@ -227,8 +263,14 @@ pub trait ProgramVisitor<'a>: StatementVisitor<'a> {
     }
 
     fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        input.modules.iter().for_each(|(_, c)| self.visit_module(c));
+
+        input.interfaces.iter().for_each(|(_, c)| self.visit_interface(c));
+
         input.structs.iter().for_each(|(_, c)| (self.visit_struct(c)));
 
+        input.enums.iter().for_each(|(_, c)| self.visit_enum(c));
+
         input.mappings.iter().for_each(|(_, c)| (self.visit_mapping(c)));
 
         input.functions.iter().for_each(|(_, c)| (self.visit_function(c)));
@@ -240,14 +282,24 @@ pub trait ProgramVisitor<'a>: StatementVisitor<'a> {
         self.visit_program(input)
     }
 
+    fn visit_interface(&mut self, _input: &'a Interface) {}
+
+    fn visit_module(&mut self, _input: &'a Module) {}
+
     fn visit_struct(&mut self, _input: &'a Struct) {}
 
+    fn visit_enum(&mut self, _input: &'a Enum) {}
+
     fn visit_mapping(&mut self, _input: &'a Mapping) {}
 
     fn visit_function(&mut self, input: &'a Function) {
         self.visit_block(&input.block);
         if let Some(finalize) = &input.finalize {
-            self.visit_block(&finalize.block);
+            self.visit_finalize(finalize);
         }
     }
+
+    fn visit_finalize(&mut self, input: &'a Finalize) {
+        self.visit_block(&input.block);
+    }
 }
@@ -16,23 +16,34 @@
 
 //! A Leo program consists of import statements and program scopes.
 
+pub mod module;
+pub use module::*;
+
 pub mod program_id;
 pub use program_id::*;
 
 pub mod program_scope;
 pub use program_scope::*;
 
+pub mod stub;
+pub use stub::*;
+
 use leo_span::{Span, Symbol};
 
 use indexmap::IndexMap;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Stores the Leo program abstract syntax tree.
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Program {
     /// A map from import names to import definitions.
     pub imports: IndexMap<Symbol, (Program, Span)>,
+    /// A map from the names of imported deployed programs to their stubs, e.g. `token` in `import token.aleo;`.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "IndexMap::is_empty"))]
+    pub stubs: IndexMap<Symbol, (Stub, Span)>,
     /// A map from program names to program scopes.
     pub program_scopes: IndexMap<Symbol, ProgramScope>,
 }
@@ -42,6 +53,9 @@ impl fmt::Display for Program {
         for (id, _import) in self.imports.iter() {
             writeln!(f, "import {id}.leo;")?;
         }
+        for (id, _stub) in self.stubs.iter() {
+            writeln!(f, "import {id}.aleo;")?;
+        }
         for (_, program_scope) in self.program_scopes.iter() {
             program_scope.fmt(f)?;
             writeln!(f,)?;
@@ -53,6 +67,6 @@ impl fmt::Display for Program {
 impl Default for Program {
     /// Constructs an empty program node.
     fn default() -> Self {
-        Self { imports: IndexMap::new(), program_scopes: IndexMap::new() }
+        Self { imports: IndexMap::new(), stubs: IndexMap::new(), program_scopes: IndexMap::new() }
     }
 }
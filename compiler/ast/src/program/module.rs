@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, Node, NodeID};
+
+use leo_span::{Span, Symbol};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A module declaration, e.g. `module math;`, which adds the items of `math.leo` to the program scope.
+/// The items of the module are referred to by qualified names, e.g. `math::add`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Module {
+    /// The name of the module, which is also the name of its file.
+    pub name: Identifier,
+    /// The span of the module declaration.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl Module {
+    /// Returns the name that the item `item` of the module `module` is declared under in the program scope,
+    /// e.g. `math__add` for `math::add`.
+    pub fn item_name(module: Symbol, item: Symbol) -> Symbol {
+        Symbol::intern(&format!("{module}__{item}"))
+    }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "module {};", self.name)
+    }
+}
+
+crate::simple_node_impl!(Module);
@@ -17,7 +17,11 @@
 use crate::Identifier;
 
 use core::fmt;
+#[cfg(feature = "serde")]
+use leo_span::Symbol;
+#[cfg(feature = "serde")]
 use serde::{de, de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "serde")]
 use std::collections::BTreeMap;
 
 /// An identifier for a program that is eventually deployed to the network.
@@ -35,6 +39,7 @@ impl fmt::Display for ProgramId {
     }
 }
 
+#[cfg(feature = "serde")]
 impl Serialize for ProgramId {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         // Converts an element that implements Serialize into a string.
@@ -52,6 +57,7 @@ impl Serialize for ProgramId {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for ProgramId {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         struct ProgramIdVisitor;
@@ -75,8 +81,10 @@ impl<'de> Deserialize<'de> for ProgramId {
                 // Convert the serialized string into a BTreeMap to recover ProgramId.
                 let key: BTreeMap<String, String> = to_json_string(value)?;
 
+                // The name is serialized as its symbol alone, so its span is not recovered.
                 let name: Identifier = match key.get("name") {
-                    Some(name) => to_json_string(name)?,
+                    Some(name) => to_json_string(name)
+                        .unwrap_or_else(|_: E| Identifier::new(Symbol::intern(name), Default::default())),
                     None => return Err(E::custom("missing 'name' in serialized ProgramId struct")),
                 };
 
@@ -14,23 +14,37 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-//! A Leo program scope consists of struct, function, and mapping definitions.
+//! A Leo program scope consists of interface, struct, enum, function, and mapping definitions.
 
-use crate::{ConstDeclaration, Function, Mapping, ProgramId, Struct};
+use crate::{Annotation, ConstDeclaration, Enum, Function, Interface, Mapping, Module, ProgramId, Struct};
 
 use leo_span::{Span, Symbol};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Stores the Leo program scope abstract syntax tree.
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProgramScope {
     /// The program id of the program scope.
     pub program_id: ProgramId,
+    /// The annotations of the program scope, e.g. `@deny(mixed_signedness)`.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub annotations: Vec<Annotation>,
+    /// A vector of module declarations.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub modules: Vec<(Symbol, Module)>,
     /// A vector of const definitions
     pub consts: Vec<(Symbol, ConstDeclaration)>,
+    /// A vector of interface definitions.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub interfaces: Vec<(Symbol, Interface)>,
     /// A vector of struct definitions.
     pub structs: Vec<(Symbol, Struct)>,
+    /// A vector of enum definitions.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub enums: Vec<(Symbol, Enum)>,
     /// A vector of mapping definitions.
     pub mappings: Vec<(Symbol, Mapping)>,
     /// A vector of function definitions.
@@ -41,10 +55,22 @@ pub struct ProgramScope {
 
 impl fmt::Display for ProgramScope {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for annotation in self.annotations.iter() {
+            writeln!(f, "{annotation}")?;
+        }
         writeln!(f, "program {} {{", self.program_id)?;
+        for (_, module) in self.modules.iter() {
+            writeln!(f, "    {module}")?;
+        }
+        for (_, interface) in self.interfaces.iter() {
+            writeln!(f, "    {interface}")?;
+        }
         for (_, struct_) in self.structs.iter() {
             writeln!(f, "    {struct_}")?;
         }
+        for (_, enum_) in self.enums.iter() {
+            writeln!(f, "    {enum_}")?;
+        }
         for (_, mapping) in self.mappings.iter() {
             writeln!(f, "    {mapping}")?;
         }
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Function, ProgramId, Struct};
+
+use leo_span::{Span, Symbol};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The signatures of a deployed program, e.g. `token.aleo` in `import token.aleo;`.
+/// A stub is read from the Aleo instructions of the program, rather than compiled from Leo source, so its functions
+/// are transitions with empty bodies, and those with a finalize block have an empty one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Stub {
+    /// The program ID of the deployed program.
+    pub program_id: ProgramId,
+    /// The structs and records of the program.
+    pub structs: Vec<(Symbol, Struct)>,
+    /// The functions of the program, which may be called by importing programs.
+    pub functions: Vec<(Symbol, Function)>,
+    /// The span of the program declaration in the Aleo instructions.
+    pub span: Span,
+}
+
+impl fmt::Display for Stub {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "stub {} {{", self.program_id)?;
+        for (_, struct_) in self.structs.iter() {
+            writeln!(f, "    {struct_}")?;
+        }
+        for (_, function) in self.functions.iter() {
+            let inputs = function.input.iter().map(|input| input.to_string()).collect::<Vec<_>>().join(", ");
+            writeln!(f, "    transition {}({inputs}) -> {};", function.identifier, function.output_type)?;
+        }
+        write!(f, "}}")
+    }
+}
@@ -18,11 +18,13 @@ use crate::{Expression, Node, NodeID};
 
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A variant of an assert statement.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AssertVariant {
     /// A `assert(expr)` variant, asserting that the expression evaluates to true.
     Assert(Expression),
@@ -33,7 +35,8 @@ pub enum AssertVariant {
 }
 
 /// An assert statement, `assert(<expr>)`, `assert_eq(<expr>)` or `assert_neq(<expr>)`.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssertStatement {
     /// The variant of the assert statement.
     pub variant: AssertVariant,
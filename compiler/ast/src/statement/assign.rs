@@ -17,12 +17,14 @@
 use crate::{Expression, Node, NodeID};
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// An assignment statement, `assignee = value`.
 /// Note that there is no operation associated with the assignment.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AssignStatement {
     /// The place to assign to.
     /// Note that `place` can either be an identifier or tuple.
@@ -14,15 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Node, NodeID, Statement};
+use crate::{Annotation, Node, NodeID, Statement};
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A block `{ [stmt]* }` consisting of a list of statements to execute in order.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Block {
+    /// The annotations on the block, e.g. `@wrapping`.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub annotations: Vec<Annotation>,
     /// The list of statements to execute.
     pub statements: Vec<Statement>,
     /// The span from `{` to `}`.
@@ -33,6 +38,7 @@ pub struct Block {
 
 impl fmt::Display for Block {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.annotations.iter().try_for_each(|annotation| write!(f, "{annotation} "))?;
         writeln!(f, "{{")?;
         if self.statements.is_empty() {
             writeln!(f, "\t")?;
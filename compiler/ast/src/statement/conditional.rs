@@ -17,11 +17,13 @@
 use crate::{Block, Expression, Node, NodeID, Statement};
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// An `if condition block (else next)?` statement.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConditionalStatement {
     /// The `bool`-typed condition deciding what to evaluate.
     pub condition: Expression,
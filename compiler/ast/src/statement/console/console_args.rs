@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Expression;
+use leo_span::Span;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A part of the format string of a `console.log` statement.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FormatStringPart {
+    /// Text that is printed as is.
+    Const(String),
+    /// A `{}` placeholder, which is replaced by the next argument.
+    Container,
+}
+
+impl FormatStringPart {
+    /// Splits `string` into its text and placeholders, where `{{` and `}}` are escaped braces.
+    /// Returns `None` if `string` contains a brace that is neither escaped nor part of a placeholder.
+    pub fn parse(string: &str) -> Option<Vec<Self>> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        let mut chars = string.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('{', Some('{')) | ('}', Some('}')) => {
+                    chars.next();
+                    text.push(c);
+                }
+                ('{', Some('}')) => {
+                    chars.next();
+                    if !text.is_empty() {
+                        parts.push(Self::Const(std::mem::take(&mut text)));
+                    }
+                    parts.push(Self::Container);
+                }
+                ('{', _) | ('}', _) => return None,
+                _ => text.push(c),
+            }
+        }
+        if !text.is_empty() {
+            parts.push(Self::Const(text));
+        }
+        Some(parts)
+    }
+}
+
+impl fmt::Display for FormatStringPart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Const(text) => write!(f, "{}", text.replace('{', "{{").replace('}', "}}")),
+            Self::Container => write!(f, "{{}}"),
+        }
+    }
+}
+
+/// The arguments of a `console.log` statement, e.g. `"x is {}", x`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConsoleArgs {
+    /// The parts of the format string.
+    pub string: Vec<FormatStringPart>,
+    /// The arguments that replace the placeholders of the format string, in order.
+    pub parameters: Vec<Expression>,
+    /// The span of the format string.
+    pub span: Span,
+}
+
+impl ConsoleArgs {
+    /// Returns the number of placeholders in the format string.
+    pub fn containers(&self) -> usize {
+        self.string.iter().filter(|part| matches!(part, FormatStringPart::Container)).count()
+    }
+}
+
+impl fmt::Display for ConsoleArgs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\"")?;
+        self.string.iter().try_for_each(|part| write!(f, "{part}"))?;
+        write!(f, "\"")?;
+        self.parameters.iter().try_for_each(|parameter| write!(f, ", {parameter}"))
+    }
+}
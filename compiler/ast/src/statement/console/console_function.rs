@@ -14,13 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Expression;
+use crate::{ConsoleArgs, Expression};
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A console logging function to invoke.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ConsoleFunction {
     /// A `console.assert(expr)` call to invoke, asserting that the expression evaluates to true.
     Assert(Expression),
@@ -28,6 +30,8 @@ pub enum ConsoleFunction {
     AssertEq(Expression, Expression),
     /// A `console.assert_neq(expr1, expr2)` call to invoke, asserting that the operands are not equal.
     AssertNeq(Expression, Expression),
+    /// A `console.log(format, args...)` call to invoke, printing the format string with its placeholders replaced.
+    Log(ConsoleArgs),
 }
 
 impl fmt::Display for ConsoleFunction {
@@ -36,6 +40,7 @@ impl fmt::Display for ConsoleFunction {
             ConsoleFunction::Assert(expr) => write!(f, "assert({expr})"),
             ConsoleFunction::AssertEq(expr1, expr2) => write!(f, "assert_eq({expr1}, {expr2})"),
             ConsoleFunction::AssertNeq(expr1, expr2) => write!(f, "assert_neq({expr1}, {expr2})"),
+            ConsoleFunction::Log(args) => write!(f, "log({args})"),
         }
     }
 }
@@ -17,11 +17,13 @@
 use crate::{ConsoleFunction, Node, NodeID};
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A console logging statement like `console.log(...);`.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ConsoleStatement {
     /// The logging function to run.
     pub function: ConsoleFunction,
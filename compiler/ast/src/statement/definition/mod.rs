@@ -17,6 +17,7 @@
 use crate::{Expression, Node, NodeID, Type};
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -24,14 +25,16 @@ mod declaration_type;
 pub use declaration_type::*;
 
 /// A `let` or `const` declaration statement.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DefinitionStatement {
     /// What sort of declaration is this? `let` or `const`?.
     pub declaration_type: DeclarationType,
     /// The bindings / variable names to declare.
     pub place: Expression,
-    /// The types of the bindings, if specified, or inferred otherwise.
-    pub type_: Type,
+    /// The types of the bindings, if specified.
+    /// If omitted, the types are inferred from the initializer by the type checker.
+    pub type_: Option<Type>,
     /// An initializer value for the bindings.
     pub value: Expression,
     /// The span excluding the semicolon.
@@ -44,7 +47,9 @@ impl fmt::Display for DefinitionStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{} ", self.declaration_type)?;
         write!(f, "{}", self.place)?;
-        write!(f, ": {}", self.type_)?;
+        if let Some(type_) = &self.type_ {
+            write!(f, ": {type_}")?;
+        }
         write!(f, " = {};", self.value)
     }
 }
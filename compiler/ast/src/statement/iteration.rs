@@ -18,11 +18,13 @@ use crate::{Block, Expression, Identifier, Node, NodeID, Type, Value};
 
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::{cell::RefCell, fmt};
 
 /// A bounded `for` loop statement `for variable in start .. =? stop block`.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IterationStatement {
     /// The binding / variable to introduce in the body `block`.
     pub variable: Identifier,
@@ -31,12 +33,12 @@ pub struct IterationStatement {
     /// The start of the iteration.
     pub start: Expression,
     /// The concrete value of `start`.
-    #[serde(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub start_value: RefCell<Option<Value>>,
     /// The end of the iteration, possibly `inclusive`.
     pub stop: Expression,
     /// The concrete value of `stop`.
-    #[serde(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub stop_value: RefCell<Option<Value>>,
     /// Whether `stop` is inclusive or not.
     /// Signified with `=` when parsing.
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Block, Expression, Node, NodeID};
+use leo_span::Span;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A `match value { pattern => block, ... }` statement.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MatchStatement {
+    /// The value to match the patterns of the arms against.
+    pub value: Expression,
+    /// The arms of the match, in the order that they are tried.
+    pub arms: Vec<MatchArm>,
+    /// The span from `match` to the closing `}`.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for MatchStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "match {} {{", self.value)?;
+        self.arms.iter().try_for_each(|arm| writeln!(f, "\t{arm}"))?;
+        write!(f, "}}")
+    }
+}
+
+crate::simple_node_impl!(MatchStatement);
+
+/// An arm `pattern => block` of a match statement.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MatchArm {
+    /// The pattern of the arm, or `None` for the wildcard pattern `_`, which matches every value.
+    pub pattern: Option<Expression>,
+    /// The block to run if the pattern matches.
+    pub block: Block,
+    /// The span from the pattern to the end of `block`.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for MatchArm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.pattern {
+            Some(pattern) => write!(f, "{pattern} => {}", self.block),
+            None => write!(f, "_ => {}", self.block),
+        }
+    }
+}
+
+crate::simple_node_impl!(MatchArm);
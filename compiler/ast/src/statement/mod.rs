@@ -32,15 +32,24 @@ pub use console::*;
 pub mod const_;
 pub use const_::*;
 
+pub mod declaration;
+pub use declaration::*;
+
 pub mod definition;
 pub use definition::*;
 
+pub mod err;
+pub use err::*;
+
 pub mod expression;
 pub use expression::*;
 
 pub mod iteration;
 pub use iteration::*;
 
+pub mod match_;
+pub use match_::*;
+
 pub mod return_;
 pub use return_::*;
 
@@ -48,11 +57,13 @@ use crate::{Node, NodeID};
 
 use leo_span::Span;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Program statement that defines some action (or expression) to be carried out.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Statement {
     /// An assert statement.
     Assert(AssertStatement),
@@ -66,12 +77,18 @@ pub enum Statement {
     Console(ConsoleStatement),
     /// A binding from identifier to constant value.
     Const(ConstDeclaration),
+    /// A declaration of a variable without an initializer.
+    Declaration(DeclarationStatement),
     /// A binding or set of bindings / variables to declare.
     Definition(DefinitionStatement),
+    /// A syntactically invalid statement.
+    Err(ErrStatement),
     /// An expression statement
     Expression(ExpressionStatement),
     /// A `for` statement.
     Iteration(Box<IterationStatement>),
+    /// A `match` statement.
+    Match(MatchStatement),
     /// A return statement `return expr;`.
     Return(ReturnStatement),
 }
@@ -79,7 +96,7 @@ pub enum Statement {
 impl Statement {
     /// Returns a dummy statement made from an empty block `{}`.
     pub fn dummy(span: Span, id: NodeID) -> Self {
-        Self::Block(Block { statements: Vec::new(), span, id })
+        Self::Block(Block { annotations: Vec::new(), statements: Vec::new(), span, id })
     }
 }
 
@@ -92,9 +109,12 @@ impl fmt::Display for Statement {
             Statement::Conditional(x) => x.fmt(f),
             Statement::Console(x) => x.fmt(f),
             Statement::Const(x) => x.fmt(f),
+            Statement::Declaration(x) => x.fmt(f),
             Statement::Definition(x) => x.fmt(f),
+            Statement::Err(x) => x.fmt(f),
             Statement::Expression(x) => x.fmt(f),
             Statement::Iteration(x) => x.fmt(f),
+            Statement::Match(x) => x.fmt(f),
             Statement::Return(x) => x.fmt(f),
         }
     }
@@ -110,9 +130,12 @@ impl Node for Statement {
             Conditional(n) => n.span(),
             Console(n) => n.span(),
             Const(n) => n.span(),
+            Declaration(n) => n.span(),
             Definition(n) => n.span(),
+            Err(n) => n.span(),
             Expression(n) => n.span(),
             Iteration(n) => n.span(),
+            Match(n) => n.span(),
             Return(n) => n.span(),
         }
     }
@@ -126,9 +149,12 @@ impl Node for Statement {
             Conditional(n) => n.set_span(span),
             Console(n) => n.set_span(span),
             Const(n) => n.set_span(span),
+            Declaration(n) => n.set_span(span),
             Definition(n) => n.set_span(span),
+            Err(n) => n.set_span(span),
             Expression(n) => n.set_span(span),
             Iteration(n) => n.set_span(span),
+            Match(n) => n.set_span(span),
             Return(n) => n.set_span(span),
         }
     }
@@ -142,9 +168,12 @@ impl Node for Statement {
             Conditional(n) => n.id(),
             Console(n) => n.id(),
             Const(n) => n.id(),
+            Declaration(n) => n.id(),
             Definition(n) => n.id(),
+            Err(n) => n.id(),
             Expression(n) => n.id(),
             Iteration(n) => n.id(),
+            Match(n) => n.id(),
             Return(n) => n.id(),
         }
     }
@@ -158,9 +187,12 @@ impl Node for Statement {
             Conditional(n) => n.set_id(id),
             Console(n) => n.set_id(id),
             Const(n) => n.set_id(id),
+            Declaration(n) => n.set_id(id),
             Definition(n) => n.set_id(id),
+            Err(n) => n.set_id(id),
             Expression(n) => n.set_id(id),
             Iteration(n) => n.set_id(id),
+            Match(n) => n.set_id(id),
             Return(n) => n.set_id(id),
         }
     }
@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, Node, NodeID, Type};
+
+use leo_span::{Span, Symbol};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A constant parameter of a generic struct, e.g. `const N: u32` in `struct Matrix<const N: u32> { ... }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConstParameter {
+    /// The identifier of the parameter.
+    pub identifier: Identifier,
+    /// The type of the parameter.
+    pub type_: Type,
+    /// The span of the parameter.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl ConstParameter {
+    /// Returns the name of the parameter without span.
+    pub fn name(&self) -> Symbol {
+        self.identifier.name
+    }
+}
+
+impl fmt::Display for ConstParameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "const {}: {}", self.identifier, self.type_)
+    }
+}
+
+crate::simple_node_impl!(ConstParameter);
@@ -14,15 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Identifier, Mode, Node, NodeID, Type};
+use crate::{Expression, Identifier, Mode, Node, NodeID, Type};
 
 use leo_span::{Span, Symbol};
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// A member of a structured data type, e.g `foobar: u8` or `private baz: bool` .
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Member {
     /// The mode of the member.
     pub mode: Mode,
@@ -30,6 +32,9 @@ pub struct Member {
     pub identifier: Identifier,
     /// The type of the member.
     pub type_: Type,
+    /// The default value of the member, e.g. `6u8` in `decimals: u8 = 6u8`, which lets initializers omit it.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub default: Option<Expression>,
     /// The span of the member.
     pub span: Span,
     /// The ID of the node.
@@ -46,8 +51,12 @@ impl Member {
 impl fmt::Display for Member {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.mode {
-            Mode::None => write!(f, "{}: {}", self.identifier, self.type_),
-            _ => write!(f, "{} {} {}", self.mode, self.identifier, self.type_),
+            Mode::None => write!(f, "{}: {}", self.identifier, self.type_)?,
+            _ => write!(f, "{} {} {}", self.mode, self.identifier, self.type_)?,
+        }
+        match &self.default {
+            Some(default) => write!(f, " = {default}"),
+            None => Ok(()),
         }
     }
 }
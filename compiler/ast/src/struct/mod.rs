@@ -14,12 +14,28 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod const_parameter;
+pub use const_parameter::*;
+
 pub mod member;
 pub use member::*;
 
-use crate::{Identifier, Node, NodeID};
-use leo_span::{Span, Symbol};
+use crate::{
+    Annotation,
+    BinaryOperation,
+    CoreFunction,
+    DefaultMangler,
+    Function,
+    Identifier,
+    Mangler,
+    Node,
+    NodeID,
+    UnaryOperation,
+    Visibility,
+};
+use leo_span::{sym, Span, Symbol};
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -29,12 +45,32 @@ use std::fmt;
 /// Type identity is decided by the full path including `struct_name`,
 /// as the record is nominal, not structural.
 /// The fields are named so `struct Foo(u8, u16)` is not allowed.
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Struct {
+    /// The doc comment of the struct, i.e. the text of the `///` comments before it.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub doc: Option<String>,
+    /// The annotations of the struct, e.g. `@deprecated`.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub annotations: Vec<Annotation>,
     /// The name of the type in the type system in this module.
     pub identifier: Identifier,
+    /// The visibility of the struct to importing programs.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Visibility::is_default"))]
+    pub visibility: Visibility,
+    /// The constant parameters of a generic struct, e.g. `const N: u32` in `struct Matrix<const N: u32> { ... }`.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub const_parameters: Vec<ConstParameter>,
     /// The fields, constant variables, and functions of this structure.
     pub members: Vec<Member>,
+    /// The interfaces that the struct conforms to, e.g. `struct Foo: Bar + Baz { ... }`.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub interfaces: Vec<Identifier>,
+    /// The methods of the struct, e.g. `function norm(self) -> u32 { ... }`.
+    /// The first input of each method is the `self` receiver.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub methods: Vec<(Symbol, Function)>,
     /// Was this a `record Foo { ... }`?
     /// If so, it wasn't a struct.
     pub is_record: bool,
@@ -57,6 +93,44 @@ impl Struct {
     pub fn name(&self) -> Symbol {
         self.identifier.name
     }
+
+    /// Returns `true` if the struct has constant parameters, and is only used through its instances.
+    pub fn is_generic(&self) -> bool {
+        !self.const_parameters.is_empty()
+    }
+
+    /// Returns the name of the struct that the generic struct `struct_name` is specialized to for `arguments`,
+    /// e.g. `Matrix__2_3` for `Matrix<2, 3>`. The name must be a valid identifier in the generated bytecode.
+    pub fn instance_name(struct_name: Symbol, arguments: &[usize]) -> Symbol {
+        let arguments = arguments.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+        Symbol::intern(&format!("{struct_name}__{}", arguments.join("_")))
+    }
+
+    /// Returns the `@deprecated` annotation of the struct, if it has one.
+    pub fn deprecation(&self) -> Option<&Annotation> {
+        self.annotations.iter().find(|annotation| annotation.is_deprecated())
+    }
+
+    /// Returns `true` if the struct is visible to importing programs.
+    /// Records are public unless declared otherwise.
+    pub fn is_public(&self) -> bool {
+        self.visibility.is_public(self.is_record)
+    }
+
+    /// Returns the name of the function that the method `method` of `struct_name` is lowered to, e.g. `Foo::bar`.
+    /// Method names are looked up by several passes, so they always follow the default naming scheme.
+    pub fn method_name(struct_name: Symbol, method: Symbol) -> Symbol {
+        DefaultMangler.method_name(struct_name, method)
+    }
+
+    /// Returns `true` if `receiver.name(..)` is reserved for a built-in operation, e.g. `a.add(b)` or `m.get(k)`.
+    /// A method with such a name could not be called.
+    pub fn is_reserved_method_name(name: Symbol) -> bool {
+        UnaryOperation::from_symbol(name).is_some()
+            || BinaryOperation::from_symbol(name).is_some()
+            || CoreFunction::from_symbols(sym::Mapping, name).is_some()
+            || CoreFunction::from_symbols(sym::signature, name).is_some()
+    }
 }
 
 impl fmt::Debug for Struct {
@@ -67,11 +141,22 @@ impl fmt::Debug for Struct {
 
 impl fmt::Display for Struct {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.visibility)?;
         f.write_str(if self.is_record { "record" } else { "struct" })?;
-        writeln!(f, " {} {{ ", self.identifier)?;
+        write!(f, " {}", self.identifier)?;
+        if self.is_generic() {
+            write!(f, "<{}>", self.const_parameters.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", "))?;
+        }
+        if !self.interfaces.is_empty() {
+            write!(f, ": {}", self.interfaces.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(" + "))?;
+        }
+        writeln!(f, " {{ ")?;
         for field in self.members.iter() {
             writeln!(f, "    {field}")?;
         }
+        for (_, method) in self.methods.iter() {
+            writeln!(f, "    {method}")?;
+        }
         write!(f, "}}")
     }
 }
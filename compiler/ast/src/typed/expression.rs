@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{BinaryOperation, Literal, NodeID, Type, UnaryOperation};
+
+use leo_span::{Span, Symbol};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::TypedStatement;
+
+/// An expression of the typed IR, together with its resolved type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TypedExpression {
+    /// The kind of the expression.
+    pub kind: TypedExpressionKind,
+    /// The type of the expression, as resolved by the type checker.
+    pub type_: Type,
+    /// The span of the expression in the source.
+    #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))]
+    pub span: Span,
+    /// The ID of the expression, which is the ID of the AST node it was lowered from.
+    pub id: NodeID,
+}
+
+/// The kinds of expressions of the typed IR.
+/// The subexpressions of an expression are themselves typed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TypedExpressionKind {
+    /// An array access, e.g. `a[i]`.
+    ArrayAccess { array: Box<TypedExpression>, index: Box<TypedExpression> },
+    /// An array, e.g. `[1u8, 2u8]`.
+    Array(Vec<TypedExpression>),
+    /// An associated constant of a type, e.g. `group::GEN`.
+    AssociatedConstant { ty: Type, name: Symbol },
+    /// A call to an associated function of a type, e.g. `BHP256::hash_to_field(a)`.
+    AssociatedFunction { ty: Type, name: Symbol, arguments: Vec<TypedExpression> },
+    /// A binary expression, e.g. `a + b`.
+    Binary { op: BinaryOperation, left: Box<TypedExpression>, right: Box<TypedExpression> },
+    /// A block expression, e.g. `{ let b: u8 = a + a; b * b }`.
+    Block { statements: Vec<TypedStatement>, value: Box<TypedExpression> },
+    /// A call to a function, e.g. `foo(a)`, `bar.leo/foo(a)`, or the method call `p.foo(a)`.
+    /// A method call has the receiver `p`, and an external call names the program it calls.
+    Call {
+        function: Symbol,
+        receiver: Option<Box<TypedExpression>>,
+        program: Option<Symbol>,
+        arguments: Vec<TypedExpression>,
+    },
+    /// A cast, e.g. `a as u8`, whose target type is the type of the expression.
+    Cast { expression: Box<TypedExpression>, wrapped: bool },
+    /// A variable or constant, e.g. `a`.
+    Identifier(Symbol),
+    /// A property of the environment that is not a value of the program, e.g. `self.caller` or `block.height`.
+    Intrinsic { scope: Symbol, name: Symbol },
+    /// A literal, whose integer type is resolved if it was unsuffixed, e.g. `1` in `a + 1` for `a: u8` is `1u8`.
+    Literal(Literal),
+    /// A member access, e.g. `p.x`.
+    MemberAccess { inner: Box<TypedExpression>, name: Symbol },
+    /// A struct initializer, e.g. `Point { x: 1u8, y }`, whose shorthand members are made explicit.
+    Struct { name: Symbol, members: Vec<(Symbol, TypedExpression)> },
+    /// A ternary conditional, e.g. `c ? a : b`.
+    Ternary { condition: Box<TypedExpression>, if_true: Box<TypedExpression>, if_false: Box<TypedExpression> },
+    /// A tuple, e.g. `(a, b)`.
+    Tuple(Vec<TypedExpression>),
+    /// A tuple access, e.g. `t.0`.
+    TupleAccess { tuple: Box<TypedExpression>, index: usize },
+    /// A unary expression, e.g. `!a` or `a.abs()`.
+    Unary { op: UnaryOperation, receiver: Box<TypedExpression> },
+    /// The unit expression `()`.
+    Unit,
+}
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The typed IR, a representation of a type-checked program in which every expression carries its resolved type.
+//! It is produced from the AST and the types inferred by the type checker, so that the passes which consume it
+//! need not infer the types of expressions again, nor handle the expressions whose types are unknown.
+//! Unsuffixed literals are given their inferred integer types, and shorthand struct initializers are made explicit.
+
+pub mod expression;
+pub use expression::*;
+
+pub mod program;
+pub use program::*;
+
+pub mod statement;
+pub use statement::*;
+
+#[cfg(feature = "serde")]
+use leo_errors::{AstError, Result};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A type-checked program, lowered to the typed IR.
+/// Imported programs are not lowered, since they are compiled separately.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TypedProgram {
+    /// The program scopes of the program.
+    pub program_scopes: IndexMap<Symbol, TypedProgramScope>,
+}
+
+impl TypedProgram {
+    /// Serializes the typed IR into a JSON string.
+    #[cfg(feature = "serde")]
+    pub fn to_json_string(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&self).map_err(|e| AstError::failed_to_convert_ast_to_json_string(&e))?)
+    }
+
+    /// Serializes the typed IR into a JSON file.
+    #[cfg(feature = "serde")]
+    pub fn to_json_file(&self, mut path: std::path::PathBuf, file_name: &str) -> Result<()> {
+        path.push(file_name);
+        let file =
+            std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_typed_ir_json_file(&path, &e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(serde_json::to_writer_pretty(writer, &self)
+            .map_err(|e| AstError::failed_to_write_typed_ir_to_json_file(&path, &e))?)
+    }
+
+    /// Deserializes the JSON string into a typed IR.
+    #[cfg(feature = "serde")]
+    pub fn from_json_string(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json).map_err(|e| AstError::failed_to_read_json_string_to_typed_ir(&e))?)
+    }
+}
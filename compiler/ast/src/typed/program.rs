@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, Mapping, Mode, NodeID, ProgramId, Struct, Type, Variant};
+
+use leo_span::{Span, Symbol};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{TypedBlock, TypedExpression};
+
+/// A program scope of the typed IR.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TypedProgramScope {
+    /// The program ID of the scope.
+    pub program_id: ProgramId,
+    /// The global constants of the scope, with their values.
+    pub consts: Vec<(Symbol, TypedExpression)>,
+    /// The structs and records of the scope, whose methods are lowered to functions of the scope.
+    pub structs: Vec<(Symbol, Struct)>,
+    /// The mappings of the scope.
+    pub mappings: Vec<(Symbol, Mapping)>,
+    /// The functions of the scope, followed by the methods of its structs under their mangled names.
+    pub functions: Vec<(Symbol, TypedFunction)>,
+    /// The span of the scope in the source.
+    #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))]
+    pub span: Span,
+}
+
+/// A function of the typed IR.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TypedFunction {
+    /// The variant of the function, e.g. `transition`.
+    pub variant: Variant,
+    /// The name of the function.
+    pub identifier: Identifier,
+    /// The inputs of the function.
+    pub input: Vec<TypedFunctionInput>,
+    /// The output type of the function.
+    pub output_type: Type,
+    /// The body of the function.
+    pub block: TypedBlock,
+    /// The finalize block of the function, if it has one.
+    pub finalize: Option<TypedFinalize>,
+    /// The span of the function in the source.
+    #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))]
+    pub span: Span,
+    /// The ID of the function, which is the ID of the AST node it was lowered from.
+    pub id: NodeID,
+}
+
+/// A finalize block of the typed IR.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TypedFinalize {
+    /// The name of the finalize block.
+    pub identifier: Identifier,
+    /// The inputs of the finalize block.
+    pub input: Vec<TypedFunctionInput>,
+    /// The output type of the finalize block.
+    pub output_type: Type,
+    /// The body of the finalize block.
+    pub block: TypedBlock,
+    /// The span of the finalize block in the source.
+    #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))]
+    pub span: Span,
+    /// The ID of the finalize block, which is the ID of the AST node it was lowered from.
+    pub id: NodeID,
+}
+
+/// An input of a function or finalize block of the typed IR, e.g. `public a: u8`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TypedFunctionInput {
+    /// The name of the input.
+    pub identifier: Identifier,
+    /// The mode of the input.
+    pub mode: Mode,
+    /// The type of the input.
+    pub type_: Type,
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{DeclarationType, FormatStringPart, NodeID, Type};
+
+use leo_span::{Span, Symbol};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::TypedExpression;
+
+/// A statement of the typed IR.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TypedStatement {
+    /// The kind of the statement.
+    pub kind: TypedStatementKind,
+    /// The span of the statement in the source.
+    #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))]
+    pub span: Span,
+    /// The ID of the statement, which is the ID of the AST node it was lowered from.
+    pub id: NodeID,
+}
+
+/// The kinds of statements of the typed IR.
+/// The console assertions of the AST are lowered to assertions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TypedStatementKind {
+    /// An assertion, e.g. `assert(a);`.
+    Assert(TypedExpression),
+    /// An equality assertion, e.g. `assert_eq(a, b);`.
+    AssertEq(TypedExpression, TypedExpression),
+    /// An inequality assertion, e.g. `assert_neq(a, b);`.
+    AssertNeq(TypedExpression, TypedExpression),
+    /// An assignment, e.g. `a = b;`, whose place is a variable or a tuple of variables.
+    Assign { place: TypedExpression, value: TypedExpression },
+    /// A block, e.g. `{ a = b; }`.
+    Block(TypedBlock),
+    /// A conditional, e.g. `if c { a = b; } else { a = d; }`.
+    Conditional { condition: TypedExpression, then: TypedBlock, otherwise: Option<Box<TypedStatement>> },
+    /// A declaration of a variable without an initializer, e.g. `let a: u8;`.
+    Declaration { variable: Symbol, type_: Type },
+    /// A definition, e.g. `let a: u8 = b;`, `const C: u8 = 1u8;`, or `let (a, b): (u8, u8) = f();`.
+    /// The place is a variable or a tuple of variables.
+    Definition { declaration_type: DeclarationType, place: TypedExpression, value: TypedExpression },
+    /// An expression evaluated for its effects, e.g. `foo(a);`.
+    Expression(TypedExpression),
+    /// A loop, e.g. `for i: u8 in 0u8..4u8 { a = a + i; }`.
+    Iteration {
+        variable: Symbol,
+        type_: Type,
+        start: TypedExpression,
+        stop: TypedExpression,
+        inclusive: bool,
+        block: TypedBlock,
+    },
+    /// A log, e.g. `console.log("a is {}", a);`, which is not part of the circuit.
+    Log { string: Vec<FormatStringPart>, parameters: Vec<TypedExpression> },
+    /// A return, e.g. `return a then finalize(b);`.
+    Return { expression: TypedExpression, finalize_arguments: Option<Vec<TypedExpression>> },
+}
+
+/// A block of statements of the typed IR.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TypedBlock {
+    /// The statements of the block.
+    pub statements: Vec<TypedStatement>,
+    /// The span of the block in the source.
+    #[cfg_attr(feature = "serde", serde(with = "leo_span::span_json"))]
+    pub span: Span,
+    /// The ID of the block, which is the ID of the AST node it was lowered from.
+    pub id: NodeID,
+}
@@ -14,22 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{NonNegativeNumber, Type};
+use crate::{ConstArgument, Type};
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// An array type.
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ArrayType {
     element_type: Box<Type>,
-    length: NonNegativeNumber,
+    length: ConstArgument,
 }
 
 impl ArrayType {
     /// Creates a new array type.
-    pub fn new(element: Type, length: NonNegativeNumber) -> Self {
-        Self { element_type: Box::new(element), length }
+    pub fn new(element: Type, length: impl Into<ConstArgument>) -> Self {
+        Self { element_type: Box::new(element), length: length.into() }
     }
 
     /// Returns the element type of the array.
@@ -38,8 +40,17 @@ impl ArrayType {
     }
 
     /// Returns the length of the array.
+    /// Lengths given as constants are resolved before the length of an array type is used.
     pub fn length(&self) -> usize {
-        self.length.value()
+        match &self.length {
+            ConstArgument::Number(number) => number.value(),
+            ConstArgument::Constant(constant) => unreachable!("The array length `{constant}` has not been resolved."),
+        }
+    }
+
+    /// Returns the length of the array as it is written.
+    pub fn array_length(&self) -> &ConstArgument {
+        &self.length
     }
 
     /// Returns the base element type of the array.
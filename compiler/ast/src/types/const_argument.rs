@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, NonNegativeNumber};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A constant argument of a type, i.e. the length of an array type, or an argument of a generic struct type.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(untagged))]
+pub enum ConstArgument {
+    /// An argument given as a number, e.g. `3` in `[u8; 3]`.
+    Number(NonNegativeNumber),
+    /// An argument given as a constant of the program, e.g. `N` in `[u8; N]`.
+    /// Constants are replaced by their values after parsing, see the constant resolution pass.
+    Constant(Identifier),
+}
+
+impl From<NonNegativeNumber> for ConstArgument {
+    fn from(number: NonNegativeNumber) -> Self {
+        Self::Number(number)
+    }
+}
+
+impl fmt::Display for ConstArgument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Constant(constant) => write!(f, "{constant}"),
+        }
+    }
+}
@@ -16,11 +16,13 @@
 
 use leo_span::{sym, Symbol};
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Explicit integer type.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum IntegerType {
     U8,
     U16,
@@ -42,6 +44,18 @@ impl IntegerType {
         matches!(self, I8 | I16 | I32 | I64 | I128)
     }
 
+    /// Returns the number of bits in the integer type.
+    pub fn bit_width(&self) -> u32 {
+        use IntegerType::*;
+        match self {
+            I8 | U8 => 8,
+            I16 | U16 => 16,
+            I32 | U32 => 32,
+            I64 | U64 => 64,
+            I128 | U128 => 128,
+        }
+    }
+
     /// Returns the symbol for the integer type.
     pub fn symbol(self) -> Symbol {
         match self {
@@ -17,9 +17,15 @@
 pub mod array;
 pub use array::*;
 
+pub mod const_argument;
+pub use const_argument::*;
+
 pub mod core_constant;
 pub use core_constant::*;
 
+pub mod generic;
+pub use generic::*;
+
 pub mod integer_type;
 pub use integer_type::*;
 
@@ -14,14 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ArrayType, Identifier, IntegerType, MappingType, TupleType};
+use crate::{ArrayType, GenericType, Identifier, IntegerType, MappingType, TupleType};
 
 use itertools::Itertools;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Explicit type used for defining a variable or expression type
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Type {
     /// The `address` type.
     Address,
@@ -33,6 +35,8 @@ pub enum Type {
     Field,
     /// The `group` type.
     Group,
+    /// An instance of a generic struct type.
+    Generic(GenericType),
     /// A reference to a built in type.
     Identifier(Identifier),
     /// An integer type.
@@ -96,6 +100,7 @@ impl fmt::Display for Type {
             Type::Boolean => write!(f, "boolean"),
             Type::Field => write!(f, "field"),
             Type::Group => write!(f, "group"),
+            Type::Generic(ref generic_type) => write!(f, "{generic_type}"),
             Type::Identifier(ref variable) => write!(f, "{variable}"),
             Type::Integer(ref integer_type) => write!(f, "{integer_type}"),
             Type::Mapping(ref mapping_type) => write!(f, "{mapping_type}"),
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{GroupLiteral, Identifier, IntegerType, Literal, NodeID, Type};
+use crate::{ArrayType, GroupLiteral, Identifier, IntegerType, Literal, NodeID, NonNegativeNumber, TupleType, Type};
 
 use leo_errors::{type_name, FlattenError, LeoError, Result};
 use leo_span::{Span, Symbol};
@@ -28,9 +28,6 @@ use std::{
 
 // TODO: Consider refactoring this module to use the console implementations from snarkVM.
 
-// This is temporary since the currently unused code is used in constant folding.
-#[allow(dead_code)]
-
 // Macro for making implementing unary operations over appropriate types easier.
 macro_rules! implement_const_unary {
     (
@@ -71,9 +68,7 @@ macro_rules! implement_const_unary {
             l: $logic:expr
         ]),+]
     ) => {
-        // TODO: This is temporary since the currently unused code is used in constant folding.
-        #[allow(dead_code)]
-        pub(crate) fn $name(self, span: Span) -> Result<Self> {
+        pub fn $name(self, span: Span) -> Result<Self> {
             use Value::*;
 
             match self {
@@ -157,9 +152,7 @@ macro_rules! implement_const_binary {
             logic: $logic:expr
         ]),+]
     ) => {
-        // This is temporary since the currently unused code is used in constant folding.
-        #[allow(dead_code)]
-        pub(crate) fn $name(self, other: Self, span: Span) -> Result<Self> {
+        pub fn $name(self, other: Self, span: Span) -> Result<Self> {
             use Value::*;
 
             match (self, other) {
@@ -183,6 +176,7 @@ macro_rules! implement_const_binary {
 pub enum Value {
     Input(Type, Identifier),
     Address(String, Span),
+    Array(Vec<Value>),
     Boolean(bool, Span),
     Struct(Identifier, IndexMap<Symbol, Value>),
     Field(String, Span),
@@ -199,6 +193,7 @@ pub enum Value {
     U128(u128, Span),
     Scalar(String, Span),
     String(String, Span),
+    Tuple(Vec<Value>),
 }
 
 impl Value {
@@ -566,6 +561,43 @@ impl Value {
         ]
     );
 
+    implement_const_binary!(
+        @overflowing
+        name: rem,
+        method: checked_rem,
+        string: "%",
+        patterns: [
+            [I8, [I8], I8, i8, i8],
+            [I16, [I16], I16, i16, i16],
+            [I32, [I32], I32, i32, i32],
+            [I64, [I64], I64, i64, i64],
+            [I128, [I128], I128, i128, i128],
+            [U8, [U8], U8, u8, u8],
+            [U16, [U16], U16, u16, u16],
+            [U32, [U32], U32, u32, u32],
+            [U64, [U64], U64, u64, u64],
+            [U128, [U128], U128, u128, u128]
+        ]
+    );
+
+    implement_const_binary!(
+        @non-overflowing
+        name: rem_wrapped,
+        method: wrapping_rem,
+        patterns: [
+            [I8, [I8], I8, i8, i8],
+            [I16, [I16], I16, i16, i16],
+            [I32, [I32], I32, i32, i32],
+            [I64, [I64], I64, i64, i64],
+            [I128, [I128], I128, i128, i128],
+            [U8, [U8], U8, u8, u8],
+            [U16, [U16], U16, u16, u16],
+            [U32, [U32], U32, u32, u32],
+            [U64, [U64], U64, u64, u64],
+            [U128, [U128], U128, u128, u128]
+        ]
+    );
+
     implement_const_binary!(
         @overflowing
         name: shl,
@@ -702,9 +734,8 @@ impl Value {
         ]
     );
 
-    // TODO: This is temporary since the currently unused code is used in constant folding.
-    #[allow(dead_code)]
-    pub(crate) fn is_supported_const_fold_type(&self) -> bool {
+    /// Returns `true` if the operations on the value can be evaluated at compile time.
+    pub fn is_supported_const_fold_type(&self) -> bool {
         use Value::*;
         matches!(
             self,
@@ -723,32 +754,71 @@ impl Value {
     }
 }
 
-impl Display for Value {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Value {
+    /// Writes the value in the format of an Aleo value, indenting the members of nested values by `depth` levels.
+    fn fmt_internal(&self, f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {
         use Value::*;
+        const INDENT: usize = 2;
         match self {
             Input(type_, ident) => write!(f, "input var {}: {type_}", ident.name),
             Address(val, _) => write!(f, "{val}"),
-            Struct(val, _) => write!(f, "{}", val.name),
+            Array(elements) => {
+                write!(f, "[")?;
+                for (i, element) in elements.iter().enumerate() {
+                    write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
+                    element.fmt_internal(f, depth + 1)?;
+                    if i + 1 < elements.len() {
+                        write!(f, ",")?;
+                    }
+                }
+                write!(f, "\n{:indent$}]", "", indent = depth * INDENT)
+            }
+            Struct(_, members) => {
+                write!(f, "{{")?;
+                for (i, (name, member)) in members.iter().enumerate() {
+                    write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
+                    member.fmt_internal(f, depth + 1)?;
+                    if i + 1 < members.len() {
+                        write!(f, ",")?;
+                    }
+                }
+                write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
+            }
             Boolean(val, _) => write!(f, "{val}"),
-            Field(val, _) => write!(f, "{val}"),
-            Group(val) => write!(f, "{val}"),
-            I8(val, _) => write!(f, "{val}"),
-            I16(val, _) => write!(f, "{val}"),
-            I32(val, _) => write!(f, "{val}"),
-            I64(val, _) => write!(f, "{val}"),
-            I128(val, _) => write!(f, "{val}"),
-            U8(val, _) => write!(f, "{val}"),
-            U16(val, _) => write!(f, "{val}"),
-            U32(val, _) => write!(f, "{val}"),
-            U64(val, _) => write!(f, "{val}"),
-            U128(val, _) => write!(f, "{val}"),
-            Scalar(val, _) => write!(f, "{val}"),
-            String(val, _) => write!(f, "{val}"),
+            Field(val, _) => write!(f, "{val}field"),
+            Group(val) => write!(f, "{val}group"),
+            I8(val, _) => write!(f, "{val}i8"),
+            I16(val, _) => write!(f, "{val}i16"),
+            I32(val, _) => write!(f, "{val}i32"),
+            I64(val, _) => write!(f, "{val}i64"),
+            I128(val, _) => write!(f, "{val}i128"),
+            U8(val, _) => write!(f, "{val}u8"),
+            U16(val, _) => write!(f, "{val}u16"),
+            U32(val, _) => write!(f, "{val}u32"),
+            U64(val, _) => write!(f, "{val}u64"),
+            U128(val, _) => write!(f, "{val}u128"),
+            Scalar(val, _) => write!(f, "{val}scalar"),
+            String(val, _) => write!(f, "\"{val}\""),
+            Tuple(elements) => {
+                write!(f, "(")?;
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    element.fmt_internal(f, depth)?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
 
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_internal(f, 0)
+    }
+}
+
 impl TryFrom<Value> for i128 {
     type Error = LeoError;
 
@@ -845,6 +915,10 @@ impl From<&Value> for Type {
         match v {
             Input(type_, _) => type_.clone(),
             Address(_, _) => Type::Address,
+            Array(elements) => Type::Array(ArrayType::new(
+                elements.first().map_or(Type::Err, Type::from),
+                NonNegativeNumber::from(elements.len()),
+            )),
             Boolean(_, _) => Type::Boolean,
             Struct(ident, _) => Type::Identifier(*ident),
             Field(_, _) => Type::Field,
@@ -861,12 +935,39 @@ impl From<&Value> for Type {
             U128(_, _) => Type::Integer(IntegerType::U128),
             Scalar(_, _) => Type::Scalar,
             String(_, _) => Type::String,
+            Tuple(elements) => Type::Tuple(TupleType::new(elements.iter().map(Type::from).collect())),
         }
     }
 }
 
+/// The reason a literal could not be converted to a value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LiteralValueError {
+    /// The literal is unsuffixed, so its type is not yet known.
+    Unsuffixed,
+    /// The digits of an integer literal do not fit its type.
+    Integer(ParseIntError),
+}
+
+impl Display for LiteralValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsuffixed => write!(f, "an unsuffixed literal has no value until its type is inferred"),
+            Self::Integer(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for LiteralValueError {}
+
+impl From<ParseIntError> for LiteralValueError {
+    fn from(error: ParseIntError) -> Self {
+        Self::Integer(error)
+    }
+}
+
 impl TryFrom<&Literal> for Value {
-    type Error = ParseIntError;
+    type Error = LiteralValueError;
 
     /// Converts a literal to a value.
     fn try_from(literal: &Literal) -> Result<Self, Self::Error> {
@@ -877,19 +978,21 @@ impl TryFrom<&Literal> for Value {
             Literal::Group(group_literal) => Self::Group(group_literal.clone()),
             Literal::Scalar(string, span, _) => Self::Scalar(string.clone(), *span),
             Literal::String(string, span, _) => Self::String(string.clone(), *span),
+            // An unsuffixed literal has no value until its type is inferred, see `Literal::with_inferred_type`.
+            Literal::Unsuffixed(..) => return Err(LiteralValueError::Unsuffixed),
             Literal::Integer(integer_type, raw_string, span, _) => {
-                let string = raw_string.replace('_', "");
+                let (string, radix) = Literal::integer_digits(raw_string);
                 match integer_type {
-                    IntegerType::U8 => Self::U8(string.parse()?, *span),
-                    IntegerType::U16 => Self::U16(string.parse()?, *span),
-                    IntegerType::U32 => Self::U32(string.parse()?, *span),
-                    IntegerType::U64 => Self::U64(string.parse()?, *span),
-                    IntegerType::U128 => Self::U128(string.parse()?, *span),
-                    IntegerType::I8 => Self::I8(string.parse()?, *span),
-                    IntegerType::I16 => Self::I16(string.parse()?, *span),
-                    IntegerType::I32 => Self::I32(string.parse()?, *span),
-                    IntegerType::I64 => Self::I64(string.parse()?, *span),
-                    IntegerType::I128 => Self::I128(string.parse()?, *span),
+                    IntegerType::U8 => Self::U8(u8::from_str_radix(&string, radix)?, *span),
+                    IntegerType::U16 => Self::U16(u16::from_str_radix(&string, radix)?, *span),
+                    IntegerType::U32 => Self::U32(u32::from_str_radix(&string, radix)?, *span),
+                    IntegerType::U64 => Self::U64(u64::from_str_radix(&string, radix)?, *span),
+                    IntegerType::U128 => Self::U128(u128::from_str_radix(&string, radix)?, *span),
+                    IntegerType::I8 => Self::I8(i8::from_str_radix(&string, radix)?, *span),
+                    IntegerType::I16 => Self::I16(i16::from_str_radix(&string, radix)?, *span),
+                    IntegerType::I32 => Self::I32(i32::from_str_radix(&string, radix)?, *span),
+                    IntegerType::I64 => Self::I64(i64::from_str_radix(&string, radix)?, *span),
+                    IntegerType::I128 => Self::I128(i128::from_str_radix(&string, radix)?, *span),
                 }
             }
         })
@@ -897,14 +1000,14 @@ impl TryFrom<&Literal> for Value {
 }
 
 impl Literal {
-    #[allow(unused)]
-    fn from_value(v: Value, id: NodeID) -> Self {
+    /// Returns the literal of `value` with the ID `id`, or `None` if the value is an array, struct, or tuple,
+    /// which are not literals, or an input, whose value is unknown.
+    pub fn from_value(value: Value, id: NodeID) -> Option<Self> {
         use Value::*;
-        match v {
-            Input(_, _) => todo!("We need to test if this is hittable"),
+        Some(match value {
+            Input(..) | Array(_) | Struct(..) | Tuple(_) => return None,
             Address(v, span) => Literal::Address(v, span, id),
             Boolean(v, span) => Literal::Boolean(v, span, id),
-            Struct(_ident, _values) => todo!("We need to test if this is hittable"),
             Field(v, span) => Literal::Field(v, span, id),
             Group(v) => Literal::Group(v),
             I8(v, span) => Literal::Integer(IntegerType::I8, v.to_string(), span, id),
@@ -919,6 +1022,6 @@ impl Literal {
             U128(v, span) => Literal::Integer(IntegerType::U128, v.to_string(), span, id),
             Scalar(v, span) => Literal::Scalar(v, span, id),
             String(v, span) => Literal::String(v, span, id),
-        }
+        })
     }
 }
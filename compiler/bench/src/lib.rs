@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+#![forbid(unsafe_code)]
+#![doc = include_str!("../README.md")]
+
+pub mod stage;
+pub use stage::*;
+
+pub mod workload;
+pub use workload::*;
+
+use criterion::{BenchmarkId, Criterion};
+
+/// The sizes the benchmarks are run with by `cargo bench`.
+pub const DEFAULT_SIZES: [usize; 3] = [16, 64, 256];
+
+/// A stage and the family of workloads it is measured on.
+pub type Benchmark = (Stage, fn(usize) -> Workload);
+
+/// Returns the front-end benchmarks.
+pub fn benchmarks() -> [Benchmark; 4] {
+    [
+        (Stage::Parse, Workload::Functions),
+        (Stage::TypeCheck, Workload::ExpressionNest),
+        (Stage::TypeCheck, Workload::ScopeNest),
+        (Stage::TypeCheck, Workload::StructUses),
+    ]
+}
+
+/// Registers a group of benchmarks with `criterion` for each of the front-end benchmarks, parameterized by `sizes`.
+pub fn register_benchmarks(c: &mut Criterion, sizes: &[usize]) {
+    for (stage, workload) in benchmarks() {
+        let mut group = c.benchmark_group(format!("{stage} {}", workload(0).family()));
+        for &size in sizes {
+            let source = workload(size).source();
+            group.bench_with_input(BenchmarkId::from_parameter(size), &source, |b, source| {
+                // Only the time spent in the stage itself is measured.
+                b.iter_custom(|iters| {
+                    (0..iters).map(|_| measure(stage, source).expect("the workload should compile")).sum()
+                })
+            });
+        }
+        group.finish();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_workloads_compile() {
+        for (stage, workload) in benchmarks() {
+            for size in [0, 1, 8] {
+                let workload = workload(size);
+                if let Err(error) = measure(stage, &workload.source()) {
+                    panic!("`{workload}` failed to compile: {error}\n{}", workload.source());
+                }
+            }
+        }
+    }
+}
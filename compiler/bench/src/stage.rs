@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::Compiler;
+use leo_errors::{emitter::Handler, Result};
+use leo_span::{source_map::FileName, symbol::SESSION_GLOBALS};
+
+use std::{
+    fmt,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+/// A stage of the Leo front end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    /// Parsing the program into an AST.
+    Parse,
+    /// Creating the symbol table.
+    SymbolTable,
+    /// Type checking the program.
+    TypeCheck,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::SymbolTable => write!(f, "symbol table"),
+            Self::TypeCheck => write!(f, "type check"),
+        }
+    }
+}
+
+/// Runs the front end on `source` up to `stage`, returning the time spent in `stage` alone.
+/// Each run uses a fresh session, so that runs do not share interned symbols or source files.
+/// The program in `source` must be named `bench.aleo`.
+pub fn measure(stage: Stage, source: &str) -> Result<Duration> {
+    SESSION_GLOBALS.set(&Default::default(), || {
+        let handler = Handler::default();
+        let mut compiler =
+            Compiler::new("bench".into(), "aleo".into(), &handler, PathBuf::new(), PathBuf::new(), None);
+
+        let start = Instant::now();
+        compiler.parse_program_from_string(source, FileName::Custom("bench".into()))?;
+        if stage == Stage::Parse {
+            return Ok(start.elapsed());
+        }
+
+        let start = Instant::now();
+        let symbol_table = compiler.symbol_table_pass()?;
+        if stage == Stage::SymbolTable {
+            return Ok(start.elapsed());
+        }
+
+        let start = Instant::now();
+        compiler.type_checker_pass(symbol_table)?;
+        Ok(start.elapsed())
+    })
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt::Write;
+
+/// A generated program, parameterized by its size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Workload {
+    /// A program with `n` functions.
+    Functions(usize),
+    /// A transition returning an arithmetic expression nested `n` deep.
+    ExpressionNest(usize),
+    /// A transition with `n` nested blocks, each defining a variable that is read by every block it encloses.
+    ScopeNest(usize),
+    /// A struct with a method of `n` statements, and a transition with `n` statements that each create a struct,
+    /// read a member of one, and call the method.
+    StructUses(usize),
+}
+
+impl Workload {
+    /// Returns the name of the family of the workload.
+    pub fn family(&self) -> &'static str {
+        match self {
+            Self::Functions(_) => "functions",
+            Self::ExpressionNest(_) => "expression nest",
+            Self::ScopeNest(_) => "scope nest",
+            Self::StructUses(_) => "struct uses",
+        }
+    }
+
+    /// Returns the Leo source of the workload, a program named `bench.aleo`.
+    pub fn source(&self) -> String {
+        let mut body = String::new();
+        match *self {
+            Self::Functions(n) => {
+                for i in 0..n {
+                    writeln!(body, "    function f{i}(a: u32, b: u32) -> u32 {{").unwrap();
+                    writeln!(body, "        let c: u32 = a * {i}u32 + b;").unwrap();
+                    writeln!(body, "        return c;").unwrap();
+                    writeln!(body, "    }}\n").unwrap();
+                }
+                writeln!(body, "    transition main(a: u32) -> u32 {{\n        return a;\n    }}").unwrap();
+            }
+            Self::ExpressionNest(n) => {
+                let expression = (0..n).fold("a".to_string(), |expression, i| match i % 2 {
+                    0 => format!("({expression} + {i}u32)"),
+                    _ => format!("({expression} * {i}u32)"),
+                });
+                writeln!(body, "    transition main(a: u32) -> u32 {{\n        return {expression};\n    }}").unwrap();
+            }
+            Self::ScopeNest(n) => {
+                writeln!(body, "    transition main(a: u32) -> u32 {{").unwrap();
+                writeln!(body, "        let v0: u32 = a;").unwrap();
+                for i in 1..=n {
+                    let indent = "    ".repeat(i + 1);
+                    let sum = (0..i).map(|j| format!("v{j}")).collect::<Vec<_>>().join(" + ");
+                    writeln!(body, "{indent}if v{} > 0u32 {{", i - 1).unwrap();
+                    writeln!(body, "{indent}    let v{i}: u32 = {sum};").unwrap();
+                }
+                for i in (1..=n).rev() {
+                    writeln!(body, "{}}}", "    ".repeat(i + 1)).unwrap();
+                }
+                writeln!(body, "        return v0;\n    }}").unwrap();
+            }
+            Self::StructUses(n) => {
+                writeln!(body, "    struct Point {{\n        x: u32,\n        y: u32,\n").unwrap();
+                writeln!(body, "        function sum(self) -> u32 {{").unwrap();
+                writeln!(body, "            let s0: u32 = self.x;").unwrap();
+                for i in 1..=n {
+                    writeln!(body, "            let s{i}: u32 = s{} + self.y;", i - 1).unwrap();
+                }
+                writeln!(body, "            return s{n};\n        }}\n    }}\n").unwrap();
+                writeln!(body, "    transition main(a: u32) -> u32 {{").unwrap();
+                writeln!(body, "        let p0: Point = Point {{ x: a, y: a }};").unwrap();
+                for i in 1..=n {
+                    let j = i - 1;
+                    writeln!(body, "        let p{i}: Point = Point {{ x: p{j}.x, y: p{j}.sum() }};").unwrap();
+                }
+                writeln!(body, "        return p{n}.x;\n    }}").unwrap();
+            }
+        }
+        format!("program bench.aleo {{\n{body}}}\n")
+    }
+}
+
+impl std::fmt::Display for Workload {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (Self::Functions(n) | Self::ExpressionNest(n) | Self::ScopeNest(n) | Self::StructUses(n)) = self;
+        write!(f, "{} {n}", self.family())
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_passes::{CallGraph, StructGraph, SymbolTable};
+
+/// The analyses of a program that passed the checks of [`crate::Compiler::check`].
+#[derive(Debug)]
+pub struct CheckedProgram {
+    /// The symbol table of the program, including the types inferred by the type checker.
+    pub symbol_table: SymbolTable,
+    /// The graph of the structs that each struct depends on.
+    pub struct_graph: StructGraph,
+    /// The graph of the functions that each function calls.
+    pub call_graph: CallGraph,
+}
+
+/// The Aleo instructions generated by [`crate::Compiler::compile`].
+#[derive(Debug)]
+pub struct CompiledInstructions {
+    /// The symbol table of the program after the passes that transform it.
+    pub symbol_table: SymbolTable,
+    /// The Aleo instructions of the program.
+    pub instructions: String,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AstSnapshotOptions, Compiler, OptLevel};
+
+    use leo_errors::emitter::Handler;
+    use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+    use std::fs;
+
+    const PROGRAM: &str = "program test.aleo {\n\
+                           function double(a: u8) -> u8 { return a + a; }\n\
+                           transition main(a: u8) -> u8 { return double(a); }\n\
+                           }\n";
+
+    #[test]
+    fn test_compiler_phases() {
+        let directory = std::env::temp_dir().join(format!("leo-compiler-phases-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let main = directory.join("main.leo");
+        fs::write(&main, PROGRAM).unwrap();
+
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let compiler = || {
+                Compiler::new("test".into(), "aleo".into(), &handler, main.clone(), "unused".into(), None)
+                    .with_output_dir(directory.clone())
+                    .with_opt_level(OptLevel::Size)
+            };
+
+            let program_scopes = compiler().parse().unwrap().ast.program_scopes.len();
+            let checked = compiler().check().unwrap();
+            let snapshots = AstSnapshotOptions { initial: true, ..Default::default() };
+            let compiled = compiler().with_snapshots(snapshots).compile();
+            let snapshot = directory.join("test.initial_ast.json");
+
+            assert_eq!(program_scopes, 1);
+            assert!(checked.symbol_table.lookup_fn_symbol(Symbol::intern("main")).is_some());
+            assert_eq!(checked.call_graph.neighbors(Symbol::intern("main")).collect::<Vec<_>>(), [
+                Symbol::intern("double")
+            ]);
+            assert!(compiled.unwrap().instructions.contains("function main:"));
+            assert!(snapshot.exists());
+        });
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_compiler_with_handler() {
+        let directory = std::env::temp_dir().join(format!("leo-compiler-handler-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let main = directory.join("main.leo");
+        fs::write(&main, PROGRAM.replace("return a + a;", "return b;")).unwrap();
+
+        let errors = create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let (buffered, buffer) = Handler::new_with_buf();
+            let compiler = Compiler::new("test".into(), "aleo".into(), &handler, main, directory.clone(), None);
+            assert!(compiler.with_handler(&buffered).check().is_err());
+            assert!(!handler.had_errors());
+            buffer.extract_errs().into_inner().iter().map(ToString::to_string).collect::<Vec<_>>()
+        });
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert!(errors.iter().any(|error| error.contains("Unknown variable `b`")), "{errors:?}");
+    }
+}
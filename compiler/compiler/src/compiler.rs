@@ -17,17 +17,32 @@
 //! The compiler for Leo programs.
 //!
 //! The [`Compiler`] type compiles Leo programs into R1CS circuits.
-pub use leo_ast::{Ast, InputAst};
-use leo_ast::{NodeBuilder, Program};
+pub use leo_ast::{Ast, InputAst, TypedInputAst};
+use leo_ast::{Demangled, ImportTrace, Mangler, NodeBuilder, Program, TypedProgram};
 use leo_errors::{emitter::Handler, CompilerError, Result};
-pub use leo_passes::SymbolTable;
+pub use leo_passes::{SymbolTable, TypeCheckCache};
+use leo_parser::ImportResolver;
 use leo_passes::*;
-use leo_span::{source_map::FileName, symbol::with_session_globals};
+use leo_span::{
+    source_map::FileName,
+    symbol::{create_session_if_not_set_then, with_session_globals},
+};
 
 use sha2::{Digest, Sha256};
-use std::{fs, path::PathBuf};
+use std::{fs, ops::ControlFlow, path::PathBuf, rc::Rc, time::Instant};
 
-use crate::CompilerOptions;
+use crate::{
+    ast_nodes,
+    reset_peak_memory,
+    AstSnapshotOptions,
+    CheckedProgram,
+    CompilationStats,
+    CompiledInstructions,
+    CompilerOptions,
+    FileWatcher,
+    OptLevel,
+    Rebuild,
+};
 
 /// The primary entry point of the Leo compiler.
 #[derive(Clone)]
@@ -54,6 +69,32 @@ pub struct Compiler<'a> {
     assigner: Assigner,
     /// The type table.
     type_table: TypeTable,
+    /// The values of the constants of the program scope, evaluated by the constant resolution pass.
+    constants: ConstantEnvironment,
+    /// The typed IR, lowered from the AST after type checking.
+    typed_program: TypedProgram,
+    /// The resolver of imports, which records the steps of import resolution if enabled.
+    import_resolver: ImportResolver,
+    /// The symbol table of the imports kept between builds, if the compiler rebuilds the program in watch mode.
+    symbol_table_cache: Option<SymbolTableCache>,
+    /// The built-in functions registered by the embedder, in addition to the core functions.
+    core_functions: CoreFunctionRegistry,
+    /// The optional passes that are disabled.
+    disabled_passes: Vec<&'static str>,
+    /// The time that each pass took in the last compilation.
+    pass_timings: Vec<PassTiming>,
+    /// The statistics of each phase of compilation, which are only recorded if enabled by the output options.
+    stats: CompilationStats,
+    /// The estimated number of constraints of each function, which is only computed if enabled by the output options.
+    constraint_estimate: ConstraintEstimate,
+}
+
+/// The state threaded through the passes of the compiler pipeline.
+struct PipelineState<'c, 'a> {
+    compiler: &'c mut Compiler<'a>,
+    symbol_table: Option<SymbolTable>,
+    struct_graph: Option<StructGraph>,
+    call_graph: Option<CallGraph>,
 }
 
 impl<'a> Compiler<'a> {
@@ -69,6 +110,12 @@ impl<'a> Compiler<'a> {
         let node_builder = NodeBuilder::default();
         let assigner = Assigner::default();
         let type_table = TypeTable::default();
+        let compiler_options = compiler_options.unwrap_or_default();
+        let import_resolver = ImportResolver::new(
+            compiler_options.build.import_paths.clone(),
+            ImportTrace::new(compiler_options.output.import_trace),
+        )
+        .with_max_depth(compiler_options.build.program_limits.max_depth);
         Self {
             handler,
             main_file_path,
@@ -77,13 +124,97 @@ impl<'a> Compiler<'a> {
             network,
             ast: Ast::new(Program::default()),
             input_ast: None,
-            compiler_options: compiler_options.unwrap_or_default(),
+            compiler_options,
             node_builder,
             assigner,
             type_table,
+            constants: ConstantEnvironment::default(),
+            typed_program: TypedProgram::default(),
+            import_resolver,
+            symbol_table_cache: None,
+            core_functions: CoreFunctionRegistry::default(),
+            disabled_passes: Vec::new(),
+            pass_timings: Vec::new(),
+            stats: CompilationStats::default(),
+            constraint_estimate: ConstraintEstimate::default(),
         }
     }
 
+    /// Writes the generated files, e.g. the AST snapshots, to `output_directory`.
+    pub fn with_output_dir(mut self, output_directory: PathBuf) -> Self {
+        self.output_directory = output_directory;
+        self
+    }
+
+    /// Writes the AST snapshots selected by `snapshots` to the output directory.
+    pub fn with_snapshots(mut self, snapshots: AstSnapshotOptions) -> Self {
+        self.compiler_options.output.ast_snapshots = snapshots;
+        self
+    }
+
+    /// Optimizes the generated program at `opt_level`.
+    pub fn with_opt_level(mut self, opt_level: OptLevel) -> Self {
+        self.compiler_options.build.set_opt_level(opt_level);
+        self
+    }
+
+    /// Emits the errors and warnings of compilation to `handler`.
+    pub fn with_handler(self, handler: &Handler) -> Compiler<'_> {
+        Compiler {
+            handler,
+            main_file_path: self.main_file_path,
+            output_directory: self.output_directory,
+            program_name: self.program_name,
+            network: self.network,
+            ast: self.ast,
+            input_ast: self.input_ast,
+            compiler_options: self.compiler_options,
+            node_builder: self.node_builder,
+            assigner: self.assigner,
+            type_table: self.type_table,
+            constants: self.constants,
+            typed_program: self.typed_program,
+            import_resolver: self.import_resolver,
+            symbol_table_cache: self.symbol_table_cache,
+            core_functions: self.core_functions,
+            disabled_passes: self.disabled_passes,
+            pass_timings: self.pass_timings,
+            stats: self.stats,
+            constraint_estimate: self.constraint_estimate,
+        }
+    }
+
+    /// Names the variables introduced while lowering the program with `mangler` instead of the default scheme.
+    pub fn with_mangler(mut self, mangler: Rc<dyn Mangler>) -> Self {
+        self.assigner = Assigner::new(mangler);
+        self
+    }
+
+    /// Restricts imports to `dependencies`, the names and files of the programs declared by a package manifest.
+    pub fn with_dependencies(mut self, dependencies: impl IntoIterator<Item = (String, PathBuf)>) -> Self {
+        self.import_resolver = self.import_resolver.with_dependencies(dependencies.into_iter().collect());
+        self
+    }
+
+    /// Lets programs call the built-in functions in `registry`, in addition to the core functions, e.g. for a VM that
+    /// provides instructions of its own. Calls are type checked against the registered signatures.
+    pub fn with_core_functions(mut self, registry: CoreFunctionRegistry) -> Self {
+        self.core_functions = registry;
+        self
+    }
+
+    /// Disables the optional pass named `pass`, e.g. `dead_code_elimination`.
+    /// Compilation fails if there is no such pass, or if later passes rely on it.
+    pub fn disable_pass(mut self, pass: &'static str) -> Self {
+        self.disabled_passes.push(pass);
+        self
+    }
+
+    /// Returns the source item that a name in the compiled program was derived from, if it was introduced by lowering.
+    pub fn demangle(&self, name: &str) -> Option<Demangled> {
+        self.assigner.mangler().demangle(name)
+    }
+
     /// Returns a SHA256 checksum of the program file.
     pub fn checksum(&self) -> Result<String> {
         // Read in the main file as string
@@ -100,11 +231,7 @@ impl<'a> Compiler<'a> {
 
     /// Parses and stores a program file content from a string, constructs a syntax tree, and generates a program.
     pub fn parse_program_from_string(&mut self, program_string: &str, name: FileName) -> Result<()> {
-        // Register the source (`program_string`) in the source map.
-        let prg_sf = with_session_globals(|s| s.source_map.new_source(program_string, name));
-
-        // Use the parser to construct the abstract syntax tree (ast).
-        self.ast = leo_parser::parse_ast(self.handler, &self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
+        self.parse_ast_from_string(program_string, name)?;
 
         // If the program is imported, then check that the name of its program scope matches the file name.
         // Note that parsing enforces that there is exactly one program scope in a file.
@@ -120,10 +247,67 @@ impl<'a> Compiler<'a> {
             .into());
         }
 
-        if self.compiler_options.output.initial_ast {
+        if self.compiler_options.output.ast_snapshots.initial {
             self.write_ast_to_json("initial_ast.json")?;
         }
 
+        self.front_end_passes()
+    }
+
+    /// Parses a program file content from a string and resolves its imports, without running any passes on its AST.
+    pub fn parse_ast_from_string(&mut self, program_string: &str, name: FileName) -> Result<()> {
+        // Register the source (`program_string`) in the source map.
+        let prg_sf = with_session_globals(|s| s.source_map.new_source(program_string, name));
+
+        self.record_phase("parsing", |compiler| {
+            // Use the parser to construct the abstract syntax tree (ast).
+            let ast = leo_parser::parse_ast_with_imports(
+                compiler.handler,
+                &compiler.node_builder,
+                &compiler.import_resolver,
+                &prg_sf.src,
+                prg_sf.start_pos,
+            );
+            // The trace is written even if parsing fails, since it explains why an import was not found.
+            compiler.write_import_trace()?;
+            compiler.ast = ast?;
+            compiler.check_program_size()
+        })
+    }
+
+    /// Runs the passes that prepare the parsed AST for the symbol table, e.g. desugaring and constant resolution.
+    pub fn front_end_passes(&mut self) -> Result<()> {
+        self.record_phase("edition_checking", |compiler| compiler.edition_checking_pass())?;
+
+        self.record_phase("test_filtering", |compiler| {
+            compiler.test_filtering_pass();
+            Ok(())
+        })?;
+
+        self.record_phase("wrapping_desugaring", |compiler| {
+            compiler.wrapping_desugaring_pass();
+            Ok(())
+        })?;
+
+        self.record_phase("literal_folding", |compiler| {
+            compiler.literal_folding_pass();
+            Ok(())
+        })?;
+
+        self.record_phase("constant_resolution", Self::constant_resolution_pass)?;
+
+        self.record_phase("monomorphization", Self::monomorphization_pass)
+    }
+
+    /// Checks that the parsed program, including its imports, has at most as many nodes as the build options allow.
+    fn check_program_size(&self) -> Result<()> {
+        let Some(max_nodes) = self.compiler_options.build.program_limits.max_nodes else {
+            return Ok(());
+        };
+        let nodes = ast_nodes(&self.ast);
+        if nodes > max_nodes {
+            return Err(CompilerError::program_too_complex(nodes, max_nodes).into());
+        }
         Ok(())
     }
 
@@ -146,7 +330,7 @@ impl<'a> Compiler<'a> {
             // Parse and serialize it.
             let input_ast =
                 leo_parser::parse_input(self.handler, &self.node_builder, &input_sf.src, input_sf.start_pos)?;
-            if self.compiler_options.output.initial_ast {
+            if self.compiler_options.output.initial_input_ast {
                 // Write the input AST snapshot post parsing.
                 if self.compiler_options.output.ast_spans_enabled {
                     input_ast.to_json_file(
@@ -167,9 +351,61 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Runs the input binding pass on the stored input file, if there is one.
+    /// The symbol table must be the one produced by the type checker.
+    pub fn input_binding_pass(&self, symbol_table: &SymbolTable) -> Result<Option<TypedInputAst>> {
+        self.input_ast
+            .as_ref()
+            .map(|input_ast| InputBinder::do_pass((input_ast, symbol_table, self.handler)))
+            .transpose()
+    }
+
+    /// Runs the test filtering pass, unless tests are enabled.
+    pub fn test_filtering_pass(&mut self) {
+        if !self.compiler_options.build.tests_enabled {
+            self.ast = TestFilter::do_pass(std::mem::take(&mut self.ast));
+        }
+    }
+
+    /// Runs the edition checking pass, which rejects the features that are newer than the targeted edition.
+    pub fn edition_checking_pass(&self) -> Result<()> {
+        EditionChecker::do_pass((&self.ast, self.handler, self.compiler_options.build.language_edition))
+    }
+
+    /// Runs the wrapping desugaring pass.
+    pub fn wrapping_desugaring_pass(&mut self) {
+        self.ast = WrappingDesugarer::do_pass(std::mem::take(&mut self.ast));
+    }
+
+    /// Runs the literal folding pass.
+    pub fn literal_folding_pass(&mut self) {
+        self.ast = LiteralFolder::do_pass(std::mem::take(&mut self.ast));
+    }
+
+    /// Runs the constant resolution pass.
+    pub fn constant_resolution_pass(&mut self) -> Result<()> {
+        (self.ast, self.constants) = ConstantResolver::do_pass((std::mem::take(&mut self.ast), self.handler))?;
+
+        Ok(())
+    }
+
+    /// Runs the monomorphization pass.
+    pub fn monomorphization_pass(&mut self) -> Result<()> {
+        self.ast = Monomorphizer::do_pass((std::mem::take(&mut self.ast), self.handler, &self.node_builder))?;
+
+        Ok(())
+    }
+
     /// Runs the symbol table pass.
     pub fn symbol_table_pass(&self) -> Result<SymbolTable> {
-        let symbol_table = SymbolTableCreator::do_pass((&self.ast, self.handler))?;
+        let import_trace = self.import_resolver.import_trace();
+        let mut creator = SymbolTableCreator::new(self.handler, import_trace);
+        if let Some(cache) = &self.symbol_table_cache {
+            creator = creator.with_cache(cache);
+        }
+        let symbol_table = creator.run(&self.ast);
+        self.write_import_trace()?;
+        let symbol_table = symbol_table?;
         if self.compiler_options.output.initial_symbol_table {
             self.write_symbol_table_to_json("initial_symbol_table.json", &symbol_table)?;
         }
@@ -178,14 +414,97 @@ impl<'a> Compiler<'a> {
 
     /// Runs the type checker pass.
     pub fn type_checker_pass(&'a self, symbol_table: SymbolTable) -> Result<(SymbolTable, StructGraph, CallGraph)> {
-        let (symbol_table, struct_graph, call_graph) =
-            TypeChecker::do_pass((&self.ast, self.handler, symbol_table, &self.type_table))?;
+        let (symbol_table, struct_graph, call_graph) = TypeChecker::new(symbol_table, &self.type_table, self.handler)
+            .with_core_functions(&self.core_functions)
+            .run(&self.ast)?;
+        if self.compiler_options.output.ast_snapshots.type_checked {
+            self.write_ast_to_json("type_checked_ast.json")?;
+        }
         if self.compiler_options.output.type_checked_symbol_table {
             self.write_symbol_table_to_json("type_checked_symbol_table.json", &symbol_table)?;
         }
+        if self.compiler_options.output.symbol_table_export {
+            symbol_table
+                .export()
+                .to_json_file(self.output_directory.clone(), &format!("{}.symbols.json", self.program_name))?;
+        }
         Ok((symbol_table, struct_graph, call_graph))
     }
 
+    /// Runs the typed lowering pass.
+    pub fn typed_lowering_pass(&mut self) -> Result<()> {
+        self.typed_program = TypedLowerer::do_pass((&self.ast, &self.type_table))?;
+        if self.compiler_options.output.ast_snapshots.typed_ir {
+            self.typed_program
+                .to_json_file(self.output_directory.clone(), &format!("{}.typed_ir.json", self.program_name))?;
+        }
+        Ok(())
+    }
+
+    /// Runs the pattern lowering pass.
+    pub fn pattern_lowering_pass(&mut self) {
+        self.ast =
+            PatternLowerer::do_pass((std::mem::take(&mut self.ast), &self.type_table, &self.node_builder, &self.assigner));
+    }
+
+    /// Runs the enum lowering pass.
+    pub fn enum_lowering_pass(&mut self, symbol_table: SymbolTable) -> SymbolTable {
+        let (ast, symbol_table) = EnumLowerer::do_pass((std::mem::take(&mut self.ast), symbol_table, &self.type_table));
+        self.ast = ast;
+        symbol_table
+    }
+
+    /// Runs the definite assignment pass.
+    pub fn definite_assignment_pass(&self) -> Result<()> {
+        DefiniteAssignmentChecker::do_pass((&self.ast, self.handler))
+    }
+
+    /// Runs the entry point validation pass.
+    pub fn entry_point_validation_pass(&self, symbol_table: &SymbolTable) -> Result<()> {
+        EntryPointValidator::do_pass((&self.ast, self.handler, symbol_table))
+    }
+
+    /// Runs the information flow pass.
+    pub fn information_flow_pass(&self, symbol_table: &SymbolTable) -> Result<()> {
+        InformationFlowChecker::do_pass((&self.ast, self.handler, symbol_table))
+    }
+
+    /// Runs the record linearity pass.
+    pub fn record_linearity_pass(&self, symbol_table: &SymbolTable) -> Result<()> {
+        RecordLinearityChecker::do_pass((&self.ast, self.handler, symbol_table, &self.type_table))
+    }
+
+    /// Runs the signedness checking pass, which only reports if a program scope sets the level of its lint.
+    pub fn signedness_checking_pass(&self) -> Result<()> {
+        SignednessChecker::do_pass((&self.ast, self.handler, &self.type_table))
+    }
+
+    /// Runs the unused checking pass, if lints are enabled.
+    /// The symbol table must be the one produced by the type checker.
+    pub fn unused_checking_pass(&self, symbol_table: &SymbolTable) {
+        if self.compiler_options.build.lints_enabled {
+            UnusedChecker::do_pass((&self.ast, self.handler, symbol_table));
+        }
+    }
+
+    /// Runs the panic audit pass, writing its report to the output directory.
+    pub fn panic_audit_pass(&self) -> Result<PanicAuditReport> {
+        let report = PanicAuditor::do_pass((&self.ast, &self.type_table));
+        report.to_json_file(self.output_directory.clone(), &format!("{}.panic_audit.json", self.program_name))?;
+        Ok(report)
+    }
+
+    /// Runs the target checking pass.
+    pub fn target_checking_pass(&self) -> Result<()> {
+        TargetChecker::do_pass((&self.ast, self.handler, &self.type_table))
+    }
+
+    /// Runs the method lowering pass.
+    pub fn method_lowering_pass(&mut self) -> Result<()> {
+        self.ast = MethodLowerer::do_pass((std::mem::take(&mut self.ast), &self.type_table, &self.node_builder))?;
+        Ok(())
+    }
+
     /// Runs the loop unrolling pass.
     pub fn loop_unrolling_pass(&mut self, symbol_table: SymbolTable) -> Result<SymbolTable> {
         let (ast, symbol_table) = Unroller::do_pass((
@@ -194,10 +513,11 @@ impl<'a> Compiler<'a> {
             &self.node_builder,
             symbol_table,
             &self.type_table,
+            &self.constants,
         ))?;
         self.ast = ast;
 
-        if self.compiler_options.output.unrolled_ast {
+        if self.compiler_options.output.ast_snapshots.unrolled {
             self.write_ast_to_json("unrolled_ast.json")?;
         }
 
@@ -218,7 +538,7 @@ impl<'a> Compiler<'a> {
             &self.type_table,
         ))?;
 
-        if self.compiler_options.output.ssa_ast {
+        if self.compiler_options.output.ast_snapshots.ssa {
             self.write_ast_to_json("ssa_ast.json")?;
         }
 
@@ -235,7 +555,7 @@ impl<'a> Compiler<'a> {
             &self.assigner,
         ))?;
 
-        if self.compiler_options.output.flattened_ast {
+        if self.compiler_options.output.ast_snapshots.flattened {
             self.write_ast_to_json("flattened_ast.json")?;
         }
 
@@ -251,7 +571,7 @@ impl<'a> Compiler<'a> {
             &self.assigner,
         ))?;
 
-        if self.compiler_options.output.destructured_ast {
+        if self.compiler_options.output.ast_snapshots.destructured {
             self.write_ast_to_json("destructured_ast.json")?;
         }
 
@@ -269,7 +589,7 @@ impl<'a> Compiler<'a> {
         ))?;
         self.ast = ast;
 
-        if self.compiler_options.output.inlined_ast {
+        if self.compiler_options.output.ast_snapshots.inlined {
             self.write_ast_to_json("inlined_ast.json")?;
         }
 
@@ -282,13 +602,30 @@ impl<'a> Compiler<'a> {
             self.ast = DeadCodeEliminator::do_pass((std::mem::take(&mut self.ast), &self.node_builder))?;
         }
 
-        if self.compiler_options.output.dce_ast {
+        if self.compiler_options.output.ast_snapshots.dce {
             self.write_ast_to_json("dce_ast.json")?;
         }
 
         Ok(())
     }
 
+    /// Runs the cost estimation pass, if an instruction budget is configured or the constraint estimate is enabled.
+    pub fn cost_estimation_pass(&mut self) -> Result<()> {
+        let budget = self.compiler_options.build.instruction_budget;
+        if budget.is_some() || self.compiler_options.output.constraint_estimate {
+            self.constraint_estimate = CostEstimator::do_pass((&self.ast, self.handler, &self.type_table, budget))?;
+        }
+        Ok(())
+    }
+
+    /// Runs the item stripping pass, if enabled.
+    pub fn item_stripping_pass(&mut self) -> Result<()> {
+        if self.compiler_options.build.strip_enabled {
+            self.ast = ItemStripper::do_pass((std::mem::take(&mut self.ast), self.handler))?;
+        }
+        Ok(())
+    }
+
     /// Runs the code generation pass.
     pub fn code_generation_pass(
         &mut self,
@@ -296,45 +633,504 @@ impl<'a> Compiler<'a> {
         struct_graph: &StructGraph,
         call_graph: &CallGraph,
     ) -> Result<String> {
-        CodeGenerator::do_pass((&self.ast, symbol_table, &self.type_table, struct_graph, call_graph, &self.ast.ast))
+        let (bytecode, source_map) = CodeGenerator::do_pass((
+            &self.ast,
+            symbol_table,
+            &self.type_table,
+            struct_graph,
+            call_graph,
+            &self.core_functions,
+            &self.ast.ast,
+        ))?;
+        if self.compiler_options.output.source_map {
+            source_map
+                .to_json_file(self.output_directory.clone(), &format!("{}.source_map.json", self.program_name))?;
+        }
+        Ok(bytecode)
+    }
+
+    /// Runs the input stub generation pass, returning a skeleton input file for the program's transitions.
+    /// The symbol table must be the one produced by the type checker.
+    pub fn input_stub_pass(&self, symbol_table: &SymbolTable) -> String {
+        InputStubGenerator::do_pass((&self.ast, symbol_table))
+    }
+
+    /// Runs the reference indexing pass and persists the index in the output directory.
+    /// The AST must be the one produced by the parser, since later passes rewrite the names of the program.
+    pub fn reference_indexing_pass(&self) -> Result<ReferenceIndex> {
+        let reference_index = ReferenceIndexer::do_pass(&self.ast);
+        reference_index.persist(&self.output_directory, &self.main_file_path)?;
+        Ok(reference_index)
+    }
+
+    /// Returns the persisted reference index if it was built from the current program file.
+    /// This does not require the program to be parsed.
+    pub fn cached_reference_index(&self) -> Result<Option<ReferenceIndex>> {
+        let source = fs::read_to_string(&self.main_file_path)
+            .map_err(|e| CompilerError::file_read_error(&self.main_file_path, e))?;
+        Ok(ReferenceIndex::load(&self.output_directory, &self.main_file_path, &source))
+    }
+
+    /// Runs the interpreter on the test vectors in the given JSON file, and writes the expected outputs to a fixture in the output directory.
+    /// The program must be type checked, and not yet transformed by the later passes.
+    pub fn test_vector_fixture_pass(&self, test_vectors_path: PathBuf) -> Result<TestVectorFixture> {
+        let test_vectors = TestVectors::from_json_file(test_vectors_path)?;
+        // Note that parsing enforces that there is exactly one program scope in a file.
+        let program_scope = self.ast.ast.program_scopes.values().next().unwrap();
+        let fixture = TestVectorFixture::generate(
+            program_scope,
+            &self.type_table,
+            &self.constants,
+            &test_vectors,
+            self.compiler_options.build.interpreter_limits,
+        )?;
+        fixture
+            .to_json_file(self.output_directory.clone(), &format!("{}.test_vector_fixture.json", self.program_name))?;
+        Ok(fixture)
     }
 
     /// Runs the compiler stages.
     pub fn compiler_stages(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
-        let st = self.symbol_table_pass()?;
-        let (st, struct_graph, call_graph) = self.type_checker_pass(st)?;
+        self.compiler_stages_inspected(|_, _| {})
+    }
 
-        // TODO: Make this pass optional.
-        let st = self.loop_unrolling_pass(st)?;
+    /// Runs the compiler stages, calling `inspect` with the AST and the name of each pass after it succeeds,
+    /// e.g. so that tests can check the invariants of the AST between passes.
+    pub fn compiler_stages_inspected(
+        &mut self,
+        inspect: impl FnMut(&Ast, &'static str) + 'static,
+    ) -> Result<(SymbolTable, StructGraph, CallGraph)> {
+        let (symbol_table, struct_graph, call_graph) = self.run_pipeline(None, inspect)?;
+        // The pipeline is checked to provide the analyses before it runs.
+        Ok((symbol_table.unwrap(), struct_graph.unwrap(), call_graph.unwrap()))
+    }
 
-        self.static_single_assignment_pass(&st)?;
+    /// Runs the passes between parsing and code generation up to and including `last_pass`, e.g. `flattening`,
+    /// so that the AST it produces can be inspected.
+    pub fn compiler_stages_until(&mut self, last_pass: &str) -> Result<()> {
+        self.run_pipeline(Some(last_pass), |_, _| {}).map(|_| ())
+    }
 
-        self.flattening_pass(&st)?;
+    /// Runs the pipeline, stopping after `last_pass` if it is given, and returns the analyses it provided.
+    fn run_pipeline(
+        &mut self,
+        last_pass: Option<&str>,
+        mut inspect: impl FnMut(&Ast, &'static str) + 'static,
+    ) -> Result<(Option<SymbolTable>, Option<StructGraph>, Option<CallGraph>)> {
+        let mut pipeline = Self::pipeline().set_enabled("panic_audit", self.compiler_options.output.panic_audit);
+        for &pass in &self.disabled_passes {
+            pipeline = pipeline.disable(pass);
+        }
+        if let Some(last_pass) = last_pass {
+            pipeline = pipeline.stop_after(last_pass);
+        }
+        if self.compiler_options.output.stats {
+            pipeline = pipeline.before_each(|_, _| reset_peak_memory()).after_each(|state, timing| {
+                state.compiler.stats.record(timing.pass, timing.duration, &state.compiler.ast)
+            });
+        }
+
+        pipeline = pipeline.after_each(move |state, timing| inspect(&state.compiler.ast, timing.pass));
+
+        let mut state = PipelineState { compiler: self, symbol_table: None, struct_graph: None, call_graph: None };
+        let result = pipeline.run(&mut state);
+        let PipelineState { compiler, symbol_table, struct_graph, call_graph } = state;
+        compiler.pass_timings = pipeline.timings().to_vec();
+        result?;
+        Ok((symbol_table, struct_graph, call_graph))
+    }
 
-        self.destructuring_pass()?;
+    /// Returns the pipeline of the passes between parsing and code generation.
+    fn pipeline<'c>() -> PassManager<PipelineState<'c, 'a>> {
+        use Analysis::*;
+        PassManager::default()
+            .pass(
+                PassDescriptor::new("symbol_table_creation", |state: &mut PipelineState| {
+                    state.symbol_table = Some(state.compiler.symbol_table_pass()?);
+                    Ok(())
+                })
+                .provides([SymbolTable]),
+            )
+            .pass(
+                PassDescriptor::new("type_checking", |state: &mut PipelineState| {
+                    let (st, struct_graph, call_graph) =
+                        state.compiler.type_checker_pass(state.symbol_table.take().unwrap())?;
+                    state.symbol_table = Some(st);
+                    state.struct_graph = Some(struct_graph);
+                    state.call_graph = Some(call_graph);
+                    Ok(())
+                })
+                .requires([SymbolTable])
+                .provides([TypeTable, StructGraph, CallGraph]),
+            )
+            .pass(
+                PassDescriptor::new("pattern_lowering", |state: &mut PipelineState| {
+                    state.compiler.pattern_lowering_pass();
+                    Ok(())
+                })
+                .requires([TypeTable])
+                .after(["type_checking"]),
+            )
+            .pass(
+                PassDescriptor::new("enum_lowering", |state: &mut PipelineState| {
+                    let st = state.compiler.enum_lowering_pass(state.symbol_table.take().unwrap());
+                    state.symbol_table = Some(st);
+                    Ok(())
+                })
+                .requires([SymbolTable, TypeTable])
+                .after(["pattern_lowering"]),
+            )
+            .pass(
+                PassDescriptor::new("definite_assignment", |state: &mut PipelineState| {
+                    state.compiler.definite_assignment_pass()
+                })
+                .after(["pattern_lowering"]),
+            )
+            .pass(
+                PassDescriptor::new("entry_point_validation", |state: &mut PipelineState| {
+                    state.compiler.entry_point_validation_pass(state.symbol_table.as_ref().unwrap())
+                })
+                .requires([SymbolTable])
+                .after(["type_checking"]),
+            )
+            .pass(
+                PassDescriptor::new("information_flow", |state: &mut PipelineState| {
+                    state.compiler.information_flow_pass(state.symbol_table.as_ref().unwrap())
+                })
+                .requires([SymbolTable])
+                .after(["type_checking"]),
+            )
+            .pass(
+                PassDescriptor::new("record_linearity", |state: &mut PipelineState| {
+                    state.compiler.record_linearity_pass(state.symbol_table.as_ref().unwrap())
+                })
+                .requires([SymbolTable, TypeTable])
+                .after(["pattern_lowering"]),
+            )
+            .pass(
+                PassDescriptor::new("signedness_checking", |state: &mut PipelineState| {
+                    state.compiler.signedness_checking_pass()
+                })
+                .requires([TypeTable])
+                .after(["type_checking"]),
+            )
+            .pass(
+                PassDescriptor::new("unused_checking", |state: &mut PipelineState| {
+                    state.compiler.unused_checking_pass(state.symbol_table.as_ref().unwrap());
+                    Ok(())
+                })
+                .optional()
+                .requires([SymbolTable])
+                .after(["type_checking"]),
+            )
+            .pass(
+                PassDescriptor::new("typed_lowering", |state: &mut PipelineState| {
+                    state.compiler.typed_lowering_pass()
+                })
+                .requires([TypeTable])
+                .after(["pattern_lowering"]),
+            )
+            .pass(
+                PassDescriptor::new("panic_audit", |state: &mut PipelineState| {
+                    state.compiler.panic_audit_pass().map(|_| ())
+                })
+                .optional()
+                .requires([TypeTable]),
+            )
+            .pass(
+                PassDescriptor::new("target_checking", |state: &mut PipelineState| {
+                    state.compiler.target_checking_pass()
+                })
+                .requires([TypeTable]),
+            )
+            .pass(
+                PassDescriptor::new("method_lowering", |state: &mut PipelineState| {
+                    state.compiler.method_lowering_pass()
+                })
+                .requires([TypeTable])
+                .after(["target_checking"]),
+            )
+            .pass(
+                PassDescriptor::new("loop_unrolling", |state: &mut PipelineState| {
+                    let st = state.compiler.loop_unrolling_pass(state.symbol_table.take().unwrap())?;
+                    state.symbol_table = Some(st);
+                    Ok(())
+                })
+                .requires([SymbolTable, TypeTable])
+                .after(["method_lowering"]),
+            )
+            .pass(
+                PassDescriptor::new("static_single_assignment", |state: &mut PipelineState| {
+                    state.compiler.static_single_assignment_pass(state.symbol_table.as_ref().unwrap())
+                })
+                .requires([SymbolTable, TypeTable])
+                .after(["loop_unrolling"]),
+            )
+            .pass(
+                PassDescriptor::new("flattening", |state: &mut PipelineState| {
+                    state.compiler.flattening_pass(state.symbol_table.as_ref().unwrap())
+                })
+                .requires([SymbolTable, TypeTable])
+                .after(["static_single_assignment"]),
+            )
+            .pass(
+                PassDescriptor::new("destructuring", |state: &mut PipelineState| state.compiler.destructuring_pass())
+                    .requires([TypeTable])
+                    .after(["flattening"]),
+            )
+            .pass(
+                PassDescriptor::new("function_inlining", |state: &mut PipelineState| {
+                    state.compiler.function_inlining_pass(state.call_graph.as_ref().unwrap())
+                })
+                .requires([CallGraph, TypeTable])
+                .after(["destructuring"]),
+            )
+            .pass(
+                PassDescriptor::new("dead_code_elimination", |state: &mut PipelineState| {
+                    state.compiler.dead_code_elimination_pass()
+                })
+                .optional()
+                .after(["function_inlining"]),
+            )
+            .pass(
+                PassDescriptor::new("cost_estimation", |state: &mut PipelineState| {
+                    state.compiler.cost_estimation_pass()
+                })
+                .optional()
+                .after(["function_inlining"]),
+            )
+            .pass(
+                PassDescriptor::new("item_stripping", |state: &mut PipelineState| state.compiler.item_stripping_pass())
+                    .optional()
+                    .after(["function_inlining"]),
+            )
+    }
 
-        self.function_inlining_pass(&call_graph)?;
+    /// Returns the time that each pass between parsing and code generation took in the last compilation.
+    pub fn pass_timings(&self) -> &[PassTiming] {
+        &self.pass_timings
+    }
 
-        self.dead_code_elimination_pass()?;
+    /// Returns the statistics of each phase of compilation, which are empty unless enabled by the output options.
+    pub fn stats(&self) -> &CompilationStats {
+        &self.stats
+    }
 
-        Ok((st, struct_graph, call_graph))
+    /// Runs the phase `phase`, recording its statistics if they are enabled.
+    fn record_phase<T>(&mut self, phase: &str, run: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        if !self.compiler_options.output.stats {
+            return run(self);
+        }
+        reset_peak_memory();
+        let start = Instant::now();
+        let output = run(self)?;
+        self.stats.record(phase, start.elapsed(), &self.ast);
+        Ok(output)
+    }
+
+    /// Parses the main program file, and returns its AST.
+    pub fn parse(&mut self) -> Result<&Ast> {
+        self.parse_program()?;
+        Ok(&self.ast)
+    }
+
+    /// Parses the program, and runs the passes that check it, but not those that transform it for code generation.
+    pub fn check(&mut self) -> Result<CheckedProgram> {
+        self.parse_program()?;
+        self.check_passes()
+    }
+
+    /// Runs the passes that check the program, which must be parsed and prepared by the front end passes.
+    pub fn check_passes(&mut self) -> Result<CheckedProgram> {
+        // The checks end with the lints, unless they are disabled.
+        let last_pass = match self.disabled_passes.contains(&"unused_checking") {
+            true => "signedness_checking",
+            false => "unused_checking",
+        };
+        let (symbol_table, struct_graph, call_graph) = self.run_pipeline(Some(last_pass), |_, _| {})?;
+        Ok(CheckedProgram {
+            symbol_table: symbol_table.unwrap(),
+            struct_graph: struct_graph.unwrap(),
+            call_graph: call_graph.unwrap(),
+        })
     }
 
     /// Returns a compiled Leo program.
-    pub fn compile(&mut self) -> Result<(SymbolTable, String)> {
+    pub fn compile(&mut self) -> Result<CompiledInstructions> {
         // Parse the program.
         self.parse_program()?;
+        // Persist the reference index for tooling, e.g. the language server, if there is an output directory.
+        if self.output_directory.is_dir() {
+            self.reference_indexing_pass()?;
+        }
         // Run the intermediate compiler stages.
         let (symbol_table, struct_graph, call_graph) = self.compiler_stages()?;
         // Run code generation.
-        let bytecode = self.code_generation_pass(&symbol_table, &struct_graph, &call_graph)?;
-        Ok((symbol_table, bytecode))
+        let instructions = self.record_phase("code_generation", |compiler| {
+            compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph)
+        })?;
+        Ok(CompiledInstructions { symbol_table, instructions })
+    }
+
+    /// Parses and type checks the program, skipping the functions that are unchanged since they were checked with
+    /// `cache`. This lets tooling that re-checks a program on every edit, e.g. in watch mode, only check what changed.
+    /// Note that no code is generated, since the type table is incomplete for the functions that are skipped.
+    pub fn check_incremental(&mut self, cache: &TypeCheckCache) -> Result<()> {
+        // Parse the program.
+        self.parse_program()?;
+        // Construct the symbol table and type check the program.
+        let symbol_table = self.symbol_table_pass()?;
+        TypeChecker::new(symbol_table, &self.type_table, self.handler)
+            .with_cache(cache)
+            .with_core_functions(&self.core_functions)
+            .run(&self.ast)?;
+        Ok(())
+    }
+
+    /// Builds the program, and rebuilds it whenever one of the Leo files in `paths`, which are files or directories,
+    /// is added, changed, or removed. Changes are detected through filesystem notifications, or by polling the files
+    /// every [`WATCH_INTERVAL`](crate::WATCH_INTERVAL) if notifications are unavailable, and a file only counts as
+    /// changed if its contents did.
+    /// `callback` is called with the diagnostics of each build, rather than the handler of the compiler, and watching
+    /// stops once it returns `ControlFlow::Break`. Each build only parses the files that changed, since the programs
+    /// parsed from unchanged imports are reused, along with the symbol table of the imports if none of them changed.
+    /// The sources of each build are removed from the source map by the next one, except for those of the imports.
+    pub fn watch(&self, paths: &[PathBuf], mut callback: impl FnMut(&Rebuild) -> ControlFlow<()>) {
+        create_session_if_not_set_then(|_| {
+            let mut watcher = FileWatcher::new(paths.to_vec());
+            // The node builder is shared by the builds, so that the `NodeID`s of the reused imports stay unique.
+            let mut node_builder = self.node_builder.clone();
+            let mut import_resolver = self.import_resolver.clone().with_import_cache();
+            let mut symbol_table_cache = Some(SymbolTableCache::default());
+            // The sources registered before watching began are kept.
+            let preexisting = with_session_globals(|s| s.source_map.len());
+            let mut changed = watcher.changes();
+            loop {
+                if !changed.is_empty() {
+                    let mut index = 0;
+                    with_session_globals(|s| {
+                        s.source_map.retain(|file| {
+                            index += 1;
+                            index <= preexisting || import_resolver.is_cached_source(file)
+                        })
+                    });
+                    let (handler, buffer) = Handler::new_with_buf();
+                    let mut compiler = self.rebuild(&handler, node_builder, import_resolver, symbol_table_cache);
+                    let result = compiler.compile().map(|compiled| compiled.instructions);
+                    node_builder = compiler.node_builder;
+                    import_resolver = compiler.import_resolver;
+                    symbol_table_cache = compiler.symbol_table_cache;
+                    if callback(&Rebuild::new(changed, &buffer, result)).is_break() {
+                        return;
+                    }
+                }
+                changed = watcher.wait_for_changes();
+            }
+        })
+    }
+
+    /// Returns a compiler configured like this one for another build, which emits its diagnostics to `handler`.
+    fn rebuild<'b>(
+        &self,
+        handler: &'b Handler,
+        node_builder: NodeBuilder,
+        import_resolver: ImportResolver,
+        symbol_table_cache: Option<SymbolTableCache>,
+    ) -> Compiler<'b> {
+        Compiler {
+            handler,
+            main_file_path: self.main_file_path.clone(),
+            output_directory: self.output_directory.clone(),
+            program_name: self.program_name.clone(),
+            network: self.network.clone(),
+            ast: Ast::new(Program::default()),
+            input_ast: None,
+            compiler_options: self.compiler_options.clone(),
+            node_builder,
+            assigner: self.assigner.clone(),
+            type_table: TypeTable::default(),
+            constants: ConstantEnvironment::default(),
+            typed_program: TypedProgram::default(),
+            import_resolver,
+            symbol_table_cache,
+            core_functions: self.core_functions.clone(),
+            disabled_passes: self.disabled_passes.clone(),
+            pass_timings: Vec::new(),
+            stats: CompilationStats::default(),
+            constraint_estimate: ConstraintEstimate::default(),
+        }
+    }
+
+    /// Parses and type checks the program with its test functions, and runs each function annotated with `@test`
+    /// through the interpreter. A failed test is reported, with the span of the assertion that failed, rather than
+    /// returned as an error.
+    pub fn run_tests(&mut self) -> Result<TestReport> {
+        // Keep the test functions when parsing the program.
+        self.compiler_options.build.tests_enabled = true;
+        self.parse_program()?;
+        let symbol_table = self.symbol_table_pass()?;
+        self.type_checker_pass(symbol_table)?;
+        self.definite_assignment_pass()?;
+        // Note that parsing enforces that there is exactly one program scope in a file.
+        let program_scope = self.ast.ast.program_scopes.values().next().unwrap();
+        Ok(TestRunner::run(
+            program_scope,
+            &self.type_table,
+            &self.constants,
+            self.compiler_options.build.interpreter_limits,
+        ))
+    }
+
+    /// Returns the types inferred by the type checker, keyed by the IDs of the AST nodes they were inferred for.
+    /// The table is empty until the type checker has run, and is not updated by the passes that transform the AST.
+    pub fn type_table(&self) -> &TypeTable {
+        &self.type_table
+    }
+
+    /// Returns the values of the constants declared in the program scope.
+    /// The environment is empty until the program is parsed, since the constants are evaluated right after parsing.
+    pub fn constants(&self) -> &ConstantEnvironment {
+        &self.constants
+    }
+
+    /// Returns the typed IR lowered from the AST after type checking.
+    /// The typed IR is empty until the typed lowering pass has run.
+    pub fn typed_program(&self) -> &TypedProgram {
+        &self.typed_program
+    }
+
+    /// Returns the estimated number of constraints of each function.
+    /// The estimate is empty unless it is enabled by the output options.
+    pub fn constraint_estimate(&self) -> &ConstraintEstimate {
+        &self.constraint_estimate
+    }
+
+    /// Returns the trace of import resolution, which is empty unless it is enabled by the output options.
+    pub fn import_trace(&self) -> &ImportTrace {
+        self.import_resolver.import_trace()
+    }
+
+    /// Writes the import trace to a JSON file, if it is enabled.
+    fn write_import_trace(&self) -> Result<()> {
+        let import_trace = self.import_resolver.import_trace();
+        if import_trace.is_enabled() {
+            import_trace
+                .to_json_file(self.output_directory.clone(), &format!("{}.import_trace.json", self.program_name))?;
+        }
+        Ok(())
     }
 
     /// Writes the AST to a JSON file.
     fn write_ast_to_json(&self, file_suffix: &str) -> Result<()> {
         // Remove `Span`s if they are not enabled.
-        if self.compiler_options.output.ast_spans_enabled {
+        if self.compiler_options.output.canonical_snapshots {
+            let strip_spans = !self.compiler_options.output.ast_spans_enabled;
+            self.ast.to_canonical_json_file(
+                self.output_directory.clone(),
+                &format!("{}.{file_suffix}", self.program_name),
+                strip_spans,
+            )?;
+        } else if self.compiler_options.output.ast_spans_enabled {
             self.ast.to_json_file(self.output_directory.clone(), &format!("{}.{file_suffix}", self.program_name))?;
         } else {
             self.ast.to_json_file_without_keys(
@@ -349,7 +1145,14 @@ impl<'a> Compiler<'a> {
     /// Writes the Symbol Table to a JSON file.
     fn write_symbol_table_to_json(&self, file_suffix: &str, symbol_table: &SymbolTable) -> Result<()> {
         // Remove `Span`s if they are not enabled.
-        if self.compiler_options.output.symbol_table_spans_enabled {
+        if self.compiler_options.output.canonical_snapshots {
+            let strip_spans = !self.compiler_options.output.symbol_table_spans_enabled;
+            symbol_table.to_canonical_json_file(
+                self.output_directory.clone(),
+                &format!("{}.{file_suffix}", self.program_name),
+                strip_spans,
+            )?;
+        } else if self.compiler_options.output.symbol_table_spans_enabled {
             symbol_table
                 .to_json_file(self.output_directory.clone(), &format!("{}.{file_suffix}", self.program_name))?;
         } else {
@@ -19,8 +19,17 @@
 #![allow(clippy::upper_case_acronyms)]
 #![doc = include_str!("../README.md")]
 
+mod artifacts;
+pub use artifacts::*;
+
 mod compiler;
 pub use compiler::*;
 
 mod options;
 pub use options::*;
+
+mod stats;
+pub use stats::*;
+
+mod watch;
+pub use watch::*;
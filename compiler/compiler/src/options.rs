@@ -16,7 +16,16 @@
 
 // NOTE: If compiler passes are made optional, pass preconditions and invariants may not necessarily hold true.
 
-#[derive(Clone, Default)]
+use leo_errors::{CompilerError, Result};
+use leo_passes::{Edition, InterpreterLimits};
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// The options configuring compilation.
+/// The options can be written as TOML or JSON, in which case omitted options take their default values.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct CompilerOptions {
     /// Build options.
     pub build: BuildOptions,
@@ -24,13 +33,101 @@ pub struct CompilerOptions {
     pub output: OutputOptions,
 }
 
-#[derive(Clone, Default)]
+impl CompilerOptions {
+    /// Deserializes the options from a TOML string.
+    pub fn from_toml_string(toml: &str) -> Result<Self> {
+        Ok(toml::from_str(toml).map_err(CompilerError::failed_to_parse_compiler_options)?)
+    }
+
+    /// Serializes the options into a TOML string.
+    pub fn to_toml_string(&self) -> Result<String> {
+        Ok(toml::to_string(self).map_err(CompilerError::failed_to_serialize_compiler_options)?)
+    }
+
+    /// Deserializes the options from a JSON string.
+    pub fn from_json_string(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json).map_err(CompilerError::failed_to_parse_compiler_options)?)
+    }
+
+    /// Serializes the options into a JSON string.
+    pub fn to_json_string(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self).map_err(CompilerError::failed_to_serialize_compiler_options)?)
+    }
+
+    /// Reads the options from a file, which is parsed as JSON if it has a `.json` extension and as TOML otherwise.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path).map_err(|e| CompilerError::file_read_error(path, e))?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_json_string(&contents),
+            _ => Self::from_toml_string(&contents),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct BuildOptions {
     /// Whether to enable dead code elimination.
     pub dce_enabled: bool,
+    /// The maximum number of instructions a transition may be estimated to execute, if any.
+    pub instruction_budget: Option<u64>,
+    /// Whether to strip the functions, structs, and constants that are unreachable from the transitions.
+    pub strip_enabled: bool,
+    /// Whether to keep the functions annotated with `@test`, which are otherwise removed after parsing.
+    pub tests_enabled: bool,
+    /// Whether to warn of the variables and imports that are never used.
+    pub lints_enabled: bool,
+    /// The directories searched for imported programs after the `imports` directory, in order.
+    pub import_paths: Vec<PathBuf>,
+    /// The resources that each run of the interpreter may use, e.g. when generating test vector fixtures.
+    pub interpreter_limits: InterpreterLimits,
+    /// The limits on the size of the programs that are compiled.
+    pub program_limits: ProgramLimits,
+    /// The edition of the language that the programs target, which is the latest edition by default.
+    /// Features added in later editions are rejected.
+    pub language_edition: Edition,
+}
+
+impl BuildOptions {
+    /// Enables the optional passes that optimize the generated program at `opt_level`, and disables the others.
+    pub fn set_opt_level(&mut self, opt_level: OptLevel) {
+        self.dce_enabled = opt_level >= OptLevel::DeadCode;
+        self.strip_enabled = opt_level >= OptLevel::Size;
+    }
+}
+
+/// How much the optional passes optimize the generated program.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OptLevel {
+    /// The program is not optimized.
+    #[default]
+    None,
+    /// The dead code in each function is eliminated.
+    DeadCode,
+    /// The dead code is eliminated, and the items that are unreachable from the transitions are stripped.
+    Size,
+}
+
+/// The limits on the size of the programs that are compiled, which are checked when the programs are parsed.
+/// A program beyond them is rejected with an error, rather than exhausting the stack or memory of the compiler.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProgramLimits {
+    /// The maximum depth that expressions, blocks, and types may be nested to, in every parsed file.
+    pub max_depth: usize,
+    /// The maximum number of AST nodes of the program, including those of its imports, if any.
+    pub max_nodes: Option<usize>,
+}
+
+impl Default for ProgramLimits {
+    /// By default, programs may be nested as deep as the recoverable parser allows, and may be of any size.
+    fn default() -> Self {
+        Self { max_depth: leo_parser::RECOVERABLE_MAX_DEPTH, max_nodes: None }
+    }
 }
 
-#[derive(Clone, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct OutputOptions {
     //// Whether spans are enabled in the output symbol tables.
     pub symbol_table_spans_enabled: bool,
@@ -40,22 +137,122 @@ pub struct OutputOptions {
     pub type_checked_symbol_table: bool,
     /// If enabled writes the symbol table after loop unrolling.
     pub unrolled_symbol_table: bool,
+    /// If enabled writes the functions, structs, and constants of the program after type checking, in a stable schema.
+    pub symbol_table_export: bool,
     /// Whether spans are enabled in the output ASTs.
     pub ast_spans_enabled: bool,
-    /// If enabled writes the AST after parsing.
-    pub initial_ast: bool,
     /// If enabled writes the input AST after parsing.
     pub initial_input_ast: bool,
+    /// If enabled writes the AST and symbol table snapshots in canonical form, with sorted keys.
+    pub canonical_snapshots: bool,
+    /// If enabled writes the panic audit report after type checking.
+    pub panic_audit: bool,
+    /// If enabled records the steps of import resolution, and writes them after parsing and symbol table creation.
+    pub import_trace: bool,
+    /// If enabled writes a source map from the generated instructions to the Leo source.
+    pub source_map: bool,
+    /// If enabled records the time, AST node count, and peak memory of each phase of compilation.
+    pub stats: bool,
+    /// If enabled estimates the number of constraints of each function after dead code elimination.
+    pub constraint_estimate: bool,
+    /// The AST snapshots to write at each pass boundary.
+    pub ast_snapshots: AstSnapshotOptions,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AstSnapshotOptions {
+    /// If enabled writes the AST after parsing.
+    pub initial: bool,
+    /// If enabled writes the AST after type checking.
+    pub type_checked: bool,
+    /// If enabled writes the typed IR lowered from the AST after type checking.
+    pub typed_ir: bool,
     /// If enabled writes the AST after loop unrolling.
-    pub unrolled_ast: bool,
+    pub unrolled: bool,
     /// If enabled writes the AST after static single assignment.
-    pub ssa_ast: bool,
+    pub ssa: bool,
     /// If enabled writes the AST after flattening.
-    pub flattened_ast: bool,
+    pub flattened: bool,
     /// If enabled writes the AST after destructuring.
-    pub destructured_ast: bool,
+    pub destructured: bool,
     /// If enabled writes the AST after inlining.
-    pub inlined_ast: bool,
+    pub inlined: bool,
     /// If enabled writes the AST after dead code elimination.
-    pub dce_ast: bool,
+    pub dce: bool,
+}
+
+impl AstSnapshotOptions {
+    /// Returns options that write an AST snapshot after every pass.
+    pub fn all() -> Self {
+        Self {
+            initial: true,
+            type_checked: true,
+            typed_ir: true,
+            unrolled: true,
+            ssa: true,
+            flattened: true,
+            destructured: true,
+            inlined: true,
+            dce: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_partial_toml_uses_defaults() {
+        let options = CompilerOptions::from_toml_string(
+            "[build]\ndce_enabled = true\n\n[output.ast_snapshots]\ninitial = true\n",
+        )
+        .unwrap();
+        assert!(options.build.dce_enabled);
+        assert_eq!(options.build.instruction_budget, None);
+        assert!(!options.build.strip_enabled);
+        assert!(!options.build.tests_enabled);
+        assert_eq!(options.build.program_limits, ProgramLimits::default());
+        assert_eq!(options.build.language_edition, Edition::Edition2024);
+        assert!(options.output.ast_snapshots.initial);
+        assert!(!options.output.ast_snapshots.dce);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut options = CompilerOptions::default();
+        options.build.instruction_budget = Some(100);
+        options.build.interpreter_limits.max_steps = Some(1000);
+        options.build.program_limits.max_nodes = Some(10_000);
+        options.build.language_edition = Edition::Edition2022;
+        options.output.ast_snapshots = AstSnapshotOptions::all();
+
+        assert_eq!(CompilerOptions::from_toml_string(&options.to_toml_string().unwrap()).unwrap(), options);
+        assert_eq!(CompilerOptions::from_json_string(&options.to_json_string().unwrap()).unwrap(), options);
+    }
+
+    #[test]
+    fn test_language_edition() {
+        let options = CompilerOptions::from_toml_string("[build]\nlanguage_edition = \"2023\"\n").unwrap();
+        assert_eq!(options.build.language_edition, Edition::Edition2023);
+        assert!(CompilerOptions::from_toml_string("[build]\nlanguage_edition = \"2021\"\n").is_err());
+    }
+
+    #[test]
+    fn test_opt_level() {
+        let mut options = BuildOptions { strip_enabled: true, ..Default::default() };
+        options.set_opt_level(OptLevel::DeadCode);
+        assert!(options.dce_enabled);
+        assert!(!options.strip_enabled);
+        options.set_opt_level(OptLevel::Size);
+        assert!(options.dce_enabled && options.strip_enabled);
+        options.set_opt_level(OptLevel::None);
+        assert!(!options.dce_enabled && !options.strip_enabled);
+    }
+
+    #[test]
+    fn test_unknown_option() {
+        assert!(CompilerOptions::from_toml_string("[build]\ndce = 1\n").is_err());
+    }
 }
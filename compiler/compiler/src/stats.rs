@@ -0,0 +1,251 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{CompilerError, Result};
+
+use serde::{Deserialize, Serialize};
+use std::{fmt, time::Duration};
+
+/// The statistics of a phase of compilation, e.g. parsing or a pass.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PhaseStats {
+    /// The name of the phase.
+    pub phase: String,
+    /// The wall-clock time that the phase took, in milliseconds.
+    pub time_ms: f64,
+    /// The number of items, statements, and expressions in the AST after the phase.
+    pub ast_nodes: usize,
+    /// The peak resident memory of the compiler during the phase in bytes, if the platform reports it.
+    pub peak_memory: Option<u64>,
+}
+
+/// The statistics of each phase of compilation, in the order that the phases ran in.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CompilationStats {
+    pub phases: Vec<PhaseStats>,
+}
+
+impl CompilationStats {
+    /// Records the statistics of the phase `phase`, which took `duration` and left `ast`.
+    /// The peak memory is that since the last call to `reset_peak_memory`.
+    pub(crate) fn record(&mut self, phase: &str, duration: Duration, ast: &Ast) {
+        self.phases.push(PhaseStats {
+            phase: phase.to_string(),
+            time_ms: duration.as_secs_f64() * 1000.0,
+            ast_nodes: ast_nodes(ast),
+            peak_memory: peak_memory(),
+        });
+    }
+
+    /// Returns the total wall-clock time of the phases, in milliseconds.
+    pub fn total_time_ms(&self) -> f64 {
+        self.phases.iter().map(|phase| phase.time_ms).sum()
+    }
+
+    /// Serializes the statistics into a JSON string.
+    pub fn to_json_string(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self).map_err(CompilerError::failed_to_serialize_stats)?)
+    }
+}
+
+impl fmt::Display for CompilationStats {
+    /// Formats the statistics as a table, with a row for each phase.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self.phases.iter().map(|phase| phase.phase.len()).chain(["total".len()]).max().unwrap_or_default();
+        writeln!(f, "{:width$}  {:>12}  {:>10}  {:>17}", "phase", "time (ms)", "AST nodes", "peak memory (KiB)")?;
+        for phase in &self.phases {
+            let peak_memory = phase.peak_memory.map_or("-".to_string(), |bytes| (bytes / 1024).to_string());
+            writeln!(
+                f,
+                "{:width$}  {:>12.3}  {:>10}  {:>17}",
+                phase.phase, phase.time_ms, phase.ast_nodes, peak_memory
+            )?;
+        }
+        write!(f, "{:width$}  {:>12.3}", "total", self.total_time_ms())
+    }
+}
+
+/// Returns the number of items, statements, and expressions in `ast`, including those of its imports.
+pub(crate) fn ast_nodes(ast: &Ast) -> usize {
+    let mut counter = NodeCounter::default();
+    counter.visit_program(ast.as_repr());
+    counter.nodes
+}
+
+/// Counts the items, statements, and expressions of a program.
+#[derive(Default)]
+struct NodeCounter {
+    nodes: usize,
+}
+
+impl<'a> ExpressionVisitor<'a> for NodeCounter {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_expression(&mut self, input: &'a Expression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.nodes += 1;
+        match input {
+            Expression::Access(access) => self.visit_access(access, additional),
+            Expression::Array(array) => self.visit_array(array, additional),
+            Expression::Binary(binary) => self.visit_binary(binary, additional),
+            Expression::Block(block) => self.visit_block_expression(block, additional),
+            Expression::Call(call) => self.visit_call(call, additional),
+            Expression::Cast(cast) => self.visit_cast(cast, additional),
+            Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
+            Expression::Err(err) => self.visit_err(err, additional),
+            Expression::Identifier(identifier) => self.visit_identifier(identifier, additional),
+            Expression::Literal(literal) => self.visit_literal(literal, additional),
+            Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
+            Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
+            Expression::Unary(unary) => self.visit_unary(unary, additional),
+            Expression::Unit(unit) => self.visit_unit(unit, additional),
+        }
+    }
+
+    fn visit_block_expression(&mut self, input: &'a BlockExpression, additional: &Self::AdditionalInput) {
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.visit_expression(&input.value, additional);
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) {
+        self.visit_expression(&input.function, additional);
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+        if let Some(external) = &input.external {
+            self.visit_expression(external, additional);
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) {
+        for member in &input.members {
+            if let Some(expression) = &member.expression {
+                self.visit_expression(expression, additional);
+            }
+        }
+    }
+
+    fn visit_err(&mut self, _input: &'a ErrExpression, _additional: &Self::AdditionalInput) {}
+}
+
+impl<'a> StatementVisitor<'a> for NodeCounter {
+    fn visit_statement(&mut self, input: &'a Statement) {
+        self.nodes += 1;
+        match input {
+            Statement::Assert(stmt) => self.visit_assert(stmt),
+            Statement::Assign(stmt) => self.visit_assign(stmt),
+            Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Conditional(stmt) => self.visit_conditional(stmt),
+            Statement::Console(stmt) => self.visit_console(stmt),
+            Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Declaration(stmt) => self.visit_declaration(stmt),
+            Statement::Definition(stmt) => self.visit_definition(stmt),
+            Statement::Err(stmt) => self.visit_err_statement(stmt),
+            Statement::Expression(stmt) => self.visit_expression_statement(stmt),
+            Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(stmt) => self.visit_match(stmt),
+            Statement::Return(stmt) => self.visit_return(stmt),
+        }
+    }
+
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.place, &());
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.visit_expression(&input.place, &());
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_err_statement(&mut self, _input: &'a ErrStatement) {}
+}
+
+impl<'a> ProgramVisitor<'a> for NodeCounter {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.nodes += 1 + input.modules.len() + input.interfaces.len() + input.enums.len() + input.mappings.len();
+        input.structs.iter().for_each(|(_, struct_)| self.visit_struct(struct_));
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+        // The constants of a program scope are not statements, so they are counted here.
+        self.nodes += input.consts.len();
+        input.consts.iter().for_each(|(_, declaration)| self.visit_const(declaration));
+    }
+
+    fn visit_struct(&mut self, input: &'a Struct) {
+        self.nodes += 1 + input.members.len();
+        input.methods.iter().for_each(|(_, method)| self.visit_function(method));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.nodes += 1;
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.nodes += 1;
+            self.visit_block(&finalize.block);
+        }
+    }
+}
+
+/// Resets the peak resident memory of the process, which is only supported on Linux.
+pub(crate) fn reset_peak_memory() {
+    let _ = std::fs::write("/proc/self/clear_refs", "5");
+}
+
+/// Returns the peak resident memory of the process in bytes, which is only reported on Linux.
+fn peak_memory() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kilobytes = status.lines().find_map(|line| line.strip_prefix("VmHWM:"))?.trim().strip_suffix("kB")?;
+    Some(kilobytes.trim().parse::<u64>().ok()? * 1024)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_ast_nodes() {
+        create_session_if_not_set_then(|_| {
+            let source = "program test.aleo {\n\
+                          const N: u8 = 1u8;\n\
+                          transition main(a: u8) -> u8 { let b: u8 = a + N; return b; }\n\
+                          }\n";
+            let ast = leo_parser::parse_ast(&Handler::default(), &NodeBuilder::default(), source, Default::default())
+                .unwrap();
+            // The program scope, the constant and its value, the function, the definition with its place and
+            // the three nodes of its value, and the return with its value.
+            assert_eq!(ast_nodes(&ast), 11);
+        })
+    }
+
+    #[test]
+    fn test_table() {
+        let stats = CompilationStats {
+            phases: vec![
+                PhaseStats { phase: "parsing".to_string(), time_ms: 1.5, ast_nodes: 10, peak_memory: Some(2048) },
+                PhaseStats { phase: "type_checking".to_string(), time_ms: 2.0, ast_nodes: 10, peak_memory: None },
+            ],
+        };
+        let table = stats.to_string();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].starts_with("parsing ") && lines[1].ends_with(" 2"), "{table}");
+        assert!(lines[2].ends_with(" -"), "{table}");
+        assert!(lines[3].starts_with("total") && lines[3].ends_with("3.500"), "{table}");
+        assert_eq!(serde_json::from_str::<CompilationStats>(&stats.to_json_string().unwrap()).unwrap(), stats);
+    }
+}
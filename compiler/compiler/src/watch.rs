@@ -0,0 +1,239 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The building blocks of watch mode, in which a program is rebuilt whenever one of its files changes.
+
+use leo_errors::{emitter::BufferEmitter, LeoError, Result};
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::mpsc::{channel, Receiver},
+    thread,
+    time::Duration,
+};
+
+/// The interval at which the watched files are polled for changes if filesystem notifications are unavailable.
+pub const WATCH_INTERVAL: Duration = Duration::from_millis(200);
+
+/// The time to wait for further notifications after one arrives, so that a burst of events, e.g. an editor saving a
+/// file by truncating and then writing it, is handled as a single change.
+const DEBOUNCE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The outcome of a build in watch mode.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Rebuild {
+    /// The watched files that were added, changed, or removed since the previous build, i.e. all of them at first.
+    pub changed: Vec<PathBuf>,
+    /// The errors of the build, rendered with excerpts of the source code.
+    pub errors: Vec<String>,
+    /// The warnings of the build, rendered with excerpts of the source code.
+    pub warnings: Vec<String>,
+    /// The Aleo instructions of the program, if it compiled.
+    pub instructions: Option<String>,
+}
+
+impl Rebuild {
+    /// Returns the outcome of a build, given the diagnostics collected in `buffer` and the `result` of compilation.
+    /// The diagnostics are rendered here, since their source excerpts are only available in the session of the build.
+    pub(crate) fn new(changed: Vec<PathBuf>, buffer: &BufferEmitter, result: Result<String>) -> Self {
+        let mut errors: Vec<_> = buffer.extract_errs().into_inner().iter().map(ToString::to_string).collect();
+        let warnings = buffer.extract_warnings().into_inner().iter().map(ToString::to_string).collect();
+        let instructions = match result {
+            Ok(instructions) => Some(instructions),
+            // The error was already emitted.
+            Err(LeoError::LastErrorCode(_)) => None,
+            Err(err) => {
+                errors.push(err.to_string());
+                None
+            }
+        };
+        Self { changed, errors, warnings, instructions }
+    }
+}
+
+/// Watches files and directories for changes to the Leo files in them.
+/// The files are rescanned whenever the filesystem reports an event in the watched paths, and a file is only
+/// considered changed if its contents changed, so that e.g. writing the outputs of a build does not trigger another.
+pub(crate) struct FileWatcher {
+    /// The watched files and directories.
+    paths: Vec<PathBuf>,
+    /// The hash of the contents of each watched file, when it was last scanned.
+    files: BTreeMap<PathBuf, Vec<u8>>,
+    /// The watcher delivering filesystem notifications, along with the channel it sends them to.
+    /// If notifications are unavailable, e.g. since the limit of watched files is reached, the files are polled.
+    notifications: Option<(RecommendedWatcher, Receiver<notify::Result<notify::Event>>)>,
+}
+
+impl FileWatcher {
+    /// Returns a watcher of `paths`, which are files or directories, whose Leo files are watched recursively.
+    pub(crate) fn new(paths: Vec<PathBuf>) -> Self {
+        let (sender, receiver) = channel();
+        let notifications = notify::recommended_watcher(sender).ok().and_then(|mut watcher| {
+            for path in &paths {
+                watcher.watch(path, RecursiveMode::Recursive).ok()?;
+            }
+            Some((watcher, receiver))
+        });
+        Self { paths, files: BTreeMap::new(), notifications }
+    }
+
+    /// Returns the watched files that were added, changed, or removed since the last scan, i.e. all of them at first.
+    pub(crate) fn changes(&mut self) -> Vec<PathBuf> {
+        let mut files = BTreeMap::new();
+        for path in &self.paths {
+            collect_files(path, true, &mut files);
+        }
+
+        let mut changes: Vec<_> = files
+            .iter()
+            .filter(|(path, hash)| self.files.get(*path) != Some(hash))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changes.extend(self.files.keys().filter(|path| !files.contains_key(*path)).cloned());
+        self.files = files;
+        changes
+    }
+
+    /// Blocks until one of the watched files is added, changed, or removed, and returns the files that were.
+    pub(crate) fn wait_for_changes(&mut self) -> Vec<PathBuf> {
+        loop {
+            let notified = match &self.notifications {
+                Some((_, receiver)) => {
+                    let notified = receiver.recv().is_ok();
+                    while receiver.recv_timeout(DEBOUNCE_INTERVAL).is_ok() {}
+                    notified
+                }
+                None => {
+                    thread::sleep(WATCH_INTERVAL);
+                    true
+                }
+            };
+            // The files are polled from now on if the watcher stopped delivering notifications.
+            if !notified {
+                self.notifications = None;
+            }
+            let changes = self.changes();
+            if !changes.is_empty() {
+                return changes;
+            }
+        }
+    }
+}
+
+/// Records the hash of the contents of the file at `path`, or of the Leo files in the directory at `path`.
+/// A file given explicitly, i.e. not found in a directory, is watched even if it is not a Leo file.
+fn collect_files(path: &Path, explicit: bool, files: &mut BTreeMap<PathBuf, Vec<u8>>) {
+    if path.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            collect_files(&entry.path(), false, files);
+        }
+    } else if explicit || path.extension().map_or(false, |extension| extension == "leo") {
+        if let Ok(contents) = fs::read(path) {
+            files.insert(path.to_path_buf(), Sha256::digest(contents).to_vec());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Compiler, CompilerOptions};
+
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::with_session_globals;
+    use std::ops::ControlFlow;
+
+    #[test]
+    fn test_file_watcher_changes() {
+        let directory = std::env::temp_dir().join(format!("leo-file-watcher-{}", std::process::id()));
+        fs::create_dir_all(directory.join("imports")).unwrap();
+        let main = directory.join("main.leo");
+        let import = directory.join("imports/token.leo");
+        fs::write(&main, "program test.aleo {}").unwrap();
+        fs::write(&import, "program token.aleo {}").unwrap();
+        fs::write(directory.join("notes.txt"), "").unwrap();
+
+        let mut watcher = FileWatcher::new(vec![directory.clone()]);
+        let initial = watcher.changes();
+        let unchanged = watcher.changes();
+        fs::write(&import, "program token.aleo { }").unwrap();
+        let changed = watcher.changes();
+        fs::remove_file(&main).unwrap();
+        let removed = watcher.changes();
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(initial, [import.clone(), main.clone()]);
+        assert!(unchanged.is_empty());
+        assert_eq!(changed, [import]);
+        assert_eq!(removed, [main]);
+    }
+
+    const MAIN: &str = "import token.leo;\n\
+                        program test.aleo {\n\
+                        transition main(a: u8) -> u8 { return {value}; }\n\
+                        }\n";
+
+    #[test]
+    fn test_watch_rebuilds_changed_program() {
+        let directory = std::env::temp_dir().join(format!("leo-watch-{}", std::process::id()));
+        fs::create_dir_all(directory.join("imports")).unwrap();
+        let main = directory.join("main.leo");
+        fs::write(directory.join("imports/token.leo"), "program token.aleo {\n    transition t() {}\n}\n").unwrap();
+        fs::write(&main, MAIN.replace("{value}", "a")).unwrap();
+
+        let mut options = CompilerOptions::default();
+        options.build.import_paths = vec![directory.join("imports")];
+        let handler = Handler::default();
+        let compiler =
+            Compiler::new("test".into(), "aleo".into(), &handler, main.clone(), directory.clone(), Some(options));
+
+        let mut rebuilds = Vec::new();
+        let mut sources = Vec::new();
+        compiler.watch(std::slice::from_ref(&directory), |rebuild| {
+            rebuilds.push(rebuild.clone());
+            sources.push(with_session_globals(|s| s.source_map.len()));
+            match rebuilds.len() {
+                // Introduce an error, and then fix it again. Rewriting a file with the same contents is not a change.
+                1 => fs::write(&main, MAIN.replace("{value}", "b")).unwrap(),
+                2 => {
+                    fs::write(directory.join("imports/token.leo"), "program token.aleo {\n    transition t() {}\n}\n")
+                        .unwrap();
+                    fs::write(&main, MAIN.replace("{value}", "a")).unwrap()
+                }
+                _ => return ControlFlow::Break(()),
+            }
+            ControlFlow::Continue(())
+        });
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(rebuilds.len(), 3);
+        assert_eq!(rebuilds[0].changed.len(), 2);
+        assert!(rebuilds[0].errors.is_empty(), "{:?}", rebuilds[0].errors);
+        assert!(rebuilds[0].instructions.as_ref().unwrap().contains("import token.aleo;"));
+        assert_eq!(rebuilds[1].changed, std::slice::from_ref(&main));
+        assert!(rebuilds[1].instructions.is_none());
+        let errors = &rebuilds[1].errors;
+        assert!(errors.iter().any(|error| error.contains("Unknown variable `b`")), "{errors:?}");
+        assert_eq!(rebuilds[2].changed, [main]);
+        assert_eq!(rebuilds[2].instructions, rebuilds[0].instructions);
+        // Each build only keeps its own main file and the reused import in the source map.
+        assert_eq!(sources, [2, 2, 2]);
+    }
+}
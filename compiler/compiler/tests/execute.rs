@@ -21,6 +21,7 @@ use utilities::{
     dotenv_private_key,
     get_build_options,
     get_cwd_option,
+    get_import_paths,
     hash_asts,
     hash_content,
     hash_symbol_tables,
@@ -31,7 +32,7 @@ use utilities::{
     Network,
 };
 
-use leo_compiler::{CompilerOptions, OutputOptions};
+use leo_compiler::{AstSnapshotOptions, CompilerOptions, OutputOptions};
 use leo_errors::emitter::Handler;
 use leo_span::symbol::create_session_if_not_set_then;
 use leo_test_framework::{
@@ -91,12 +92,16 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
     // Check for CWD option:
     let cwd = get_cwd_option(&test);
 
+    // Check for an import directory option:
+    let import_paths = get_import_paths(&test);
+
     // Extract the compiler build configurations from the config file.
     let build_options = get_build_options(&test.config);
 
     let mut outputs = Vec::with_capacity(build_options.len());
 
-    for build in build_options {
+    for mut build in build_options {
+        build.import_paths = import_paths.clone();
         let compiler_options = CompilerOptions {
             build,
             output: OutputOptions {
@@ -104,21 +109,23 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
                 initial_symbol_table: true,
                 type_checked_symbol_table: true,
                 unrolled_symbol_table: true,
+                symbol_table_export: false,
                 ast_spans_enabled: false,
                 initial_input_ast: true,
-                initial_ast: true,
-                unrolled_ast: true,
-                ssa_ast: true,
-                flattened_ast: true,
-                destructured_ast: true,
-                inlined_ast: true,
-                dce_ast: true,
+                canonical_snapshots: false,
+                panic_audit: false,
+                import_trace: false,
+                source_map: false,
+                stats: false,
+                constraint_estimate: false,
+                ast_snapshots: AstSnapshotOptions::all(),
             },
         };
 
         // Parse the program.
-        let mut parsed =
-            handler.extend_if_error(parse_program(handler, &test.content, cwd.clone(), Some(compiler_options)))?;
+        // The syntax errors the parser recovers from are reported with the errors of the later passes.
+        let mut parsed = parse_program(handler, &test.content, cwd.clone(), Some(compiler_options))
+            .map_err(|err| handler.emit_err(err))?;
 
         // Compile the program to bytecode.
         let program_name = format!("{}.{}", parsed.program_name, parsed.network);
@@ -137,7 +144,7 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
         let mut results = BTreeMap::new();
 
         // Setup the build directory.
-        let package = setup_build_directory(&program_name, &bytecode, handler)?;
+        let package = setup_build_directory(&program_name, &bytecode, &import_paths, handler)?;
 
         // Initialize an rng.
         let rng = &mut TestRng::default();
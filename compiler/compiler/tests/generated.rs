@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Property-based tests of the passes on randomly generated, well-typed programs.
+//!
+//! Each generated program is run through the pipeline up to every pass that rewrites the AST, after which the
+//! type checker is run again on the result, which must still be well typed. The passes from static single
+//! assignment onwards introduce variables that are not declared in Leo, so their output is not checked again;
+//! instead, the program must compile to instructions.
+
+mod utilities;
+use utilities::{parse_program, BufferEmitter};
+
+use leo_compiler::Compiler;
+use leo_errors::{emitter::Handler, LeoError};
+use leo_span::symbol::create_session_if_not_set_then;
+use leo_test_framework::generator::ProgramGenerator;
+
+use std::rc::Rc;
+
+/// The number of generated programs that are checked.
+const GENERATED_PROGRAMS: u64 = 100;
+
+/// The passes after which the AST is type checked again.
+const PASSES: &[&str] = &[
+    "symbol_table_creation",
+    "type_checking",
+    "pattern_lowering",
+    "enum_lowering",
+    "definite_assignment",
+    "entry_point_validation",
+    "information_flow",
+    "record_linearity",
+    "signedness_checking",
+    "typed_lowering",
+    "target_checking",
+    "method_lowering",
+    "loop_unrolling",
+];
+
+/// Runs the symbol table and type checker passes again on the AST of `compiler`.
+fn type_check<'a>(compiler: &'a Compiler<'a>) -> Result<(), LeoError> {
+    let symbol_table = compiler.symbol_table_pass()?;
+    compiler.type_checker_pass(symbol_table)?;
+    Ok(())
+}
+
+/// Parses `source` and runs it through the pipeline up to and including `pass`, then type checks the result.
+fn check_after(source: &str, pass: &str) -> Result<(), String> {
+    let buf = BufferEmitter(Rc::default(), Rc::default());
+    let handler = Handler::new(Box::new(buf.clone()));
+    let result = parse_program(&handler, source, None, None).and_then(|mut compiler| {
+        compiler.compiler_stages_until(pass)?;
+        type_check(&compiler)
+    });
+    result.map_err(|err| format!("{err}{}", buf.0.take()))
+}
+
+/// Parses `source` and compiles it to instructions.
+fn compile(source: &str) -> Result<String, String> {
+    let buf = BufferEmitter(Rc::default(), Rc::default());
+    let handler = Handler::new(Box::new(buf.clone()));
+    let result = parse_program(&handler, source, None, None).and_then(|mut compiler| {
+        let (symbol_table, struct_graph, call_graph) = compiler.compiler_stages()?;
+        compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph)
+    });
+    result.map_err(|err| format!("{err}{}", buf.0.take()))
+}
+
+#[test]
+fn passes_preserve_types() {
+    for seed in 0..GENERATED_PROGRAMS {
+        create_session_if_not_set_then(|_| {
+            let source = leo_parser::print_program(&ProgramGenerator::new(seed).program());
+            for pass in PASSES {
+                if let Err(err) = check_after(&source, pass) {
+                    panic!("the program generated from seed {seed} is ill typed after `{pass}`: {err}\n{source}");
+                }
+            }
+            if let Err(err) = compile(&source) {
+                panic!("the program generated from seed {seed} does not compile: {err}\n{source}");
+            }
+        })
+    }
+}
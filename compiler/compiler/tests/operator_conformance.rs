@@ -0,0 +1,336 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Generates a program for every (operator, operand types) combination, compiles it, and checks that the
+//! result agrees with the declarative operator table below. Accepted programs are additionally handed to
+//! snarkVM, so that drift between the type checker and the backend is caught as well. Finally, a coverage report
+//! compares the accepted combinations against the opcode table used by target checking and code generation.
+
+mod utilities;
+use utilities::{compile_and_process, parse_program, setup_build_directory, BufferEmitter};
+
+use leo_ast::{BinaryOperation, IntegerType, Type, UnaryOperation};
+use leo_errors::{emitter::Handler, LeoError};
+use leo_passes::{binary_opcode, unary_opcode};
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::rc::Rc;
+
+/// The primitive types that are exercised as operands.
+const TYPES: &[&str] = &[
+    "address",
+    "bool",
+    "field",
+    "group",
+    "scalar",
+    "signature",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+];
+
+const INT: &[&str] = &["i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128"];
+const SIGNED_INT: &[&str] = &["i8", "i16", "i32", "i64", "i128"];
+const UNSIGNED_INT: &[&str] = &["u8", "u16", "u32", "u64", "u128"];
+const MAGNITUDE: &[&str] = &["u8", "u16", "u32"];
+const BOOL: &[&str] = &["bool"];
+const FIELD: &[&str] = &["field"];
+const BOOL_INT: &[&str] = &["bool", "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128"];
+const FIELD_INT: &[&str] = &["field", "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128"];
+const FIELD_GROUP: &[&str] = &["field", "group"];
+const FIELD_GROUP_INT: &[&str] =
+    &["field", "group", "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128"];
+const FIELD_SCALAR_INT: &[&str] =
+    &["field", "scalar", "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128"];
+const FIELD_GROUP_SCALAR_INT: &[&str] =
+    &["field", "group", "scalar", "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128"];
+const FIELD_GROUP_SIGNED_INT: &[&str] = &["field", "group", "i8", "i16", "i32", "i64", "i128"];
+
+/// The typing rule of a binary operator.
+#[derive(Clone, Copy)]
+enum BinaryRule {
+    /// Both operands have the same type, drawn from the given set, and the result has that type too.
+    Homogeneous(&'static [&'static str]),
+    /// Both operands have the same type, drawn from the given set, and the result is a `bool`.
+    Comparison(&'static [&'static str]),
+    /// The left operand is an integer, the right operand is a magnitude, and the result has the left type.
+    Magnitude,
+    /// `field * field`, `group * scalar`, `scalar * group`, or the same integer type on both sides.
+    Multiplication,
+    /// `field ** field`, or an integer raised to a magnitude.
+    Exponentiation,
+}
+
+impl BinaryRule {
+    /// Returns the result type of the operation, or `None` if the operand types must be rejected.
+    fn result(self, lhs: &'static str, rhs: &'static str) -> Option<&'static str> {
+        match self {
+            Self::Homogeneous(types) => (lhs == rhs && types.contains(&lhs)).then_some(lhs),
+            Self::Comparison(types) => (lhs == rhs && types.contains(&lhs)).then_some("bool"),
+            Self::Magnitude => (INT.contains(&lhs) && MAGNITUDE.contains(&rhs)).then_some(lhs),
+            Self::Multiplication => match (lhs, rhs) {
+                ("group", "scalar") | ("scalar", "group") => Some("group"),
+                _ => Self::Homogeneous(FIELD_INT).result(lhs, rhs),
+            },
+            Self::Exponentiation => match (lhs, rhs) {
+                ("field", "field") => Some("field"),
+                _ => Self::Magnitude.result(lhs, rhs),
+            },
+        }
+    }
+}
+
+/// The typing rule of a unary operator.
+#[derive(Clone, Copy)]
+enum UnaryRule {
+    /// The operand has a type drawn from the given set, and the result has that type too.
+    Homogeneous(&'static [&'static str]),
+    /// The operand has the first type and the result has the second type.
+    Conversion(&'static str, &'static str),
+}
+
+impl UnaryRule {
+    /// Returns the result type of the operation, or `None` if the operand type must be rejected.
+    fn result(self, operand: &'static str) -> Option<&'static str> {
+        match self {
+            Self::Homogeneous(types) => types.contains(&operand).then_some(operand),
+            Self::Conversion(from, to) => (operand == from).then_some(to),
+        }
+    }
+}
+
+/// The declarative binary operator table: the operator, the expression used to exercise it, and its typing rule.
+const BINARY_OPERATORS: &[(BinaryOperation, &str, BinaryRule)] = &[
+    (BinaryOperation::Add, "a.add(b)", BinaryRule::Homogeneous(FIELD_GROUP_SCALAR_INT)),
+    (BinaryOperation::AddWrapped, "a.add_wrapped(b)", BinaryRule::Homogeneous(INT)),
+    (BinaryOperation::And, "a && b", BinaryRule::Homogeneous(BOOL)),
+    (BinaryOperation::BitwiseAnd, "a.and(b)", BinaryRule::Homogeneous(BOOL_INT)),
+    (BinaryOperation::Div, "a.div(b)", BinaryRule::Homogeneous(FIELD_INT)),
+    (BinaryOperation::DivWrapped, "a.div_wrapped(b)", BinaryRule::Homogeneous(INT)),
+    (BinaryOperation::Eq, "a.eq(b)", BinaryRule::Comparison(TYPES)),
+    (BinaryOperation::Gte, "a.gte(b)", BinaryRule::Comparison(FIELD_SCALAR_INT)),
+    (BinaryOperation::Gt, "a.gt(b)", BinaryRule::Comparison(FIELD_SCALAR_INT)),
+    (BinaryOperation::Lte, "a.lte(b)", BinaryRule::Comparison(FIELD_SCALAR_INT)),
+    (BinaryOperation::Lt, "a.lt(b)", BinaryRule::Comparison(FIELD_SCALAR_INT)),
+    (BinaryOperation::Mod, "a.mod(b)", BinaryRule::Homogeneous(UNSIGNED_INT)),
+    (BinaryOperation::Mul, "a.mul(b)", BinaryRule::Multiplication),
+    (BinaryOperation::MulWrapped, "a.mul_wrapped(b)", BinaryRule::Homogeneous(INT)),
+    (BinaryOperation::Nand, "a.nand(b)", BinaryRule::Homogeneous(BOOL)),
+    (BinaryOperation::Neq, "a.neq(b)", BinaryRule::Comparison(TYPES)),
+    (BinaryOperation::Nor, "a.nor(b)", BinaryRule::Homogeneous(BOOL)),
+    (BinaryOperation::Or, "a || b", BinaryRule::Homogeneous(BOOL)),
+    (BinaryOperation::BitwiseOr, "a.or(b)", BinaryRule::Homogeneous(BOOL_INT)),
+    (BinaryOperation::Pow, "a.pow(b)", BinaryRule::Exponentiation),
+    (BinaryOperation::PowWrapped, "a.pow_wrapped(b)", BinaryRule::Magnitude),
+    (BinaryOperation::Rem, "a.rem(b)", BinaryRule::Homogeneous(INT)),
+    (BinaryOperation::RemWrapped, "a.rem_wrapped(b)", BinaryRule::Homogeneous(INT)),
+    (BinaryOperation::Shl, "a.shl(b)", BinaryRule::Magnitude),
+    (BinaryOperation::ShlWrapped, "a.shl_wrapped(b)", BinaryRule::Magnitude),
+    (BinaryOperation::Shr, "a.shr(b)", BinaryRule::Magnitude),
+    (BinaryOperation::ShrWrapped, "a.shr_wrapped(b)", BinaryRule::Magnitude),
+    (BinaryOperation::Sub, "a.sub(b)", BinaryRule::Homogeneous(FIELD_GROUP_INT)),
+    (BinaryOperation::SubWrapped, "a.sub_wrapped(b)", BinaryRule::Homogeneous(INT)),
+    (BinaryOperation::Xor, "a.xor(b)", BinaryRule::Homogeneous(BOOL_INT)),
+];
+
+/// The declarative unary operator table: the operator, the expression used to exercise it, and its typing rule.
+const UNARY_OPERATORS: &[(UnaryOperation, &str, UnaryRule)] = &[
+    (UnaryOperation::Abs, "a.abs()", UnaryRule::Homogeneous(SIGNED_INT)),
+    (UnaryOperation::AbsWrapped, "a.abs_wrapped()", UnaryRule::Homogeneous(SIGNED_INT)),
+    (UnaryOperation::Double, "a.double()", UnaryRule::Homogeneous(FIELD_GROUP)),
+    (UnaryOperation::Inverse, "a.inv()", UnaryRule::Homogeneous(FIELD)),
+    (UnaryOperation::Negate, "a.neg()", UnaryRule::Homogeneous(FIELD_GROUP_SIGNED_INT)),
+    (UnaryOperation::Not, "a.not()", UnaryRule::Homogeneous(BOOL_INT)),
+    (UnaryOperation::Square, "a.square()", UnaryRule::Homogeneous(FIELD)),
+    (UnaryOperation::SquareRoot, "a.square_root()", UnaryRule::Homogeneous(FIELD)),
+    (UnaryOperation::ToXCoordinate, "a.to_x_coordinate()", UnaryRule::Conversion("group", "field")),
+    (UnaryOperation::ToYCoordinate, "a.to_y_coordinate()", UnaryRule::Conversion("group", "field")),
+];
+
+/// The maximum number of accepted cases bundled into a single program for the backend check.
+const CASES_PER_PROGRAM: usize = 16;
+
+/// A single generated (operator, operand types) combination.
+struct Case {
+    /// A human readable description, used when reporting mismatches.
+    description: String,
+    /// The transition parameters, e.g. `a: u8, b: u8`.
+    parameters: String,
+    /// The expression under test.
+    expression: &'static str,
+    /// The expected result type, or `None` if the type checker must reject the combination.
+    expected: Option<&'static str>,
+    /// The return type of the transition.
+    output: &'static str,
+    /// The opcode implementing the operator, and whether the target supports it for the operand types.
+    opcode: &'static str,
+    supported: bool,
+}
+
+impl Case {
+    /// Returns the source of a transition exercising this case.
+    fn transition(&self, name: &str) -> String {
+        format!(
+            "    transition {name}({}) -> {} {{\n        return {};\n    }}\n",
+            self.parameters, self.output, self.expression
+        )
+    }
+}
+
+/// Wraps the given transitions into a program.
+fn program(transitions: &str) -> String {
+    format!("program test.aleo {{\n{transitions}}}\n")
+}
+
+/// Returns the AST type named by `name`.
+fn ast_type(name: &str) -> Type {
+    match name {
+        "address" => Type::Address,
+        "bool" => Type::Boolean,
+        "field" => Type::Field,
+        "group" => Type::Group,
+        "scalar" => Type::Scalar,
+        "signature" => Type::Signature,
+        "i8" => Type::Integer(IntegerType::I8),
+        "i16" => Type::Integer(IntegerType::I16),
+        "i32" => Type::Integer(IntegerType::I32),
+        "i64" => Type::Integer(IntegerType::I64),
+        "i128" => Type::Integer(IntegerType::I128),
+        "u8" => Type::Integer(IntegerType::U8),
+        "u16" => Type::Integer(IntegerType::U16),
+        "u32" => Type::Integer(IntegerType::U32),
+        "u64" => Type::Integer(IntegerType::U64),
+        "u128" => Type::Integer(IntegerType::U128),
+        _ => unreachable!("unknown operand type `{name}`"),
+    }
+}
+
+/// Enumerates every case described by the operator tables.
+fn cases() -> Vec<Case> {
+    let mut cases = Vec::new();
+    for &(op, expression, rule) in BINARY_OPERATORS {
+        for &lhs in TYPES {
+            for &rhs in TYPES {
+                let expected = rule.result(lhs, rhs);
+                let (opcode, left_types, right_types) = binary_opcode(op);
+                cases.push(Case {
+                    description: format!("{op:?}({lhs}, {rhs})"),
+                    parameters: format!("a: {lhs}, b: {rhs}"),
+                    expression,
+                    expected,
+                    output: expected.unwrap_or(lhs),
+                    opcode,
+                    supported: left_types.contains(&ast_type(lhs)) && right_types.contains(&ast_type(rhs)),
+                });
+            }
+        }
+    }
+    for &(op, expression, rule) in UNARY_OPERATORS {
+        for &operand in TYPES {
+            let expected = rule.result(operand);
+            let (opcode, _, operand_types) = unary_opcode(op);
+            cases.push(Case {
+                description: format!("{op:?}({operand})"),
+                parameters: format!("a: {operand}"),
+                expression,
+                expected,
+                output: expected.unwrap_or(operand),
+                opcode,
+                supported: operand_types.contains(&ast_type(operand)),
+            });
+        }
+    }
+    cases
+}
+
+/// Runs the compiler on the given source, returning the bytecode on success.
+fn compile(source: &str, handler: &Handler) -> Result<String, LeoError> {
+    let mut parsed = parse_program(handler, source, None, None)?;
+    compile_and_process(&mut parsed)
+}
+
+#[test]
+fn operators_conform_to_table() {
+    create_session_if_not_set_then(|_| {
+        let mut mismatches = Vec::new();
+        let mut accepted = Vec::new();
+
+        // Check every combination against the type checker.
+        for case in cases() {
+            let buf = BufferEmitter(Rc::default(), Rc::default());
+            let handler = Handler::new(Box::new(buf.clone()));
+            let result = compile(&program(&case.transition("main")), &handler);
+            let errors = buf.0.take().to_string();
+            match (result, case.expected) {
+                (Ok(_), Some(_)) => accepted.push(case),
+                // Rejections must come from the type checker, not from a malformed generated program.
+                (Err(_), None) if errors.contains("ETYC") => {}
+                (Ok(_), None) => mismatches.push(format!("{} was accepted but the table rejects it", case.description)),
+                (Err(err), _) => mismatches.push(format!("{} was rejected: {err}\n{errors}", case.description)),
+            }
+        }
+
+        // Check that snarkVM agrees with every combination the type checker accepted.
+        for chunk in accepted.chunks(CASES_PER_PROGRAM) {
+            let transitions: String =
+                chunk.iter().enumerate().map(|(i, case)| case.transition(&format!("case_{i}"))).collect();
+            let handler = Handler::new(Box::new(BufferEmitter(Rc::default(), Rc::default())));
+            let verified = compile(&program(&transitions), &handler)
+                .map_err(|err| err.to_string())
+                .and_then(|bytecode| {
+                    setup_build_directory("test.aleo", &bytecode, &[], &handler).map_err(|()| "invalid bytecode".to_string())
+                })
+                .and_then(|package| package.get_process().map(|_| ()).map_err(|err| err.to_string()));
+            if let Err(err) = verified {
+                let descriptions: Vec<_> = chunk.iter().map(|case| case.description.as_str()).collect();
+                mismatches.push(format!("snarkVM rejected one of [{}]: {err}", descriptions.join(", ")));
+            }
+        }
+
+        // Report how many of the accepted combinations each opcode covers, flagging any the target cannot emit.
+        let mut coverage: Vec<(&str, usize, usize)> = Vec::new();
+        for case in &accepted {
+            match coverage.iter_mut().find(|(opcode, ..)| *opcode == case.opcode) {
+                Some((_, total, supported)) => {
+                    *total += 1;
+                    *supported += case.supported as usize;
+                }
+                None => coverage.push((case.opcode, 1, case.supported as usize)),
+            }
+            if !case.supported {
+                mismatches.push(format!("{} is accepted but `{}` does not support it", case.description, case.opcode));
+            }
+        }
+        println!("opcode coverage of accepted operations:");
+        for (opcode, total, supported) in coverage {
+            println!("  {opcode:<8} {supported}/{total}");
+        }
+
+        assert!(
+            mismatches.is_empty(),
+            "{} operator conformance mismatches:\n{}",
+            mismatches.len(),
+            mismatches.join("\n")
+        );
+    })
+}
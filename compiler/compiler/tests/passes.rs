@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Golden tests of the output of individual compiler phases.
+//!
+//! Each test in `tests/tests/passes` runs the program through the compiler up to the `phase` in its configuration:
+//! - `parsing`, which only parses the program,
+//! - the name of a pass of the compiler pipeline, e.g. `type_checking` or `flattening`, which runs the pipeline up
+//!   to and including that pass, or
+//! - `code_generation`, which compiles the program to instructions.
+//!
+//! The AST is printed as Leo code, or as canonical JSON without spans if `ast_format: json` is set.
+//! The instructions are written in full after code generation, so that changes to any phase show up as a diff of
+//! the expectation file. Set `CLEAR_LEO_TEST_EXPECTATIONS` to regenerate the expectations.
+
+mod utilities;
+use utilities::{get_build_options, get_cwd_option, parse_program, BufferEmitter};
+
+use leo_compiler::{Compiler, CompilerOptions};
+use leo_errors::{emitter::Handler, LeoError};
+use leo_span::symbol::create_session_if_not_set_then;
+use leo_test_framework::{
+    runner::{Namespace, ParseType, Runner},
+    Test,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use std::rc::Rc;
+
+struct PassesNamespace;
+
+impl Namespace for PassesNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        let buf = BufferEmitter(Rc::default(), Rc::default());
+        let handler = Handler::new(Box::new(buf.clone()));
+        create_session_if_not_set_then(|_| {
+            run_test(test, &handler, &buf).map_err(|()| buf.0.take().to_string() + &buf.1.take().to_string())
+        })
+    }
+}
+
+#[derive(Deserialize, PartialEq, Eq, Serialize)]
+struct PassesOutput {
+    /// The AST after the phase, unless the phase is code generation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ast: Option<String>,
+    /// The instructions generated for the program, if the phase is code generation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytecode: Option<String>,
+    pub warnings: String,
+}
+
+/// Runs the program through the compiler up to `phase`, returning the AST or, after code generation, the bytecode.
+fn run_phase(compiler: &mut Compiler, phase: &str, json: bool) -> Result<PassesOutput, LeoError> {
+    if phase == "code_generation" {
+        let (symbol_table, struct_graph, call_graph) = compiler.compiler_stages()?;
+        let bytecode = compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph)?;
+        return Ok(PassesOutput { ast: None, bytecode: Some(bytecode), warnings: String::new() });
+    }
+    if phase != "parsing" {
+        compiler.compiler_stages_until(phase)?;
+    }
+    let ast = if json { compiler.ast.to_canonical_json_string(true)? } else { compiler.ast.ast.to_string() };
+    Ok(PassesOutput { ast: Some(ast), bytecode: None, warnings: String::new() })
+}
+
+fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value, ()> {
+    // Check for CWD option:
+    let cwd = get_cwd_option(&test);
+
+    // Check for the phase to stop after:
+    // ``` phase: flattening ```
+    let phase = test.config.extra.get("phase").and_then(|phase| phase.as_str()).expect("Expected a `phase`.");
+    // Check for the format of the AST:
+    // ``` ast_format: json ```
+    // When set, writes the AST as canonical JSON rather than Leo code.
+    let json = match test.config.extra.get("ast_format").map(|format| format.as_str()) {
+        None | Some(Some("leo")) => false,
+        Some(Some("json")) => true,
+        _ => panic!("Expected `ast_format` to be `leo` or `json`."),
+    };
+
+    let mut outputs = Vec::new();
+    for build in get_build_options(&test.config) {
+        let compiler_options = CompilerOptions { build, ..Default::default() };
+
+        // Parse the program.
+        let mut parsed = parse_program(handler, &test.content, cwd.clone(), Some(compiler_options))
+            .map_err(|err| handler.emit_err(err))?;
+
+        // Run the program through the phase.
+        let mut output = handler.extend_if_error(run_phase(&mut parsed, phase, json))?;
+        output.warnings = buf.1.take().to_string();
+
+        outputs.push(output);
+    }
+    Ok(serde_yaml::to_value(outputs).expect("serialization failed"))
+}
+
+struct TestRunner;
+
+impl Runner for TestRunner {
+    fn resolve_namespace(&self, name: &str) -> Option<Box<dyn Namespace>> {
+        Some(match name {
+            "Passes" => Box::new(PassesNamespace),
+            _ => return None,
+        })
+    }
+}
+
+#[test]
+pub fn passes_tests() {
+    leo_test_framework::run_tests(&TestRunner, "passes");
+}
@@ -38,6 +38,17 @@ impl<'a> CheckUniqueNodeIds<'a> {
         }
     }
 
+    /// Checks that the given annotations have unique `NodeID`s.
+    pub fn check_annotations(&mut self, annotations: &'a [Annotation]) {
+        for Annotation { identifier, value, id, .. } in annotations {
+            self.visit_identifier(identifier, &Default::default());
+            if let Some(AnnotationValue::Identifier(value)) = value {
+                self.visit_identifier(value, &Default::default());
+            }
+            self.check(*id);
+        }
+    }
+
     /// Checks that the given `Type` has a unique `NodeID`.
     pub fn check_ty(&mut self, ty: &'a Type) {
         match ty {
@@ -60,6 +71,13 @@ impl<'a> ExpressionVisitor<'a> for CheckUniqueNodeIds<'a> {
     type AdditionalInput = ();
     type Output = ();
 
+    fn visit_block_expression(&mut self, input: &'a BlockExpression, _: &Self::AdditionalInput) -> Self::Output {
+        let BlockExpression { statements, value, id, .. } = input;
+        statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.visit_expression(value, &Default::default());
+        self.check(*id)
+    }
+
     fn visit_access(&mut self, input: &'a AccessExpression, _: &Self::AdditionalInput) -> Self::Output {
         match input {
             AccessExpression::Array(ArrayAccess { array, index, id, .. }) => {
@@ -215,17 +233,32 @@ impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
                 self.visit_expression(left, &Default::default());
                 self.visit_expression(right, &Default::default());
             }
+            ConsoleFunction::Log(args) => {
+                args.parameters.iter().for_each(|parameter| self.visit_expression(parameter, &Default::default()));
+            }
         };
         self.check(input.id)
     }
 
+    fn visit_declaration(&mut self, input: &'a DeclarationStatement) {
+        self.visit_identifier(&input.identifier, &Default::default());
+        self.check_ty(&input.type_);
+        self.check(input.id)
+    }
+
     fn visit_definition(&mut self, input: &'a DefinitionStatement) {
         self.visit_expression(&input.place, &Default::default());
-        self.check_ty(&input.type_);
+        if let Some(type_) = &input.type_ {
+            self.check_ty(type_);
+        }
         self.visit_expression(&input.value, &Default::default());
         self.check(input.id)
     }
 
+    fn visit_err_statement(&mut self, input: &'a ErrStatement) {
+        self.check(input.id)
+    }
+
     fn visit_expression_statement(&mut self, input: &'a ExpressionStatement) {
         self.visit_expression(&input.expression, &Default::default());
         self.check(input.id)
@@ -240,6 +273,18 @@ impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
         self.check(input.id)
     }
 
+    fn visit_match(&mut self, input: &'a MatchStatement) {
+        self.visit_expression(&input.value, &Default::default());
+        for arm in &input.arms {
+            if let Some(pattern) = &arm.pattern {
+                self.visit_expression(pattern, &Default::default());
+            }
+            self.visit_block(&arm.block);
+            self.check(arm.id);
+        }
+        self.check(input.id)
+    }
+
     fn visit_return(&mut self, input: &'a ReturnStatement) {
         self.visit_expression(&input.expression, &Default::default());
         if let Some(arguments) = &input.finalize_arguments {
@@ -253,13 +298,17 @@ impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
 
 impl<'a> ProgramVisitor<'a> for CheckUniqueNodeIds<'a> {
     fn visit_struct(&mut self, input: &'a Struct) {
-        let Struct { identifier, members, id, .. } = input;
+        let Struct { annotations, identifier, members, methods, id, .. } = input;
+        self.check_annotations(annotations);
         self.visit_identifier(identifier, &Default::default());
         for Member { identifier, type_, id, .. } in members {
             self.visit_identifier(identifier, &Default::default());
             self.check_ty(type_);
             self.check(*id);
         }
+        for (_, method) in methods {
+            self.visit_function(method);
+        }
         self.check(*id);
     }
 
@@ -274,10 +323,7 @@ impl<'a> ProgramVisitor<'a> for CheckUniqueNodeIds<'a> {
     fn visit_function(&mut self, input: &'a Function) {
         let Function { annotations, identifier, input, output, block, finalize, id, .. } = input;
         // Check the annotations.
-        for Annotation { identifier, id, .. } in annotations {
-            self.visit_identifier(identifier, &Default::default());
-            self.check(*id);
-        }
+        self.check_annotations(annotations);
         // Check the function name.
         self.visit_identifier(identifier, &Default::default());
         // Check the inputs.
@@ -17,13 +17,14 @@
 mod check_unique_node_ids;
 use check_unique_node_ids::*;
 
-use leo_compiler::{BuildOptions, Compiler, CompilerOptions};
+use leo_compiler::{BuildOptions, Compiler, CompilerOptions, ProgramLimits};
 use leo_errors::{
     emitter::{Buffer, Emitter, Handler},
     LeoError,
     LeoWarning,
 };
 use leo_package::root::env::Env;
+use leo_passes::Edition;
 use leo_span::source_map::FileName;
 use leo_test_framework::{test::TestConfig, Test};
 
@@ -44,6 +45,7 @@ pub type Network = Testnet3;
 #[allow(unused)]
 pub type Aleo = snarkvm::circuit::AleoV0;
 
+#[allow(unused)]
 pub fn hash_asts() -> (String, String, String, String, String, String, String) {
     let initial_ast = hash_file("/tmp/output/test.initial_ast.json");
     let unrolled_ast = hash_file("/tmp/output/test.unrolled_ast.json");
@@ -56,6 +58,7 @@ pub fn hash_asts() -> (String, String, String, String, String, String, String) {
     (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, dce_ast)
 }
 
+#[allow(unused)]
 pub fn hash_symbol_tables() -> (String, String, String) {
     let initial_symbol_table = hash_file("/tmp/output/test.initial_symbol_table.json");
     let type_checked_symbol_table = hash_file("/tmp/output/test.type_checked_symbol_table.json");
@@ -63,6 +66,7 @@ pub fn hash_symbol_tables() -> (String, String, String) {
     (initial_symbol_table, type_checked_symbol_table, unrolled_symbol_table)
 }
 
+#[allow(unused)]
 pub fn get_cwd_option(test: &Test) -> Option<PathBuf> {
     // Check for CWD option:
     // ``` cwd: import ```
@@ -75,7 +79,69 @@ pub fn get_cwd_option(test: &Test) -> Option<PathBuf> {
     })
 }
 
+#[allow(unused)]
+pub fn get_import_paths(test: &Test) -> Vec<PathBuf> {
+    // Check for an import directory option:
+    // ``` import_directory: imports ```
+    // When set, searches the directory, relative to the file, for the programs and stubs that the file imports.
+    test.config
+        .extra
+        .get("import_directory")
+        .map(|val| {
+            let mut directory = test.path.clone();
+            directory.pop();
+            directory.join(val.as_str().unwrap())
+        })
+        .into_iter()
+        .collect()
+}
+
+#[allow(unused)]
 pub fn get_build_options(test_config: &TestConfig) -> Vec<BuildOptions> {
+    // Check for an instruction budget option:
+    // ``` instruction_budget: 10 ```
+    // When set, applies the budget to every compiler configuration.
+    let instruction_budget = test_config
+        .extra
+        .get("instruction_budget")
+        .map(|budget| budget.as_u64().expect("Expected the instruction budget to be an integer."));
+    // Check for a stripping option:
+    // ``` strip_enabled: true ```
+    // When set, strips unreachable items in every compiler configuration.
+    let strip_enabled = test_config
+        .extra
+        .get("strip_enabled")
+        .map_or(false, |strip| strip.as_bool().expect("Expected `strip_enabled` to be a boolean."));
+    // Check for a tests option:
+    // ``` tests_enabled: true ```
+    // When set, keeps the functions annotated with `@test` in every compiler configuration.
+    let tests_enabled = test_config
+        .extra
+        .get("tests_enabled")
+        .map_or(false, |tests| tests.as_bool().expect("Expected `tests_enabled` to be a boolean."));
+    // Check for a lints option:
+    // ``` lints_enabled: true ```
+    // When set, warns of unused variables and imports in every compiler configuration.
+    let lints_enabled = test_config
+        .extra
+        .get("lints_enabled")
+        .map_or(false, |lints| lints.as_bool().expect("Expected `lints_enabled` to be a boolean."));
+    // Check for program limit options:
+    // ``` max_depth: 8 ``` or ``` max_nodes: 100 ```
+    // When set, limits the programs in every compiler configuration.
+    let mut program_limits = ProgramLimits::default();
+    if let Some(max_depth) = test_config.extra.get("max_depth") {
+        program_limits.max_depth = max_depth.as_u64().expect("Expected `max_depth` to be an integer.") as usize;
+    }
+    if let Some(max_nodes) = test_config.extra.get("max_nodes") {
+        program_limits.max_nodes = Some(max_nodes.as_u64().expect("Expected `max_nodes` to be an integer.") as usize);
+    }
+    // Check for an edition option:
+    // ``` language_edition: "2022" ```
+    // When set, targets the edition in every compiler configuration.
+    let language_edition = test_config.extra.get("language_edition").map_or(Edition::default(), |edition| {
+        serde_yaml::from_value(edition.clone()).expect("Expected `language_edition` to be an edition, e.g. \"2023\".")
+    });
     match test_config.extra.get("configs") {
         Some(configs) => {
             // Parse the sequence of compiler configurations.
@@ -96,16 +162,41 @@ pub fn get_build_options(test_config: &TestConfig) -> Vec<BuildOptions> {
                             .expect("Expected key `dce_enabled`")
                             .as_bool()
                             .expect("Expected value to be a boolean."),
+                        instruction_budget,
+                        strip_enabled,
+                        tests_enabled,
+                        lints_enabled,
+                        import_paths: Vec::new(),
+                        interpreter_limits: Default::default(),
+                        program_limits,
+                        language_edition,
                     }
                 })
                 .collect()
         }
-        None => vec![BuildOptions { dce_enabled: true }],
+        None => {
+            vec![BuildOptions {
+                dce_enabled: true,
+                instruction_budget,
+                strip_enabled,
+                tests_enabled,
+                lints_enabled,
+                import_paths: Vec::new(),
+                interpreter_limits: Default::default(),
+                program_limits,
+                language_edition,
+            }]
+        }
     }
 }
 
 #[allow(unused)]
-pub fn setup_build_directory(program_name: &str, bytecode: &String, handler: &Handler) -> Result<Package<Network>, ()> {
+pub fn setup_build_directory(
+    program_name: &str,
+    bytecode: &String,
+    import_paths: &[PathBuf],
+    handler: &Handler,
+) -> Result<Package<Network>, ()> {
     // Initialize a temporary directory.
     let directory = temp_dir();
 
@@ -130,6 +221,18 @@ pub fn setup_build_directory(program_name: &str, bytecode: &String, handler: &Ha
     let build_directory = directory.join("build");
     fs::create_dir_all(build_directory).unwrap();
 
+    // Copy the stubs of the deployed programs that the program may import into the imports directory.
+    let imports_directory = directory.join("imports");
+    fs::create_dir_all(&imports_directory).unwrap();
+    for import_path in import_paths {
+        for entry in fs::read_dir(import_path).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().map_or(false, |extension| extension == "aleo") {
+                fs::copy(&path, imports_directory.join(path.file_name().unwrap())).unwrap();
+            }
+        }
+    }
+
     // Open the package at the temporary directory.
     handler.extend_if_error(Package::<Network>::open(&directory).map_err(LeoError::Anyhow))
 }
@@ -160,6 +263,7 @@ pub fn parse_program<'a>(
     Ok(compiler)
 }
 
+#[allow(unused)]
 pub fn hash_content(content: &str) -> String {
     use sha2::{Digest, Sha256};
     let mut hasher = Sha256::new();
@@ -169,6 +273,7 @@ pub fn hash_content(content: &str) -> String {
     format!("{hash:x}")
 }
 
+#[allow(unused)]
 pub fn hash_file(path: &str) -> String {
     let file = fs::read_to_string(Path::new(path)).unwrap();
     hash_content(&file)
@@ -221,26 +326,16 @@ pub fn temp_dir() -> PathBuf {
     tempfile::tempdir().expect("Failed to open temporary directory").into_path()
 }
 
+#[allow(unused)]
 pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, LeoError> {
-    let st = parsed.symbol_table_pass()?;
-
-    CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
-
-    let (st, struct_graph, call_graph) = parsed.type_checker_pass(st)?;
-
-    CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
-
-    let st = parsed.loop_unrolling_pass(st)?;
-
-    parsed.static_single_assignment_pass(&st)?;
-
-    parsed.flattening_pass(&st)?;
-
-    parsed.destructuring_pass()?;
-
-    parsed.function_inlining_pass(&call_graph)?;
-
-    parsed.dead_code_elimination_pass()?;
+    // The passes are run through the same pipeline as the compiler, checking that the AST has unique node IDs after
+    // the symbol table is created, and after the last of the checks, since the transformations that follow may not
+    // preserve them.
+    let (st, struct_graph, call_graph) = parsed.compiler_stages_inspected(|ast, pass| {
+        if matches!(pass, "symbol_table_creation" | "target_checking") {
+            CheckUniqueNodeIds::new().visit_program(&ast.ast);
+        }
+    })?;
 
     // Compile Leo program to bytecode.
     let bytecode = parsed.code_generation_pass(&st, &struct_graph, &call_graph)?;
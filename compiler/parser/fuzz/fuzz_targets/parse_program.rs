@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+#![no_main]
+
+use leo_errors::{LeoMessageCode, ParserError};
+
+use libfuzzer_sys::fuzz_target;
+
+// Run with `cargo fuzz run parse_program` from `compiler/parser`.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(source) = std::str::from_utf8(data) {
+        let (_, diagnostics) = leo_parser::parse_program_recoverable(source);
+        // Panics are caught and reported as errors, which must not happen on any input.
+        let code = ParserError::parser_panicked("").error_code();
+        if let Some(diagnostic) = diagnostics.iter().find(|diagnostic| diagnostic.code == code) {
+            panic!("{}", diagnostic.message);
+        }
+    }
+});
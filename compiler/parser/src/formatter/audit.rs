@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The checks that a printed AST parses as the AST it was printed from.
+
+use leo_ast::{Expression, NodeBuilder};
+use leo_errors::{emitter::Handler, ParserError, Result};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// Checks that `printed`, the source code that the AST `expression` was printed as, parses as the same expression,
+/// up to its spans and node ids. This catches a printer that disagrees with the parser on precedence, e.g. one that
+/// prints `(a + b) * c` as `a + b * c`. A session is created if there is none.
+pub fn check_printed_expression(expression: &Expression, printed: &str) -> Result<()> {
+    create_session_if_not_set_then(|s| {
+        let source_file = s.source_map.new_source(printed, FileName::Custom("printed".into()));
+        let (handler, _) = Handler::new_with_buf();
+        let node_builder = NodeBuilder::default();
+        let reparsed = crate::parse_expression(&handler, &node_builder, &source_file.src, source_file.start_pos)?;
+        if without_positions(expression) != without_positions(&reparsed) {
+            return Err(ParserError::printed_expression_mismatch(printed).into());
+        }
+        Ok(())
+    })
+}
+
+/// Returns `node` as JSON, without the spans and node ids, which differ between a node and its printed form.
+pub(crate) fn without_positions(node: &impl Serialize) -> Value {
+    remove_positions(serde_json::to_value(node).expect("failed to convert to json value"))
+}
+
+// Helper function to remove the spans and node ids from AST JSON.
+// Identifiers are serialized as strings containing their spans and ids, and are replaced by their names.
+fn remove_positions(value: Value) -> Value {
+    let is_span = |value: &Value| matches!(value, Value::Object(map) if map.len() == 2 && map.contains_key("lo"));
+    let is_node_span =
+        |value: &Value| matches!(value, Value::Object(map) if map.len() == 1 && map.contains_key("span"));
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(key, _)| key != "span" && key != "id")
+                .map(|(key, value)| (key, remove_positions(value)))
+                .collect(),
+        ),
+        // The span of a node that is serialized as an array, e.g. a literal, is a map from `span` to the span,
+        // and is followed by the id of the node. The span of an import is serialized as is.
+        Value::Array(values) => {
+            let mut values = values.into_iter().peekable();
+            let mut new_values = Vec::new();
+            while let Some(value) = values.next() {
+                if is_node_span(&value) {
+                    values.next_if(|id| id.is_u64());
+                } else if !is_span(&value) {
+                    new_values.push(remove_positions(value));
+                }
+            }
+            Value::Array(new_values)
+        }
+        Value::String(string) => match serde_json::from_str::<serde_json::Map<String, Value>>(&string) {
+            Ok(mut identifier) if identifier.contains_key("name") => identifier.remove("name").unwrap(),
+            _ => Value::String(string),
+        },
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::print_expression;
+
+    /// Parses the expression `source`.
+    fn parse(source: &str) -> Expression {
+        create_session_if_not_set_then(|s| {
+            let source_file = s.source_map.new_source(source, FileName::Custom("test".into()));
+            let node_builder = NodeBuilder::default();
+            crate::parse_expression(&Handler::default(), &node_builder, &source_file.src, source_file.start_pos)
+                .unwrap()
+        })
+    }
+
+    #[test]
+    fn test_print_expression_minimal_parentheses() {
+        create_session_if_not_set_then(|_| {
+            for source in [
+                "a + b * c",
+                "(a + b) * c",
+                "a - (b - c)",
+                "a - b - c",
+                "(a == b) == c",
+                "-(a + b) as u8",
+                "!(a && b) || c",
+                "(a ? b : c).d",
+                "a ** b ** c",
+                "a ** (b ** c)",
+                "a.add_wrapped(b + c) << 1u8",
+            ] {
+                let expression = parse(source);
+                let printed = print_expression(&expression);
+                assert_eq!(printed, source);
+                check_printed_expression(&expression, &printed).unwrap();
+            }
+        })
+    }
+
+    #[test]
+    fn test_check_printed_expression_mismatch() {
+        create_session_if_not_set_then(|_| {
+            let expression = parse("(a + b) * c");
+            assert!(check_printed_expression(&expression, "a + b * c").is_err());
+            assert!(check_printed_expression(&expression, "(a + b) * c;").is_err());
+            assert!(check_printed_expression(&expression, "((a + b)) * c").is_ok());
+        })
+    }
+}
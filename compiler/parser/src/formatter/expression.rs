@@ -0,0 +1,330 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{type_::const_arguments, Printer};
+
+use leo_ast::*;
+use leo_span::Span;
+
+use std::{mem, slice};
+
+/// How tightly an expression binds, from loosest to tightest.
+/// An operand that binds more loosely than its position requires is parenthesized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    Ternary,
+    Or,
+    And,
+    Equality,
+    Ordering,
+    BitwiseXor,
+    BitwiseOr,
+    BitwiseAnd,
+    Shift,
+    Additive,
+    Multiplicative,
+    Exponential,
+    Cast,
+    Prefix,
+    Postfix,
+}
+
+impl Precedence {
+    /// Returns the next tighter precedence.
+    fn tighter(self) -> Self {
+        use Precedence::*;
+        match self {
+            Ternary => Or,
+            Or => And,
+            And => Equality,
+            Equality => Ordering,
+            Ordering => BitwiseXor,
+            BitwiseXor => BitwiseOr,
+            BitwiseOr => BitwiseAnd,
+            BitwiseAnd => Shift,
+            Shift => Additive,
+            Additive => Multiplicative,
+            Multiplicative => Exponential,
+            Exponential => Cast,
+            Cast => Prefix,
+            Prefix | Postfix => Postfix,
+        }
+    }
+
+    /// Returns the precedence of the infix operator `operation`,
+    /// or `None` if the operation is written as a method call, e.g. `a.add_wrapped(b)`.
+    fn of_binary(operation: BinaryOperation) -> Option<Self> {
+        use BinaryOperation::*;
+        Some(match operation {
+            Or => Self::Or,
+            And => Self::And,
+            Eq | Neq => Self::Equality,
+            Lt | Lte | Gt | Gte => Self::Ordering,
+            Xor => Self::BitwiseXor,
+            BitwiseOr => Self::BitwiseOr,
+            BitwiseAnd => Self::BitwiseAnd,
+            Shl | Shr => Self::Shift,
+            Add | Sub => Self::Additive,
+            Mul | Div | Rem => Self::Multiplicative,
+            Pow => Self::Exponential,
+            AddWrapped | DivWrapped | Mod | MulWrapped | Nand | Nor | PowWrapped | RemWrapped | ShlWrapped
+            | ShrWrapped | SubWrapped => return None,
+        })
+    }
+
+    /// Returns the precedence of `expression` as it is written.
+    fn of(expression: &Expression) -> Self {
+        match expression {
+            Expression::Binary(binary) => Self::of_binary(binary.op).unwrap_or(Self::Postfix),
+            Expression::Cast(_) => Self::Cast,
+            Expression::Literal(literal) if is_negative(literal) => Self::Prefix,
+            Expression::Ternary(_) => Self::Ternary,
+            Expression::Unary(unary) if is_prefix(unary) => Self::Prefix,
+            _ => Self::Postfix,
+        }
+    }
+}
+
+/// Returns the name of the method that the binary operation `operation` is written as, e.g. `add_wrapped`.
+fn binary_method(operation: BinaryOperation) -> &'static str {
+    use BinaryOperation::*;
+    match operation {
+        AddWrapped => "add_wrapped",
+        DivWrapped => "div_wrapped",
+        Mod => "mod",
+        MulWrapped => "mul_wrapped",
+        Nand => "nand",
+        Nor => "nor",
+        PowWrapped => "pow_wrapped",
+        RemWrapped => "rem_wrapped",
+        ShlWrapped => "shl_wrapped",
+        ShrWrapped => "shr_wrapped",
+        SubWrapped => "sub_wrapped",
+        _ => unreachable!("infix operations are not written as method calls"),
+    }
+}
+
+/// Returns `true` if `literal` is written with a leading minus sign.
+fn is_negative(literal: &Literal) -> bool {
+    match literal {
+        Literal::Field(value, ..)
+        | Literal::Integer(_, value, ..)
+        | Literal::Scalar(value, ..)
+        | Literal::Unsuffixed(value, ..) => value.starts_with('-'),
+        Literal::Group(group) => matches!(group.as_ref(), GroupLiteral::Single(value, ..) if value.starts_with('-')),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `unary` is written with a prefix operator, e.g. `!a` or `-1u8`, rather than as a method call.
+fn is_prefix(unary: &UnaryExpression) -> bool {
+    matches!(unary.op, UnaryOperation::Not | UnaryOperation::Negate)
+}
+
+/// An element of a block expression, which ends with the value of the block.
+enum BlockElement<'a> {
+    Statement(&'a Statement),
+    Value(&'a Expression),
+}
+
+impl Printer<'_> {
+    /// Writes an expression.
+    pub(super) fn expression(&mut self, expression: &Expression) {
+        self.operand(expression, Precedence::Ternary);
+    }
+
+    /// Writes an expression in which a struct initialization must be parenthesized,
+    /// e.g. the condition of an `if` statement, which is followed by a block.
+    pub(super) fn condition(&mut self, expression: &Expression) {
+        let disallow = mem::replace(&mut self.disallow_struct_construction, true);
+        self.expression(expression);
+        self.disallow_struct_construction = disallow;
+    }
+
+    /// Writes an expression enclosed by delimiters, e.g. an argument of a call, in which struct initializations
+    /// are allowed.
+    fn delimited(&mut self, expression: &Expression) {
+        let disallow = mem::replace(&mut self.disallow_struct_construction, false);
+        self.expression(expression);
+        self.disallow_struct_construction = disallow;
+    }
+
+    /// Writes `expression` as an operand that binds at least as tightly as `precedence`,
+    /// and parenthesizes it otherwise.
+    fn operand(&mut self, expression: &Expression, precedence: Precedence) {
+        // The branches of a ternary are parsed as expressions in which struct initializations are allowed again,
+        // so a ternary whose last branch ends in an identifier would swallow the following block.
+        let is_disallowed =
+            self.disallow_struct_construction && matches!(expression, Expression::Struct(_) | Expression::Ternary(_));
+        if Precedence::of(expression) < precedence || is_disallowed {
+            self.write("(");
+            self.delimited(expression);
+            self.write(")");
+            return;
+        }
+
+        match expression {
+            Expression::Access(access) => self.access(access),
+            Expression::Array(array) => self.list(("[", "]"), false, &array.elements, Self::delimited),
+            Expression::Binary(binary) => match Precedence::of_binary(binary.op) {
+                Some(precedence) => {
+                    // Equality and ordering operators are not associative, so neither of their operands may be
+                    // another equality or ordering, respectively.
+                    let left = match precedence {
+                        Precedence::Equality | Precedence::Ordering => precedence.tighter(),
+                        _ => precedence,
+                    };
+                    self.operand(&binary.left, left);
+                    self.write(&format!(" {} ", binary.op));
+                    self.operand(&binary.right, precedence.tighter());
+                }
+                None => self.method_call(&binary.left, binary_method(binary.op), slice::from_ref(&binary.right)),
+            },
+            Expression::Block(block) => self.block_expression(block),
+            Expression::Call(call) => match (call.function.as_ref(), &call.external) {
+                // A call to a method of a struct, e.g. `p.norm()`.
+                (Expression::Access(AccessExpression::Member(member)), None) => {
+                    self.method_call(&member.inner, &member.name.to_string(), &call.arguments)
+                }
+                // A call to a function of another program, e.g. `token.leo/mint(a)`.
+                (function, Some(program)) => {
+                    self.write(&format!("{program}.{}/{function}", call.program_suffix()));
+                    self.list(("(", ")"), false, &call.arguments, Self::delimited);
+                }
+                (function, None) => {
+                    self.operand(function, Precedence::Postfix);
+                    self.list(("(", ")"), false, &call.arguments, Self::delimited);
+                }
+            },
+            Expression::Cast(cast) => {
+                self.operand(&cast.expression, Precedence::Prefix);
+                self.write(if cast.wrapped { " as.wrapped " } else { " as " });
+                self.type_(&cast.type_);
+            }
+            Expression::Struct(struct_) => {
+                self.write(&struct_.name.to_string());
+                if !struct_.const_arguments.is_empty() {
+                    self.write(&format!("::<{}>", const_arguments(&struct_.const_arguments)));
+                }
+                self.write(" ");
+                self.list(("{", "}"), true, &struct_.members, |p, member| {
+                    p.write(&member.identifier.to_string());
+                    if let Some(expression) = &member.expression {
+                        p.write(": ");
+                        p.delimited(expression);
+                    }
+                });
+            }
+            Expression::Err(err) => self.write(&err.to_string()),
+            Expression::Identifier(identifier) => self.write(&identifier.to_string()),
+            Expression::Literal(literal) => self.literal(literal),
+            Expression::Ternary(ternary) => {
+                self.operand(&ternary.condition, Precedence::Or);
+                self.write(" ? ");
+                self.delimited(&ternary.if_true);
+                self.write(" : ");
+                self.delimited(&ternary.if_false);
+            }
+            Expression::Tuple(tuple) => self.list(("(", ")"), false, &tuple.elements, Self::delimited),
+            Expression::Unary(unary) => match is_prefix(unary) {
+                true => {
+                    self.write(if unary.op == UnaryOperation::Not { "!" } else { "-" });
+                    self.operand(&unary.receiver, Precedence::Prefix);
+                }
+                false => self.method_call(&unary.receiver, unary.op.as_str(), &[]),
+            },
+            Expression::Unit(_) => self.write("()"),
+        }
+    }
+
+    /// Writes a method call `receiver.method(arguments)`.
+    fn method_call(&mut self, receiver: &Expression, method: &str, arguments: &[Expression]) {
+        self.operand(receiver, Precedence::Postfix);
+        self.write(&format!(".{method}"));
+        self.list(("(", ")"), false, arguments, Self::delimited);
+    }
+
+    /// Writes an access expression.
+    fn access(&mut self, access: &AccessExpression) {
+        match access {
+            AccessExpression::Array(array) => {
+                self.operand(&array.array, Precedence::Postfix);
+                self.write("[");
+                self.delimited(&array.index);
+                self.write("]");
+            }
+            AccessExpression::AssociatedConstant(constant) => {
+                self.type_(&constant.ty);
+                self.write(&format!("::{}", constant.name));
+            }
+            AccessExpression::AssociatedFunction(function) => match (&function.ty, function.arguments.split_first()) {
+                // A function that is called as a method of its first argument, e.g. `balances.get(a)`,
+                // is parsed with a type that has no span.
+                (Type::Identifier(ty), Some((receiver, arguments))) if ty.span == Span::default() => {
+                    self.method_call(receiver, &function.name.to_string(), arguments)
+                }
+                (ty, _) => {
+                    self.type_(ty);
+                    self.write(&format!("::{}", function.name));
+                    self.list(("(", ")"), false, &function.arguments, Self::delimited);
+                }
+            },
+            AccessExpression::Member(member) => {
+                self.operand(&member.inner, Precedence::Postfix);
+                self.write(&format!(".{}", member.name));
+            }
+            AccessExpression::Tuple(tuple) => {
+                self.operand(&tuple.tuple, Precedence::Postfix);
+                self.write(&format!(".{}", tuple.index));
+            }
+        }
+    }
+
+    /// Writes a literal.
+    fn literal(&mut self, literal: &Literal) {
+        match literal {
+            Literal::Group(group) => match group.as_ref() {
+                GroupLiteral::Single(value, ..) => self.write(&format!("{value}group")),
+                GroupLiteral::Tuple(tuple) => self.write(&format!("({}, {})group", tuple.x, tuple.y)),
+            },
+            literal => self.write(&literal.to_string()),
+        }
+    }
+
+    /// Writes a block expression, on a single line if it only contains its value and no comments.
+    fn block_expression(&mut self, block: &BlockExpression) {
+        let trivia = self.trivia;
+        let dangling = &trivia.node(block.id).dangling;
+        if block.statements.is_empty() && trivia.node(block.value.id()).is_empty() && dangling.is_empty() {
+            self.write("{ ");
+            self.delimited(&block.value);
+            return self.write(" }");
+        }
+
+        let elements = block
+            .statements
+            .iter()
+            .map(|statement| (trivia.node(statement.id()), BlockElement::Statement(statement)))
+            .chain(std::iter::once((trivia.node(block.value.id()), BlockElement::Value(&block.value))))
+            .collect();
+        let disallow = mem::replace(&mut self.disallow_struct_construction, false);
+        self.braced_sequence(elements, dangling, |p, element| match element {
+            BlockElement::Statement(statement) => p.statement(statement),
+            BlockElement::Value(value) => p.expression(value),
+        });
+        self.disallow_struct_construction = disallow;
+    }
+}
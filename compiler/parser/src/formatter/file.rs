@@ -0,0 +1,274 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Printer;
+use crate::{function_span, struct_span};
+
+use leo_ast::*;
+use leo_span::{sym, Symbol};
+
+/// An item of a program, in the order the items are written.
+enum ProgramItem<'a> {
+    Import(Symbol),
+    Stub(Symbol),
+    Scope(&'a ProgramScope),
+}
+
+/// An item of a program scope, in the order the items are written.
+enum ScopeItem<'a> {
+    Module(&'a Module),
+    Const(&'a ConstDeclaration),
+    Interface(&'a Interface),
+    Struct(&'a Struct),
+    Enum(&'a Enum),
+    Mapping(&'a Mapping),
+    Function(&'a Function),
+}
+
+/// A member or method of a struct, in the order they are written.
+enum StructItem<'a> {
+    Member(&'a Member),
+    Method(&'a Function),
+}
+
+impl Printer<'_> {
+    /// Writes a program, whose items are ordered as they are written.
+    pub(super) fn program(&mut self, program: &Program) {
+        let mut items = program
+            .imports
+            .iter()
+            .map(|(name, (_, span))| (*span, ProgramItem::Import(*name)))
+            .chain(program.stubs.iter().map(|(name, (_, span))| (*span, ProgramItem::Stub(*name))))
+            .chain(program.program_scopes.values().map(|scope| (scope.span, ProgramItem::Scope(scope))))
+            .collect::<Vec<_>>();
+        items.sort_by_key(|(span, _)| span.lo);
+
+        let trivia = self.trivia;
+        let items = items.into_iter().map(|(_, item)| match item {
+            ProgramItem::Import(name) | ProgramItem::Stub(name) => (trivia.import(name), item),
+            ProgramItem::Scope(scope) => (trivia.node(scope.program_id.name.id), item),
+        });
+        self.sequence(items, trivia.end_of_file(), |p, item| match item {
+            ProgramItem::Import(name) => p.write(&format!("import {name}.leo;")),
+            ProgramItem::Stub(name) => p.write(&format!("import {name}.aleo;")),
+            ProgramItem::Scope(scope) => p.program_scope(scope),
+        });
+    }
+
+    /// Writes a program scope, whose items are ordered as they are written.
+    fn program_scope(&mut self, scope: &ProgramScope) {
+        let mut items = scope
+            .modules
+            .iter()
+            .map(|(_, module)| (module.span, ScopeItem::Module(module)))
+            .chain(scope.consts.iter().map(|(_, declaration)| (declaration.span, ScopeItem::Const(declaration))))
+            .chain(scope.interfaces.iter().map(|(_, interface)| (interface.span, ScopeItem::Interface(interface))))
+            .chain(scope.structs.iter().map(|(_, struct_)| (struct_span(struct_), ScopeItem::Struct(struct_))))
+            .chain(scope.enums.iter().map(|(_, enum_)| (enum_.span, ScopeItem::Enum(enum_))))
+            .chain(scope.mappings.iter().map(|(_, mapping)| (mapping.span, ScopeItem::Mapping(mapping))))
+            .chain(scope.functions.iter().map(|(_, function)| (function_span(function), ScopeItem::Function(function))))
+            .collect::<Vec<_>>();
+        items.sort_by_key(|(span, _)| span.lo);
+
+        let trivia = self.trivia;
+        let items = items
+            .into_iter()
+            .map(|(_, item)| {
+                let id = match item {
+                    ScopeItem::Module(module) => module.id,
+                    ScopeItem::Const(declaration) => declaration.id,
+                    ScopeItem::Interface(interface) => interface.id,
+                    ScopeItem::Struct(struct_) => struct_.id,
+                    ScopeItem::Enum(enum_) => enum_.id,
+                    ScopeItem::Mapping(mapping) => mapping.id,
+                    ScopeItem::Function(function) => function.id,
+                };
+                (trivia.node(id), item)
+            })
+            .collect();
+
+        for annotation in &scope.annotations {
+            self.write(&annotation.to_string());
+            self.newline();
+        }
+        self.write(&format!("program {} ", scope.program_id));
+        let dangling = &trivia.node(scope.program_id.name.id).dangling;
+        self.braced_sequence(items, dangling, |p, item| match item {
+            ScopeItem::Module(module) => p.write(&module.to_string()),
+            ScopeItem::Const(declaration) => p.const_declaration(declaration),
+            ScopeItem::Interface(interface) => {
+                p.write(&format!("interface {} ", interface.identifier));
+                let members = interface.members.iter().map(|member| (trivia.node(member.id), member)).collect();
+                p.braced_sequence(members, &trivia.node(interface.id).dangling, Self::member);
+            }
+            ScopeItem::Struct(struct_) => p.struct_(struct_),
+            ScopeItem::Enum(enum_) => {
+                p.write(&format!("enum {} ", enum_.identifier));
+                let variants = enum_.variants.iter().map(|variant| (trivia.node(variant.id), variant)).collect();
+                p.braced_sequence(variants, &trivia.node(enum_.id).dangling, |p, variant| {
+                    p.write(&format!("{variant},"))
+                });
+            }
+            ScopeItem::Mapping(mapping) => {
+                p.write(&format!("mapping {}: ", mapping.identifier));
+                p.type_(&mapping.key_type);
+                p.write(" => ");
+                p.type_(&mapping.value_type);
+                p.write(";");
+            }
+            ScopeItem::Function(function) => p.function(function, false),
+        });
+    }
+
+    /// Writes a struct or record, whose members and methods are ordered as they are written.
+    fn struct_(&mut self, struct_: &Struct) {
+        for annotation in &struct_.annotations {
+            self.write(&annotation.to_string());
+            self.newline();
+            self.write_indent();
+        }
+        let keyword = if struct_.is_record { "record" } else { "struct" };
+        self.write(&format!("{}{keyword} {}", struct_.visibility, struct_.identifier));
+        for (i, parameter) in struct_.const_parameters.iter().enumerate() {
+            self.write(&format!("{}const {}: ", if i == 0 { "<" } else { ", " }, parameter.identifier));
+            self.type_(&parameter.type_);
+        }
+        if !struct_.const_parameters.is_empty() {
+            self.write(">");
+        }
+        for (i, interface) in struct_.interfaces.iter().enumerate() {
+            self.write(&format!("{}{interface}", if i == 0 { ": " } else { " + " }));
+        }
+        self.write(" ");
+
+        let mut items = struct_
+            .members
+            .iter()
+            .map(|member| (member.span, StructItem::Member(member)))
+            .chain(struct_.methods.iter().map(|(_, method)| (function_span(method), StructItem::Method(method))))
+            .collect::<Vec<_>>();
+        items.sort_by_key(|(span, _)| span.lo);
+        let trivia = self.trivia;
+        let items = items
+            .into_iter()
+            .map(|(_, item)| match item {
+                StructItem::Member(member) => (trivia.node(member.id), item),
+                StructItem::Method(method) => (trivia.node(method.id), item),
+            })
+            .collect();
+        self.braced_sequence(items, &trivia.node(struct_.id).dangling, |p, item| match item {
+            StructItem::Member(member) => p.member(member),
+            StructItem::Method(method) => p.function(method, true),
+        });
+    }
+
+    /// Writes a member of a struct, record, or interface, e.g. `owner: address,`.
+    fn member(&mut self, member: &Member) {
+        self.write(&format!("{}{}: ", mode_prefix(member.mode), member.identifier));
+        self.type_(&member.type_);
+        if let Some(default) = &member.default {
+            self.write(" = ");
+            self.expression(default);
+        }
+        self.write(",");
+    }
+
+    /// Writes a function, followed by its finalize block, if any.
+    /// Methods are parsed as inline functions, but are written as functions, e.g. `function norm(self) -> u32`.
+    fn function(&mut self, function: &Function, is_method: bool) {
+        for annotation in &function.annotations {
+            self.write(&annotation.to_string());
+            self.newline();
+            self.write_indent();
+        }
+        let variant = match function.variant {
+            _ if is_method => "function",
+            Variant::Inline => "inline",
+            Variant::Standard => "function",
+            Variant::Transition => "transition",
+        };
+        self.write(&format!("{}{variant} {}", function.visibility, function.identifier));
+        self.signature(&function.input, &function.output);
+        self.block(&function.block);
+
+        if let Some(finalize) = &function.finalize {
+            self.newline();
+            let trivia = self.trivia.node(finalize.id);
+            self.leading_comments(&trivia.leading);
+            if trivia.blank_line_before {
+                self.newline();
+            }
+            self.write_indent();
+            self.write(&format!("finalize {}", finalize.identifier));
+            self.signature(&finalize.input, &finalize.output);
+            self.block(&finalize.block);
+        }
+    }
+
+    /// Writes the inputs and outputs of a function, e.g. `(a: u8) -> u8 `.
+    fn signature(&mut self, inputs: &[Input], outputs: &[Output]) {
+        // The inputs are broken into one per line if the outputs and the opening brace do not fit after them.
+        let mut flat = self.flat();
+        flat.outputs(outputs);
+        self.list_followed_by(("(", ")"), false, inputs, flat.output.chars().count() + 1, |p, input| match input {
+            Input::Internal(input) if input.identifier.name == sym::SelfLower => {
+                p.write(&format!("{}self", mode_prefix(input.mode)))
+            }
+            Input::Internal(input) => {
+                p.write(&format!("{}{}: ", mode_prefix(input.mode), input.identifier));
+                p.type_(&input.type_);
+            }
+            Input::External(external) => p.write(&format!("{}: {}", external.identifier, external_type(external))),
+        });
+        self.outputs(outputs);
+    }
+
+    /// Writes the outputs of a function, e.g. ` -> (u8, u8) `.
+    fn outputs(&mut self, outputs: &[Output]) {
+        let write_output = |p: &mut Self, output: &Output| match output {
+            Output::Internal(output) => {
+                p.write(&mode_prefix(output.mode));
+                p.type_(&output.type_);
+            }
+            Output::External(external) => p.write(&external_type(external)),
+        };
+        match outputs {
+            [] => {}
+            [output] => {
+                self.write(" -> ");
+                write_output(self, output);
+            }
+            outputs => {
+                self.write(" -> ");
+                self.list_followed_by(("(", ")"), false, outputs, 2, write_output);
+            }
+        }
+        self.write(" ");
+    }
+}
+
+/// Returns the mode of an input, output, or member followed by a space, or nothing if the mode is not given.
+fn mode_prefix(mode: Mode) -> String {
+    match mode {
+        Mode::None => String::new(),
+        mode => format!("{mode} "),
+    }
+}
+
+/// Returns the type of a record of another program, e.g. `token.leo/Token.record` or `token.aleo/Token.record`.
+fn external_type(external: &External) -> String {
+    format!("{}.{}/{}.record", external.program_name, external.program_suffix(), external.record)
+}
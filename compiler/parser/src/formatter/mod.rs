@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The formatter, which prints a Leo program in its canonical form.
+//!
+//! The program is parsed into an AST, which is printed with consistent indentation, spacing, and line breaks.
+//! The comments of the program are printed with the items, members, and statements they are attached to by the parser.
+
+use crate::parse_with_trivia;
+
+use leo_ast::{Expression, NodeBuilder, Program, TriviaMap};
+use leo_errors::{emitter::Handler, Result};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+pub(crate) mod audit;
+pub use audit::check_printed_expression;
+mod expression;
+mod file;
+mod printer;
+use printer::Printer;
+mod statement;
+mod type_;
+pub use type_::type_string;
+
+/// The maximum width of a formatted line, beyond which lists are broken into one element per line.
+pub const MAX_LINE_WIDTH: usize = 120;
+
+/// Formats the Leo program `source` in the canonical style, preserving its comments.
+/// The imports of the program are not resolved.
+pub fn format_program(source: &str) -> Result<String> {
+    create_session_if_not_set_then(|s| {
+        let source_file = s.source_map.new_source(source, FileName::Custom("format".into()));
+
+        // Parse the program, returning the first error, if any.
+        let (handler, buffer) = Handler::new_with_buf();
+        let node_builder = NodeBuilder::default();
+        let (program, trivia) = parse_with_trivia(&handler, &node_builder, &source_file.src, source_file.start_pos)?;
+        if let Some(err) = buffer.extract_errs().into_inner().into_iter().next() {
+            return Err(err);
+        }
+
+        let mut printer = Printer::new(&trivia);
+        printer.program(&program);
+        Ok(printer.finish())
+    })
+}
+
+/// Prints the AST `program` in the canonical style, e.g. a program that was constructed rather than parsed.
+/// Since an AST has no comments, none are printed.
+pub fn print_program(program: &Program) -> String {
+    let trivia = TriviaMap::default();
+    let mut printer = Printer::new(&trivia);
+    printer.program(program);
+    printer.finish()
+}
+
+/// Prints the AST `expression` in the canonical style, with only the parentheses that the precedence and
+/// associativity of its operators require, e.g. `(a + b) * c` but `a + b * c`.
+pub fn print_expression(expression: &Expression) -> String {
+    let trivia = TriviaMap::default();
+    let mut printer = Printer::new(&trivia);
+    printer.expression(expression);
+    printer.output
+}
@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::MAX_LINE_WIDTH;
+
+use leo_ast::{Comment, Trivia, TriviaMap};
+
+/// The indentation of each level of nesting.
+const INDENT: &str = "    ";
+
+/// Prints an AST as formatted source code, along with the comments attached to its nodes.
+pub(super) struct Printer<'a> {
+    /// The comments and blank lines around the nodes of the AST.
+    pub(super) trivia: &'a TriviaMap,
+    /// The formatted source code.
+    pub(super) output: String,
+    /// The current level of nesting.
+    indent: usize,
+    /// The width beyond which lists are broken into one element per line.
+    max_width: usize,
+    /// true if a struct initialization must be parenthesized, e.g. in the condition of an `if` statement.
+    pub(super) disallow_struct_construction: bool,
+}
+
+impl<'a> Printer<'a> {
+    /// Returns a new printer for an AST with the given trivia.
+    pub(super) fn new(trivia: &'a TriviaMap) -> Self {
+        Self {
+            trivia,
+            output: String::new(),
+            indent: 0,
+            max_width: MAX_LINE_WIDTH,
+            disallow_struct_construction: false,
+        }
+    }
+
+    /// Returns the formatted source code, which ends with a single line break.
+    pub(super) fn finish(self) -> String {
+        let mut output = self.output.trim_end().to_string();
+        output.push('\n');
+        output
+    }
+
+    /// Writes `text` to the output.
+    pub(super) fn write(&mut self, text: &str) {
+        self.output.push_str(text);
+    }
+
+    /// Starts a new line.
+    pub(super) fn newline(&mut self) {
+        self.output.push('\n');
+    }
+
+    /// Writes the indentation of the current level of nesting.
+    pub(super) fn write_indent(&mut self) {
+        for _ in 0..self.indent {
+            self.output.push_str(INDENT);
+        }
+    }
+
+    /// Returns the number of characters written on the current line.
+    fn column(&self) -> usize {
+        self.output.rsplit('\n').next().map_or(0, |line| line.chars().count())
+    }
+
+    /// Writes `comments` each on its own line, keeping the blank lines before them.
+    pub(super) fn leading_comments(&mut self, comments: &[Comment]) {
+        for comment in comments {
+            if comment.blank_line_before {
+                self.newline();
+            }
+            self.write_indent();
+            self.write(&comment.text);
+            self.newline();
+        }
+    }
+
+    /// Writes `comments` after an element on the line it ends on.
+    fn trailing_comments(&mut self, comments: &[Comment]) {
+        let mut after_line_comment = false;
+        for comment in comments {
+            match after_line_comment {
+                // Nothing can be written after a line comment on the same line.
+                true => {
+                    self.newline();
+                    self.write_indent();
+                }
+                false => self.write(" "),
+            }
+            self.write(&comment.text);
+            after_line_comment = comment.is_line_comment();
+        }
+    }
+
+    /// Writes `elements` one per line, each preceded by its leading comments and followed by its trailing comments,
+    /// followed by the `dangling` comments of the enclosing node.
+    /// Blank lines between the elements in the source code are kept, but at most one at a time.
+    pub(super) fn sequence<T>(
+        &mut self,
+        elements: impl IntoIterator<Item = (&'a Trivia, T)>,
+        dangling: &[Comment],
+        mut write_element: impl FnMut(&mut Self, T),
+    ) {
+        for (trivia, element) in elements {
+            self.leading_comments(&trivia.leading);
+            if trivia.blank_line_before {
+                self.newline();
+            }
+            self.write_indent();
+            write_element(self, element);
+            self.trailing_comments(&trivia.trailing);
+            self.newline();
+        }
+        self.leading_comments(dangling);
+    }
+
+    /// Writes a block of `elements` enclosed in braces, see [`Printer::sequence`].
+    /// The block is written as `{}` if it contains neither elements nor comments.
+    pub(super) fn braced_sequence<T>(
+        &mut self,
+        elements: Vec<(&'a Trivia, T)>,
+        dangling: &[Comment],
+        write_element: impl FnMut(&mut Self, T),
+    ) {
+        if elements.is_empty() && dangling.is_empty() {
+            return self.write("{}");
+        }
+        self.write("{");
+        self.newline();
+        self.indent += 1;
+        self.sequence(elements, dangling, write_element);
+        self.indent -= 1;
+        self.write_indent();
+        self.write("}");
+    }
+
+    /// Returns a printer that writes lists on a single line, at the current indentation.
+    /// An element with comments is written on several lines, and so does not fit on a single line.
+    pub(super) fn flat(&self) -> Printer<'a> {
+        Printer {
+            trivia: self.trivia,
+            output: String::new(),
+            indent: self.indent,
+            max_width: usize::MAX,
+            disallow_struct_construction: false,
+        }
+    }
+
+    /// Writes `elements` separated by commas and enclosed by `open` and `close` on the current line, if they fit,
+    /// and otherwise one element per line, each followed by a comma.
+    /// If `padded`, then a space is written inside the delimiters of a list written on a single line.
+    pub(super) fn list<T>(
+        &mut self,
+        delimiters: (&str, &str),
+        padded: bool,
+        elements: &[T],
+        write_element: impl FnMut(&mut Self, &T),
+    ) {
+        self.list_followed_by(delimiters, padded, elements, 0, write_element)
+    }
+
+    /// Writes a list as in [`Printer::list`], where the list only fits on the current line
+    /// if `trailing_width` more characters fit after it.
+    pub(super) fn list_followed_by<T>(
+        &mut self,
+        (open, close): (&str, &str),
+        padded: bool,
+        elements: &[T],
+        trailing_width: usize,
+        mut write_element: impl FnMut(&mut Self, &T),
+    ) {
+        // Write the list on a single line, without breaking any nested lists.
+        let mut flat = self.flat();
+        flat.write(open);
+        for (i, element) in elements.iter().enumerate() {
+            flat.write(match (i, padded) {
+                (0, true) => " ",
+                (0, false) => "",
+                _ => ", ",
+            });
+            write_element(&mut flat, element);
+        }
+        flat.write(if padded && !elements.is_empty() { " " } else { "" });
+        flat.write(close);
+
+        let width = self.column() + flat.output.chars().count() + trailing_width;
+        let fits = !flat.output.contains('\n') && width <= self.max_width;
+        if elements.is_empty() || fits {
+            return self.write(&flat.output);
+        }
+
+        // Otherwise, write each element on its own line.
+        self.write(open);
+        self.indent += 1;
+        for element in elements {
+            self.newline();
+            self.write_indent();
+            write_element(self, element);
+            self.write(",");
+        }
+        self.indent -= 1;
+        self.newline();
+        self.write_indent();
+        self.write(close);
+    }
+}
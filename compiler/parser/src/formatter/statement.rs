@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Printer;
+
+use leo_ast::*;
+
+impl Printer<'_> {
+    /// Writes a statement.
+    pub(super) fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Assert(assert) => match &assert.variant {
+                AssertVariant::Assert(expression) => self.call_statement("assert", &[expression]),
+                AssertVariant::AssertEq(left, right) => self.call_statement("assert_eq", &[left, right]),
+                AssertVariant::AssertNeq(left, right) => self.call_statement("assert_neq", &[left, right]),
+            },
+            Statement::Assign(assign) => self.assign_statement(assign),
+            Statement::Block(block) => self.block(block),
+            Statement::Conditional(conditional) => self.conditional_statement(conditional),
+            Statement::Console(console) => match &console.function {
+                ConsoleFunction::Assert(expression) => self.call_statement("console.assert", &[expression]),
+                ConsoleFunction::AssertEq(left, right) => self.call_statement("console.assert_eq", &[left, right]),
+                ConsoleFunction::AssertNeq(left, right) => self.call_statement("console.assert_neq", &[left, right]),
+                ConsoleFunction::Log(args) => {
+                    self.write("console.log(\"");
+                    args.string.iter().for_each(|part| self.write(&part.to_string()));
+                    self.write("\"");
+                    for parameter in &args.parameters {
+                        self.write(", ");
+                        self.expression(parameter);
+                    }
+                    self.write(");");
+                }
+            },
+            Statement::Const(declaration) => self.const_declaration(declaration),
+            Statement::Declaration(declaration) => {
+                self.write(&format!("let {}: ", declaration.identifier));
+                self.type_(&declaration.type_);
+                self.write(";");
+            }
+            Statement::Definition(definition) => {
+                self.write(&format!("{} ", definition.declaration_type));
+                self.expression(&definition.place);
+                if let Some(type_) = &definition.type_ {
+                    self.write(": ");
+                    self.type_(type_);
+                }
+                self.write(" = ");
+                self.expression(&definition.value);
+                self.write(";");
+            }
+            Statement::Err(err) => self.write(&err.to_string()),
+            Statement::Expression(statement) => {
+                self.expression(&statement.expression);
+                self.write(";");
+            }
+            Statement::Iteration(iteration) => {
+                self.write(&format!("for {}: ", iteration.variable));
+                self.type_(&iteration.type_);
+                self.write(" in ");
+                self.expression(&iteration.start);
+                self.write("..");
+                self.condition(&iteration.stop);
+                self.write(" ");
+                self.block(&iteration.block);
+            }
+            Statement::Match(match_) => self.match_statement(match_),
+            Statement::Return(return_) => {
+                self.write("return");
+                if !matches!(return_.expression, Expression::Unit(_)) {
+                    self.write(" ");
+                    self.expression(&return_.expression);
+                }
+                if let Some(arguments) = &return_.finalize_arguments {
+                    self.write(" then finalize");
+                    self.list(("(", ")"), false, arguments, Self::expression);
+                }
+                self.write(";");
+            }
+        }
+    }
+
+    /// Writes a block of statements, preceded by its annotations, e.g. `@wrapping { ... }`.
+    pub(super) fn block(&mut self, block: &Block) {
+        for annotation in &block.annotations {
+            self.write(&format!("{annotation} "));
+        }
+        let trivia = self.trivia;
+        let statements = block.statements.iter().map(|statement| (trivia.node(statement.id()), statement)).collect();
+        self.braced_sequence(statements, &trivia.node(block.id).dangling, Self::statement);
+    }
+
+    /// Writes a constant declaration, e.g. `const N: u32 = 3u32;`.
+    pub(super) fn const_declaration(&mut self, declaration: &ConstDeclaration) {
+        self.write(&format!("const {}: ", declaration.place));
+        self.type_(&declaration.type_);
+        self.write(" = ");
+        self.expression(&declaration.value);
+        self.write(";");
+    }
+
+    /// Writes a statement that is written like a call, e.g. `assert_eq(a, b);`.
+    /// The arguments are written on a single line, since they cannot be followed by a trailing comma.
+    fn call_statement(&mut self, name: &str, arguments: &[&Expression]) {
+        self.write(&format!("{name}("));
+        for (i, argument) in arguments.iter().enumerate() {
+            if i > 0 {
+                self.write(", ");
+            }
+            self.expression(argument);
+        }
+        self.write(");");
+    }
+
+    /// Writes an assignment, which the parser desugars from e.g. `x += 1` into `x = x + 1`.
+    fn assign_statement(&mut self, assign: &AssignStatement) {
+        self.expression(&assign.place);
+        match &assign.value {
+            // The left operand of a desugared assignment is a copy of the place, with the same span.
+            // The operand is compared too, since the nodes of a constructed AST may all have the default span.
+            Expression::Binary(binary)
+                if binary.left.span() == assign.place.span()
+                    && is_compound(binary.op)
+                    && binary.left.to_string() == assign.place.to_string() =>
+            {
+                self.write(&format!(" {}= ", binary.op));
+                self.expression(&binary.right);
+            }
+            value => {
+                self.write(" = ");
+                self.expression(value);
+            }
+        }
+        self.write(";");
+    }
+
+    /// Writes an `if` statement, followed by its `else` branch, if any.
+    fn conditional_statement(&mut self, conditional: &ConditionalStatement) {
+        self.write("if ");
+        self.condition(&conditional.condition);
+        self.write(" ");
+        self.block(&conditional.then);
+        if let Some(otherwise) = &conditional.otherwise {
+            self.write(" else ");
+            self.statement(otherwise);
+        }
+    }
+
+    /// Writes a `match` statement, with one arm per line.
+    fn match_statement(&mut self, match_: &MatchStatement) {
+        self.write("match ");
+        self.condition(&match_.value);
+        self.write(" ");
+        let trivia = self.trivia;
+        let arms = match_.arms.iter().map(|arm| (trivia.node(arm.id), arm)).collect();
+        self.braced_sequence(arms, &trivia.node(match_.id).dangling, |printer, arm| {
+            match &arm.pattern {
+                Some(pattern) => printer.expression(pattern),
+                None => printer.write("_"),
+            }
+            printer.write(" => ");
+            printer.block(&arm.block);
+        });
+    }
+}
+
+/// Returns `true` if the binary operation `operation` has a compound assignment, e.g. `+=`.
+fn is_compound(operation: BinaryOperation) -> bool {
+    use BinaryOperation::*;
+    matches!(operation, Add | Sub | Mul | Div | Rem | Pow | Or | And | BitwiseAnd | BitwiseOr | Xor | Shl | Shr)
+}
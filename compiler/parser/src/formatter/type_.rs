@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Printer;
+
+use leo_ast::{ConstArgument, Type};
+
+impl Printer<'_> {
+    /// Writes a type as it is written in Leo code.
+    pub(super) fn type_(&mut self, type_: &Type) {
+        self.write(&type_string(type_));
+    }
+}
+
+/// Returns the Leo code for a type, which differs from its [`Display`](std::fmt::Display) for some types.
+pub fn type_string(type_: &Type) -> String {
+    match type_ {
+        Type::Array(array_type) => {
+            format!("[{}; {}]", type_string(array_type.element_type()), array_type.array_length())
+        }
+        Type::Boolean => "bool".to_string(),
+        Type::Generic(generic_type) => format!("{}<{}>", generic_type.name, const_arguments(&generic_type.arguments)),
+        Type::Tuple(tuple_type) => {
+            format!("({})", tuple_type.elements().iter().map(type_string).collect::<Vec<_>>().join(", "))
+        }
+        type_ => type_.to_string(),
+    }
+}
+
+/// Returns the Leo code for the constant arguments of a generic struct, without the angle brackets.
+pub(super) fn const_arguments(arguments: &[ConstArgument]) -> String {
+    arguments.iter().map(|argument| argument.to_string()).collect::<Vec<_>>().join(", ")
+}
@@ -0,0 +1,563 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{parse_ast_with_imports, parse_module, parse_stub};
+
+use leo_ast::{Identifier, ImportStep, ImportTrace, Module, NodeBuilder, Program, ProgramId, ProgramScope, Stub};
+use leo_errors::{emitter::Handler, CompilerError, Result};
+use leo_span::{
+    source_map::{FileName, SourceFile},
+    symbol::with_session_globals,
+    Span,
+    Symbol,
+};
+
+use indexmap::IndexMap;
+use std::{
+    cell::RefCell,
+    fs,
+    iter,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// Resolves the imports of a program to files on disk, and parses the imported programs.
+/// An import `import foo.leo;` is resolved to the first file `foo.leo` found in the search paths,
+/// which are the `imports` directory of the current working directory followed by any additional search paths,
+/// e.g. a cache of downloaded programs. An import of a deployed program `import foo.aleo;` is resolved to the stub
+/// `foo.aleo` in the same way, which holds the Aleo instructions of the program.
+/// A resolver built for a package only resolves the dependencies declared by its manifest, to their declared files.
+/// A resolver with an import cache reuses the programs it parsed in earlier builds for the files that are unchanged.
+#[derive(Clone, Debug, Default)]
+pub struct ImportResolver {
+    /// The directories searched for imported files after the `imports` directory, in order.
+    search_paths: Vec<PathBuf>,
+    /// The files of the programs that may be imported, by name, if imports are restricted to declared dependencies.
+    dependencies: Option<IndexMap<String, PathBuf>>,
+    /// The trace recording the steps of import resolution.
+    import_trace: ImportTrace,
+    /// The imports being loaded, along with the spans of their import statements, from the outermost one.
+    /// `RefCell` is used here, since imports are loaded through a shared reference.
+    loading: RefCell<Vec<(Symbol, Span)>>,
+    /// The programs directly imported in earlier builds, by path, if imports are cached.
+    cache: Option<RefCell<IndexMap<PathBuf, CachedImport>>>,
+    /// The files read while loading the current direct import, if imports are cached.
+    sources: RefCell<Vec<Arc<SourceFile>>>,
+    /// The maximum depth that expressions, blocks, and types may be nested to in the parsed files, if any.
+    max_depth: Option<usize>,
+}
+
+/// A program parsed from the file of a direct import, along with every file that was read to parse it.
+#[derive(Clone, Debug)]
+struct CachedImport {
+    /// The files read, i.e. the imported file, the files of its own imports, and those of its modules.
+    sources: Vec<Arc<SourceFile>>,
+    /// The parsed program.
+    program: Program,
+}
+
+impl ImportResolver {
+    /// Returns a new resolver, which searches `search_paths` after the `imports` directory.
+    pub fn new(search_paths: Vec<PathBuf>, import_trace: ImportTrace) -> Self {
+        Self { search_paths, import_trace, ..Default::default() }
+    }
+
+    /// Caches the programs directly imported by the main program, so that a later build with the same resolver only
+    /// parses the imports whose files, or the files of their own imports, have changed.
+    /// Note that the `NodeID`s of a reused program were assigned in an earlier build, so every build with the resolver
+    /// must assign `NodeID`s with the same `NodeBuilder` for them to stay unique.
+    pub fn with_import_cache(mut self) -> Self {
+        self.cache = Some(Default::default());
+        self
+    }
+
+    /// Restricts imports to `dependencies`, which maps the name of each importable program to its file.
+    /// The search paths are not searched for such a resolver.
+    pub fn with_dependencies(mut self, dependencies: IndexMap<String, PathBuf>) -> Self {
+        self.dependencies = Some(dependencies);
+        self
+    }
+
+    /// Limits the depth that expressions, blocks, and types may be nested to in the main program and every file it
+    /// imports, so that a deeply nested program is rejected with an error rather than overflowing the stack.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Returns the maximum depth that expressions, blocks, and types may be nested to in the parsed files, if any.
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Returns the trace recording the steps of import resolution.
+    pub fn import_trace(&self) -> &ImportTrace {
+        &self.import_trace
+    }
+
+    /// Returns the directories searched for imported files, in order.
+    pub fn search_paths(&self, span: Span) -> Result<Vec<PathBuf>> {
+        let imports_directory =
+            std::env::current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, span))?.join("imports");
+        Ok(std::iter::once(imports_directory).chain(self.search_paths.iter().cloned()).collect())
+    }
+
+    /// Returns the path of the file imported as `import`, where `span` is the span of the import statement.
+    pub fn resolve(&self, import: &Identifier, span: Span) -> Result<PathBuf> {
+        self.resolve_with_extension(import, "leo", span)
+    }
+
+    /// Returns the path of the file imported as `import`, whose extension is `extension` unless it is a dependency.
+    fn resolve_with_extension(&self, import: &Identifier, extension: &str, span: Span) -> Result<PathBuf> {
+        let candidates = match &self.dependencies {
+            Some(dependencies) => match dependencies.get(&import.name.to_string()) {
+                Some(path) => vec![path.clone()],
+                None => return Err(CompilerError::undeclared_dependency(import, span).into()),
+            },
+            None => self
+                .search_paths(span)?
+                .into_iter()
+                .map(|directory| directory.join(format!("{}.{extension}", import.name)))
+                .collect(),
+        };
+
+        let mut missing = Vec::new();
+        for path in candidates {
+            let exists = path.exists();
+            self.import_trace.record(|| ImportStep::CandidatePath {
+                import: import.to_string(),
+                path: path.clone(),
+                exists,
+            });
+            match exists {
+                true => return Ok(path),
+                false => missing.push(path),
+            }
+        }
+
+        let candidates = missing.iter().map(|path| format!("`{}`", path.display())).collect::<Vec<_>>().join(", ");
+        Err(CompilerError::unresolved_import(import, candidates, span).into())
+    }
+
+    /// Resolves the file imported as `import`, and parses the program it contains.
+    pub fn load(
+        &self,
+        handler: &Handler,
+        node_builder: &NodeBuilder,
+        import: &Identifier,
+        span: Span,
+    ) -> Result<Program> {
+        self.check_cycle(import, span)?;
+        let path = self.resolve(import, span)?;
+
+        // Only the direct imports of the main program are cached, so that a cached program is used at most once.
+        let is_direct = self.loading.borrow().is_empty();
+        if is_direct {
+            if let Some(program) = self.reuse(&path) {
+                self.import_trace.record(|| ImportStep::Reused { import: import.to_string(), path });
+                return Ok(program);
+            }
+            self.sources.borrow_mut().clear();
+        }
+
+        // Read the import file and register it in the source map.
+        let prg_sf = self.register(&path)?;
+
+        // Use the parser to construct the imported abstract syntax tree (ast), resolving its own imports in turn.
+        self.loading.borrow_mut().push((import.name, span));
+        let program_ast = parse_ast_with_imports(handler, node_builder, self, &prg_sf.src, prg_sf.start_pos);
+        self.loading.borrow_mut().pop();
+        let program_ast = program_ast?;
+        self.import_trace.record(|| ImportStep::Loaded {
+            import: import.to_string(),
+            path: path.clone(),
+            programs: program_ast.as_repr().program_scopes.keys().map(|name| name.to_string()).collect(),
+        });
+
+        let program = program_ast.into_repr();
+        if let Some(cache) = self.cache.as_ref().filter(|_| is_direct) {
+            let sources = self.sources.take();
+            cache.borrow_mut().insert(path, CachedImport { sources, program: program.clone() });
+        }
+        Ok(program)
+    }
+
+    /// Resolves the stub of the deployed program imported as `import`, and reads the signatures of the program.
+    pub fn load_stub(&self, node_builder: &NodeBuilder, import: &Identifier, span: Span) -> Result<Stub> {
+        let path = self.resolve_with_extension(import, "aleo", span)?;
+        let source_file = self.register(&path)?;
+        let stub = parse_stub(node_builder, &source_file.src, source_file.start_pos)?;
+        if stub.program_id.name.name != import.name {
+            let error = CompilerError::program_name_should_match_file_name(stub.program_id, path.display(), stub.span);
+            return Err(error.into());
+        }
+
+        self.import_trace.record(|| ImportStep::Loaded {
+            import: import.to_string(),
+            path,
+            programs: vec![stub.program_id.name.to_string()],
+        });
+        Ok(stub)
+    }
+
+    /// Reads the file at `path` and registers it in the source map, recording it if imports are cached.
+    fn register(&self, path: &Path) -> Result<Arc<SourceFile>> {
+        let contents = fs::read_to_string(path).map_err(|e| CompilerError::file_read_error(path, e))?;
+        let source_file =
+            with_session_globals(|s| s.source_map.new_source(&contents, FileName::Real(path.to_path_buf())));
+        if self.cache.is_some() {
+            self.sources.borrow_mut().push(source_file.clone());
+        }
+        Ok(source_file)
+    }
+
+    /// Returns the program cached for the direct import at `path`, if none of the files read to parse it changed.
+    fn reuse(&self, path: &Path) -> Option<Program> {
+        let cache = self.cache.as_ref()?.borrow();
+        let cached = cache.get(path)?;
+        let unchanged = cached.sources.iter().all(|source| match &source.name {
+            FileName::Real(path) => fs::read_to_string(path).ok().as_ref() == Some(&source.src),
+            FileName::Custom(_) => false,
+        });
+        unchanged.then(|| cached.program.clone())
+    }
+
+    /// Returns `true` if `file` was read to parse a cached import, so that it must stay in the source map for the
+    /// spans of the import to remain valid when it is reused.
+    pub fn is_cached_source(&self, file: &SourceFile) -> bool {
+        self.cache.as_ref().map_or(false, |cache| {
+            cache.borrow().values().flat_map(|cached| &cached.sources).any(|source| source.start_pos == file.start_pos)
+        })
+    }
+
+    /// Returns an error if `import`, whose import statement has the span `span`, is already being loaded.
+    /// The error reports the chain of imports from the program first importing `import` back to itself,
+    /// along with the import statements forming it.
+    fn check_cycle(&self, import: &Identifier, span: Span) -> Result<()> {
+        let loading = self.loading.borrow();
+        let Some(start) = loading.iter().position(|(name, _)| *name == import.name) else {
+            return Ok(());
+        };
+
+        let cycle = loading[start..].iter().map(|(name, _)| *name).chain(iter::once(import.name)).collect::<Vec<_>>();
+        let statements = loading[start + 1..]
+            .iter()
+            .map(|(_, span)| *span)
+            .chain(iter::once(span))
+            .map(|span| {
+                with_session_globals(|s| {
+                    let statement = s.source_map.contents_of_span(span).unwrap_or_default();
+                    match s.source_map.span_to_location(span) {
+                        Some(location) => format!(
+                            "`{statement}` in {}:{}:{}",
+                            location.source_file.name, location.line_start, location.col_start
+                        ),
+                        None => format!("`{statement}`"),
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        self.import_trace.record(|| ImportStep::CycleDetected {
+            import: import.to_string(),
+            cycle: cycle.iter().map(|name| name.to_string()).collect(),
+        });
+        Err(CompilerError::cyclic_import(cycle, statements, span).into())
+    }
+
+    /// Returns the path of the file of the module `module`, which is in the directory of the file declaring it.
+    /// The modules of a program given as a string are resolved to the current working directory.
+    pub fn resolve_module(&self, module: &Module) -> Result<PathBuf> {
+        let declaring_file = with_session_globals(|s| s.source_map.span_to_location(module.span))
+            .map(|location| location.source_file.name.clone());
+        let directory = match declaring_file {
+            Some(FileName::Real(path)) => path.parent().map_or_else(PathBuf::new, Path::to_path_buf),
+            _ => std::env::current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, module.span))?,
+        };
+
+        let path = directory.join(format!("{}.leo", module.name));
+        match path.exists() {
+            true => Ok(path),
+            false => Err(CompilerError::unresolved_module(module.name, path.display(), module.span).into()),
+        }
+    }
+
+    /// Resolves the file of the module `module` of the program `program_id`, and parses the items it contains.
+    /// The names of the items are qualified with the name of the module.
+    pub fn load_module(
+        &self,
+        handler: &Handler,
+        node_builder: &NodeBuilder,
+        program_id: ProgramId,
+        module: &Module,
+    ) -> Result<ProgramScope> {
+        let path = self.resolve_module(module)?;
+        let source_file = self.register(&path)?;
+        parse_module(handler, node_builder, program_id, module, &source_file.src, source_file.start_pos, self.max_depth)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_ast::{AccessExpression, Expression, ReturnStatement, Statement};
+    use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+    fn parse(source: &str, import_resolver: &ImportResolver) -> Result<Program> {
+        let source_file = with_session_globals(|s| s.source_map.new_source(source, FileName::Custom("test".into())));
+        let node_builder = NodeBuilder::default();
+        crate::parse_with_imports(
+            &Handler::default(),
+            &node_builder,
+            import_resolver,
+            &source_file.src,
+            source_file.start_pos,
+        )
+    }
+
+    #[test]
+    fn test_missing_import() {
+        create_session_if_not_set_then(|_| {
+            let cache = std::env::temp_dir().join("leo-import-resolver-missing");
+            let import_resolver = ImportResolver::new(vec![cache.clone()], ImportTrace::new(true));
+            let error = parse("import missing.leo;\nprogram test.aleo {}\n", &import_resolver).unwrap_err();
+            assert!(error.to_string().contains("Could not resolve the import `missing`"));
+
+            let imports = std::env::current_dir().unwrap().join("imports");
+            assert_eq!(import_resolver.import_trace().steps(), [
+                ImportStep::CandidatePath {
+                    import: "missing".to_string(),
+                    path: imports.join("missing.leo"),
+                    exists: false
+                },
+                ImportStep::CandidatePath {
+                    import: "missing".to_string(),
+                    path: cache.join("missing.leo"),
+                    exists: false
+                },
+            ]);
+        })
+    }
+
+    #[test]
+    fn test_import_from_search_path() {
+        create_session_if_not_set_then(|_| {
+            let cache = std::env::temp_dir().join(format!("leo-import-resolver-{}", std::process::id()));
+            fs::create_dir_all(&cache).unwrap();
+            fs::write(cache.join("token.leo"), "program token.aleo {\n    record Token { owner: address }\n}\n")
+                .unwrap();
+
+            let import_resolver = ImportResolver::new(vec![cache.clone()], ImportTrace::new(true));
+            let program = parse("import token.leo;\nprogram test.aleo {}\n", &import_resolver);
+            fs::remove_dir_all(&cache).unwrap();
+
+            let program = program.unwrap();
+            let (import, _) = &program.imports.values().next().unwrap();
+            assert_eq!(import.program_scopes.keys().map(|name| name.to_string()).collect::<Vec<_>>(), ["token"]);
+            assert_eq!(import_resolver.import_trace().steps().last(), Some(&ImportStep::Loaded {
+                import: "token".to_string(),
+                path: cache.join("token.leo"),
+                programs: vec!["token".to_string()],
+            }));
+        })
+    }
+
+    #[test]
+    fn test_import_stub_from_search_path() {
+        create_session_if_not_set_then(|_| {
+            let cache = std::env::temp_dir().join(format!("leo-import-stub-{}", std::process::id()));
+            fs::create_dir_all(&cache).unwrap();
+            let stub = "program token.aleo;\n\nfunction mint:\n    input r0 as u64.public;\n    output r0 as u64;\n";
+            fs::write(cache.join("token.aleo"), stub).unwrap();
+            fs::write(cache.join("coin.aleo"), stub).unwrap();
+
+            let import_resolver = ImportResolver::new(vec![cache.clone()], ImportTrace::new(true));
+            let program = parse("import token.aleo;\nprogram test.aleo {}\n", &import_resolver);
+            let error = parse("import coin.aleo;\nprogram test.aleo {}\n", &import_resolver).unwrap_err();
+            fs::remove_dir_all(&cache).unwrap();
+
+            let program = program.unwrap();
+            let (stub, _) = &program.stubs[&Symbol::intern("token")];
+            assert_eq!(stub.functions.iter().map(|(name, _)| name.to_string()).collect::<Vec<_>>(), ["mint"]);
+            assert!(error.to_string().contains("Program name `token.aleo` should match file name"), "{error}");
+            assert!(import_resolver.import_trace().steps().contains(&ImportStep::Loaded {
+                import: "token".to_string(),
+                path: cache.join("token.aleo"),
+                programs: vec!["token".to_string()],
+            }));
+        })
+    }
+
+    #[test]
+    fn test_cyclic_import() {
+        create_session_if_not_set_then(|_| {
+            let cache = std::env::temp_dir().join(format!("leo-import-cycle-{}", std::process::id()));
+            fs::create_dir_all(&cache).unwrap();
+            for (name, import) in [("a", "b"), ("b", "c"), ("c", "a")] {
+                let source = format!("import {import}.leo;\nprogram {name}.aleo {{}}\n");
+                fs::write(cache.join(format!("{name}.leo")), source).unwrap();
+            }
+
+            let import_resolver = ImportResolver::new(vec![cache.clone()], ImportTrace::new(true));
+            let error = parse("import a.leo;\nprogram test.aleo {}\n", &import_resolver).unwrap_err().to_string();
+            fs::remove_dir_all(&cache).unwrap();
+
+            assert!(error.contains("Cyclic dependency between imports: `a` --> `b` --> `c` --> `a`"), "{error}");
+            for (name, import) in [("a", "b"), ("b", "c"), ("c", "a")] {
+                let path = cache.join(format!("{name}.leo"));
+                let statement = format!("`import {import}.leo;` in {}:1:1", path.display());
+                assert!(error.contains(&statement), "{error}");
+            }
+            assert_eq!(import_resolver.import_trace().steps().last(), Some(&ImportStep::CycleDetected {
+                import: "a".to_string(),
+                cycle: vec!["a".to_string(), "b".to_string(), "c".to_string(), "a".to_string()],
+            }));
+        })
+    }
+
+    #[test]
+    fn test_import_declared_dependency() {
+        create_session_if_not_set_then(|_| {
+            let directory = std::env::temp_dir().join(format!("leo-import-dependencies-{}", std::process::id()));
+            fs::create_dir_all(&directory).unwrap();
+            let path = directory.join("coin.leo");
+            fs::write(&path, "program token.aleo {\n    record Token { owner: address }\n}\n").unwrap();
+
+            let dependencies = IndexMap::from([("token".to_string(), path.clone())]);
+            let import_resolver = ImportResolver::default().with_dependencies(dependencies);
+            let program = parse("import token.leo;\nprogram test.aleo {}\n", &import_resolver);
+            let error = parse("import other.leo;\nprogram test.aleo {}\n", &import_resolver).unwrap_err();
+            fs::remove_dir_all(&directory).unwrap();
+
+            let program = program.unwrap();
+            let (import, _) = &program.imports.values().next().unwrap();
+            assert_eq!(import.program_scopes.keys().map(|name| name.to_string()).collect::<Vec<_>>(), ["token"]);
+            assert!(error.to_string().contains("Cannot import `other`, since it is not a declared dependency"));
+        })
+    }
+
+    #[test]
+    fn test_reuse_cached_import() {
+        create_session_if_not_set_then(|_| {
+            let cache = std::env::temp_dir().join(format!("leo-import-cache-{}", std::process::id()));
+            fs::create_dir_all(&cache).unwrap();
+            fs::write(cache.join("token.leo"), "import coin.leo;\nprogram token.aleo {}\n").unwrap();
+            fs::write(cache.join("coin.leo"), "program coin.aleo {}\n").unwrap();
+
+            let import_resolver = ImportResolver::new(vec![cache.clone()], ImportTrace::new(true)).with_import_cache();
+            let node_builder = NodeBuilder::default();
+            let mut reused = Vec::new();
+            for coin in ["program coin.aleo {}\n", "program coin.aleo {}\n", "program coin.aleo {\n}\n"] {
+                fs::write(cache.join("coin.leo"), coin).unwrap();
+                let source = "import token.leo;\nprogram test.aleo {}\n";
+                let name = FileName::Custom("test".into());
+                let source_file = with_session_globals(|s| s.source_map.new_source(source, name));
+                crate::parse_with_imports(
+                    &Handler::default(),
+                    &node_builder,
+                    &import_resolver,
+                    &source_file.src,
+                    source_file.start_pos,
+                )
+                .unwrap();
+                let step = ImportStep::Reused { import: "token".to_string(), path: cache.join("token.leo") };
+                reused.push(import_resolver.import_trace().steps().last() == Some(&step));
+            }
+            fs::remove_dir_all(&cache).unwrap();
+
+            // The import is reused until the file of its own import changes.
+            assert_eq!(reused, [false, true, false]);
+        })
+    }
+
+    /// Parses the program `main.leo` in a directory containing the given module files.
+    fn parse_with_modules(name: &str, main: &str, modules: &[(&str, &str)]) -> Result<Program> {
+        let directory = std::env::temp_dir().join(format!("leo-modules-{name}-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        for (module, source) in modules {
+            fs::write(directory.join(format!("{module}.leo")), source).unwrap();
+        }
+        let path = directory.join("main.leo");
+        let source_file = with_session_globals(|s| s.source_map.new_source(main, FileName::Real(path)));
+        let program = crate::parse_with_imports(
+            &Handler::default(),
+            &NodeBuilder::default(),
+            &ImportResolver::default(),
+            &source_file.src,
+            source_file.start_pos,
+        );
+        fs::remove_dir_all(&directory).unwrap();
+        program
+    }
+
+    #[test]
+    fn test_module_items_are_qualified() {
+        create_session_if_not_set_then(|_| {
+            let math = "const SCALE: u32 = 10u32;\n\
+                        struct Point { x: u32, y: u32 }\n\
+                        inline scale(p: Point) -> Point { return Point { x: p.x * SCALE, y: p.y }; }\n";
+            let main = "program test.aleo {\n\
+                        module math;\n\
+                        transition main(p: math::Point) -> math::Point { return math::scale(p); }\n\
+                        }\n";
+            let program = parse_with_modules("qualified", main, &[("math", math)]).unwrap();
+            let scope = program.program_scopes.values().next().unwrap();
+
+            let names = |items: Vec<Symbol>| items.into_iter().map(|name| name.to_string()).collect::<Vec<_>>();
+            assert_eq!(names(scope.modules.iter().map(|(name, _)| *name).collect()), ["math"]);
+            assert_eq!(names(scope.consts.iter().map(|(name, _)| *name).collect()), ["math__SCALE"]);
+            assert_eq!(names(scope.structs.iter().map(|(name, _)| *name).collect()), ["math__Point"]);
+            assert_eq!(names(scope.functions.iter().map(|(name, _)| *name).collect()), ["math__scale", "main"]);
+
+            // The references within the module are qualified, but the members of the struct are not.
+            let scale = &scope.functions[0].1;
+            assert_eq!(scale.input[0].type_().to_string(), "math__Point");
+            assert_eq!(scale.output_type.to_string(), "math__Point");
+            match &scale.block.statements[0] {
+                Statement::Return(ReturnStatement { expression: Expression::Struct(struct_), .. }) => {
+                    assert_eq!(struct_.name.to_string(), "math__Point");
+                    assert_eq!(struct_.members[0].to_string(), "x: p.x * math__SCALE");
+                }
+                statement => panic!("unexpected statement {statement}"),
+            }
+
+            // The qualified references of the program are resolved during type checking.
+            let main = &scope.functions[1].1;
+            assert_eq!(main.input[0].type_().to_string(), "math__Point");
+            match &main.block.statements[0] {
+                Statement::Return(ReturnStatement {
+                    expression: Expression::Access(AccessExpression::AssociatedFunction(call)),
+                    ..
+                }) => assert_eq!((call.ty.to_string(), call.name.to_string()), ("math".into(), "scale".into())),
+                statement => panic!("unexpected statement {statement}"),
+            }
+        })
+    }
+
+    #[test]
+    fn test_module_cannot_declare_transition() {
+        create_session_if_not_set_then(|_| {
+            let main = "program test.aleo {\n    module math;\n}\n";
+            let math = "transition add(a: u8, b: u8) -> u8 { return a + b; }\n";
+            let error = parse_with_modules("transition", main, &[("math", math)]).unwrap_err();
+            assert!(error.to_string().contains("The module `math` cannot declare a `transition`."), "{error}");
+
+            let error = parse_with_modules("missing", main, &[]).unwrap_err();
+            assert!(error.to_string().contains("Could not resolve the module `math`"), "{error}");
+        })
+    }
+}
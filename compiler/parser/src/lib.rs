@@ -28,10 +28,19 @@ use leo_span::span::BytePos;
 pub use tokenizer::KEYWORD_TOKENS;
 pub(crate) use tokenizer::*;
 
+pub mod formatter;
+pub use formatter::*;
+
+pub mod import_resolver;
+pub use import_resolver::*;
+
 pub mod parser;
 pub use parser::*;
 
-use leo_ast::{input::InputData, Ast, NodeBuilder, ProgramInput};
+pub mod stub;
+pub use stub::*;
+
+use leo_ast::{input::InputData, Ast, NodeBuilder, ProgramInput, TriviaMap};
 use leo_errors::{emitter::Handler, Result};
 
 #[cfg(test)]
@@ -42,6 +51,30 @@ pub fn parse_ast(handler: &Handler, node_builder: &NodeBuilder, source: &str, st
     Ok(Ast::new(parser::parse(handler, node_builder, source, start_pos)?))
 }
 
+/// Creates a new AST from a given file path and source code text,
+/// resolving its imports with `import_resolver`.
+pub fn parse_ast_with_imports(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    import_resolver: &ImportResolver,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<Ast> {
+    Ok(Ast::new(parser::parse_with_imports(handler, node_builder, import_resolver, source, start_pos)?))
+}
+
+/// Creates a new AST from a given file path and source code text,
+/// along with the comments and blank lines around its nodes. The imports of the program are not resolved.
+pub fn parse_ast_with_trivia(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<(Ast, TriviaMap)> {
+    let (program, trivia) = parser::parse_with_trivia(handler, node_builder, source, start_pos)?;
+    Ok((Ast::new(program), trivia))
+}
+
 /// Parses program inputs from the input file path
 pub fn parse_program_inputs(
     handler: &Handler,
@@ -14,13 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{tokenizer::*, Token};
+use crate::{tokenizer::*, ImportResolver, Token};
 
 use leo_ast::*;
-use leo_errors::{emitter::Handler, ParserError, ParserWarning, Result};
-use leo_span::{Span, Symbol};
+use leo_errors::{emitter::Handler, LeoError, ParserError, ParserWarning, Result};
+use leo_span::{span::BytePos, sym, Span, Symbol};
 
-use std::{fmt::Display, mem};
+use indexmap::IndexMap;
+use std::{fmt::Display, iter, mem};
 
 /// Stores a program in tokenized format plus additional context.
 /// May be converted into a [`Program`] AST by parsing all tokens.
@@ -41,6 +42,22 @@ pub(crate) struct ParserContext<'a> {
     pub(crate) disallow_struct_construction: bool,
     /// true if parsing an identifier inside an input file.
     pub(crate) allow_identifier_underscores: bool,
+    /// The struct whose method is being parsed, if any. This is the type of the `self` receiver.
+    pub(crate) receiver: Option<Identifier>,
+    /// The resolver of the imports of the program, if any.
+    pub(crate) import_resolver: Option<&'a ImportResolver>,
+    /// true if the programs that are imported are parsed, false if only the import statements are.
+    pub(crate) resolve_imports: bool,
+    /// The doc comments, keyed by the start of the token after them.
+    doc_comments: IndexMap<BytePos, String>,
+    /// The maximum depth that expressions, blocks, and types may be nested to, if any.
+    pub(crate) max_depth: Option<usize>,
+    /// The depth of the expression, block, or type being parsed.
+    depth: usize,
+    /// true if the syntax errors in statements and items are emitted, and the parser continues after them.
+    pub(crate) recover: bool,
+    /// The number of braces that are open at the current token.
+    brace_depth: usize,
 }
 
 /// Dummy span used to appease borrow checker.
@@ -49,8 +66,22 @@ const DUMMY_EOF: SpannedToken = SpannedToken { token: Token::Eof, span: Span::du
 impl<'a> ParserContext<'a> {
     /// Returns a new [`ParserContext`] type given a vector of tokens.
     pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder, mut tokens: Vec<SpannedToken>) -> Self {
-        // Strip out comments.
-        tokens.retain(|x| !matches!(x.token, Token::CommentLine(_) | Token::CommentBlock(_)));
+        // Strip out comments, keeping the text of the doc comments for the tokens after them.
+        let mut doc_comments = IndexMap::new();
+        let mut doc_lines = Vec::new();
+        tokens.retain(|x| match &x.token {
+            Token::CommentLine(comment) => {
+                doc_lines.extend(doc_comment_line(comment));
+                false
+            }
+            Token::CommentBlock(_) => false,
+            _ => {
+                if !doc_lines.is_empty() {
+                    doc_comments.insert(x.span.lo, mem::take(&mut doc_lines).join("\n"));
+                }
+                true
+            }
+        });
         // For performance we reverse so that we get cheap `.pop()`s.
         tokens.reverse();
 
@@ -60,6 +91,14 @@ impl<'a> ParserContext<'a> {
             node_builder,
             disallow_struct_construction: false,
             allow_identifier_underscores: false,
+            receiver: None,
+            import_resolver: None,
+            resolve_imports: true,
+            doc_comments,
+            max_depth: None,
+            depth: 0,
+            recover: false,
+            brace_depth: 0,
             prev_token: token.clone(),
             token,
             tokens,
@@ -68,6 +107,11 @@ impl<'a> ParserContext<'a> {
         p
     }
 
+    /// Returns the doc comment before the current token, if any.
+    pub(super) fn doc_comment(&self) -> Option<String> {
+        self.doc_comments.get(&self.token.span.lo).cloned()
+    }
+
     /// Advances the parser cursor by one token.
     ///
     /// So e.g., if we had `previous = A`, `current = B`, and `tokens = [C, D, E]`,
@@ -78,6 +122,13 @@ impl<'a> ParserContext<'a> {
             panic!("attempted to bump the parser past EOF (may be stuck in a loop)");
         }
 
+        // Keep track of the braces that are open, which delimit the statements and items to recover at.
+        match self.token.token {
+            Token::LeftCurly => self.brace_depth += 1,
+            Token::RightCurly => self.brace_depth = self.brace_depth.saturating_sub(1),
+            _ => {}
+        }
+
         // Extract next token, or `Eof` if there was none.
         let next_token = self.tokens.pop().unwrap_or(SpannedToken { token: Token::Eof, span: self.token.span });
 
@@ -116,6 +167,73 @@ impl<'a> ParserContext<'a> {
         looker(self.tokens.get(idx).unwrap_or(&DUMMY_EOF))
     }
 
+    /// Runs `parse` one level of nesting deeper, failing if that is deeper than the maximum depth.
+    pub(super) fn nested<T>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        if let Some(max_depth) = self.max_depth.filter(|max_depth| self.depth >= *max_depth) {
+            return Err(ParserError::nesting_too_deep(max_depth, self.token.span).into());
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Returns the span of the current token and the number of braces open at it.
+    /// This is where parsing resumes after a syntax error in the statement or item starting at the current token.
+    pub(super) fn recovery_point(&self) -> (Span, usize) {
+        (self.token.span, self.brace_depth)
+    }
+
+    /// Recovers from the syntax error `err` in the statement or item starting at `start`, by emitting it and skipping
+    /// the rest of the statement or item. It ends before any of the `stop` tokens or a `}` closing an enclosing
+    /// block, or after a block, at the brace depth `depth` it started at.
+    /// The error is returned instead if the parser does not recover from errors, or if it reached the end of the file.
+    pub(super) fn recover(&mut self, err: LeoError, (start, depth): (Span, usize), stop: &[Token]) -> Result<()> {
+        if !self.recover || !self.has_next() {
+            return Err(err);
+        }
+        self.handler.emit_err(err);
+
+        // Make progress if the error is at the first token, unless it is a `;` that ends the statement.
+        if self.token.span == start && !self.check(&Token::Semicolon) {
+            self.bump();
+        }
+        while self.has_next() && self.brace_depth >= depth {
+            if self.brace_depth == depth && (self.check(&Token::RightCurly) || stop.contains(&self.token.token)) {
+                break;
+            }
+            self.bump();
+            if self.brace_depth == depth && self.prev_token.token == Token::RightCurly {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Skips to the `;` that ends the statement started at the brace depth `depth`, returning `false` without skipping
+    /// anything if there is none, i.e. if the block that is open at `depth` ends first.
+    pub(super) fn skip_to_semicolon(&mut self, depth: usize) -> bool {
+        let mut brace_depth = self.brace_depth;
+        let mut distance = None;
+        for (index, token) in iter::once(&self.token).chain(self.tokens.iter().rev()).enumerate() {
+            match token.token {
+                Token::Semicolon if brace_depth == depth => {
+                    distance = Some(index);
+                    break;
+                }
+                Token::LeftCurly => brace_depth += 1,
+                Token::RightCurly if brace_depth <= depth => break,
+                Token::RightCurly => brace_depth -= 1,
+                _ => {}
+            }
+        }
+        let Some(distance) = distance else {
+            return false;
+        };
+        (0..distance).for_each(|_| self.bump());
+        true
+    }
+
     /// Emit the error `err`.
     pub(super) fn emit_err(&self, err: ParserError) {
         self.handler.emit_err(err);
@@ -146,6 +264,21 @@ impl<'a> ParserContext<'a> {
         None
     }
 
+    /// Eats the rest of the name of an external program after the `.`, i.e. `leo/` in `foo.leo/bar`,
+    /// or `aleo/` in `foo.aleo/bar` for a deployed program, in which case the network is returned.
+    pub(super) fn eat_external_program(&mut self) -> Option<Option<Identifier>> {
+        if self.eat(&Token::Leo) {
+            self.eat(&Token::Div); // todo: Make `/` a more general token.
+            return Some(None);
+        }
+        if self.check(&Token::Identifier(sym::aleo)) && self.look_ahead(1, |next| next.token == Token::Div) {
+            let network = self.eat_identifier();
+            self.bump();
+            return Some(network);
+        }
+        None
+    }
+
     /// Expects an [`Identifier`], or errors.
     pub(super) fn expect_identifier(&mut self) -> Result<Identifier> {
         self.eat_identifier()
@@ -253,3 +386,9 @@ impl<'a> ParserContext<'a> {
         matches!(self.token.token, Token::LeftParen)
     }
 }
+
+/// Returns the text of a doc comment line, i.e. a line comment starting with exactly three slashes, if it is one.
+fn doc_comment_line(comment: &str) -> Option<String> {
+    let text = comment.strip_prefix("///").filter(|text| !text.starts_with('/'))?;
+    Some(text.strip_prefix(' ').unwrap_or(text).trim_end().to_string())
+}
@@ -18,7 +18,6 @@ use super::*;
 use leo_errors::{ParserError, Result};
 
 use leo_span::{sym, Symbol};
-use snarkvm_console::{account::Address, network::Testnet3};
 
 const INT_TYPES: &[Token] = &[
     Token::I8,
@@ -47,7 +46,7 @@ impl ParserContext<'_> {
         self.disallow_struct_construction = false;
 
         // Parse expression.
-        let result = self.parse_conditional_expression();
+        let result = self.nested(Self::parse_conditional_expression);
 
         // Restore prior parser state.
         self.disallow_struct_construction = prior_fuzzy_state;
@@ -229,17 +228,29 @@ impl ParserContext<'_> {
     }
 
     /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// cast expression.
+    /// cast expression, e.g. `x as u8` or `x as.wrapped u8`.
     ///
     /// Otherwise, tries to parse the next token using [`parse_unary_expression`].
     fn parse_cast_expression(&mut self) -> Result<Expression> {
         let mut expr = self.parse_unary_expression()?;
         if self.eat(&Token::As) {
+            // Parse the `.wrapped` variant of the cast, if present.
+            let wrapped = match self.eat(&Token::Dot) {
+                true => {
+                    let variant = self.expect_identifier()?;
+                    if variant.name != sym::wrapped {
+                        return Err(ParserError::unexpected(variant, "wrapped", variant.span).into());
+                    }
+                    true
+                }
+                false => false,
+            };
             let (type_, end_span) = self.parse_primitive_type()?;
             let span = expr.span() + end_span;
             expr = Expression::Cast(CastExpression {
                 expression: Box::new(expr),
                 type_,
+                wrapped,
                 span,
                 id: self.node_builder.next_id(),
             });
@@ -263,48 +274,10 @@ impl ParserContext<'_> {
             ops.push((operation, self.prev_token.span));
         }
 
+        // Note that a negated literal, e.g. `-1i8`, is parsed as a unary expression, and is folded into a negative
+        // literal after parsing.
         let mut inner = self.parse_postfix_expression()?;
 
-        // If the last operation is a negation and the inner expression is a literal, then construct a negative literal.
-        if let Some((UnaryOperation::Negate, _)) = ops.last() {
-            match inner {
-                Expression::Literal(Literal::Integer(integer_type, string, span, id)) => {
-                    // Remove the negation from the operations.
-                    // Note that this unwrap is safe because there is at least one operation in `ops`.
-                    let (_, op_span) = ops.pop().unwrap();
-                    // Construct a negative integer literal.
-                    inner =
-                        Expression::Literal(Literal::Integer(integer_type, format!("-{string}"), op_span + span, id));
-                }
-                Expression::Literal(Literal::Field(string, span, id)) => {
-                    // Remove the negation from the operations.
-                    // Note that
-                    let (_, op_span) = ops.pop().unwrap();
-                    // Construct a negative field literal.
-                    inner = Expression::Literal(Literal::Field(format!("-{string}"), op_span + span, id));
-                }
-                Expression::Literal(Literal::Group(group_literal)) => {
-                    // Remove the negation from the operations.
-                    let (_, op_span) = ops.pop().unwrap();
-                    // Construct a negative group literal.
-                    // Note that we only handle the case where the group literal is a single integral value.
-                    inner = Expression::Literal(Literal::Group(Box::new(match *group_literal {
-                        GroupLiteral::Single(string, span, id) => {
-                            GroupLiteral::Single(format!("-{string}"), op_span + span, id)
-                        }
-                        GroupLiteral::Tuple(tuple) => GroupLiteral::Tuple(tuple),
-                    })));
-                }
-                Expression::Literal(Literal::Scalar(string, span, id)) => {
-                    // Remove the negation from the operations.
-                    let (_, op_span) = ops.pop().unwrap();
-                    // Construct a negative scalar literal.
-                    inner = Expression::Literal(Literal::Scalar(format!("-{string}"), op_span + span, id));
-                }
-                _ => (), // Do nothing.
-            }
-        }
-
         // Apply the operations in reverse order, constructing a unary expression.
         for (op, op_span) in ops.into_iter().rev() {
             inner = Expression::Unary(UnaryExpression {
@@ -378,11 +351,28 @@ impl ParserContext<'_> {
                         id: self.node_builder.next_id(),
                     })))
                 }
-                _ => {
+                _ if Struct::is_reserved_method_name(method.name) => {
                     // Either an invalid unary/binary operator, or more arguments given.
                     self.emit_err(ParserError::invalid_method_call(receiver, method, args.len(), span));
                     Ok(Expression::Err(ErrExpression { span, id: self.node_builder.next_id() }))
                 }
+                _ => {
+                    // Otherwise, the method call is a call to a struct method, e.g. `p.norm()`.
+                    // Note that the type checker resolves the method from the type of the receiver.
+                    Ok(Expression::Call(CallExpression {
+                        function: Box::new(Expression::Access(AccessExpression::Member(MemberAccess {
+                            span: receiver.span() + method.span,
+                            inner: Box::new(receiver),
+                            name: method,
+                            id: self.node_builder.next_id(),
+                        }))),
+                        arguments: args,
+                        external: None,
+                        network: None,
+                        span,
+                        id: self.node_builder.next_id(),
+                    }))
+                }
             }
         }
     }
@@ -449,9 +439,8 @@ impl ParserContext<'_> {
                         span,
                         id: self.node_builder.next_id(),
                     }))
-                } else if self.eat(&Token::Leo) {
-                    // Eat an external function call.
-                    self.eat(&Token::Div); // todo: Make `/` a more general token.
+                } else if let Some(network) = self.eat_external_program() {
+                    // Eat an external function call, e.g. `foo.leo/bar(...)` or `foo.aleo/bar(...)`.
 
                     // Parse function name.
                     let name = self.expect_identifier()?;
@@ -462,6 +451,7 @@ impl ParserContext<'_> {
                         span: expr.span() + span,
                         function: Box::new(Expression::Identifier(name)),
                         external: Some(Box::new(expr)),
+                        network,
                         arguments,
                         id: self.node_builder.next_id(),
                     });
@@ -507,6 +497,7 @@ impl ParserContext<'_> {
                     span: expr.span() + span,
                     function: Box::new(expr),
                     external: None,
+                    network: None,
                     arguments,
                     id: self.node_builder.next_id(),
                 });
@@ -543,6 +534,45 @@ impl ParserContext<'_> {
         }
     }
 
+    /// Returns an [`Expression`] AST node if the next tokens represent a block expression, `{ statements; value }`.
+    fn parse_block_expression(&mut self) -> Result<Expression> {
+        let start = self.expect(&Token::LeftCurly)?;
+
+        let mut statements = Vec::new();
+        let value = loop {
+            match self.token.token {
+                // Statements that begin with a keyword, or with a nested block, are never the value of the block.
+                Token::Return
+                | Token::If
+                | Token::For
+                | Token::Assert
+                | Token::AssertEq
+                | Token::AssertNeq
+                | Token::Let
+                | Token::Const
+                | Token::LeftCurly
+                | Token::Console
+                | Token::Finalize => statements.push(self.parse_statement()?),
+                // Otherwise, an expression followed by the closing brace is the value of the block.
+                _ => {
+                    let expression = self.parse_expression()?;
+                    if self.check(&Token::RightCurly) {
+                        break expression;
+                    }
+                    statements.push(self.parse_assign_statement_with_place(expression)?);
+                }
+            }
+        };
+
+        let end = self.expect(&Token::RightCurly)?;
+        Ok(Expression::Block(BlockExpression {
+            statements,
+            value: Box::new(value),
+            span: start + end,
+            id: self.node_builder.next_id(),
+        }))
+    }
+
     /// Returns an [`Expression`] AST node if the next tokens represent an array initialization expression.
     fn parse_array_expression(&mut self) -> Result<Expression> {
         let (elements, _, span) = self.parse_bracket_comma_list(|p| p.parse_expression().map(Some))?;
@@ -642,13 +672,18 @@ impl ParserContext<'_> {
     /// Returns an [`Expression`] AST node if the next tokens represent a
     /// struct initialization expression.
     /// let foo = Foo { x: 1u8 };
-    pub fn parse_struct_init_expression(&mut self, identifier: Identifier) -> Result<Expression> {
+    pub fn parse_struct_init_expression(
+        &mut self,
+        identifier: Identifier,
+        const_arguments: Vec<ConstArgument>,
+    ) -> Result<Expression> {
         let (members, _, end) =
             self.parse_list(Delimiter::Brace, Some(Token::Comma), |p| p.parse_struct_member().map(Some))?;
 
         Ok(Expression::Struct(StructExpression {
             span: identifier.span + end,
             name: identifier,
+            const_arguments,
             members,
             id: self.node_builder.next_id(),
         }))
@@ -666,6 +701,13 @@ impl ParserContext<'_> {
             return self.parse_tuple_expression();
         } else if let Token::LeftSquare = self.token.token {
             return self.parse_array_expression();
+        } else if let Token::LeftCurly = self.token.token {
+            return self.parse_block_expression();
+        }
+
+        // The tokens that end a statement or block are not consumed, so that the parser can recover at them.
+        if matches!(self.token.token, Token::Semicolon | Token::RightCurly) {
+            return Err(ParserError::unexpected_str(&self.token.token, "expression", self.token.span).into());
         }
 
         let SpannedToken { token, span } = self.token.clone();
@@ -676,15 +718,22 @@ impl ParserContext<'_> {
                 let suffix_span = self.token.span;
                 let full_span = span + suffix_span;
                 let assert_no_whitespace = |x| assert_no_whitespace(span, suffix_span, &value, x);
+                // Only integers can be written in hexadecimal, octal, or binary.
+                let assert_decimal = |x| match Literal::integer_digits(&value) {
+                    (_, 10) => Ok(()),
+                    _ => Err(ParserError::radix_literal_not_integer(&value, x, full_span)),
+                };
                 match self.eat_any(INT_TYPES).then_some(&self.prev_token.token) {
                     // Literal followed by `field`, e.g., `42field`.
                     Some(Token::Field) => {
                         assert_no_whitespace("field")?;
+                        assert_decimal("field")?;
                         Expression::Literal(Literal::Field(value, full_span, self.node_builder.next_id()))
                     }
                     // Literal followed by `group`, e.g., `42group`.
                     Some(Token::Group) => {
                         assert_no_whitespace("group")?;
+                        assert_decimal("group")?;
                         Expression::Literal(Literal::Group(Box::new(GroupLiteral::Single(
                             value,
                             full_span,
@@ -694,6 +743,7 @@ impl ParserContext<'_> {
                     // Literal followed by `scalar` e.g., `42scalar`.
                     Some(Token::Scalar) => {
                         assert_no_whitespace("scalar")?;
+                        assert_decimal("scalar")?;
                         Expression::Literal(Literal::Scalar(value, full_span, self.node_builder.next_id()))
                     }
                     // Literal followed by other type suffix, e.g., `42u8`.
@@ -702,15 +752,13 @@ impl ParserContext<'_> {
                         let int_ty = Self::token_to_int_type(suffix).expect("unknown int type token");
                         Expression::Literal(Literal::Integer(int_ty, value, full_span, self.node_builder.next_id()))
                     }
-                    None => return Err(ParserError::implicit_values_not_allowed(value, span).into()),
+                    // Literal without a suffix, e.g., `42`, whose type is inferred during type checking.
+                    None => Expression::Literal(Literal::Unsuffixed(value, span, self.node_builder.next_id())),
                 }
             }
             Token::True => Expression::Literal(Literal::Boolean(true, span, self.node_builder.next_id())),
             Token::False => Expression::Literal(Literal::Boolean(false, span, self.node_builder.next_id())),
             Token::AddressLit(address_string) => {
-                if address_string.parse::<Address<Testnet3>>().is_err() {
-                    self.emit_err(ParserError::invalid_address_lit(&address_string, span));
-                }
                 Expression::Literal(Literal::Address(address_string, span, self.node_builder.next_id()))
             }
             Token::StaticString(value) => {
@@ -721,7 +769,19 @@ impl ParserContext<'_> {
                 if !self.disallow_struct_construction && self.check(&Token::LeftCurly) {
                     // Parse struct and records inits as struct expressions.
                     // Enforce struct or record type later at type checking.
-                    self.parse_struct_init_expression(ident)?
+                    self.parse_struct_init_expression(ident, Vec::new())?
+                } else if !self.disallow_struct_construction
+                    && self.check_qualified_name()
+                    && self.look_ahead(2, |next| next.token == Token::LeftCurly)
+                {
+                    // Parse an instance of a struct of a module, e.g. `math::Point { ... }`.
+                    let name = self.parse_qualified_name(ident)?;
+                    self.parse_struct_init_expression(name, Vec::new())?
+                } else if self.check(&Token::DoubleColon) && self.look_ahead(1, |next| next.token == Token::Lt) {
+                    // Parse an instance of a generic struct, e.g. `Matrix::<2, 3> { ... }`.
+                    self.expect(&Token::DoubleColon)?;
+                    let (const_arguments, _) = self.parse_const_arguments()?;
+                    self.parse_struct_init_expression(ident, const_arguments)?
                 } else {
                     Expression::Identifier(ident)
                 }
@@ -15,16 +15,31 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::parse_ast;
-use leo_errors::{CompilerError, ParserError, Result};
-use leo_span::{source_map::FileName, symbol::with_session_globals};
-
-use std::fs;
+use crate::ImportResolver;
+use leo_errors::{ParserError, Result};
+
+use indexmap::IndexSet;
+
+/// The tokens that start the items of a program scope or module, where the parser resumes after a syntax error.
+const ITEM_TOKENS: &[Token] = &[
+    Token::Module,
+    Token::Const,
+    Token::Interface,
+    Token::Struct,
+    Token::Record,
+    Token::Enum,
+    Token::Mapping,
+    Token::At,
+    Token::Function,
+    Token::Transition,
+    Token::Inline,
+];
 
 impl ParserContext<'_> {
     /// Returns a [`Program`] AST if all tokens can be consumed and represent a valid Leo program.
     pub fn parse_program(&mut self) -> Result<Program> {
         let mut imports = IndexMap::new();
+        let mut stubs = IndexMap::new();
         let mut program_scopes = IndexMap::new();
 
         // TODO: Remove restrictions on multiple program scopes
@@ -32,11 +47,16 @@ impl ParserContext<'_> {
 
         while self.has_next() {
             match &self.token.token {
+                // An import of a deployed program, e.g. `import token.aleo;`.
+                Token::Import if self.look_ahead(3, |t| t.token == Token::Identifier(sym::aleo)) => {
+                    let (id, stub) = self.parse_stub_import()?;
+                    stubs.insert(id, stub);
+                }
                 Token::Import => {
                     let (id, import) = self.parse_import()?;
                     imports.insert(id, import);
                 }
-                Token::Program => {
+                Token::At | Token::Program => {
                     match parsed_program_scope {
                         // Only one program scope is allowed per file.
                         true => return Err(ParserError::only_one_program_scope_is_allowed(self.token.span).into()),
@@ -56,7 +76,7 @@ impl ParserContext<'_> {
             return Err(ParserError::missing_program_scope(self.token.span).into());
         }
 
-        Ok(Program { imports, program_scopes })
+        Ok(Program { imports, stubs, program_scopes })
     }
 
     fn unexpected_item(token: &SpannedToken, expected: &[Token]) -> ParserError {
@@ -67,7 +87,6 @@ impl ParserContext<'_> {
         )
     }
 
-    // TODO: remove import resolution from parser.
     /// Parses an import statement `import foo.leo;`.
     pub(super) fn parse_import(&mut self) -> Result<(Symbol, (Program, Span))> {
         // Parse `import`.
@@ -85,37 +104,53 @@ impl ParserContext<'_> {
 
         let end = self.expect(&Token::Semicolon)?;
 
-        // Tokenize and parse import file.
-        // Todo: move this to a different module.
-        let mut import_file_path =
-            std::env::current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, self.token.span))?;
-        import_file_path.push("imports");
-        import_file_path.push(format!("{}.leo", import_name.name));
-
-        // Throw an error if the import file doesn't exist.
-        if !import_file_path.exists() {
-            return Err(CompilerError::import_not_found(import_file_path.display(), self.prev_token.span).into());
-        }
-
-        // Read the import file into string.
-        // Todo: protect against cyclic imports.
-        let program_string =
-            fs::read_to_string(&import_file_path).map_err(|e| CompilerError::file_read_error(&import_file_path, e))?;
+        // Resolve the import file, and parse the program it contains.
+        // The imported program is left empty if imports are not resolved, e.g. when formatting a program.
+        let program = match self.resolve_imports {
+            true => {
+                let default_resolver = ImportResolver::default();
+                let import_resolver = self.import_resolver.unwrap_or(&default_resolver);
+                import_resolver.load(self.handler, self.node_builder, &import_name, start + end)?
+            }
+            false => Program::default(),
+        };
 
-        // Create import file name.
-        let name: FileName = FileName::Real(import_file_path);
+        Ok((import_name.name, (program, start + end)))
+    }
 
-        // Register the source (`program_string`) in the source map.
-        let prg_sf = with_session_globals(|s| s.source_map.new_source(&program_string, name));
+    /// Parses an import of a deployed program `import foo.aleo;`, whose signatures are read from its stub.
+    fn parse_stub_import(&mut self) -> Result<(Symbol, (Stub, Span))> {
+        // Parse `import foo.aleo;`.
+        let start = self.expect(&Token::Import)?;
+        let import_name = self.expect_identifier()?;
+        self.expect(&Token::Dot)?;
+        let network = self.expect_identifier()?;
+        let end = self.expect(&Token::Semicolon)?;
 
-        // Use the parser to construct the imported abstract syntax tree (ast).
-        let program_ast = parse_ast(self.handler, self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
+        // Resolve the stub file, and read the signatures of the program.
+        // The stub is left empty if imports are not resolved, e.g. when formatting a program.
+        let stub = match self.resolve_imports {
+            true => {
+                let default_resolver = ImportResolver::default();
+                let import_resolver = self.import_resolver.unwrap_or(&default_resolver);
+                import_resolver.load_stub(self.node_builder, &import_name, start + end)?
+            }
+            false => Stub {
+                program_id: ProgramId { name: import_name, network },
+                structs: Vec::new(),
+                functions: Vec::new(),
+                span: start + end,
+            },
+        };
 
-        Ok((import_name.name, (program_ast.into_repr(), start + end)))
+        Ok((import_name.name, (stub, start + end)))
     }
 
-    /// Parsers a program scope `program foo.aleo { ... }`.
+    /// Parsers a program scope `program foo.aleo { ... }`, which may be preceded by annotations.
     fn parse_program_scope(&mut self) -> Result<ProgramScope> {
+        // Parse the annotations, e.g. `@deny(mixed_signedness)`.
+        let annotations = self.parse_annotations()?;
+
         // Parse `program` keyword.
         let start = self.expect(&Token::Program)?;
 
@@ -138,10 +173,148 @@ impl ParserContext<'_> {
         self.expect(&Token::LeftCurly)?;
 
         // Parse the body of the program scope.
+        let mut scope = ProgramScope {
+            program_id,
+            annotations,
+            modules: Vec::new(),
+            consts: Vec::new(),
+            functions: Vec::new(),
+            interfaces: Vec::new(),
+            structs: Vec::new(),
+            enums: Vec::new(),
+            mappings: Vec::new(),
+            span: start,
+        };
+        // The qualified names of the items of the modules, along with the names of their modules.
+        let mut module_items = IndexMap::new();
+        while self.has_next() && !self.check(&Token::RightCurly) {
+            // The errors in the files of modules are not recovered from, since they are not in this file.
+            if self.check(&Token::Module) {
+                module_items.extend(self.parse_module_item(&mut scope)?);
+                continue;
+            }
+            let point = self.recovery_point();
+            if let Err(err) = self.parse_program_item(&mut scope) {
+                self.recover(err, point, ITEM_TOKENS)?;
+            }
+        }
+
+        // Parse `}`.
+        let end = self.expect(&Token::RightCurly)?;
+
+        Self::check_module_item_names(&scope, &module_items)?;
+
+        Ok(ProgramScope { span: start + end, ..scope })
+    }
+
+    /// Parses a module declaration, adding it and the items of the module to `scope`.
+    /// Returns the qualified names of the items of the module, each along with the name of the module.
+    fn parse_module_item(&mut self, scope: &mut ProgramScope) -> Result<Vec<(Symbol, Identifier)>> {
+        let (id, module) = self.parse_module()?;
+        // Add the items of the module to the program scope, under their qualified names.
+        // The items are not loaded if imports are not resolved, e.g. when formatting a program.
+        let mut names = Vec::new();
+        if self.resolve_imports {
+            let default_resolver = ImportResolver::default();
+            let import_resolver = self.import_resolver.unwrap_or(&default_resolver);
+            let items = import_resolver.load_module(self.handler, self.node_builder, scope.program_id, &module)?;
+            names.extend(items.consts.iter().map(|(name, _)| (*name, module.name)));
+            names.extend(items.structs.iter().map(|(name, _)| (*name, module.name)));
+            names.extend(items.functions.iter().map(|(name, _)| (*name, module.name)));
+            scope.consts.extend(items.consts);
+            scope.structs.extend(items.structs);
+            scope.functions.extend(items.functions);
+        }
+        scope.modules.push((id, module));
+        Ok(names)
+    }
+
+    /// Checks that no item of a module has the same qualified name as another item of the program scope.
+    /// For example, `math::add` is declared as `math__add`, which collides with a function `math__add` of the
+    /// program, and `a::b__c` collides with `a__b::c`, since both are declared as `a__b__c`.
+    fn check_module_item_names(scope: &ProgramScope, module_items: &IndexMap<Symbol, Identifier>) -> Result<()> {
+        let items = scope
+            .consts
+            .iter()
+            .map(|(name, declaration)| (*name, declaration.span))
+            .chain(scope.structs.iter().map(|(name, struct_)| (*name, struct_.span)))
+            .chain(scope.functions.iter().map(|(name, function)| (*name, function.span)));
+        let mut names = IndexSet::new();
+        for (name, span) in items {
+            if !names.insert(name) {
+                if let Some(module) = module_items.get(&name) {
+                    return Err(ParserError::module_item_name_collision(name, module, span).into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses an item of a program scope other than a module declaration, adding it to `scope`.
+    fn parse_program_item(&mut self, scope: &mut ProgramScope) -> Result<()> {
+        match &self.token.token {
+            Token::Const => {
+                let declaration = self.parse_const_declaration_statement()?;
+                scope.consts.push((Symbol::intern(&declaration.place.to_string()), declaration));
+            }
+            Token::Interface => {
+                let (id, interface) = self.parse_interface()?;
+                scope.interfaces.push((id, interface));
+            }
+            Token::At | Token::Public | Token::Private if self.peek_is_annotated_struct() => {
+                let (id, struct_) = self.parse_struct()?;
+                scope.structs.push((id, struct_));
+            }
+            Token::Struct | Token::Record => {
+                let (id, struct_) = self.parse_struct()?;
+                scope.structs.push((id, struct_));
+            }
+            Token::Enum => {
+                let (id, enum_) = self.parse_enum()?;
+                scope.enums.push((id, enum_));
+            }
+            Token::Mapping => {
+                let (id, mapping) = self.parse_mapping()?;
+                scope.mappings.push((id, mapping));
+            }
+            Token::At | Token::Public | Token::Private | Token::Function | Token::Transition | Token::Inline => {
+                let (id, function) = self.parse_function()?;
+                scope.functions.push((id, function));
+            }
+            _ => {
+                return Err(Self::unexpected_item(&self.token, &[
+                    Token::Interface,
+                    Token::Struct,
+                    Token::Record,
+                    Token::Enum,
+                    Token::Mapping,
+                    Token::At,
+                    Token::Function,
+                    Token::Transition,
+                    Token::Inline,
+                ])
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a module declaration `module foo;`.
+    fn parse_module(&mut self) -> Result<(Symbol, Module)> {
+        let start = self.expect(&Token::Module)?;
+        let name = self.expect_identifier()?;
+        let end = self.expect(&Token::Semicolon)?;
+        Ok((name.name, Module { name, span: start + end, id: self.node_builder.next_id() }))
+    }
+
+    /// Parses the items of the file of the module `module`, which belongs to the program `program_id`.
+    /// A module may declare constants, structs, records, and functions, but not transitions, mappings, or modules.
+    /// The items are returned as a program scope, under the names they are declared with in the module.
+    pub(super) fn parse_module_items(&mut self, program_id: ProgramId, module: &Module) -> Result<ProgramScope> {
+        let start = self.token.span;
         let mut consts: Vec<(Symbol, ConstDeclaration)> = Vec::new();
         let mut functions: Vec<(Symbol, Function)> = Vec::new();
         let mut structs: Vec<(Symbol, Struct)> = Vec::new();
-        let mut mappings: Vec<(Symbol, Mapping)> = Vec::new();
 
         while self.has_next() {
             match &self.token.token {
@@ -149,27 +322,28 @@ impl ParserContext<'_> {
                     let declaration = self.parse_const_declaration_statement()?;
                     consts.push((Symbol::intern(&declaration.place.to_string()), declaration));
                 }
-                Token::Struct | Token::Record => {
+                Token::At | Token::Public | Token::Private if self.peek_is_annotated_struct() => {
                     let (id, struct_) = self.parse_struct()?;
                     structs.push((id, struct_));
                 }
-                Token::Mapping => {
-                    let (id, mapping) = self.parse_mapping()?;
-                    mappings.push((id, mapping));
+                Token::Struct | Token::Record => {
+                    let (id, struct_) = self.parse_struct()?;
+                    structs.push((id, struct_));
                 }
-                Token::At | Token::Function | Token::Transition | Token::Inline => {
+                Token::At | Token::Public | Token::Private | Token::Function | Token::Transition | Token::Inline => {
                     let (id, function) = self.parse_function()?;
+                    if function.variant == Variant::Transition {
+                        return Err(ParserError::transition_in_module(module.name, function.span).into());
+                    }
                     functions.push((id, function));
                 }
-                Token::RightCurly => break,
                 _ => {
                     return Err(Self::unexpected_item(&self.token, &[
+                        Token::Const,
                         Token::Struct,
                         Token::Record,
-                        Token::Mapping,
                         Token::At,
                         Token::Function,
-                        Token::Transition,
                         Token::Inline,
                     ])
                     .into());
@@ -177,19 +351,40 @@ impl ParserContext<'_> {
             }
         }
 
-        // Parse `}`.
-        let end = self.expect(&Token::RightCurly)?;
-
-        Ok(ProgramScope { program_id, consts, functions, structs, mappings, span: start + end })
+        Ok(ProgramScope {
+            program_id,
+            annotations: Vec::new(),
+            modules: Vec::new(),
+            consts,
+            functions,
+            interfaces: Vec::new(),
+            structs,
+            enums: Vec::new(),
+            mappings: Vec::new(),
+            span: start + self.prev_token.span,
+        })
     }
 
     /// Returns a [`Vec<Member>`] AST node if the next tokens represent a struct member.
-    fn parse_struct_members(&mut self) -> Result<(Vec<Member>, Span)> {
+    /// If `struct_name` is given, then the members may also include methods, which are added to `methods`.
+    fn parse_struct_members(
+        &mut self,
+        struct_name: Option<&Identifier>,
+        methods: &mut Vec<(Symbol, Function)>,
+    ) -> Result<(Vec<Member>, Span)> {
         let mut members = Vec::new();
 
         let (mut semi_colons, mut commas) = (false, false);
 
         while !self.check(&Token::RightCurly) {
+            // Parse a method, if the members belong to a struct or record.
+            if let Some(struct_name) = struct_name {
+                if matches!(self.token.token, Token::At | Token::Function | Token::Inline | Token::Transition) {
+                    methods.push(self.parse_method(struct_name)?);
+                    continue;
+                }
+            }
+
             let variable = self.parse_member_variable_declaration()?;
 
             if self.eat(&Token::Semicolon) {
@@ -213,6 +408,25 @@ impl ParserContext<'_> {
         Ok((members, span))
     }
 
+    /// Parses a method of the struct `struct_name`, e.g. `function norm(self) -> u32 { ... }`.
+    /// Methods are inlined at their call sites, so they are parsed as `inline` functions whose first input is `self`.
+    fn parse_method(&mut self, struct_name: &Identifier) -> Result<(Symbol, Function)> {
+        let previous_receiver = self.receiver.replace(*struct_name);
+        let method = self.parse_function();
+        self.receiver = previous_receiver;
+        let (name, mut method) = method?;
+
+        match method.variant {
+            Variant::Transition => self.emit_err(ParserError::method_cannot_be_transition(method.identifier.span)),
+            _ => method.variant = Variant::Inline,
+        }
+        if method.input.first().map(|input| input.identifier().name) != Some(sym::SelfLower) {
+            self.emit_err(ParserError::method_missing_self(name, method.identifier.span));
+        }
+
+        Ok((name, method))
+    }
+
     /// Parses `IDENT: TYPE`.
     pub(super) fn parse_typed_ident(&mut self) -> Result<(Identifier, Type, Span)> {
         let name = self.expect_identifier()?;
@@ -228,27 +442,103 @@ impl ParserContext<'_> {
 
         let (identifier, type_, span) = self.parse_typed_ident()?;
 
-        Ok(Member { mode, identifier, type_, span, id: self.node_builder.next_id() })
+        // Parse the default value of the member, if it is given, e.g. `= 6u8`.
+        let (default, span) = match self.eat(&Token::Assign) {
+            true => {
+                let default = self.parse_expression()?;
+                let span = span + default.span();
+                (Some(default), span)
+            }
+            false => (None, span),
+        };
+
+        Ok(Member { mode, identifier, type_, default, span, id: self.node_builder.next_id() })
     }
 
     /// Parses a struct or record definition, e.g., `struct Foo { ... }` or `record Foo { ... }`.
     pub(super) fn parse_struct(&mut self) -> Result<(Symbol, Struct)> {
+        let doc = self.doc_comment();
+        let annotations = self.parse_annotations()?;
+        let (visibility, visibility_span) = self.parse_visibility();
         let is_record = matches!(&self.token.token, Token::Record);
         let start = self.expect_any(&[Token::Struct, Token::Record])?;
+        let start = visibility_span.map_or(start, |span| span + start);
         let struct_name = self.expect_identifier()?;
 
+        // Parse the constant parameters of a generic struct, e.g. `<const N: u32>`.
+        let const_parameters = match self.check(&Token::Lt) {
+            true => {
+                self.parse_list(Delimiter::AngleBracket, Some(Token::Comma), |p| p.parse_const_parameter().map(Some))?.0
+            }
+            false => Vec::new(),
+        };
+
+        // Parse the interfaces that the struct conforms to, e.g. `: Foo + Bar`.
+        let mut interfaces = Vec::new();
+        if self.eat(&Token::Colon) {
+            interfaces.push(self.expect_identifier()?);
+            while self.eat(&Token::Add) {
+                interfaces.push(self.expect_identifier()?);
+            }
+        }
+
         self.expect(&Token::LeftCurly)?;
-        let (members, end) = self.parse_struct_members()?;
+        let mut methods = Vec::new();
+        let (members, end) = self.parse_struct_members(Some(&struct_name), &mut methods)?;
 
         Ok((struct_name.name, Struct {
+            doc,
+            annotations,
             identifier: struct_name,
+            visibility,
+            const_parameters,
             members,
+            interfaces,
+            methods,
             is_record,
             span: start + end,
             id: self.node_builder.next_id(),
         }))
     }
 
+    /// Parses a constant parameter of a generic struct, e.g. `const N: u32`.
+    fn parse_const_parameter(&mut self) -> Result<ConstParameter> {
+        let start = self.expect(&Token::Const)?;
+        let (identifier, type_, span) = self.parse_typed_ident()?;
+        Ok(ConstParameter { identifier, type_, span: start + span, id: self.node_builder.next_id() })
+    }
+
+    /// Parses an interface definition, e.g., `interface Foo { ... }`.
+    pub(super) fn parse_interface(&mut self) -> Result<(Symbol, Interface)> {
+        let start = self.expect(&Token::Interface)?;
+        let interface_name = self.expect_identifier()?;
+
+        self.expect(&Token::LeftCurly)?;
+        let (members, end) = self.parse_struct_members(None, &mut Vec::new())?;
+
+        Ok((interface_name.name, Interface {
+            identifier: interface_name,
+            members,
+            span: start + end,
+            id: self.node_builder.next_id(),
+        }))
+    }
+
+    /// Parses an enum definition, e.g., `enum Color { Red, Green, Blue }`.
+    pub(super) fn parse_enum(&mut self) -> Result<(Symbol, Enum)> {
+        let start = self.expect(&Token::Enum)?;
+        let enum_name = self.expect_identifier()?;
+        let (variants, _, end) =
+            self.parse_list(Delimiter::Brace, Some(Token::Comma), |p| p.expect_identifier().map(Some))?;
+
+        Ok((enum_name.name, Enum {
+            identifier: enum_name,
+            variants,
+            span: start + end,
+            id: self.node_builder.next_id(),
+        }))
+    }
+
     /// Parses a mapping declaration, e.g. `mapping balances: address => u128`.
     pub(super) fn parse_mapping(&mut self) -> Result<(Symbol, Mapping)> {
         let start = self.expect(&Token::Mapping)?;
@@ -295,6 +585,18 @@ impl ParserContext<'_> {
     /// Returns a [`Input`] AST node if the next tokens represent a function output.
     fn parse_input(&mut self) -> Result<functions::Input> {
         let mode = self.parse_mode()?;
+
+        // Parse the `self` receiver of a method, whose type is the struct the method belongs to.
+        if let (Some(receiver), Token::SelfLower) = (self.receiver, &self.token.token) {
+            let span = self.expect(&Token::SelfLower)?;
+            return Ok(functions::Input::Internal(FunctionInput {
+                identifier: Identifier { name: sym::SelfLower, span, id: self.node_builder.next_id() },
+                mode,
+                type_: Type::Identifier(Identifier { name: receiver.name, span, id: self.node_builder.next_id() }),
+                span,
+                id: self.node_builder.next_id(),
+            }));
+        }
         let name = self.expect_identifier()?;
         self.expect(&Token::Colon)?;
 
@@ -302,10 +604,9 @@ impl ParserContext<'_> {
             let external = self.expect_identifier()?;
             let mut span = name.span + external.span;
 
-            // Parse `.leo/`.
+            // Parse `.leo/` or `.aleo/`.
             self.eat(&Token::Dot);
-            self.eat(&Token::Leo);
-            self.eat(&Token::Div);
+            let network = self.eat_external_program().flatten();
 
             // Parse record name.
             let record = self.expect_identifier()?;
@@ -318,6 +619,7 @@ impl ParserContext<'_> {
             Ok(functions::Input::External(External {
                 identifier: name,
                 program_name: external,
+                network,
                 record,
                 span,
                 id: self.node_builder.next_id(),
@@ -349,10 +651,9 @@ impl ParserContext<'_> {
             let external = self.expect_identifier()?;
             let mut span = external.span;
 
-            // Parse `.leo/`.
+            // Parse `.leo/` or `.aleo/`.
             self.eat(&Token::Dot);
-            self.eat(&Token::Leo);
-            self.eat(&Token::Div);
+            let network = self.eat_external_program().flatten();
 
             // Parse record name.
             let record = self.expect_identifier()?;
@@ -365,6 +666,7 @@ impl ParserContext<'_> {
             Ok(Output::External(External {
                 identifier: Identifier::new(Symbol::intern("dummy"), self.node_builder.next_id()),
                 program_name: external,
+                network,
                 record,
                 span,
                 id: self.node_builder.next_id(),
@@ -378,8 +680,33 @@ impl ParserContext<'_> {
         matches!((&self.token.token, self.look_ahead(1, |t| &t.token)), (Token::Identifier(_), Token::Dot))
     }
 
+    /// Parses the annotations before an item, if there are any.
+    fn parse_annotations(&mut self) -> Result<Vec<Annotation>> {
+        let mut annotations = Vec::new();
+        while self.check(&Token::At) {
+            annotations.push(self.parse_annotation()?)
+        }
+        Ok(annotations)
+    }
+
+    /// Returns `true` if the next tokens are annotations followed by a struct or record, e.g. `@deprecated struct`.
+    fn peek_is_annotated_struct(&self) -> bool {
+        let mut dist = 0;
+        while self.look_ahead(dist, |t| t.token == Token::At) {
+            // Skip `@key`, and `(value)` if it is given.
+            dist += 2;
+            if self.look_ahead(dist, |t| t.token == Token::LeftParen) {
+                dist += 3;
+            }
+        }
+        if self.look_ahead(dist, |t| matches!(t.token, Token::Public | Token::Private)) {
+            dist += 1;
+        }
+        self.look_ahead(dist, |t| matches!(t.token, Token::Struct | Token::Record))
+    }
+
     /// Returns an [`Annotation`] AST node if the next tokens represent an annotation.
-    fn parse_annotation(&mut self) -> Result<Annotation> {
+    pub(super) fn parse_annotation(&mut self) -> Result<Annotation> {
         // Parse the `@` symbol and identifier.
         let start = self.expect(&Token::At)?;
         let identifier = match self.token.token {
@@ -388,25 +715,52 @@ impl ParserContext<'_> {
             }
             _ => self.expect_identifier()?,
         };
-        let span = start + identifier.span;
 
         // TODO: Verify that this check is sound.
         // Check that there is no whitespace in between the `@` symbol and identifier.
-        match identifier.span.hi.0 - start.lo.0 > 1 + identifier.name.to_string().len() as u32 {
-            true => Err(ParserError::space_in_annotation(span).into()),
-            false => Ok(Annotation { identifier, span, id: self.node_builder.next_id() }),
+        if identifier.span.hi.0 - start.lo.0 > 1 + identifier.name.to_string().len() as u32 {
+            return Err(ParserError::space_in_annotation(start + identifier.span).into());
+        }
+
+        // Parse the value of an annotation of the form `@key(value)` or `@key("value")`, if it is given.
+        let (value, end) = match self.eat(&Token::LeftParen) {
+            true => {
+                let value = match &self.token.token {
+                    Token::StaticString(string) => {
+                        let value = AnnotationValue::String(string.clone(), self.token.span);
+                        self.bump();
+                        value
+                    }
+                    _ => AnnotationValue::Identifier(self.expect_identifier()?),
+                };
+                (Some(value), self.expect(&Token::RightParen)?)
+            }
+            false => (None, identifier.span),
+        };
+
+        Ok(Annotation { identifier, value, span: start + end, id: self.node_builder.next_id() })
+    }
+
+    /// Parses the visibility of an item, i.e. `public` or `private`, if it is given.
+    /// Returns the visibility and the span of the keyword.
+    fn parse_visibility(&mut self) -> (Visibility, Option<Span>) {
+        if self.eat(&Token::Public) {
+            (Visibility::Public, Some(self.prev_token.span))
+        } else if self.eat(&Token::Private) {
+            (Visibility::Private, Some(self.prev_token.span))
+        } else {
+            (Visibility::Default, None)
         }
     }
 
     /// Returns an [`(Identifier, Function)`] AST node if the next tokens represent a function name
     /// and function definition.
     fn parse_function(&mut self) -> Result<(Symbol, Function)> {
+        let doc = self.doc_comment();
         // TODO: Handle dangling annotations.
-        // Parse annotations, if they exist.
-        let mut annotations = Vec::new();
-        while self.look_ahead(0, |t| &t.token) == &Token::At {
-            annotations.push(self.parse_annotation()?)
-        }
+        let annotations = self.parse_annotations()?;
+        // Parse the visibility, if it is given.
+        let (visibility, visibility_span) = self.parse_visibility();
         // Parse `<variant> IDENT`, where `<variant>` is `function`, `transition`, or `inline`.
         let (variant, start) = match self.token.token {
             Token::Inline => (Variant::Inline, self.expect(&Token::Inline)?),
@@ -414,6 +768,7 @@ impl ParserContext<'_> {
             Token::Transition => (Variant::Transition, self.expect(&Token::Transition)?),
             _ => self.unexpected("'function', 'transition', or 'inline'")?,
         };
+        let start = visibility_span.map_or(start, |span| span + start);
         let name = self.expect_identifier()?;
 
         // Parse parameters.
@@ -472,20 +827,19 @@ impl ParserContext<'_> {
         };
 
         let span = start + block.span;
-        Ok((
-            name.name,
-            Function::new(
-                annotations,
-                variant,
-                name,
-                inputs,
-                output,
-                block,
-                finalize,
-                span,
-                self.node_builder.next_id(),
-            ),
-        ))
+        let function = Function::new(
+            annotations,
+            visibility,
+            variant,
+            name,
+            inputs,
+            output,
+            block,
+            finalize,
+            span,
+            self.node_builder.next_id(),
+        );
+        Ok((name.name, Function { doc, ..function }))
     }
 }
 
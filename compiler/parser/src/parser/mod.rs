@@ -19,15 +19,23 @@
 //! This module contains the [`parse()`] method which calls the underlying [`tokenize()`]
 //! method to create a new program ast.
 
-use crate::{tokenizer::*, Token};
+use crate::{tokenizer::*, ImportResolver, Token};
 
 use leo_ast::*;
-use leo_errors::{emitter::Handler, Result};
-use leo_span::Span;
+use leo_errors::{
+    emitter::{Diagnostic, Handler},
+    ParserError,
+    Result,
+};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then, Span};
 
 use indexmap::IndexMap;
 use leo_span::span::BytePos;
-use std::unreachable;
+use std::{
+    any::Any,
+    panic::{catch_unwind, AssertUnwindSafe},
+    unreachable,
+};
 
 mod context;
 pub(super) use context::ParserContext;
@@ -35,16 +43,132 @@ pub(super) use context::ParserContext;
 mod expression;
 mod file;
 mod input;
+mod module;
+use module::ModuleQualifier;
 mod statement;
+mod trivia;
+pub(crate) use trivia::{function_span, struct_span};
+use trivia::TriviaCollector;
 pub(super) mod type_;
 
 /// Creates a new program from a given file path and source code text.
 pub fn parse(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_pos: BytePos) -> Result<Program> {
+    parse_with_imports(handler, node_builder, &ImportResolver::default(), source, start_pos)
+}
+
+/// Creates a new program from a given file path and source code text,
+/// resolving its imports with `import_resolver`.
+/// The syntax errors in statements and items are emitted to `handler`, and replaced by [`ErrStatement`]s,
+/// [`ErrExpression`]s, or left out, so the caller must check `handler` for errors.
+pub fn parse_with_imports(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    import_resolver: &ImportResolver,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<Program> {
     let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+    tokens.import_resolver = Some(import_resolver);
+    tokens.max_depth = import_resolver.max_depth();
+    // The syntax errors in statements and items are emitted, so that the later passes can check the rest of the file.
+    tokens.recover = true;
 
     tokens.parse_program()
 }
 
+/// Creates a new program from the given source code text, along with the comments and blank lines around its nodes.
+/// The imports of the program are not resolved, since the comments are only those of the given source code.
+pub fn parse_with_trivia(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<(Program, TriviaMap)> {
+    let tokens = crate::tokenize(source, start_pos)?;
+    let collector = TriviaCollector::new(source, start_pos, &tokens);
+    let mut context = ParserContext::new(handler, node_builder, tokens);
+    context.resolve_imports = false;
+
+    let program = context.parse_program()?;
+    let trivia = collector.collect(&program);
+    Ok((program, trivia))
+}
+
+/// The maximum depth that expressions, blocks, and types may be nested to when parsing recoverably.
+/// This leaves ample room on a thread with a 2 MiB stack in release builds.
+pub const RECOVERABLE_MAX_DEPTH: usize = 128;
+
+/// Parses a program from the given source code text, without resolving its imports.
+/// Returns the program if it could be parsed, along with the diagnostics of the errors and warnings, in that order.
+///
+/// Unlike the other entry points, this never panics, even on malformed input, so that it can be called by fuzz targets
+/// and by editors parsing on every keystroke. A panic of the parser is reported as a `parser_panicked` error, and
+/// programs nested deeper than [`RECOVERABLE_MAX_DEPTH`] are rejected rather than risking a stack overflow.
+/// A session is created if there is none.
+pub fn parse_program_recoverable(source: &str) -> (Option<Program>, Vec<Diagnostic>) {
+    create_session_if_not_set_then(|s| {
+        let source_file = s.source_map.new_source(source, FileName::Custom("input".to_string()));
+        let (handler, buffer) = Handler::new_with_buf();
+        let node_builder = NodeBuilder::default();
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let tokens = crate::tokenize(&source_file.src, source_file.start_pos)?;
+            let mut context = ParserContext::new(&handler, &node_builder, tokens);
+            context.resolve_imports = false;
+            context.max_depth = Some(RECOVERABLE_MAX_DEPTH);
+            context.parse_program()
+        }));
+        let program = match result {
+            Ok(Ok(program)) => Some(program),
+            Ok(Err(err)) => {
+                handler.emit_err(err);
+                None
+            }
+            Err(payload) => {
+                handler.emit_err(ParserError::parser_panicked(panic_message(payload.as_ref())));
+                None
+            }
+        };
+
+        let errors = buffer.extract_errs().into_inner();
+        let warnings = buffer.extract_warnings().into_inner();
+        let diagnostics = errors
+            .iter()
+            .filter_map(Diagnostic::from_error)
+            .chain(warnings.iter().map(Diagnostic::from_warning))
+            .collect();
+        (program, diagnostics)
+    })
+}
+
+/// Returns the message of a panic with the payload `payload`.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => message,
+        (_, Some(message)) => message,
+        _ => "unknown panic",
+    }
+}
+
+/// Parses the items of the module `module` of the program `program_id` from the given source code text.
+/// The names of the items are qualified with the name of the module, e.g. `add` becomes `math__add`.
+/// Expressions, blocks, and types may be nested at most `max_depth` levels deep, if it is given.
+pub fn parse_module(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    program_id: ProgramId,
+    module: &Module,
+    source: &str,
+    start_pos: BytePos,
+    max_depth: Option<usize>,
+) -> Result<ProgramScope> {
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+    tokens.max_depth = max_depth;
+
+    let items = tokens.parse_module_items(program_id, module)?;
+    Ok(ModuleQualifier::new(module.name.name, &items).reconstruct_program_scope(items))
+}
+
 /// Parses an input file at the given file `path` and `source` code text.
 pub fn parse_input(
     handler: &Handler,
@@ -56,3 +180,64 @@ pub fn parse_input(
 
     tokens.parse_input_file()
 }
+
+/// Parses a single expression from the given source code text, which must contain nothing else.
+pub fn parse_expression(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<Expression> {
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+
+    let expression = tokens.parse_expression()?;
+    match tokens.has_next() {
+        true => tokens.unexpected("the end of the expression"),
+        false => Ok(expression),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_errors::LeoMessageCode;
+
+    #[test]
+    fn test_parse_program_recoverable() {
+        let (program, diagnostics) =
+            parse_program_recoverable("program test.aleo { transition main(a: u8) -> u8 { return a; } }");
+        assert!(program.is_some());
+        assert!(diagnostics.is_empty());
+
+        let (program, diagnostics) = parse_program_recoverable("program test.aleo { transition main( -> u8 { }");
+        assert!(program.is_none());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, "error");
+        assert_eq!(diagnostics[0].span.as_ref().map(|span| span.line_start), Some(1));
+
+        // Invalid tokens are reported rather than returned.
+        let (program, diagnostics) = parse_program_recoverable("program test.aleo { § }");
+        assert!(program.is_none());
+        assert_eq!(diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_program_recoverable_limits_depth() {
+        let depth = RECOVERABLE_MAX_DEPTH + 1;
+        let source = format!(
+            "program test.aleo {{ transition main() -> u8 {{ return {}1u8{}; }} }}",
+            "(".repeat(depth),
+            ")".repeat(depth)
+        );
+        // Unoptimized builds need a larger stack than the default for tests to reach the limit.
+        let diagnostics = std::thread::Builder::new()
+            .stack_size(64 << 20)
+            .spawn(move || parse_program_recoverable(&source).1)
+            .unwrap()
+            .join()
+            .unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, ParserError::nesting_too_deep(0, Span::default()).error_code());
+    }
+}
@@ -0,0 +1,293 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+/// Qualifies the names of the items of a module with the name of the module, e.g. `add` becomes `math__add`,
+/// along with every reference to them in the module, so that the items can be added to the program scope.
+/// Local variables named after an item are renamed along with it, which does not change their meaning.
+pub(super) struct ModuleQualifier {
+    /// The name of the module.
+    module: Symbol,
+    /// The names of the constants, structs, and functions of the module.
+    names: IndexSet<Symbol>,
+}
+
+impl ModuleQualifier {
+    pub(super) fn new(module: Symbol, items: &ProgramScope) -> Self {
+        let names = items
+            .consts
+            .iter()
+            .map(|(name, _)| *name)
+            .chain(items.structs.iter().map(|(name, _)| *name))
+            .chain(items.functions.iter().map(|(name, _)| *name))
+            .collect();
+        Self { module, names }
+    }
+
+    /// Returns the qualified name of `name`, if it is the name of an item of the module.
+    fn qualify_name(&self, name: Symbol) -> Symbol {
+        match self.names.contains(&name) {
+            true => Module::item_name(self.module, name),
+            false => name,
+        }
+    }
+
+    fn qualify(&self, identifier: Identifier) -> Identifier {
+        Identifier { name: self.qualify_name(identifier.name), ..identifier }
+    }
+
+    fn qualify_type(&self, input: Type) -> Type {
+        match input {
+            Type::Identifier(identifier) => Type::Identifier(self.qualify(identifier)),
+            Type::Generic(generic_type) => {
+                Type::Generic(GenericType { name: self.qualify(generic_type.name), ..generic_type })
+            }
+            Type::Array(array_type) => Type::Array(ArrayType::new(
+                self.qualify_type(array_type.element_type().clone()),
+                match array_type.array_length() {
+                    ConstArgument::Constant(constant) => ConstArgument::Constant(self.qualify(*constant)),
+                    length => length.clone(),
+                },
+            )),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|type_| self.qualify_type(type_.clone())).collect(),
+            )),
+            type_ => type_,
+        }
+    }
+
+    fn qualify_input(&self, input: Input) -> Input {
+        match input {
+            Input::Internal(input) => Input::Internal(FunctionInput {
+                identifier: self.qualify(input.identifier),
+                type_: self.qualify_type(input.type_),
+                ..input
+            }),
+            input => input,
+        }
+    }
+
+    fn qualify_output(&self, output: Output) -> Output {
+        match output {
+            Output::Internal(output) => {
+                Output::Internal(FunctionOutput { type_: self.qualify_type(output.type_), ..output })
+            }
+            output => output,
+        }
+    }
+
+    /// Qualifies the body of a method, keeping its name, which is qualified by the name of its struct.
+    fn qualify_method(&mut self, method: Function) -> Function {
+        let identifier = method.identifier;
+        Function { identifier, ..self.reconstruct_function(method) }
+    }
+}
+
+impl ExpressionReconstructor for ModuleQualifier {
+    type AdditionalOutput = ();
+
+    fn reconstruct_associated_constant(&mut self, input: AssociatedConstant) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::AssociatedConstant(AssociatedConstant {
+                ty: self.qualify_type(input.ty),
+                ..input
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_associated_function(&mut self, input: AssociatedFunction) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                ty: self.qualify_type(input.ty),
+                arguments: input
+                    .arguments
+                    .into_iter()
+                    .map(|argument| self.reconstruct_expression(argument).0)
+                    .collect(),
+                ..input
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_block_expression(&mut self, input: BlockExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Block(BlockExpression {
+                statements: input
+                    .statements
+                    .into_iter()
+                    .map(|statement| self.reconstruct_statement(statement).0)
+                    .collect(),
+                value: Box::new(self.reconstruct_expression(*input.value).0),
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Cast(CastExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                type_: self.qualify_type(input.type_),
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+
+    /// The expressions the parser failed on are kept, since the errors have already been reported.
+    fn reconstruct_err(&mut self, input: ErrExpression) -> (Expression, Self::AdditionalOutput) {
+        (Expression::Err(input), Default::default())
+    }
+
+    fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
+        (Expression::Identifier(self.qualify(input)), Default::default())
+    }
+
+    /// Qualifies the name of the struct, keeping the names of its members and the shorthand initializers.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                name: self.qualify(input.name),
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        expression: member.expression.map(|expression| self.reconstruct_expression(expression).0),
+                        ..member
+                    })
+                    .collect(),
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl StatementReconstructor for ModuleQualifier {
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Const(ConstDeclaration {
+                place: self.qualify(input.place),
+                type_: self.qualify_type(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_declaration(&mut self, input: DeclarationStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Declaration(DeclarationStatement {
+                identifier: self.qualify(input.identifier),
+                type_: self.qualify_type(input.type_),
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Definition(DefinitionStatement {
+                place: self.reconstruct_expression(input.place).0,
+                type_: input.type_.map(|type_| self.qualify_type(type_)),
+                value: self.reconstruct_expression(input.value).0,
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Iteration(Box::new(IterationStatement {
+                variable: self.qualify(input.variable),
+                type_: self.qualify_type(input.type_),
+                start: self.reconstruct_expression(input.start).0,
+                stop: self.reconstruct_expression(input.stop).0,
+                block: self.reconstruct_block(input.block).0,
+                ..input
+            })),
+            Default::default(),
+        )
+    }
+}
+
+impl ProgramReconstructor for ModuleQualifier {
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        ProgramScope {
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(name, declaration)| match self.reconstruct_const(declaration) {
+                    (Statement::Const(declaration), _) => (self.qualify_name(name), declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            structs: input
+                .structs
+                .into_iter()
+                .map(|(i, c)| (self.qualify_name(i), self.reconstruct_struct(c)))
+                .collect(),
+            functions: input
+                .functions
+                .into_iter()
+                .map(|(i, f)| (self.qualify_name(i), self.reconstruct_function(f)))
+                .collect(),
+            ..input
+        }
+    }
+
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        Function {
+            identifier: self.qualify(input.identifier),
+            input: input.input.into_iter().map(|input| self.qualify_input(input)).collect(),
+            output: input.output.into_iter().map(|output| self.qualify_output(output)).collect(),
+            output_type: self.qualify_type(input.output_type),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| Finalize {
+                input: finalize.input.into_iter().map(|input| self.qualify_input(input)).collect(),
+                output: finalize.output.into_iter().map(|output| self.qualify_output(output)).collect(),
+                output_type: self.qualify_type(finalize.output_type),
+                block: self.reconstruct_block(finalize.block).0,
+                ..finalize
+            }),
+            ..input
+        }
+    }
+
+    /// Qualifies the name of the struct and the types of its members, but not the names of its members or methods.
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            identifier: self.qualify(input.identifier),
+            members: input
+                .members
+                .into_iter()
+                .map(|member| Member { type_: self.qualify_type(member.type_), ..member })
+                .collect(),
+            methods: input.methods.into_iter().map(|(name, method)| (name, self.qualify_method(method))).collect(),
+            ..input
+        }
+    }
+}
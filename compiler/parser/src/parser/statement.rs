@@ -42,12 +42,14 @@ impl ParserContext<'_> {
         match &self.token.token {
             Token::Return => Ok(Statement::Return(self.parse_return_statement()?)),
             Token::If => Ok(Statement::Conditional(self.parse_conditional_statement()?)),
+            Token::Match => Ok(Statement::Match(self.parse_match_statement()?)),
             Token::For => Ok(Statement::Iteration(Box::new(self.parse_loop_statement()?))),
             Token::Assert | Token::AssertEq | Token::AssertNeq => Ok(self.parse_assert_statement()?),
-            Token::Let => Ok(Statement::Definition(self.parse_definition_statement()?)),
+            Token::Let => Ok(self.parse_definition_statement()?),
             Token::Const => Ok(Statement::Const(self.parse_const_declaration_statement()?)),
             Token::LeftCurly => Ok(Statement::Block(self.parse_block()?)),
-            Token::Console => Err(ParserError::console_statements_are_not_yet_supported(self.token.span).into()),
+            Token::At => Ok(Statement::Block(self.parse_annotated_block()?)),
+            Token::Console => Ok(Statement::Console(self.parse_console_statement()?)),
             Token::Finalize => Err(ParserError::finalize_statements_are_deprecated(self.token.span).into()),
             _ => Ok(self.parse_assign_statement()?),
         }
@@ -89,7 +91,12 @@ impl ParserContext<'_> {
     /// Returns a [`AssignStatement`] AST node if the next tokens represent a assign, otherwise expects an expression statement.
     fn parse_assign_statement(&mut self) -> Result<Statement> {
         let place = self.parse_expression()?;
+        self.parse_assign_statement_with_place(place)
+    }
 
+    /// Returns a [`AssignStatement`] AST node if the next tokens represent the rest of an assign to `place`,
+    /// otherwise expects the rest of an expression statement.
+    pub(super) fn parse_assign_statement_with_place(&mut self, place: Expression) -> Result<Statement> {
         if self.eat_any(ASSIGN_TOKENS) {
             // Determine the corresponding binary operation for each token, if it exists.
             let operation = match &self.prev_token.token {
@@ -110,7 +117,7 @@ impl ParserContext<'_> {
                 _ => unreachable!("`parse_assign_statement` shouldn't produce this"),
             };
 
-            let value = self.parse_expression()?;
+            let value = self.parse_statement_value()?;
             self.expect(&Token::Semicolon)?;
 
             // Construct the span for the statement.
@@ -168,11 +175,53 @@ impl ParserContext<'_> {
 
     /// Returns a [`Block`] AST node if the next tokens represent a block of statements.
     pub(super) fn parse_block(&mut self) -> Result<Block> {
-        self.parse_list(Delimiter::Brace, None, |p| p.parse_statement().map(Some)).map(|(statements, _, span)| Block {
-            statements,
-            span,
-            id: self.node_builder.next_id(),
-        })
+        let (statements, _, span) =
+            self.nested(|p| p.parse_list(Delimiter::Brace, None, |p| p.parse_statement_in_block().map(Some)))?;
+        Ok(Block { annotations: Vec::new(), statements, span, id: self.node_builder.next_id() })
+    }
+
+    /// Returns a [`Block`] AST node if the next tokens represent a block of statements preceded by annotations,
+    /// e.g. `@wrapping { ... }`.
+    fn parse_annotated_block(&mut self) -> Result<Block> {
+        let mut annotations = Vec::new();
+        while self.check(&Token::At) {
+            annotations.push(self.parse_annotation()?);
+        }
+        let block = self.parse_block()?;
+        Ok(Block { span: annotations[0].span + block.span, annotations, ..block })
+    }
+
+    /// Returns a [`Statement`] AST node if the next tokens represent a statement of a block.
+    /// If the parser recovers from a syntax error in the statement, an [`ErrStatement`] is returned in its place.
+    fn parse_statement_in_block(&mut self) -> Result<Statement> {
+        let point = self.recovery_point();
+        match self.parse_statement() {
+            Ok(statement) => Ok(statement),
+            Err(err) => {
+                self.recover(err, point, &[Token::Semicolon])?;
+                self.eat(&Token::Semicolon);
+                let span = point.0 + self.prev_token.span;
+                Ok(Statement::Err(ErrStatement { span, id: self.node_builder.next_id() }))
+            }
+        }
+    }
+
+    /// Returns an [`Expression`] AST node if the next tokens represent the value of a statement, which ends in a `;`.
+    /// If the parser recovers from a syntax error in the value, an [`ErrExpression`] is returned in its place, so that
+    /// the rest of the statement is kept.
+    fn parse_statement_value(&mut self) -> Result<Expression> {
+        let (start, depth) = self.recovery_point();
+        match self.parse_expression() {
+            Err(err) if self.recover && self.has_next() => {
+                if !self.skip_to_semicolon(depth) {
+                    return Err(err);
+                }
+                self.handler.emit_err(err);
+                let span = if self.token.span == start { start } else { start + self.prev_token.span };
+                Ok(Expression::Err(ErrExpression { span, id: self.node_builder.next_id() }))
+            }
+            result => result,
+        }
     }
 
     /// Returns a [`ReturnStatement`] AST node if the next tokens represent a return statement.
@@ -185,7 +234,7 @@ impl ParserContext<'_> {
                 Expression::Unit(UnitExpression { span: self.token.span, id: self.node_builder.next_id() })
             }
             // Otherwise, attempt to parse an expression.
-            _ => self.parse_expression()?,
+            _ => self.parse_statement_value()?,
         };
 
         let finalize_args = match self.token.token {
@@ -234,6 +283,39 @@ impl ParserContext<'_> {
         })
     }
 
+    /// Returns a [`MatchStatement`] AST node if the next tokens represent a match statement.
+    fn parse_match_statement(&mut self) -> Result<MatchStatement> {
+        let start = self.expect(&Token::Match)?;
+        self.disallow_struct_construction = true;
+        let value = self.parse_conditional_expression()?;
+        self.disallow_struct_construction = false;
+
+        // Parse the arms, each of which may be followed by a comma.
+        let (arms, _, span) = self.parse_list(Delimiter::Brace, None, |p| {
+            let arm = p.parse_match_arm()?;
+            p.eat(&Token::Comma);
+            Ok(Some(arm))
+        })?;
+
+        Ok(MatchStatement { value, arms, span: start + span, id: self.node_builder.next_id() })
+    }
+
+    /// Returns a [`MatchArm`] AST node if the next tokens represent an arm `pattern => block` of a match statement.
+    fn parse_match_arm(&mut self) -> Result<MatchArm> {
+        let (pattern, start) = match self.eat(&Token::Underscore) {
+            true => (None, self.prev_token.span),
+            false => {
+                let pattern = self.parse_expression()?;
+                let span = pattern.span();
+                (Some(pattern), span)
+            }
+        };
+        self.expect(&Token::BigArrow)?;
+        let block = self.parse_block()?;
+
+        Ok(MatchArm { pattern, span: start + block.span, block, id: self.node_builder.next_id() })
+    }
+
     /// Returns an [`IterationStatement`] AST node if the next tokens represent an iteration statement.
     fn parse_loop_statement(&mut self) -> Result<IterationStatement> {
         let start_span = self.expect(&Token::For)?;
@@ -266,12 +348,19 @@ impl ParserContext<'_> {
     }
 
     /// Returns a [`ConsoleStatement`] AST node if the next tokens represent a console statement.
-    #[allow(dead_code)]
+    /// Only `console.log` is supported, since the assertions are written as `assert`, `assert_eq`, and `assert_neq`.
     fn parse_console_statement(&mut self) -> Result<ConsoleStatement> {
         let keyword = self.expect(&Token::Console)?;
+        if !self.look_ahead(1, |next| next.token == Token::Identifier(sym::log)) {
+            return Err(ParserError::console_statements_are_not_yet_supported(keyword).into());
+        }
         self.expect(&Token::Dot)?;
         let identifier = self.expect_identifier()?;
         let (span, function) = match identifier.name {
+            sym::log => {
+                let (args, span) = self.parse_console_args()?;
+                (span, ConsoleFunction::Log(args))
+            }
             sym::assert => {
                 self.expect(&Token::LeftParen)?;
                 let expr = self.parse_expression()?;
@@ -315,8 +404,31 @@ impl ParserContext<'_> {
         Ok(ConsoleStatement { span: keyword + span, function, id: self.node_builder.next_id() })
     }
 
+    /// Returns the arguments of a `console.log` call, e.g. `("x is {}", x)`, and their span.
+    fn parse_console_args(&mut self) -> Result<(ConsoleArgs, Span)> {
+        let open_span = self.expect(&Token::LeftParen)?;
+        let (string, string_span) = match &self.token.token {
+            Token::StaticString(string) => (string.clone(), self.token.span),
+            _ => return self.unexpected("a format string"),
+        };
+        self.bump();
+
+        let string = FormatStringPart::parse(&string).unwrap_or_else(|| {
+            self.emit_err(ParserError::invalid_format_string(string_span));
+            Vec::new()
+        });
+        let mut parameters = Vec::new();
+        while self.eat(&Token::Comma) && !self.check(&Token::RightParen) {
+            parameters.push(self.parse_expression()?);
+        }
+        let close_span = self.expect(&Token::RightParen)?;
+
+        Ok((ConsoleArgs { string, parameters, span: string_span }, open_span + close_span))
+    }
+
     /// Returns a [`ConstDeclaration`] AST node if the next tokens represent a const declaration statement.
     pub(super) fn parse_const_declaration_statement(&mut self) -> Result<ConstDeclaration> {
+        let doc = self.doc_comment();
         self.expect(&Token::Const)?;
         let decl_span = self.prev_token.span;
 
@@ -324,14 +436,22 @@ impl ParserContext<'_> {
         let (place, type_, _) = self.parse_typed_ident()?;
 
         self.expect(&Token::Assign)?;
-        let value = self.parse_expression()?;
+        let value = self.parse_statement_value()?;
         self.expect(&Token::Semicolon)?;
 
-        Ok(ConstDeclaration { span: decl_span + value.span(), place, type_, value, id: self.node_builder.next_id() })
+        Ok(ConstDeclaration {
+            doc,
+            span: decl_span + value.span(),
+            place,
+            type_,
+            value,
+            id: self.node_builder.next_id(),
+        })
     }
 
     /// Returns a [`DefinitionStatement`] AST node if the next tokens represent a definition statement.
-    pub(super) fn parse_definition_statement(&mut self) -> Result<DefinitionStatement> {
+    /// A variable that is declared with a type but without a value, e.g. `let x: u32;`, is returned as a [`DeclarationStatement`].
+    pub(super) fn parse_definition_statement(&mut self) -> Result<Statement> {
         self.expect(&Token::Let)?;
         let decl_span = self.prev_token.span;
         let decl_type = match &self.prev_token.token {
@@ -340,22 +460,40 @@ impl ParserContext<'_> {
             _ => unreachable!("parse_definition_statement_ shouldn't produce this"),
         };
 
-        // Parse variable name and type.
+        // Parse variable name and, if present, type.
         let place = self.parse_expression()?;
-        self.expect(&Token::Colon)?;
-        let type_ = self.parse_type()?.0;
+        if let Expression::Literal(literal) = &place {
+            return Err(ParserError::implicit_values_not_allowed(literal, place.span()).into());
+        }
+        let type_ = match self.eat(&Token::Colon) {
+            true => Some(self.parse_type()?.0),
+            false => None,
+        };
+
+        // Parse a declaration without a value.
+        if let (Expression::Identifier(identifier), Some(type_)) = (&place, &type_) {
+            let type_span = self.prev_token.span;
+            if self.eat(&Token::Semicolon) {
+                return Ok(Statement::Declaration(DeclarationStatement {
+                    identifier: *identifier,
+                    type_: type_.clone(),
+                    span: decl_span + type_span,
+                    id: self.node_builder.next_id(),
+                }));
+            }
+        }
 
         self.expect(&Token::Assign)?;
-        let value = self.parse_expression()?;
+        let value = self.parse_statement_value()?;
         self.expect(&Token::Semicolon)?;
 
-        Ok(DefinitionStatement {
+        Ok(Statement::Definition(DefinitionStatement {
             span: decl_span + value.span(),
             declaration_type: decl_type,
             place,
             type_,
             value,
             id: self.node_builder.next_id(),
-        })
+        }))
     }
 }
@@ -0,0 +1,329 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_span::{span::Pos, Symbol};
+
+/// A node of a program that comments are attached to.
+enum Element<'a> {
+    Import(Symbol, Span),
+    Scope(&'a ProgramScope),
+    Module(&'a Module),
+    Const(&'a ConstDeclaration),
+    Interface(&'a Interface),
+    Struct(&'a Struct),
+    Member(&'a Member),
+    Enum(&'a Enum),
+    Variant(&'a Identifier),
+    Mapping(&'a Mapping),
+    Function(&'a Function),
+    Statement(&'a Statement),
+    Arm(&'a MatchArm),
+    Value(&'a Expression),
+}
+
+impl Element<'_> {
+    /// Returns the span of the element, which includes the annotations of a struct or function,
+    /// and the finalize block of a function.
+    fn span(&self) -> Span {
+        match self {
+            Element::Import(_, span) => *span,
+            Element::Scope(scope) => scope.span,
+            Element::Module(module) => module.span,
+            Element::Const(declaration) => declaration.span,
+            Element::Interface(interface) => interface.span,
+            Element::Struct(struct_) => struct_span(struct_),
+            Element::Member(member) => member.span,
+            Element::Enum(enum_) => enum_.span,
+            Element::Variant(variant) => variant.span,
+            Element::Mapping(mapping) => mapping.span,
+            Element::Function(function) => function_span(function),
+            Element::Statement(statement) => statement.span(),
+            Element::Arm(arm) => arm.span,
+            Element::Value(value) => value.span(),
+        }
+    }
+}
+
+/// Returns the span of a struct, including its annotations.
+pub(crate) fn struct_span(struct_: &Struct) -> Span {
+    struct_.annotations.first().map_or(struct_.span, |annotation| annotation.span + struct_.span)
+}
+
+/// Returns the span of a function, including its annotations and its finalize block.
+pub(crate) fn function_span(function: &Function) -> Span {
+    let span = function.finalize.as_ref().map_or(function.span, |finalize| function.span + finalize.span);
+    function.annotations.first().map_or(span, |annotation| annotation.span + span)
+}
+
+/// Attaches the comments of a program to its imports, items, members, and statements.
+///
+/// A comment is attached to the first element after it, unless it is within an element,
+/// or after an element on the line that the element ends on, in which case it trails that element.
+/// The comments after the last element of a block are attached to the block.
+pub(super) struct TriviaCollector {
+    /// The position of the start of the source code.
+    start_pos: BytePos,
+    /// The offsets of the line breaks in the source code, used to find the line of a position.
+    line_breaks: Vec<usize>,
+    /// The comments that are yet to be attached, in reverse order, so that the next comment is at the end.
+    comments: Vec<(String, Span)>,
+    /// The trivia collected so far.
+    trivia: TriviaMap,
+}
+
+impl TriviaCollector {
+    /// Returns a new collector for the comments in `tokens`, which are the tokens of `source`.
+    pub(super) fn new(source: &str, start_pos: BytePos, tokens: &[SpannedToken]) -> Self {
+        let comments = tokens
+            .iter()
+            .rev()
+            .filter_map(|token| match &token.token {
+                // The span of a line comment includes the line break that ends it, which is not part of the text.
+                Token::CommentLine(text) | Token::CommentBlock(text) => {
+                    let text = text.trim_end().to_string();
+                    let span = Span::new(token.span.lo, token.span.lo + BytePos::from_usize(text.len()));
+                    Some((text, span))
+                }
+                _ => None,
+            })
+            .collect();
+        let line_breaks = source.match_indices('\n').map(|(offset, _)| offset).collect();
+        Self { start_pos, line_breaks, comments, trivia: TriviaMap::default() }
+    }
+
+    /// Returns the trivia of `program`.
+    pub(super) fn collect(mut self, program: &Program) -> TriviaMap {
+        let mut elements = program
+            .imports
+            .iter()
+            .map(|(name, (_, span))| Element::Import(*name, *span))
+            .chain(program.stubs.iter().map(|(name, (_, span))| Element::Import(*name, *span)))
+            .chain(program.program_scopes.values().map(Element::Scope))
+            .collect::<Vec<_>>();
+        elements.sort_by_key(|element| element.span().lo);
+
+        let end_of_file = self.sequence(elements, BytePos(u32::MAX));
+        self.trivia.set_end_of_file(end_of_file);
+        self.trivia
+    }
+
+    /// Returns the line of the source code that `position` is on.
+    fn line(&self, position: BytePos) -> usize {
+        let offset = position.to_usize() - self.start_pos.to_usize();
+        self.line_breaks.partition_point(|line_break| *line_break < offset)
+    }
+
+    /// Returns `true` if there is a blank line in the source code between `previous` and `position`.
+    fn separated(&self, previous: Option<BytePos>, position: BytePos) -> bool {
+        previous.map_or(false, |previous| self.line(position) > self.line(previous) + 1)
+    }
+
+    /// Returns the comments before `position`, and the end of the last of them, or `previous` if there are none.
+    fn leading(&mut self, position: BytePos, mut previous: Option<BytePos>) -> (Vec<Comment>, Option<BytePos>) {
+        let mut comments = Vec::new();
+        while self.comments.last().map_or(false, |(_, span)| span.lo < position) {
+            let (text, span) = self.comments.pop().unwrap();
+            comments.push(Comment { text, span, blank_line_before: self.separated(previous, span.lo) });
+            previous = Some(span.hi);
+        }
+        (comments, previous)
+    }
+
+    /// Returns the comments within an element that ends at `end`, and the comments after it on the same line,
+    /// if they are before `limit`, along with the end of the last of them, or `end` if there are none.
+    fn trailing(&mut self, end: BytePos, limit: BytePos) -> (Vec<Comment>, BytePos) {
+        let mut comments = Vec::new();
+        let mut previous = end;
+        while let Some((_, span)) = self.comments.last() {
+            let within = span.lo < end;
+            let same_line = span.lo < limit && self.line(span.lo) == self.line(end);
+            if !(within || same_line) {
+                break;
+            }
+            let (text, span) = self.comments.pop().unwrap();
+            comments.push(Comment { text, span, blank_line_before: false });
+            previous = previous.max(span.hi);
+        }
+        (comments, previous)
+    }
+
+    /// Attaches the comments to `elements` and their children, where `end` is the end of the enclosing node.
+    /// Returns the comments after the last element.
+    fn sequence(&mut self, elements: Vec<Element>, end: BytePos) -> Vec<Comment> {
+        let mut previous = None;
+        for element in elements {
+            let span = element.span();
+            let (leading, last) = self.leading(span.lo, previous);
+            let blank_line_before = self.separated(last, span.lo);
+            let dangling = self.element(&element);
+            let (trailing, last) = self.trailing(span.hi, end);
+            previous = Some(last);
+
+            let trivia = Trivia { leading, blank_line_before, trailing, dangling };
+            if !trivia.is_empty() {
+                *match element {
+                    Element::Import(name, _) => self.trivia.import_mut(name),
+                    Element::Scope(scope) => self.trivia.node_mut(scope.program_id.name.id),
+                    Element::Module(module) => self.trivia.node_mut(module.id),
+                    Element::Const(declaration) => self.trivia.node_mut(declaration.id),
+                    Element::Interface(interface) => self.trivia.node_mut(interface.id),
+                    Element::Struct(struct_) => self.trivia.node_mut(struct_.id),
+                    Element::Member(member) => self.trivia.node_mut(member.id),
+                    Element::Enum(enum_) => self.trivia.node_mut(enum_.id),
+                    Element::Variant(variant) => self.trivia.node_mut(variant.id),
+                    Element::Mapping(mapping) => self.trivia.node_mut(mapping.id),
+                    Element::Function(function) => self.trivia.node_mut(function.id),
+                    Element::Statement(statement) => self.trivia.node_mut(statement.id()),
+                    Element::Arm(arm) => self.trivia.node_mut(arm.id),
+                    Element::Value(value) => self.trivia.node_mut(value.id()),
+                } = trivia;
+            }
+        }
+        self.leading(end, previous).0
+    }
+
+    /// Attaches the comments to the children of `element`, and returns the comments after its last child.
+    fn element(&mut self, element: &Element) -> Vec<Comment> {
+        match element {
+            Element::Import(..)
+            | Element::Module(_)
+            | Element::Member(_)
+            | Element::Variant(_)
+            | Element::Mapping(_) => Vec::new(),
+            Element::Scope(scope) => {
+                let mut elements = scope
+                    .modules
+                    .iter()
+                    .map(|(_, module)| Element::Module(module))
+                    .chain(scope.consts.iter().map(|(_, declaration)| Element::Const(declaration)))
+                    .chain(scope.interfaces.iter().map(|(_, interface)| Element::Interface(interface)))
+                    .chain(scope.structs.iter().map(|(_, struct_)| Element::Struct(struct_)))
+                    .chain(scope.enums.iter().map(|(_, enum_)| Element::Enum(enum_)))
+                    .chain(scope.mappings.iter().map(|(_, mapping)| Element::Mapping(mapping)))
+                    .chain(scope.functions.iter().map(|(_, function)| Element::Function(function)))
+                    .collect::<Vec<_>>();
+                elements.sort_by_key(|element| element.span().lo);
+                self.sequence(elements, scope.span.hi)
+            }
+            Element::Const(declaration) => {
+                self.visit_expression(&declaration.value, &());
+                Vec::new()
+            }
+            Element::Interface(interface) => {
+                self.sequence(interface.members.iter().map(Element::Member).collect(), interface.span.hi)
+            }
+            Element::Enum(enum_) => self.sequence(enum_.variants.iter().map(Element::Variant).collect(), enum_.span.hi),
+            Element::Struct(struct_) => {
+                let mut elements = struct_
+                    .members
+                    .iter()
+                    .map(Element::Member)
+                    .chain(struct_.methods.iter().map(|(_, method)| Element::Function(method)))
+                    .collect::<Vec<_>>();
+                elements.sort_by_key(|element| element.span().lo);
+                self.sequence(elements, struct_.span.hi)
+            }
+            Element::Function(function) => {
+                self.visit_block(&function.block);
+                if let Some(finalize) = &function.finalize {
+                    let (leading, last) = self.leading(finalize.span.lo, Some(function.block.span.hi));
+                    let blank_line_before = self.separated(last, finalize.span.lo);
+                    if !leading.is_empty() || blank_line_before {
+                        let trivia = self.trivia.node_mut(finalize.id);
+                        trivia.leading = leading;
+                        trivia.blank_line_before = blank_line_before;
+                    }
+                    self.visit_block(&finalize.block);
+                }
+                Vec::new()
+            }
+            // The comments after the last child of a block are attached to the block itself, which is the element.
+            Element::Statement(Statement::Block(block)) => self.block_sequence(block),
+            Element::Value(Expression::Block(block)) => self.block_expression_sequence(block),
+            // Likewise, the comments after the last arm of a match are attached to the match.
+            Element::Statement(Statement::Match(match_)) => {
+                self.visit_expression(&match_.value, &());
+                self.sequence(match_.arms.iter().map(Element::Arm).collect(), match_.span.hi)
+            }
+            Element::Arm(arm) => {
+                if let Some(pattern) = &arm.pattern {
+                    self.visit_expression(pattern, &());
+                }
+                self.visit_block(&arm.block);
+                Vec::new()
+            }
+            Element::Statement(statement) => {
+                self.visit_statement(statement);
+                Vec::new()
+            }
+            Element::Value(value) => {
+                self.visit_expression(value, &());
+                Vec::new()
+            }
+        }
+    }
+
+    /// Attaches the comments to the statements of `block`, and returns the comments after the last statement.
+    fn block_sequence(&mut self, block: &Block) -> Vec<Comment> {
+        self.sequence(block.statements.iter().map(Element::Statement).collect(), block.span.hi)
+    }
+
+    /// Attaches the comments to the statements and the value of `block`, and returns the comments after the value.
+    fn block_expression_sequence(&mut self, block: &BlockExpression) -> Vec<Comment> {
+        let elements = block
+            .statements
+            .iter()
+            .map(Element::Statement)
+            .chain(std::iter::once(Element::Value(&block.value)))
+            .collect();
+        self.sequence(elements, block.span.hi)
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for TriviaCollector {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_block_expression(&mut self, input: &'a BlockExpression, _additional: &Self::AdditionalInput) {
+        let dangling = self.block_expression_sequence(input);
+        if !dangling.is_empty() {
+            self.trivia.node_mut(input.id).dangling = dangling;
+        }
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) {
+        // The receiver of a method call is written before its arguments.
+        self.visit_expression(&input.function, additional);
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for TriviaCollector {
+    fn visit_block(&mut self, input: &'a Block) {
+        let dangling = self.block_sequence(input);
+        if !dangling.is_empty() {
+            self.trivia.node_mut(input.id).dangling = dangling;
+        }
+    }
+}
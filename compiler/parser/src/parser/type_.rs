@@ -75,26 +75,68 @@ impl ParserContext<'_> {
         ))
     }
 
+    /// Returns a [`ConstArgument`] AST node if the next token is a whole number or the name of a constant.
+    pub(super) fn parse_const_argument(&mut self) -> Result<ConstArgument> {
+        match self.eat_identifier() {
+            Some(constant) => Ok(ConstArgument::Constant(constant)),
+            None => Ok(ConstArgument::Number(self.eat_whole_number()?.0)),
+        }
+    }
+
+    /// Returns the constant arguments of a generic struct, e.g. `<2, N>`, and their span.
+    pub(super) fn parse_const_arguments(&mut self) -> Result<(Vec<ConstArgument>, Span)> {
+        let (arguments, _, span) =
+            self.parse_list(Delimiter::AngleBracket, Some(Token::Comma), |p| p.parse_const_argument().map(Some))?;
+        Ok((arguments, span))
+    }
+
+    /// Returns `true` if the next tokens are `::` followed by the name of an item of a module, e.g. `::Point`.
+    pub(super) fn check_qualified_name(&self) -> bool {
+        self.check(&Token::DoubleColon) && self.look_ahead(1, |next| matches!(next.token, Token::Identifier(_)))
+    }
+
+    /// Parses `::item` after the name of the module `module`, and returns the name that the item is declared under
+    /// in the program scope, e.g. `math__Point` for `math::Point`.
+    pub(super) fn parse_qualified_name(&mut self, module: Identifier) -> Result<Identifier> {
+        self.expect(&Token::DoubleColon)?;
+        let item = self.expect_identifier()?;
+        Ok(Identifier {
+            name: Module::item_name(module.name, item.name),
+            span: module.span + item.span,
+            id: self.node_builder.next_id(),
+        })
+    }
+
     /// Returns a [`(Type, Span)`] tuple of AST nodes if the next token represents a type.
     /// Also returns the span of the parsed token.
     pub fn parse_type(&mut self) -> Result<(Type, Span)> {
         if let Some(ident) = self.eat_identifier() {
-            Ok((Type::Identifier(ident), ident.span))
+            let ident = match self.check_qualified_name() {
+                true => self.parse_qualified_name(ident)?,
+                false => ident,
+            };
+            if self.check(&Token::Lt) {
+                // Parse an instance of a generic struct, e.g. `Matrix<2, 3>`.
+                let (arguments, span) = self.parse_const_arguments()?;
+                Ok((Type::Generic(GenericType { name: ident, arguments }), ident.span + span))
+            } else {
+                Ok((Type::Identifier(ident), ident.span))
+            }
         } else if self.token.token == Token::LeftSquare {
             // Parse the left bracket.
             self.expect(&Token::LeftSquare)?;
             // Parse the element type.
-            let (element_type, _) = self.parse_type()?;
+            let (element_type, _) = self.nested(Self::parse_type)?;
             // Parse the semi-colon.
             self.expect(&Token::Semicolon)?;
-            // Parse the length.
-            let (length, _) = self.eat_whole_number()?;
+            // Parse the length, which is either a number or the name of a constant.
+            let length = self.parse_const_argument()?;
             // Parse the right bracket.
             self.expect(&Token::RightSquare)?;
             // Return the array type.
             Ok((Type::Array(ArrayType::new(element_type, length)), self.prev_token.span))
         } else if self.token.token == Token::LeftParen {
-            let (types, _, span) = self.parse_paren_comma_list(|p| p.parse_type().map(Some))?;
+            let (types, _, span) = self.parse_paren_comma_list(|p| p.nested(Self::parse_type).map(Some))?;
             match types.len() {
                 // If the parenthetical block is empty, e.g. `()` or `( )`, it should be parsed into `Unit` types.
                 0 => Ok((Type::Unit, span)),
@@ -0,0 +1,430 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Reads the stub of a deployed program from its Aleo instructions.
+//! Only the declarations that importing programs depend on are read, i.e. the program ID, the structs and records,
+//! and the inputs, outputs, and finalize inputs of the functions. Closures and mappings cannot be used by other
+//! programs, so they are skipped, along with the instructions in the bodies of functions.
+
+use leo_ast::*;
+use leo_errors::{ParserError, Result};
+use leo_span::{
+    span::{BytePos, Pos},
+    sym,
+    Span,
+    Symbol,
+};
+
+/// Returns the stub of the deployed program whose Aleo instructions are `source`, which starts at `start_pos`.
+pub fn parse_stub(node_builder: &NodeBuilder, source: &str, start_pos: BytePos) -> Result<Stub> {
+    let mut reader = StubReader { node_builder, program_id: None, structs: Vec::new(), functions: Vec::new() };
+    let mut item = Item::None;
+
+    let mut offset = 0;
+    for line in source.split_inclusive('\n') {
+        // Remove the comment and surrounding whitespace from the line.
+        let code = line.split("//").next().unwrap_or_default();
+        let start = offset + (code.len() - code.trim_start().len());
+        let code = code.trim();
+        offset += line.len();
+
+        if !code.is_empty() {
+            let span =
+                Span::new(start_pos + BytePos::from_usize(start), start_pos + BytePos::from_usize(start + code.len()));
+            reader.read_line(&mut item, code, span)?;
+        }
+    }
+    reader.finish_item(item);
+
+    let end = start_pos + BytePos::from_usize(source.len());
+    match reader.program_id {
+        Some((program_id, span)) => Ok(Stub { program_id, structs: reader.structs, functions: reader.functions, span }),
+        None => Err(ParserError::invalid_stub("there is no program declaration", Span::new(start_pos, end)).into()),
+    }
+}
+
+/// The item whose body is being read.
+enum Item {
+    /// No item has been declared yet.
+    None,
+    /// A closure or mapping, which is skipped.
+    Skipped,
+    /// A struct or record.
+    Struct(Struct),
+    /// A function, along with whether it has a finalize block.
+    Function(Box<Function>, bool),
+    /// The finalize block of the last function.
+    Finalize(Vec<Input>),
+}
+
+/// The state of reading a stub, line by line.
+struct StubReader<'a> {
+    /// Used to generate unique node ids.
+    node_builder: &'a NodeBuilder,
+    /// The program ID, along with the span of its declaration, once it has been read.
+    program_id: Option<(ProgramId, Span)>,
+    /// The structs and records read so far.
+    structs: Vec<(Symbol, Struct)>,
+    /// The functions read so far.
+    functions: Vec<(Symbol, Function)>,
+}
+
+impl StubReader<'_> {
+    /// Reads a line of Aleo instructions, without its comment, in the body of `item`.
+    fn read_line(&mut self, item: &mut Item, line: &str, span: Span) -> Result<()> {
+        if let Some(header) = line.strip_suffix(':') {
+            let previous = std::mem::replace(item, Item::Skipped);
+            self.finish_item(previous);
+            *item = self.read_item(header, span)?;
+            return Ok(());
+        }
+        let Some(statement) = line.strip_suffix(';') else {
+            return Err(ParserError::invalid_stub(format!("`{line}` does not end with `;` or `:`"), span).into());
+        };
+        // The type after `as` is kept whole, since an array type contains whitespace, e.g. `[u8; 4u32]`.
+        let mut words = Vec::new();
+        match statement.split_once(" as ") {
+            Some((operands, type_)) => words.extend(operands.split_whitespace().chain(["as", type_.trim()])),
+            None => words.extend(statement.split_whitespace()),
+        }
+
+        match (item, words.as_slice()) {
+            // The imports of the program are deployed, so they are not needed to call it.
+            (Item::None, ["import", _]) => {}
+            (Item::None, ["program", program_id]) => {
+                let (name, network) = self.program_id(program_id, span)?;
+                let program_id = ProgramId { name, network };
+                self.program_id = Some((program_id, span));
+            }
+            (Item::Skipped, _) => {}
+            (Item::Struct(struct_), [name, "as", type_]) => {
+                let (type_, mode) = match struct_.is_record {
+                    true => self.value_type(type_, span)?,
+                    false => (self.plaintext_type(type_, span)?, Mode::None),
+                };
+                let identifier = self.identifier(name, span)?;
+                struct_.members.push(Member {
+                    mode,
+                    identifier,
+                    type_,
+                    default: None,
+                    span,
+                    id: self.node_builder.next_id(),
+                });
+            }
+            (Item::Function(function, _), ["input", register, "as", type_]) => {
+                function.input.push(self.input(register, type_, span)?);
+            }
+            (Item::Function(function, _), ["output", register, "as", type_]) => {
+                let output = match self.input(register, type_, span)? {
+                    Input::Internal(input) => Output::Internal(FunctionOutput {
+                        mode: input.mode,
+                        type_: input.type_,
+                        span,
+                        id: self.node_builder.next_id(),
+                    }),
+                    Input::External(external) => Output::External(external),
+                };
+                function.output.push(output);
+            }
+            (Item::Function(_, has_finalize), ["finalize", ..]) => *has_finalize = true,
+            // The instructions of a function are not needed to call it.
+            (Item::Function(..), _) => {}
+            (Item::Finalize(inputs), ["input", register, "as", type_]) => {
+                inputs.push(self.input(register, type_, span)?);
+            }
+            (Item::Finalize(..), _) => {}
+            _ => return Err(ParserError::invalid_stub(format!("`{line}` is not expected here"), span).into()),
+        }
+        Ok(())
+    }
+
+    /// Returns the item declared by `header`, e.g. `function transfer`.
+    fn read_item(&self, header: &str, span: Span) -> Result<Item> {
+        if self.program_id.is_none() {
+            return Err(ParserError::invalid_stub("an item is declared before the program", span).into());
+        }
+
+        match header.split_whitespace().collect::<Vec<_>>().as_slice() {
+            ["closure" | "mapping", _] => Ok(Item::Skipped),
+            [keyword @ ("struct" | "record"), name] => Ok(Item::Struct(Struct {
+                doc: None,
+                annotations: Vec::new(),
+                identifier: self.identifier(name, span)?,
+                visibility: Visibility::Public,
+                const_parameters: Vec::new(),
+                members: Vec::new(),
+                interfaces: Vec::new(),
+                methods: Vec::new(),
+                is_record: *keyword == "record",
+                span,
+                id: self.node_builder.next_id(),
+            })),
+            ["function", name] => {
+                let function = Function::new(
+                    Vec::new(),
+                    Visibility::Default,
+                    Variant::Transition,
+                    self.identifier(name, span)?,
+                    Vec::new(),
+                    Vec::new(),
+                    Block { annotations: Vec::new(), statements: Vec::new(), span, id: self.node_builder.next_id() },
+                    None,
+                    span,
+                    self.node_builder.next_id(),
+                );
+                Ok(Item::Function(Box::new(function), false))
+            }
+            ["finalize", name] => {
+                let name = self.identifier(name, span)?.name;
+                match self.functions.last() {
+                    Some((function, _)) if *function == name => Ok(Item::Finalize(Vec::new())),
+                    _ => {
+                        let reason = format!("the finalize block `{name}` does not follow the function `{name}`");
+                        Err(ParserError::invalid_stub(reason, span).into())
+                    }
+                }
+            }
+            _ => Err(ParserError::invalid_stub(format!("`{header}` is not an item"), span).into()),
+        }
+    }
+
+    /// Adds `item`, whose body has been read, to the stub.
+    fn finish_item(&mut self, item: Item) {
+        match item {
+            Item::None | Item::Skipped => {}
+            Item::Struct(struct_) => self.structs.push((struct_.name(), struct_)),
+            Item::Function(function, has_finalize) => {
+                // The output type is computed from the outputs, which were read after the function was created.
+                let mut function = Function::new(
+                    function.annotations,
+                    function.visibility,
+                    function.variant,
+                    function.identifier,
+                    function.input,
+                    function.output,
+                    function.block,
+                    None,
+                    function.span,
+                    function.id,
+                );
+                if has_finalize {
+                    let block = function.block.clone();
+                    function.finalize = Some(Finalize::new(
+                        function.identifier,
+                        Vec::new(),
+                        Vec::new(),
+                        block,
+                        function.span,
+                        self.node_builder.next_id(),
+                    ));
+                }
+                self.functions.push((function.name(), function));
+            }
+            Item::Finalize(inputs) => {
+                if let Some(finalize) = self.functions.last_mut().and_then(|(_, function)| function.finalize.as_mut()) {
+                    finalize.input = inputs;
+                }
+            }
+        }
+    }
+
+    /// Returns the input `register as type_` of a function or finalize block.
+    fn input(&self, register: &str, type_: &str, span: Span) -> Result<Input> {
+        let identifier = self.identifier(register, span)?;
+
+        // A record of another program, e.g. `token.aleo/Token.record`.
+        if let Some((program_id, record)) = type_.strip_suffix(".record").and_then(|type_| type_.split_once('/')) {
+            let (program_name, network) = self.program_id(program_id, span)?;
+            return Ok(Input::External(External {
+                identifier,
+                program_name,
+                network: Some(network),
+                record: self.identifier(record, span)?,
+                span,
+                id: self.node_builder.next_id(),
+            }));
+        }
+
+        let (type_, mode) = self.value_type(type_, span)?;
+        Ok(Input::Internal(FunctionInput { identifier, mode, type_, span, id: self.node_builder.next_id() }))
+    }
+
+    /// Returns the type and mode of a value type, e.g. `u64.public` or `Token.record`.
+    fn value_type(&self, type_: &str, span: Span) -> Result<(Type, Mode)> {
+        let (plaintext, mode) = match type_.rsplit_once('.') {
+            Some((record, "record")) => return Ok((Type::Identifier(self.identifier(record, span)?), Mode::None)),
+            Some((plaintext, "constant")) => (plaintext, Mode::Constant),
+            Some((plaintext, "public")) => (plaintext, Mode::Public),
+            Some((plaintext, "private")) => (plaintext, Mode::Private),
+            _ => (type_, Mode::None),
+        };
+        Ok((self.plaintext_type(plaintext, span)?, mode))
+    }
+
+    /// Returns a plaintext type, i.e. a literal type, an array type, or the name of a struct.
+    fn plaintext_type(&self, type_: &str, span: Span) -> Result<Type> {
+        let literal_type = match type_ {
+            "address" => Some(Type::Address),
+            "boolean" => Some(Type::Boolean),
+            "field" => Some(Type::Field),
+            "group" => Some(Type::Group),
+            "scalar" => Some(Type::Scalar),
+            "signature" => Some(Type::Signature),
+            "string" => Some(Type::String),
+            "i8" => Some(Type::Integer(IntegerType::I8)),
+            "i16" => Some(Type::Integer(IntegerType::I16)),
+            "i32" => Some(Type::Integer(IntegerType::I32)),
+            "i64" => Some(Type::Integer(IntegerType::I64)),
+            "i128" => Some(Type::Integer(IntegerType::I128)),
+            "u8" => Some(Type::Integer(IntegerType::U8)),
+            "u16" => Some(Type::Integer(IntegerType::U16)),
+            "u32" => Some(Type::Integer(IntegerType::U32)),
+            "u64" => Some(Type::Integer(IntegerType::U64)),
+            "u128" => Some(Type::Integer(IntegerType::U128)),
+            _ => None,
+        };
+        if let Some(literal_type) = literal_type {
+            return Ok(literal_type);
+        }
+
+        // An array type, e.g. `[u8; 4u32]`, whose length is a `u32` literal.
+        if let Some(array) = type_.strip_prefix('[').and_then(|type_| type_.strip_suffix(']')) {
+            let (element, length) = array.rsplit_once(';').unwrap_or((array, ""));
+            let Some(length) = length.trim().strip_suffix("u32").and_then(|length| length.parse::<usize>().ok()) else {
+                return Err(ParserError::invalid_stub(format!("`{type_}` is not a valid array type"), span).into());
+            };
+            let element = self.plaintext_type(element.trim(), span)?;
+            return Ok(Type::Array(ArrayType::new(element, NonNegativeNumber::from(length))));
+        }
+
+        Ok(Type::Identifier(self.identifier(type_, span)?))
+    }
+
+    /// Returns the name and network of a program ID, e.g. `token.aleo`.
+    fn program_id(&self, program_id: &str, span: Span) -> Result<(Identifier, Identifier)> {
+        match program_id.split_once('.') {
+            Some((name, network)) if network == sym::aleo.to_string() => {
+                Ok((self.identifier(name, span)?, self.identifier(network, span)?))
+            }
+            _ => Err(ParserError::invalid_stub(format!("`{program_id}` is not a program ID"), span).into()),
+        }
+    }
+
+    /// Returns the identifier `name`, which must start with a letter, and contain only letters, digits, and `_`.
+    fn identifier(&self, name: &str, span: Span) -> Result<Identifier> {
+        let is_identifier = name.starts_with(|c: char| c.is_ascii_alphabetic())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        match is_identifier {
+            true => Ok(Identifier { name: Symbol::intern(name), span, id: self.node_builder.next_id() }),
+            false => Err(ParserError::invalid_stub(format!("`{name}` is not an identifier"), span).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const TOKEN: &str = "import credits.aleo;
+program token.aleo;
+
+// A balance, along with the block height it was last updated at.
+struct Balance:
+    amount as u64;
+    heights as [u32; 2u32];
+
+record Token:
+    owner as address.private;
+    amount as u64.private;
+
+mapping balances:
+    key as address.public;
+    value as Balance.public;
+
+closure double:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    cast r0 r1 into r2 as Token.record;
+    output r2 as Token.record;
+    finalize r0 r1;
+
+finalize mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    set r1 into balances[r0];
+
+function pay:
+    input r0 as credits.aleo/credits.record;
+    output r0 as credits.aleo/credits.record;
+";
+
+    #[test]
+    fn test_parse_stub() {
+        create_session_if_not_set_then(|_| {
+            let stub = parse_stub(&NodeBuilder::default(), TOKEN, BytePos::default()).unwrap();
+            assert_eq!(stub.program_id.to_string(), "token.aleo");
+
+            let structs = stub
+                .structs
+                .iter()
+                .map(|(name, struct_)| {
+                    let members = struct_.members.iter().map(|member| member.to_string()).collect::<Vec<_>>();
+                    format!("{name} {{ {} }}", members.join(", "))
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(structs, [
+                "Balance { amount: u64, heights: [u32; 2] }",
+                "Token { private owner address, private amount u64 }"
+            ]);
+            assert!(stub.structs[1].1.is_record);
+
+            let functions = stub.functions.iter().map(|(name, _)| name.to_string()).collect::<Vec<_>>();
+            assert_eq!(functions, ["mint", "pay"]);
+
+            let (_, mint) = &stub.functions[0];
+            assert_eq!(mint.input.len(), 2);
+            assert_eq!(mint.output_type.to_string(), "Token");
+            assert_eq!(mint.finalize.as_ref().map(|finalize| finalize.input.len()), Some(2));
+
+            let (_, pay) = &stub.functions[1];
+            let Input::External(credits) = &pay.input[0] else { panic!("expected an external record") };
+            assert_eq!(credits.to_string(), "r0: credits.aleo/credits.record");
+            assert!(pay.finalize.is_none());
+        })
+    }
+
+    #[test]
+    fn test_parse_invalid_stub() {
+        create_session_if_not_set_then(|_| {
+            let node_builder = NodeBuilder::default();
+            let error = |source: &str| parse_stub(&node_builder, source, BytePos::default()).unwrap_err().to_string();
+            assert!(error("struct Foo:\n    x as u8;\n").contains("an item is declared before the program"));
+            assert!(error("program token.aleo;\nstruct Foo:\n    x as u8\n").contains("does not end with"));
+            let array = "program token.aleo;\nfunction foo:\n    input r0 as [u8; 2];\n";
+            assert!(error(array).contains("is not a valid array type"));
+            assert!(error("program token.aleo;\nfinalize foo:\n").contains("does not follow the function"));
+            assert!(error("import credits.aleo;\n").contains("there is no program declaration"));
+        })
+    }
+}
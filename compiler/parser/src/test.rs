@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{tokenizer, ParserContext, SpannedToken};
+use crate::{formatter::audit::without_positions, tokenizer, ParserContext, SpannedToken};
 
-use leo_ast::{NodeBuilder, NodeID, Statement};
+use leo_ast::{Node, NodeBuilder, NodeID, Statement};
 use leo_errors::{emitter::Handler, LeoError};
 use leo_span::{
     source_map::FileName,
@@ -24,6 +24,7 @@ use leo_span::{
     Span,
 };
 use leo_test_framework::{
+    generator::ProgramGenerator,
     runner::{Namespace, ParseType, Runner},
     Test,
 };
@@ -214,6 +215,48 @@ impl Namespace for InputNamespace {
     }
 }
 
+struct FormatNamespace;
+
+impl Namespace for FormatNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        create_session_if_not_set_then(|_| format_and_check(&test.content)).map(Value::String)
+    }
+}
+
+/// Formats `source`, checking that formatting the result again does not change it,
+/// and that the formatted program has the same AST as `source`.
+fn format_and_check(source: &str) -> Result<String, String> {
+    let formatted = crate::format_program(source).map_err(|err| err.to_string())?;
+    let reformatted = crate::format_program(&formatted)
+        .map_err(|err| format!("the formatted program does not parse: {err}\n{formatted}"))?;
+    if reformatted != formatted {
+        return Err(format!(
+            "formatting is not idempotent, the formatted program\n{formatted}\nis formatted as\n{reformatted}"
+        ));
+    }
+    if ast_without_positions(source)? != ast_without_positions(&formatted)? {
+        return Err(format!("formatting changed the AST of the program, which is formatted as\n{formatted}"));
+    }
+    Ok(formatted)
+}
+
+/// Returns the AST of the program `source` as JSON, without the spans and node ids, which formatting changes.
+fn ast_without_positions(source: &str) -> Result<serde_json::Value, String> {
+    create_session_if_not_set_then(|s| {
+        let source_file = s.source_map.new_source(source, FileName::Custom("test".into()));
+        let tokens = tokenizer::tokenize(&source_file.src, source_file.start_pos).map_err(|x| x.to_string())?;
+        let program = with_handler(tokens, |p| {
+            p.resolve_imports = false;
+            p.parse_program()
+        })?;
+        Ok(without_positions(&program))
+    })
+}
+
 struct TestRunner;
 
 impl Runner for TestRunner {
@@ -224,6 +267,7 @@ impl Runner for TestRunner {
             "ParseStatement" => Box::new(ParseStatementNamespace),
             "Serialize" => Box::new(SerializeNamespace),
             "Input" => Box::new(InputNamespace),
+            "Format" => Box::new(FormatNamespace),
             "Token" => Box::new(TokenNamespace),
             _ => return None,
         })
@@ -234,3 +278,107 @@ impl Runner for TestRunner {
 pub fn parser_tests() {
     leo_test_framework::run_tests(&TestRunner, "parser");
 }
+
+#[test]
+pub fn formatter_tests() {
+    for (name, source) in leo_test_framework::get_benches() {
+        if let Err(err) = create_session_if_not_set_then(|_| format_and_check(&source)) {
+            panic!("failed to format the compiler test `{name}`: {err}");
+        }
+    }
+}
+
+/// The number of generated programs that the property-based tests check.
+const GENERATED_PROGRAMS: u64 = 200;
+
+#[test]
+pub fn generated_round_trip_test() {
+    for seed in 0..GENERATED_PROGRAMS {
+        create_session_if_not_set_then(|_| {
+            let program = ProgramGenerator::new(seed).program();
+            let printed = crate::print_program(&program);
+            let parsed = ast_without_positions(&printed).unwrap_or_else(|err| {
+                panic!("the program generated from seed {seed} does not parse: {err}\n{printed}")
+            });
+            let generated = without_positions(&program);
+            assert!(generated == parsed, "the program generated from seed {seed} is printed as\n{printed}");
+            assert_eq!(format_and_check(&printed).as_ref(), Ok(&printed), "seed {seed}");
+        })
+    }
+}
+
+#[test]
+pub fn trivia_test() {
+    let source = "// The program.
+program test.aleo {
+    function main(a: u8) -> u8 {
+        let b: u8 = a; // Trailing.
+
+        /* Leading. */
+        return b;
+        // Dangling.
+    }
+}
+// The end.
+";
+    create_session_if_not_set_then(|s| {
+        let source_file = s.source_map.new_source(source, FileName::Custom("trivia".into()));
+        let (handler, _) = Handler::new_with_buf();
+        let (program, trivia) =
+            crate::parse_with_trivia(&handler, &NodeBuilder::default(), &source_file.src, source_file.start_pos)
+                .expect("failed to parse the program");
+        let texts = |comments: &[leo_ast::Comment]| comments.iter().map(|c| c.text.clone()).collect::<Vec<_>>();
+
+        let scope = program.program_scopes.values().next().unwrap();
+        assert_eq!(texts(&trivia.node(scope.program_id.name.id).leading), ["// The program."]);
+        assert_eq!(texts(trivia.end_of_file()), ["// The end."]);
+
+        let block = &scope.functions[0].1.block;
+        let (definition, ret) = (&block.statements[0], &block.statements[1]);
+        assert_eq!(texts(&trivia.node(definition.id()).trailing), ["// Trailing."]);
+        assert_eq!(texts(&trivia.node(ret.id()).leading), ["/* Leading. */"]);
+        assert!(trivia.node(ret.id()).leading[0].blank_line_before);
+        assert!(!trivia.node(ret.id()).blank_line_before);
+        assert_eq!(texts(&trivia.node(block.id).dangling), ["// Dangling."]);
+        assert_eq!(trivia.comments().count(), 5);
+    });
+}
+
+#[test]
+pub fn canonical_json_test() {
+    create_session_if_not_set_then(|s| {
+        let parse = |name: &str, source: &str| {
+            let source_file = s.source_map.new_source(source, FileName::Custom(name.into()));
+            let (handler, _) = Handler::new_with_buf();
+            crate::parse_ast(&handler, &NodeBuilder::default(), &source_file.src, source_file.start_pos)
+                .expect("failed to parse the program")
+        };
+        let first = parse("first", "program test.aleo { transition main(a: u8) -> u8 { return a + 1u8; } }");
+        let second = parse(
+            "second",
+            "program test.aleo {\n    transition main(a: u8) -> u8 {\n        return a + 1u8;\n    }\n}\n",
+        );
+
+        // The programs only differ in their spans.
+        assert_ne!(first.to_canonical_json_string(false).unwrap(), second.to_canonical_json_string(false).unwrap());
+        let canonical = first.to_canonical_json_string(true).unwrap();
+        assert_eq!(canonical, second.to_canonical_json_string(true).unwrap());
+        assert!(!canonical.contains("span"));
+
+        // The keys of every object, including those of identifiers, are sorted.
+        fn assert_sorted(value: &serde_json::Value) {
+            match value {
+                serde_json::Value::Object(map) => {
+                    assert!(map.keys().zip(map.keys().skip(1)).all(|(a, b)| a < b));
+                    map.values().for_each(assert_sorted);
+                }
+                serde_json::Value::Array(values) => values.iter().for_each(assert_sorted),
+                serde_json::Value::String(string) if string.starts_with('{') || string.starts_with('"') => {
+                    assert_sorted(&serde_json::from_str(string).unwrap())
+                }
+                _ => {}
+            }
+        }
+        assert_sorted(&serde_json::from_str(&canonical).unwrap());
+    });
+}
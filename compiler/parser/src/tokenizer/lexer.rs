@@ -24,9 +24,10 @@ use std::{
     iter::{from_fn, Peekable},
 };
 
-/// Eat an identifier, that is, a string matching '[a-zA-Z][a-zA-Z\d_]*', if any.
+/// Eat an identifier, that is, a string matching '_?[a-zA-Z][a-zA-Z\d_]*', if any.
+/// An `_` that is not followed by a letter is lexed as `Token::Underscore` before this is called.
 fn eat_identifier(input: &mut Peekable<impl Iterator<Item = char>>) -> Option<String> {
-    input.peek().filter(|c| c.is_ascii_alphabetic())?;
+    input.peek().filter(|c| c.is_ascii_alphabetic() || c == &&'_')?;
     Some(from_fn(|| input.next_if(|c| c.is_ascii_alphanumeric() || c == &'_')).collect())
 }
 
@@ -156,22 +157,37 @@ impl Token {
 
     /// Returns a tuple: [(integer length, integer token)] if an integer can be eaten, otherwise returns [`None`].
     /// An integer can be eaten if its bytes are at the front of the given `input` string.
-    fn eat_integer(input: &mut Peekable<impl Iterator<Item = char>>) -> Result<(usize, Token)> {
+    /// The integer may be hexadecimal, octal, or binary, e.g. `0xff`, `0o77`, or `0b1010`, and its digits may be
+    /// separated by `_`, e.g. `1_000_000`.
+    fn eat_integer(input: &mut Peekable<impl Iterator<Item = char> + Clone>) -> Result<(usize, Token)> {
         if input.peek().is_none() {
             return Err(ParserError::lexer_empty_input().into());
         }
 
         let mut int = String::new();
 
+        let mut prefix = input.clone();
+        let radix = match (prefix.next(), prefix.next()) {
+            (Some('0'), Some('x')) => 16,
+            (Some('0'), Some('o')) => 8,
+            (Some('0'), Some('b')) => 2,
+            _ => 10,
+        };
+        if radix != 10 {
+            int.extend(input.by_ref().take(2));
+        }
+
         // Note that it is still impossible to have a number that starts with an `_` because eat_integer is only called when the first character is a digit.
-        while let Some(c) = input.next_if(|c| c.is_ascii_digit() || *c == '_') {
-            if c == '0' && matches!(input.peek(), Some('x')) {
-                int.push(c);
-                int.push(input.next().unwrap());
-                return Err(ParserError::lexer_hex_number_provided(int).into());
+        while let Some(c) = input.peek().filter(|c| c.is_digit(radix) || **c == '_') {
+            // The `f` of a `field` suffix is not a hexadecimal digit, e.g. in `0x1field`.
+            if *c == 'f' && input.clone().take(5).eq("field".chars()) {
+                break;
             }
+            int.push(input.next().unwrap());
+        }
 
-            int.push(c);
+        if radix != 10 && !int[2..].chars().any(|c| c.is_digit(radix)) {
+            return Err(ParserError::lexer_radix_number_without_digits(int).into());
         }
 
         Ok((int.len(), Token::Integer(int)))
@@ -284,7 +300,10 @@ impl Token {
             }
             '(' => return match_one(&mut input, Token::LeftParen),
             ')' => return match_one(&mut input, Token::RightParen),
-            '_' => return match_one(&mut input, Token::Underscore),
+            // An underscore followed by a letter starts an identifier, e.g. `_unused`.
+            '_' if !input_str[1..].starts_with(|c: char| c.is_ascii_alphabetic()) => {
+                return match_one(&mut input, Token::Underscore);
+            }
             '*' => {
                 return match_four(
                     &mut input,
@@ -389,6 +408,7 @@ impl Token {
                     "const" => Token::Const,
                     "constant" => Token::Constant,
                     "else" => Token::Else,
+                    "enum" => Token::Enum,
                     "false" => Token::False,
                     "field" => Token::Field,
                     "finalize" => Token::Finalize,
@@ -404,9 +424,12 @@ impl Token {
                     "import" => Token::Import,
                     "in" => Token::In,
                     "inline" => Token::Inline,
+                    "interface" => Token::Interface,
                     "let" => Token::Let,
                     "leo" => Token::Leo,
                     "mapping" => Token::Mapping,
+                    "match" => Token::Match,
+                    "module" => Token::Module,
                     "private" => Token::Private,
                     "program" => Token::Program,
                     "public" => Token::Public,
@@ -117,6 +117,7 @@ pub enum Token {
     Const,
     Constant,
     Else,
+    Enum,
     Finalize,
     For,
     Function,
@@ -124,8 +125,11 @@ pub enum Token {
     Import,
     In,
     Inline,
+    Interface,
     Let,
     Mapping,
+    Match,
+    Module,
     Private,
     Program,
 
@@ -158,6 +162,7 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::Const,
     Token::Constant,
     Token::Else,
+    Token::Enum,
     Token::False,
     Token::Field,
     Token::Finalize,
@@ -173,8 +178,11 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::Import,
     Token::In,
     Token::Inline,
+    Token::Interface,
     Token::Let,
     Token::Mapping,
+    Token::Match,
+    Token::Module,
     Token::Private,
     Token::Program,
     Token::Public,
@@ -215,6 +223,7 @@ impl Token {
             Token::Const => sym::Const,
             Token::Constant => sym::constant,
             Token::Else => sym::Else,
+            Token::Enum => sym::Enum,
             Token::False => sym::False,
             Token::Field => sym::field,
             Token::Finalize => sym::finalize,
@@ -230,9 +239,12 @@ impl Token {
             Token::Import => sym::import,
             Token::In => sym::In,
             Token::Inline => sym::inline,
+            Token::Interface => sym::interface,
             Token::Let => sym::Let,
             Token::Leo => sym::leo,
             Token::Mapping => sym::mapping,
+            Token::Match => sym::Match,
+            Token::Module => sym::module,
             Token::Private => sym::private,
             Token::Program => sym::program,
             Token::Public => sym::public,
@@ -349,6 +361,7 @@ impl fmt::Display for Token {
             Const => write!(f, "const"),
             Constant => write!(f, "constant"),
             Else => write!(f, "else"),
+            Enum => write!(f, "enum"),
             Finalize => write!(f, "finalize"),
             For => write!(f, "for"),
             Function => write!(f, "function"),
@@ -356,8 +369,11 @@ impl fmt::Display for Token {
             Import => write!(f, "import"),
             In => write!(f, "in"),
             Inline => write!(f, "inline"),
+            Interface => write!(f, "interface"),
             Let => write!(f, "let"),
             Mapping => write!(f, "mapping"),
+            Match => write!(f, "match"),
+            Module => write!(f, "module"),
             Private => write!(f, "private"),
             Program => write!(f, "program"),
             Public => write!(f, "public"),
@@ -382,6 +398,8 @@ pub enum Delimiter {
     Brace,
     /// `[ ... ]`
     Bracket,
+    /// `< ... >`
+    AngleBracket,
 }
 
 impl Delimiter {
@@ -391,6 +409,7 @@ impl Delimiter {
             Self::Parenthesis => (Token::LeftParen, Token::RightParen),
             Self::Brace => (Token::LeftCurly, Token::RightCurly),
             Self::Bracket => (Token::LeftSquare, Token::RightSquare),
+            Self::AngleBracket => (Token::Lt, Token::Gt),
         }
     }
 }
@@ -14,10 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CallGraph, StructGraph, SymbolTable, TypeTable};
+use crate::{CallGraph, CoreFunctionRegistry, StructGraph, SymbolTable, TypeTable};
 
 use leo_ast::{Function, Program, ProgramId};
-use leo_span::Symbol;
+use leo_span::{Span, Symbol};
 
 use indexmap::IndexMap;
 
@@ -30,6 +30,8 @@ pub struct CodeGenerator<'a> {
     pub(crate) struct_graph: &'a StructGraph,
     /// The call graph for the program.
     pub(crate) _call_graph: &'a CallGraph,
+    /// The built-in functions registered by the embedder of the compiler, in addition to the core functions.
+    pub(crate) core_functions: &'a CoreFunctionRegistry,
     /// A counter to track the next available register.
     pub(crate) next_register: u64,
     /// Reference to the current function.
@@ -53,6 +55,8 @@ pub struct CodeGenerator<'a> {
     pub(crate) program: &'a Program,
     // The program ID of the current program.
     pub(crate) program_id: Option<ProgramId>,
+    /// The instructions generated for each statement of the program, with the span they were generated from.
+    pub(crate) instruction_spans: Vec<(Span, String)>,
 }
 
 impl<'a> CodeGenerator<'a> {
@@ -62,6 +66,7 @@ impl<'a> CodeGenerator<'a> {
         type_table: &'a TypeTable,
         struct_graph: &'a StructGraph,
         _call_graph: &'a CallGraph,
+        core_functions: &'a CoreFunctionRegistry,
         program: &'a Program,
     ) -> Self {
         // Initialize variable mapping.
@@ -70,6 +75,7 @@ impl<'a> CodeGenerator<'a> {
             type_table,
             struct_graph,
             _call_graph,
+            core_functions,
             next_register: 0,
             current_function: None,
             variable_mapping: IndexMap::new(),
@@ -80,6 +86,7 @@ impl<'a> CodeGenerator<'a> {
             futures: Vec::new(),
             program,
             program_id: None,
+            instruction_spans: Vec::new(),
         }
     }
 }
@@ -17,6 +17,14 @@
 pub mod generator;
 pub use generator::*;
 
+pub mod opcodes;
+pub use opcodes::*;
+
+pub mod source_map;
+pub use source_map::*;
+
+mod visit_decomposition;
+
 mod visit_expressions;
 
 mod visit_program;
@@ -25,19 +33,30 @@ mod visit_statements;
 
 mod visit_type;
 
-use crate::{CallGraph, Pass, StructGraph, SymbolTable, TypeTable};
+use crate::{CallGraph, CoreFunctionRegistry, Pass, StructGraph, SymbolTable, TypeTable};
 
 use leo_ast::{Ast, Program};
 use leo_errors::Result;
 
 impl<'a> Pass for CodeGenerator<'a> {
-    type Input = (&'a Ast, &'a SymbolTable, &'a TypeTable, &'a StructGraph, &'a CallGraph, &'a Program);
-    type Output = Result<String>;
-
-    fn do_pass((ast, symbol_table, type_table, struct_graph, call_graph, program): Self::Input) -> Self::Output {
-        let mut generator = Self::new(symbol_table, type_table, struct_graph, call_graph, program);
+    type Input = (
+        &'a Ast,
+        &'a SymbolTable,
+        &'a TypeTable,
+        &'a StructGraph,
+        &'a CallGraph,
+        &'a CoreFunctionRegistry,
+        &'a Program,
+    );
+    type Output = Result<(String, BytecodeSourceMap)>;
+
+    fn do_pass(
+        (ast, symbol_table, type_table, struct_graph, call_graph, core_functions, program): Self::Input,
+    ) -> Self::Output {
+        let mut generator = Self::new(symbol_table, type_table, struct_graph, call_graph, core_functions, program);
         let bytecode = generator.visit_program(ast.as_repr());
+        let source_map = BytecodeSourceMap::new(&bytecode, &generator.instruction_spans);
 
-        Ok(bytecode)
+        Ok((bytecode, source_map))
     }
 }
@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Aleo instructions emitted for each operator, along with the operand types the target supports for them.
+
+use leo_ast::{BinaryOperation, IntegerType, Type, UnaryOperation};
+
+/// A set of operand types supported by an instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OperandTypes {
+    Any,
+    Bool,
+    BoolInt,
+    Field,
+    FieldGroup,
+    FieldGroupInt,
+    FieldGroupScalarInt,
+    FieldGroupSignedInt,
+    FieldInt,
+    FieldScalarInt,
+    Group,
+    Int,
+    /// The integer types that may be used as an exponent or shift amount, i.e. `u8`, `u16`, and `u32`.
+    Magnitude,
+    FieldMagnitude,
+    SignedInt,
+    UnsignedInt,
+}
+
+impl OperandTypes {
+    /// Returns `true` if `type_` belongs to the set.
+    pub fn contains(self, type_: &Type) -> bool {
+        let (is_bool, is_field, is_group, is_scalar) = (
+            matches!(type_, Type::Boolean),
+            matches!(type_, Type::Field),
+            matches!(type_, Type::Group),
+            matches!(type_, Type::Scalar),
+        );
+        let (is_int, is_signed_int) = match type_ {
+            Type::Integer(integer_type) => (true, integer_type.is_signed()),
+            _ => (false, false),
+        };
+        let is_magnitude = matches!(type_, Type::Integer(IntegerType::U8 | IntegerType::U16 | IntegerType::U32));
+        match self {
+            Self::Any => true,
+            Self::Bool => is_bool,
+            Self::BoolInt => is_bool || is_int,
+            Self::Field => is_field,
+            Self::FieldGroup => is_field || is_group,
+            Self::FieldGroupInt => is_field || is_group || is_int,
+            Self::FieldGroupScalarInt => is_field || is_group || is_scalar || is_int,
+            Self::FieldGroupSignedInt => is_field || is_group || is_signed_int,
+            Self::FieldInt => is_field || is_int,
+            Self::FieldScalarInt => is_field || is_scalar || is_int,
+            Self::Group => is_group,
+            Self::Int => is_int,
+            Self::Magnitude => is_magnitude,
+            Self::FieldMagnitude => is_field || is_magnitude,
+            Self::SignedInt => is_signed_int,
+            Self::UnsignedInt => is_int && !is_signed_int,
+        }
+    }
+}
+
+/// Returns the opcode implementing `op`, along with the types its first and second operands may have.
+pub fn binary_opcode(op: BinaryOperation) -> (&'static str, OperandTypes, OperandTypes) {
+    match op {
+        BinaryOperation::Add => ("add", OperandTypes::FieldGroupScalarInt, OperandTypes::FieldGroupScalarInt),
+        BinaryOperation::AddWrapped => ("add.w", OperandTypes::Int, OperandTypes::Int),
+        BinaryOperation::And => ("and", OperandTypes::Bool, OperandTypes::Bool),
+        BinaryOperation::BitwiseAnd => ("and", OperandTypes::BoolInt, OperandTypes::BoolInt),
+        BinaryOperation::Div => ("div", OperandTypes::FieldInt, OperandTypes::FieldInt),
+        BinaryOperation::DivWrapped => ("div.w", OperandTypes::Int, OperandTypes::Int),
+        BinaryOperation::Eq => ("is.eq", OperandTypes::Any, OperandTypes::Any),
+        BinaryOperation::Gte => ("gte", OperandTypes::FieldScalarInt, OperandTypes::FieldScalarInt),
+        BinaryOperation::Gt => ("gt", OperandTypes::FieldScalarInt, OperandTypes::FieldScalarInt),
+        BinaryOperation::Lte => ("lte", OperandTypes::FieldScalarInt, OperandTypes::FieldScalarInt),
+        BinaryOperation::Lt => ("lt", OperandTypes::FieldScalarInt, OperandTypes::FieldScalarInt),
+        BinaryOperation::Mod => ("mod", OperandTypes::UnsignedInt, OperandTypes::UnsignedInt),
+        // Note that `scalar * group` is supported as well as `group * scalar`.
+        BinaryOperation::Mul => ("mul", OperandTypes::FieldGroupScalarInt, OperandTypes::FieldGroupScalarInt),
+        BinaryOperation::MulWrapped => ("mul.w", OperandTypes::Int, OperandTypes::Int),
+        BinaryOperation::Nand => ("nand", OperandTypes::Bool, OperandTypes::Bool),
+        BinaryOperation::Neq => ("is.neq", OperandTypes::Any, OperandTypes::Any),
+        BinaryOperation::Nor => ("nor", OperandTypes::Bool, OperandTypes::Bool),
+        BinaryOperation::Or => ("or", OperandTypes::Bool, OperandTypes::Bool),
+        BinaryOperation::BitwiseOr => ("or", OperandTypes::BoolInt, OperandTypes::BoolInt),
+        BinaryOperation::Pow => ("pow", OperandTypes::FieldInt, OperandTypes::FieldMagnitude),
+        BinaryOperation::PowWrapped => ("pow.w", OperandTypes::Int, OperandTypes::Magnitude),
+        BinaryOperation::Rem => ("rem", OperandTypes::Int, OperandTypes::Int),
+        BinaryOperation::RemWrapped => ("rem.w", OperandTypes::Int, OperandTypes::Int),
+        BinaryOperation::Shl => ("shl", OperandTypes::Int, OperandTypes::Magnitude),
+        BinaryOperation::ShlWrapped => ("shl.w", OperandTypes::Int, OperandTypes::Magnitude),
+        BinaryOperation::Shr => ("shr", OperandTypes::Int, OperandTypes::Magnitude),
+        BinaryOperation::ShrWrapped => ("shr.w", OperandTypes::Int, OperandTypes::Magnitude),
+        BinaryOperation::Sub => ("sub", OperandTypes::FieldGroupInt, OperandTypes::FieldGroupInt),
+        BinaryOperation::SubWrapped => ("sub.w", OperandTypes::Int, OperandTypes::Int),
+        BinaryOperation::Xor => ("xor", OperandTypes::BoolInt, OperandTypes::BoolInt),
+    }
+}
+
+/// Returns the opcode and suffix implementing `op`, along with the types its operand may have.
+/// Note that non-empty suffixes must be preceded by a space.
+pub fn unary_opcode(op: UnaryOperation) -> (&'static str, &'static str, OperandTypes) {
+    match op {
+        UnaryOperation::Abs => ("abs", "", OperandTypes::SignedInt),
+        UnaryOperation::AbsWrapped => ("abs.w", "", OperandTypes::SignedInt),
+        UnaryOperation::Double => ("double", "", OperandTypes::FieldGroup),
+        UnaryOperation::Inverse => ("inv", "", OperandTypes::Field),
+        UnaryOperation::Not => ("not", "", OperandTypes::BoolInt),
+        UnaryOperation::Negate => ("neg", "", OperandTypes::FieldGroupSignedInt),
+        UnaryOperation::Square => ("square", "", OperandTypes::Field),
+        UnaryOperation::SquareRoot => ("sqrt", "", OperandTypes::Field),
+        UnaryOperation::ToXCoordinate => ("cast", " as group.x", OperandTypes::Group),
+        UnaryOperation::ToYCoordinate => ("cast", " as group.y", OperandTypes::Group),
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Location;
+
+use leo_errors::{AstError, Result};
+use leo_span::Span;
+
+use serde::{Deserialize, Serialize};
+
+/// An instruction of the generated bytecode, and the location of the Leo code it was generated from.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceMapEntry {
+    /// The line of the instruction in the bytecode, starting from 1.
+    pub line: usize,
+    /// The instruction, without its indentation.
+    pub instruction: String,
+    /// The location of the statement or expression that the instruction was generated from.
+    pub location: Location,
+}
+
+/// A map from the instructions of the generated bytecode back to the Leo source,
+/// so that an error that the runtime or the prover reports for an instruction can be traced to a line of the program.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BytecodeSourceMap {
+    /// The entries, in the order of their lines.
+    pub entries: Vec<SourceMapEntry>,
+}
+
+impl BytecodeSourceMap {
+    /// Returns the source map of `bytecode`, given the instructions generated for each span, in the order they were
+    /// generated. Each instruction is matched to the first line with the same text after the previous instruction.
+    /// Instructions whose spans are dummies, or are not in the current session's source map, are left out.
+    pub fn new(bytecode: &str, instructions: &[(Span, String)]) -> Self {
+        let mut lines = bytecode.lines().enumerate();
+        let mut entries = Vec::new();
+        for (span, instruction) in instructions {
+            let instruction = instruction.trim();
+            let Some((index, _)) = lines.find(|(_, line)| line.trim() == instruction) else {
+                break;
+            };
+            let location = if span.is_dummy() { None } else { Location::from_span(*span) };
+            if let Some(location) = location {
+                entries.push(SourceMapEntry { line: index + 1, instruction: instruction.to_string(), location });
+            }
+        }
+        Self { entries }
+    }
+
+    /// Returns the location of the Leo code that the instruction on `line` was generated from, if it is known.
+    pub fn location_of(&self, line: usize) -> Option<Location> {
+        let index = self.entries.binary_search_by_key(&line, |entry| entry.line).ok()?;
+        Some(self.entries[index].location)
+    }
+
+    /// Serializes the source map into a JSON file.
+    pub fn to_json_file(&self, mut path: std::path::PathBuf, file_name: &str) -> Result<()> {
+        path.push(file_name);
+        let file =
+            std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_source_map_json_file(&path, &e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(serde_json::to_writer_pretty(writer, &self)
+            .map_err(|e| AstError::failed_to_write_source_map_to_json_file(&path, &e))?)
+    }
+
+    /// Deserializes the JSON string into a source map.
+    pub fn from_json_string(json: &str) -> Result<Self> {
+        let source_map: BytecodeSourceMap =
+            serde_json::from_str(json).map_err(|e| AstError::failed_to_read_json_string_to_source_map(&e))?;
+        Ok(source_map)
+    }
+
+    /// Deserializes the JSON string into a source map from a file.
+    pub fn from_json_file(path: std::path::PathBuf) -> Result<Self> {
+        let data = std::fs::read_to_string(&path).map_err(|e| AstError::failed_to_read_json_file(&path, &e))?;
+        Self::from_json_string(&data)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::source_file;
+
+    use leo_span::{span::BytePos, symbol::create_session_if_not_set_then};
+
+    #[test]
+    fn test_source_map() {
+        create_session_if_not_set_then(|_| {
+            let file = source_file("let c: u32 = a + b;\nreturn c * c;\n");
+            let span = |lo: u32, hi: u32| Span::new(file.start_pos + BytePos(lo), file.start_pos + BytePos(hi));
+
+            let bytecode = [
+                "program test.aleo;",
+                "",
+                "function main:",
+                "    input r0 as u32.private;",
+                "    input r1 as u32.private;",
+                "    add r0 r1 into r2;",
+                "    mul r2 r2 into r3;",
+                "    output r3 as u32.private;",
+            ]
+            .join("\n");
+            let instructions = vec![
+                (span(13, 18), "    add r0 r1 into r2;\n".to_string()),
+                (Span::dummy(), "    mul r2 r2 into r3;\n".to_string()),
+                (span(20, 33), "    output r3 as u32.private;\n".to_string()),
+            ];
+            let source_map = BytecodeSourceMap::new(&bytecode, &instructions);
+
+            // The instruction without a span is not in the map.
+            assert_eq!(source_map.entries.len(), 2);
+            assert_eq!(source_map.entries[0].instruction, "add r0 r1 into r2;");
+            let location = Location { line_start: 1, line_stop: 1, col_start: 14, col_stop: 19 };
+            assert_eq!(source_map.location_of(6), Some(location));
+            assert_eq!(source_map.location_of(7), None);
+            assert_eq!(source_map.location_of(8).unwrap().line_start, 2);
+
+            let json = serde_json::to_string(&source_map).unwrap();
+            assert_eq!(BytecodeSourceMap::from_json_string(&json).unwrap(), source_map);
+        })
+    }
+}
@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::CodeGenerator;
+
+use leo_ast::{IntegerType, Type};
+use leo_span::{sym, Symbol};
+
+use num_bigint::BigUint;
+use std::fmt::Write as _;
+
+/// The number of bits of the canonical representation of a field element.
+const FIELD_BITS: usize = 253;
+
+/// A type whose values can be decomposed into their little-endian bits.
+#[derive(Clone, Copy)]
+enum Decomposable {
+    Integer(IntegerType),
+    Field,
+    /// A group element is decomposed into the bits of its x-coordinate.
+    Group,
+}
+
+impl Decomposable {
+    fn from_symbol(type_: Symbol) -> Self {
+        use IntegerType::*;
+        match type_ {
+            sym::field => Self::Field,
+            sym::group => Self::Group,
+            _ => match [I8, I16, I32, I64, I128, U8, U16, U32, U64, U128].into_iter().find(|t| t.symbol() == type_) {
+                Some(integer_type) => Self::Integer(integer_type),
+                None => unreachable!("Type checking guarantees that only integers, fields, and groups are decomposed"),
+            },
+        }
+    }
+
+    /// Returns the number of bytes of the decomposition, which is padded to a whole number of bytes.
+    fn bytes(self) -> usize {
+        match self {
+            Self::Integer(integer_type) => integer_type.bit_width() as usize / 8,
+            Self::Field | Self::Group => (FIELD_BITS + 7) / 8,
+        }
+    }
+}
+
+impl<'a> CodeGenerator<'a> {
+    /// Generates the instructions of a decomposition of a value into its little-endian bits or bytes, or of its
+    /// recomposition from them, e.g. `u16::to_bits_le(x)` or `field::from_bytes_le(b)`.
+    /// The AVM has no instruction for either, so the value is decomposed one bit at a time, with shifts for integers,
+    /// and by subtracting the powers of two that fit for fields.
+    pub(crate) fn visit_decomposition(&mut self, type_: Symbol, function: Symbol, argument: &str) -> (String, String) {
+        let type_ = Decomposable::from_symbol(type_);
+        let bytes = type_.bytes();
+        let mut instructions = String::new();
+        let destination = match function {
+            sym::to_bits_le => {
+                let bits = self.decompose(type_, argument, &mut instructions);
+                let mut grouped = Vec::with_capacity(bytes);
+                for byte in bits.chunks(8) {
+                    grouped.push(self.cast_into(byte, "[boolean; 8u32]", &mut instructions));
+                }
+                self.cast_into(&grouped, &format!("[[boolean; 8u32]; {bytes}u32]"), &mut instructions)
+            }
+            sym::to_bytes_le => {
+                let bits = self.decompose(type_, argument, &mut instructions);
+                let mut recomposed = Vec::with_capacity(bytes);
+                for byte in bits.chunks(8) {
+                    recomposed.push(self.recompose_integer(IntegerType::U8, byte, &mut instructions));
+                }
+                self.cast_into(&recomposed, &format!("[u8; {bytes}u32]"), &mut instructions)
+            }
+            sym::from_bits_le => {
+                let bits =
+                    (0..8 * bytes).map(|i| format!("{argument}[{}u32][{}u32]", i / 8, i % 8)).collect::<Vec<_>>();
+                self.recompose(type_, &bits, &mut instructions)
+            }
+            sym::from_bytes_le => {
+                let mut bits = Vec::with_capacity(8 * bytes);
+                for i in 0..bytes {
+                    let byte = format!("{argument}[{i}u32]");
+                    bits.extend(self.decompose_integer(IntegerType::U8, &byte, &mut instructions));
+                }
+                self.recompose(type_, &bits, &mut instructions)
+            }
+            _ => unreachable!("The only decompositions are `to_bits_le`, `to_bytes_le`, and their inverses"),
+        };
+        (destination, instructions)
+    }
+
+    /// Generates the instructions of a wrapping cast of `value`, a field or an integer of type `source`, into the
+    /// integer type `target`, e.g. `a as.wrapped u8` or `u8::from_field_wrapped(f)`.
+    /// The value is decomposed into its bits, which are truncated, or sign extended if `source` is a signed integer,
+    /// to the width of `target`, and recomposed.
+    pub(crate) fn visit_wrapping_cast(&mut self, source: &Type, target: Symbol, value: &str) -> (String, String) {
+        let target = match Decomposable::from_symbol(target) {
+            Decomposable::Integer(integer_type) => integer_type,
+            _ => unreachable!("Type checking guarantees that a wrapping cast is into an integer type"),
+        };
+        let mut instructions = String::new();
+        let mut bits = match source {
+            Type::Integer(integer_type) => self.decompose_integer(*integer_type, value, &mut instructions),
+            Type::Field => self.decompose_field(value, &mut instructions),
+            _ => unreachable!("Type checking guarantees that only fields and integers are cast with wrapping"),
+        };
+        let extension = match source {
+            Type::Integer(integer_type) if integer_type.is_signed() => bits.last().cloned(),
+            _ => None,
+        };
+        bits.resize(target.bit_width() as usize, extension.unwrap_or_else(|| "false".to_string()));
+        let destination = self.recompose_integer(target, &bits, &mut instructions);
+        (destination, instructions)
+    }
+
+    /// Returns a fresh destination register.
+    fn next_destination(&mut self) -> String {
+        let destination = format!("r{}", self.next_register);
+        self.next_register += 1;
+        destination
+    }
+
+    /// Writes a cast of the operands into a value of the given type, and returns its register.
+    fn cast_into(&mut self, operands: &[String], type_: &str, instructions: &mut String) -> String {
+        let destination = self.next_destination();
+        writeln!(instructions, "    cast {} into {destination} as {type_};", operands.join(" "))
+            .expect("failed to write to string");
+        destination
+    }
+
+    /// Writes the decomposition of `value` into its bits, padded to whole bytes, and returns their operands.
+    fn decompose(&mut self, type_: Decomposable, value: &str, instructions: &mut String) -> Vec<String> {
+        let mut bits = match type_ {
+            Decomposable::Integer(integer_type) => return self.decompose_integer(integer_type, value, instructions),
+            Decomposable::Field => self.decompose_field(value, instructions),
+            Decomposable::Group => {
+                let x_coordinate = self.next_destination();
+                writeln!(instructions, "    cast {value} into {x_coordinate} as group.x;")
+                    .expect("failed to write to string");
+                self.decompose_field(&x_coordinate, instructions)
+            }
+        };
+        bits.resize(8 * type_.bytes(), "false".to_string());
+        bits
+    }
+
+    /// Writes the decomposition of an integer into its bits, where bit `j` is `((value >> j) & 1) == 1`.
+    /// Since the shift of a signed integer is arithmetic, the most significant bit of a negative integer is set.
+    fn decompose_integer(&mut self, type_: IntegerType, value: &str, instructions: &mut String) -> Vec<String> {
+        let mut bits = Vec::with_capacity(type_.bit_width() as usize);
+        for j in 0..type_.bit_width() {
+            let shifted = match j {
+                0 => value.to_string(),
+                _ => {
+                    let shifted = self.next_destination();
+                    writeln!(instructions, "    shr {value} {j}u8 into {shifted};").expect("failed to write to string");
+                    shifted
+                }
+            };
+            let (masked, bit) = (self.next_destination(), self.next_destination());
+            writeln!(instructions, "    and {shifted} 1{type_} into {masked};").expect("failed to write to string");
+            writeln!(instructions, "    is.eq {masked} 1{type_} into {bit};").expect("failed to write to string");
+            bits.push(bit);
+        }
+        bits
+    }
+
+    /// Writes the decomposition of a field into the bits of its canonical representation.
+    /// From the most significant bit down, a bit is set if the remainder is at least its power of two,
+    /// in which case the power is subtracted from the remainder.
+    fn decompose_field(&mut self, value: &str, instructions: &mut String) -> Vec<String> {
+        let mut bits = vec![String::new(); FIELD_BITS];
+        let mut remainder = value.to_string();
+        for j in (0..FIELD_BITS).rev() {
+            let power = BigUint::from(1u8) << j;
+            let bit = self.next_destination();
+            writeln!(instructions, "    gte {remainder} {power}field into {bit};").expect("failed to write to string");
+            if j > 0 {
+                let (difference, next) = (self.next_destination(), self.next_destination());
+                writeln!(instructions, "    sub {remainder} {power}field into {difference};")
+                    .expect("failed to write to string");
+                writeln!(instructions, "    ternary {bit} {difference} {remainder} into {next};")
+                    .expect("failed to write to string");
+                remainder = next;
+            }
+            bits[j] = bit;
+        }
+        bits
+    }
+
+    /// Writes the recomposition of a value from its bits, and returns its register.
+    fn recompose(&mut self, type_: Decomposable, bits: &[String], instructions: &mut String) -> String {
+        match type_ {
+            Decomposable::Integer(integer_type) => {
+                self.recompose_integer(integer_type, &bits[..integer_type.bit_width() as usize], instructions)
+            }
+            Decomposable::Field => self.recompose_field(bits, instructions),
+            // Recovering a group element from its x-coordinate halts if there is no such element.
+            Decomposable::Group => {
+                let x_coordinate = self.recompose_field(bits, instructions);
+                self.cast_into(&[x_coordinate], "group", instructions)
+            }
+        }
+    }
+
+    /// Writes the sum of the powers of two of the set bits, and returns its register.
+    /// The most significant bit of a signed integer stands for its minimum value, as in two's complement,
+    /// and is added last, so that the sum never overflows.
+    fn recompose_integer(&mut self, type_: IntegerType, bits: &[String], instructions: &mut String) -> String {
+        let width = type_.bit_width() as usize;
+        let terms = bits.iter().enumerate().map(|(j, bit)| {
+            let power = BigUint::from(1u8) << j;
+            let power = match type_.is_signed() && j == width - 1 {
+                true => format!("-{power}{type_}"),
+                false => format!("{power}{type_}"),
+            };
+            (bit, power)
+        });
+        self.sum_of_terms(terms.collect(), &format!("0{type_}"), instructions)
+    }
+
+    /// Writes the sum of the powers of two of the set bits, modulo the field modulus, and returns its register.
+    fn recompose_field(&mut self, bits: &[String], instructions: &mut String) -> String {
+        let terms = bits.iter().enumerate().map(|(j, bit)| (bit, format!("{}field", BigUint::from(1u8) << j)));
+        self.sum_of_terms(terms.collect(), "0field", instructions)
+    }
+
+    /// Writes the sum of the terms, each of which is its power if its bit is set, and `zero` otherwise.
+    fn sum_of_terms(&mut self, terms: Vec<(&String, String)>, zero: &str, instructions: &mut String) -> String {
+        let mut sum: Option<String> = None;
+        for (bit, power) in terms {
+            let term = self.next_destination();
+            writeln!(instructions, "    ternary {bit} {power} {zero} into {term};").expect("failed to write to string");
+            sum = Some(match sum {
+                None => term,
+                Some(sum) => {
+                    let next = self.next_destination();
+                    writeln!(instructions, "    add {sum} {term} into {next};").expect("failed to write to string");
+                    next
+                }
+            });
+        }
+        sum.expect("a value has at least one bit")
+    }
+}
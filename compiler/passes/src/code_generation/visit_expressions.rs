@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::CodeGenerator;
+use crate::{binary_opcode, unary_opcode, CodeGenerator};
 use leo_ast::{
     AccessExpression,
     ArrayAccess,
@@ -22,7 +22,7 @@ use leo_ast::{
     AssociatedConstant,
     AssociatedFunction,
     BinaryExpression,
-    BinaryOperation,
+    BlockExpression,
     CallExpression,
     CastExpression,
     ErrExpression,
@@ -30,12 +30,12 @@ use leo_ast::{
     Identifier,
     Literal,
     MemberAccess,
+    Node,
     StructExpression,
     TernaryExpression,
     TupleExpression,
     Type,
     UnaryExpression,
-    UnaryOperation,
     UnitExpression,
 };
 use leo_span::sym;
@@ -53,6 +53,7 @@ impl<'a> CodeGenerator<'a> {
             Expression::Access(expr) => self.visit_access(expr),
             Expression::Array(expr) => self.visit_array(expr),
             Expression::Binary(expr) => self.visit_binary(expr),
+            Expression::Block(expr) => self.visit_block_expression(expr),
             Expression::Call(expr) => self.visit_call(expr),
             Expression::Cast(expr) => self.visit_cast(expr),
             Expression::Struct(expr) => self.visit_struct_init(expr),
@@ -73,50 +74,29 @@ impl<'a> CodeGenerator<'a> {
         )
     }
 
+    fn visit_block_expression(&mut self, _input: &'a BlockExpression) -> (String, String) {
+        unreachable!("`BlockExpression`s should not be in the AST at this phase of compilation.")
+    }
+
     fn visit_err(&mut self, _input: &'a ErrExpression) -> (String, String) {
         unreachable!("`ErrExpression`s should not be in the AST at this phase of compilation.")
     }
 
     fn visit_value(&mut self, input: &'a Literal) -> (String, String) {
-        (format!("{input}"), String::new())
+        match input {
+            // Aleo instructions only have decimal literals, so `0xffu8` is written as `255u8`.
+            Literal::Integer(type_, value, ..) => {
+                (format!("{}{type_}", Literal::integer_to_decimal(value)), String::new())
+            }
+            _ => (format!("{input}"), String::new()),
+        }
     }
 
     fn visit_binary(&mut self, input: &'a BinaryExpression) -> (String, String) {
         let (left_operand, left_instructions) = self.visit_expression(&input.left);
         let (right_operand, right_instructions) = self.visit_expression(&input.right);
 
-        let opcode = match input.op {
-            BinaryOperation::Add => String::from("add"),
-            BinaryOperation::AddWrapped => String::from("add.w"),
-            BinaryOperation::And => String::from("and"),
-            BinaryOperation::BitwiseAnd => String::from("and"),
-            BinaryOperation::Div => String::from("div"),
-            BinaryOperation::DivWrapped => String::from("div.w"),
-            BinaryOperation::Eq => String::from("is.eq"),
-            BinaryOperation::Gte => String::from("gte"),
-            BinaryOperation::Gt => String::from("gt"),
-            BinaryOperation::Lte => String::from("lte"),
-            BinaryOperation::Lt => String::from("lt"),
-            BinaryOperation::Mod => String::from("mod"),
-            BinaryOperation::Mul => String::from("mul"),
-            BinaryOperation::MulWrapped => String::from("mul.w"),
-            BinaryOperation::Nand => String::from("nand"),
-            BinaryOperation::Neq => String::from("is.neq"),
-            BinaryOperation::Nor => String::from("nor"),
-            BinaryOperation::Or => String::from("or"),
-            BinaryOperation::BitwiseOr => String::from("or"),
-            BinaryOperation::Pow => String::from("pow"),
-            BinaryOperation::PowWrapped => String::from("pow.w"),
-            BinaryOperation::Rem => String::from("rem"),
-            BinaryOperation::RemWrapped => String::from("rem.w"),
-            BinaryOperation::Shl => String::from("shl"),
-            BinaryOperation::ShlWrapped => String::from("shl.w"),
-            BinaryOperation::Shr => String::from("shr"),
-            BinaryOperation::ShrWrapped => String::from("shr.w"),
-            BinaryOperation::Sub => String::from("sub"),
-            BinaryOperation::SubWrapped => String::from("sub.w"),
-            BinaryOperation::Xor => String::from("xor"),
-        };
+        let (opcode, ..) = binary_opcode(input.op);
 
         let destination_register = format!("r{}", self.next_register);
         let binary_instruction = format!("    {opcode} {left_operand} {right_operand} into {destination_register};\n",);
@@ -135,13 +115,23 @@ impl<'a> CodeGenerator<'a> {
     fn visit_cast(&mut self, input: &'a CastExpression) -> (String, String) {
         let (expression_operand, mut instructions) = self.visit_expression(&input.expression);
 
+        // A wrapped cast truncates the value instead of halting.
+        if input.wrapped {
+            let (Some(source), Type::Integer(target)) = (self.type_table.get(&input.expression.id()), &input.type_) else {
+                unreachable!("Type checking guarantees that a wrapping cast is from a field or integer into an integer")
+            };
+            let (destination, cast_instructions) =
+                self.visit_wrapping_cast(&source, target.symbol(), &expression_operand);
+            instructions.push_str(&cast_instructions);
+            return (destination, instructions);
+        }
+
         // Construct the destination register.
         let destination_register = format!("r{}", self.next_register);
         // Increment the register counter.
         self.next_register += 1;
 
-        let cast_instruction =
-            format!("    cast {expression_operand} into {destination_register} as {};\n", input.type_);
+        let cast_instruction = format!("    cast {expression_operand} into {destination_register} as {};\n", input.type_);
 
         // Concatenate the instructions.
         instructions.push_str(&cast_instruction);
@@ -154,7 +144,7 @@ impl<'a> CodeGenerator<'a> {
             input.elements.iter().map(|expr| self.visit_expression(expr)).fold(
                 (String::new(), String::new()),
                 |(mut operands, mut instructions), (operand, operand_instructions)| {
-                    operands.push_str(&operand);
+                    write!(operands, " {operand}").expect("failed to write to string");
                     instructions.push_str(&operand_instructions);
                     (operands, instructions)
                 },
@@ -173,7 +163,7 @@ impl<'a> CodeGenerator<'a> {
         let array_type: String = Self::visit_type(&array_type);
 
         let array_instruction =
-            format!("    cast {expression_operands} into {destination_register} as {};\n", array_type);
+            format!("    cast{expression_operands} into {destination_register} as {};\n", array_type);
 
         // Concatenate the instructions.
         instructions.push_str(&array_instruction);
@@ -184,19 +174,7 @@ impl<'a> CodeGenerator<'a> {
     fn visit_unary(&mut self, input: &'a UnaryExpression) -> (String, String) {
         let (expression_operand, expression_instructions) = self.visit_expression(&input.receiver);
 
-        // Note that non-empty suffixes must be preceded by a space.
-        let (opcode, suffix) = match input.op {
-            UnaryOperation::Abs => ("abs", ""),
-            UnaryOperation::AbsWrapped => ("abs.w", ""),
-            UnaryOperation::Double => ("double", ""),
-            UnaryOperation::Inverse => ("inv", ""),
-            UnaryOperation::Not => ("not", ""),
-            UnaryOperation::Negate => ("neg", ""),
-            UnaryOperation::Square => ("square", ""),
-            UnaryOperation::SquareRoot => ("sqrt", ""),
-            UnaryOperation::ToXCoordinate => ("cast", " as group.x"),
-            UnaryOperation::ToYCoordinate => ("cast", " as group.y"),
-        };
+        let (opcode, suffix, _) = unary_opcode(input.op);
 
         let destination_register = format!("r{}", self.next_register);
         let unary_instruction = format!("    {opcode} {expression_operand} into {destination_register}{suffix};\n");
@@ -287,7 +265,9 @@ impl<'a> CodeGenerator<'a> {
     fn visit_array_access(&mut self, input: &'a ArrayAccess) -> (String, String) {
         let (array_operand, _) = self.visit_expression(&input.array);
         let index_operand = match input.index.as_ref() {
-            Expression::Literal(Literal::Integer(_, string, _, _)) => format!("{}u32", string),
+            Expression::Literal(Literal::Integer(_, string, _, _)) => {
+                format!("{}u32", Literal::integer_to_decimal(string))
+            }
             _ => unreachable!("Array indices must be integer literals"),
         };
         let array_access = format!("{}[{}]", array_operand, index_operand);
@@ -296,6 +276,16 @@ impl<'a> CodeGenerator<'a> {
     }
 
     fn visit_member_access(&mut self, input: &'a MemberAccess) -> (String, String) {
+        // The coordinates of a group element are extracted with a cast e.g. `g.x` -> `cast g into r0 as group.x;`
+        if let Some(Type::Group) = self.type_table.get(&input.inner.id()) {
+            let (inner_group, mut instructions) = self.visit_expression(&input.inner);
+            let destination_register = format!("r{}", self.next_register);
+            self.next_register += 1;
+            writeln!(instructions, "    cast {inner_group} into {destination_register} as group.{};", input.name)
+                .expect("failed to write to string");
+            return (destination_register, instructions);
+        }
+
         let (inner_struct, _) = self.visit_expression(&input.inner);
         let member_access = format!("{inner_struct}.{}", input.name);
 
@@ -322,6 +312,21 @@ impl<'a> CodeGenerator<'a> {
             })
             .collect::<Vec<_>>();
 
+        // A call to a built-in function registered by the embedder of the compiler, e.g. `oracle.price r0 into r1;`.
+        if let Type::Identifier(module) = &input.ty {
+            if let Some(signature) = self.core_functions.get(module.name, input.name.name) {
+                let destination_register = format!("r{}", self.next_register);
+                self.next_register += 1;
+                let mut instruction = format!("    {}", signature.opcode);
+                for argument in arguments {
+                    write!(instruction, " {argument}").expect("failed to write to string");
+                }
+                writeln!(instruction, " into {destination_register};").expect("failed to write to string");
+                instructions.push_str(&instruction);
+                return (destination_register, instructions);
+            }
+        }
+
         // Helper function to get a destination register for a function call.
         let mut get_destination_register = || {
             let destination_register = format!("r{}", self.next_register);
@@ -336,7 +341,10 @@ impl<'a> CodeGenerator<'a> {
             let function_name = function.name.to_string();
             let mut names = function_name.split("_to_");
             let opcode = names.next().expect("failed to get opcode");
-            let return_type = names.next().expect("failed to get type");
+            // A variadic hash consumes its inputs as a single array operand e.g. hash_many_to_field -> hash
+            let opcode = opcode.trim_end_matches("_many");
+            // A commitment without an output type e.g. commit is a commitment to a field.
+            let return_type = names.next().unwrap_or("field");
 
             let mut instruction = format!("    {opcode}.{variant}");
             for argument in arguments {
@@ -349,6 +357,16 @@ impl<'a> CodeGenerator<'a> {
 
         // Construct the instruction.
         let (destination, instruction) = match &input.ty {
+            // A decomposition into bits or bytes, or a recomposition from them, e.g. `u64::to_bits_le(x)`.
+            Type::Identifier(Identifier { name: type_, .. })
+                if matches!(input.name.name, sym::to_bits_le | sym::to_bytes_le | sym::from_bits_le | sym::from_bytes_le) =>
+            {
+                self.visit_decomposition(*type_, input.name.name, &arguments[0])
+            }
+            // A truncating conversion from a field to an integer, e.g. `u64::from_field_wrapped(f)`.
+            Type::Identifier(Identifier { name: type_, .. }) if input.name.name == sym::from_field_wrapped => {
+                self.visit_wrapping_cast(&Type::Field, *type_, &arguments[0])
+            }
             Type::Identifier(Identifier { name: sym::BHP256, .. }) => {
                 construct_simple_function_call(&input.name, "bhp256", arguments)
             }
@@ -498,6 +516,58 @@ impl<'a> CodeGenerator<'a> {
                 .expect("failed to write to string");
                 (destination_register, instruction)
             }
+            // A conversion to or from an address, e.g. `address::from_group(g)`, is a cast.
+            // An address is the x-coordinate of a group element, so converting a field to an address halts if the
+            // field is not the x-coordinate of an element of the group.
+            Type::Identifier(Identifier { name: sym::address, .. }) => {
+                let type_ = match input.name.name {
+                    sym::from_field | sym::from_group => "address",
+                    sym::to_field => "field",
+                    sym::to_group => "group",
+                    _ => unreachable!("The only associated functions of address are `from_*` and `to_*`"),
+                };
+                let mut instruction = "    cast".to_string();
+                let destination_register = get_destination_register();
+                // Write the argument and the destination register.
+                writeln!(instruction, " {} into {destination_register} as {type_};", arguments[0])
+                    .expect("failed to write to string");
+                (destination_register, instruction)
+            }
+            // An ElGamal encryption of `message` under `public_key` is the pair
+            // `(randomness * group::GEN, message + randomness * public_key)`.
+            Type::Identifier(Identifier { name: sym::ElGamal, .. }) => {
+                let (message, public_key, randomness) = (&arguments[0], &arguments[1], &arguments[2]);
+                let (commitment, shared_secret, ciphertext) =
+                    (get_destination_register(), get_destination_register(), get_destination_register());
+                let mut instruction = String::new();
+                writeln!(instruction, "    mul group::GEN {randomness} into {commitment};")
+                    .expect("failed to write to string");
+                writeln!(instruction, "    mul {public_key} {randomness} into {shared_secret};")
+                    .expect("failed to write to string");
+                writeln!(instruction, "    add {message} {shared_secret} into {ciphertext};")
+                    .expect("failed to write to string");
+                (format!("{commitment} {ciphertext}"), instruction)
+            }
+            // A conversion from an integer to a field, e.g. `field::from_u64(x)`, is a cast, which cannot fail.
+            Type::Identifier(Identifier { name: sym::field, .. }) => {
+                let mut instruction = "    cast".to_string();
+                let destination_register = get_destination_register();
+                // Write the argument and the destination register.
+                writeln!(instruction, " {} into {destination_register} as field;", arguments[0])
+                    .expect("failed to write to string");
+                (destination_register, instruction)
+            }
+            // A conversion from a field to an integer, e.g. `u64::try_from_field(f)`, is a cast,
+            // which halts if the field does not fit in the integer type.
+            Type::Identifier(Identifier { name: type_, .. }) if input.name.name == sym::try_from_field =>
+            {
+                let mut instruction = "    cast".to_string();
+                let destination_register = get_destination_register();
+                // Write the argument and the destination register.
+                writeln!(instruction, " {} into {destination_register} as {type_};", arguments[0])
+                    .expect("failed to write to string");
+                (destination_register, instruction)
+            }
             _ => unreachable!("All core functions should be known at this phase of compilation"),
         };
         // Add the instruction to the list of instructions.
@@ -528,14 +598,14 @@ impl<'a> CodeGenerator<'a> {
                     Expression::Identifier(identifier) => identifier.name,
                     _ => unreachable!("Parsing guarantees that a program name is always an identifier."),
                 };
-                // Lookup the imported program scope.
-                let imported_program_scope = match self
-                    .program
-                    .imports
-                    .get(&program_name)
-                    .and_then(|(program, _)| program.program_scopes.get(&program_name))
-                {
-                    Some(program) => program,
+                // Lookup the functions of the imported program scope, or of the deployed program.
+                let functions = match (self.program.imports.get(&program_name), self.program.stubs.get(&program_name)) {
+                    (Some((program, _)), _) => program.program_scopes.get(&program_name).map(|scope| &scope.functions),
+                    (None, Some((stub, _))) => Some(&stub.functions),
+                    (None, None) => None,
+                };
+                let functions = match functions {
+                    Some(functions) => functions,
                     None => unreachable!("Type checking guarantees that imported programs are well defined."),
                 };
                 // Check if the external function has a finalize block.
@@ -543,8 +613,7 @@ impl<'a> CodeGenerator<'a> {
                     Expression::Identifier(identifier) => identifier.name,
                     _ => unreachable!("Parsing guarantees that a function name is always an identifier."),
                 };
-                let has_finalize = match imported_program_scope.functions.iter().find(|(sym, _)| *sym == function_name)
-                {
+                let has_finalize = match functions.iter().find(|(sym, _)| *sym == function_name) {
                     Some((_, function)) => function.finalize.is_some(),
                     None => unreachable!("Type checking guarantees that imported functions are well defined."),
                 };
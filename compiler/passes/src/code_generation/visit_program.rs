@@ -16,7 +16,7 @@
 
 use crate::CodeGenerator;
 
-use leo_ast::{functions, Function, Mapping, Mode, Program, ProgramScope, Struct, Type, Variant};
+use leo_ast::{functions, Function, Mapping, Mode, Program, ProgramScope, Struct, Stub, Type, Variant};
 
 use indexmap::IndexMap;
 use itertools::Itertools;
@@ -28,15 +28,15 @@ impl<'a> CodeGenerator<'a> {
         // Accumulate instructions into a program string.
         let mut program_string = String::new();
 
-        if !input.imports.is_empty() {
+        if !input.imports.is_empty() || !input.stubs.is_empty() {
             // Visit each import statement and produce a Aleo import instruction.
-            program_string.push_str(
-                &input
-                    .imports
-                    .iter()
-                    .map(|(identifier, (imported_program, _))| self.visit_import(identifier, imported_program))
-                    .join("\n"),
-            );
+            let mut imports = input
+                .imports
+                .iter()
+                .map(|(identifier, (imported_program, _))| self.visit_import(identifier, imported_program))
+                .collect::<Vec<_>>();
+            imports.extend(input.stubs.iter().map(|(identifier, (stub, _))| self.visit_stub(identifier, stub)));
+            program_string.push_str(&imports.join("\n"));
 
             // Newline separator.
             program_string.push('\n');
@@ -111,13 +111,26 @@ impl<'a> CodeGenerator<'a> {
 
     fn visit_import(&mut self, import_name: &'a Symbol, import_program: &'a Program) -> String {
         // Load symbols into composite mapping.
+        // The instructions of the imported program are not in the bytecode, so they are left out of the source map.
+        let instruction_spans = std::mem::take(&mut self.instruction_spans);
         let _import_program_string = self.visit_program(import_program);
+        self.instruction_spans = instruction_spans;
         // todo: We do not need the import program string because we generate instructions for imports separately during leo build.
 
         // Generate string for import statement.
         format!("import {import_name}.aleo;")
     }
 
+    fn visit_stub(&mut self, stub_name: &'a Symbol, stub: &'a Stub) -> String {
+        // Load the structs and records of the deployed program into the composite mapping.
+        stub.structs.iter().for_each(|(_, struct_)| {
+            self.visit_struct_or_record(struct_);
+        });
+
+        // Generate string for import statement.
+        format!("import {stub_name}.aleo;")
+    }
+
     fn visit_struct_or_record(&mut self, struct_: &'a Struct) -> String {
         if struct_.is_record { self.visit_record(struct_) } else { self.visit_struct(struct_) }
     }
@@ -17,6 +17,7 @@
 use crate::CodeGenerator;
 
 use leo_ast::{
+    AccessExpression,
     AssertStatement,
     AssertVariant,
     AssignStatement,
@@ -28,6 +29,7 @@ use leo_ast::{
     ExpressionStatement,
     IterationStatement,
     Mode,
+    Node,
     Output,
     ReturnStatement,
     Statement,
@@ -47,9 +49,18 @@ impl<'a> CodeGenerator<'a> {
             Statement::Const(_) => {
                 unreachable!("`ConstStatement`s should not be in the AST at this phase of compilation.")
             }
+            Statement::Declaration(_) => {
+                unreachable!("`DeclarationStatement`s should not be in the AST at this phase of compilation.")
+            }
             Statement::Definition(stmt) => self.visit_definition(stmt),
+            Statement::Err(_) => {
+                unreachable!("`ErrStatement`s should not be in the AST at this phase of compilation.")
+            }
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(_) => {
+                unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.")
+            }
             Statement::Return(stmt) => self.visit_return(stmt),
         }
     }
@@ -207,7 +218,10 @@ impl<'a> CodeGenerator<'a> {
                 self.variable_mapping.insert(&identifier.name, operand);
                 expression_instructions
             }
-            (Expression::Tuple(tuple), Expression::Call(_)) => {
+            (
+                Expression::Tuple(tuple),
+                Expression::Call(_) | Expression::Access(AccessExpression::AssociatedFunction(_)),
+            ) => {
                 let (operand, expression_instructions) = self.visit_expression(&input.value);
                 // Split out the destinations from the tuple.
                 let operands = operand.split(' ').collect::<Vec<_>>();
@@ -241,11 +255,33 @@ impl<'a> CodeGenerator<'a> {
     }
 
     fn visit_console(&mut self, _: &'a ConsoleStatement) -> String {
-        unreachable!("Parsing guarantees that `ConsoleStatement`s are not present in the AST.")
+        unreachable!("Static single assignment removes `ConsoleStatement`s from the AST.")
     }
 
     pub(crate) fn visit_block(&mut self, input: &'a Block) -> String {
         // For each statement in the block, visit it and add its instructions to the list.
-        input.statements.iter().map(|stmt| self.visit_statement(stmt)).join("")
+        input
+            .statements
+            .iter()
+            .map(|stmt| {
+                let instructions = self.visit_statement(stmt);
+                self.record_instruction_spans(stmt, &instructions);
+                instructions
+            })
+            .join("")
+    }
+
+    /// Records the span of each instruction generated for a statement, for the source map.
+    fn record_instruction_spans(&mut self, input: &'a Statement, instructions: &str) {
+        let span = match input {
+            // The statements of a nested block are recorded when the block is visited.
+            Statement::Block(_) => return,
+            // The assignments introduced by the compiler have no span, so the span of the value is used.
+            Statement::Assign(assign) if assign.span.is_dummy() => assign.value.span(),
+            _ => input.span(),
+        };
+        if !span.is_dummy() {
+            self.instruction_spans.extend(instructions.lines().map(|instruction| (span, instruction.to_string())));
+        }
     }
 }
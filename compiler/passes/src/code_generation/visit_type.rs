@@ -39,6 +39,7 @@ impl<'a> CodeGenerator<'a> {
             Type::Tuple(_) => {
                 unreachable!("Tuple types should not be visited at this phase of compilation")
             }
+            Type::Generic(_) => unreachable!("Generic types are monomorphized before code generation"),
             Type::Err => unreachable!("Error types should not exist at this phase of compilation"),
             Type::Unit => unreachable!("Unit types are not supported at this phase of compilation"),
         }
@@ -14,23 +14,42 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{AssignStatement, Expression, Identifier, NodeID, Statement};
+use leo_ast::{AssignStatement, DefaultMangler, Expression, Identifier, Mangler, NodeID, Statement};
 use leo_span::Symbol;
 
-use std::{cell::RefCell, fmt::Display};
+use std::{cell::RefCell, fmt::Display, rc::Rc};
 
 /// A struct used to create assignment statements.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct Assigner {
     /// The inner counter.
     /// `RefCell` is used here to avoid `&mut` all over the compiler.
     inner: RefCell<AssignerInner>,
+    /// The scheme used to name the variables introduced by the compiler.
+    mangler: Rc<dyn Mangler>,
+}
+
+impl Default for Assigner {
+    fn default() -> Self {
+        Self::new(Rc::new(DefaultMangler))
+    }
 }
 
 impl Assigner {
+    /// Returns a new `Assigner` that names the variables it introduces with `mangler`.
+    pub fn new(mangler: Rc<dyn Mangler>) -> Self {
+        Self { inner: Default::default(), mangler }
+    }
+
+    /// Returns the scheme used to name the variables introduced by the compiler.
+    pub fn mangler(&self) -> &dyn Mangler {
+        self.mangler.as_ref()
+    }
+
     /// Return a new unique `Symbol` from a `&str`.
     pub fn unique_symbol(&self, arg: impl Display, separator: impl Display) -> Symbol {
-        self.inner.borrow_mut().unique_symbol(arg, separator)
+        let index = self.inner.borrow_mut().next_index();
+        self.mangler.fresh_name(&arg.to_string(), &separator.to_string(), index)
     }
 
     /// Constructs the assignment statement `place = expr;`.
@@ -49,10 +68,10 @@ pub struct AssignerInner {
 }
 
 impl AssignerInner {
-    /// Return a new unique `Symbol` from a `&str`.
-    fn unique_symbol(&mut self, arg: impl Display, separator: impl Display) -> Symbol {
+    /// Returns a new index for a unique `Symbol`.
+    fn next_index(&mut self) -> usize {
         self.counter += 1;
-        Symbol::intern(&format!("{}{}{}", arg, separator, self.counter - 1))
+        self.counter - 1
     }
 
     /// Constructs the assignment statement `place = expr;`.
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Expression, IntegerType, NodeID, Type, Value};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// The values of the constants declared in a program scope.
+/// The values are evaluated once, by the constant resolution pass, and the passes that need them, i.e. loop unrolling,
+/// the resolution of array lengths, and the interpreter, read them from here rather than evaluating the constants
+/// again.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConstantEnvironment {
+    /// The values of the constants, by name.
+    values: IndexMap<Symbol, Value>,
+    /// The names of the constants, by the ID of their declarations.
+    declarations: IndexMap<NodeID, Symbol>,
+}
+
+impl ConstantEnvironment {
+    /// Returns the value of `expression`, a constant of type `type_`, if it is a literal or an array of literals.
+    /// Unsuffixed integer literals are given the integer type of `type_`.
+    pub fn evaluate(expression: &Expression, type_: &Type) -> Option<Value> {
+        match (expression, type_) {
+            (Expression::Literal(literal), _) => Value::try_from(&literal.clone().with_inferred_type(type_)).ok(),
+            (Expression::Array(array), Type::Array(array_type)) => Some(Value::Array(
+                array
+                    .elements
+                    .iter()
+                    .map(|element| Self::evaluate(element, array_type.element_type()))
+                    .collect::<Option<_>>()?,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Records the value of the constant `name`, declared by the node `id`.
+    pub fn insert(&mut self, name: Symbol, id: NodeID, value: Value) {
+        self.values.insert(name, value);
+        self.declarations.insert(id, name);
+    }
+
+    /// Removes all the constants, e.g. before the constants of another program scope are recorded.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.declarations.clear();
+    }
+
+    /// Returns the value of the constant `name`, if it is known.
+    pub fn lookup(&self, name: Symbol) -> Option<&Value> {
+        self.values.get(&name)
+    }
+
+    /// Returns the value of the constant declared by the node `id`, if it is known.
+    pub fn lookup_declaration(&self, id: NodeID) -> Option<&Value> {
+        self.declarations.get(&id).and_then(|name| self.lookup(*name))
+    }
+
+    /// Returns the names and values of the constants, in the order they were evaluated.
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, &Value)> {
+        self.values.iter().map(|(name, value)| (*name, value))
+    }
+
+    /// Returns the type and value of the constant `name`, if it is an integer.
+    /// A `u128` that does not fit in an `i128` has no value.
+    pub fn integer(&self, name: Symbol) -> Option<(IntegerType, i128)> {
+        let value = self.lookup(name)?;
+        match Type::from(value) {
+            Type::Integer(integer_type) => Some((integer_type, i128::try_from(value).ok()?)),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the constant `name`, if it is a non-negative integer, and thus a valid array length.
+    pub fn array_length(&self, name: Symbol) -> Option<usize> {
+        self.integer(name).and_then(|(_, value)| usize::try_from(value).ok())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utilities::{parse_ast, program_scope},
+        ConstantResolver,
+        Pass,
+    };
+
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_constant_environment() {
+        create_session_if_not_set_then(|_| {
+            let source = "program test.aleo {
+    const M: u32 = N * 2u32;
+    const N: u32 = 4;
+    const NEGATIVE: i8 = -1i8;
+    const TABLE: [u8; N] = [1u8, 2, 3u8, 4];
+    const KEY: field = 1field;
+    transition main(a: [u8; M]) -> u8 { return a[0u8]; }
+}
+";
+            let (ast, constants) = ConstantResolver::do_pass((parse_ast(source), &Handler::default())).unwrap();
+
+            let values = constants.iter().map(|(name, value)| format!("{name} = {value}")).collect::<Vec<_>>();
+            // The constants are evaluated in the order of their dependencies.
+            assert_eq!(values[..2], ["N = 4u32", "M = 8u32"]);
+            assert_eq!(constants.integer(Symbol::intern("NEGATIVE")), Some((IntegerType::I8, -1)));
+            assert_eq!(constants.array_length(Symbol::intern("M")), Some(8));
+            // Negative integers and other types of values are not array lengths.
+            assert_eq!(constants.array_length(Symbol::intern("NEGATIVE")), None);
+            assert_eq!(constants.array_length(Symbol::intern("KEY")), None);
+            assert!(
+                matches!(constants.lookup(Symbol::intern("TABLE")), Some(Value::Array(elements)) if elements.len() == 4)
+            );
+
+            let program = program_scope(&ast);
+            let (_, key) = program.consts.iter().find(|(name, _)| *name == Symbol::intern("KEY")).unwrap();
+            assert_eq!(constants.lookup_declaration(key.id).map(|value| value.to_string()).as_deref(), Some("1field"));
+        })
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{CoreFunction, Type};
+use leo_errors::{CompilerError, Result};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// The signature of a built-in function registered by an embedder of the compiler, e.g. `Oracle::price(u32) -> u64`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoreFunctionSignature {
+    /// The types of the arguments, in order.
+    pub arguments: Vec<Type>,
+    /// The type of the single value that a call returns.
+    pub output: Type,
+    /// The instruction that a call compiles to, e.g. `oracle.price`.
+    /// It is followed by the argument operands and `into` the destination register, e.g. `oracle.price r0 into r1;`.
+    pub opcode: String,
+    /// Whether the function may only be called in a finalize block, e.g. because it reads on-chain state.
+    pub finalize_only: bool,
+}
+
+/// The built-in functions that a program may call in addition to the core functions of Leo, e.g. `BHP256::hash`.
+/// Embedders of the compiler, e.g. custom VMs or test harnesses, register their built-ins with their signatures, so
+/// that calls to them are type checked and compiled without patching the type checker. The registry is empty by default.
+#[derive(Clone, Debug, Default)]
+pub struct CoreFunctionRegistry {
+    /// The signatures of the registered functions, by the names of their module and function.
+    functions: IndexMap<(Symbol, Symbol), CoreFunctionSignature>,
+}
+
+impl CoreFunctionRegistry {
+    /// Registers the function `module::function` with the given signature.
+    /// Fails if it is a core function of Leo, or if it is already registered.
+    pub fn register(&mut self, module: Symbol, function: Symbol, signature: CoreFunctionSignature) -> Result<()> {
+        if CoreFunction::from_symbols(module, function).is_some() || self.get(module, function).is_some() {
+            return Err(CompilerError::core_function_already_defined(module, function).into());
+        }
+        self.functions.insert((module, function), signature);
+        Ok(())
+    }
+
+    /// Returns the signature of the registered function `module::function`, if it is registered.
+    pub fn get(&self, module: Symbol, function: Symbol) -> Option<&CoreFunctionSignature> {
+        self.functions.get(&(module, function))
+    }
+
+    /// Returns an iterator over the names and signatures of the registered functions, in the order of registration.
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, Symbol, &CoreFunctionSignature)> {
+        self.functions.iter().map(|((module, function), signature)| (*module, *function, signature))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_ast::IntegerType;
+    use leo_span::{sym, symbol::create_session_if_not_set_then};
+
+    fn signature() -> CoreFunctionSignature {
+        CoreFunctionSignature {
+            arguments: vec![Type::Integer(IntegerType::U32)],
+            output: Type::Integer(IntegerType::U64),
+            opcode: "oracle.price".to_string(),
+            finalize_only: true,
+        }
+    }
+
+    #[test]
+    fn test_register() {
+        create_session_if_not_set_then(|_| {
+            let (oracle, price) = (Symbol::intern("Oracle"), Symbol::intern("price"));
+            let mut registry = CoreFunctionRegistry::default();
+            assert!(registry.get(oracle, price).is_none());
+
+            registry.register(oracle, price, signature()).unwrap();
+            assert_eq!(registry.get(oracle, price), Some(&signature()));
+            assert_eq!(registry.iter().count(), 1);
+
+            // A function cannot be registered twice, nor shadow a core function of Leo.
+            assert!(registry.register(oracle, price, signature()).is_err());
+            assert!(registry.register(sym::BHP256, sym::hash_to_field, signature()).is_err());
+        })
+    }
+}
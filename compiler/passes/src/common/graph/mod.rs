@@ -72,6 +72,11 @@ impl<N: Node> DiGraph<N> {
         self.nodes.contains(&node)
     }
 
+    /// Returns the nodes that `node` has an edge to.
+    pub fn neighbors(&self, node: N) -> impl Iterator<Item = N> + '_ {
+        self.edges.get(&node).into_iter().flatten().copied()
+    }
+
     /// Returns the post-order ordering of the graph.
     /// Detects if there is a cycle in the graph.
     pub fn post_order(&self) -> Result<IndexSet<N>, DiGraphError<N>> {
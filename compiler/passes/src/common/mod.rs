@@ -17,15 +17,24 @@
 pub mod assigner;
 pub use assigner::*;
 
+pub mod core_function_registry;
+pub use core_function_registry::*;
+
 pub mod graph;
 pub use graph::*;
 
+pub mod reference_index;
+pub use reference_index::*;
+
 pub mod rename_table;
 pub use rename_table::*;
 
 pub mod replacer;
 pub use replacer::*;
 
+pub mod constant_environment;
+pub use constant_environment::*;
+
 pub mod constant_propagation_table;
 pub use constant_propagation_table::*;
 
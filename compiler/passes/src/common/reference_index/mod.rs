@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_errors::{AstError, Result};
+use leo_span::{symbol::with_session_globals, Span};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// A region of a source file.
+/// Unlike a `Span`, a `Location` does not depend on the source map, so it remains valid across sessions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Location {
+    pub line_start: usize,
+    pub line_stop: usize,
+    pub col_start: usize,
+    pub col_stop: usize,
+}
+
+impl Location {
+    /// Returns the location of `span` in the current session's source map.
+    pub fn from_span(span: Span) -> Option<Self> {
+        let location = with_session_globals(|s| s.source_map.span_to_location(span))?;
+        Some(Self {
+            line_start: location.line_start,
+            line_stop: location.line_stop,
+            col_start: location.col_start,
+            col_stop: location.col_stop,
+        })
+    }
+
+    /// Returns `true` if the position at `line` and `col` lies within the location.
+    pub fn contains(&self, line: usize, col: usize) -> bool {
+        (self.line_start, self.col_start) <= (line, col) && (line, col) < (self.line_stop, self.col_stop)
+    }
+}
+
+/// The kind of item introduced by a definition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DefinitionKind {
+    Const,
+    Enum,
+    Function,
+    Input,
+    Interface,
+    Mapping,
+    Struct,
+    Variable,
+}
+
+/// A named item that references can resolve to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Definition {
+    /// The name of the item.
+    pub name: String,
+    /// The kind of the item.
+    pub kind: DefinitionKind,
+    /// The location of the name in the definition.
+    pub location: Location,
+}
+
+/// A use of a name, resolved to its definition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reference {
+    /// The index of the definition in `ReferenceIndex::definitions`.
+    pub definition: usize,
+    /// The location of the use.
+    pub location: Location,
+}
+
+/// An index of the definitions and references in a program, used to answer go-to-definition and find-all-references queries.
+/// The index is keyed by a fingerprint of the source it was built from, so that a persisted index can be reused as long as the source is unchanged.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReferenceIndex {
+    /// The fingerprint of the source the index was built from.
+    pub fingerprint: String,
+    /// The definitions in the program.
+    pub definitions: Vec<Definition>,
+    /// The references in the program.
+    pub references: Vec<Reference>,
+}
+
+impl ReferenceIndex {
+    /// Returns the fingerprint of `source`, that is, its SHA-256 hash in hexadecimal.
+    pub fn fingerprint(source: &str) -> String {
+        format!("{:x}", Sha256::digest(source.as_bytes()))
+    }
+
+    /// Returns the name of the file the reference index of the source file at `source_path` is persisted to.
+    pub fn file_name(source_path: &Path) -> String {
+        let stem = source_path.file_stem().map(|stem| stem.to_string_lossy()).unwrap_or_default();
+        format!("{stem}.reference_index.json")
+    }
+
+    /// Persists the reference index of the source file at `source_path` in `directory`.
+    pub fn persist(&self, directory: &Path, source_path: &Path) -> Result<()> {
+        self.to_json_file(directory.to_path_buf(), &Self::file_name(source_path))
+    }
+
+    /// Returns the reference index of the source file at `source_path` persisted in `directory`, if it was built from `source`.
+    /// An index that is missing, unreadable, or built from another version of the file is ignored.
+    pub fn load(directory: &Path, source_path: &Path, source: &str) -> Option<Self> {
+        let reference_index = Self::from_json_file(directory.join(Self::file_name(source_path))).ok()?;
+        (reference_index.fingerprint == Self::fingerprint(source)).then_some(reference_index)
+    }
+
+    /// Returns the index of the definition named or referenced at the given position, if one exists.
+    fn definition_index_at(&self, line: usize, col: usize) -> Option<usize> {
+        self.references
+            .iter()
+            .find(|reference| reference.location.contains(line, col))
+            .map(|reference| reference.definition)
+            .or_else(|| self.definitions.iter().position(|definition| definition.location.contains(line, col)))
+    }
+
+    /// Returns the definition named or referenced at the given position, if one exists.
+    pub fn definition_at(&self, line: usize, col: usize) -> Option<&Definition> {
+        self.definition_index_at(line, col).map(|index| &self.definitions[index])
+    }
+
+    /// Returns the locations of all references to the definition named or referenced at the given position.
+    pub fn references_at(&self, line: usize, col: usize) -> Vec<Location> {
+        match self.definition_index_at(line, col) {
+            None => Vec::new(),
+            Some(index) => self
+                .references
+                .iter()
+                .filter(|reference| reference.definition == index)
+                .map(|reference| reference.location)
+                .collect(),
+        }
+    }
+
+    /// Serializes the reference index into a JSON file.
+    pub fn to_json_file(&self, mut path: PathBuf, file_name: &str) -> Result<()> {
+        path.push(file_name);
+        let file =
+            std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_reference_index_json_file(&path, &e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(serde_json::to_writer(writer, &self)
+            .map_err(|e| AstError::failed_to_write_reference_index_to_json_file(&path, &e))?)
+    }
+
+    /// Deserializes the JSON string into a reference index.
+    pub fn from_json_string(json: &str) -> Result<Self> {
+        let reference_index: ReferenceIndex =
+            serde_json::from_str(json).map_err(|e| AstError::failed_to_read_json_string_to_reference_index(&e))?;
+        Ok(reference_index)
+    }
+
+    /// Deserializes the JSON string into a reference index from a file.
+    pub fn from_json_file(path: PathBuf) -> Result<Self> {
+        let data = std::fs::read_to_string(&path).map_err(|e| AstError::failed_to_read_json_file(&path, &e))?;
+        Self::from_json_string(&data)
+    }
+}
@@ -0,0 +1,235 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Location, SymbolTable, VariableType};
+
+use leo_ast::{Input, Member, Mode, Type, Variant};
+use leo_errors::{AstError, Result};
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The version of the schema of a `SymbolTableExport`.
+/// It is incremented whenever a field is removed or changes its meaning, but not when an optional field is added.
+pub const SYMBOL_TABLE_EXPORT_VERSION: u32 = 1;
+
+/// A typed name, i.e. a parameter of a function or a member of a struct.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedBinding {
+    pub name: String,
+    /// The mode of the binding, e.g. `public`, or `None` if it has no explicit mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    /// The type of the binding, as written in Leo, e.g. `[u8; 4]`.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+/// The signature of a finalize block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedFinalize {
+    pub inputs: Vec<ExportedBinding>,
+    pub output_type: String,
+}
+
+/// The signature of a function.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedFunction {
+    pub name: String,
+    /// One of `transition`, `function`, or `inline`.
+    pub variant: String,
+    /// Whether the function is visible to importing programs.
+    pub public: bool,
+    pub inputs: Vec<ExportedBinding>,
+    pub output_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finalize: Option<ExportedFinalize>,
+    /// The imported program that defines the function, if it is not defined by the program itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub import: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+}
+
+/// A struct or a record, with its members.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedStruct {
+    pub name: String,
+    pub is_record: bool,
+    pub members: Vec<ExportedBinding>,
+    /// The imported program that defines the struct, if it is not defined by the program itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub import: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+}
+
+/// A constant of the program scope, with its value.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedConstant {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The value of the constant, as written in Leo, or `None` if it is not known after type checking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+}
+
+/// The structure of a program, as recorded by its symbol table, for tools that analyze programs without parsing Leo.
+/// Unlike the serialization of the `SymbolTable` itself, which follows the internals of the compiler, the schema of
+/// the export is stable: types and values are written as Leo source, and locations do not depend on the source map.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolTableExport {
+    /// The version of the schema, see `SYMBOL_TABLE_EXPORT_VERSION`.
+    pub version: u32,
+    pub functions: Vec<ExportedFunction>,
+    pub structs: Vec<ExportedStruct>,
+    pub constants: Vec<ExportedConstant>,
+}
+
+impl SymbolTableExport {
+    /// Serializes the export into a JSON file.
+    pub fn to_json_file(&self, mut path: PathBuf, file_name: &str) -> Result<()> {
+        path.push(file_name);
+        let file =
+            std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_symbol_table_json_file(&path, &e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(serde_json::to_writer_pretty(writer, &self)
+            .map_err(|e| AstError::failed_to_write_symbol_table_to_json_file(&path, &e))?)
+    }
+
+    /// Deserializes the JSON string into an export.
+    pub fn from_json_string(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json).map_err(|e| AstError::failed_to_read_json_string_to_symbol_table(&e))?)
+    }
+}
+
+impl SymbolTable {
+    /// Returns the functions, structs, and constants of the global scope, in the order they were inserted.
+    pub fn export(&self) -> SymbolTableExport {
+        let import = |name| self.lookup_import(name).map(|import| import.to_string());
+        let functions = self.functions.iter().map(|(name, function)| ExportedFunction {
+            name: name.to_string(),
+            variant: match function.variant {
+                Variant::Inline => "inline",
+                Variant::Standard => "function",
+                Variant::Transition => "transition",
+            }
+            .to_string(),
+            public: function.is_public(),
+            inputs: function.input.iter().map(export_input).collect(),
+            output_type: function.output_type.to_string(),
+            finalize: function.finalize.as_ref().map(|finalize| ExportedFinalize {
+                inputs: finalize.input.iter().map(export_input).collect(),
+                output_type: finalize.output_type.to_string(),
+            }),
+            import: import(*name),
+            location: Location::from_span(function._span),
+        });
+        let structs = self.structs.iter().map(|(name, struct_)| ExportedStruct {
+            name: name.to_string(),
+            is_record: struct_.is_record,
+            members: struct_.members.iter().map(export_member).collect(),
+            import: import(*name),
+            location: Location::from_span(struct_.span),
+        });
+        let constants = self.variables.iter().filter(|(_, variable)| variable.declaration == VariableType::Const).map(
+            |(name, variable)| ExportedConstant {
+                name: name.to_string(),
+                type_: variable.type_.to_string(),
+                value: variable.value.as_ref().map(|value| value.to_string()),
+                location: Location::from_span(variable.span),
+            },
+        );
+        SymbolTableExport {
+            version: SYMBOL_TABLE_EXPORT_VERSION,
+            functions: functions.collect(),
+            structs: structs.collect(),
+            constants: constants.collect(),
+        }
+    }
+}
+
+fn export_binding(name: impl ToString, mode: Mode, type_: &Type) -> ExportedBinding {
+    ExportedBinding {
+        name: name.to_string(),
+        mode: (mode != Mode::None).then(|| mode.to_string()),
+        type_: type_.to_string(),
+    }
+}
+
+fn export_input(input: &Input) -> ExportedBinding {
+    export_binding(input.identifier(), input.mode(), &input.type_())
+}
+
+fn export_member(member: &Member) -> ExportedBinding {
+    export_binding(member.identifier, member.mode, &member.type_)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utilities::{parse_ast, type_check},
+        TypeTable,
+    };
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_export() {
+        create_session_if_not_set_then(|_| {
+            let source = "program test.aleo {
+    const SIZE: u8 = 3u8;
+    record Token { owner: address, amount: u64 }
+    struct Pair { a: u8, b: [u8; 2] }
+    mapping counts: u8 => u8;
+    transition main(public a: u8, b: Pair) -> u8 { return a + SIZE; }
+    transition count(a: u8) { return then finalize(a); } finalize count(a: u8) { counts.set(a, a); }
+    inline helper(a: u8) -> u8 { const LOCAL: u8 = 1u8; return a + LOCAL; }
+}
+";
+            let symbol_table = type_check(&parse_ast(source), &TypeTable::default());
+            let export = symbol_table.export();
+
+            let functions = export.functions.iter().map(|function| function.name.as_str()).collect::<Vec<_>>();
+            let structs = export.structs.iter().map(|struct_| struct_.name.as_str()).collect::<Vec<_>>();
+            let constants = export.constants.iter().map(|constant| constant.name.as_str()).collect::<Vec<_>>();
+            assert_eq!(functions, ["main", "count", "helper"]);
+            assert_eq!(structs, ["Token", "Pair"]);
+            // Only the constants of the program scope are exported.
+            assert_eq!(constants, ["SIZE"]);
+
+            let main = &export.functions[0];
+            assert_eq!((main.variant.as_str(), main.public, main.output_type.as_str()), ("transition", true, "u8"));
+            assert_eq!(main.inputs[0].mode.as_deref(), Some("public"));
+            assert_eq!((main.inputs[1].mode.as_deref(), main.inputs[1].type_.as_str()), (None, "Pair"));
+            assert_eq!(main.location.unwrap().line_start, 6);
+            assert_eq!(export.functions[1].finalize.as_ref().unwrap().inputs[0].name, "a");
+            assert_eq!((export.functions[2].variant.as_str(), export.functions[2].public), ("inline", false));
+
+            assert!(export.structs[0].is_record);
+            assert_eq!(export.structs[1].members[1].type_, "[u8; 2]");
+            assert_eq!(export.constants[0].value.as_deref(), Some("3u8"));
+
+            let json = serde_json::to_string(&export).unwrap();
+            assert!(json.contains(r#""type":"u8""#), "{json}");
+            assert_eq!(SymbolTableExport::from_json_string(&json).unwrap(), export);
+        })
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{Function, Input, Type, Variant};
+use leo_ast::{Annotation, Function, Input, Node, Type, Variant, Visibility};
 use leo_span::Span;
 
 use serde::{Deserialize, Serialize};
@@ -28,6 +28,9 @@ pub struct FinalizeData {
     pub(crate) input: Vec<Input>,
     /// The output type of the finalize block.
     pub(crate) output_type: Type,
+    /// The `Span` of the outputs of the finalize block, if it has any.
+    #[serde(skip)]
+    pub(crate) output_span: Option<Span>,
 }
 
 /// An entry for a function in the symbol table.
@@ -37,14 +40,43 @@ pub struct FunctionSymbol {
     pub(crate) id: usize,
     /// The output type of the function.
     pub(crate) output_type: Type,
+    /// The `Span` of the outputs of the function, if it has any.
+    #[serde(skip)]
+    pub(crate) output_span: Option<Span>,
     /// Is this function a transition, inlined, or a regular function?.
     pub variant: Variant,
+    /// The visibility of the function to importing programs.
+    #[serde(default, skip_serializing_if = "Visibility::is_default")]
+    pub visibility: Visibility,
     /// The `Span` associated with the function.
     pub(crate) _span: Span,
+    /// The `Span` of the head of the function, from its keyword to its name.
+    #[serde(skip)]
+    pub(crate) signature_span: Span,
     /// The inputs to the function.
     pub(crate) input: Vec<Input>,
     /// Metadata associated with the finalize block.
     pub(crate) finalize: Option<FinalizeData>,
+    /// The `@deprecated` annotation of the function, if it has one.
+    #[serde(skip)]
+    pub(crate) deprecation: Option<Annotation>,
+}
+
+impl FunctionSymbol {
+    /// Returns the inputs of the function.
+    pub fn input(&self) -> &[Input] {
+        &self.input
+    }
+
+    /// Returns the output type of the function.
+    pub fn output_type(&self) -> &Type {
+        &self.output_type
+    }
+
+    /// Returns `true` if the function is visible to importing programs.
+    pub fn is_public(&self) -> bool {
+        self.visibility.is_public(self.variant == Variant::Transition)
+    }
 }
 
 impl SymbolTable {
@@ -52,13 +84,18 @@ impl SymbolTable {
         FunctionSymbol {
             id,
             output_type: func.output_type.clone(),
+            output_span: func.output.iter().map(|output| output.span()).reduce(|a, b| a + b),
             variant: func.variant,
+            visibility: func.visibility,
             _span: func.span,
+            signature_span: Span::new(func.span.lo, func.identifier.span.hi),
             input: func.input.clone(),
             finalize: func.finalize.as_ref().map(|finalize| FinalizeData {
                 input: finalize.input.clone(),
                 output_type: finalize.output_type.clone(),
+                output_span: finalize.output.iter().map(|output| output.span()).reduce(|a, b| a + b),
             }),
+            deprecation: func.deprecation().cloned(),
         }
     }
 }
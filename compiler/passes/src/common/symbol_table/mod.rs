@@ -14,16 +14,22 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod export;
+pub use export::*;
+
 pub mod function_symbol;
 pub use function_symbol::*;
 
+pub mod suggestion;
+pub(crate) use suggestion::*;
+
 pub mod variable_symbol;
 pub use variable_symbol::*;
 
 use std::cell::RefCell;
 
-use leo_ast::{normalize_json_value, remove_key_from_json, Function, Struct};
-use leo_errors::{AstError, Result};
+use leo_ast::{normalize_json_value, remove_key_from_json, Enum, Expression, Function, Interface, Module, Struct};
+use leo_errors::{AstError, Label, Result};
 use leo_span::{Span, Symbol};
 
 use indexmap::IndexMap;
@@ -43,6 +49,23 @@ pub struct SymbolTable {
     /// Maps struct names to struct definitions.
     /// This field is populated at a first pass.
     pub structs: IndexMap<Symbol, Struct>,
+    /// Maps interface names to interface definitions.
+    /// This field is populated at a first pass.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub interfaces: IndexMap<Symbol, Interface>,
+    /// Maps enum names to enum definitions.
+    /// This field is populated at a first pass.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub enums: IndexMap<Symbol, Enum>,
+    /// Maps the names of the items defined by imported programs to the names of the imports that define them.
+    /// This field is populated at a first pass.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub imports: IndexMap<Symbol, Symbol>,
+    /// Maps the names of the modules of the program to their declarations.
+    /// The items of a module are stored under their qualified names, e.g. `math__add` for `math::add`.
+    /// This field is populated at a first pass.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub modules: IndexMap<Symbol, Module>,
     /// The variables defined in a scope.
     /// This field is populated as necessary.
     pub(crate) variables: IndexMap<Symbol, VariableSymbol>,
@@ -55,9 +78,19 @@ pub struct SymbolTable {
 impl SymbolTable {
     /// Recursively checks if the symbol table contains an entry for the given symbol.
     /// Leo does not allow any variable shadowing or overlap between different symbols.
+    /// A local variable that shadows a parameter or another local is reported along with the declaration it shadows.
     pub fn check_shadowing(&self, symbol: Symbol, span: Span) -> Result<()> {
-        if self.variables.contains_key(&symbol) {
-            Err(AstError::shadowed_variable(symbol, span).into())
+        if let Some(existing) = self.variables.get(&symbol) {
+            // Only the global scope has no parent, so every other variable is a parameter or a local.
+            if self.parent.is_none() {
+                return Err(AstError::shadowed_variable(symbol, span).into());
+            }
+            let kind = match existing.declaration {
+                VariableType::Input(_) => "parameter",
+                VariableType::Const | VariableType::Mut => "local variable",
+            };
+            let label = Label::new(format!("`{symbol}` is first declared here"), existing.span);
+            Err(AstError::shadowed_local_variable(symbol, kind, span).with_label(label).into())
         } else if self.functions.contains_key(&symbol) {
             Err(AstError::shadowed_function(symbol, span).into())
         } else if let Some(existing) = self.structs.get(&symbol) {
@@ -65,6 +98,10 @@ impl SymbolTable {
                 true => Err(AstError::shadowed_record(symbol, span).into()),
                 false => Err(AstError::shadowed_struct(symbol, span).into()),
             }
+        } else if self.interfaces.contains_key(&symbol) {
+            Err(AstError::shadowed_interface(symbol, span).into())
+        } else if self.enums.contains_key(&symbol) {
+            Err(AstError::shadowed_enum(symbol, span).into())
         } else if let Some(parent) = self.parent.as_ref() {
             parent.check_shadowing(symbol, span)
         } else {
@@ -96,6 +133,29 @@ impl SymbolTable {
         Ok(())
     }
 
+    /// Inserts an interface into the symbol table.
+    pub fn insert_interface(&mut self, symbol: Symbol, insert: &Interface) -> Result<()> {
+        self.check_shadowing(symbol, insert.span)?;
+        self.interfaces.insert(symbol, insert.clone());
+        Ok(())
+    }
+
+    /// Inserts an enum into the symbol table.
+    pub fn insert_enum(&mut self, symbol: Symbol, insert: &Enum) -> Result<()> {
+        self.check_shadowing(symbol, insert.span)?;
+        self.enums.insert(symbol, insert.clone());
+        Ok(())
+    }
+
+    /// Inserts a module into the symbol table.
+    pub fn insert_module(&mut self, symbol: Symbol, insert: &Module) -> Result<()> {
+        if self.modules.contains_key(&symbol) {
+            return Err(AstError::duplicate_module(symbol, insert.span).into());
+        }
+        self.modules.insert(symbol, insert.clone());
+        Ok(())
+    }
+
     /// Inserts a variable into the symbol table.
     pub fn insert_variable(&mut self, symbol: Symbol, insert: VariableSymbol) -> Result<()> {
         self.check_shadowing(symbol, insert.span)?;
@@ -125,6 +185,24 @@ impl SymbolTable {
         }
     }
 
+    /// Attempts to lookup the function `item` of the module `module` in the symbol table.
+    /// Returns `None` if `module` is not a module, or if it does not declare the function.
+    pub fn lookup_module_fn(&self, module: Symbol, item: Symbol) -> Option<&FunctionSymbol> {
+        self.lookup_module(module)?;
+        self.lookup_fn_symbol(Module::item_name(module, item))
+    }
+
+    /// Attempts to lookup a module in the symbol table.
+    pub fn lookup_module(&self, symbol: Symbol) -> Option<&Module> {
+        if let Some(module) = self.modules.get(&symbol) {
+            Some(module)
+        } else if let Some(parent) = self.parent.as_ref() {
+            parent.lookup_module(symbol)
+        } else {
+            None
+        }
+    }
+
     /// Attempts to lookup a struct in the symbol table.
     pub fn lookup_struct(&self, symbol: Symbol) -> Option<&Struct> {
         if let Some(struct_) = self.structs.get(&symbol) {
@@ -136,6 +214,40 @@ impl SymbolTable {
         }
     }
 
+    /// Attempts to lookup an interface in the symbol table.
+    pub fn lookup_interface(&self, symbol: Symbol) -> Option<&Interface> {
+        if let Some(interface) = self.interfaces.get(&symbol) {
+            Some(interface)
+        } else if let Some(parent) = self.parent.as_ref() {
+            parent.lookup_interface(symbol)
+        } else {
+            None
+        }
+    }
+
+    /// Attempts to lookup an enum in the symbol table.
+    pub fn lookup_enum(&self, symbol: Symbol) -> Option<&Enum> {
+        if let Some(enum_) = self.enums.get(&symbol) {
+            Some(enum_)
+        } else if let Some(parent) = self.parent.as_ref() {
+            parent.lookup_enum(symbol)
+        } else {
+            None
+        }
+    }
+
+    /// Attempts to lookup the import that defines an item in the symbol table.
+    /// Returns `None` if the item is not defined by an imported program.
+    pub fn lookup_import(&self, symbol: Symbol) -> Option<Symbol> {
+        if let Some(import) = self.imports.get(&symbol) {
+            Some(*import)
+        } else if let Some(parent) = self.parent.as_ref() {
+            parent.lookup_import(symbol)
+        } else {
+            None
+        }
+    }
+
     /// Attempts to lookup a variable in the symbol table.
     pub fn lookup_variable(&self, symbol: Symbol) -> Option<&VariableSymbol> {
         if let Some(var) = self.variables.get(&symbol) {
@@ -147,6 +259,43 @@ impl SymbolTable {
         }
     }
 
+    /// Attempts to lookup the value of a variable that is a known constant in the current scope.
+    /// Returns `None` if the variable is not defined, or if its value is not known at compile time.
+    pub fn lookup_constant_value(&self, symbol: Symbol) -> Option<&Expression> {
+        self.lookup_variable(symbol).and_then(|variable| variable.value.as_ref())
+    }
+
+    /// Returns the variable in scope whose name is closest to `symbol`, if any is a likely misspelling of it.
+    pub fn similar_variable(&self, symbol: Symbol) -> Option<Symbol> {
+        closest_name(symbol, self.names_in_scope(|table| table.variables.keys()))
+    }
+
+    /// Returns the function whose name is closest to `symbol`, if any is a likely misspelling of it.
+    pub fn similar_fn(&self, symbol: Symbol) -> Option<Symbol> {
+        closest_name(symbol, self.names_in_scope(|table| table.functions.keys()))
+    }
+
+    /// Returns the struct whose name is closest to `symbol`, if any is a likely misspelling of it.
+    pub fn similar_struct(&self, symbol: Symbol) -> Option<Symbol> {
+        closest_name(symbol, self.names_in_scope(|table| table.structs.keys()))
+    }
+
+    /// Returns the interface whose name is closest to `symbol`, if any is a likely misspelling of it.
+    pub fn similar_interface(&self, symbol: Symbol) -> Option<Symbol> {
+        closest_name(symbol, self.names_in_scope(|table| table.interfaces.keys()))
+    }
+
+    /// Returns the names selected by `names` from this scope and each of its parents, innermost first.
+    fn names_in_scope<'a, I: Iterator<Item = &'a Symbol>>(&'a self, names: impl Fn(&'a Self) -> I) -> Vec<Symbol> {
+        let mut scope = Some(self);
+        let mut result = Vec::new();
+        while let Some(table) = scope {
+            result.extend(names(table).copied());
+            scope = table.parent.as_deref();
+        }
+        result
+    }
+
     /// Returns true if the variable exists in the local scope
     pub fn variable_in_local_scope(&self, symbol: Symbol) -> bool {
         self.variables.contains_key(&symbol)
@@ -225,6 +374,23 @@ impl SymbolTable {
             .map_err(|e| AstError::failed_to_write_symbol_table_to_json_file(&path, &e))?)
     }
 
+    /// Serializes the symbol table into a canonical JSON file, whose object keys are sorted.
+    /// If `strip_spans` is set, the spans are removed, including those of identifiers.
+    pub fn to_canonical_json_file(
+        &self,
+        mut path: std::path::PathBuf,
+        file_name: &str,
+        strip_spans: bool,
+    ) -> Result<()> {
+        path.push(file_name);
+        let file =
+            std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_symbol_table_json_file(&path, &e))?;
+        let writer = std::io::BufWriter::new(file);
+        let value = leo_ast::canonicalize_json_value(self.to_json_value()?, strip_spans);
+        Ok(serde_json::to_writer_pretty(writer, &value)
+            .map_err(|e| AstError::failed_to_write_symbol_table_to_json_file(&path, &e))?)
+    }
+
     /// Deserializes the JSON string into a symbol table.
     pub fn from_json_string(json: &str) -> Result<Self> {
         let symbol_table: SymbolTable =
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::Symbol;
+
+/// Returns the number of single-character insertions, deletions, and substitutions needed to turn `a` into `b`.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // The distances between the prefix of `a` considered so far and each prefix of `b`.
+    let mut distances: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut previous = distances[0];
+        distances[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous + usize::from(a_char != *b_char);
+            previous = distances[j + 1];
+            distances[j + 1] = substitution.min(distances[j] + 1).min(previous + 1);
+        }
+    }
+    distances[b.len()]
+}
+
+/// Returns the candidate closest to `name`, if any is close enough to be a likely misspelling of it.
+/// Ties are broken in favor of the first candidate.
+pub(crate) fn closest_name(name: Symbol, candidates: impl IntoIterator<Item = Symbol>) -> Option<Symbol> {
+    let name_str = name.to_string();
+    let length = name_str.chars().count();
+    // Allow one edit for every three characters, and never as many edits as there are characters,
+    // so that short names are not matched to unrelated ones, e.g. `x` to `y`.
+    let max_distance = (length.max(3) / 3).min(length.saturating_sub(1));
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (edit_distance(&name_str, &candidate.to_string()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("amount", "amount"), 0);
+        assert_eq!(edit_distance("amount", "amonut"), 2);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_closest_name() {
+        create_session_if_not_set_then(|_| {
+            let candidates = ["balance", "owner", "amount", "amounts"].map(Symbol::intern);
+            assert_eq!(closest_name(Symbol::intern("amout"), candidates), Some(Symbol::intern("amount")));
+            assert_eq!(closest_name(Symbol::intern("ownr"), candidates), Some(Symbol::intern("owner")));
+            assert_eq!(closest_name(Symbol::intern("amount"), candidates), Some(Symbol::intern("amounts")));
+            assert_eq!(closest_name(Symbol::intern("x"), candidates), None);
+            assert_eq!(closest_name(Symbol::intern("x"), [Symbol::intern("y")]), None);
+            assert_eq!(closest_name(Symbol::intern("ab"), [Symbol::intern("a")]), Some(Symbol::intern("a")));
+            assert_eq!(closest_name(Symbol::intern("total"), candidates), None);
+        })
+    }
+}
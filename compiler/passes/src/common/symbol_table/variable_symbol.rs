@@ -18,7 +18,7 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
-use leo_ast::{Mode, Type};
+use leo_ast::{Expression, Mode, Type};
 use leo_span::Span;
 
 /// An enumeration of the different types of variable type.
@@ -50,11 +50,17 @@ pub struct VariableSymbol {
     pub span: Span,
     /// The type of declaration for the variable.
     pub declaration: VariableType,
+    /// The value of the variable, if it is a known constant, e.g. a `const` or the variable of an unrolled loop.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Expression>,
 }
 
 impl Display for VariableSymbol {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}: {}", self.declaration, self.type_)?;
+        if let Some(value) = &self.value {
+            write!(f, " = {value}")?;
+        }
         Ok(())
     }
 }
@@ -15,6 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::{NodeID, Type};
+use leo_span::{span::BytePos, Span};
 
 use indexmap::IndexMap;
 use std::cell::RefCell;
@@ -25,6 +26,9 @@ pub struct TypeTable {
     /// The inner table.
     /// `RefCell` is used here to avoid `&mut` all over the compiler.
     inner: RefCell<IndexMap<NodeID, Type>>,
+    /// The source spans of the entries, recorded by the type checker.
+    /// This side table allows tooling to look up the type of an expression by its position.
+    spans: RefCell<IndexMap<NodeID, Span>>,
 }
 
 impl TypeTable {
@@ -37,4 +41,56 @@ impl TypeTable {
     pub fn insert(&self, index: NodeID, value: Type) {
         self.inner.borrow_mut().insert(index, value);
     }
+
+    /// Records the span of an entry in the table.
+    pub fn insert_span(&self, index: NodeID, span: Span) {
+        self.spans.borrow_mut().insert(index, span);
+    }
+
+    /// Returns every entry in the table, with its span if it was recorded.
+    pub fn entries(&self) -> Vec<(NodeID, Type, Option<Span>)> {
+        let spans = self.spans.borrow();
+        self.inner.borrow().iter().map(|(id, type_)| (*id, type_.clone(), spans.get(id).copied())).collect()
+    }
+
+    /// Returns the span and type of every entry whose span was recorded.
+    pub fn spanned_types(&self) -> Vec<(Span, Type)> {
+        let inner = self.inner.borrow();
+        self.spans.borrow().iter().filter_map(|(id, span)| Some((*span, inner.get(id)?.clone()))).collect()
+    }
+
+    /// Returns the span and type of the innermost entry whose span contains the byte position `pos`, if one exists.
+    pub fn type_at(&self, pos: BytePos) -> Option<(Span, Type)> {
+        self.spanned_types()
+            .into_iter()
+            .filter(|(span, _)| span.lo <= pos && pos < span.hi)
+            .min_by_key(|(span, _)| span.hi - span.lo)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_ast::IntegerType;
+
+    #[test]
+    fn test_type_at() {
+        let span = |lo, hi| Span::new(BytePos(lo), BytePos(hi));
+        let table = TypeTable::default();
+        // `a + b` at 10..15, where `a` is at 10..11 and `b` is at 14..15.
+        table.insert(0, Type::Integer(IntegerType::U32));
+        table.insert_span(0, span(10, 15));
+        table.insert(1, Type::Integer(IntegerType::U32));
+        table.insert_span(1, span(10, 11));
+        table.insert(2, Type::Integer(IntegerType::U8));
+        table.insert_span(2, span(14, 15));
+        // An entry without a span is never found by position.
+        table.insert(3, Type::Boolean);
+
+        assert_eq!(table.type_at(BytePos(10)), Some((span(10, 11), Type::Integer(IntegerType::U32))));
+        assert_eq!(table.type_at(BytePos(12)), Some((span(10, 15), Type::Integer(IntegerType::U32))));
+        assert_eq!(table.type_at(BytePos(14)), Some((span(14, 15), Type::Integer(IntegerType::U8))));
+        assert_eq!(table.type_at(BytePos(15)), None);
+    }
 }
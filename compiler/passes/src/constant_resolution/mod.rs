@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Constant Resolution pass evaluates the constants declared in a program scope, and replaces the constants
+//! that give the lengths of array types, or the arguments of generic structs, with their values. It runs directly
+//! after parsing, so that the later passes only see array types of known lengths.
+//!
+//! The values of the constants are recorded in a [`ConstantEnvironment`], which the later passes read them from,
+//! rather than evaluating the constants again.
+//!
+//! A constant may be defined in terms of the constants declared before or after it, so the constants are evaluated
+//! in the order of their dependencies, and a cycle between them is reported as an error. An integer constant whose
+//! value is an expression of integer literals and other constants, e.g. `N * 2u32`, is replaced by a literal holding
+//! its value. Other values are left for the type checker, which requires the value of a constant to be a literal.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! program test.aleo {
+//!     const M: u32 = N * 2u32;
+//!     const N: u32 = 4u32;
+//!
+//!     transition main(a: [u8; M]) -> u8 {
+//!         let sum: u8 = 0u8;
+//!         for i: u32 in 0u32..N {
+//!             sum = sum + a[i];
+//!         }
+//!         return sum;
+//!     }
+//! }
+//! ```
+//!
+//! The constant resolution pass produces the following code.
+//! ```leo
+//! program test.aleo {
+//!     const M: u32 = 8u32;
+//!     const N: u32 = 4u32;
+//!
+//!     transition main(a: [u8; 8]) -> u8 {
+//!         let sum: u8 = 0u8;
+//!         for i: u32 in 0u32..N {
+//!             sum = sum + a[i];
+//!         }
+//!         return sum;
+//!     }
+//! }
+//! ```
+
+pub mod resolver;
+pub use resolver::*;
+
+use crate::{ConstantEnvironment, Pass};
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ConstantResolver<'a> {
+    type Input = (Ast, &'a Handler);
+    type Output = Result<(Ast, ConstantEnvironment)>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        // The parser recovers from some errors, which are reported by the later passes.
+        let err_count = handler.err_count();
+        let mut reconstructor = ConstantResolver::new(handler);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        if handler.err_count() > err_count {
+            handler.last_err().map_err(|e| *e)?;
+        }
+
+        Ok((Ast::new(program), reconstructor.environment))
+    }
+}
@@ -0,0 +1,426 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{integer_in_range, parse_integer_literal, ConstantEnvironment, DiGraph, DiGraphError};
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A compiler pass that evaluates the constants of each program scope and resolves the array lengths given by them.
+pub struct ConstantResolver<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The values of the constants of the current program scope that have been resolved, as written.
+    constants: IndexMap<Symbol, Expression>,
+    /// The values of the constants of the current program scope that are known at compile time.
+    pub(crate) environment: ConstantEnvironment,
+    /// The constant parameters of the generic struct being resolved, which are substituted by its instances.
+    parameters: IndexSet<Symbol>,
+}
+
+impl<'a> ConstantResolver<'a> {
+    pub fn new(handler: &'a Handler) -> Self {
+        Self {
+            handler,
+            constants: IndexMap::new(),
+            environment: ConstantEnvironment::default(),
+            parameters: IndexSet::new(),
+        }
+    }
+
+    /// Returns the names of the constants in the order they must be evaluated in, so that each constant is evaluated
+    /// after the constants its value and type refer to.
+    /// Emits an error and returns `None` if the constants form a cycle.
+    fn evaluation_order(&self, consts: &[(Symbol, ConstDeclaration)]) -> Option<IndexSet<Symbol>> {
+        let names = consts.iter().map(|(name, _)| *name).collect::<IndexSet<_>>();
+        let mut graph = DiGraph::new(names.clone());
+        for (name, declaration) in consts {
+            let mut references = ConstantReferences::default();
+            references.visit_expression(&declaration.value, &());
+            references.visit_type(&declaration.type_);
+            for reference in references.names.into_iter().filter(|reference| names.contains(reference)) {
+                graph.add_edge(*name, reference);
+            }
+        }
+        match graph.post_order() {
+            Ok(order) => Some(order),
+            Err(DiGraphError::CycleDetected(path)) => {
+                let span = consts.iter().find(|(name, _)| *name == path[0]).map_or_else(Default::default, |c| c.1.span);
+                self.handler.emit_err(TypeCheckerError::cyclic_constant_dependency(path, span));
+                None
+            }
+        }
+    }
+
+    /// Resolves the type of `declaration`, and replaces its value by a literal if it is an integer known at compile
+    /// time. The value is then recorded, so that the constants declared in terms of it can be resolved, and in the
+    /// environment, if it is known at compile time.
+    fn resolve_constant(&mut self, declaration: ConstDeclaration) -> ConstDeclaration {
+        let type_ = self.resolve_type(declaration.type_);
+        let value = match (&type_, &declaration.value) {
+            // Literals are kept as they are written.
+            (_, Expression::Literal(_)) => declaration.value,
+            (Type::Integer(integer_type), value) => match self.evaluate_integer(value, *integer_type) {
+                Some((integer_type, integer)) => {
+                    Expression::Literal(Literal::Integer(integer_type, integer.to_string(), value.span(), value.id()))
+                }
+                None => declaration.value,
+            },
+            _ => declaration.value,
+        };
+        if let Some(evaluated) = ConstantEnvironment::evaluate(&value, &type_) {
+            self.environment.insert(declaration.place.name, declaration.id, evaluated);
+        }
+        self.constants.insert(declaration.place.name, value.clone());
+        ConstDeclaration { type_, value, ..declaration }
+    }
+
+    /// Returns the type and value of `input` if it is an integer known at compile time, and in the range of its type.
+    /// Unsuffixed literals are given the type `expected`. Expressions that the type checker rejects, e.g. operations
+    /// on different integer types, or operations that overflow, are not evaluated.
+    fn evaluate_integer(&self, input: &Expression, expected: IntegerType) -> Option<(IntegerType, i128)> {
+        let (integer_type, value) = match input {
+            Expression::Literal(literal @ Literal::Integer(integer_type, ..)) => {
+                (*integer_type, parse_integer_literal(literal)?)
+            }
+            Expression::Literal(literal @ Literal::Unsuffixed(..)) => (expected, parse_integer_literal(literal)?),
+            Expression::Identifier(identifier) => self.environment.integer(identifier.name)?,
+            Expression::Unary(unary) => {
+                let (integer_type, receiver) = self.evaluate_integer(&unary.receiver, expected)?;
+                let value = match unary.op {
+                    UnaryOperation::Abs => receiver.checked_abs(),
+                    UnaryOperation::Negate => receiver.checked_neg(),
+                    _ => None,
+                };
+                (integer_type, value?)
+            }
+            Expression::Binary(binary) => {
+                let (integer_type, left) = self.evaluate_integer(&binary.left, expected)?;
+                let (right_type, right) = self.evaluate_integer(&binary.right, integer_type)?;
+                // The exponent of `**` may have a different type than the base.
+                if binary.op != BinaryOperation::Pow && right_type != integer_type {
+                    return None;
+                }
+                let value = match binary.op {
+                    BinaryOperation::Add => left.checked_add(right),
+                    BinaryOperation::Sub => left.checked_sub(right),
+                    BinaryOperation::Mul => left.checked_mul(right),
+                    BinaryOperation::Pow => u32::try_from(right).ok().and_then(|right| left.checked_pow(right)),
+                    BinaryOperation::Div if right != 0 => left.checked_div(right),
+                    BinaryOperation::Mod | BinaryOperation::Rem if right != 0 => left.checked_rem(right),
+                    _ => None,
+                };
+                (integer_type, value?)
+            }
+            _ => return None,
+        };
+        integer_in_range(value, integer_type).then_some((integer_type, value))
+    }
+
+    /// Returns `input` with the array lengths and the arguments of generic structs given by constants replaced by
+    /// their values.
+    fn resolve_type(&self, input: Type) -> Type {
+        match input {
+            Type::Array(array_type) => {
+                let element_type = self.resolve_type(array_type.element_type().clone());
+                let length = match array_type.array_length() {
+                    ConstArgument::Constant(constant) if self.parameters.contains(&constant.name) => {
+                        ConstArgument::Constant(*constant)
+                    }
+                    ConstArgument::Constant(constant) => match self.array_length(constant) {
+                        Some(length) => ConstArgument::Number(NonNegativeNumber::from(length)),
+                        None => ConstArgument::Constant(*constant),
+                    },
+                    length => length.clone(),
+                };
+                Type::Array(ArrayType::new(element_type, length))
+            }
+            // The arguments that are not integer constants are reported when the instance is specialized.
+            Type::Generic(generic_type) => Type::Generic(GenericType {
+                arguments: generic_type.arguments.into_iter().map(|argument| self.resolve_argument(argument)).collect(),
+                ..generic_type
+            }),
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(self.resolve_type(*mapping_type.key)),
+                value: Box::new(self.resolve_type(*mapping_type.value)),
+            }),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|t| self.resolve_type(t.clone())).collect(),
+            )),
+            type_ => type_,
+        }
+    }
+
+    /// Returns the value of `constant` as an array length, emitting an error if it is not one.
+    fn array_length(&self, constant: &Identifier) -> Option<usize> {
+        let Some(value) = self.constants.get(&constant.name) else {
+            self.handler.emit_err(TypeCheckerError::unknown_array_length(constant, constant.span));
+            return None;
+        };
+        let length = self.environment.array_length(constant.name);
+        if length.is_none() {
+            self.handler.emit_err(TypeCheckerError::invalid_array_length(constant, value, constant.span));
+        }
+        length
+    }
+
+    /// Returns `argument` with a constant replaced by its value, unless it is a parameter of the current struct.
+    fn resolve_argument(&self, argument: ConstArgument) -> ConstArgument {
+        match argument {
+            ConstArgument::Constant(constant) if !self.parameters.contains(&constant.name) => {
+                match self.environment.array_length(constant.name) {
+                    Some(value) => ConstArgument::Number(NonNegativeNumber::from(value)),
+                    None => argument,
+                }
+            }
+            argument => argument,
+        }
+    }
+
+    /// Returns `input` with the types of its inputs and outputs resolved.
+    fn resolve_input(&self, input: Input) -> Input {
+        match input {
+            Input::Internal(input) => Input::Internal(FunctionInput { type_: self.resolve_type(input.type_), ..input }),
+            input => input,
+        }
+    }
+
+    /// Returns `output` with its type resolved.
+    fn resolve_output(&self, output: Output) -> Output {
+        match output {
+            Output::Internal(output) => {
+                Output::Internal(FunctionOutput { type_: self.resolve_type(output.type_), ..output })
+            }
+            output => output,
+        }
+    }
+
+    /// Returns `member` with its type resolved.
+    fn resolve_member(&self, member: Member) -> Member {
+        Member { type_: self.resolve_type(member.type_), ..member }
+    }
+}
+
+/// Collects the names that an expression or a type refers to, which may be constants.
+#[derive(Default)]
+struct ConstantReferences {
+    names: Vec<Symbol>,
+}
+
+impl ConstantReferences {
+    /// Collects the constants giving the lengths of the array types, and the arguments of generic structs, in `type_`.
+    fn visit_type(&mut self, type_: &Type) {
+        match type_ {
+            Type::Array(array_type) => {
+                if let ConstArgument::Constant(constant) = array_type.array_length() {
+                    self.names.push(constant.name);
+                }
+                self.visit_type(array_type.element_type());
+            }
+            Type::Generic(generic_type) => {
+                for argument in &generic_type.arguments {
+                    if let ConstArgument::Constant(constant) = argument {
+                        self.names.push(constant.name);
+                    }
+                }
+            }
+            Type::Tuple(tuple_type) => tuple_type.elements().iter().for_each(|type_| self.visit_type(type_)),
+            _ => {}
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ConstantReferences {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.names.push(input.name);
+    }
+}
+
+impl ExpressionReconstructor for ConstantResolver<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_block_expression(&mut self, input: BlockExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Block(BlockExpression {
+                statements: input
+                    .statements
+                    .into_iter()
+                    .map(|statement| self.reconstruct_statement(statement).0)
+                    .collect(),
+                value: Box::new(self.reconstruct_expression(*input.value).0),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Cast(CastExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                type_: self.resolve_type(input.type_),
+                wrapped: input.wrapped,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// This pass runs directly after parsing, so the expressions the parser failed on are kept for later passes.
+    fn reconstruct_err(&mut self, input: ErrExpression) -> (Expression, Self::AdditionalOutput) {
+        (Expression::Err(input), Default::default())
+    }
+
+    /// Reconstructs a struct expression, resolving its const arguments.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                const_arguments: input
+                    .const_arguments
+                    .into_iter()
+                    .map(|argument| self.resolve_argument(argument))
+                    .collect(),
+                members: input.members.into_iter().map(|member| self.reconstruct_struct_member(member)).collect(),
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl StatementReconstructor for ConstantResolver<'_> {
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Const(ConstDeclaration {
+                type_: self.resolve_type(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_declaration(&mut self, input: DeclarationStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Declaration(DeclarationStatement { type_: self.resolve_type(input.type_), ..input }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Definition(DefinitionStatement {
+                type_: input.type_.map(|type_| self.resolve_type(type_)),
+                value: self.reconstruct_expression(input.value).0,
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+
+    /// The statements the parser failed on are kept for later passes, like the expressions.
+    fn reconstruct_err_statement(&mut self, input: ErrStatement) -> (Statement, Self::AdditionalOutput) {
+        (Statement::Err(input), Default::default())
+    }
+}
+
+impl ProgramReconstructor for ConstantResolver<'_> {
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        // The constants of each program scope are separate.
+        self.constants.clear();
+        self.environment.clear();
+        let Some(order) = self.evaluation_order(&input.consts) else {
+            return input;
+        };
+
+        // Resolve the constants in the order of their dependencies, and keep them in the order they are declared.
+        let mut consts = input.consts.into_iter().map(Some).collect::<Vec<_>>();
+        let mut resolved = consts.iter().map(|_| None).collect::<Vec<_>>();
+        for name in order {
+            for (index, entry) in consts.iter_mut().enumerate() {
+                if entry.as_ref().map_or(false, |(symbol, _)| *symbol == name) {
+                    let (symbol, declaration) = entry.take().unwrap();
+                    resolved[index] = Some((symbol, self.resolve_constant(declaration)));
+                }
+            }
+        }
+
+        ProgramScope {
+            program_id: input.program_id,
+            annotations: input.annotations,
+            modules: input.modules,
+            consts: resolved.into_iter().flatten().collect(),
+            interfaces: input.interfaces.into_iter().map(|(i, c)| (i, self.reconstruct_interface(c))).collect(),
+            structs: input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect(),
+            enums: input.enums.into_iter().map(|(i, c)| (i, self.reconstruct_enum(c))).collect(),
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
+            span: input.span,
+        }
+    }
+
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        Function {
+            input: input.input.into_iter().map(|input| self.resolve_input(input)).collect(),
+            output: input.output.into_iter().map(|output| self.resolve_output(output)).collect(),
+            output_type: self.resolve_type(input.output_type),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| Finalize {
+                input: finalize.input.into_iter().map(|input| self.resolve_input(input)).collect(),
+                output: finalize.output.into_iter().map(|output| self.resolve_output(output)).collect(),
+                output_type: self.resolve_type(finalize.output_type),
+                block: self.reconstruct_block(finalize.block).0,
+                ..finalize
+            }),
+            ..input
+        }
+    }
+
+    fn reconstruct_interface(&mut self, input: Interface) -> Interface {
+        Interface { members: input.members.into_iter().map(|member| self.resolve_member(member)).collect(), ..input }
+    }
+
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        self.parameters = input.const_parameters.iter().map(|parameter| parameter.name()).collect();
+        let struct_ = Struct {
+            members: input.members.into_iter().map(|member| self.resolve_member(member)).collect(),
+            methods: input
+                .methods
+                .into_iter()
+                .map(|(name, method)| (name, self.reconstruct_function(method)))
+                .collect(),
+            ..input
+        };
+        self.parameters.clear();
+        struct_
+    }
+
+    fn reconstruct_mapping(&mut self, input: Mapping) -> Mapping {
+        Mapping {
+            key_type: self.resolve_type(input.key_type),
+            value_type: self.resolve_type(input.value_type),
+            ..input
+        }
+    }
+}
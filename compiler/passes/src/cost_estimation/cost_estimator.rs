@@ -0,0 +1,317 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    binary_cost,
+    cast_cost,
+    core_function_cost,
+    type_size,
+    unary_cost,
+    ConstraintEstimate,
+    Cost,
+    FunctionEstimate,
+    TypeTable,
+};
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, CompilerError};
+use leo_span::{symbol::with_session_globals, Span, Symbol};
+
+use indexmap::IndexMap;
+use std::fmt::Write as _;
+
+pub struct CostEstimator<'a> {
+    /// An error handler used for any errors found during cost estimation.
+    handler: &'a Handler,
+    /// A mapping between node IDs and their types.
+    type_table: &'a TypeTable,
+    /// The maximum number of instructions a transition may execute, if any.
+    budget: Option<u64>,
+    /// The structs in the current program scope.
+    structs: IndexMap<Symbol, &'a Struct>,
+    /// The functions in the current program scope.
+    functions: IndexMap<Symbol, &'a Function>,
+    /// The estimated costs of the functions whose cost has been computed.
+    function_costs: IndexMap<Symbol, Cost>,
+    /// The estimated number of constraints of each function visited so far.
+    pub(crate) estimate: ConstraintEstimate,
+}
+
+impl<'a> CostEstimator<'a> {
+    /// Returns a new cost estimator given an error handler, a type table, and the budget.
+    pub fn new(handler: &'a Handler, type_table: &'a TypeTable, budget: Option<u64>) -> Self {
+        Self {
+            handler,
+            type_table,
+            budget,
+            structs: IndexMap::new(),
+            functions: IndexMap::new(),
+            function_costs: IndexMap::new(),
+            estimate: ConstraintEstimate::default(),
+        }
+    }
+
+    /// Returns the estimated cost of calling the function `name`, including the cost of its body.
+    fn function_cost(&mut self, name: Symbol) -> Cost {
+        if let Some(cost) = self.function_costs.get(&name) {
+            return *cost;
+        }
+        // Recursive calls are rejected by the type checker, so a function is never visited while it is being estimated.
+        self.function_costs.insert(name, Cost::default());
+        let cost = match self.functions.get(&name).copied() {
+            Some(function) => self.visit_block_cost(&function.block),
+            None => Cost::default(),
+        };
+        self.function_costs.insert(name, cost);
+        cost
+    }
+
+    /// Returns the type of an expression, or `Type::Err` if it is not in the type table.
+    fn type_of(&self, input: &Expression) -> Type {
+        self.type_table.get(&input.id()).unwrap_or(Type::Err)
+    }
+
+    /// Returns the number of field elements that represent a value of type `type_`.
+    fn size(&self, type_: &Type) -> u64 {
+        type_size(type_, &|name| match self.structs.get(&name) {
+            Some(struct_) => struct_.members.iter().map(|member| self.size(&member.type_)).sum(),
+            None => 0,
+        })
+    }
+
+    /// Returns the estimated cost of asserting that two values are equal or not equal.
+    fn assert_eq_cost(&mut self, left: &'a Expression, right: &'a Expression) -> Cost {
+        Cost::new(1, 2 * self.size(&self.type_of(left)))
+            + self.visit_expression(left, &())
+            + self.visit_expression(right, &())
+    }
+
+    /// Returns the estimated cost of executing a block.
+    fn visit_block_cost(&mut self, input: &'a Block) -> Cost {
+        input.statements.iter().map(|statement| self.visit_statement_cost(statement)).sum()
+    }
+
+    /// Returns the estimated cost of executing a statement.
+    fn visit_statement_cost(&mut self, input: &'a Statement) -> Cost {
+        match input {
+            Statement::Assert(assert) => match &assert.variant {
+                AssertVariant::Assert(expression) => Cost::new(1, 1) + self.visit_expression(expression, &()),
+                AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
+                    self.assert_eq_cost(left, right)
+                }
+            },
+            Statement::Assign(assign) => self.visit_expression(&assign.value, &()),
+            Statement::Block(block) => self.visit_block_cost(block),
+            Statement::Conditional(conditional) => {
+                // Both branches are counted, since either may be taken, and a circuit evaluates both of them.
+                self.visit_expression(&conditional.condition, &())
+                    + self.visit_block_cost(&conditional.then)
+                    + conditional
+                        .otherwise
+                        .as_ref()
+                        .map_or(Cost::default(), |otherwise| self.visit_statement_cost(otherwise))
+            }
+            Statement::Console(console) => match &console.function {
+                ConsoleFunction::Assert(expression) => Cost::new(1, 1) + self.visit_expression(expression, &()),
+                ConsoleFunction::AssertEq(left, right) | ConsoleFunction::AssertNeq(left, right) => {
+                    self.assert_eq_cost(left, right)
+                }
+                // Logs are not compiled.
+                ConsoleFunction::Log(_) => Cost::default(),
+            },
+            Statement::Const(_) | Statement::Declaration(_) | Statement::Err(_) => Cost::default(),
+            Statement::Definition(definition) => self.visit_expression(&definition.value, &()),
+            Statement::Expression(expression) => self.visit_expression(&expression.expression, &()),
+            Statement::Iteration(iteration) => self.visit_block_cost(&iteration.block),
+            Statement::Match(_) => {
+                unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.")
+            }
+            Statement::Return(return_) => {
+                let arguments = return_.finalize_arguments.iter().flatten();
+                self.visit_expression(&return_.expression, &())
+                    + arguments.map(|argument| self.visit_expression(argument, &())).sum::<Cost>()
+            }
+        }
+    }
+
+    /// Returns the span a statement's cost is attributed to.
+    /// Statements introduced by earlier passes have no span of their own, so their cost is attributed to their value.
+    fn statement_span(input: &Statement) -> Span {
+        let span = input.span();
+        match input {
+            Statement::Assign(assign) if span.is_dummy() => assign.value.span(),
+            Statement::Definition(definition) if span.is_dummy() => definition.value.span(),
+            _ => span,
+        }
+    }
+
+    /// Reports a transition whose `cost` exceeds the budget, along with the cost of each statement.
+    fn report_transition(&mut self, input: &'a Function, cost: u64, budget: u64) {
+        let mut breakdown: IndexMap<Span, u64> = IndexMap::new();
+        for statement in &input.block.statements {
+            let cost = self.visit_statement_cost(statement).instructions;
+            if cost > 0 {
+                *breakdown.entry(Self::statement_span(statement)).or_default() += cost;
+            }
+        }
+
+        // Statements introduced by earlier passes whose cost cannot be attributed to the source are listed last.
+        breakdown.sort_by(|left, _, right, _| (left.is_dummy(), left.lo).cmp(&(right.is_dummy(), right.lo)));
+        let mut lines = String::new();
+        for (span, cost) in breakdown {
+            let line = match span.is_dummy() {
+                true => format!("  generated code costs {cost}"),
+                false => {
+                    let location = with_session_globals(|s| s.source_map.span_to_location(span));
+                    let source = with_session_globals(|s| s.source_map.contents_of_span(span)).unwrap_or_default();
+                    let line = location.map_or(0, |location| location.line_start);
+                    let source = source.lines().next().unwrap_or_default().trim().to_string();
+                    format!("  line {line}: `{source}` costs {cost}")
+                }
+            };
+            writeln!(lines, "{line}").expect("writing to a string cannot fail");
+        }
+        self.handler.emit_err(CompilerError::transition_exceeds_instruction_budget(
+            input.identifier,
+            cost,
+            budget,
+            lines.trim_end(),
+            input.identifier.span,
+        ));
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for CostEstimator<'a> {
+    type AdditionalInput = ();
+    type Output = Cost;
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(access) => {
+                self.visit_expression(&access.array, additional) + self.visit_expression(&access.index, additional)
+            }
+            // Core functions, e.g. `BHP256::hash_to_field`, are a single instruction.
+            AccessExpression::AssociatedFunction(function) => {
+                let constraints = match &function.ty {
+                    Type::Identifier(module) => core_function_cost(module.name, function.name.name),
+                    _ => 0,
+                };
+                Cost::new(1, constraints)
+                    + function
+                        .arguments
+                        .iter()
+                        .map(|argument| self.visit_expression(argument, additional))
+                        .sum::<Cost>()
+            }
+            AccessExpression::Member(access) => self.visit_expression(&access.inner, additional),
+            AccessExpression::Tuple(access) => self.visit_expression(&access.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => Cost::default(),
+        }
+    }
+
+    fn visit_array(&mut self, input: &'a ArrayExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        Cost::new(1, 0) + input.elements.iter().map(|element| self.visit_expression(element, additional)).sum::<Cost>()
+    }
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        let operand = self.type_of(&input.left);
+        Cost::new(1, binary_cost(input.op, &operand, self.size(&operand)))
+            + self.visit_expression(&input.left, additional)
+            + self.visit_expression(&input.right, additional)
+    }
+
+    fn visit_block_expression(
+        &mut self,
+        input: &'a BlockExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        input.statements.iter().map(|statement| self.visit_statement_cost(statement)).sum::<Cost>()
+            + self.visit_expression(&input.value, additional)
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // The bodies of functions in other programs are not known and are proven separately,
+        // so only the `call` instruction is counted.
+        let body = match (&input.external, &*input.function) {
+            (None, Expression::Identifier(identifier)) => self.function_cost(identifier.name),
+            _ => Cost::default(),
+        };
+        Cost::new(1, 0)
+            + body
+            + input.arguments.iter().map(|argument| self.visit_expression(argument, additional)).sum::<Cost>()
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        Cost::new(1, cast_cost(&input.type_)) + self.visit_expression(&input.expression, additional)
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        Cost::new(1, 0)
+            + input
+                .members
+                .iter()
+                .filter_map(|member| member.expression.as_ref())
+                .map(|expression| self.visit_expression(expression, additional))
+                .sum::<Cost>()
+    }
+
+    fn visit_ternary(&mut self, input: &'a TernaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Selecting between the branches costs a constraint for each element of the value.
+        let type_ = self.type_table.get(&input.id).unwrap_or(Type::Err);
+        Cost::new(1, self.size(&type_))
+            + self.visit_expression(&input.condition, additional)
+            + self.visit_expression(&input.if_true, additional)
+            + self.visit_expression(&input.if_false, additional)
+    }
+
+    fn visit_tuple(&mut self, input: &'a TupleExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.elements.iter().map(|element| self.visit_expression(element, additional)).sum()
+    }
+
+    fn visit_unary(&mut self, input: &'a UnaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        Cost::new(1, unary_cost(input.op, &self.type_of(&input.receiver)))
+            + self.visit_expression(&input.receiver, additional)
+    }
+}
+
+impl<'a> StatementVisitor<'a> for CostEstimator<'a> {}
+
+impl<'a> ProgramVisitor<'a> for CostEstimator<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.structs = input.structs.iter().map(|(name, struct_)| (*name, struct_)).collect();
+        self.functions = input.functions.iter().map(|(name, function)| (*name, function)).collect();
+        self.function_costs.clear();
+
+        for (name, function) in &input.functions {
+            let cost = self.function_cost(*name);
+            let variant = match function.variant {
+                Variant::Inline => "inline",
+                Variant::Standard => "function",
+                Variant::Transition => "transition",
+            };
+            self.estimate.functions.push(FunctionEstimate {
+                function: name.to_string(),
+                variant: variant.to_string(),
+                constraints: cost.constraints,
+            });
+            if let (Variant::Transition, Some(budget)) = (function.variant, self.budget) {
+                if cost.instructions > budget {
+                    self.report_transition(function, cost.instructions, budget);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The estimated number of constraints of each operation.
+//! The costs are rough estimates of the circuits that snarkVM synthesizes, intended for comparing programs rather than
+//! predicting exact counts. Integers are decomposed into bits, so the cost of most integer operations grows with the
+//! width of the integer, while additions of field elements are linear and free.
+
+use leo_ast::{BinaryOperation, Type, UnaryOperation};
+use leo_span::Symbol;
+
+/// The number of bits of a field element.
+const FIELD_BITS: u64 = 253;
+/// The number of bits of a scalar.
+const SCALAR_BITS: u64 = 251;
+/// The cost of adding two group elements.
+const GROUP_ADDITION: u64 = 6;
+/// The cost of multiplying a group element by a scalar.
+const SCALAR_MULTIPLICATION: u64 = 4 * SCALAR_BITS;
+
+/// Returns the number of field elements that represent a value of type `type_`.
+/// The sizes of structs are given by `struct_size`, since the members of a struct are not part of its type.
+pub fn type_size(type_: &Type, struct_size: &dyn Fn(Symbol) -> u64) -> u64 {
+    match type_ {
+        Type::Address | Type::Boolean | Type::Field | Type::Integer(_) | Type::Scalar => 1,
+        // A group element is represented by its affine coordinates.
+        Type::Group => 2,
+        Type::Array(array) => array.length() as u64 * type_size(array.element_type(), struct_size),
+        Type::Tuple(tuple) => tuple.elements().iter().map(|element| type_size(element, struct_size)).sum(),
+        Type::Identifier(identifier) => struct_size(identifier.name),
+        _ => 0,
+    }
+}
+
+/// Returns the cost of the binary operation `op` on operands of type `operand`, where `size` is the size of an operand.
+pub fn binary_cost(op: BinaryOperation, operand: &Type, size: u64) -> u64 {
+    use BinaryOperation::*;
+    match (op, operand) {
+        // Checking equality requires an inverse for each element.
+        (Eq | Neq, _) => 2 * size,
+        (And | Or | Nand | Nor | Xor, Type::Boolean) => 1,
+        (Add | Sub, Type::Field) => 0,
+        (Mul, Type::Field) => 1,
+        (Div, Type::Field) => 2,
+        (Pow, Type::Field) => 2 * FIELD_BITS,
+        (Gt | Gte | Lt | Lte, Type::Field) => 2 * FIELD_BITS,
+        (Add | Sub, Type::Group) => GROUP_ADDITION,
+        (Mul, Type::Group | Type::Scalar) => SCALAR_MULTIPLICATION,
+        (Gt | Gte | Lt | Lte, Type::Scalar) => 2 * SCALAR_BITS,
+        (op, Type::Integer(integer_type)) => {
+            let bits = u64::from(integer_type.bit_width());
+            match op {
+                AddWrapped | SubWrapped | BitwiseAnd | BitwiseOr | Xor => bits,
+                // The checked operations also check for overflow.
+                Add | Sub | Gt | Gte | Lt | Lte => bits + 1,
+                MulWrapped | Shl | ShlWrapped | Shr | ShrWrapped => 2 * bits,
+                Mul => 3 * bits,
+                Div | DivWrapped | Rem | RemWrapped | Mod => 4 * bits,
+                Pow | PowWrapped => bits * bits,
+                _ => 0,
+            }
+        }
+        _ => 0,
+    }
+}
+
+/// Returns the cost of the unary operation `op` on an operand of type `operand`.
+pub fn unary_cost(op: UnaryOperation, operand: &Type) -> u64 {
+    use UnaryOperation::*;
+    match (op, operand) {
+        (Inverse | Square, Type::Field) => 1,
+        (SquareRoot, Type::Field) => FIELD_BITS,
+        (Double, Type::Group) => GROUP_ADDITION,
+        (op, Type::Integer(integer_type)) => {
+            let bits = u64::from(integer_type.bit_width());
+            match op {
+                AbsWrapped | Not => bits,
+                Abs | Negate => bits + 1,
+                _ => 0,
+            }
+        }
+        _ => 0,
+    }
+}
+
+/// Returns the cost of casting a value to the type `target`.
+pub fn cast_cost(target: &Type) -> u64 {
+    match target {
+        // The value is range checked by decomposing it into bits.
+        Type::Integer(integer_type) => u64::from(integer_type.bit_width()),
+        Type::Scalar => SCALAR_BITS,
+        // Recovering a group element from its x-coordinate requires a square root.
+        Type::Group => FIELD_BITS,
+        Type::Boolean => 1,
+        _ => 0,
+    }
+}
+
+/// Returns the cost of the core function `function` of the core module `module`, e.g. `BHP256::hash_to_field`.
+pub fn core_function_cost(module: Symbol, function: Symbol) -> u64 {
+    let (module, function) = (module.to_string(), function.to_string());
+    let cost = match module.as_str() {
+        "BHP256" => 500,
+        "BHP512" => 900,
+        "BHP768" => 1300,
+        "BHP1024" => 1700,
+        "Pedersen64" => 150,
+        "Pedersen128" => 300,
+        "Poseidon2" => 250,
+        "Poseidon4" => 350,
+        "Poseidon8" => 550,
+        "Keccak256" | "Keccak384" | "Keccak512" | "SHA3_256" | "SHA3_384" | "SHA3_512" => 150_000,
+        "signature" => 4 * SCALAR_MULTIPLICATION,
+        // Recovering a group element from its x-coordinate requires a square root.
+        "address" if function == "from_field" => FIELD_BITS,
+        // The functions of the other modules, e.g. `Mapping` and `ChaCha`, are executed on-chain rather than proven.
+        _ => 0,
+    };
+    // A commitment also hashes its randomizer.
+    match function.starts_with("commit") {
+        true => cost + SCALAR_MULTIPLICATION,
+        false => cost,
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::{Deserialize, Serialize};
+use std::{fmt, iter::Sum, ops::Add};
+
+/// The estimated cost of executing a piece of code.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Cost {
+    /// The number of instructions generated for the code.
+    pub instructions: u64,
+    /// The number of constraints of the circuit that proves the code.
+    pub constraints: u64,
+}
+
+impl Cost {
+    /// Returns the cost of `instructions` instructions with `constraints` constraints.
+    pub fn new(instructions: u64, constraints: u64) -> Self {
+        Self { instructions, constraints }
+    }
+}
+
+impl Add for Cost {
+    type Output = Cost;
+
+    fn add(self, other: Cost) -> Cost {
+        Cost::new(self.instructions + other.instructions, self.constraints + other.constraints)
+    }
+}
+
+impl Sum for Cost {
+    fn sum<I: Iterator<Item = Cost>>(iter: I) -> Cost {
+        iter.fold(Cost::default(), Add::add)
+    }
+}
+
+/// The estimated number of constraints of a function.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionEstimate {
+    /// The name of the function, e.g. `main`, or the mangled name of a struct method.
+    pub function: String,
+    /// The variant of the function, e.g. `transition`.
+    pub variant: String,
+    /// The estimated number of constraints of the function, including those of the functions it calls.
+    pub constraints: u64,
+}
+
+/// The estimated number of constraints of each function of a program, in the order of the functions in the program.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConstraintEstimate {
+    pub functions: Vec<FunctionEstimate>,
+}
+
+impl ConstraintEstimate {
+    /// Returns the estimate of the function `name`, if the program has a function of that name.
+    pub fn function(&self, name: &str) -> Option<&FunctionEstimate> {
+        self.functions.iter().find(|estimate| estimate.function == name)
+    }
+}
+
+impl fmt::Display for ConstraintEstimate {
+    /// Formats the estimate as a table, with a row for each function.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width =
+            self.functions.iter().map(|estimate| estimate.function.len()).chain(["function".len()]).max().unwrap();
+        write!(f, "{:width$}  {:10}  {:>11}", "function", "variant", "constraints")?;
+        for estimate in &self.functions {
+            write!(f, "\n{:width$}  {:10}  {:>11}", estimate.function, estimate.variant, estimate.constraints)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Cost Estimation pass estimates the number of instructions and the number of R1CS constraints of each function,
+//! so that expensive code can be found before the program is proven.
+//! It reports the transitions whose number of instructions exceeds the configured budget,
+//! and returns the number of constraints of each function.
+//! The pass is run after dead code elimination, so that the estimate reflects the generated code.
+//!
+//! The cost of a function is the cost of the instructions generated for its body,
+//! where a call to a function also incurs the cost of the function's body.
+//! The number of constraints of an instruction is taken from a table of costs for the operations on each type and
+//! for the core functions, e.g. `BHP256::hash_to_field`.
+//! Finalize blocks are not included, since they are executed on-chain rather than proven.
+//! The costs are rough estimates, intended for comparing programs rather than predicting exact counts.
+//!
+//! Consider the following Leo code, compiled with a budget of 2 instructions.
+//! ```leo
+//! transition main(a: u8, b: u8) -> u8 {
+//!     let c: u8 = a + b;
+//!     let d: u8 = c * c;
+//!     return d - a;
+//! }
+//! ```
+//!
+//! The pass reports that `main` executes 3 instructions, along with the cost of each statement.
+//! It estimates that `main` has 42 constraints: 9 for each checked addition and subtraction of `u8`s,
+//! and 24 for the checked multiplication.
+
+pub mod cost_estimator;
+pub use cost_estimator::*;
+
+pub mod cost_table;
+pub use cost_table::*;
+
+pub mod estimate;
+pub use estimate::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for CostEstimator<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a TypeTable, Option<u64>);
+    type Output = Result<ConstraintEstimate>;
+
+    fn do_pass((ast, handler, type_table, budget): Self::Input) -> Self::Output {
+        let mut visitor = CostEstimator::new(handler, type_table, budget);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)?;
+        Ok(visitor.estimate)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::{parse_ast, type_check};
+
+    use leo_span::{Symbol, symbol::create_session_if_not_set_then};
+
+    fn estimate(program: &str) -> ConstraintEstimate {
+        let ast = parse_ast(program);
+        let type_table = TypeTable::default();
+        type_check(&ast, &type_table);
+        CostEstimator::do_pass((&ast, &Handler::default(), &type_table, None)).unwrap()
+    }
+
+    #[test]
+    fn test_constraint_estimation() {
+        create_session_if_not_set_then(|_| {
+            let estimate = estimate(
+                "program test.aleo {
+    function square(a: field) -> field {
+        return a * a;
+    }
+    transition main(a: u8, b: field) -> field {
+        let c: u8 = a + 1u8;
+        let d: field = b + b;
+        return square(square(d));
+    }
+    transition hash(a: field) -> field {
+        return BHP256::hash_to_field(a);
+    }
+}
+",
+            );
+
+            assert_eq!(estimate.function("square").unwrap().constraints, 1);
+            // The checked addition of `u8`s, and two calls to `square`. The addition of field elements is free.
+            assert_eq!(estimate.function("main").unwrap().constraints, 9 + 2);
+            assert_eq!(estimate.function("main").unwrap().variant, "transition");
+            let hash_cost = core_function_cost(Symbol::intern("BHP256"), Symbol::intern("hash_to_field"));
+            assert_eq!(estimate.function("hash").unwrap().constraints, hash_cost);
+            let table = estimate.to_string();
+            let header = "function  variant     constraints";
+            assert!(table.starts_with(&format!("{header}\nsquare    function              1")), "{table}");
+        })
+    }
+}
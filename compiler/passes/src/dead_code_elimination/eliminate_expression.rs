@@ -63,6 +63,7 @@ impl ExpressionReconstructor for DeadCodeEliminator<'_> {
         (
             Expression::Struct(StructExpression {
                 name: input.name,
+                const_arguments: input.const_arguments,
                 // Reconstruct each of the struct members.
                 members: input
                     .members
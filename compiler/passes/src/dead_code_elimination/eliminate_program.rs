@@ -28,28 +28,13 @@ impl ProgramReconstructor for DeadCodeEliminator<'_> {
         let block = self.reconstruct_block(input.block).0;
 
         // Reconstruct the finalize block, if it exists.
-        let finalize = input.finalize.map(|finalize| {
-            // Reset the state of the dead code eliminator.
-            self.used_variables.clear();
-            self.is_necessary = false;
-
-            // Traverse the finalize block.
-            let block = self.reconstruct_block(finalize.block).0;
-
-            Finalize {
-                identifier: finalize.identifier,
-                input: finalize.input,
-                output: finalize.output,
-                output_type: finalize.output_type,
-                block,
-                span: finalize.span,
-                id: finalize.id,
-            }
-        });
+        let finalize = input.finalize.map(|finalize| self.reconstruct_finalize(finalize));
 
         Function {
+            doc: input.doc,
             annotations: input.annotations,
             variant: input.variant,
+            visibility: input.visibility,
             identifier: input.identifier,
             input: input.input,
             output: input.output,
@@ -60,4 +45,23 @@ impl ProgramReconstructor for DeadCodeEliminator<'_> {
             id: input.id,
         }
     }
+
+    fn reconstruct_finalize(&mut self, input: Finalize) -> Finalize {
+        // Reset the state of the dead code eliminator.
+        self.used_variables.clear();
+        self.is_necessary = false;
+
+        // Traverse the finalize block.
+        let block = self.reconstruct_block(input.block).0;
+
+        Finalize {
+            identifier: input.identifier,
+            input: input.input,
+            output: input.output,
+            output_type: input.output_type,
+            block,
+            span: input.span,
+            id: input.id,
+        }
+    }
 }
@@ -113,7 +113,7 @@ impl StatementReconstructor for DeadCodeEliminator<'_> {
         // Reverse the direction of `statements`.
         statements.reverse();
 
-        (Block { statements, span: block.span, id: block.id }, Default::default())
+        (Block { annotations: Vec::new(), statements, span: block.span, id: block.id }, Default::default())
     }
 
     /// Flattening removes conditional statements from the program.
@@ -121,7 +121,7 @@ impl StatementReconstructor for DeadCodeEliminator<'_> {
         unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
     }
 
-    /// Parsing guarantees that console statements are not present in the program.
+    /// Static single assignment removes console statements.
     fn reconstruct_console(&mut self, _: ConsoleStatement) -> (Statement, Self::AdditionalOutput) {
         unreachable!("`ConsoleStatement`s should not be in the AST at this phase of compilation.")
     }
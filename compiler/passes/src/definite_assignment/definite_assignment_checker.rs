@@ -0,0 +1,175 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+pub struct DefiniteAssignmentChecker<'a> {
+    /// An error handler used for any errors found during definite assignment checking.
+    handler: &'a Handler,
+    /// The variables that are declared but may not be assigned on the current path.
+    unassigned: IndexSet<Symbol>,
+    /// Whether the current path has returned.
+    has_return: bool,
+}
+
+impl<'a> DefiniteAssignmentChecker<'a> {
+    /// Returns a new definite assignment checker given an error handler.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler, unassigned: Default::default(), has_return: false }
+    }
+
+    /// Checks a branch from the current state, returning the unassigned variables at its end and whether it returns.
+    /// The current state is left unchanged.
+    fn check_branch(&mut self, check: impl FnOnce(&mut Self)) -> (IndexSet<Symbol>, bool) {
+        let unassigned = self.unassigned.clone();
+        let has_return = self.has_return;
+        check(self);
+        let branch = (core::mem::replace(&mut self.unassigned, unassigned), self.has_return);
+        self.has_return = has_return;
+        branch
+    }
+
+    /// Checks the body of a function or finalize block.
+    fn check_body(&mut self, input: &'a Block) {
+        self.unassigned.clear();
+        self.has_return = false;
+        self.visit_block(input);
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for DefiniteAssignmentChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_block_expression(
+        &mut self,
+        input: &'a BlockExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.visit_expression(&input.value, additional)
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // The receiver of a method call is a part of the function.
+        self.visit_expression(&input.function, additional);
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        for member in &input.members {
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                // A shorthand initializer `Foo { x }` reads the variable `x`.
+                None => self.visit_identifier(&member.identifier, additional),
+            }
+        }
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        if self.unassigned.contains(&input.name) {
+            self.handler.emit_err(TypeCheckerError::use_of_unassigned_variable(input.name, input.span));
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for DefiniteAssignmentChecker<'a> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.value, &());
+        match &input.place {
+            Expression::Identifier(identifier) => {
+                self.unassigned.swap_remove(&identifier.name);
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| match element {
+                Expression::Identifier(identifier) => {
+                    self.unassigned.swap_remove(&identifier.name);
+                }
+                element => self.visit_expression(element, &()),
+            }),
+            place => self.visit_expression(place, &()),
+        }
+    }
+
+    fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
+        self.visit_expression(&input.condition, &());
+
+        let (then_unassigned, then_returns) = self.check_branch(|checker| checker.visit_block(&input.then));
+        let (otherwise_unassigned, otherwise_returns) = match &input.otherwise {
+            Some(otherwise) => self.check_branch(|checker| checker.visit_statement(otherwise)),
+            None => (self.unassigned.clone(), false),
+        };
+
+        // Only the branches that do not return reach the end of the conditional.
+        self.unassigned = match (then_returns, otherwise_returns) {
+            (true, false) => otherwise_unassigned,
+            (false, true) => then_unassigned,
+            _ => then_unassigned.union(&otherwise_unassigned).copied().collect(),
+        };
+        self.has_return = then_returns && otherwise_returns;
+    }
+
+    fn visit_declaration(&mut self, input: &'a DeclarationStatement) {
+        self.unassigned.insert(input.identifier.name);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.visit_expression(&input.value, &());
+        // A definition may reuse the name of a variable declared in a sibling scope.
+        match &input.place {
+            Expression::Identifier(identifier) => {
+                self.unassigned.swap_remove(&identifier.name);
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| {
+                if let Expression::Identifier(identifier) = element {
+                    self.unassigned.swap_remove(&identifier.name);
+                }
+            }),
+            _ => {}
+        }
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.visit_expression(&input.start, &());
+        self.visit_expression(&input.stop, &());
+        // The body of the loop may not be executed.
+        self.check_branch(|checker| checker.visit_block(&input.block));
+    }
+
+    fn visit_return(&mut self, input: &'a ReturnStatement) {
+        self.visit_expression(&input.expression, &());
+        if let Some(arguments) = &input.finalize_arguments {
+            arguments.iter().for_each(|argument| self.visit_expression(argument, &()));
+        }
+        self.has_return = true;
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for DefiniteAssignmentChecker<'a> {
+    fn visit_struct(&mut self, input: &'a Struct) {
+        input.methods.iter().for_each(|(_, method)| self.visit_function(method));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.check_body(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.check_body(&finalize.block);
+        }
+    }
+}
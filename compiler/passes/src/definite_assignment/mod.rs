@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Definite Assignment pass checks that every variable declared without a value, e.g. `let x: u32;`,
+//! is assigned on every path through the program before it is used.
+//! Each branch of a conditional statement is checked from a snapshot of the variables that are unassigned before it,
+//! and a variable is unassigned after the conditional if it is unassigned at the end of either branch.
+//! A branch that returns does not reach the end of the conditional, so it is ignored.
+//! Since a loop may not execute, the assignments in its body are ignored after the loop.
+//! The pass is run after type checking, so the program is known to be well-typed.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(flag: bool) -> u8 {
+//!     let x: u8;
+//!     if flag {
+//!         x = 1u8;
+//!     }
+//!     return x;
+//! }
+//! ```
+//!
+//! The pass reports that `x` is used before it is assigned, since `x` is unassigned if `flag` is false.
+
+pub mod definite_assignment_checker;
+pub use definite_assignment_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for DefiniteAssignmentChecker<'a> {
+    type Input = (&'a Ast, &'a Handler);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut visitor = DefiniteAssignmentChecker::new(handler);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)
+    }
+}
@@ -17,6 +17,7 @@
 use crate::Destructurer;
 
 use leo_ast::{
+    AccessExpression,
     AssignStatement,
     Block,
     ConditionalStatement,
@@ -64,7 +65,11 @@ impl StatementReconstructor for Destructurer<'_> {
                 (Statement::dummy(Default::default(), self.node_builder.next_id()), Default::default())
             }
             // If the lhs is an identifier and the rhs is a function call that produces a tuple, then add it to `self.tuples`.
-            (Expression::Identifier(lhs_identifier), Expression::Call(call)) => {
+            // Note that core functions may also produce tuples.
+            (
+                Expression::Identifier(lhs_identifier),
+                call @ (Expression::Call(_) | Expression::Access(AccessExpression::AssociatedFunction(_))),
+            ) => {
                 // Retrieve the entry in the type table for the function call.
                 let value_type = match self.type_table.get(&call.id()) {
                     Some(type_) => type_,
@@ -111,7 +116,7 @@ impl StatementReconstructor for Destructurer<'_> {
                         (
                             Statement::Assign(Box::new(AssignStatement {
                                 place: Expression::Tuple(tuple_expression),
-                                value: Expression::Call(call),
+                                value: call,
                                 span: Default::default(),
                                 id: self.node_builder.next_id(),
                             })),
@@ -119,17 +124,20 @@ impl StatementReconstructor for Destructurer<'_> {
                         )
                     }
                     // Otherwise, reconstruct the assignment as is.
-                    _ => (self.simple_assign_statement(lhs_identifier, Expression::Call(call)), Default::default()),
+                    _ => (self.simple_assign_statement(lhs_identifier, call), Default::default()),
                 }
             }
             (Expression::Identifier(identifier), expression) => {
                 (self.simple_assign_statement(identifier, expression), Default::default())
             }
             // If the lhs is a tuple and the rhs is a function call, then return the reconstructed statement.
-            (Expression::Tuple(tuple), Expression::Call(call)) => (
+            (
+                Expression::Tuple(tuple),
+                call @ (Expression::Call(_) | Expression::Access(AccessExpression::AssociatedFunction(_))),
+            ) => (
                 Statement::Assign(Box::new(AssignStatement {
                     place: Expression::Tuple(tuple),
-                    value: Expression::Call(call),
+                    value: call,
                     span: Default::default(),
                     id: self.node_builder.next_id(),
                 })),
@@ -217,7 +225,10 @@ impl StatementReconstructor for Destructurer<'_> {
             statements.push(reconstructed_statement);
         }
 
-        (Block { span: block.span, statements, id: self.node_builder.next_id() }, Default::default())
+        (
+            Block { annotations: Vec::new(), span: block.span, statements, id: self.node_builder.next_id() },
+            Default::default(),
+        )
     }
 
     fn reconstruct_conditional(&mut self, _: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An edition of the Leo language, which a program targets.
+/// Each edition adds features to the previous one, so a program may use the features of its edition and earlier ones.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Edition {
+    /// The first edition, with transitions, functions, structs, and records.
+    #[serde(rename = "2022")]
+    Edition2022,
+    /// Adds finalize blocks and mappings, which update on-chain state.
+    #[serde(rename = "2023")]
+    Edition2023,
+    /// Adds const generics, e.g. `struct Matrix<const N: u32>`.
+    #[default]
+    #[serde(rename = "2024")]
+    Edition2024,
+}
+
+impl fmt::Display for Edition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Edition2022 => write!(f, "2022"),
+            Self::Edition2023 => write!(f, "2023"),
+            Self::Edition2024 => write!(f, "2024"),
+        }
+    }
+}
+
+/// A feature of the language that was added after the first edition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Feature {
+    /// A finalize block of a transition, e.g. `finalize main(a: u8) { ... }`.
+    Finalize,
+    /// A mapping declaration, e.g. `mapping balances: address => u64;`.
+    Mapping,
+    /// A const parameter of a struct, e.g. `const N: u32` in `struct Matrix<const N: u32>`.
+    ConstGenerics,
+}
+
+impl Feature {
+    /// Returns the first edition with the feature.
+    pub fn edition(self) -> Edition {
+        match self {
+            Self::Finalize | Self::Mapping => Edition::Edition2023,
+            Self::ConstGenerics => Edition::Edition2024,
+        }
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Finalize => write!(f, "finalize"),
+            Self::Mapping => write!(f, "mapping"),
+            Self::ConstGenerics => write!(f, "const generics"),
+        }
+    }
+}
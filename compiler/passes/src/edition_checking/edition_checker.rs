@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Edition, Feature};
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, CompilerError};
+use leo_span::Span;
+
+pub struct EditionChecker<'a> {
+    /// An error handler used for any errors found during edition checking.
+    handler: &'a Handler,
+    /// The edition that the program targets.
+    edition: Edition,
+}
+
+impl<'a> EditionChecker<'a> {
+    /// Returns a new edition checker given an error handler and the edition that the program targets.
+    pub fn new(handler: &'a Handler, edition: Edition) -> Self {
+        Self { handler, edition }
+    }
+
+    /// Emits an error if `feature`, used at `span`, is newer than the edition that the program targets.
+    fn check_feature(&self, feature: Feature, span: Span) {
+        if feature.edition() > self.edition {
+            self.handler.emit_err(CompilerError::feature_requires_edition(
+                feature,
+                feature.edition(),
+                self.edition,
+                span,
+            ));
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for EditionChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for EditionChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for EditionChecker<'a> {
+    fn visit_struct(&mut self, input: &'a Struct) {
+        if let Some(parameter) = input.const_parameters.first() {
+            self.check_feature(Feature::ConstGenerics, parameter.span);
+        }
+    }
+
+    fn visit_mapping(&mut self, input: &'a Mapping) {
+        self.check_feature(Feature::Mapping, input.span);
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        if let Some(finalize) = &input.finalize {
+            self.check_feature(Feature::Finalize, finalize.identifier.span);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Edition Checking pass reports the features of the language that are newer than the edition that a program
+//! targets, which is set by the `language_edition` build option. Each edition adds features to the previous one:
+//! - `2022` has transitions, functions, structs, and records,
+//! - `2023` adds finalize blocks and mappings, and
+//! - `2024` adds const generics.
+//!
+//! The pass runs directly after parsing, so that a program using a feature that is too new is rejected with the
+//! edition the feature requires, rather than an error of a later pass. Imported programs are checked as well.
+//!
+//! Consider the following Leo code, built for edition `2022`.
+//! ```leo
+//! program test.aleo {
+//!     mapping balances: address => u64;
+//! }
+//! ```
+//!
+//! The pass reports that the feature `mapping` requires edition `2023`.
+
+pub mod edition;
+pub use edition::*;
+
+pub mod edition_checker;
+pub use edition_checker::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for EditionChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, Edition);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, edition): Self::Input) -> Self::Output {
+        // The parser recovers from some errors, which are reported by the later passes.
+        let err_count = handler.err_count();
+        let mut visitor = EditionChecker::new(handler, edition);
+        visitor.visit_program(ast.as_repr());
+        if handler.err_count() > err_count {
+            handler.last_err().map_err(|e| *e)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Entry Point Validation pass checks the signatures of the transitions of a program, which are its entry points.
+//! A program must declare at least one transition. The inputs and outputs of a transition cross the boundary of the
+//! program, so they cannot be strings or the unit type, and records cannot be annotated with a mode, since their
+//! visibility is determined by their owner. The remaining modes are checked by the type checker.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! program test.aleo {
+//!     record Token { owner: address, amount: u64 }
+//!
+//!     transition mint(public token: Token) -> Token {
+//!         return token;
+//!     }
+//! }
+//! ```
+//!
+//! The pass reports that the record input `token` cannot be annotated with the mode `public`.
+
+pub mod validator;
+pub use validator::*;
+
+use crate::{Pass, SymbolTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for EntryPointValidator<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a SymbolTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, symbol_table): Self::Input) -> Self::Output {
+        let mut visitor = EntryPointValidator::new(handler, symbol_table);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)
+    }
+}
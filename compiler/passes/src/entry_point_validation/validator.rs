@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::SymbolTable;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_span::Span;
+
+pub struct EntryPointValidator<'a> {
+    /// An error handler used for any errors found during entry point validation.
+    handler: &'a Handler,
+    /// The symbol table of the type-checked program.
+    symbol_table: &'a SymbolTable,
+}
+
+impl<'a> EntryPointValidator<'a> {
+    /// Returns a new entry point validator given an error handler and the symbol table.
+    pub fn new(handler: &'a Handler, symbol_table: &'a SymbolTable) -> Self {
+        Self { handler, symbol_table }
+    }
+
+    /// Returns `true` if `type_` is a record of the program.
+    fn is_record(&self, type_: &Type) -> bool {
+        match type_ {
+            Type::Identifier(identifier) => {
+                self.symbol_table.lookup_struct(identifier.name).map_or(false, |struct_| struct_.is_record)
+            }
+            _ => false,
+        }
+    }
+
+    /// Emits an error if an input or output of a transition cannot cross the boundary of the program.
+    /// The visibility of a record is determined by its owner, so records cannot be annotated with a mode.
+    fn check_parameter(&self, kind: &str, mode: Mode, type_: &Type, span: Span) {
+        match type_ {
+            Type::Unit | Type::String => {
+                self.handler.emit_err(TypeCheckerError::invalid_entry_point_type(kind, type_, span));
+            }
+            _ if mode != Mode::None && self.is_record(type_) => {
+                self.handler.emit_err(TypeCheckerError::entry_point_record_cannot_have_mode(kind, mode, span));
+            }
+            _ => {}
+        }
+    }
+}
+
+// Only the signatures of the transitions are validated, so their bodies are not visited.
+impl<'a> ExpressionVisitor<'a> for EntryPointValidator<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for EntryPointValidator<'a> {}
+
+impl<'a> ProgramVisitor<'a> for EntryPointValidator<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        // The transitions of a program are its entry points, so a program without transitions cannot be executed.
+        let entry_points = input.functions.iter().filter(|(_, function)| function.variant == Variant::Transition);
+        if entry_points.clone().next().is_none() {
+            let span = input.program_id.name.span + input.program_id.network.span;
+            self.handler.emit_err(TypeCheckerError::program_has_no_entry_point(input.program_id, span));
+        }
+
+        entry_points.for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        for function_input in &input.input {
+            // The inputs of external records are checked when their program is compiled.
+            if let functions::Input::Internal(function_input) = function_input {
+                self.check_parameter("input", function_input.mode, &function_input.type_, function_input.span);
+            }
+        }
+        for output in &input.output {
+            if let Output::Internal(output) = output {
+                self.check_parameter("output", output.mode, &output.type_, output.span);
+            }
+        }
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{FunctionSymbol, TypeTable};
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+pub struct EnumLowerer<'a> {
+    /// The enums of the program, by name.
+    enums: IndexMap<Symbol, Enum>,
+    /// A mapping between node IDs and their types.
+    type_table: &'a TypeTable,
+}
+
+impl<'a> EnumLowerer<'a> {
+    pub(crate) fn new(enums: IndexMap<Symbol, Enum>, type_table: &'a TypeTable) -> Self {
+        Self { enums, type_table }
+    }
+
+    /// Lowers the types of the expressions, e.g. so that a ternary of variants is not mistaken for one of structs.
+    pub(crate) fn lower_type_table(&self) {
+        for (id, type_, _) in self.type_table.entries() {
+            let lowered = self.lower_type(type_.clone());
+            if lowered != type_ {
+                self.type_table.insert(id, lowered);
+            }
+        }
+    }
+
+    /// Returns `type_` with each enum type replaced by the integer type that represents it.
+    pub(crate) fn lower_type(&self, type_: Type) -> Type {
+        match type_ {
+            Type::Identifier(identifier) if self.enums.contains_key(&identifier.name) => {
+                Type::Integer(Enum::REPRESENTATION)
+            }
+            Type::Array(array_type) => {
+                let element_type = self.lower_type(array_type.element_type().clone());
+                Type::Array(ArrayType::new(element_type, array_type.array_length().clone()))
+            }
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(self.lower_type(*mapping_type.key)),
+                value: Box::new(self.lower_type(*mapping_type.value)),
+            }),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|type_| self.lower_type(type_.clone())).collect(),
+            )),
+            type_ => type_,
+        }
+    }
+
+    /// Returns `input` with its type lowered.
+    fn lower_input(&self, input: Input) -> Input {
+        match input {
+            Input::Internal(input) => Input::Internal(FunctionInput { type_: self.lower_type(input.type_), ..input }),
+            input => input,
+        }
+    }
+
+    /// Returns `output` with its type lowered.
+    fn lower_output(&self, output: Output) -> Output {
+        match output {
+            Output::Internal(output) => {
+                Output::Internal(FunctionOutput { type_: self.lower_type(output.type_), ..output })
+            }
+            output => output,
+        }
+    }
+
+    /// Returns `member` with its type lowered.
+    pub(crate) fn lower_member(&self, member: Member) -> Member {
+        Member { type_: self.lower_type(member.type_), ..member }
+    }
+
+    /// Lowers the types in the signature of `function`, and of its finalize block.
+    pub(crate) fn lower_function_symbol(&self, function: &mut FunctionSymbol) {
+        function.output_type = self.lower_type(function.output_type.clone());
+        function.input = function.input.drain(..).map(|input| self.lower_input(input)).collect();
+        if let Some(finalize) = &mut function.finalize {
+            finalize.output_type = self.lower_type(finalize.output_type.clone());
+            finalize.input = finalize.input.drain(..).map(|input| self.lower_input(input)).collect();
+        }
+    }
+}
+
+impl<'a> ExpressionReconstructor for EnumLowerer<'a> {
+    type AdditionalOutput = ();
+
+    /// Lowers a variant of an enum, e.g. `Color::Green`, into its index, e.g. `1u8`.
+    fn reconstruct_associated_constant(&mut self, input: AssociatedConstant) -> (Expression, Self::AdditionalOutput) {
+        let index = match &input.ty {
+            Type::Identifier(identifier) => {
+                self.enums.get(&identifier.name).and_then(|enum_| enum_.variant_index(input.name.name))
+            }
+            _ => None,
+        };
+        match index {
+            Some(index) => (
+                Expression::Literal(Literal::Integer(Enum::REPRESENTATION, index.to_string(), input.span, input.id)),
+                Default::default(),
+            ),
+            None => (Expression::Access(AccessExpression::AssociatedConstant(input)), Default::default()),
+        }
+    }
+
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Cast(CastExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                type_: self.lower_type(input.type_),
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl<'a> StatementReconstructor for EnumLowerer<'a> {
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Const(ConstDeclaration {
+                type_: self.lower_type(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_declaration(&mut self, input: DeclarationStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Declaration(DeclarationStatement { type_: self.lower_type(input.type_), ..input }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Definition(DefinitionStatement {
+                type_: input.type_.map(|type_| self.lower_type(type_)),
+                value: self.reconstruct_expression(input.value).0,
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl<'a> ProgramReconstructor for EnumLowerer<'a> {
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        ProgramScope {
+            interfaces: input.interfaces.into_iter().map(|(i, c)| (i, self.reconstruct_interface(c))).collect(),
+            structs: input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect(),
+            // The enums are not needed once their variants and types are lowered.
+            enums: Vec::new(),
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            ..input
+        }
+    }
+
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        Function {
+            input: input.input.into_iter().map(|input| self.lower_input(input)).collect(),
+            output: input.output.into_iter().map(|output| self.lower_output(output)).collect(),
+            output_type: self.lower_type(input.output_type),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| self.reconstruct_finalize(finalize)),
+            ..input
+        }
+    }
+
+    fn reconstruct_finalize(&mut self, input: Finalize) -> Finalize {
+        Finalize {
+            input: input.input.into_iter().map(|input| self.lower_input(input)).collect(),
+            output: input.output.into_iter().map(|output| self.lower_output(output)).collect(),
+            output_type: self.lower_type(input.output_type),
+            block: self.reconstruct_block(input.block).0,
+            ..input
+        }
+    }
+
+    fn reconstruct_interface(&mut self, input: Interface) -> Interface {
+        Interface { members: input.members.into_iter().map(|member| self.lower_member(member)).collect(), ..input }
+    }
+
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            members: input.members.into_iter().map(|member| self.lower_member(member)).collect(),
+            methods: input
+                .methods
+                .into_iter()
+                .map(|(name, method)| (name, self.reconstruct_function(method)))
+                .collect(),
+            ..input
+        }
+    }
+
+    fn reconstruct_mapping(&mut self, input: Mapping) -> Mapping {
+        Mapping { key_type: self.lower_type(input.key_type), value_type: self.lower_type(input.value_type), ..input }
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The enum lowering pass lowers enums into the integers that represent them.
+//! Each variant is replaced by its index in the enum definition, and each enum type by the type `u8` of its indices.
+//! The types recorded in the type table and the signatures in the symbol table are lowered in the same way,
+//! and the enum definitions are then removed, so that the later passes only see integers.
+//! The pass is run after type checking, which checks that each variant exists.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! enum Color { Red, Green, Blue }
+//!
+//! transition main(c: Color) -> bool {
+//!     return c == Color::Green;
+//! }
+//! ```
+//!
+//! The enum lowering pass produces the following code.
+//! ```leo
+//! transition main(c: u8) -> bool {
+//!     return c == 1u8;
+//! }
+//! ```
+
+pub mod enum_lowerer;
+pub use enum_lowerer::*;
+
+use crate::{Pass, SymbolTable, TypeTable};
+
+use leo_ast::{Ast, ProgramReconstructor};
+
+impl<'a> Pass for EnumLowerer<'a> {
+    type Input = (Ast, SymbolTable, &'a TypeTable);
+    type Output = (Ast, SymbolTable);
+
+    fn do_pass((ast, mut symbol_table, type_table): Self::Input) -> Self::Output {
+        let mut reconstructor = EnumLowerer::new(symbol_table.enums.clone(), type_table);
+        let ast = Ast::new(reconstructor.reconstruct_program(ast.into_repr()));
+        reconstructor.lower_type_table();
+
+        // Lower the signatures of the functions and the members of the structs that the later passes look up.
+        for function in symbol_table.functions.values_mut() {
+            reconstructor.lower_function_symbol(function);
+        }
+        for struct_ in symbol_table.structs.values_mut() {
+            struct_.members = struct_.members.drain(..).map(|member| reconstructor.lower_member(member)).collect();
+        }
+
+        (ast, symbol_table)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::{parse_ast, program_scope, type_check};
+
+    use leo_ast::{Enum, Type};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_enum_lowering_of_finalize_inputs() {
+        create_session_if_not_set_then(|_| {
+            let ast = parse_ast(
+                "program test.aleo {
+    enum Color {
+        Red,
+        Green,
+    }
+    mapping colors: u8 => Color;
+    transition paint(flag: bool) {
+        let c: Color = flag ? Color::Red : Color::Green;
+        return then finalize(c);
+    }
+    finalize paint(c: Color) {
+        Mapping::set(colors, 0u8, c);
+    }
+}
+",
+            );
+            let type_table = TypeTable::default();
+            let symbol_table = type_check(&ast, &type_table);
+            let (ast, _) = EnumLowerer::do_pass((ast, symbol_table, &type_table));
+
+            // The finalize block is lowered by `reconstruct_finalize`, like the transition that calls it.
+            let finalize = program_scope(&ast).functions[0].1.finalize.as_ref().unwrap();
+            assert_eq!(finalize.input[0].type_(), Type::Integer(Enum::REPRESENTATION));
+        })
+    }
+}
@@ -50,7 +50,7 @@ impl ExpressionReconstructor for Flattener<'_> {
             });
         }
 
-        (Expression::Struct(StructExpression { name: input.name, members, span: input.span, id: input.id }), statements)
+        (Expression::Struct(StructExpression { members, ..input }), statements)
     }
 
     /// Reconstructs ternary expressions over arrays, structs, and tuples, accumulating any statements that are generated.
@@ -54,8 +54,10 @@ impl ProgramReconstructor for Flattener<'_> {
         self.fold_returns(&mut block, returns);
 
         Function {
+            doc: function.doc,
             annotations: function.annotations,
             variant: function.variant,
+            visibility: function.visibility,
             identifier: function.identifier,
             input: function.input,
             output: function.output,
@@ -215,7 +215,10 @@ impl StatementReconstructor for Flattener<'_> {
             statements.push(reconstructed_statement);
         }
 
-        (Block { span: block.span, statements, id: self.node_builder.next_id() }, Default::default())
+        (
+            Block { annotations: Vec::new(), span: block.span, statements, id: self.node_builder.next_id() },
+            Default::default(),
+        )
     }
 
     /// Flatten a conditional statement into a list of statements.
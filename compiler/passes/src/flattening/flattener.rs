@@ -205,6 +205,11 @@ impl<'a> Flattener<'a> {
         if !returns.is_empty() {
             let mut return_expressions = Vec::with_capacity(returns.len());
 
+            // The folded return statement takes the span of the last return statement, so that its instructions can
+            // be traced back to the source.
+            // Note that the indexing is safe since we check that `returns` is not empty.
+            let span = returns[returns.len() - 1].1.span;
+
             // Construct a vector for each argument position.
             // Note that the indexing is safe since we check that `returns` is not empty.
             let (has_finalize, number_of_finalize_arguments) = match &returns[0].1.finalize_arguments {
@@ -251,7 +256,7 @@ impl<'a> Flattener<'a> {
             block.statements.push(Statement::Return(ReturnStatement {
                 expression,
                 finalize_arguments,
-                span: Default::default(),
+                span,
                 id: self.node_builder.next_id(),
             }));
         }
@@ -457,6 +462,7 @@ impl<'a> Flattener<'a> {
 
         let (expr, stmts) = self.reconstruct_struct_init(StructExpression {
             name: struct_.identifier,
+            const_arguments: Vec::new(),
             members,
             span: Default::default(),
             id: {
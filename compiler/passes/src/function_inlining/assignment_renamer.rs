@@ -88,6 +88,7 @@ impl ExpressionReconstructor for AssignmentRenamer<'_> {
         (
             Expression::Struct(StructExpression {
                 name: input.name,
+                const_arguments: input.const_arguments,
                 members: input
                     .members
                     .into_iter()
@@ -134,7 +135,7 @@ impl StatementReconstructor for AssignmentRenamer<'_> {
         unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
     }
 
-    /// Parsing guarantees that console statements are not present in the program.
+    /// Static single assignment removes console statements.
     fn reconstruct_console(&mut self, _: ConsoleStatement) -> (Statement, Self::AdditionalOutput) {
         unreachable!("`ConsoleStatement`s should not be in the AST at this phase of compilation.")
     }
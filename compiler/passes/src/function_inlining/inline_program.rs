@@ -50,7 +50,11 @@ impl ProgramReconstructor for FunctionInliner<'_> {
 
         ProgramScope {
             program_id: input.program_id,
+            annotations: input.annotations,
+            modules: input.modules,
+            interfaces: input.interfaces,
             structs: input.structs,
+            enums: input.enums,
             mappings: input.mappings,
             functions,
             consts: input.consts,
@@ -66,7 +66,7 @@ impl StatementReconstructor for FunctionInliner<'_> {
             statements.push(reconstructed_statement);
         }
 
-        (Block { span: block.span, statements, id: block.id }, Default::default())
+        (Block { annotations: Vec::new(), span: block.span, statements, id: block.id }, Default::default())
     }
 
     /// Flattening removes conditional statements from the program.
@@ -74,7 +74,7 @@ impl StatementReconstructor for FunctionInliner<'_> {
         unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
     }
 
-    /// Parsing guarantees that console statements are not present in the program.
+    /// Static single assignment removes console statements.
     fn reconstruct_console(&mut self, _: ConsoleStatement) -> (Statement, Self::AdditionalOutput) {
         unreachable!("`ConsoleStatement`s should not be in the AST at this phase of compilation.")
     }
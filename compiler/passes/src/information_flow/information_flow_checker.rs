@@ -0,0 +1,344 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{SymbolTable, VariableType};
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_span::{source_map::SpanLocation, sym, symbol::with_session_globals, Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// The information a value depends on, ordered from the least to the most sensitive.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Taint {
+    /// The value is known at compile time, e.g. a literal or a constant parameter.
+    #[default]
+    Constant,
+    /// The value is only known at runtime, e.g. a public input or the caller.
+    Runtime,
+    /// The value depends on private data, e.g. a private input.
+    Private,
+}
+
+impl Taint {
+    /// Returns the taint of an input with the given mode.
+    fn of_input(variant: Variant, mode: Mode) -> Self {
+        match (variant, mode) {
+            (_, Mode::Constant) => Taint::Constant,
+            // The inputs of a transition are private unless they are declared public.
+            (Variant::Transition, Mode::Private | Mode::None) => Taint::Private,
+            _ => Taint::Runtime,
+        }
+    }
+}
+
+pub struct InformationFlowChecker<'a> {
+    /// An error handler used for any errors found during information flow checking.
+    handler: &'a Handler,
+    /// The symbol table of the type-checked program.
+    symbol_table: &'a SymbolTable,
+    /// The taint of each variable on the current path.
+    variables: IndexMap<Symbol, Taint>,
+    /// The taint of the conditions of the enclosing conditional statements, which flows into every assignment.
+    condition: Taint,
+    /// Whether errors are reported, which they are not while the taints in a loop body are computed.
+    report: bool,
+}
+
+impl<'a> InformationFlowChecker<'a> {
+    /// Returns a new information flow checker given an error handler and the symbol table.
+    pub fn new(handler: &'a Handler, symbol_table: &'a SymbolTable) -> Self {
+        Self { handler, symbol_table, variables: Default::default(), condition: Taint::Constant, report: true }
+    }
+
+    /// Checks the body of a function or finalize block, whose inputs have the given variant and modes.
+    fn check_body(&mut self, variant: Variant, input: &'a [Input], block: &'a Block) {
+        self.variables =
+            input.iter().map(|input| (input.identifier().name, Taint::of_input(variant, input.mode()))).collect();
+        self.condition = Taint::Constant;
+        self.visit_block(block);
+    }
+
+    /// Records that `taint` flows into the variable assigned by `place`, along with the taint of the conditions.
+    fn assign(&mut self, place: &Expression, taint: Taint) {
+        let taint = taint.max(self.condition);
+        match place {
+            Expression::Identifier(identifier) => {
+                self.variables.insert(identifier.name, taint);
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| self.assign(element, taint)),
+            // Assigning to a member or an element taints the whole variable.
+            Expression::Access(AccessExpression::Member(access)) => self.assign_part(&access.inner, taint),
+            Expression::Access(AccessExpression::Array(access)) => self.assign_part(&access.array, taint),
+            Expression::Access(AccessExpression::Tuple(access)) => self.assign_part(&access.tuple, taint),
+            _ => {}
+        }
+    }
+
+    /// Records that `taint` flows into a part of the variable assigned by `place`.
+    fn assign_part(&mut self, place: &Expression, taint: Taint) {
+        let taint = match place {
+            Expression::Identifier(identifier) => {
+                taint.max(self.variables.get(&identifier.name).copied().unwrap_or_default())
+            }
+            _ => taint,
+        };
+        self.assign(place, taint);
+    }
+
+    /// Checks a branch from the current state, returning the taint of the variables at its end.
+    /// The current state is left unchanged.
+    fn check_branch(&mut self, check: impl FnOnce(&mut Self)) -> IndexMap<Symbol, Taint> {
+        let variables = self.variables.clone();
+        check(self);
+        core::mem::replace(&mut self.variables, variables)
+    }
+
+    /// Sets the taint of each variable to the greater of its taints in `self.variables` and `other`.
+    fn join(&mut self, other: IndexMap<Symbol, Taint>) {
+        for (name, taint) in other {
+            let entry = self.variables.entry(name).or_default();
+            *entry = (*entry).max(taint);
+        }
+    }
+
+    /// Emits an error if an argument that is not known at compile time is passed to a constant parameter.
+    fn check_arguments(&mut self, function: &Identifier, arguments: &'a [Expression]) -> Taint {
+        let inputs = self.symbol_table.lookup_fn_symbol(function.name).map(|function| function.input().to_vec());
+        let mut taint = Taint::Constant;
+        for (index, argument) in arguments.iter().enumerate() {
+            let argument_taint = self.visit_expression(argument, &());
+            taint = taint.max(argument_taint);
+
+            let Some(input) = inputs.as_ref().and_then(|inputs| inputs.get(index)) else { continue };
+            if !self.report || input.mode() != Mode::Constant || argument_taint == Taint::Constant {
+                continue;
+            }
+            let (parameter, location) = (input.identifier(), location(input.identifier().span));
+            self.handler.emit_err(match argument_taint {
+                Taint::Private => TypeCheckerError::private_value_to_constant_parameter(
+                    parameter,
+                    function,
+                    location,
+                    argument.span(),
+                ),
+                _ => TypeCheckerError::runtime_value_to_constant_parameter(
+                    parameter,
+                    function,
+                    location,
+                    argument.span(),
+                ),
+            });
+        }
+        taint
+    }
+}
+
+/// Returns the location of `span` as `file:line:column`.
+fn location(span: Span) -> String {
+    let location = with_session_globals(|s| s.source_map.span_to_location(span)).unwrap_or_else(SpanLocation::dummy);
+    format!("{}:{}:{}", location.source_file.name, location.line_start, location.col_start)
+}
+
+impl<'a> ExpressionVisitor<'a> for InformationFlowChecker<'a> {
+    type AdditionalInput = ();
+    type Output = Taint;
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(access) => {
+                self.visit_expression(&access.array, additional).max(self.visit_expression(&access.index, additional))
+            }
+            AccessExpression::AssociatedConstant(_) => Taint::Constant,
+            AccessExpression::AssociatedFunction(function) => {
+                let taint = function
+                    .arguments
+                    .iter()
+                    .fold(Taint::Constant, |taint, argument| taint.max(self.visit_expression(argument, additional)));
+                // The values of mappings and random numbers are only known when the finalize block is executed.
+                match &function.ty {
+                    Type::Identifier(ty) if matches!(ty.name, sym::Mapping | sym::ChaCha) => taint.max(Taint::Runtime),
+                    _ => taint,
+                }
+            }
+            AccessExpression::Member(access) => match &*access.inner {
+                // Accesses such as `self.caller` and `block.height` are only known at runtime.
+                Expression::Identifier(identifier) if !self.variables.contains_key(&identifier.name) => Taint::Runtime,
+                inner => self.visit_expression(inner, additional),
+            },
+            AccessExpression::Tuple(access) => self.visit_expression(&access.tuple, additional),
+        }
+    }
+
+    fn visit_array(&mut self, input: &'a ArrayExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input
+            .elements
+            .iter()
+            .fold(Taint::Constant, |taint, element| taint.max(self.visit_expression(element, additional)))
+    }
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.left, additional).max(self.visit_expression(&input.right, additional))
+    }
+
+    fn visit_block_expression(
+        &mut self,
+        input: &'a BlockExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.visit_expression(&input.value, additional)
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match &*input.function {
+            Expression::Identifier(function) => {
+                let taint = self.check_arguments(function, &input.arguments);
+                // Only an inline function is evaluated at compile time, when all of its arguments are.
+                let variant = self.symbol_table.lookup_fn_symbol(function.name).map(|function| function.variant);
+                match (variant, &input.external) {
+                    (Some(Variant::Inline), None) => taint,
+                    _ => taint.max(Taint::Runtime),
+                }
+            }
+            // A method call depends on its receiver and arguments.
+            function => input
+                .arguments
+                .iter()
+                .fold(self.visit_expression(function, additional), |taint, argument| {
+                    taint.max(self.visit_expression(argument, additional))
+                })
+                .max(Taint::Runtime),
+        }
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, additional)
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().fold(Taint::Constant, |taint, member| {
+            taint.max(match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                // A shorthand initializer `Foo { x }` reads the variable `x`.
+                None => self.visit_identifier(&member.identifier, additional),
+            })
+        })
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        match self.variables.get(&input.name) {
+            Some(taint) => *taint,
+            None => match self.symbol_table.lookup_variable(input.name).map(|variable| &variable.declaration) {
+                // The constants of the program scope are known at compile time.
+                Some(VariableType::Const) => Taint::Constant,
+                _ => Taint::Runtime,
+            },
+        }
+    }
+
+    fn visit_literal(&mut self, _input: &'a Literal, _additional: &Self::AdditionalInput) -> Self::Output {
+        Taint::Constant
+    }
+
+    fn visit_ternary(&mut self, input: &'a TernaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.condition, additional)
+            .max(self.visit_expression(&input.if_true, additional))
+            .max(self.visit_expression(&input.if_false, additional))
+    }
+
+    fn visit_tuple(&mut self, input: &'a TupleExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input
+            .elements
+            .iter()
+            .fold(Taint::Constant, |taint, element| taint.max(self.visit_expression(element, additional)))
+    }
+
+    fn visit_unary(&mut self, input: &'a UnaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.receiver, additional)
+    }
+}
+
+impl<'a> StatementVisitor<'a> for InformationFlowChecker<'a> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        let taint = self.visit_expression(&input.value, &());
+        self.assign(&input.place, taint);
+    }
+
+    fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
+        let condition = self.condition;
+        self.condition = condition.max(self.visit_expression(&input.condition, &()));
+
+        let then = self.check_branch(|checker| checker.visit_block(&input.then));
+        let otherwise = self.check_branch(|checker| {
+            if let Some(otherwise) = &input.otherwise {
+                checker.visit_statement(otherwise);
+            }
+        });
+        self.join(then);
+        self.join(otherwise);
+
+        self.condition = condition;
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.visit_expression(&input.value, &());
+        self.variables.insert(input.place.name, Taint::Constant);
+    }
+
+    fn visit_declaration(&mut self, input: &'a DeclarationStatement) {
+        // The variable is tainted by the assignments to it.
+        self.variables.insert(input.identifier.name, Taint::Constant);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        let taint = self.visit_expression(&input.value, &());
+        self.assign(&input.place, taint);
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.visit_expression(&input.start, &());
+        self.visit_expression(&input.stop, &());
+        // Loops are unrolled, so the loop variable is known at compile time.
+        self.variables.insert(input.variable.name, Taint::Constant);
+
+        // The taint of a variable may flow into another variable in a later iteration, so the body is checked until
+        // the taints no longer change. Since taints only grow, this terminates. The errors are only reported once.
+        let report = core::mem::replace(&mut self.report, false);
+        loop {
+            let variables = self.variables.clone();
+            let body = self.check_branch(|checker| checker.visit_block(&input.block));
+            self.join(body);
+            if self.variables == variables {
+                break;
+            }
+        }
+        self.report = report;
+        let body = self.check_branch(|checker| checker.visit_block(&input.block));
+        self.join(body);
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for InformationFlowChecker<'a> {
+    fn visit_function(&mut self, input: &'a Function) {
+        self.check_body(input.variant, &input.input, &input.block);
+        if let Some(finalize) = &input.finalize {
+            // The inputs of a finalize block are public.
+            self.check_body(Variant::Standard, &finalize.input, &finalize.block);
+        }
+    }
+}
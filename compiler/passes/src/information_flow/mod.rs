@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Information Flow pass checks that the arguments passed to the constant parameters of inline functions are
+//! known at compile time. It tracks the taint of every variable: a value is constant if it only depends on literals,
+//! constants, loop variables, and constant parameters; otherwise it is only known at runtime, and it is private if it
+//! depends on private data, such as a private input of a transition. Taints flow through expressions, assignments,
+//! and the conditions of the conditional statements that enclose an assignment.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! program test.aleo {
+//!     inline shift(a: u32, constant amount: u32) -> u32 {
+//!         return a << amount;
+//!     }
+//!
+//!     transition main(a: u32, b: u32) -> u32 {
+//!         let c: u32 = shift(a, 2u32);
+//!         return shift(c, b);
+//!     }
+//! }
+//! ```
+//!
+//! The first call is valid, since `2u32` is constant. The second call is reported, since `b` is a private input.
+
+pub mod information_flow_checker;
+pub use information_flow_checker::*;
+
+use crate::{Pass, SymbolTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for InformationFlowChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a SymbolTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, symbol_table): Self::Input) -> Self::Output {
+        let mut visitor = InformationFlowChecker::new(handler, symbol_table);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)
+    }
+}
@@ -0,0 +1,271 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{validate_address, SymbolTable};
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, InputError};
+use leo_span::{symbol::with_session_globals, Span};
+
+use indexmap::IndexMap;
+
+/// A compiler pass that binds the values of an input file to the parameters of the functions they are given for.
+pub struct InputBinder<'a> {
+    /// The symbol table of the program, which defines its functions and structs.
+    symbol_table: &'a SymbolTable,
+    /// The error handler.
+    handler: &'a Handler,
+}
+
+impl<'a> InputBinder<'a> {
+    pub fn new(symbol_table: &'a SymbolTable, handler: &'a Handler) -> Self {
+        Self { symbol_table, handler }
+    }
+
+    /// Binds the sections of `input_ast`, emitting an error for each input that does not match its parameter.
+    pub fn bind(&self, input_ast: &InputAst) -> TypedInputAst {
+        let mut typed_input_ast = TypedInputAst::default();
+        for section in &input_ast.sections {
+            match self.symbol_table.lookup_fn_symbol(section.name) {
+                Some(function) => {
+                    let inputs = self.bind_section(section, &function.input);
+                    typed_input_ast.functions.insert(section.name, inputs);
+                }
+                None => self.handler.emit_err(InputError::unknown_input_section(section.name, section.span)),
+            }
+        }
+        typed_input_ast
+    }
+
+    /// Binds the definitions of `section` to `parameters`, returning the inputs in the order of the parameters.
+    fn bind_section(&self, section: &Section, parameters: &[Input]) -> Vec<TypedInput> {
+        let mut definitions = IndexMap::new();
+        for definition in &section.definitions {
+            if definitions.insert(definition.name.name, definition).is_some() {
+                self.handler.emit_err(InputError::duplicate_input(definition.name, definition.name.span));
+            } else if !parameters.iter().any(|parameter| parameter.identifier().name == definition.name.name) {
+                self.handler.emit_err(InputError::unexpected_input(
+                    definition.name,
+                    section.name,
+                    definition.name.span,
+                ));
+            }
+        }
+
+        parameters
+            .iter()
+            .filter_map(|parameter| {
+                let name = parameter.identifier();
+                let Some(definition) = definitions.get(&name.name) else {
+                    self.handler.emit_err(InputError::missing_input(name, section.name, section.span));
+                    return None;
+                };
+                self.bind_definition(definition, parameter)
+            })
+            .collect()
+    }
+
+    /// Checks that `definition` gives a value of the type of `parameter`.
+    fn bind_definition(&self, definition: &Definition, parameter: &Input) -> Option<TypedInput> {
+        let type_ = parameter.type_();
+        if !type_.eq_flat(&definition.type_) {
+            self.handler.emit_err(InputError::input_type_mismatch(
+                definition.name,
+                &type_,
+                &definition.type_,
+                location(parameter.span()),
+                definition.span,
+            ));
+            return None;
+        }
+        // Literals are checked against the type they are given for.
+        if matches!(type_, Type::Address | Type::Boolean | Type::Field | Type::Group | Type::Integer(_)) {
+            if let Err(err) = InputValue::try_from((type_.clone(), definition.value.clone())) {
+                self.handler.emit_err(err);
+                return None;
+            }
+        }
+        // Addresses are validated here, since the type checker, which validates those of programs, does not see inputs.
+        if !self.check_addresses(&definition.value) {
+            return None;
+        }
+        let is_record = match parameter {
+            Input::Internal(_) => match &type_ {
+                Type::Identifier(identifier) => {
+                    self.symbol_table.lookup_struct(identifier.name).map_or(false, |struct_| struct_.is_record)
+                }
+                _ => false,
+            },
+            Input::External(_) => true,
+        };
+        let value = definition.value.clone();
+        Some(TypedInput { name: definition.name, mode: definition.mode, type_, value, is_record })
+    }
+
+    /// Emits an error for each address literal in `expression`, e.g. the owner of a record, that is not a valid
+    /// bech32m encoded address. Returns `true` if they are all valid.
+    fn check_addresses(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Literal(Literal::Address(address, span, _)) => match validate_address(address) {
+                Ok(()) => true,
+                Err(err) => {
+                    self.handler.emit_err(InputError::invalid_address(address, err, *span));
+                    false
+                }
+            },
+            Expression::Struct(struct_) => {
+                self.check_all_addresses(struct_.members.iter().filter_map(|member| member.expression.as_ref()))
+            }
+            Expression::Array(array) => self.check_all_addresses(array.elements.iter()),
+            Expression::Tuple(tuple) => self.check_all_addresses(tuple.elements.iter()),
+            _ => true,
+        }
+    }
+
+    /// Checks the addresses of each of `expressions`, without stopping at the first invalid one.
+    fn check_all_addresses<'b>(&self, expressions: impl Iterator<Item = &'b Expression>) -> bool {
+        expressions.filter(|expression| !self.check_addresses(expression)).count() == 0
+    }
+}
+
+/// Returns the location of `span` as `path:line:column`, which points at a declaration in another file than the error.
+fn location(span: Span) -> String {
+    with_session_globals(|s| s.source_map.span_to_location(span)).map_or_else(
+        || "no-location".to_string(),
+        |location| format!("{}:{}:{}", location.source_file.name, location.line_start, location.col_start),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        test_utilities::{parse, type_check},
+        Pass,
+        TypeTable,
+    };
+
+    use leo_ast::*;
+    use leo_errors::emitter::Handler;
+    use leo_span::{
+        source_map::FileName,
+        symbol::{create_session_if_not_set_then, with_session_globals},
+        Symbol,
+    };
+
+    use super::InputBinder;
+
+    const PROGRAM: &str = "program test.aleo {
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    transition main(a: u8, public b: bool) -> u8 {
+        return b ? a : 0u8;
+    }
+
+    transition spend(t: Token) -> u64 {
+        return t.amount;
+    }
+}
+";
+
+    /// Binds `input` to `PROGRAM`, returning the typed input, or the errors that were emitted.
+    fn bind(input: &str) -> Result<TypedInputAst, Vec<String>> {
+        let (handler, buffer) = Handler::new_with_buf();
+        let node_builder = NodeBuilder::default();
+        let ast = Ast::new(parse(PROGRAM, &node_builder));
+        let symbol_table = type_check(&ast, &TypeTable::default());
+
+        let input = with_session_globals(|s| s.source_map.new_source(input, FileName::Custom("input".into())));
+        let input_ast = leo_parser::parse_input(&handler, &node_builder, &input.src, input.start_pos).unwrap();
+        InputBinder::do_pass((&input_ast, &symbol_table, &handler))
+            .map_err(|_| buffer.extract_errs().into_inner().iter().map(|error| error.to_string()).collect())
+    }
+
+    #[test]
+    fn test_input_binding() {
+        create_session_if_not_set_then(|_| {
+            let input = bind(
+                "[main]
+public b: bool = true;
+a: u8 = 1u8;
+
+[spend]
+t: Token = Token { owner: aleo1qnr4dkkvkgfqph0vzc3y6z2eu975wnpz2925ntjccd5cfqxtyu8s7pyjh9, amount: 5u64, _nonce: 0group };
+",
+            )
+            .unwrap();
+
+            // The inputs are ordered as the parameters are declared.
+            let main = input.function_inputs(Symbol::intern("main")).unwrap();
+            assert_eq!(main, ["1u8", "true"]);
+            // Records are given with the visibility of their members.
+            let spend = input.function_inputs(Symbol::intern("spend")).unwrap();
+            assert_eq!(spend.len(), 1);
+            assert!(spend[0].contains("amount: 5u64.private"), "{}", spend[0]);
+            assert!(input.function_inputs(Symbol::intern("other")).is_none());
+        })
+    }
+
+    #[test]
+    fn test_input_binding_errors() {
+        create_session_if_not_set_then(|_| {
+            let errors = bind(
+                "[main]
+a: u16 = 1u16;
+b: bool = 1u8;
+c: u8 = 2u8;
+
+[spend]
+
+[other]
+",
+            )
+            .unwrap_err();
+
+            assert_eq!(errors.len(), 5, "{errors:#?}");
+            // A mismatched type points at the input and names where the parameter is declared.
+            assert!(errors[0].contains("`c` is not a parameter of `main`"), "{}", errors[0]);
+            assert!(errors[1].contains("`a` is given as a `u16`, but the parameter has type `u8`"), "{}", errors[1]);
+            assert!(errors[1].contains("a: u16 = 1u16;"), "{}", errors[1]);
+            assert!(errors[1].contains("is declared as a `u8` at test:7:21"), "{}", errors[1]);
+            assert!(errors[2].contains("unexpected type, expected: 'boolean', received: '1u8'"), "{}", errors[2]);
+            assert!(errors[3].contains("missing a value for the parameter `t` of `spend`"), "{}", errors[3]);
+            assert!(errors[4].contains("section `[other]` does not name a function"), "{}", errors[4]);
+        })
+    }
+
+    #[test]
+    fn test_input_binding_invalid_addresses() {
+        create_session_if_not_set_then(|_| {
+            let errors = bind(
+                "[main]
+public b: bool = true;
+a: u8 = 1u8;
+
+[spend]
+t: Token = Token { owner: aleo1qnr4dkkvkgfqph0vzc3y6z2eu975wnpz2925ntjccd5cfqxtyu8s7pyjh8, amount: 5u64, _nonce: 0group };
+",
+            )
+            .unwrap_err();
+
+            // The addresses of inputs are validated like those of programs, e.g. the owner of a record.
+            assert_eq!(errors.len(), 1, "{errors:#?}");
+            assert!(errors[0].contains("is not a valid address: its checksum does not match"), "{}", errors[0]);
+        })
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Input Binding pass checks an input file against the program it gives the inputs of.
+//! Each section of the input file names a function, and each definition in a section gives the value of the
+//! function's parameter of the same name. The pass checks that every parameter is given a value of its declared type,
+//! and produces a [`TypedInputAst`](leo_ast::TypedInputAst) holding the values in the order of the parameters.
+//! When a value is given with the wrong type, the error points at the input and names where the parameter is declared.
+//!
+//! Consider the following Leo code and input file.
+//! ```leo
+//! program test.aleo {
+//!     transition main(a: u8, public b: bool) -> u8 {
+//!         return b ? a : 0u8;
+//!     }
+//! }
+//! ```
+//! ```leo
+//! [main]
+//! public b: bool = true;
+//! a: u8 = 1u8;
+//! ```
+//!
+//! The inputs of `main` are bound as `1u8` for `a` and `true` for `b`, in that order.
+
+pub mod input_binder;
+pub use input_binder::*;
+
+use crate::{Pass, SymbolTable};
+
+use leo_ast::{InputAst, TypedInputAst};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for InputBinder<'a> {
+    type Input = (&'a InputAst, &'a SymbolTable, &'a Handler);
+    type Output = Result<TypedInputAst>;
+
+    fn do_pass((input_ast, symbol_table, handler): Self::Input) -> Self::Output {
+        let binder = InputBinder::new(symbol_table, handler);
+        let typed_input_ast = binder.bind(input_ast);
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(typed_input_ast)
+    }
+}
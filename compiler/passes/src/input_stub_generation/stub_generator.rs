@@ -0,0 +1,242 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::SymbolTable;
+
+use leo_ast::*;
+
+use std::fmt::Write;
+
+/// The placeholder written for inputs of type `address`, which is the address used throughout the compiler tests.
+const PLACEHOLDER_ADDRESS: &str = "aleo10qerras5799u6k7rjtc9y3hcwxuykr45qra7x7dp6jgnc0923czqm0lgta";
+
+/// A compiler pass that writes a skeleton input file for the transitions of a type-checked program.
+/// Each transition gets a section defining each of its inputs with a placeholder value of the right type,
+/// e.g. `0u32` for a `u32`. Input files only hold literals, so inputs of other types, such as structs and arrays,
+/// are written out commented, with a placeholder value showing their shape.
+/// Imported programs are skipped, since their transitions are not entry points of the program.
+pub struct InputStubGenerator<'a> {
+    /// The symbol table of the program, which defines the structs and records used by the inputs.
+    symbol_table: &'a SymbolTable,
+    /// The input file constructed by this compiler pass.
+    pub(crate) stub: String,
+}
+
+impl<'a> InputStubGenerator<'a> {
+    pub fn new(symbol_table: &'a SymbolTable) -> Self {
+        Self { symbol_table, stub: String::new() }
+    }
+
+    /// Returns a placeholder value of type `type_`, indented for the given nesting `depth`.
+    /// Returns `None` if values of the type cannot be written in an input file.
+    fn placeholder(&self, type_: &Type, depth: usize) -> Option<String> {
+        Some(match type_ {
+            Type::Address => PLACEHOLDER_ADDRESS.to_string(),
+            Type::Array(array_type) => {
+                let element = self.placeholder(array_type.element_type(), depth)?;
+                format!("[{}]", vec![element; array_type.length()].join(", "))
+            }
+            Type::Boolean => "false".to_string(),
+            Type::Field => "0field".to_string(),
+            Type::Group => "0group".to_string(),
+            Type::Identifier(identifier) => {
+                let struct_ = self.symbol_table.lookup_struct(identifier.name)?;
+                let indent = "    ".repeat(depth + 1);
+                let mut members = String::new();
+                for member in &struct_.members {
+                    let value = self.placeholder(&member.type_, depth + 1)?;
+                    writeln!(members, "{indent}{}: {value},", member.name()).unwrap();
+                }
+                // The nonce of a record is given with the record.
+                if struct_.is_record {
+                    writeln!(members, "{indent}_nonce: 0group,").unwrap();
+                }
+                format!("{} {{\n{members}{}}}", struct_.identifier, "    ".repeat(depth))
+            }
+            Type::Integer(integer_type) => format!("0{integer_type}"),
+            Type::Scalar => "0scalar".to_string(),
+            Type::Generic(_)
+            | Type::Mapping(_)
+            | Type::Signature
+            | Type::String
+            | Type::Tuple(_)
+            | Type::Unit
+            | Type::Err => return None,
+        })
+    }
+}
+
+/// Returns whether values of type `type_` are literals, which are the only values an input file can hold.
+fn is_literal(type_: &Type) -> bool {
+    matches!(type_, Type::Address | Type::Boolean | Type::Field | Type::Group | Type::Integer(_))
+}
+
+/// Returns `type_` as it is written in Leo source, which differs from its `Display` for booleans.
+fn source_type(type_: &Type) -> String {
+    match type_ {
+        Type::Array(array_type) => format!("[{}; {}]", source_type(array_type.element_type()), array_type.length()),
+        Type::Boolean => "bool".to_string(),
+        type_ => type_.to_string(),
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for InputStubGenerator<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for InputStubGenerator<'a> {}
+
+impl<'a> ProgramVisitor<'a> for InputStubGenerator<'a> {
+    fn visit_program(&mut self, input: &'a Program) {
+        input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
+    }
+
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        if input.variant != Variant::Transition {
+            return;
+        }
+        if !self.stub.is_empty() {
+            self.stub.push('\n');
+        }
+        writeln!(self.stub, "// Inputs for the `{}` transition.\n[{}]", input.name(), input.name()).unwrap();
+        for function_input in &input.input {
+            let (name, type_) = (function_input.identifier(), function_input.type_());
+            let type_name = source_type(&type_);
+            // Only public and constant inputs are annotated with their mode in an input file.
+            let mode = match function_input.mode() {
+                mode @ (Mode::Public | Mode::Constant) => format!("{mode} "),
+                Mode::None | Mode::Private => String::new(),
+            };
+            match function_input {
+                Input::Internal(_) => match self.placeholder(&type_, 0) {
+                    Some(value) if is_literal(&type_) => {
+                        writeln!(self.stub, "{mode}{name}: {type_name} = {value};").unwrap()
+                    }
+                    Some(value) => {
+                        writeln!(self.stub, "// Values of type `{type_name}` cannot be given in an input file:")
+                            .unwrap();
+                        for line in format!("{mode}{name}: {type_name} = {value};").lines() {
+                            writeln!(self.stub, "// {line}").unwrap();
+                        }
+                    }
+                    None => writeln!(self.stub, "// `{name}: {type_name}` has no placeholder value.").unwrap(),
+                },
+                Input::External(external) => {
+                    let suffix = external.program_suffix();
+                    let record = format!("{}.{suffix}/{}.record", external.program_name, external.record);
+                    writeln!(self.stub, "// `{name}` is a `{record}` record of another program.").unwrap()
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utilities::{parse, type_check},
+        Pass,
+        TypeTable,
+    };
+
+    use leo_errors::emitter::Handler;
+    use leo_span::{
+        source_map::FileName,
+        symbol::{create_session_if_not_set_then, with_session_globals},
+    };
+
+    const PROGRAM: &str = "program test.aleo {
+    struct Point {
+        x: u8,
+        y: [i16; 2],
+    }
+
+    record Token {
+        owner: address,
+        amount: u64,
+        origin: Point,
+    }
+
+    function helper(a: u8) -> u8 {
+        return a;
+    }
+
+    transition main(a: u32, public b: bool, p: Point, flags: [bool; 2]) -> u32 {
+        return a;
+    }
+
+    transition spend(t: Token, s: scalar, public g: group) -> field {
+        return 0field;
+    }
+}
+";
+
+    const STUB: &str = "// Inputs for the `main` transition.
+[main]
+a: u32 = 0u32;
+public b: bool = false;
+// Values of type `Point` cannot be given in an input file:
+// p: Point = Point {
+//     x: 0u8,
+//     y: [0i16, 0i16],
+// };
+// Values of type `[bool; 2]` cannot be given in an input file:
+// flags: [bool; 2] = [false, false];
+
+// Inputs for the `spend` transition.
+[spend]
+// Values of type `Token` cannot be given in an input file:
+// t: Token = Token {
+//     owner: aleo10qerras5799u6k7rjtc9y3hcwxuykr45qra7x7dp6jgnc0923czqm0lgta,
+//     amount: 0u64,
+//     origin: Point {
+//         x: 0u8,
+//         y: [0i16, 0i16],
+//     },
+//     _nonce: 0group,
+// };
+// Values of type `scalar` cannot be given in an input file:
+// s: scalar = 0scalar;
+public g: group = 0group;
+";
+
+    #[test]
+    fn test_input_stub() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let node_builder = NodeBuilder::default();
+            let ast = Ast::new(parse(PROGRAM, &node_builder));
+            let symbol_table = type_check(&ast, &TypeTable::default());
+
+            let stub = InputStubGenerator::do_pass((&ast, &symbol_table));
+            assert_eq!(stub, STUB);
+
+            // The stub is a valid input file.
+            let input_file = with_session_globals(|s| s.source_map.new_source(&stub, FileName::Custom("stub".into())));
+            let input =
+                leo_parser::parse_input(&handler, &node_builder, &input_file.src, input_file.start_pos).unwrap();
+            for definition in input.sections.into_iter().flat_map(|section| section.definitions) {
+                InputValue::try_from((definition.type_, definition.value)).unwrap();
+            }
+        })
+    }
+}
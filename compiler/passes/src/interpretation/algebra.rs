@@ -0,0 +1,342 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Emulates the field, group, and scalar types of Aleo on concrete values.
+//! A field or scalar is the canonical representative of its residue class, i.e. a number less than its modulus.
+//! A group element is represented by its discrete logarithm with respect to the generator, which is how group literals
+//! are written, so `2group` is the generator added to itself. Group elements are therefore written as group literals,
+//! and not by their coordinates as in the outputs of snarkVM.
+
+use super::interpreter::{unsupported, wrapped_integer_value};
+use crate::Halt;
+
+use leo_ast::{BinaryOperation, GroupLiteral, IntegerType, NodeID, Type, UnaryOperation, Value};
+use leo_errors::InterpreterError;
+use leo_span::Span;
+
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+use std::fmt::Display;
+
+/// The modulus of the field whose elements are the values of type `field`.
+pub const FIELD_MODULUS: &str = "8444461749428370424248824938781546531375899335154063827935233455917409239041";
+
+/// The order of the group generated by the generator, which is the modulus of the values of type `scalar`.
+pub const SCALAR_MODULUS: &str = "2111115437357092606062206234695386632838870926408408195193685246394721360383";
+
+/// The kind of an algebraic value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Field,
+    Group,
+    Scalar,
+}
+
+impl Kind {
+    /// Returns the modulus of the values of this kind. A group element is reduced modulo the order of the group.
+    fn modulus(self) -> BigUint {
+        let modulus = match self {
+            Self::Field => FIELD_MODULUS,
+            Self::Group | Self::Scalar => SCALAR_MODULUS,
+        };
+        modulus.parse().expect("The moduli are valid numbers.")
+    }
+
+    /// Returns the value of this kind represented by `number`, which must be reduced.
+    fn value(self, number: BigUint, span: Span) -> Value {
+        match self {
+            Self::Field => Value::Field(number.to_string(), span),
+            Self::Group => Value::Group(Box::new(GroupLiteral::Single(number.to_string(), span, NodeID::default()))),
+            Self::Scalar => Value::Scalar(number.to_string(), span),
+        }
+    }
+}
+
+/// Returns the kind of `value` and the number that represents it, if it is a field, a group element, or a scalar.
+/// Affine group literals are not supported, since they are written by their coordinates.
+fn element(value: &Value) -> Option<(Kind, BigUint)> {
+    let (kind, digits) = match value {
+        Value::Field(digits, _) => (Kind::Field, digits),
+        Value::Group(group) => match group.as_ref() {
+            GroupLiteral::Single(digits, ..) => (Kind::Group, digits),
+            GroupLiteral::Tuple(_) => return None,
+        },
+        Value::Scalar(digits, _) => (Kind::Scalar, digits),
+        _ => return None,
+    };
+    let modulus = BigInt::from(kind.modulus());
+    let number = (digits.parse::<BigInt>().ok()? % &modulus + &modulus) % modulus;
+    number.to_biguint().map(|number| (kind, number))
+}
+
+/// Returns `value` written as the canonical representative of its residue class, if it is a field, a group element,
+/// or a scalar, and `value` otherwise. Values must be canonical to be compared by how they are written.
+pub(crate) fn canonicalize(value: Value) -> Value {
+    match element(&value) {
+        Some((kind, number)) => kind.value(number, span_of(&value)),
+        None => value,
+    }
+}
+
+/// Returns the span of an algebraic value.
+fn span_of(value: &Value) -> Span {
+    match value {
+        Value::Field(_, span) | Value::Scalar(_, span) => *span,
+        Value::Group(group) => *group.span(),
+        _ => Span::default(),
+    }
+}
+
+/// Returns the inverse of `number` modulo the prime `modulus`, or halts if it is zero.
+fn inverse(number: &BigUint, modulus: &BigUint, operation: impl Display, span: Span) -> Result<BigUint, Halt> {
+    match number.is_zero() {
+        true => Err(Halt::Program(InterpreterError::division_by_zero(operation, span).into())),
+        // By Fermat's little theorem, the inverse is `number^(modulus - 2)`.
+        false => Ok(number.modpow(&(modulus - 2u32), modulus)),
+    }
+}
+
+/// Evaluates `left op right` if either operand is a field, a group element, or a scalar.
+/// Returns `None` if neither is, and an unsupported halt if the operation is not emulated.
+pub(crate) fn eval_binary(
+    op: BinaryOperation,
+    left: &Value,
+    right: &Value,
+    operation: impl Display,
+    span: Span,
+) -> Option<Result<Value, Halt>> {
+    let (left, right) = match (element(left), element(right)) {
+        (None, None) => return None,
+        (Some(left), Some(right)) => (left, right),
+        _ => return Some(unsupported(operation, span)),
+    };
+    Some(eval_elements(op, left, right, operation, span))
+}
+
+/// Evaluates `left op right` on two algebraic values.
+fn eval_elements(
+    op: BinaryOperation,
+    (left_kind, left): (Kind, BigUint),
+    (right_kind, right): (Kind, BigUint),
+    operation: impl Display,
+    span: Span,
+) -> Result<Value, Halt> {
+    let modulus = left_kind.modulus();
+    let boolean = |value| Ok(Value::Boolean(value, span));
+    match (op, left_kind, right_kind) {
+        (BinaryOperation::Add, _, _) if left_kind == right_kind => Ok(left_kind.value((left + right) % modulus, span)),
+        (BinaryOperation::Sub, Kind::Field | Kind::Group, _) if left_kind == right_kind => {
+            Ok(left_kind.value((left + &modulus - right) % modulus, span))
+        }
+        (BinaryOperation::Mul, Kind::Field, Kind::Field) => Ok(Kind::Field.value((left * right) % modulus, span)),
+        // A group element is multiplied by a scalar by multiplying its discrete logarithm.
+        (BinaryOperation::Mul, Kind::Group, Kind::Scalar) | (BinaryOperation::Mul, Kind::Scalar, Kind::Group) => {
+            Ok(Kind::Group.value((left * right) % modulus, span))
+        }
+        (BinaryOperation::Div, Kind::Field, Kind::Field) => {
+            let inverse = inverse(&right, &modulus, operation, span)?;
+            Ok(Kind::Field.value((left * inverse) % modulus, span))
+        }
+        (BinaryOperation::Pow, Kind::Field, Kind::Field) => Ok(Kind::Field.value(left.modpow(&right, &modulus), span)),
+        (BinaryOperation::Lt, Kind::Field | Kind::Scalar, _) if left_kind == right_kind => boolean(left < right),
+        (BinaryOperation::Lte, Kind::Field | Kind::Scalar, _) if left_kind == right_kind => boolean(left <= right),
+        (BinaryOperation::Gt, Kind::Field | Kind::Scalar, _) if left_kind == right_kind => boolean(left > right),
+        (BinaryOperation::Gte, Kind::Field | Kind::Scalar, _) if left_kind == right_kind => boolean(left >= right),
+        _ => unsupported(operation, span),
+    }
+}
+
+/// Evaluates `op receiver` if the receiver is a field, a group element, or a scalar.
+/// Returns `None` if it is not, and an unsupported halt if the operation is not emulated.
+/// Square roots are not emulated, since which of the two roots snarkVM returns is not specified.
+pub(crate) fn eval_unary(
+    op: UnaryOperation,
+    receiver: &Value,
+    operation: impl Display,
+    span: Span,
+) -> Option<Result<Value, Halt>> {
+    let (kind, number) = element(receiver)?;
+    let modulus = kind.modulus();
+    Some(match (op, kind) {
+        (UnaryOperation::Double, Kind::Field | Kind::Group) => Ok(kind.value((number * 2u32) % modulus, span)),
+        (UnaryOperation::Inverse, Kind::Field) => {
+            inverse(&number, &modulus, operation, span).map(|inverse| kind.value(inverse, span))
+        }
+        (UnaryOperation::Negate, Kind::Field | Kind::Group) => Ok(kind.value((&modulus - number) % modulus, span)),
+        (UnaryOperation::Square, Kind::Field) => Ok(kind.value((&number * &number) % modulus, span)),
+        _ => unsupported(operation, span),
+    })
+}
+
+/// Evaluates a cast of `value` to `type_` if either is a field or a scalar, as snarkVM does.
+/// Booleans and integers are converted to fields and scalars by their bits in two's complement. A cast to an integer
+/// takes the lowest bits of the number if `wrapped`, and otherwise halts if the number does not fit in them.
+/// Returns `None` if neither is, and an unsupported halt if the cast is not emulated.
+pub(crate) fn eval_cast(
+    value: &Value,
+    type_: &Type,
+    wrapped: bool,
+    operation: impl Display,
+    span: Span,
+) -> Option<Result<Value, Halt>> {
+    let number = match element(value) {
+        Some((Kind::Field | Kind::Scalar, number)) => number,
+        Some((Kind::Group, _)) => return Some(unsupported(operation, span)),
+        None if matches!(type_, Type::Field | Type::Scalar) => match bits(value) {
+            Some(bits) => bits,
+            None => return Some(unsupported(operation, span)),
+        },
+        None => return None,
+    };
+    let overflow = || Err(Halt::Program(InterpreterError::cast_overflow(value, type_, span).into()));
+    Some(match type_ {
+        Type::Boolean if number <= BigUint::one() => Ok(Value::Boolean(number.is_one(), span)),
+        Type::Boolean => overflow(),
+        Type::Field => Ok(Kind::Field.value(number, span)),
+        Type::Scalar if number < Kind::Scalar.modulus() => Ok(Kind::Scalar.value(number, span)),
+        Type::Scalar => overflow(),
+        Type::Integer(integer_type) if wrapped || number.bits() <= u64::from(integer_type.bit_width()) => {
+            let digits = (number & BigUint::from(mask(*integer_type))).to_u64_digits();
+            let bits = digits.iter().rev().fold(0u128, |bits, digit| (bits << 64) | u128::from(*digit));
+            Ok(wrapped_integer_value(*integer_type, bits, span))
+        }
+        Type::Integer(_) => overflow(),
+        _ => unsupported(operation, span),
+    })
+}
+
+/// Returns the bits of a boolean or an integer in two's complement, read as an unsigned number.
+fn bits(value: &Value) -> Option<BigUint> {
+    if let Value::Boolean(boolean, _) = value {
+        return Some(BigUint::from(u8::from(*boolean)));
+    }
+    let Type::Integer(integer_type) = Type::from(value) else {
+        return None;
+    };
+    match i128::try_from(value) {
+        Ok(integer) => Some(BigUint::from(integer as u128 & mask(integer_type))),
+        Err(_) => u128::try_from(value).ok().map(BigUint::from),
+    }
+}
+
+/// Returns the mask of the bits of an integer of type `integer_type`.
+fn mask(integer_type: IntegerType) -> u128 {
+    u128::MAX >> (128 - integer_type.bit_width())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        test_utilities::{parse_ast, program_scope},
+        ConstantEnvironment,
+        Interpreter,
+        TypeTable,
+    };
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    use itertools::Itertools;
+
+    const PROGRAM: &str = "program test.aleo {
+    transition field_ops(a: field, b: field) -> (field, field, bool, bool, field, bool) {
+        return (a - b, -a, (a / b) * b == a, b.inv() * b == 1field, a ** 3field, a < b);
+    }
+
+    transition inverse(a: field) -> field {
+        return a.inv();
+    }
+
+    transition group_ops(s: scalar) -> (group, bool, bool) {
+        let g: group = 1group * s;
+        return (g - 2group, g.double() == g + g, -g + g == 0group);
+    }
+
+    transition scalar_add(a: scalar, b: scalar) -> scalar {
+        return a + b;
+    }
+
+    transition to_field(a: i8, b: bool) -> (field, field) {
+        return (a as field, b as field);
+    }
+
+    transition to_u8(a: field) -> (u8, u8) {
+        return (a as.wrapped u8, a as u8);
+    }
+
+    transition to_scalar(a: field) -> scalar {
+        return a as scalar;
+    }
+}
+";
+
+    /// Runs `function` on `inputs`, returning its outputs or the reason it halted.
+    fn run(function: &str, inputs: &[&str]) -> String {
+        let ast = parse_ast(PROGRAM);
+        let program = program_scope(&ast);
+        let (type_table, constants) = (TypeTable::default(), ConstantEnvironment::default());
+        let mut interpreter = Interpreter::new(program, &type_table, &constants);
+        let inputs = interpreter.parse_inputs(function, &inputs.iter().map(|input| input.to_string()).collect_vec());
+        match interpreter.run(function, inputs.unwrap()) {
+            Ok(outputs) => format!("[{}]", outputs.iter().join(", ")),
+            Err(halt) => leo_errors::LeoError::from(halt).parts().unwrap().0.message.clone(),
+        }
+    }
+
+    #[test]
+    fn test_field_arithmetic() {
+        create_session_if_not_set_then(|_| {
+            // Subtraction and negation wrap around the modulus.
+            assert_eq!(
+                run("field_ops", &["2field", "3field"]),
+                "[8444461749428370424248824938781546531375899335154063827935233455917409239040field, \
+                 8444461749428370424248824938781546531375899335154063827935233455917409239039field, \
+                 true, true, 8field, true]"
+            );
+            assert!(run("inverse", &["0field"]).ends_with("divides by zero."));
+            // Inputs are reduced modulo the modulus.
+            assert_eq!(
+                run("inverse", &["8444461749428370424248824938781546531375899335154063827935233455917409239042field"]),
+                "[1field]"
+            );
+        })
+    }
+
+    #[test]
+    fn test_group_and_scalar_arithmetic() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(run("group_ops", &["5scalar"]), "[3group, true, true]");
+            assert_eq!(
+                run("scalar_add", &[
+                    "2111115437357092606062206234695386632838870926408408195193685246394721360382scalar",
+                    "2scalar"
+                ]),
+                "[1scalar]"
+            );
+        })
+    }
+
+    #[test]
+    fn test_casts() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(run("to_field", &["-1i8", "true"]), "[255field, 1field]");
+            assert_eq!(run("to_u8", &["44field"]), "[44u8, 44u8]");
+            assert_eq!(run("to_u8", &["300field"]), "Casting `300field` to `u8` overflows.");
+            assert_eq!(run("to_scalar", &["7field"]), "[7scalar]");
+            let modulus = format!("{}field", super::SCALAR_MODULUS);
+            assert_eq!(run("to_scalar", &[&modulus]), format!("Casting `{modulus}` to `scalar` overflows."));
+        })
+    }
+}
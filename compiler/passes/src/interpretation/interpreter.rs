@@ -0,0 +1,842 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{algebra, ConstantEnvironment, ExecutionTrace, TraceEvent, TypeTable};
+
+use leo_ast::*;
+use leo_errors::{InterpreterError, LeoError, Result};
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+/// The resources that a run of the interpreter may use, which are unlimited by default.
+/// Limiting them makes it safe to run untrusted or generated programs, e.g. ones with very long loops.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct InterpreterLimits {
+    /// The maximum number of statements and expressions evaluated by a run.
+    pub max_steps: Option<u64>,
+    /// The maximum number of values held by the variables of the active calls at any point of a run.
+    /// An array, struct, or tuple holds the values of its elements or members.
+    pub max_memory: Option<u64>,
+}
+
+/// A resource whose use by the interpreter can be limited.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Resource {
+    /// The number of statements and expressions evaluated.
+    Steps,
+    /// The number of values held by variables.
+    Memory,
+}
+
+impl fmt::Display for Resource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Steps => write!(f, "steps"),
+            Self::Memory => write!(f, "values in memory"),
+        }
+    }
+}
+
+/// The reason the evaluation of a function stopped before it returned.
+#[derive(Debug)]
+pub enum Halt {
+    /// The program halted, e.g. on an overflow or a failed assertion.
+    Program(LeoError),
+    /// The program uses a feature that the interpreter cannot evaluate.
+    Unsupported(LeoError),
+    /// The run used more of `resource` than its `limit` allows.
+    BudgetExceeded { resource: Resource, limit: u64, error: LeoError },
+}
+
+impl From<LeoError> for Halt {
+    fn from(error: LeoError) -> Self {
+        Self::Program(error)
+    }
+}
+
+impl From<Halt> for LeoError {
+    fn from(halt: Halt) -> Self {
+        match halt {
+            Halt::Program(error) | Halt::Unsupported(error) | Halt::BudgetExceeded { error, .. } => error,
+        }
+    }
+}
+
+/// Returns an `Unsupported` halt for `operation`.
+pub(crate) fn unsupported<T>(operation: impl Display, span: Span) -> Result<T, Halt> {
+    Err(Halt::Unsupported(InterpreterError::unsupported_operation(operation, span).into()))
+}
+
+/// Returns the value of an integer of type `type_` written as `digits`, if it fits in the type.
+fn integer_value(type_: IntegerType, digits: &str, span: Span) -> Option<Value> {
+    Value::try_from(&Literal::Integer(type_, digits.to_string(), span, NodeID::default())).ok()
+}
+
+/// Returns the value of type `type_` with the lowest bits of `bits`, in two's complement.
+pub(crate) fn wrapped_integer_value(type_: IntegerType, bits: u128, span: Span) -> Value {
+    match type_ {
+        IntegerType::U8 => Value::U8(bits as u8, span),
+        IntegerType::U16 => Value::U16(bits as u16, span),
+        IntegerType::U32 => Value::U32(bits as u32, span),
+        IntegerType::U64 => Value::U64(bits as u64, span),
+        IntegerType::U128 => Value::U128(bits, span),
+        IntegerType::I8 => Value::I8(bits as i8, span),
+        IntegerType::I16 => Value::I16(bits as i16, span),
+        IntegerType::I32 => Value::I32(bits as i32, span),
+        IntegerType::I64 => Value::I64(bits as i64, span),
+        IntegerType::I128 => Value::I128(bits as i128, span),
+    }
+}
+
+/// Returns the number of values held by `value`, counting the elements or members of an array, struct, or tuple.
+fn size(value: &Value) -> u64 {
+    match value {
+        Value::Array(elements) | Value::Tuple(elements) => elements.iter().map(size).sum(),
+        Value::Struct(_, members) => members.values().map(size).sum(),
+        _ => 1,
+    }
+}
+
+/// Returns `true` if `left` and `right` are the same value, ignoring their spans.
+fn equal(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Array(left), Value::Array(right)) | (Value::Tuple(left), Value::Tuple(right)) => {
+            left.len() == right.len() && left.iter().zip(right).all(|(left, right)| equal(left, right))
+        }
+        (Value::Struct(_, left), Value::Struct(_, right)) => {
+            left.len() == right.len()
+                && left.iter().all(|(name, left)| right.get(name).map_or(false, |right| equal(left, right)))
+        }
+        // Primitive values are equal if they are written the same way.
+        _ => left.to_string() == right.to_string(),
+    }
+}
+
+/// Splits the members of an array or struct value on the commas that are not nested in another value.
+fn split_members(input: &str) -> Vec<&str> {
+    let mut members = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in input.char_indices() {
+        match c {
+            '[' | '{' | '(' => depth += 1,
+            ']' | '}' | ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                members.push(&input[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    members.push(&input[start..]);
+    members.into_iter().map(str::trim).filter(|member| !member.is_empty()).collect()
+}
+
+/// An interpreter that evaluates the functions of a type-checked program on concrete values.
+pub struct Interpreter<'a> {
+    /// The program scope being interpreted.
+    program: &'a ProgramScope,
+    /// The types inferred by the type checker, used to give unsuffixed literals their integer type.
+    type_table: &'a TypeTable,
+    /// The values of the constants declared in the program scope.
+    constants: &'a ConstantEnvironment,
+    /// The variables of the functions being evaluated, innermost call last.
+    frames: Vec<IndexMap<Symbol, Value>>,
+    /// The resources that a run may use.
+    limits: InterpreterLimits,
+    /// The number of steps taken by the current run.
+    steps: u64,
+    /// The number of values held by the variables in `frames`.
+    memory: u64,
+    /// The trace of the current run, if tracing is enabled.
+    trace: Option<ExecutionTrace>,
+    /// The messages logged by `console.log` in the current run.
+    logs: Vec<String>,
+}
+
+impl<'a> Interpreter<'a> {
+    /// Returns a new interpreter for `program`, whose constants have the values in `constants`.
+    pub fn new(program: &'a ProgramScope, type_table: &'a TypeTable, constants: &'a ConstantEnvironment) -> Self {
+        Self {
+            program,
+            type_table,
+            constants,
+            frames: Default::default(),
+            limits: Default::default(),
+            steps: 0,
+            memory: 0,
+            trace: None,
+            logs: Vec::new(),
+        }
+    }
+
+    /// Returns the interpreter with the resources of each run limited by `limits`.
+    pub fn with_limits(mut self, limits: InterpreterLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Returns the interpreter with tracing enabled, so that each run records an `ExecutionTrace`.
+    pub fn with_tracing(mut self) -> Self {
+        self.trace = Some(ExecutionTrace::default());
+        self
+    }
+
+    /// Returns the trace of the last run, including a run that halted, if tracing is enabled.
+    pub fn trace(&self) -> Option<&ExecutionTrace> {
+        self.trace.as_ref()
+    }
+
+    /// Returns the messages logged by `console.log` in the last run, including a run that halted, in order.
+    pub fn logs(&self) -> &[String] {
+        &self.logs
+    }
+
+    /// Records the event constructed by `event`, if tracing is enabled.
+    fn record(&mut self, event: impl FnOnce() -> TraceEvent) {
+        if let Some(trace) = &mut self.trace {
+            trace.record(event());
+        }
+    }
+
+    /// Halts if the use of `resource` exceeds its limit.
+    fn check_budget(&self, resource: Resource, used: u64, limit: Option<u64>, span: Span) -> Result<(), Halt> {
+        match limit {
+            Some(limit) if used > limit => Err(Halt::BudgetExceeded {
+                resource,
+                limit,
+                error: InterpreterError::execution_budget_exceeded(limit, resource, span).into(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Counts a step of evaluation, halting if the run has taken too many steps.
+    fn step(&mut self, span: Span) -> Result<(), Halt> {
+        self.steps += 1;
+        self.check_budget(Resource::Steps, self.steps, self.limits.max_steps, span)
+    }
+
+    /// Returns the function named `name`, if it exists.
+    fn function(&self, name: Symbol) -> Option<&'a Function> {
+        self.program.functions.iter().find(|(function_name, _)| *function_name == name).map(|(_, function)| function)
+    }
+
+    /// Returns the struct or record named `name`, if it exists.
+    fn struct_(&self, name: Symbol) -> Option<&'a Struct> {
+        self.program.structs.iter().find(|(struct_name, _)| *struct_name == name).map(|(_, struct_)| struct_)
+    }
+
+    /// Returns the enum named `name`, if it exists.
+    fn enum_(&self, name: Symbol) -> Option<&'a Enum> {
+        self.program.enums.iter().find(|(enum_name, _)| *enum_name == name).map(|(_, enum_)| enum_)
+    }
+
+    /// Returns the value of the variable or constant named `name`.
+    fn lookup(&self, name: Symbol) -> Option<&Value> {
+        self.frames.last().and_then(|frame| frame.get(&name)).or_else(|| self.constants.lookup(name))
+    }
+
+    /// Binds `name` to `value` in the innermost call, halting if the variables hold too many values.
+    fn bind(&mut self, name: Symbol, value: Value, span: Span) -> Result<(), Halt> {
+        self.record(|| TraceEvent::Write { variable: name.to_string(), value: value.to_string(), span });
+        if let Some(frame) = self.frames.last_mut() {
+            self.memory += size(&value);
+            if let Some(previous) = frame.insert(name, value) {
+                self.memory -= size(&previous);
+            }
+        }
+        self.check_budget(Resource::Memory, self.memory, self.limits.max_memory, span)
+    }
+
+    /// Parses `inputs` as the values of the inputs of the function named `function`.
+    pub fn parse_inputs(&self, function: &str, inputs: &[String]) -> Result<Vec<Value>> {
+        let function =
+            self.function(Symbol::intern(function)).ok_or_else(|| InterpreterError::unknown_function(function))?;
+        if function.input.len() != inputs.len() {
+            return Err(
+                InterpreterError::wrong_number_of_inputs(function.name(), function.input.len(), inputs.len()).into()
+            );
+        }
+        function.input.iter().zip(inputs).map(|(input, value)| self.parse_value(value, &input.type_())).collect()
+    }
+
+    /// Returns the values that `input` binds to the inputs of the function named `function`.
+    /// A function without a section in the input file is given no inputs.
+    pub fn bound_inputs(&self, function: &str, input: &TypedInputAst) -> Result<Vec<Value>> {
+        self.parse_inputs(function, &input.function_inputs(Symbol::intern(function)).unwrap_or_default())
+    }
+
+    /// Parses `input`, written as an Aleo value, as a value of type `type_`.
+    /// Visibility suffixes such as `.private` and the `_nonce` of a record are ignored.
+    pub fn parse_value(&self, input: &str, type_: &Type) -> Result<Value> {
+        let invalid = || InterpreterError::invalid_input(input, type_);
+        let trimmed = input.trim();
+        let value = match type_ {
+            Type::Array(array_type) => {
+                let elements = trimmed.strip_prefix('[').and_then(|s| s.strip_suffix(']')).ok_or_else(invalid)?;
+                let elements = split_members(elements)
+                    .into_iter()
+                    .map(|element| self.parse_value(element, array_type.element_type()))
+                    .collect::<Result<Vec<_>>>()?;
+                if elements.len() != array_type.length() {
+                    return Err(invalid().into());
+                }
+                Value::Array(elements)
+            }
+            Type::Identifier(identifier) => {
+                let struct_ = self.struct_(identifier.name).ok_or_else(invalid)?;
+                let members = trimmed.strip_prefix('{').and_then(|s| s.strip_suffix('}')).ok_or_else(invalid)?;
+                let mut entries = split_members(members)
+                    .into_iter()
+                    .map(|entry| entry.split_once(':').map(|(name, value)| (name.trim(), value)).ok_or_else(invalid))
+                    .collect::<Result<IndexMap<_, _>, _>>()?;
+                entries.remove("_nonce");
+                let members = struct_
+                    .members
+                    .iter()
+                    .map(|member| {
+                        let entry = entries.remove(member.name().to_string().as_str()).ok_or_else(invalid)?;
+                        Ok((member.name(), self.parse_value(entry, &member.type_)?))
+                    })
+                    .collect::<Result<IndexMap<_, _>>>()?;
+                if !entries.is_empty() {
+                    return Err(invalid().into());
+                }
+                Value::Struct(struct_.identifier, members)
+            }
+            _ => {
+                let literal = [".private", ".public", ".constant"]
+                    .iter()
+                    .find_map(|visibility| trimmed.strip_suffix(visibility))
+                    .unwrap_or(trimmed);
+                Self::parse_literal(literal, type_).map(algebra::canonicalize).ok_or_else(invalid)?
+            }
+        };
+        Ok(value)
+    }
+
+    /// Parses `literal` as a value of the primitive type `type_`.
+    fn parse_literal(literal: &str, type_: &Type) -> Option<Value> {
+        let span = Span::default();
+        let number = |suffix: &str| {
+            let digits = literal.strip_suffix(suffix)?;
+            let magnitude = digits.strip_prefix('-').unwrap_or(digits);
+            (!magnitude.is_empty() && magnitude.chars().all(|c| c.is_ascii_digit())).then(|| digits.to_string())
+        };
+        match type_ {
+            Type::Address => literal.starts_with("aleo1").then(|| Value::Address(literal.to_string(), span)),
+            Type::Boolean => literal.parse().ok().map(|boolean| Value::Boolean(boolean, span)),
+            Type::Field => number("field").map(|field| Value::Field(field, span)),
+            Type::Group => number("group")
+                .map(|group| Value::Group(Box::new(GroupLiteral::Single(group, span, NodeID::default())))),
+            Type::Integer(integer_type) => integer_value(*integer_type, &number(&integer_type.to_string())?, span),
+            Type::Scalar => number("scalar").map(|scalar| Value::Scalar(scalar, span)),
+            _ => None,
+        }
+    }
+
+    /// Runs the function named `function` on `inputs`, returning its outputs.
+    pub fn run(&mut self, function: &str, inputs: Vec<Value>) -> Result<Vec<Value>, Halt> {
+        let function = self
+            .function(Symbol::intern(function))
+            .ok_or_else(|| Halt::Unsupported(InterpreterError::unknown_function(function).into()))?;
+        // A run that halted part way through may have left its calls on the stack.
+        self.frames.clear();
+        self.steps = 0;
+        self.memory = 0;
+        if let Some(trace) = &mut self.trace {
+            *trace = ExecutionTrace::default();
+        }
+        self.logs.clear();
+        let output = self.call(function, inputs)?;
+        Ok(match (&function.output_type, output) {
+            (Type::Unit, _) => Vec::new(),
+            (Type::Tuple(_), Value::Tuple(outputs)) => outputs,
+            (_, output) => vec![output],
+        })
+    }
+
+    /// Calls `function` on `arguments`, returning its output.
+    fn call(&mut self, function: &'a Function, arguments: Vec<Value>) -> Result<Value, Halt> {
+        self.frames.push(IndexMap::new());
+        for (input, argument) in function.input.iter().zip(arguments) {
+            self.bind(input.identifier().name, argument, function.span)?;
+        }
+        let output = self.eval_block(&function.block);
+        if let Some(frame) = self.frames.pop() {
+            self.memory -= frame.values().map(size).sum::<u64>();
+        }
+        Ok(output?.unwrap_or(Value::Tuple(Vec::new())))
+    }
+
+    /// Evaluates the statements in `block`, returning the returned value if a return statement is reached.
+    fn eval_block(&mut self, block: &'a Block) -> Result<Option<Value>, Halt> {
+        for statement in &block.statements {
+            if let Some(output) = self.eval_statement(statement)? {
+                return Ok(Some(output));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Evaluates `statement`, returning the returned value if a return statement is reached.
+    fn eval_statement(&mut self, statement: &'a Statement) -> Result<Option<Value>, Halt> {
+        self.step(statement.span())?;
+        self.record(|| TraceEvent::Statement { span: statement.span() });
+        match statement {
+            Statement::Assert(input) => {
+                match &input.variant {
+                    AssertVariant::Assert(condition) => self.eval_assertion(condition, input.span)?,
+                    AssertVariant::AssertEq(left, right) => self.eval_assert_eq(left, right, true, input.span)?,
+                    AssertVariant::AssertNeq(left, right) => self.eval_assert_eq(left, right, false, input.span)?,
+                }
+                Ok(None)
+            }
+            Statement::Assign(input) => {
+                let value = self.eval_expression(&input.value)?;
+                match &input.place {
+                    Expression::Identifier(identifier) => self.bind(identifier.name, value, input.span)?,
+                    place => return unsupported(place, input.span),
+                }
+                Ok(None)
+            }
+            Statement::Block(block) => self.eval_block(block),
+            Statement::Conditional(input) => {
+                let taken = self.eval_bool(&input.condition)?;
+                self.record(|| TraceEvent::Branch { taken, span: input.span });
+                if taken {
+                    self.eval_block(&input.then)
+                } else if let Some(otherwise) = &input.otherwise {
+                    self.eval_statement(otherwise)
+                } else {
+                    Ok(None)
+                }
+            }
+            Statement::Console(input) => {
+                match &input.function {
+                    ConsoleFunction::Assert(condition) => self.eval_assertion(condition, input.span)?,
+                    ConsoleFunction::AssertEq(left, right) => self.eval_assert_eq(left, right, true, input.span)?,
+                    ConsoleFunction::AssertNeq(left, right) => self.eval_assert_eq(left, right, false, input.span)?,
+                    ConsoleFunction::Log(args) => self.eval_log(args)?,
+                }
+                Ok(None)
+            }
+            Statement::Const(input) => {
+                let value = self.eval_expression(&input.value)?;
+                self.bind(input.place.name, value, input.span)?;
+                Ok(None)
+            }
+            // The variable is bound when it is assigned.
+            Statement::Declaration(_) => Ok(None),
+            Statement::Definition(input) => {
+                let value = self.eval_expression(&input.value)?;
+                self.bind_place(&input.place, value, input.span)?;
+                Ok(None)
+            }
+            Statement::Err(err) => unsupported(err, err.span),
+            Statement::Expression(input) => {
+                self.eval_expression(&input.expression)?;
+                Ok(None)
+            }
+            Statement::Iteration(input) => self.eval_iteration(input),
+            Statement::Match(input) => self.eval_match(input),
+            Statement::Return(input) => Ok(Some(self.eval_expression(&input.expression)?)),
+        }
+    }
+
+    /// Binds the variables in the place of a definition statement to `value`.
+    fn bind_place(&mut self, place: &Expression, value: Value, span: Span) -> Result<(), Halt> {
+        match (place, value) {
+            (Expression::Identifier(identifier), value) => self.bind(identifier.name, value, span)?,
+            (Expression::Tuple(tuple), Value::Tuple(values)) => {
+                for (element, value) in tuple.elements.iter().zip(values) {
+                    self.bind_place(element, value, span)?;
+                }
+            }
+            (Expression::Struct(pattern), Value::Struct(_, mut members)) => {
+                for member in &pattern.members {
+                    let binding = match &member.expression {
+                        Some(Expression::Identifier(identifier)) => identifier,
+                        _ => &member.identifier,
+                    };
+                    match members.remove(&member.identifier.name) {
+                        Some(value) => self.bind(binding.name, value, span)?,
+                        None => return unsupported(member, member.span),
+                    }
+                }
+            }
+            (place, _) => return unsupported(place, span),
+        }
+        Ok(())
+    }
+
+    /// Halts if `condition` does not hold.
+    fn eval_assertion(&mut self, condition: &'a Expression, span: Span) -> Result<(), Halt> {
+        match self.eval_bool(condition)? {
+            true => Ok(()),
+            false => Err(Halt::Program(InterpreterError::assertion_failed(condition, span).into())),
+        }
+    }
+
+    /// Halts if the equality of `left` and `right` is not `expected`.
+    fn eval_assert_eq(
+        &mut self,
+        left: &'a Expression,
+        right: &'a Expression,
+        expected: bool,
+        span: Span,
+    ) -> Result<(), Halt> {
+        let (left_value, right_value) = (self.eval_expression(left)?, self.eval_expression(right)?);
+        match equal(&left_value, &right_value) == expected {
+            true => Ok(()),
+            false => {
+                let operator = if expected { "==" } else { "!=" };
+                Err(Halt::Program(
+                    InterpreterError::assertion_failed(format!("{left} {operator} {right}"), span).into(),
+                ))
+            }
+        }
+    }
+
+    /// Logs the format string of `args`, with each placeholder replaced by the value of its argument.
+    fn eval_log(&mut self, args: &'a ConsoleArgs) -> Result<(), Halt> {
+        let mut parameters = args.parameters.iter();
+        let mut message = String::new();
+        for part in &args.string {
+            match part {
+                FormatStringPart::Const(text) => message.push_str(text),
+                // Type checking guarantees that there is an argument for each placeholder.
+                FormatStringPart::Container => {
+                    let value = self.eval_expression(parameters.next().unwrap())?;
+                    message.push_str(&value.to_string());
+                }
+            }
+        }
+        self.logs.push(message);
+        Ok(())
+    }
+
+    /// Evaluates the body of a loop for each value of the loop variable.
+    fn eval_iteration(&mut self, input: &'a IterationStatement) -> Result<Option<Value>, Halt> {
+        let Type::Integer(integer_type) = input.type_ else {
+            return unsupported(&input.type_, input.span);
+        };
+        let start = i128::try_from(self.eval_expression(&input.start)?)?;
+        let stop = i128::try_from(self.eval_expression(&input.stop)?)?;
+        for i in (start..=stop).take_while(|i| input.inclusive || *i < stop) {
+            match integer_value(integer_type, &i.to_string(), input.variable.span) {
+                Some(value) => self.bind(input.variable.name, value, input.variable.span)?,
+                None => return unsupported(input, input.span),
+            }
+            if let Some(output) = self.eval_block(&input.block)? {
+                return Ok(Some(output));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Evaluates the block of the first arm whose pattern matches the value.
+    fn eval_match(&mut self, input: &'a MatchStatement) -> Result<Option<Value>, Halt> {
+        let value = self.eval_expression(&input.value)?;
+        for arm in &input.arms {
+            let taken = match &arm.pattern {
+                Some(pattern) => equal(&value, &self.eval_expression(pattern)?),
+                None => true,
+            };
+            self.record(|| TraceEvent::Branch { taken, span: arm.span });
+            if taken {
+                return self.eval_block(&arm.block);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Evaluates `expression` to a boolean.
+    fn eval_bool(&mut self, expression: &'a Expression) -> Result<bool, Halt> {
+        match self.eval_expression(expression)? {
+            Value::Boolean(boolean, _) => Ok(boolean),
+            _ => unsupported(expression, expression.span()),
+        }
+    }
+
+    /// Evaluates `expression` to a value.
+    fn eval_expression(&mut self, expression: &'a Expression) -> Result<Value, Halt> {
+        self.step(expression.span())?;
+        match expression {
+            Expression::Access(access) => self.eval_access(access),
+            Expression::Array(array) => Ok(Value::Array(
+                array.elements.iter().map(|element| self.eval_expression(element)).collect::<Result<_, _>>()?,
+            )),
+            Expression::Binary(binary) => self.eval_binary(binary),
+            Expression::Block(block) => {
+                // Type checking guarantees that the statements of a block expression do not return.
+                for statement in &block.statements {
+                    self.eval_statement(statement)?;
+                }
+                self.eval_expression(&block.value)
+            }
+            Expression::Call(call) => self.eval_call(call),
+            Expression::Cast(cast) => self.eval_cast(cast),
+            Expression::Struct(struct_) => self.eval_struct_init(struct_),
+            Expression::Err(err) => unsupported(err, err.span),
+            Expression::Identifier(identifier) => match self.lookup(identifier.name) {
+                Some(value) => Ok(value.clone()),
+                None => unsupported(identifier, identifier.span),
+            },
+            Expression::Literal(literal) => match Value::try_from(&self.infer_literal_type(literal)) {
+                Ok(value) => Ok(algebra::canonicalize(value)),
+                Err(_) => unsupported(literal, literal.span()),
+            },
+            Expression::Ternary(ternary) => {
+                let taken = self.eval_bool(&ternary.condition)?;
+                self.record(|| TraceEvent::Branch { taken, span: ternary.span });
+                match taken {
+                    true => self.eval_expression(&ternary.if_true),
+                    false => self.eval_expression(&ternary.if_false),
+                }
+            }
+            Expression::Tuple(tuple) => Ok(Value::Tuple(
+                tuple.elements.iter().map(|element| self.eval_expression(element)).collect::<Result<_, _>>()?,
+            )),
+            Expression::Unary(unary) => self.eval_unary(unary),
+            Expression::Unit(_) => Ok(Value::Tuple(Vec::new())),
+        }
+    }
+
+    /// Evaluates an access expression.
+    fn eval_access(&mut self, input: &'a AccessExpression) -> Result<Value, Halt> {
+        match input {
+            AccessExpression::Array(access) => self.eval_index(&access.array, &access.index, access, access.span),
+            AccessExpression::Member(access) => {
+                // Members of `self` and `block` are only known when the program is executed on-chain.
+                let inner = match access.inner.as_ref() {
+                    Expression::Identifier(identifier) if self.lookup(identifier.name).is_none() => {
+                        return unsupported(access, access.span);
+                    }
+                    inner => self.eval_expression(inner)?,
+                };
+                match inner {
+                    Value::Struct(_, mut members) => match members.remove(&access.name.name) {
+                        Some(member) => Ok(member),
+                        None => unsupported(access, access.span),
+                    },
+                    _ => unsupported(access, access.span),
+                }
+            }
+            AccessExpression::Tuple(access) => match self.eval_expression(&access.tuple)? {
+                Value::Tuple(elements) => match elements.into_iter().nth(access.index.value()) {
+                    Some(element) => Ok(element),
+                    None => unsupported(access, access.span),
+                },
+                _ => unsupported(access, access.span),
+            },
+            AccessExpression::AssociatedConstant(constant) => match &constant.ty {
+                // A variant of an enum is represented by its index in the enum.
+                Type::Identifier(ty) => {
+                    match self.enum_(ty.name).and_then(|enum_| enum_.variant_index(constant.name.name)) {
+                        Some(index) => Ok(wrapped_integer_value(Enum::REPRESENTATION, index as u128, constant.span)),
+                        None => unsupported(constant, constant.span),
+                    }
+                }
+                _ => unsupported(constant, constant.span),
+            },
+            AccessExpression::AssociatedFunction(function) => match &function.ty {
+                Type::Identifier(ty)
+                    if CoreFunction::from_symbols(ty.name, function.name.name) == Some(CoreFunction::ArrayLookup) =>
+                {
+                    self.eval_index(&function.arguments[0], &function.arguments[1], function, function.span)
+                }
+                _ => unsupported(function, function.span),
+            },
+        }
+    }
+
+    /// Evaluates the element of `array` at `index`, as in an array access or a lookup.
+    fn eval_index(
+        &mut self,
+        array: &'a Expression,
+        index: &'a Expression,
+        operation: impl Display,
+        span: Span,
+    ) -> Result<Value, Halt> {
+        let array = self.eval_expression(array)?;
+        let index = self.eval_expression(index)?;
+        let Value::Array(mut elements) = array else {
+            return unsupported(operation, span);
+        };
+        match usize::try_from(u128::try_from(&index)?) {
+            Ok(i) if i < elements.len() => Ok(elements.swap_remove(i)),
+            _ => Err(Halt::Program(InterpreterError::index_out_of_bounds(index, elements.len(), span).into())),
+        }
+    }
+
+    /// Evaluates a binary expression.
+    fn eval_binary(&mut self, input: &'a BinaryExpression) -> Result<Value, Halt> {
+        let left = self.eval_expression(&input.left)?;
+        let right = self.eval_expression(&input.right)?;
+        let span = input.span;
+        match input.op {
+            BinaryOperation::Eq => return Ok(Value::Boolean(equal(&left, &right), span)),
+            BinaryOperation::Neq => return Ok(Value::Boolean(!equal(&left, &right), span)),
+            _ => {}
+        }
+        if let Some(output) = algebra::eval_binary(input.op, &left, &right, input, span) {
+            return output;
+        }
+        if !left.is_supported_const_fold_type() || !right.is_supported_const_fold_type() {
+            return unsupported(input, span);
+        }
+        Ok(match input.op {
+            BinaryOperation::Add => left.add(right, span)?,
+            BinaryOperation::AddWrapped => left.add_wrapped(right, span)?,
+            BinaryOperation::And | BinaryOperation::BitwiseAnd => left.bitand(right, span)?,
+            BinaryOperation::Div => left.div(right, span)?,
+            BinaryOperation::DivWrapped => left.div_wrapped(right, span)?,
+            BinaryOperation::Gte => left.ge(right, span)?,
+            BinaryOperation::Gt => left.gt(right, span)?,
+            BinaryOperation::Lte => left.le(right, span)?,
+            BinaryOperation::Lt => left.lt(right, span)?,
+            // `mod` is only defined on unsigned integers, where it coincides with the remainder.
+            BinaryOperation::Mod | BinaryOperation::Rem => left.rem(right, span)?,
+            BinaryOperation::Mul => left.mul(right, span)?,
+            BinaryOperation::MulWrapped => left.mul_wrapped(right, span)?,
+            BinaryOperation::Nand => left.bitand(right, span)?.not(span)?,
+            BinaryOperation::Nor => left.bitor(right, span)?.not(span)?,
+            BinaryOperation::Or | BinaryOperation::BitwiseOr => left.bitor(right, span)?,
+            BinaryOperation::Pow => left.pow(right, span)?,
+            BinaryOperation::PowWrapped => left.pow_wrapped(right, span)?,
+            BinaryOperation::RemWrapped => left.rem_wrapped(right, span)?,
+            BinaryOperation::Shl => left.shl(right, span)?,
+            BinaryOperation::ShlWrapped => left.shl_wrapped(right, span)?,
+            BinaryOperation::Shr => left.shr(right, span)?,
+            BinaryOperation::ShrWrapped => left.shr_wrapped(right, span)?,
+            BinaryOperation::Sub => left.sub(right, span)?,
+            BinaryOperation::SubWrapped => left.sub_wrapped(right, span)?,
+            BinaryOperation::Xor => left.xor(right, span)?,
+            BinaryOperation::Eq | BinaryOperation::Neq => unreachable!("Equality is evaluated above."),
+        })
+    }
+
+    /// Evaluates a call to a function in the program.
+    fn eval_call(&mut self, input: &'a CallExpression) -> Result<Value, Halt> {
+        let function = match (&input.external, input.function.as_ref()) {
+            (None, Expression::Identifier(identifier)) => self.function(identifier.name),
+            _ => None,
+        };
+        let Some(function) = function else {
+            return unsupported(input, input.span);
+        };
+        let arguments =
+            input.arguments.iter().map(|argument| self.eval_expression(argument)).collect::<Result<_, _>>()?;
+        self.call(function, arguments)
+    }
+
+    /// Evaluates a cast expression, halting if the value does not fit in the target type.
+    fn eval_cast(&mut self, input: &'a CastExpression) -> Result<Value, Halt> {
+        let value = self.eval_expression(&input.expression)?;
+        if let Some(output) = algebra::eval_cast(&value, &input.type_, input.wrapped, input, input.span) {
+            return output;
+        }
+        let digits = match &value {
+            Value::Boolean(boolean, _) => u8::from(*boolean).to_string(),
+            value if value.is_supported_const_fold_type() => match i128::try_from(value) {
+                Ok(integer) => integer.to_string(),
+                Err(_) => u128::try_from(value)?.to_string(),
+            },
+            _ => return unsupported(input, input.span),
+        };
+        match &input.type_ {
+            Type::Integer(integer_type) if input.wrapped => {
+                match digits.parse::<i128>().map(|integer| integer as u128).or_else(|_| digits.parse::<u128>()) {
+                    Ok(bits) => Ok(wrapped_integer_value(*integer_type, bits, input.span)),
+                    Err(_) => unsupported(input, input.span),
+                }
+            }
+            Type::Integer(integer_type) => integer_value(*integer_type, &digits, input.span)
+                .ok_or_else(|| Halt::Program(InterpreterError::cast_overflow(value, &input.type_, input.span).into())),
+            _ => unsupported(input, input.span),
+        }
+    }
+
+    /// Evaluates a struct or record initializer.
+    fn eval_struct_init(&mut self, input: &'a StructExpression) -> Result<Value, Halt> {
+        let mut members = IndexMap::new();
+        for member in &input.members {
+            let value = match &member.expression {
+                Some(expression) => self.eval_expression(expression)?,
+                None => match self.lookup(member.identifier.name) {
+                    Some(value) => value.clone(),
+                    None => return unsupported(member.identifier, member.span),
+                },
+            };
+            members.insert(member.identifier.name, value);
+        }
+        // Order the members as they are declared, which is the order in which Aleo values are written.
+        // An omitted member takes its default value.
+        if let Some(struct_) = self.struct_(input.name.name) {
+            let mut ordered = IndexMap::new();
+            for member in &struct_.members {
+                let value = match (members.remove(&member.name()), &member.default) {
+                    (Some(value), _) => value,
+                    (None, Some(default)) => self.eval_expression(default)?,
+                    (None, None) => continue,
+                };
+                ordered.insert(member.name(), value);
+            }
+            members = ordered;
+        }
+        Ok(Value::Struct(input.name, members))
+    }
+
+    /// Returns `literal` with the integer type inferred for it by the type checker, if it is unsuffixed.
+    fn infer_literal_type(&self, literal: &Literal) -> Literal {
+        match self.type_table.get(&literal.id()) {
+            Some(type_) => literal.clone().with_inferred_type(&type_),
+            None => literal.clone(),
+        }
+    }
+
+    /// Evaluates a unary expression.
+    fn eval_unary(&mut self, input: &'a UnaryExpression) -> Result<Value, Halt> {
+        // Negated integer literals are evaluated directly, so that the minimum value of a signed type can be written.
+        if let (UnaryOperation::Negate, Expression::Literal(Literal::Integer(type_, digits, ..))) =
+            (input.op, input.receiver.as_ref())
+        {
+            if let Some(value) = integer_value(*type_, &format!("-{digits}"), input.span) {
+                return Ok(value);
+            }
+        }
+        let receiver = self.eval_expression(&input.receiver)?;
+        if let Some(output) = algebra::eval_unary(input.op, &receiver, input, input.span) {
+            return output;
+        }
+        if !receiver.is_supported_const_fold_type() {
+            return unsupported(input, input.span);
+        }
+        Ok(match input.op {
+            UnaryOperation::Abs => receiver.abs(input.span)?,
+            UnaryOperation::AbsWrapped => receiver.abs_wrapped(input.span)?,
+            UnaryOperation::Negate => receiver.neg(input.span)?,
+            UnaryOperation::Not => receiver.not(input.span)?,
+            _ => return unsupported(input, input.span),
+        })
+    }
+}
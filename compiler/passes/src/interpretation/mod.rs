@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The interpreter evaluates the functions of a type-checked program on concrete values, without generating a circuit.
+//! The program is evaluated as written, so only the taken branch of a conditional is evaluated.
+//! Finalize blocks are not evaluated, since they are executed on-chain.
+//! The messages of `console.log` statements are rendered and collected, while they are removed from compiled programs.
+//!
+//! The interpreter supports booleans, integers, addresses, fields, groups, scalars, structs, records, arrays,
+//! and tuples. The arithmetic of fields, groups, and scalars is emulated, with group elements written as group literals.
+//! Core functions and calls to other programs are reported as unsupported.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, b: u8) -> u8 {
+//!     return a + b;
+//! }
+//! ```
+//!
+//! Running `main` on the inputs `1u8` and `2u8` returns `3u8`, while running it on `255u8` and `1u8` halts on the overflow.
+//!
+//! The number of steps and the memory used by a run can be limited with [`InterpreterLimits`],
+//! in which case a run that exceeds them stops with [`Halt::BudgetExceeded`].
+//! A run can also record an [`ExecutionTrace`] of the statements it evaluates, the variables it writes,
+//! and the branches it takes.
+//!
+//! The test vectors of a program can be run through the interpreter to produce a fixture of expected outputs,
+//! in the same format as the results of an execution test.
+//! The functions annotated with `@test` can be run with the [`TestRunner`], which reports whether each one passed.
+
+pub mod algebra;
+pub use algebra::*;
+
+pub mod interpreter;
+pub use interpreter::*;
+
+pub mod test_runner;
+pub use test_runner::*;
+
+pub mod test_vectors;
+pub use test_vectors::*;
+
+pub mod trace;
+pub use trace::*;
@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ConstantEnvironment, Interpreter, InterpreterLimits, TypeTable};
+
+use leo_ast::ProgramScope;
+use leo_errors::LeoError;
+use leo_span::Span;
+
+use serde::{Deserialize, Serialize};
+
+/// The outcome of running a test function.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TestOutcome {
+    /// The test returned without halting.
+    Passed,
+    /// The test halted, e.g. on a failed assertion, at the location given by `span`.
+    Failed { message: String, span: Option<Span> },
+}
+
+/// The result of running a test function.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestResult {
+    /// The name of the test function.
+    pub name: String,
+    /// The outcome of the run.
+    pub outcome: TestOutcome,
+    /// The messages logged by `console.log` during the run.
+    pub logs: Vec<String>,
+}
+
+/// The results of running the test functions of a program, in the order they are declared.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestReport {
+    pub results: Vec<TestResult>,
+}
+
+impl TestReport {
+    /// Returns the number of tests that passed.
+    pub fn passed(&self) -> usize {
+        self.results.iter().filter(|result| result.outcome == TestOutcome::Passed).count()
+    }
+
+    /// Returns the number of tests that failed.
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.passed()
+    }
+
+    /// Returns `true` if every test passed.
+    pub fn is_success(&self) -> bool {
+        self.failed() == 0
+    }
+}
+
+/// Runs the functions annotated with `@test` through the interpreter.
+/// A test passes if it returns, and fails if it halts, including when it uses a feature that the interpreter does not
+/// support or exceeds the resources it may use.
+pub struct TestRunner;
+
+impl TestRunner {
+    /// Runs the test functions of `program`, which must be type checked, and whose constants have the values in
+    /// `constants`.
+    pub fn run(
+        program: &ProgramScope,
+        type_table: &TypeTable,
+        constants: &ConstantEnvironment,
+        limits: InterpreterLimits,
+    ) -> TestReport {
+        let mut interpreter = Interpreter::new(program, type_table, constants).with_limits(limits);
+        let results = program
+            .functions
+            .iter()
+            .filter(|(_, function)| function.is_test())
+            .map(|(name, _)| {
+                let name = name.to_string();
+                let outcome = match interpreter.run(&name, Vec::new()) {
+                    Ok(_) => TestOutcome::Passed,
+                    Err(halt) => Self::failure(halt.into()),
+                };
+                TestResult { name, outcome, logs: interpreter.logs().to_vec() }
+            })
+            .collect();
+        TestReport { results }
+    }
+
+    /// Returns the outcome of a test that halted with `error`.
+    fn failure(error: LeoError) -> TestOutcome {
+        match error.parts() {
+            Some((backtraced, span)) => TestOutcome::Failed { message: backtraced.message.clone(), span },
+            None => TestOutcome::Failed { message: error.to_string(), span: None },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::{parse_ast, program_scope};
+
+    use leo_span::symbol::{create_session_if_not_set_then, with_session_globals};
+
+    const PROGRAM: &str = "program test.aleo {
+    function add_one(a: u8) -> u8 {
+        return a + 1u8;
+    }
+
+    transition main(a: u8) -> u8 {
+        return add_one(a);
+    }
+
+    @test
+    transition test_add_one() {
+        assert_eq(add_one(1u8), 2u8);
+    }
+
+    @test
+    transition test_add_one_fails() {
+        let b: u8 = add_one(2u8);
+        console.log(\"b is {}\", b);
+        assert(b == 4u8);
+    }
+
+    @test
+    function test_overflow() -> u8 {
+        return add_one(255u8);
+    }
+}
+";
+
+    #[test]
+    fn test_run_tests() {
+        create_session_if_not_set_then(|_| {
+            let ast = parse_ast(PROGRAM);
+            let program = program_scope(&ast);
+            let report =
+                TestRunner::run(program, &TypeTable::default(), &Default::default(), InterpreterLimits::default());
+
+            let names = report.results.iter().map(|result| result.name.as_str()).collect::<Vec<_>>();
+            assert_eq!(names, ["test_add_one", "test_add_one_fails", "test_overflow"]);
+            assert_eq!(report.results[0].outcome, TestOutcome::Passed);
+            assert_eq!((report.passed(), report.failed(), report.is_success()), (1, 2, false));
+
+            // A failed assertion is reported at the assertion.
+            let TestOutcome::Failed { message, span: Some(span) } = &report.results[1].outcome else {
+                panic!("Expected `test_add_one_fails` to fail at a span.")
+            };
+            assert_eq!(message, "The assertion `b == 4u8` failed.");
+            let line = with_session_globals(|s| s.source_map.line_contents_of_span(*span)).unwrap();
+            assert_eq!(line.trim(), "assert(b == 4u8);");
+            // The messages logged before the failure are kept.
+            assert_eq!(report.results[1].logs, ["b is 3u8"]);
+
+            let TestOutcome::Failed { message, .. } = &report.results[2].outcome else {
+                panic!("Expected `test_overflow` to fail.")
+            };
+            assert!(message.contains("overflow"), "{message}");
+        })
+    }
+}
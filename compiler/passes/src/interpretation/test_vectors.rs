@@ -0,0 +1,263 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ConstantEnvironment, Halt, Interpreter, InterpreterLimits, TypeTable};
+
+use leo_ast::ProgramScope;
+use leo_errors::{InterpreterError, Result};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, path::PathBuf};
+
+/// The inputs to run a function on, written as Aleo values, e.g. `["1u32", "true"]`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVector {
+    pub input: Vec<String>,
+}
+
+/// The test vectors of a program, keyed by the name of the function they are run on.
+/// This is the format of the `cases` of an execution test, e.g. `{ "main": [{ "input": ["1u32"] }] }`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TestVectors(pub BTreeMap<String, Vec<TestVector>>);
+
+impl TestVectors {
+    /// Deserializes the JSON string into test vectors.
+    pub fn from_json_string(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json).map_err(InterpreterError::failed_to_parse_test_vectors)?)
+    }
+
+    /// Deserializes the JSON file into test vectors.
+    pub fn from_json_file(path: PathBuf) -> Result<Self> {
+        let data =
+            std::fs::read_to_string(&path).map_err(|e| InterpreterError::failed_to_read_test_vectors(&path, e))?;
+        Self::from_json_string(&data)
+    }
+}
+
+/// The result of running a function on a test vector.
+/// Values are written as in the results of an execution test, so that the results of the interpreter and of snarkVM can be compared directly.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVectorResult {
+    /// The inputs, e.g. `[1u32, true]`.
+    pub input: String,
+    /// The outputs, e.g. `[2u32]`, or `Halt(<reason>)` if the program halted.
+    pub output: String,
+}
+
+/// The expected results of running a program on its test vectors, keyed by function name.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TestVectorFixture(pub BTreeMap<String, Vec<TestVectorResult>>);
+
+impl TestVectorFixture {
+    /// Runs the interpreter on each of the test vectors and collects the results.
+    /// Returns an error if an input is invalid, if the program uses a feature that the interpreter does not support,
+    /// or if a run exceeds the given `limits`.
+    pub fn generate(
+        program: &ProgramScope,
+        type_table: &TypeTable,
+        constants: &ConstantEnvironment,
+        test_vectors: &TestVectors,
+        limits: InterpreterLimits,
+    ) -> Result<Self> {
+        let mut interpreter = Interpreter::new(program, type_table, constants).with_limits(limits);
+        let mut fixture = BTreeMap::new();
+        for (function, vectors) in &test_vectors.0 {
+            let mut results = Vec::with_capacity(vectors.len());
+            for vector in vectors {
+                let inputs = interpreter.parse_inputs(function, &vector.input)?;
+                let input = format!("[{}]", inputs.iter().join(", "));
+                let output = match interpreter.run(function, inputs) {
+                    Ok(outputs) => format!("[{}]", outputs.iter().join(", ")),
+                    Err(Halt::Program(error)) => match error.parts() {
+                        Some((backtraced, _)) => format!("Halt({})", backtraced.message),
+                        None => format!("Halt({error})"),
+                    },
+                    Err(Halt::Unsupported(error) | Halt::BudgetExceeded { error, .. }) => return Err(error),
+                };
+                results.push(TestVectorResult { input, output });
+            }
+            fixture.insert(function.clone(), results);
+        }
+        Ok(Self(fixture))
+    }
+
+    /// Serializes the fixture into a JSON file.
+    pub fn to_json_file(&self, mut path: PathBuf, file_name: &str) -> Result<()> {
+        path.push(file_name);
+        let json = serde_json::to_string_pretty(&self)
+            .map_err(|e| InterpreterError::failed_to_write_test_vector_fixture(&path, e))?;
+        std::fs::write(&path, json).map_err(|e| InterpreterError::failed_to_write_test_vector_fixture(&path, e))?;
+        Ok(())
+    }
+
+    /// Deserializes the JSON file into a fixture.
+    pub fn from_json_file(path: PathBuf) -> Result<Self> {
+        let data =
+            std::fs::read_to_string(&path).map_err(|e| InterpreterError::failed_to_read_test_vectors(&path, e))?;
+        Ok(serde_json::from_str(&data).map_err(InterpreterError::failed_to_parse_test_vectors)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utilities::{parse_ast, program_scope},
+        ConstantResolver,
+        Pass,
+    };
+
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const PROGRAM: &str = "program test.aleo {
+    const SCALE: u8 = 2u8;
+
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    function scale(p: Point) -> Point {
+        return Point { y: p.y * SCALE, x: p.x * SCALE };
+    }
+
+    transition main(p: Point, n: u8) -> (Point, u8) {
+        let sum: u8 = 0u8;
+        for i: u8 in 0u8..n {
+            sum += i;
+        }
+        if sum > 5u8 {
+            sum = sum - 5u8;
+        } else {
+            sum = sum + 100u8;
+        }
+        return (scale(p), sum);
+    }
+
+    transition div(a: i8, b: i8) -> i8 {
+        return a / b;
+    }
+
+    transition middle(a: [u8; 3]) -> u8 {
+        let first: u8 = a[0u8];
+        let last: u8 = a[2u8];
+        assert(first <= last);
+        return a[1u8];
+    }
+
+    transition truncate(a: u16, b: i8) -> (u8, u16) {
+        return (a as.wrapped u8, b as.wrapped u16);
+    }
+
+    transition sbox(a: u8) -> u8 {
+        return Array::lookup([3u8, 0u8, 2u8, 1u8], a);
+    }
+
+    transition double(a: field) -> field {
+        return a + a;
+    }
+
+    transition hash(a: field) -> field {
+        return Poseidon2::hash_to_field(a);
+    }
+}
+";
+
+    fn generate(test_vectors: &str) -> Result<TestVectorFixture> {
+        generate_with_limits(test_vectors, InterpreterLimits::default())
+    }
+
+    fn generate_with_limits(test_vectors: &str, limits: InterpreterLimits) -> Result<TestVectorFixture> {
+        let (ast, constants) = ConstantResolver::do_pass((parse_ast(PROGRAM), &Handler::default())).unwrap();
+        let program = program_scope(&ast);
+        let test_vectors = TestVectors::from_json_string(test_vectors)?;
+        TestVectorFixture::generate(program, &TypeTable::default(), &constants, &test_vectors, limits)
+    }
+
+    #[test]
+    fn test_generate_fixture() {
+        create_session_if_not_set_then(|_| {
+            let fixture = generate(
+                r#"{
+                    "main": [
+                        { "input": ["{ x: 1u8, y: 2u8 }", "4u8"] },
+                        { "input": ["{ y: 3u8.private, x: 0u8.private }", "2u8"] }
+                    ],
+                    "div": [{ "input": ["7i8", "-2i8"] }, { "input": ["-128i8", "-1i8"] }],
+                    "middle": [{ "input": ["[1u8, 5u8, 3u8]"] }, { "input": ["[4u8, 5u8, 3u8]"] }],
+                    "truncate": [{ "input": ["300u16", "-1i8"] }],
+                    "sbox": [{ "input": ["2u8"] }, { "input": ["4u8"] }],
+                    "double": [{ "input": ["3field"] }]
+                }"#,
+            )
+            .unwrap();
+
+            let outputs =
+                |function: &str| fixture.0[function].iter().map(|result| result.output.as_str()).collect_vec();
+            assert_eq!(fixture.0["main"][0].input, "[{\n  x: 1u8,\n  y: 2u8\n}, 4u8]");
+            assert_eq!(outputs("main"), ["[{\n  x: 2u8,\n  y: 4u8\n}, 1u8]", "[{\n  x: 0u8,\n  y: 6u8\n}, 101u8]"]);
+            assert_eq!(outputs("div"), ["[-3i8]", "Halt(The const operation `-128i8 / -1i8` causes an overflow.)"]);
+            assert_eq!(outputs("middle"), ["[5u8]", "Halt(The assertion `first <= last` failed.)"]);
+            assert_eq!(outputs("truncate"), ["[44u8, 65535u16]"]);
+            assert_eq!(outputs("sbox"), ["[2u8]", "Halt(The index `4u8` is out of bounds for an array of length 4.)"]);
+            assert_eq!(outputs("double"), ["[6field]"]);
+
+            let json = serde_json::to_string(&fixture).unwrap();
+            assert_eq!(serde_json::from_str::<TestVectorFixture>(&json).unwrap(), fixture);
+        })
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        create_session_if_not_set_then(|_| {
+            assert!(generate(r#"{ "div": [{ "input": ["7u8", "2i8"] }] }"#).is_err());
+            assert!(generate(r#"{ "div": [{ "input": ["7i8"] }] }"#).is_err());
+            assert!(generate(r#"{ "main": [{ "input": ["{ x: 1u8 }", "4u8"] }] }"#).is_err());
+            assert!(generate(r#"{ "middle": [{ "input": ["[1u8, 2u8]"] }] }"#).is_err());
+            assert!(generate(r#"{ "missing": [{ "input": [] }] }"#).is_err());
+        })
+    }
+
+    #[test]
+    fn test_unsupported_operation() {
+        create_session_if_not_set_then(|_| {
+            let error = generate(r#"{ "hash": [{ "input": ["1field"] }] }"#).unwrap_err();
+            assert!(error.to_string().contains("The interpreter does not support `Poseidon2::hash_to_field`."));
+        })
+    }
+
+    #[test]
+    fn test_execution_budget() {
+        create_session_if_not_set_then(|_| {
+            let main = r#"{ "main": [{ "input": ["{ x: 1u8, y: 2u8 }", "20u8"] }] }"#;
+            let steps = |max_steps| InterpreterLimits { max_steps: Some(max_steps), ..Default::default() };
+            assert!(generate_with_limits(main, steps(1000)).is_ok());
+            let error = generate_with_limits(main, steps(50)).unwrap_err();
+            assert!(error.to_string().contains("The execution exceeded its budget of 50 steps."));
+
+            // `middle` holds the 3 elements of its input and 2 more variables.
+            let middle = r#"{ "middle": [{ "input": ["[1u8, 5u8, 3u8]"] }] }"#;
+            let memory = |max_memory| InterpreterLimits { max_memory: Some(max_memory), ..Default::default() };
+            assert!(generate_with_limits(middle, memory(5)).is_ok());
+            let error = generate_with_limits(middle, memory(4)).unwrap_err();
+            assert!(error.to_string().contains("The execution exceeded its budget of 4 values in memory."));
+        })
+    }
+}
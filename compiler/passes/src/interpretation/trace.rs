@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::Span;
+
+use serde::{Deserialize, Serialize};
+
+/// An event in a run of the interpreter.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    /// A statement was evaluated.
+    Statement { span: Span },
+    /// A variable was written, e.g. by a definition or an assignment, or on entry to a call.
+    Write { variable: String, value: String, span: Span },
+    /// A conditional statement or ternary expression took the branch given by the value of its condition.
+    Branch { taken: bool, span: Span },
+}
+
+/// The events of a run of the interpreter, in the order in which they happened.
+/// A trace is recorded if tracing is enabled with `Interpreter::with_tracing`.
+/// It gives debuggers and coverage reporters the path taken through a program, without instrumenting the interpreter.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ExecutionTrace {
+    events: Vec<TraceEvent>,
+}
+
+impl ExecutionTrace {
+    /// Records `event`.
+    pub(crate) fn record(&mut self, event: TraceEvent) {
+        self.events.push(event);
+    }
+
+    /// Returns the recorded events.
+    pub fn events(&self) -> &[TraceEvent] {
+        &self.events
+    }
+
+    /// Returns the spans of the evaluated statements, in the order in which they were evaluated.
+    pub fn statements(&self) -> impl Iterator<Item = Span> + '_ {
+        self.events.iter().filter_map(|event| match event {
+            TraceEvent::Statement { span } => Some(*span),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utilities::{parse_ast, program_scope},
+        ConstantEnvironment,
+        Interpreter,
+        TypeTable,
+    };
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const PROGRAM: &str = "program test.aleo {
+    transition main(a: u8) -> u8 {
+        let b: u8 = a + 1u8;
+        if b > 2u8 {
+            b = b * 2u8;
+        }
+        return b == 2u8 ? b : 0u8;
+    }
+}
+";
+
+    #[test]
+    fn test_trace() {
+        create_session_if_not_set_then(|_| {
+            let ast = parse_ast(PROGRAM);
+            let program = program_scope(&ast);
+            let (type_table, constants) = (TypeTable::default(), ConstantEnvironment::default());
+            let mut interpreter = Interpreter::new(program, &type_table, &constants).with_tracing();
+
+            let inputs = interpreter.parse_inputs("main", &["1u8".to_string()]).unwrap();
+            interpreter.run("main", inputs).unwrap();
+            let trace = interpreter.trace().unwrap();
+            let events = trace
+                .events()
+                .iter()
+                .map(|event| match event {
+                    TraceEvent::Statement { .. } => "statement".to_string(),
+                    TraceEvent::Write { variable, value, .. } => format!("{variable} = {value}"),
+                    TraceEvent::Branch { taken, .. } => format!("branch {taken}"),
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(events, [
+                "a = 1u8",
+                "statement",
+                "b = 2u8",
+                "statement",
+                "branch false",
+                "statement",
+                "branch true"
+            ]);
+            assert_eq!(trace.statements().count(), 3);
+
+            // Each run records a new trace.
+            let inputs = interpreter.parse_inputs("main", &["2u8".to_string()]).unwrap();
+            interpreter.run("main", inputs).unwrap();
+            assert_eq!(interpreter.trace().unwrap().events().len(), 9);
+        })
+    }
+}
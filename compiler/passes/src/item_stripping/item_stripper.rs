@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, CompilerWarning};
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+
+pub struct ItemStripper<'a> {
+    /// The handler used to report the stripped items.
+    handler: &'a Handler,
+    /// The names used by the item currently being visited.
+    uses: IndexSet<Symbol>,
+}
+
+impl<'a> ItemStripper<'a> {
+    /// Returns a new item stripper given a handler.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler, uses: Default::default() }
+    }
+
+    /// Removes the functions, structs, and constants of the program scope that are unreachable from its roots.
+    pub fn strip_program_scope(&mut self, input: &mut ProgramScope) {
+        // Collect the names used by each item of the program scope.
+        let mut dependencies: IndexMap<Symbol, IndexSet<Symbol>> = IndexMap::new();
+        for (name, const_) in &input.consts {
+            self.visit_type(&const_.type_);
+            self.visit_expression(&const_.value, &());
+            dependencies.insert(*name, core::mem::take(&mut self.uses));
+        }
+        for (name, struct_) in &input.structs {
+            struct_.members.iter().for_each(|member| self.visit_type(&member.type_));
+            dependencies.insert(*name, core::mem::take(&mut self.uses));
+        }
+        for (name, function) in &input.functions {
+            self.visit_function(function);
+            dependencies.insert(*name, core::mem::take(&mut self.uses));
+        }
+
+        // The roots are the transitions, records, and mappings of the program.
+        let mut stack: Vec<Symbol> = input
+            .functions
+            .iter()
+            .filter(|(_, function)| function.variant == Variant::Transition)
+            .map(|(name, _)| *name)
+            .chain(input.structs.iter().filter(|(_, struct_)| struct_.is_record).map(|(name, _)| *name))
+            .collect();
+        for (_, mapping) in &input.mappings {
+            self.visit_type(&mapping.key_type);
+            self.visit_type(&mapping.value_type);
+        }
+        stack.extend(core::mem::take(&mut self.uses));
+
+        // Compute the items that are reachable from the roots.
+        let mut reachable = IndexSet::new();
+        while let Some(name) = stack.pop() {
+            if reachable.insert(name) {
+                stack.extend(dependencies.get(&name).into_iter().flatten().copied());
+            }
+        }
+
+        // Strip the unreachable items.
+        input.consts.retain(|(name, const_)| {
+            let is_reachable = reachable.contains(name);
+            if !is_reachable {
+                self.warn("constant", &const_.place);
+            }
+            is_reachable
+        });
+        input.structs.retain(|(name, struct_)| {
+            let is_reachable = reachable.contains(name);
+            if !is_reachable {
+                self.warn("struct", &struct_.identifier);
+            }
+            is_reachable
+        });
+        input.functions.retain(|(name, function)| {
+            let is_reachable = reachable.contains(name);
+            if !is_reachable && function.variant != Variant::Inline {
+                self.warn("function", &function.identifier);
+            }
+            is_reachable
+        });
+    }
+
+    /// Reports that the item named `identifier` was stripped.
+    fn warn(&self, kind: &str, identifier: &Identifier) {
+        let warning = CompilerWarning::stripped_unreachable_item(kind, identifier.name, identifier.span);
+        self.handler.emit_warning(warning.into());
+    }
+
+    /// Records the structs used by `type_`.
+    fn visit_type(&mut self, type_: &Type) {
+        match type_ {
+            Type::Identifier(identifier) => {
+                self.uses.insert(identifier.name);
+            }
+            Type::Array(array_type) => self.visit_type(array_type.element_type()),
+            Type::Mapping(mapping_type) => {
+                self.visit_type(&mapping_type.key);
+                self.visit_type(&mapping_type.value);
+            }
+            Type::Tuple(tuple_type) => tuple_type.elements().iter().for_each(|type_| self.visit_type(type_)),
+            _ => {}
+        }
+    }
+
+    /// Records the names used by a function, including its signature and finalize block.
+    fn visit_function(&mut self, input: &Function) {
+        input.input.iter().for_each(|input| self.visit_type(&input.type_()));
+        self.visit_type(&input.output_type);
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            finalize.input.iter().for_each(|input| self.visit_type(&input.type_()));
+            self.visit_type(&finalize.output_type);
+            self.visit_block(&finalize.block);
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ItemStripper<'_> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Calls to external programs use items of other programs.
+        if input.external.is_none() {
+            self.visit_expression(&input.function, additional);
+        }
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, additional);
+        self.visit_type(&input.type_);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.uses.insert(input.name.name);
+        for member in &input.members {
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                None => self.visit_identifier(&member.identifier, additional),
+            }
+        }
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.uses.insert(input.name);
+    }
+}
+
+impl<'a> StatementVisitor<'a> for ItemStripper<'_> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.place, &());
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.visit_type(&input.type_);
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_declaration(&mut self, input: &'a DeclarationStatement) {
+        self.visit_type(&input.type_);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        input.type_.iter().for_each(|type_| self.visit_type(type_));
+        self.visit_expression(&input.value, &());
+    }
+}
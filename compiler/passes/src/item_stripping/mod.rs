@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Item Stripping pass removes the functions, structs, and constants that are unreachable from the transitions
+//! of a program.
+//! It is the final pass before code generation, so that the generated program only contains what its transitions use.
+//! The transitions, records, and mappings of a program are its roots, since they are part of its public interface.
+//! A warning is emitted for each function, struct, and constant that is stripped.
+//! Inline functions are removed without a warning, since they are never part of the generated program.
+//! Note that loop unrolling already folds and removes the constants of a program, so the pipeline never reports any.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! program test.aleo {
+//!     struct Unused {
+//!         a: u8,
+//!     }
+//!
+//!     function helper(a: u8) -> u8 {
+//!         return a + 1u8;
+//!     }
+//!
+//!     function unused(a: Unused) -> u8 {
+//!         return a.a;
+//!     }
+//!
+//!     transition main(a: u8) -> u8 {
+//!         return helper(a);
+//!     }
+//! }
+//! ```
+//!
+//! The item stripping pass produces the following code, and reports that `Unused` and `unused` were stripped.
+//! ```leo
+//! program test.aleo {
+//!     function helper(a: u8) -> u8 {
+//!         return a + 1u8;
+//!     }
+//!
+//!     transition main(a: u8) -> u8 {
+//!         return helper(a);
+//!     }
+//! }
+//! ```
+
+pub mod item_stripper;
+pub use item_stripper::*;
+
+use crate::Pass;
+
+use leo_ast::Ast;
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ItemStripper<'a> {
+    type Input = (Ast, &'a Handler);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut program = ast.into_repr();
+        // Note that imported programs are not stripped, since their items may be used by other programs.
+        program.program_scopes.values_mut().for_each(|scope| ItemStripper::new(handler).strip_program_scope(scope));
+
+        Ok(Ast::new(program))
+    }
+}
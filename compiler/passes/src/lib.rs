@@ -23,29 +23,110 @@ pub use code_generation::*;
 pub mod common;
 pub use common::*;
 
+pub mod constant_resolution;
+pub use constant_resolution::*;
+
+pub mod cost_estimation;
+pub use cost_estimation::*;
+
 pub mod dead_code_elimination;
 pub use dead_code_elimination::*;
 
+pub mod definite_assignment;
+pub use definite_assignment::*;
+
 pub mod destructuring;
 pub use destructuring::*;
 
+pub mod edition_checking;
+pub use edition_checking::*;
+
+pub mod enum_lowering;
+pub use enum_lowering::*;
+
+pub mod entry_point_validation;
+pub use entry_point_validation::*;
+
 pub mod flattening;
 pub use flattening::*;
 
 pub mod function_inlining;
 pub use function_inlining::*;
 
+pub mod information_flow;
+pub use information_flow::*;
+
+pub mod input_binding;
+pub use input_binding::*;
+
+pub mod input_stub_generation;
+pub use input_stub_generation::*;
+
+pub mod interpretation;
+pub use interpretation::*;
+
+pub mod item_stripping;
+pub use item_stripping::*;
+
+pub mod literal_folding;
+pub use literal_folding::*;
+
 pub mod loop_unrolling;
 pub use self::loop_unrolling::*;
 
+pub mod method_lowering;
+pub use method_lowering::*;
+
+pub mod monomorphization;
+pub use monomorphization::*;
+
+pub mod panic_audit;
+pub use panic_audit::*;
+
 pub mod pass;
 pub use self::pass::*;
 
+pub mod pass_manager;
+pub use pass_manager::*;
+
+pub mod pattern_lowering;
+pub use pattern_lowering::*;
+
+pub mod record_linearity;
+pub use record_linearity::*;
+
+pub mod reference_indexing;
+pub use reference_indexing::*;
+
+pub mod renaming;
+pub use renaming::*;
+
+pub mod signedness_checking;
+pub use signedness_checking::*;
+
 pub mod static_single_assignment;
 pub use static_single_assignment::*;
 
 pub mod symbol_table_creation;
 pub use symbol_table_creation::*;
 
+pub mod target_checking;
+pub use target_checking::*;
+
+pub mod test_filtering;
+pub use test_filtering::*;
+
+#[cfg(test)]
+mod test_utilities;
+
 pub mod type_checking;
 pub use type_checking::*;
+
+pub mod typed_lowering;
+pub use typed_lowering::*;
+
+pub mod unused_checking;
+pub use unused_checking::*;
+
+pub mod wrapping_desugaring;
+pub use wrapping_desugaring::*;
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+
+/// A compiler pass that folds the negations of literals into negative literals.
+pub struct LiteralFolder;
+
+impl ExpressionReconstructor for LiteralFolder {
+    type AdditionalOutput = ();
+
+    fn reconstruct_err(&mut self, input: ErrExpression) -> (Expression, Self::AdditionalOutput) {
+        (Expression::Err(input), Default::default())
+    }
+
+    fn reconstruct_unary(&mut self, input: UnaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let receiver = self.reconstruct_expression(*input.receiver).0;
+        let negated = match (&input.op, &receiver) {
+            (UnaryOperation::Negate, Expression::Literal(literal)) => literal.negate(input.span),
+            _ => None,
+        };
+        let expression = match negated {
+            Some(literal) => Expression::Literal(literal),
+            None => Expression::Unary(UnaryExpression { receiver: Box::new(receiver), ..input }),
+        };
+        (expression, Default::default())
+    }
+}
+
+impl StatementReconstructor for LiteralFolder {
+    fn reconstruct_err_statement(&mut self, input: ErrStatement) -> (Statement, Self::AdditionalOutput) {
+        (Statement::Err(input), Default::default())
+    }
+}
+
+impl ProgramReconstructor for LiteralFolder {
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            members: input
+                .members
+                .into_iter()
+                .map(|member| Member {
+                    default: member.default.map(|default| self.reconstruct_expression(default).0),
+                    ..member
+                })
+                .collect(),
+            methods: input
+                .methods
+                .into_iter()
+                .map(|(name, method)| (name, self.reconstruct_function(method)))
+                .collect(),
+            ..input
+        }
+    }
+}
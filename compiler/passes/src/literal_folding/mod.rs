@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Literal Folding pass folds the negation of a literal into a negative literal, so that `-128i8` is the literal
+//! `-128i8` rather than the negation of `128i8`, which is out of range. It runs directly after parsing, so that the
+//! range of each literal is checked by its true value. Repeated negations are folded one at a time, so `--1i8`
+//! is folded into `1i8`, and `--128i8` into `128i8`, which is out of range.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! program test.aleo {
+//!     transition main(a: i8) -> i8 {
+//!         let b: i8 = -128i8;
+//!         let c: i8 = -(-a);
+//!         return b + c + --1i8;
+//!     }
+//! }
+//! ```
+//!
+//! The literal folding pass produces the following code.
+//! ```leo
+//! program test.aleo {
+//!     transition main(a: i8) -> i8 {
+//!         let b: i8 = -128i8;
+//!         let c: i8 = -(-a);
+//!         return b + c + 1i8;
+//!     }
+//! }
+//! ```
+
+pub mod literal_folder;
+pub use literal_folder::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+
+impl Pass for LiteralFolder {
+    type Input = Ast;
+    type Output = Ast;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        Ast::new(LiteralFolder.reconstruct_program(ast.into_repr()))
+    }
+}
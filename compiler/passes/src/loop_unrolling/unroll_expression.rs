@@ -22,6 +22,29 @@ use crate::Unroller;
 impl ExpressionReconstructor for Unroller<'_> {
     type AdditionalOutput = bool;
 
+    fn reconstruct_block_expression(&mut self, input: BlockExpression) -> (Expression, Self::AdditionalOutput) {
+        let scope_index = self.current_scope_index();
+
+        // Enter the block scope.
+        let previous_scope_index = self.enter_scope(scope_index);
+
+        let mut statements = self.reconstruct_statements(input.statements);
+
+        // The statements produced while reconstructing the value are inserted at the end of the block.
+        let enclosing_statements = core::mem::take(&mut self.pending_statements);
+        let value = self.reconstruct_expression(*input.value).0;
+        statements.append(&mut self.pending_statements);
+        self.pending_statements = enclosing_statements;
+
+        // Exit the block scope.
+        self.exit_scope(previous_scope_index);
+
+        (
+            Expression::Block(BlockExpression { statements, value: Box::new(value), span: input.span, id: input.id }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
         // Reconstruct the index.
         let index = self.reconstruct_expression(*input.index).0;
@@ -41,11 +64,45 @@ impl ExpressionReconstructor for Unroller<'_> {
         )
     }
 
+    fn reconstruct_associated_function(&mut self, input: AssociatedFunction) -> (Expression, Self::AdditionalOutput) {
+        // Lower lookups into lookup tables.
+        if let Type::Identifier(ty) = &input.ty {
+            if CoreFunction::from_symbols(ty.name, input.name.name) == Some(CoreFunction::ArrayLookup) {
+                return (self.unroll_lookup(input), Default::default());
+            }
+        }
+
+        (
+            Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                ty: input.ty,
+                name: input.name,
+                arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_literal(&mut self, input: Literal) -> (Expression, Self::AdditionalOutput) {
+        // Give unsuffixed literals the integer type inferred by the type checker, so that later passes see concrete literals.
+        let literal = match self.type_table.get(&input.id()) {
+            Some(type_) => input.with_inferred_type(&type_),
+            None => input,
+        };
+        (Expression::Literal(literal), Default::default())
+    }
+
     fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
         // Substitute the identifier with the constant value if it is a constant.
         if let Some(expr) = self.constant_propagation_table.borrow().lookup_constant(input.name) {
             return (expr.clone(), Default::default());
         }
+        if let Some(expression) =
+            self.constants.lookup(input.name).and_then(|value| self.constant_expression(value, input.span))
+        {
+            return (expression, Default::default());
+        }
         (Expression::Identifier(input), Default::default())
     }
 }
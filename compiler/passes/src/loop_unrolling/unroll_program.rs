@@ -19,14 +19,41 @@ use leo_ast::*;
 use crate::Unroller;
 
 impl ProgramReconstructor for Unroller<'_> {
+    fn reconstruct_program(&mut self, input: Program) -> Program {
+        let imports = input
+            .imports
+            .into_iter()
+            .map(|(id, (import, span))| (id, (self.reconstruct_import(import), span)))
+            .collect();
+        // The functions of a deployed program have scopes in the symbol table, which are inserted after those of the
+        // imported programs. They have no blocks to unroll, so empty scopes are inserted for them here, to keep the
+        // scopes of the constant propagation table aligned with those of the symbol table.
+        for _ in input.stubs.values().flat_map(|(stub, _)| stub.functions.iter()) {
+            self.constant_propagation_table.borrow_mut().insert_block();
+        }
+        Program {
+            imports,
+            stubs: input.stubs,
+            program_scopes: input
+                .program_scopes
+                .into_iter()
+                .map(|(id, scope)| (id, self.reconstruct_program_scope(scope)))
+                .collect(),
+        }
+    }
+
     fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
-        // Don't need to reconstructed consts, just need to add them to constant propagation table
-        input.consts.into_iter().for_each(|(_, c)| {
-            self.reconstruct_const(c);
+        // The constants are removed, since their values are substituted from the constant environment.
+        input.consts.iter().for_each(|(name, _)| {
+            self.symbol_table.borrow_mut().remove_variable_from_current_scope(*name);
         });
         ProgramScope {
             program_id: input.program_id,
+            annotations: input.annotations,
+            modules: input.modules,
+            interfaces: input.interfaces,
             structs: input.structs,
+            enums: input.enums,
             mappings: input.mappings,
             functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
             consts: Vec::new(),
@@ -68,8 +95,10 @@ impl ProgramReconstructor for Unroller<'_> {
 
         // Reconstruct the function block.
         let reconstructed_function = Function {
+            doc: function.doc,
             annotations: function.annotations,
             variant: function.variant,
+            visibility: function.visibility,
             identifier: function.identifier,
             input: function.input,
             output: function.output,
@@ -28,21 +28,12 @@ impl StatementReconstructor for Unroller<'_> {
         // Enter the block scope.
         let previous_scope_index = self.enter_scope(scope_index);
 
-        // Filter out the statements that have additional output = true
-        let filtered_statements: Vec<_> = input
-            .statements
-            .into_iter()
-            .filter_map(|s| {
-                let (reconstructed_statement, additional_output) = self.reconstruct_statement(s);
-                if additional_output {
-                    None // Exclude this statement from the block since it is a constant variable definition
-                } else {
-                    Some(reconstructed_statement)
-                }
-            })
-            .collect();
-
-        let block = Block { statements: filtered_statements, span: input.span, id: input.id };
+        let block = Block {
+            annotations: Vec::new(),
+            statements: self.reconstruct_statements(input.statements),
+            span: input.span,
+            id: input.id,
+        };
 
         // Exit the block scope.
         self.exit_scope(previous_scope_index);
@@ -55,7 +46,11 @@ impl StatementReconstructor for Unroller<'_> {
         let reconstructed_value_expression = self.reconstruct_expression(input.value.clone()).0;
 
         // Add to constant propagation table. Since TC completed we know that the RHS is a literal or tuple of literals.
-        if let Err(err) = self.constant_propagation_table.borrow_mut().insert_constant(input.place.name, input.value) {
+        if let Err(err) = self
+            .constant_propagation_table
+            .borrow_mut()
+            .insert_constant(input.place.name, reconstructed_value_expression.clone())
+        {
             self.handler.emit_err(err);
         }
 
@@ -64,6 +59,7 @@ impl StatementReconstructor for Unroller<'_> {
 
         (
             Statement::Const(ConstDeclaration {
+                doc: input.doc,
                 place: input.place,
                 type_: input.type_,
                 value: reconstructed_value_expression,
@@ -81,6 +77,7 @@ impl StatementReconstructor for Unroller<'_> {
                 type_,
                 span,
                 declaration: VariableType::Mut,
+                value: None,
             }) {
                 self.handler.emit_err(err);
             }
@@ -88,12 +85,20 @@ impl StatementReconstructor for Unroller<'_> {
 
         // If we are unrolling a loop, then we need to repopulate the symbol table.
         if self.is_unrolling {
+            // If the type of the definition was omitted, use the type inferred by the type checker.
+            let type_ = match &input.type_ {
+                Some(type_) => type_.clone(),
+                None => self
+                    .type_table
+                    .get(&input.value.id())
+                    .expect("Type checking guarantees that the type of the value is known."),
+            };
             match &input.place {
                 Expression::Identifier(identifier) => {
-                    insert_variable(identifier.name, input.type_.clone(), input.span);
+                    insert_variable(identifier.name, type_.clone(), input.span);
                 }
                 Expression::Tuple(tuple_expression) => {
-                    let tuple_type = match input.type_ {
+                    let tuple_type = match type_ {
                         Type::Tuple(ref tuple_type) => tuple_type,
                         _ => unreachable!(
                             "Type checking guarantees that if the lhs is a tuple, its associated type is also a tuple."
@@ -104,7 +109,7 @@ impl StatementReconstructor for Unroller<'_> {
                             Expression::Identifier(identifier) => identifier,
                             _ => unreachable!("Type checking guarantees that if the lhs is a tuple, all of its elements are identifiers.")
                         };
-                        insert_variable(identifier.name, input.type_.clone(), input.span);
+                        insert_variable(identifier.name, type_.clone(), input.span);
                     });
                 }
                 _ => unreachable!(
@@ -15,17 +15,33 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::{
+    AccessExpression,
+    ArrayAccess,
+    ArrayExpression,
+    AssertStatement,
+    AssertVariant,
+    AssociatedFunction,
+    BinaryExpression,
+    BinaryOperation,
     Block,
+    DeclarationType,
+    DefinitionStatement,
     Expression,
+    ExpressionReconstructor,
+    Identifier,
     IntegerType,
     IterationStatement,
     Literal,
+    Node,
     NodeBuilder,
+    NodeID,
     Statement,
     StatementReconstructor,
+    TernaryExpression,
     Type,
     Value,
 };
+use leo_span::{Span, Symbol};
 use std::cell::RefCell;
 
 use leo_errors::{emitter::Handler, loop_unroller::LoopUnrollerError};
@@ -33,15 +49,20 @@ use leo_errors::{emitter::Handler, loop_unroller::LoopUnrollerError};
 use crate::{
     constant_propagation_table::ConstantPropagationTable,
     Clusivity,
+    ConstantEnvironment,
     LoopBound,
     RangeIterator,
     SymbolTable,
     TypeTable,
+    VariableSymbol,
+    VariableType,
 };
 
 pub struct Unroller<'a> {
-    /// A table of constant variables.
+    /// A table of the constant variables declared in functions.
     pub(crate) constant_propagation_table: RefCell<ConstantPropagationTable>,
+    /// The values of the constants declared in the program scope.
+    pub(crate) constants: &'a ConstantEnvironment,
     /// The symbol table for the function being processed.
     pub(crate) symbol_table: RefCell<SymbolTable>,
     /// A mapping from node IDs to their types.
@@ -54,23 +75,28 @@ pub struct Unroller<'a> {
     pub(crate) node_builder: &'a NodeBuilder,
     /// Are we in the midst of unrolling a loop?
     pub(crate) is_unrolling: bool,
+    /// The statements that must precede the statement currently being reconstructed, e.g. the bounds checks of lookups.
+    pub(crate) pending_statements: Vec<Statement>,
 }
 
 impl<'a> Unroller<'a> {
     pub(crate) fn new(
         symbol_table: SymbolTable,
         type_table: &'a TypeTable,
+        constants: &'a ConstantEnvironment,
         handler: &'a Handler,
         node_builder: &'a NodeBuilder,
     ) -> Self {
         Self {
             constant_propagation_table: RefCell::new(ConstantPropagationTable::default()),
+            constants,
             symbol_table: RefCell::new(symbol_table),
             type_table,
             scope_index: 0,
             handler,
             node_builder,
             is_unrolling: false,
+            pending_statements: Vec::new(),
         }
     }
 
@@ -157,6 +183,7 @@ impl<'a> Unroller<'a> {
         // Create a block statement to replace the iteration statement.
         // Creates a new block per iteration inside the outer block statement.
         let iter_blocks = Statement::Block(Block {
+            annotations: Vec::new(),
             span: input.span,
             statements: match input.inclusive {
                 true => {
@@ -234,23 +261,21 @@ impl<'a> Unroller<'a> {
             .insert_constant(input.variable.name, Expression::Literal(value.clone()))
             .expect("Failed to insert constant into CPT");
 
+        // Record the value of the loop variable in the symbol table, so that later passes can query it.
+        if let Err(err) = self.symbol_table.borrow_mut().insert_variable(input.variable.name, VariableSymbol {
+            type_: input.type_.clone(),
+            span: input.variable.span,
+            declaration: VariableType::Const,
+            value: Some(Expression::Literal(value.clone())),
+        }) {
+            self.handler.emit_err(err);
+        }
+
         // Reconstruct the statements in the loop body.
-        let statements: Vec<_> = input
-            .block
-            .statements
-            .clone()
-            .into_iter()
-            .filter_map(|s| {
-                let (reconstructed_statement, additional_output) = self.reconstruct_statement(s);
-                if additional_output {
-                    None // Exclude this statement from the block since it is a constant variable definition
-                } else {
-                    Some(reconstructed_statement)
-                }
-            })
-            .collect();
+        let statements = self.reconstruct_statements(input.block.statements.clone());
 
-        let block = Statement::Block(Block { statements, span: input.block.span, id: input.block.id });
+        let block =
+            Statement::Block(Block { annotations: Vec::new(), statements, span: input.block.span, id: input.block.id });
 
         self.is_unrolling = prior_is_unrolling;
 
@@ -259,4 +284,169 @@ impl<'a> Unroller<'a> {
 
         block
     }
+
+    /// Reconstructs a sequence of statements.
+    /// Constant definitions are removed, and the statements produced while reconstructing a statement are inserted before it.
+    pub(crate) fn reconstruct_statements(&mut self, statements: Vec<Statement>) -> Vec<Statement> {
+        // Set aside the statements produced by the enclosing statement, e.g. by the condition of a conditional statement.
+        let enclosing_statements = core::mem::take(&mut self.pending_statements);
+
+        let mut reconstructed_statements = Vec::with_capacity(statements.len());
+        for statement in statements {
+            let (reconstructed_statement, is_constant_definition) = self.reconstruct_statement(statement);
+            reconstructed_statements.append(&mut self.pending_statements);
+            // Exclude constant variable definitions from the block.
+            if !is_constant_definition {
+                reconstructed_statements.push(reconstructed_statement);
+            }
+        }
+
+        self.pending_statements = enclosing_statements;
+        reconstructed_statements
+    }
+
+    /// Lowers a lookup `Array::lookup(table, index)`.
+    /// A constant index becomes an array access, which Aleo instructions support directly.
+    /// Otherwise, the lookup becomes a chain of ternary expressions that selects the element at the index,
+    /// preceded by an assertion that the index is in bounds.
+    pub(crate) fn unroll_lookup(&mut self, input: AssociatedFunction) -> Expression {
+        let mut arguments = input.arguments.into_iter();
+        let (Some(table), Some(index)) = (arguments.next(), arguments.next()) else {
+            unreachable!("Type checking guarantees that a lookup has two arguments.")
+        };
+        let (Some(Type::Array(table_type)), Some(Type::Integer(index_type))) =
+            (self.type_table.get(&table.id()), self.type_table.get(&index.id()))
+        else {
+            unreachable!("Type checking guarantees that a lookup is on an array with an unsigned integer index.")
+        };
+        let length = table_type.length();
+        let element_type = table_type.element_type().clone();
+
+        let table = self.reconstruct_expression(table).0;
+        let index = self.reconstruct_expression(index).0;
+
+        // If the index is constant, then select the element directly.
+        if let Expression::Literal(Literal::Integer(_, value, _, _)) = &index {
+            let (digits, radix) = Literal::integer_digits(value);
+            let position = usize::from_str_radix(&digits, radix).unwrap_or(usize::MAX);
+            if position >= length {
+                self.emit_err(LoopUnrollerError::lookup_index_out_of_bounds(value, length, input.span));
+            }
+            return self.lookup_element(&table, position.min(length - 1), &element_type, input.span);
+        }
+
+        // Otherwise, bind the operands to variables, since they are used more than once.
+        let table = match table {
+            Expression::Array(_) => table,
+            table => self.bind_lookup_operand(table, Type::Array(table_type), input.span),
+        };
+        let index = self.bind_lookup_operand(index, Type::Integer(index_type), input.span);
+
+        // Check that the index is in bounds, unless every value of the index type is a valid index.
+        let max_index = match index_type {
+            IntegerType::U8 => u8::MAX as u128,
+            IntegerType::U16 => u16::MAX as u128,
+            IntegerType::U32 => u32::MAX as u128,
+            IntegerType::U64 => u64::MAX as u128,
+            _ => u128::MAX,
+        };
+        if (length as u128) <= max_index {
+            let length_literal = self.lookup_index_literal(index_type, length, input.span);
+            let condition = self.lookup_binary(index.clone(), BinaryOperation::Lt, length_literal, input.span);
+            self.pending_statements.push(Statement::Assert(AssertStatement {
+                variant: AssertVariant::Assert(condition),
+                span: input.span,
+                id: self.node_builder.next_id(),
+            }));
+        }
+
+        // Select the element with a chain of ternary expressions, starting from the last element that the index can select.
+        let count = (length as u128).min(max_index.saturating_add(1)) as usize;
+        let mut selection = self.lookup_element(&table, count - 1, &element_type, input.span);
+        for position in (0..count - 1).rev() {
+            let position_literal = self.lookup_index_literal(index_type, position, input.span);
+            let condition = self.lookup_binary(index.clone(), BinaryOperation::Eq, position_literal, input.span);
+            selection = Expression::Ternary(TernaryExpression {
+                condition: Box::new(condition),
+                if_true: Box::new(self.lookup_element(&table, position, &element_type, input.span)),
+                if_false: Box::new(selection),
+                span: input.span,
+                id: self.typed_node_id(element_type.clone()),
+            });
+        }
+        selection
+    }
+
+    /// Returns the expression of a constant `value`, a literal or an array of literals, with new node IDs,
+    /// or `None` if the value has no such expression.
+    /// Literals keep the spans of their declarations, while arrays are given the span of the use, `span`.
+    pub(crate) fn constant_expression(&self, value: &Value, span: Span) -> Option<Expression> {
+        let id = self.typed_node_id(Type::from(value));
+        Some(match value {
+            Value::Array(elements) => Expression::Array(ArrayExpression {
+                elements: elements
+                    .iter()
+                    .map(|element| self.constant_expression(element, span))
+                    .collect::<Option<_>>()?,
+                span,
+                id,
+            }),
+            value => Expression::Literal(Literal::from_value(value.clone(), id)?),
+        })
+    }
+
+    /// Returns a new node ID, recording the type of the node in the type table.
+    fn typed_node_id(&self, type_: Type) -> NodeID {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_);
+        id
+    }
+
+    /// Returns the element of the lookup table at `position`.
+    fn lookup_element(&self, table: &Expression, position: usize, element_type: &Type, span: Span) -> Expression {
+        match table {
+            Expression::Array(array) => array.elements[position].clone(),
+            _ => Expression::Access(AccessExpression::Array(ArrayAccess {
+                array: Box::new(table.clone()),
+                index: Box::new(self.lookup_index_literal(IntegerType::U32, position, span)),
+                span,
+                id: self.typed_node_id(element_type.clone()),
+            })),
+        }
+    }
+
+    /// Returns the integer literal `value` of type `type_`.
+    fn lookup_index_literal(&self, type_: IntegerType, value: usize, span: Span) -> Expression {
+        let id = self.typed_node_id(Type::Integer(type_));
+        Expression::Literal(Literal::Integer(type_, value.to_string(), span, id))
+    }
+
+    /// Returns the boolean expression `left op right`.
+    fn lookup_binary(&self, left: Expression, op: BinaryOperation, right: Expression, span: Span) -> Expression {
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span,
+            id: self.typed_node_id(Type::Boolean),
+        })
+    }
+
+    /// Binds an operand of a lookup to a new variable, unless it is already an identifier or a literal.
+    fn bind_lookup_operand(&mut self, operand: Expression, type_: Type, span: Span) -> Expression {
+        if matches!(operand, Expression::Identifier(_) | Expression::Literal(_)) {
+            return operand;
+        }
+        let id = self.typed_node_id(type_.clone());
+        let place = Identifier { name: Symbol::intern(&format!("$lookup${id}")), span, id };
+        self.pending_statements.push(Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(place),
+            type_: Some(type_),
+            value: operand,
+            span,
+            id: self.node_builder.next_id(),
+        }));
+        Expression::Identifier(place)
+    }
 }
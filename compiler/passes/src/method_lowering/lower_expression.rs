@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::MethodLowerer;
+
+use leo_ast::{
+    AccessExpression,
+    AssociatedConstant,
+    AssociatedFunction,
+    BlockExpression,
+    CallExpression,
+    Expression,
+    ExpressionReconstructor,
+    Identifier,
+    Module,
+    Node,
+    StatementReconstructor,
+    Struct,
+    Type,
+};
+use leo_span::sym;
+
+impl ExpressionReconstructor for MethodLowerer<'_> {
+    type AdditionalOutput = ();
+
+    /// Lowers a constant `module::NAME` of a module into its qualified name, e.g. `math__NAME`.
+    fn reconstruct_associated_constant(&mut self, input: AssociatedConstant) -> (Expression, Self::AdditionalOutput) {
+        match &input.ty {
+            Type::Identifier(module) if self.modules.contains(&module.name) => (
+                Expression::Identifier(Identifier {
+                    name: Module::item_name(module.name, input.name.name),
+                    span: input.span,
+                    id: input.id,
+                }),
+                Default::default(),
+            ),
+            _ => (Expression::Access(AccessExpression::AssociatedConstant(input)), Default::default()),
+        }
+    }
+
+    /// Lowers a call `module::function(args)` to a function of a module into a call to its qualified name,
+    /// e.g. `math__function(args)`.
+    fn reconstruct_associated_function(&mut self, input: AssociatedFunction) -> (Expression, Self::AdditionalOutput) {
+        let arguments = input.arguments.into_iter().map(|argument| self.reconstruct_expression(argument).0).collect();
+        let expression = match input.ty {
+            Type::Identifier(module) if self.modules.contains(&module.name) => Expression::Call(CallExpression {
+                function: Box::new(Expression::Identifier(Identifier {
+                    name: Module::item_name(module.name, input.name.name),
+                    span: input.name.span,
+                    id: self.node_builder.next_id(),
+                })),
+                arguments,
+                external: None,
+                network: None,
+                span: input.span,
+                id: input.id,
+            }),
+            ty => {
+                Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction { ty, arguments, ..input }))
+            }
+        };
+        (expression, Default::default())
+    }
+
+    fn reconstruct_block_expression(&mut self, input: BlockExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Block(BlockExpression {
+                statements: input
+                    .statements
+                    .into_iter()
+                    .map(|statement| self.reconstruct_statement(statement).0)
+                    .collect(),
+                value: Box::new(self.reconstruct_expression(*input.value).0),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Lowers a method call `receiver.method(args)` into a call `Struct::method(receiver, args)`.
+    fn reconstruct_call(&mut self, input: CallExpression) -> (Expression, Self::AdditionalOutput) {
+        let (function, arguments) = match *input.function {
+            Expression::Access(AccessExpression::Member(access)) => {
+                let struct_name = match self.type_table.get(&access.inner.id()) {
+                    Some(Type::Identifier(struct_name)) => struct_name.name,
+                    _ => unreachable!("Type checking guarantees that the receiver of a method call is a struct."),
+                };
+                let function = Identifier {
+                    name: Struct::method_name(struct_name, access.name.name),
+                    span: access.name.span,
+                    id: self.node_builder.next_id(),
+                };
+                let mut arguments = vec![*access.inner];
+                arguments.extend(input.arguments);
+                (Expression::Identifier(function), arguments)
+            }
+            function => (function, input.arguments),
+        };
+
+        (
+            Expression::Call(CallExpression {
+                function: Box::new(function),
+                arguments: arguments.into_iter().map(|argument| self.reconstruct_expression(argument).0).collect(),
+                external: input.external,
+                network: input.network,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
+        match self.is_method && input.name == sym::SelfLower {
+            true => (Expression::Identifier(Identifier { name: self.receiver, ..input }), Default::default()),
+            false => (Expression::Identifier(input), Default::default()),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::MethodLowerer;
+
+use leo_ast::{ProgramReconstructor, ProgramScope, Statement, StatementReconstructor};
+
+impl ProgramReconstructor for MethodLowerer<'_> {
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        self.modules = input.modules.iter().map(|(name, _)| *name).collect();
+
+        // Lower the methods of each struct into functions, which precede the other functions of the program scope.
+        let mut functions = Vec::new();
+        let mut structs = Vec::with_capacity(input.structs.len());
+        for (name, mut struct_) in input.structs {
+            for (method_name, method) in core::mem::take(&mut struct_.methods) {
+                functions.push(self.lower_method(struct_.name(), method_name, method));
+            }
+            structs.push((name, self.reconstruct_struct(struct_)));
+        }
+        functions.extend(input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))));
+
+        ProgramScope {
+            program_id: input.program_id,
+            annotations: input.annotations,
+            modules: input.modules,
+            interfaces: input.interfaces.into_iter().map(|(i, c)| (i, self.reconstruct_interface(c))).collect(),
+            structs,
+            enums: input.enums.into_iter().map(|(i, c)| (i, self.reconstruct_enum(c))).collect(),
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions,
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            span: input.span,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::MethodLowerer;
+
+use leo_ast::{AssignStatement, ExpressionReconstructor, Statement, StatementReconstructor};
+
+impl StatementReconstructor for MethodLowerer<'_> {
+    /// Reconstructs the place of the assignment, since it may be the receiver of a method.
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Assign(Box::new(AssignStatement {
+                place: self.reconstruct_expression(input.place).0,
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+}
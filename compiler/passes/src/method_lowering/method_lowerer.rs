@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::{Function, Input, NodeBuilder, ProgramReconstructor, Struct};
+use leo_span::{sym, Symbol};
+
+use indexmap::IndexSet;
+
+pub struct MethodLowerer<'a> {
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// A counter used to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// Whether or not the body of a method is being lowered, in which case `self` is its receiver.
+    pub(crate) is_method: bool,
+    /// The name that the `self` receiver of a method is renamed to.
+    pub(crate) receiver: Symbol,
+    /// The modules of the program scope being lowered.
+    pub(crate) modules: IndexSet<Symbol>,
+}
+
+impl<'a> MethodLowerer<'a> {
+    pub(crate) fn new(type_table: &'a TypeTable, node_builder: &'a NodeBuilder) -> Self {
+        Self {
+            type_table,
+            node_builder,
+            is_method: false,
+            receiver: Symbol::intern("$self"),
+            modules: IndexSet::new(),
+        }
+    }
+
+    /// Lowers the method `name` of the struct `struct_name` into an `inline` function, e.g. `Foo::bar`.
+    pub(crate) fn lower_method(&mut self, struct_name: Symbol, name: Symbol, method: Function) -> (Symbol, Function) {
+        self.is_method = true;
+        let mut function = self.reconstruct_function(method);
+        self.is_method = false;
+
+        // Rename the receiver, consistently with its uses in the body of the method.
+        // Note that parsing guarantees that the receiver is the first input of the method.
+        if let Some(Input::Internal(receiver)) = function.input.first_mut() {
+            if receiver.identifier.name == sym::SelfLower {
+                receiver.identifier.name = self.receiver;
+            }
+        }
+
+        let name = Struct::method_name(struct_name, name);
+        function.identifier.name = name;
+        (name, function)
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The method lowering pass lowers the methods of structs into functions.
+//! Each method becomes an `inline` function named after its struct, and each method call becomes a call to it.
+//! The receiver of the method is passed as the first argument, and is renamed from `self` to `$self`,
+//! so that it is not confused with `self.caller` or `self.signer` by the later passes.
+//! The pass is run after type checking, which resolves the method of each call from the type of its receiver.
+//! Calls to the functions of modules, e.g. `math::add(a, b)`, are lowered into calls to their qualified names,
+//! e.g. `math__add(a, b)`, and the constants of modules, e.g. `math::PI`, into their qualified names.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! struct Point {
+//!     x: u32,
+//!     y: u32,
+//!
+//!     function sum(self, z: u32) -> u32 {
+//!         return self.x + self.y + z;
+//!     }
+//! }
+//!
+//! transition main(p: Point) -> u32 {
+//!     return p.sum(1u32);
+//! }
+//! ```
+//!
+//! The method lowering pass produces the following code.
+//! ```leo
+//! struct Point {
+//!     x: u32,
+//!     y: u32,
+//! }
+//!
+//! inline Point::sum($self: Point, z: u32) -> u32 {
+//!     return $self.x + $self.y + z;
+//! }
+//!
+//! transition main(p: Point) -> u32 {
+//!     return Point::sum(p, 1u32);
+//! }
+//! ```
+
+mod lower_expression;
+
+mod lower_program;
+
+mod lower_statement;
+
+pub mod method_lowerer;
+pub use method_lowerer::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::Result;
+
+impl<'a> Pass for MethodLowerer<'a> {
+    type Input = (Ast, &'a TypeTable, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, tt, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = MethodLowerer::new(tt, node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+
+        Ok(Ast::new(program))
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Monomorphization pass replaces each instance of a generic struct, e.g. `Matrix<3>`, with a struct specialized
+//! for its constant arguments. It runs after the constant resolution pass, so the arguments given by constants of the
+//! program are already numbers. The generic structs themselves are removed, so the later passes only see structs
+//! with members of known types.
+//!
+//! The instances are named after the generic struct and their arguments, e.g. `Matrix__3`, and are added to the
+//! program scope after the other structs. An instance is only specialized once, however often it is used.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! program test.aleo {
+//!     struct Matrix<const N: u32> {
+//!         rows: [[u8; N]; N],
+//!     }
+//!
+//!     transition main(m: Matrix<2>) -> Matrix<2> {
+//!         return Matrix::<2> { rows: [[m.rows[1u32][1u32], 0u8], [0u8, m.rows[0u32][0u32]]] };
+//!     }
+//! }
+//! ```
+//!
+//! The monomorphization pass produces the following code.
+//! ```leo
+//! program test.aleo {
+//!     struct Matrix__2 {
+//!         rows: [[u8; 2]; 2],
+//!     }
+//!
+//!     transition main(m: Matrix__2) -> Matrix__2 {
+//!         return Matrix__2 { rows: [[m.rows[1u32][1u32], 0u8], [0u8, m.rows[0u32][0u32]]] };
+//!     }
+//! }
+//! ```
+
+pub mod monomorphizer;
+pub use monomorphizer::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for Monomorphizer<'a> {
+    type Input = (Ast, &'a Handler, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, node_builder): Self::Input) -> Self::Output {
+        // The parser recovers from some errors, which are reported by the later passes.
+        let err_count = handler.err_count();
+        let mut reconstructor = Monomorphizer::new(handler, node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        if handler.err_count() > err_count {
+            handler.last_err().map_err(|e| *e)?;
+        }
+
+        Ok(Ast::new(program))
+    }
+}
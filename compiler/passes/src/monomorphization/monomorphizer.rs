@@ -0,0 +1,385 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::integer_in_range;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, TypeCheckerError};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// A compiler pass that specializes the generic structs of each program scope for the arguments they are used with.
+pub struct Monomorphizer<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// A counter used to generate unique node IDs for the members of the instances.
+    node_builder: &'a NodeBuilder,
+    /// The generic structs of the current program scope.
+    generics: IndexMap<Symbol, Struct>,
+    /// The instances of the generic structs, which are `None` while their members are being specialized.
+    instances: IndexMap<Symbol, Option<Struct>>,
+}
+
+impl<'a> Monomorphizer<'a> {
+    pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder) -> Self {
+        Self { handler, node_builder, generics: IndexMap::new(), instances: IndexMap::new() }
+    }
+
+    /// Returns the name of the instance of the generic struct `name` for `arguments`, specializing the struct if it
+    /// has not been yet. Emits an error and returns `None` if the arguments are not valid for the struct.
+    fn instantiate(&mut self, name: &Identifier, arguments: &[ConstArgument]) -> Option<Symbol> {
+        let Some(generic) = self.generics.get(&name.name).cloned() else {
+            self.handler.emit_err(TypeCheckerError::not_a_generic_struct(name, name.span));
+            return None;
+        };
+        if arguments.len() != generic.const_parameters.len() {
+            self.handler.emit_err(TypeCheckerError::incorrect_num_const_arguments(
+                name,
+                generic.const_parameters.len(),
+                arguments.len(),
+                name.span,
+            ));
+            return None;
+        }
+
+        let mut values = Vec::with_capacity(arguments.len());
+        for (parameter, argument) in generic.const_parameters.iter().zip(arguments) {
+            let value = match argument {
+                ConstArgument::Number(number) => number.value(),
+                ConstArgument::Constant(constant) => {
+                    self.handler.emit_err(TypeCheckerError::unknown_const_argument(constant, constant.span));
+                    return None;
+                }
+            };
+            if let Type::Integer(integer_type) = parameter.type_ {
+                if !integer_in_range(value as i128, integer_type) {
+                    self.handler.emit_err(TypeCheckerError::const_argument_out_of_range(
+                        value,
+                        parameter.identifier,
+                        &parameter.type_,
+                        name.span,
+                    ));
+                    return None;
+                }
+            }
+            values.push(value);
+        }
+
+        let instance_name = Struct::instance_name(name.name, &values);
+        if !self.instances.contains_key(&instance_name) {
+            // The instance is recorded before its members are specialized, since they may refer to it.
+            self.instances.insert(instance_name, None);
+            let substitution = generic.const_parameters.iter().map(|parameter| parameter.name()).zip(values).collect();
+            let instance = self.specialize(generic, instance_name, &substitution);
+            self.instances.insert(instance_name, Some(instance));
+        }
+        Some(instance_name)
+    }
+
+    /// Returns the struct `name` specialized from `generic` by replacing its parameters with their values.
+    fn specialize(&mut self, generic: Struct, name: Symbol, substitution: &IndexMap<Symbol, usize>) -> Struct {
+        let members = generic
+            .members
+            .into_iter()
+            .map(|member| {
+                let type_ = self.substitute(member.type_, substitution);
+                Member {
+                    identifier: Identifier { id: self.node_builder.next_id(), ..member.identifier },
+                    type_: self.resolve_type(type_),
+                    id: self.node_builder.next_id(),
+                    ..member
+                }
+            })
+            .collect();
+        Struct {
+            identifier: Identifier { name, span: generic.identifier.span, id: self.node_builder.next_id() },
+            const_parameters: Vec::new(),
+            members,
+            id: self.node_builder.next_id(),
+            ..generic
+        }
+    }
+
+    /// Returns `type_` with the parameters of a generic struct replaced by their values.
+    /// The identifiers in `type_` are given new IDs, since the type is copied into each instance.
+    fn substitute(&self, type_: Type, substitution: &IndexMap<Symbol, usize>) -> Type {
+        let substitute_argument = |argument: &ConstArgument| match argument {
+            ConstArgument::Constant(constant) => substitution
+                .get(&constant.name)
+                .map_or_else(|| argument.clone(), |value| ConstArgument::Number(NonNegativeNumber::from(*value))),
+            argument => argument.clone(),
+        };
+        match type_ {
+            Type::Array(array_type) => Type::Array(ArrayType::new(
+                self.substitute(array_type.element_type().clone(), substitution),
+                substitute_argument(array_type.array_length()),
+            )),
+            Type::Generic(generic_type) => Type::Generic(GenericType {
+                name: Identifier { id: self.node_builder.next_id(), ..generic_type.name },
+                arguments: generic_type.arguments.iter().map(substitute_argument).collect(),
+            }),
+            Type::Identifier(identifier) => {
+                Type::Identifier(Identifier { id: self.node_builder.next_id(), ..identifier })
+            }
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(self.substitute(*mapping_type.key, substitution)),
+                value: Box::new(self.substitute(*mapping_type.value, substitution)),
+            }),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|type_| self.substitute(type_.clone(), substitution)).collect(),
+            )),
+            type_ => type_,
+        }
+    }
+
+    /// Returns `input` with the instances of generic structs replaced by the structs specialized for them.
+    fn resolve_type(&mut self, input: Type) -> Type {
+        match input {
+            Type::Array(array_type) => Type::Array(ArrayType::new(
+                self.resolve_type(array_type.element_type().clone()),
+                array_type.array_length().clone(),
+            )),
+            Type::Generic(generic_type) => match self.instantiate(&generic_type.name, &generic_type.arguments) {
+                Some(name) => Type::Identifier(Identifier { name, ..generic_type.name }),
+                None => Type::Err,
+            },
+            Type::Identifier(identifier) if self.generics.contains_key(&identifier.name) => {
+                self.handler.emit_err(TypeCheckerError::missing_const_arguments(identifier, identifier.span));
+                Type::Err
+            }
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(self.resolve_type(*mapping_type.key)),
+                value: Box::new(self.resolve_type(*mapping_type.value)),
+            }),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|type_| self.resolve_type(type_.clone())).collect(),
+            )),
+            type_ => type_,
+        }
+    }
+
+    /// Returns `input` with the types of its inputs and outputs resolved.
+    fn resolve_input(&mut self, input: Input) -> Input {
+        match input {
+            Input::Internal(input) => Input::Internal(FunctionInput { type_: self.resolve_type(input.type_), ..input }),
+            input => input,
+        }
+    }
+
+    /// Returns `output` with its type resolved.
+    fn resolve_output(&mut self, output: Output) -> Output {
+        match output {
+            Output::Internal(output) => {
+                Output::Internal(FunctionOutput { type_: self.resolve_type(output.type_), ..output })
+            }
+            output => output,
+        }
+    }
+
+    /// Returns `member` with its type resolved.
+    fn resolve_member(&mut self, member: Member) -> Member {
+        Member { type_: self.resolve_type(member.type_), ..member }
+    }
+
+    /// Checks that a generic struct can be specialized, i.e. that its parameters are integers and it has no methods.
+    fn check_generic(&self, generic: &Struct) {
+        for parameter in &generic.const_parameters {
+            if !matches!(parameter.type_, Type::Integer(_)) {
+                self.handler.emit_err(TypeCheckerError::invalid_const_parameter_type(
+                    parameter.identifier,
+                    &parameter.type_,
+                    parameter.span,
+                ));
+            }
+        }
+        if !generic.methods.is_empty() {
+            self.handler
+                .emit_err(TypeCheckerError::generic_struct_has_methods(generic.identifier, generic.identifier.span));
+        }
+    }
+}
+
+impl ExpressionReconstructor for Monomorphizer<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_block_expression(&mut self, input: BlockExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Block(BlockExpression {
+                statements: input
+                    .statements
+                    .into_iter()
+                    .map(|statement| self.reconstruct_statement(statement).0)
+                    .collect(),
+                value: Box::new(self.reconstruct_expression(*input.value).0),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// This pass runs directly after parsing, so the expressions the parser failed on are kept for later passes.
+    fn reconstruct_err(&mut self, input: ErrExpression) -> (Expression, Self::AdditionalOutput) {
+        (Expression::Err(input), Default::default())
+    }
+
+    /// Replaces the name of an instance of a generic struct with the name of the struct specialized for it.
+    /// The shorthand initializers are kept as they are written.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        let name = if input.const_arguments.is_empty() {
+            if self.generics.contains_key(&input.name.name) {
+                self.handler.emit_err(TypeCheckerError::missing_const_arguments(input.name, input.name.span));
+            }
+            input.name
+        } else {
+            match self.instantiate(&input.name, &input.const_arguments) {
+                Some(name) => Identifier { name, ..input.name },
+                None => input.name,
+            }
+        };
+        (
+            Expression::Struct(StructExpression {
+                name,
+                const_arguments: Vec::new(),
+                members: input.members.into_iter().map(|member| self.reconstruct_struct_member(member)).collect(),
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+}
+
+impl StatementReconstructor for Monomorphizer<'_> {
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Const(ConstDeclaration {
+                type_: self.resolve_type(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_declaration(&mut self, input: DeclarationStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Declaration(DeclarationStatement { type_: self.resolve_type(input.type_), ..input }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Definition(DefinitionStatement {
+                type_: input.type_.map(|type_| self.resolve_type(type_)),
+                value: self.reconstruct_expression(input.value).0,
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+
+    /// The statements the parser failed on are kept for later passes, like the expressions.
+    fn reconstruct_err_statement(&mut self, input: ErrStatement) -> (Statement, Self::AdditionalOutput) {
+        (Statement::Err(input), Default::default())
+    }
+}
+
+impl ProgramReconstructor for Monomorphizer<'_> {
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        // The generic structs of each program scope are separate.
+        self.generics.clear();
+        self.instances.clear();
+        let (generics, structs): (Vec<_>, Vec<_>) =
+            input.structs.into_iter().partition(|(_, struct_)| struct_.is_generic());
+        for (name, generic) in generics {
+            self.check_generic(&generic);
+            self.generics.insert(name, generic);
+        }
+
+        let consts = input
+            .consts
+            .into_iter()
+            .map(|(name, declaration)| match self.reconstruct_const(declaration) {
+                (Statement::Const(declaration), _) => (name, declaration),
+                _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+            })
+            .collect();
+        let interfaces = input.interfaces.into_iter().map(|(i, c)| (i, self.reconstruct_interface(c))).collect();
+        let mut structs = structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect::<Vec<_>>();
+        let enums = input.enums.into_iter().map(|(i, c)| (i, self.reconstruct_enum(c))).collect();
+        let mappings = input.mappings.into_iter().map(|(i, mapping)| (i, self.reconstruct_mapping(mapping))).collect();
+        let functions = input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect();
+
+        // The instances are specialized as they are found, so they are added once the rest of the scope is resolved.
+        structs.extend(self.instances.drain(..).filter_map(|(name, instance)| Some((name, instance?))));
+
+        ProgramScope {
+            program_id: input.program_id,
+            annotations: input.annotations,
+            modules: input.modules,
+            consts,
+            interfaces,
+            structs,
+            enums,
+            mappings,
+            functions,
+            span: input.span,
+        }
+    }
+
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        Function {
+            input: input.input.into_iter().map(|input| self.resolve_input(input)).collect(),
+            output: input.output.into_iter().map(|output| self.resolve_output(output)).collect(),
+            output_type: self.resolve_type(input.output_type),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| Finalize {
+                input: finalize.input.into_iter().map(|input| self.resolve_input(input)).collect(),
+                output: finalize.output.into_iter().map(|output| self.resolve_output(output)).collect(),
+                output_type: self.resolve_type(finalize.output_type),
+                block: self.reconstruct_block(finalize.block).0,
+                ..finalize
+            }),
+            ..input
+        }
+    }
+
+    fn reconstruct_interface(&mut self, input: Interface) -> Interface {
+        Interface { members: input.members.into_iter().map(|member| self.resolve_member(member)).collect(), ..input }
+    }
+
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            members: input.members.into_iter().map(|member| self.resolve_member(member)).collect(),
+            methods: input
+                .methods
+                .into_iter()
+                .map(|(name, method)| (name, self.reconstruct_function(method)))
+                .collect(),
+            ..input
+        }
+    }
+
+    fn reconstruct_mapping(&mut self, input: Mapping) -> Mapping {
+        Mapping {
+            key_type: self.resolve_type(input.key_type),
+            value_type: self.resolve_type(input.value_type),
+            ..input
+        }
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::IntegerType;
+
+use std::fmt;
+
+/// A closed range of integer values, used to over-approximate the values an expression may take.
+/// Bounds are stored as `i128`s, so the range of a `u128` above `i128::MAX` is not represented.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Interval {
+    pub min: i128,
+    pub max: i128,
+}
+
+impl Interval {
+    pub fn new(min: i128, max: i128) -> Self {
+        Self { min: min.min(max), max: min.max(max) }
+    }
+
+    /// Returns the interval containing only `value`.
+    pub fn point(value: i128) -> Self {
+        Self { min: value, max: value }
+    }
+
+    /// Returns the range of values of `type_`, if it is representable.
+    pub fn of_type(type_: IntegerType) -> Option<Self> {
+        use IntegerType::*;
+        let (min, max) = match type_ {
+            U8 => (0, u8::MAX as i128),
+            U16 => (0, u16::MAX as i128),
+            U32 => (0, u32::MAX as i128),
+            U64 => (0, u64::MAX as i128),
+            U128 => return None,
+            I8 => (i8::MIN as i128, i8::MAX as i128),
+            I16 => (i16::MIN as i128, i16::MAX as i128),
+            I32 => (i32::MIN as i128, i32::MAX as i128),
+            I64 => (i64::MIN as i128, i64::MAX as i128),
+            I128 => (i128::MIN, i128::MAX),
+        };
+        Some(Self { min, max })
+    }
+
+    /// Returns `true` if the interval contains a single value.
+    pub fn is_point(self) -> bool {
+        self.min == self.max
+    }
+
+    pub fn contains(self, value: i128) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    /// Returns the smallest interval containing both intervals.
+    pub fn join(self, other: Self) -> Self {
+        Self { min: self.min.min(other.min), max: self.max.max(other.max) }
+    }
+
+    /// Returns `true` if the intervals have no value in common.
+    pub fn is_disjoint(self, other: Self) -> bool {
+        self.max < other.min || other.max < self.min
+    }
+
+    /// Returns the largest absolute value in the interval, if it is representable.
+    pub fn magnitude(self) -> Option<i128> {
+        Some(self.min.checked_abs()?.max(self.max.checked_abs()?))
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.min, self.max)
+    }
+}
+
+/// Returns `true` if `value` is a value of `type_`.
+/// A `value` of `None` stands for a result that does not fit in an `i128`.
+pub(crate) fn fits(value: Option<i128>, type_: IntegerType) -> Option<bool> {
+    match (value, Interval::of_type(type_)) {
+        (Some(value), Some(bounds)) => Some(bounds.contains(value)),
+        (Some(value), None) => Some(value >= 0),
+        // Every integer type other than `u128` lies within the range of an `i128`.
+        (None, Some(_)) => Some(false),
+        (None, None) => None,
+    }
+}
+
+/// Restricts `interval` to the values of `type_`, since an operation producing any other value halts.
+pub(crate) fn clamp(interval: Option<Interval>, type_: IntegerType) -> Option<Interval> {
+    match (interval, Interval::of_type(type_)) {
+        (Some(interval), Some(bounds)) if !interval.is_disjoint(bounds) => {
+            Some(Interval::new(interval.min.max(bounds.min), interval.max.min(bounds.max)))
+        }
+        (Some(interval), None) if interval.max >= 0 => Some(Interval::new(interval.min.max(0), interval.max)),
+        (_, bounds) => bounds,
+    }
+}
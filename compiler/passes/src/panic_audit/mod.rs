@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Panic Audit pass reports the operations of a type-checked program that may halt its execution:
+//! checked arithmetic that may overflow, divisions by zero, casts to integer types that may fail, and assertions.
+//! Each operation is given a verdict, which is either `Safe` if it cannot halt on any path,
+//! `Halts` or `MayHalt` with a counterexample, or `Unknown` if the analysis cannot decide.
+//!
+//! The verdicts are derived from a range analysis, which over-approximates the values of each integer expression
+//! by an interval. Literals and constants are evaluated exactly, inputs range over their type,
+//! the ranges of a variable on the branches of a conditional are joined, and variables assigned in a loop
+//! range over their type. Field elements are only decided when they are literals.
+//! The pass does not modify the AST, and its report is written to the output directory when the audit is enabled.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(a: u8, b: u8) -> u8 {
+//!     let c: u8 = a / 4u8;
+//!     return c + b;
+//! }
+//! ```
+//!
+//! The panic audit proves that `a / 4u8` cannot divide by zero, since the divisor is a nonzero literal.
+//! It reports that `c + b` may overflow, with the counterexample `63u8 + 255u8`, since `c` lies in `[0, 63]`.
+
+pub mod interval;
+pub use interval::*;
+
+pub mod panic_auditor;
+pub use panic_auditor::*;
+
+pub mod report;
+pub use report::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::Ast;
+
+impl<'a> Pass for PanicAuditor<'a> {
+    type Input = (&'a Ast, &'a TypeTable);
+    type Output = PanicAuditReport;
+
+    fn do_pass((ast, type_table): Self::Input) -> Self::Output {
+        let mut auditor = PanicAuditor::new(type_table);
+        // Imported programs are audited when they are compiled.
+        ast.as_repr().program_scopes.values().for_each(|scope| auditor.audit_program_scope(scope));
+
+        auditor.report
+    }
+}
@@ -0,0 +1,747 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{clamp, fits, AuditFinding, AuditVerdict, HaltKind, Interval, Location, PanicAuditReport, TypeTable};
+
+use leo_ast::*;
+use leo_span::{Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+use std::{collections::HashMap, fmt::Display};
+
+/// A compiler pass that audits the operations of a program that may halt its execution.
+pub struct PanicAuditor<'a> {
+    /// The types of the expressions of the program.
+    type_table: &'a TypeTable,
+    /// The report constructed by this compiler pass.
+    pub(crate) report: PanicAuditReport,
+    /// The name of the function or constant being audited.
+    item: String,
+    /// The ranges of the constants of the program.
+    constants: IndexMap<Symbol, Interval>,
+    /// The ranges of the variables of the function being audited.
+    /// A variable without an entry may take any value of its type.
+    ranges: IndexMap<Symbol, Interval>,
+    /// The boolean expressions that are known to always evaluate to the same value.
+    truths: HashMap<NodeID, bool>,
+}
+
+impl<'a> PanicAuditor<'a> {
+    pub fn new(type_table: &'a TypeTable) -> Self {
+        Self {
+            type_table,
+            report: Default::default(),
+            item: Default::default(),
+            constants: Default::default(),
+            ranges: Default::default(),
+            truths: Default::default(),
+        }
+    }
+
+    /// Audits the constants, functions, and struct methods of the program scope.
+    pub fn audit_program_scope(&mut self, input: &'a ProgramScope) {
+        for (name, const_) in &input.consts {
+            self.item = name.to_string();
+            if let Some(range) = self.range_of(&const_.value) {
+                self.constants.insert(*name, range);
+            }
+        }
+        for (_, struct_) in &input.structs {
+            for (_, method) in &struct_.methods {
+                self.audit_function(format!("{}::{}", struct_.identifier, method.identifier), method);
+            }
+        }
+        for (_, function) in &input.functions {
+            self.audit_function(function.identifier.to_string(), function);
+        }
+    }
+
+    /// Audits the body and finalize block of a function.
+    fn audit_function(&mut self, name: String, input: &'a Function) {
+        self.ranges.clear();
+        self.item = name;
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.ranges.clear();
+            self.item = format!("{}/finalize", input.identifier);
+            self.visit_block(&finalize.block);
+        }
+    }
+
+    /// Returns the range of values of an integer expression, auditing the operations it contains.
+    /// Returns `None` if the expression is not an integer, or if its range is not representable.
+    fn range_of(&mut self, input: &'a Expression) -> Option<Interval> {
+        self.visit_expression(input, &()).or_else(|| self.integer_type(input.id()).and_then(Interval::of_type))
+    }
+
+    /// Returns the type of an expression, if it is an integer.
+    fn integer_type(&self, id: NodeID) -> Option<IntegerType> {
+        match self.type_table.get(&id) {
+            Some(Type::Integer(type_)) => Some(type_),
+            _ => None,
+        }
+    }
+
+    /// Records a finding for an operation of the current item.
+    fn record(&mut self, operation: impl Display, kind: HaltKind, span: Span, verdict: AuditVerdict) {
+        self.report.findings.push(AuditFinding {
+            item: self.item.clone(),
+            operation: operation.to_string(),
+            kind,
+            location: Location::from_span(span),
+            verdict,
+        });
+    }
+
+    /// Returns the verdict for an operation given candidate counterexamples, and whether the operation halts on each.
+    /// If `is_definite`, halting on a candidate implies that the operation halts whenever it is executed.
+    fn verdict(is_definite: bool, candidates: impl IntoIterator<Item = (String, Option<bool>)>) -> AuditVerdict {
+        let mut is_unknown = false;
+        for (counterexample, halts) in candidates {
+            match halts {
+                Some(true) if is_definite => return AuditVerdict::Halts(counterexample),
+                Some(true) => return AuditVerdict::MayHalt(counterexample),
+                Some(false) => {}
+                None => is_unknown = true,
+            }
+        }
+        match is_unknown {
+            true => AuditVerdict::Unknown,
+            false => AuditVerdict::Safe,
+        }
+    }
+
+    /// Audits a checked addition, subtraction, or multiplication, returning the range of its result.
+    /// Since these operations are monotone in each operand, they reach their extremes at the bounds of the operands.
+    fn audit_arithmetic(
+        &mut self,
+        input: &BinaryExpression,
+        type_: IntegerType,
+        (left, right): (Option<Interval>, Option<Interval>),
+        operation: fn(i128, i128) -> Option<i128>,
+    ) -> Option<Interval> {
+        let (Some(left), Some(right)) = (left, right) else {
+            self.record(input, HaltKind::Overflow, input.span, AuditVerdict::Unknown);
+            return clamp(None, type_);
+        };
+        let corners = [(left.min, right.min), (left.min, right.max), (left.max, right.min), (left.max, right.max)];
+        let results = corners.map(|(a, b)| operation(a, b));
+        let candidates = corners.iter().zip(results).map(|((a, b), result)| {
+            (format!("{a}{type_} {} {b}{type_}", input.op), fits(result, type_).map(|fits| !fits))
+        });
+        let verdict = Self::verdict(left.is_point() && right.is_point(), candidates);
+        self.record(input, HaltKind::Overflow, input.span, verdict);
+
+        let range = results
+            .into_iter()
+            .try_fold(None, |range: Option<Interval>, result| {
+                let point = Interval::point(result?);
+                Some(Some(range.map_or(point, |range| range.join(point))))
+            })
+            .flatten();
+        clamp(range, type_)
+    }
+
+    /// Audits a checked exponentiation, returning the range of its result.
+    fn audit_pow(
+        &mut self,
+        input: &BinaryExpression,
+        type_: IntegerType,
+        (left, right): (Option<Interval>, Option<Interval>),
+    ) -> Option<Interval> {
+        let (Some(base), Some(exponent)) = (left, right) else {
+            self.record(input, HaltKind::Overflow, input.span, AuditVerdict::Unknown);
+            return clamp(None, type_);
+        };
+        let exponent_type = self.integer_type(input.right.id()).unwrap_or(IntegerType::U32);
+        // The magnitude of a power is largest at the largest exponent, and its sign depends on the parity of the exponent.
+        let exponents = [exponent.min, exponent.max, (exponent.max - 1).max(exponent.min)];
+        let corners = [base.min, base.max].into_iter().flat_map(|b| exponents.map(|e| (b, e))).collect::<Vec<_>>();
+        let results =
+            corners.iter().map(|(b, e)| u32::try_from(*e).ok().and_then(|e| b.checked_pow(e))).collect::<Vec<_>>();
+        let candidates = corners.iter().zip(&results).map(|((b, e), result)| {
+            (format!("{b}{type_} ** {e}{exponent_type}"), fits(*result, type_).map(|fits| !fits))
+        });
+        let verdict = Self::verdict(base.is_point() && exponent.is_point(), candidates);
+        self.record(input, HaltKind::Overflow, input.span, verdict);
+
+        // Every power lies between the negated and the largest magnitude of a corner, or 1 for small bases.
+        let magnitude =
+            results.into_iter().try_fold(1i128, |magnitude, result| Some(magnitude.max(result?.checked_abs()?)));
+        let range = magnitude.map(|magnitude| match base.min >= 0 {
+            true => Interval::new(0, magnitude),
+            false => Interval::new(-magnitude, magnitude),
+        });
+        clamp(range, type_)
+    }
+
+    /// Audits a division or remainder for a zero divisor and, for signed integers, for the overflow of `MIN / -1`.
+    fn audit_division(
+        &mut self,
+        input: &BinaryExpression,
+        type_: IntegerType,
+        (left, right): (Option<Interval>, Option<Interval>),
+    ) -> Option<Interval> {
+        // A dividend without a range may take any value, including zero.
+        let dividend = left.map_or(0, |left| left.min);
+        let counterexample = format!("{dividend}{type_} {} 0{type_}", input.op);
+        let verdict = Self::verdict(
+            right == Some(Interval::point(0)),
+            [(counterexample, Some(right.map_or(true, |right| right.contains(0))))],
+        );
+        self.record(input, HaltKind::DivisionByZero, input.span, verdict);
+
+        if let (Some(bounds), true) = (Interval::of_type(type_), type_.is_signed()) {
+            let (left, right) = (left.unwrap_or(bounds), right.unwrap_or(bounds));
+            let counterexample = format!("{}{type_} {} -1{type_}", bounds.min, input.op);
+            let verdict = Self::verdict(
+                left.is_point() && right.is_point(),
+                [(counterexample, Some(left.contains(bounds.min) && right.contains(-1)))],
+            );
+            self.record(input, HaltKind::Overflow, input.span, verdict);
+        }
+
+        // The magnitude of a quotient or remainder is at most the magnitude of the dividend,
+        // and it is bounded by the divisor when both are positive.
+        let range = left.and_then(|left| match (left.min >= 0, right) {
+            (true, Some(right)) if right.min > 0 => Some(match input.op {
+                BinaryOperation::Div => Interval::new(left.min / right.max, left.max / right.min),
+                _ => Interval::new(0, left.max.min(right.max - 1)),
+            }),
+            (true, _) if !type_.is_signed() => Some(Interval::new(0, left.max)),
+            _ => left.magnitude().map(|magnitude| Interval::new(-magnitude, magnitude)),
+        });
+        clamp(range, type_)
+    }
+
+    /// Audits a checked shift, which halts if the shift amount is not less than the bit width of the shifted value.
+    fn audit_shift(
+        &mut self,
+        input: &BinaryExpression,
+        type_: IntegerType,
+        (left, right): (Option<Interval>, Option<Interval>),
+    ) -> Option<Interval> {
+        let bits = bit_width(type_);
+        let amount_type = self.integer_type(input.right.id()).unwrap_or(IntegerType::U32);
+        let verdict = match right {
+            Some(right) => {
+                let counterexample =
+                    format!("{}{type_} {} {bits}{amount_type}", left.map_or(0, |left| left.min), input.op);
+                Self::verdict(right.min >= bits, [(counterexample, Some(right.max >= bits))])
+            }
+            None => AuditVerdict::Unknown,
+        };
+        self.record(input, HaltKind::Overflow, input.span, verdict);
+
+        match (input.op, left) {
+            (BinaryOperation::Shr, Some(left)) if left.min >= 0 => Some(Interval::new(0, left.max)),
+            _ => clamp(None, type_),
+        }
+    }
+
+    /// Audits a division or inverse of a field element, which halts if the divisor is zero.
+    /// Field elements are not given ranges, so only literal divisors are decided.
+    fn audit_field_division(
+        &mut self,
+        operation: impl Display,
+        divisor: &Expression,
+        span: Span,
+        counterexample: String,
+    ) {
+        let verdict = match divisor {
+            Expression::Literal(Literal::Field(value, ..)) => {
+                match value.trim_start_matches('-').chars().all(|c| c == '0' || c == '_') {
+                    true => AuditVerdict::Halts(counterexample),
+                    false => AuditVerdict::Safe,
+                }
+            }
+            _ => AuditVerdict::MayHalt(counterexample),
+        };
+        self.record(operation, HaltKind::DivisionByZero, span, verdict);
+    }
+
+    /// Audits an assertion given whether its condition is known to hold.
+    fn audit_assertion(&mut self, operation: impl Display, span: Span, holds: Option<bool>, counterexample: String) {
+        let verdict = match holds {
+            Some(true) => AuditVerdict::Safe,
+            Some(false) => AuditVerdict::Halts(counterexample),
+            None => AuditVerdict::Unknown,
+        };
+        self.record(operation, HaltKind::AssertionFailure, span, verdict);
+    }
+
+    /// Audits the operands of an equality assertion, returning whether they are known to be equal.
+    fn equality(&mut self, left: &'a Expression, right: &'a Expression) -> Option<bool> {
+        let ranges = (self.range_of(left), self.range_of(right));
+        match (self.truths.get(&left.id()), self.truths.get(&right.id())) {
+            (Some(left), Some(right)) => Some(left == right),
+            _ => compare(BinaryOperation::Eq, ranges),
+        }
+    }
+
+    /// Audits a place being assigned, forgetting the range of its variables if the value has no range.
+    fn assign(&mut self, place: &Expression, range: Option<Interval>) {
+        match (place, range) {
+            (Expression::Identifier(identifier), Some(range)) => {
+                self.ranges.insert(identifier.name, range);
+            }
+            (Expression::Identifier(identifier), None) => {
+                self.ranges.shift_remove(&identifier.name);
+            }
+            (Expression::Tuple(tuple), _) => tuple.elements.iter().for_each(|element| self.assign(element, None)),
+            _ => {}
+        }
+    }
+}
+
+/// Returns the number of bits of an integer type.
+fn bit_width(type_: IntegerType) -> i128 {
+    use IntegerType::*;
+    match type_ {
+        U8 | I8 => 8,
+        U16 | I16 => 16,
+        U32 | I32 => 32,
+        U64 | I64 => 64,
+        U128 | I128 => 128,
+    }
+}
+
+/// Returns the outcome of a comparison of integers in the given ranges, if it is the same for all of them.
+fn compare(operation: BinaryOperation, ranges: (Option<Interval>, Option<Interval>)) -> Option<bool> {
+    use BinaryOperation::*;
+    let (Some(left), Some(right)) = ranges else {
+        return None;
+    };
+    let (always, never) = match operation {
+        Eq => (left.is_point() && left == right, left.is_disjoint(right)),
+        Neq => (left.is_disjoint(right), left.is_point() && left == right),
+        Lt => (left.max < right.min, left.min >= right.max),
+        Lte => (left.max <= right.min, left.min > right.max),
+        Gt => (left.min > right.max, left.max <= right.min),
+        Gte => (left.min >= right.max, left.max < right.min),
+        _ => return None,
+    };
+    match (always, never) {
+        (true, _) => Some(true),
+        (_, true) => Some(false),
+        _ => None,
+    }
+}
+
+/// Collects the variables assigned in a statement.
+fn assigned_variables(input: &Statement, assigned: &mut IndexSet<Symbol>) {
+    fn place_variables(place: &Expression, assigned: &mut IndexSet<Symbol>) {
+        match place {
+            Expression::Identifier(identifier) => {
+                assigned.insert(identifier.name);
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| place_variables(element, assigned)),
+            _ => {}
+        }
+    }
+
+    match input {
+        Statement::Assign(assign) => place_variables(&assign.place, assigned),
+        Statement::Block(block) => {
+            block.statements.iter().for_each(|statement| assigned_variables(statement, assigned))
+        }
+        Statement::Conditional(conditional) => {
+            conditional.then.statements.iter().for_each(|statement| assigned_variables(statement, assigned));
+            conditional.otherwise.iter().for_each(|statement| assigned_variables(statement, assigned));
+        }
+        Statement::Iteration(iteration) => {
+            iteration.block.statements.iter().for_each(|statement| assigned_variables(statement, assigned))
+        }
+        _ => {}
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for PanicAuditor<'a> {
+    type AdditionalInput = ();
+    type Output = Option<Interval>;
+
+    fn visit_block_expression(
+        &mut self,
+        input: &'a BlockExpression,
+        _additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.range_of(&input.value)
+    }
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        use BinaryOperation::*;
+        let operands = (self.range_of(&input.left), self.range_of(&input.right));
+
+        match input.op {
+            Eq | Neq | Lt | Lte | Gt | Gte => {
+                let truth = match (self.truths.get(&input.left.id()), self.truths.get(&input.right.id())) {
+                    (Some(left), Some(right)) if input.op == Eq => Some(left == right),
+                    (Some(left), Some(right)) if input.op == Neq => Some(left != right),
+                    _ => compare(input.op, operands),
+                };
+                truth.map(|truth| self.truths.insert(input.id, truth));
+                return None;
+            }
+            And | Or => {
+                let truth = match (self.truths.get(&input.left.id()), self.truths.get(&input.right.id())) {
+                    (Some(false), _) | (_, Some(false)) if input.op == And => Some(false),
+                    (Some(true), Some(true)) if input.op == And => Some(true),
+                    (Some(true), _) | (_, Some(true)) if input.op == Or => Some(true),
+                    (Some(false), Some(false)) if input.op == Or => Some(false),
+                    _ => None,
+                };
+                truth.map(|truth| self.truths.insert(input.id, truth));
+                return None;
+            }
+            Div if self.type_table.get(&input.id) == Some(Type::Field) => {
+                let counterexample = format!("{} / 0field", input.left);
+                self.audit_field_division(input, &input.right, input.span, counterexample);
+                return None;
+            }
+            _ => {}
+        }
+
+        let type_ = self.integer_type(input.id)?;
+        match input.op {
+            Add => self.audit_arithmetic(input, type_, operands, i128::checked_add),
+            Sub => self.audit_arithmetic(input, type_, operands, i128::checked_sub),
+            Mul => self.audit_arithmetic(input, type_, operands, i128::checked_mul),
+            Pow => self.audit_pow(input, type_, operands),
+            Div | Rem | Mod => self.audit_division(input, type_, operands),
+            Shl | Shr => self.audit_shift(input, type_, operands),
+            // The remaining operations cannot halt.
+            _ => None,
+        }
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        let range = self.range_of(&input.expression);
+        let target = match (&input.type_, input.wrapped) {
+            (Type::Integer(target), false) => *target,
+            _ => return None,
+        };
+
+        let verdict = match (self.type_table.get(&input.expression.id()), range) {
+            (Some(Type::Integer(source)), Some(range)) => Self::verdict(
+                range.is_point(),
+                [range.min, range.max]
+                    .map(|value| (format!("{value}{source} as {target}"), fits(Some(value), target).map(|fits| !fits))),
+            ),
+            (Some(Type::Integer(source)), None) => Self::verdict(
+                false,
+                [(format!("{}{source} as {target}", u128::MAX), Some(target != IntegerType::U128))],
+            ),
+            (Some(Type::Boolean), _) => AuditVerdict::Safe,
+            _ => AuditVerdict::Unknown,
+        };
+        self.record(input, HaltKind::FailedCast, input.span, verdict);
+
+        match self.type_table.get(&input.expression.id()) {
+            Some(Type::Boolean) => Some(Interval::new(0, 1)),
+            _ => clamp(range, target),
+        }
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.ranges.get(&input.name).or_else(|| self.constants.get(&input.name)).copied()
+    }
+
+    fn visit_literal(&mut self, input: &'a Literal, _additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            Literal::Boolean(value, _, id) => {
+                self.truths.insert(*id, *value);
+                None
+            }
+            Literal::Integer(..) | Literal::Unsuffixed(..) => {
+                let literal = match self.type_table.get(&input.id()) {
+                    Some(type_) => input.clone().with_inferred_type(&type_),
+                    None => input.clone(),
+                };
+                let value = Value::try_from(&literal).ok()?;
+                i128::try_from(&value).ok().map(Interval::point)
+            }
+            _ => None,
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.range_of(expression);
+        });
+        None
+    }
+
+    fn visit_ternary(&mut self, input: &'a TernaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.range_of(&input.condition);
+        let (if_true, if_false) = (self.range_of(&input.if_true), self.range_of(&input.if_false));
+        Some(if_true?.join(if_false?))
+    }
+
+    fn visit_unary(&mut self, input: &'a UnaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        let range = self.range_of(&input.receiver);
+        match input.op {
+            UnaryOperation::Not => {
+                if let Some(truth) = self.truths.get(&input.receiver.id()).copied() {
+                    self.truths.insert(input.id, !truth);
+                }
+                None
+            }
+            UnaryOperation::Inverse => {
+                let counterexample = "0field.inv()".to_string();
+                self.audit_field_division(input, &input.receiver, input.span, counterexample);
+                None
+            }
+            UnaryOperation::Abs | UnaryOperation::Negate => {
+                let type_ = self.integer_type(input.id)?;
+                let (Some(bounds), true) = (Interval::of_type(type_), type_.is_signed()) else {
+                    return clamp(range, type_);
+                };
+                let range = range.unwrap_or(bounds);
+                let counterexample = match input.op {
+                    UnaryOperation::Abs => format!("{}{type_}.abs()", bounds.min),
+                    _ => format!("-({}{type_})", bounds.min),
+                };
+                let verdict = Self::verdict(range.is_point(), [(counterexample, Some(range.contains(bounds.min)))]);
+                self.record(input, HaltKind::Overflow, input.span, verdict);
+
+                let negated = range.min.checked_neg().zip(range.max.checked_neg());
+                let range = match input.op {
+                    UnaryOperation::Abs if range.min >= 0 => Some(range),
+                    UnaryOperation::Abs => range.magnitude().map(|magnitude| Interval::new(0, magnitude)),
+                    _ => negated.map(|(max, min)| Interval::new(min, max)),
+                };
+                clamp(range, type_)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for PanicAuditor<'a> {
+    fn visit_assert(&mut self, input: &'a AssertStatement) {
+        match &input.variant {
+            AssertVariant::Assert(condition) => {
+                self.range_of(condition);
+                let holds = self.truths.get(&condition.id()).copied();
+                self.audit_assertion(
+                    format!("assert({condition})"),
+                    input.span,
+                    holds,
+                    format!("`{condition}` is false"),
+                );
+            }
+            AssertVariant::AssertEq(left, right) => {
+                let holds = self.equality(left, right);
+                let counterexample = format!("`{left}` is never equal to `{right}`");
+                self.audit_assertion(format!("assert_eq({left}, {right})"), input.span, holds, counterexample);
+            }
+            AssertVariant::AssertNeq(left, right) => {
+                let holds = self.equality(left, right).map(|equal| !equal);
+                let counterexample = format!("`{left}` is always equal to `{right}`");
+                self.audit_assertion(format!("assert_neq({left}, {right})"), input.span, holds, counterexample);
+            }
+        }
+    }
+
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        let range = self.range_of(&input.value);
+        self.assign(&input.place, range);
+    }
+
+    fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
+        self.range_of(&input.condition);
+        let before = self.ranges.clone();
+        self.visit_block(&input.then);
+        let then = core::mem::replace(&mut self.ranges, before);
+        if let Some(otherwise) = &input.otherwise {
+            self.visit_statement(otherwise);
+        }
+        // After the conditional, a variable ranges over the values it may take on either branch.
+        let otherwise = core::mem::take(&mut self.ranges);
+        self.ranges =
+            then.into_iter().filter_map(|(name, range)| Some((name, range.join(*otherwise.get(&name)?)))).collect();
+    }
+
+    fn visit_console(&mut self, input: &'a ConsoleStatement) {
+        match &input.function {
+            ConsoleFunction::Assert(condition) => {
+                self.range_of(condition);
+                let holds = self.truths.get(&condition.id()).copied();
+                let operation = format!("console.assert({condition})");
+                self.audit_assertion(operation, input.span, holds, format!("`{condition}` is false"));
+            }
+            ConsoleFunction::AssertEq(left, right) => {
+                let holds = self.equality(left, right);
+                let counterexample = format!("`{left}` is never equal to `{right}`");
+                self.audit_assertion(format!("console.assert_eq({left}, {right})"), input.span, holds, counterexample);
+            }
+            ConsoleFunction::AssertNeq(left, right) => {
+                let holds = self.equality(left, right).map(|equal| !equal);
+                let counterexample = format!("`{left}` is always equal to `{right}`");
+                self.audit_assertion(format!("console.assert_neq({left}, {right})"), input.span, holds, counterexample);
+            }
+            ConsoleFunction::Log(args) => args.parameters.iter().for_each(|parameter| {
+                self.visit_expression(parameter, &());
+            }),
+        }
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        let range = self.range_of(&input.value);
+        self.assign(&Expression::Identifier(input.place), range);
+    }
+
+    fn visit_declaration(&mut self, input: &'a DeclarationStatement) {
+        self.ranges.shift_remove(&input.identifier.name);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        let range = self.range_of(&input.value);
+        self.assign(&input.place, range);
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        let (start, stop) = (self.range_of(&input.start), self.range_of(&input.stop));
+
+        // The body is audited once, for any iteration, so the variables it assigns may take any value of their type.
+        let mut assigned = IndexSet::new();
+        input.block.statements.iter().for_each(|statement| assigned_variables(statement, &mut assigned));
+        assigned.iter().for_each(|name| {
+            self.ranges.shift_remove(name);
+        });
+
+        let variable = start.zip(stop).and_then(|(start, stop)| {
+            let max = if input.inclusive { stop.max } else { stop.max.checked_sub(1)? };
+            (start.min <= max).then(|| Interval::new(start.min, max))
+        });
+        self.assign(&Expression::Identifier(input.variable), variable);
+        self.visit_block(&input.block);
+
+        assigned.iter().chain([&input.variable.name]).for_each(|name| {
+            self.ranges.shift_remove(name);
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utilities::{parse_ast, type_check},
+        Pass,
+    };
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const PROGRAM: &str = "program test.aleo {
+    const LIMIT: u8 = 200u8;
+
+    transition main(a: u8, b: u8, c: i8, d: u128) -> u8 {
+        let quarter: u8 = a / 4u8;
+        let small: u8 = quarter + 10u8;
+        let sum: u8 = a + b;
+        let quotient: u8 = a / b;
+        let limit: u8 = LIMIT;
+        let large: u8 = limit + 100u8;
+        let cast: u8 = 300u16 as u8;
+        let negated: i8 = -c;
+        let shifted: u128 = d >> 3u8;
+        let total: u8 = 0u8;
+        for i: u8 in 0u8..10u8 {
+            total = total + i;
+        }
+        let branch: u8 = 0u8;
+        if a > b {
+            branch = 5u8;
+        } else {
+            branch = 7u8;
+        }
+        let scaled: u8 = branch * 30u8;
+        assert(small < 100u8);
+        assert_eq(quarter, 64u8);
+        assert_neq(a, b);
+        return scaled;
+    }
+}
+";
+
+    fn audit(source: &str) -> PanicAuditReport {
+        let ast = parse_ast(source);
+        let type_table = TypeTable::default();
+        type_check(&ast, &type_table);
+        PanicAuditor::do_pass((&ast, &type_table))
+    }
+
+    fn verdicts(report: &PanicAuditReport) -> Vec<(&str, HaltKind, &AuditVerdict)> {
+        report.findings.iter().map(|finding| (finding.operation.as_str(), finding.kind, &finding.verdict)).collect()
+    }
+
+    #[test]
+    fn test_verdicts() {
+        create_session_if_not_set_then(|_| {
+            let report = audit(PROGRAM);
+            let may_halt = |counterexample: &str| AuditVerdict::MayHalt(counterexample.to_string());
+            let halts = |counterexample: &str| AuditVerdict::Halts(counterexample.to_string());
+            assert_eq!(
+                verdicts(&report),
+                [
+                    ("a / 4u8", HaltKind::DivisionByZero, &AuditVerdict::Safe),
+                    ("quarter + 10u8", HaltKind::Overflow, &AuditVerdict::Safe),
+                    ("a + b", HaltKind::Overflow, &may_halt("255u8 + 255u8")),
+                    ("a / b", HaltKind::DivisionByZero, &may_halt("0u8 / 0u8")),
+                    ("limit + 100u8", HaltKind::Overflow, &halts("200u8 + 100u8")),
+                    ("(300u16 as u8)", HaltKind::FailedCast, &halts("300u16 as u8")),
+                    ("negc", HaltKind::Overflow, &may_halt("-(-128i8)")),
+                    ("d >> 3u8", HaltKind::Overflow, &AuditVerdict::Safe),
+                    ("total + i", HaltKind::Overflow, &may_halt("255u8 + 9u8")),
+                    ("branch * 30u8", HaltKind::Overflow, &AuditVerdict::Safe),
+                    ("assert(small < 100u8)", HaltKind::AssertionFailure, &AuditVerdict::Safe),
+                    (
+                        "assert_eq(quarter, 64u8)",
+                        HaltKind::AssertionFailure,
+                        &halts("`quarter` is never equal to `64u8`")
+                    ),
+                    ("assert_neq(a, b)", HaltKind::AssertionFailure, &AuditVerdict::Unknown),
+                ]
+            );
+            assert!(!report.is_panic_free());
+            assert_eq!(report.unsafe_findings().count(), 8);
+            assert_eq!(report.findings[0].item, "main");
+            assert_eq!(report.findings[0].location.unwrap().line_start, 5);
+        })
+    }
+
+    #[test]
+    fn test_panic_free() {
+        create_session_if_not_set_then(|_| {
+            let report = audit(
+                "program test.aleo {
+    transition main(a: u8) -> u16 {
+        let b: u16 = a as u16;
+        return b * 256u16 + 1u16.pow(2u8);
+    }
+}
+",
+            );
+            assert_eq!(report.findings.len(), 4);
+            assert!(report.is_panic_free(), "{report:?}");
+
+            let json = serde_json::to_string(&report).unwrap();
+            assert_eq!(PanicAuditReport::from_json_string(&json).unwrap(), report);
+        })
+    }
+}
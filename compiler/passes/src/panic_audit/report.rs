@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Location;
+
+use leo_errors::{AstError, Result};
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The ways in which an operation can halt the execution of a program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HaltKind {
+    /// A checked arithmetic operation produces a value outside of its type, e.g. `255u8 + 1u8`.
+    Overflow,
+    /// A division, remainder, or inverse of zero.
+    DivisionByZero,
+    /// A cast of a value that does not fit in the target type, e.g. `256u16 as u8`.
+    FailedCast,
+    /// An assertion whose condition does not hold.
+    AssertionFailure,
+}
+
+/// The outcome of auditing an operation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "verdict", content = "counterexample", rename_all = "snake_case")]
+pub enum AuditVerdict {
+    /// The operation does not halt on any path.
+    Safe,
+    /// The operation halts whenever it is executed, on the given operands.
+    Halts(String),
+    /// The operation halts on the given operands, which lie within the ranges computed for them.
+    /// Since the ranges over-approximate the values of the operands, the counterexample may not be reachable.
+    MayHalt(String),
+    /// The analysis cannot decide whether the operation halts.
+    Unknown,
+}
+
+/// An operation of the program that may halt its execution.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditFinding {
+    /// The function or constant containing the operation.
+    pub item: String,
+    /// The operation, as written in the source, e.g. `a + b`.
+    pub operation: String,
+    pub kind: HaltKind,
+    pub location: Option<Location>,
+    pub verdict: AuditVerdict,
+}
+
+/// The findings of the panic audit of a program, in the order of the operations in the program.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PanicAuditReport {
+    pub findings: Vec<AuditFinding>,
+}
+
+impl PanicAuditReport {
+    /// Returns `true` if every operation of the program is proven not to halt.
+    pub fn is_panic_free(&self) -> bool {
+        self.findings.iter().all(|finding| finding.verdict == AuditVerdict::Safe)
+    }
+
+    /// Returns the findings that are not proven safe.
+    pub fn unsafe_findings(&self) -> impl Iterator<Item = &AuditFinding> {
+        self.findings.iter().filter(|finding| finding.verdict != AuditVerdict::Safe)
+    }
+
+    /// Serializes the report into a JSON file.
+    pub fn to_json_file(&self, mut path: PathBuf, file_name: &str) -> Result<()> {
+        path.push(file_name);
+        let file =
+            std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_panic_audit_json_file(&path, &e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(serde_json::to_writer_pretty(writer, &self)
+            .map_err(|e| AstError::failed_to_write_panic_audit_to_json_file(&path, &e))?)
+    }
+
+    /// Deserializes the JSON string into a report.
+    pub fn from_json_string(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json).map_err(|e| AstError::failed_to_read_json_string_to_panic_audit(&e))?)
+    }
+}
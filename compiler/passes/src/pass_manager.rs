@@ -0,0 +1,392 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_errors::{CompilerError, Result};
+
+use indexmap::{IndexMap, IndexSet};
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// An analysis of the program that is computed by a pass, and used by later passes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Analysis {
+    SymbolTable,
+    TypeTable,
+    StructGraph,
+    CallGraph,
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::SymbolTable => write!(f, "symbol table"),
+            Self::TypeTable => write!(f, "type table"),
+            Self::StructGraph => write!(f, "struct graph"),
+            Self::CallGraph => write!(f, "call graph"),
+        }
+    }
+}
+
+/// The function running a pass on the state `C`.
+type PassFn<C> = Box<dyn FnMut(&mut C) -> Result<()>>;
+
+/// A function run on the state `C` before each pass, with the name of the pass.
+type BeforeHook<C> = Box<dyn FnMut(&mut C, &'static str)>;
+
+/// A function run on the state `C` after each pass that succeeds, with the time that the pass took.
+type AfterHook<C> = Box<dyn FnMut(&mut C, &PassTiming)>;
+
+/// A pass in a pipeline, which runs on the state `C` threaded through the pipeline.
+pub struct PassDescriptor<C> {
+    name: &'static str,
+    run: PassFn<C>,
+    /// Whether the pass can be disabled, since no later pass relies on it, e.g. an optimization.
+    optional: bool,
+    /// The passes that must run before this pass, if they are enabled.
+    after: Vec<&'static str>,
+    /// The passes that must run after this pass, if they are enabled.
+    before: Vec<&'static str>,
+    /// The analyses that must be available when the pass runs.
+    requires: Vec<Analysis>,
+    /// The analyses that the pass computes.
+    provides: Vec<Analysis>,
+    /// The analyses that are out of date after the pass runs, unless it also provides them.
+    invalidates: Vec<Analysis>,
+}
+
+impl<C> PassDescriptor<C> {
+    /// Returns a required pass named `name` that runs `run`.
+    pub fn new(name: &'static str, run: impl FnMut(&mut C) -> Result<()> + 'static) -> Self {
+        Self {
+            name,
+            run: Box::new(run),
+            optional: false,
+            after: Vec::new(),
+            before: Vec::new(),
+            requires: Vec::new(),
+            provides: Vec::new(),
+            invalidates: Vec::new(),
+        }
+    }
+
+    /// Marks the pass as optional, so that it can be disabled.
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// Requires the pass to run after the passes `passes`.
+    pub fn after(mut self, passes: impl IntoIterator<Item = &'static str>) -> Self {
+        self.after.extend(passes);
+        self
+    }
+
+    /// Requires the pass to run before the passes `passes`.
+    pub fn before(mut self, passes: impl IntoIterator<Item = &'static str>) -> Self {
+        self.before.extend(passes);
+        self
+    }
+
+    /// Declares the analyses that the pass requires.
+    pub fn requires(mut self, analyses: impl IntoIterator<Item = Analysis>) -> Self {
+        self.requires.extend(analyses);
+        self
+    }
+
+    /// Declares the analyses that the pass computes.
+    pub fn provides(mut self, analyses: impl IntoIterator<Item = Analysis>) -> Self {
+        self.provides.extend(analyses);
+        self
+    }
+
+    /// Declares the analyses that the pass makes out of date.
+    pub fn invalidates(mut self, analyses: impl IntoIterator<Item = Analysis>) -> Self {
+        self.invalidates.extend(analyses);
+        self
+    }
+
+    /// Returns the name of the pass.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// The time that a pass took to run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PassTiming {
+    pub pass: &'static str,
+    pub duration: Duration,
+}
+
+/// Runs a pipeline of passes on the state `C`.
+/// The passes run in the order they are added in, except where that violates an ordering constraint.
+/// Before any pass runs, the pipeline is checked to provide the analyses that each pass requires.
+pub struct PassManager<C> {
+    passes: Vec<PassDescriptor<C>>,
+    /// The passes that are enabled or disabled, in the order that they are toggled in.
+    toggled: IndexMap<&'static str, bool>,
+    /// The pass after which the pipeline stops, if any.
+    stop_after: Option<String>,
+    before_each: Vec<BeforeHook<C>>,
+    after_each: Vec<AfterHook<C>>,
+    timings: Vec<PassTiming>,
+}
+
+impl<C> Default for PassManager<C> {
+    fn default() -> Self {
+        Self {
+            passes: Vec::new(),
+            toggled: IndexMap::new(),
+            stop_after: None,
+            before_each: Vec::new(),
+            after_each: Vec::new(),
+            timings: Vec::new(),
+        }
+    }
+}
+
+impl<C> PassManager<C> {
+    /// Adds the pass `pass` to the pipeline.
+    pub fn pass(mut self, pass: PassDescriptor<C>) -> Self {
+        self.passes.push(pass);
+        self
+    }
+
+    /// Enables the optional pass named `name`.
+    pub fn enable(self, name: &'static str) -> Self {
+        self.set_enabled(name, true)
+    }
+
+    /// Disables the optional pass named `name`.
+    pub fn disable(self, name: &'static str) -> Self {
+        self.set_enabled(name, false)
+    }
+
+    /// Enables or disables the optional pass named `name`.
+    /// Unknown passes and required passes are reported when the pipeline is run.
+    pub fn set_enabled(mut self, name: &'static str, enabled: bool) -> Self {
+        self.toggled.insert(name, enabled);
+        self
+    }
+
+    /// Stops the pipeline after the pass named `name`, skipping the passes scheduled after it, e.g. to test its output.
+    /// Unknown passes are reported when the pipeline is run.
+    pub fn stop_after(mut self, name: impl Into<String>) -> Self {
+        self.stop_after = Some(name.into());
+        self
+    }
+
+    /// Runs `hook` before each pass, e.g. to instrument the pipeline.
+    pub fn before_each(mut self, hook: impl FnMut(&mut C, &'static str) + 'static) -> Self {
+        self.before_each.push(Box::new(hook));
+        self
+    }
+
+    /// Runs `hook` after each pass that succeeds.
+    pub fn after_each(mut self, hook: impl FnMut(&mut C, &PassTiming) + 'static) -> Self {
+        self.after_each.push(Box::new(hook));
+        self
+    }
+
+    /// Returns the names of the enabled passes, in the order that they run in.
+    pub fn schedule(&self) -> Result<Vec<&'static str>> {
+        Ok(self.order()?.into_iter().map(|index| self.passes[index].name).collect())
+    }
+
+    /// Runs the enabled passes on `state`, stopping at the first pass that fails.
+    pub fn run(&mut self, state: &mut C) -> Result<()> {
+        self.timings.clear();
+        for index in self.order()? {
+            let pass = &mut self.passes[index];
+            self.before_each.iter_mut().for_each(|hook| hook(state, pass.name));
+            let start = Instant::now();
+            let result = (pass.run)(state);
+            let timing = PassTiming { pass: pass.name, duration: start.elapsed() };
+            self.timings.push(timing);
+            result?;
+            self.after_each.iter_mut().for_each(|hook| hook(state, &timing));
+        }
+        Ok(())
+    }
+
+    /// Returns the time that each pass took in the last run, in the order that they ran in.
+    pub fn timings(&self) -> &[PassTiming] {
+        &self.timings
+    }
+
+    /// Returns the indices of the enabled passes in the order that they run in, after checking the pipeline.
+    fn order(&self) -> Result<Vec<usize>> {
+        let index_of = |name: &str| {
+            self.passes.iter().position(|pass| pass.name == name).ok_or_else(|| CompilerError::unknown_pass(name))
+        };
+        for (&name, &enabled) in &self.toggled {
+            if !enabled && !self.passes[index_of(name)?].optional {
+                return Err(CompilerError::pass_cannot_be_disabled(name).into());
+            }
+        }
+        let enabled: IndexSet<usize> = (0..self.passes.len())
+            .filter(|&index| {
+                let pass = &self.passes[index];
+                self.toggled.get(pass.name).copied().unwrap_or(true)
+            })
+            .collect();
+
+        // The passes that each enabled pass must run after.
+        let mut predecessors: Vec<IndexSet<usize>> = vec![IndexSet::new(); self.passes.len()];
+        for &index in &enabled {
+            let pass = &self.passes[index];
+            for name in &pass.after {
+                predecessors[index].insert(index_of(name)?);
+            }
+            for name in &pass.before {
+                predecessors[index_of(name)?].insert(index);
+            }
+        }
+
+        // Repeatedly schedule the first pass whose predecessors are all scheduled, or disabled.
+        let mut order = Vec::with_capacity(enabled.len());
+        let mut remaining = enabled.clone();
+        while !remaining.is_empty() {
+            let Some(next) = remaining
+                .iter()
+                .copied()
+                .find(|&index| predecessors[index].iter().all(|predecessor| !remaining.contains(predecessor)))
+            else {
+                return Err(CompilerError::cyclic_pass_ordering(
+                    remaining.iter().map(|&index| self.passes[index].name).collect(),
+                )
+                .into());
+            };
+            remaining.shift_remove(&next);
+            order.push(next);
+        }
+
+        // Check that each pass has the analyses it requires.
+        let mut available = IndexSet::new();
+        for &index in &order {
+            let pass = &self.passes[index];
+            if let Some(analysis) = pass.requires.iter().find(|analysis| !available.contains(*analysis)) {
+                return Err(CompilerError::missing_analysis(pass.name, analysis).into());
+            }
+            for analysis in &pass.invalidates {
+                available.shift_remove(analysis);
+            }
+            available.extend(pass.provides.iter().copied());
+        }
+
+        if let Some(name) = &self.stop_after {
+            let last = index_of(name)?;
+            if let Some(position) = order.iter().position(|&index| index == last) {
+                order.truncate(position + 1);
+            }
+        }
+
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns a pass that records its name in the state.
+    fn pass(name: &'static str) -> PassDescriptor<Vec<&'static str>> {
+        PassDescriptor::new(name, move |ran: &mut Vec<&'static str>| {
+            ran.push(name);
+            Ok(())
+        })
+    }
+
+    fn pipeline() -> PassManager<Vec<&'static str>> {
+        PassManager::default()
+            .pass(pass("symbols").provides([Analysis::SymbolTable]))
+            .pass(pass("types").requires([Analysis::SymbolTable]).provides([Analysis::TypeTable]))
+            .pass(pass("unrolling").requires([Analysis::SymbolTable, Analysis::TypeTable]))
+            .pass(pass("dce").optional().after(["unrolling"]))
+    }
+
+    #[test]
+    fn test_run() {
+        let mut manager = pipeline();
+        let mut ran = Vec::new();
+        manager.run(&mut ran).unwrap();
+        assert_eq!(ran, ["symbols", "types", "unrolling", "dce"]);
+        assert_eq!(manager.timings().iter().map(|timing| timing.pass).collect::<Vec<_>>(), ran);
+    }
+
+    #[test]
+    fn test_hooks() {
+        let mut manager = pipeline()
+            .disable("dce")
+            .before_each(|ran: &mut Vec<&'static str>, _| ran.push("before"))
+            .after_each(|ran: &mut Vec<&'static str>, timing| ran.push(timing.pass));
+        let mut ran = Vec::new();
+        manager.run(&mut ran).unwrap();
+        assert_eq!(
+            ran,
+            ["before", "symbols", "symbols", "before", "types", "types", "before", "unrolling", "unrolling"]
+        );
+    }
+
+    #[test]
+    fn test_ordering_constraints() {
+        let manager = PassManager::default()
+            .pass(pass("audit").after(["types"]))
+            .pass(pass("types"))
+            .pass(pass("lint").before(["types"]));
+        assert_eq!(manager.schedule().unwrap(), ["lint", "types", "audit"]);
+
+        let manager = pipeline().pass(pass("early").before(["symbols"]).after(["dce"]));
+        assert!(manager.schedule().is_err());
+    }
+
+    #[test]
+    fn test_enable_and_disable() {
+        assert_eq!(pipeline().disable("dce").schedule().unwrap(), ["symbols", "types", "unrolling"]);
+        assert_eq!(pipeline().disable("dce").enable("dce").schedule().unwrap().len(), 4);
+        assert!(pipeline().disable("types").schedule().is_err());
+        assert!(pipeline().disable("unknown").schedule().is_err());
+    }
+
+    #[test]
+    fn test_stop_after() {
+        let mut manager = pipeline().stop_after("types");
+        let mut ran = Vec::new();
+        manager.run(&mut ran).unwrap();
+        assert_eq!(ran, ["symbols", "types"]);
+        assert_eq!(pipeline().stop_after("dce").schedule().unwrap().len(), 4);
+        assert!(pipeline().stop_after("unknown").schedule().is_err());
+    }
+
+    #[test]
+    fn test_analyses() {
+        // The type table is invalidated by the pass after type checking.
+        let mut manager = PassManager::default()
+            .pass(pass("symbols").provides([Analysis::SymbolTable]))
+            .pass(pass("types").requires([Analysis::SymbolTable]).provides([Analysis::TypeTable]))
+            .pass(pass("rewrite").invalidates([Analysis::TypeTable]))
+            .pass(pass("codegen").requires([Analysis::TypeTable]));
+        let mut ran = Vec::new();
+        assert!(manager.run(&mut ran).is_err());
+        assert!(ran.is_empty());
+
+        let manager =
+            manager.pass(pass("retype").provides([Analysis::TypeTable]).after(["rewrite"]).before(["codegen"]));
+        assert_eq!(manager.schedule().unwrap(), ["symbols", "types", "rewrite", "retype", "codegen"]);
+    }
+}
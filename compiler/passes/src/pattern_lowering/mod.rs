@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The pattern lowering pass lowers the destructuring of structs into definitions of their members.
+//! The destructured value is first bound to a new variable, so that it is evaluated exactly once,
+//! and each variable of the pattern is then defined as the corresponding member of that variable.
+//! The pass is run directly after type checking, which checks the patterns and records the types they bind.
+//! Note that the destructuring of tuples, e.g. `let (a, b) = pair;`, is left to the later passes.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition main(t: Token) -> u64 {
+//!     let Token { holder, amount: a } = t;
+//!     return a;
+//! }
+//! ```
+//!
+//! The pattern lowering pass produces the following code.
+//! ```leo
+//! transition main(t: Token) -> u64 {
+//!     let $pattern$0 = t;
+//!     let holder = $pattern$0.holder;
+//!     let a = $pattern$0.amount;
+//!     return a;
+//! }
+//! ```
+//!
+//! The pass also lowers `match` statements into a chain of conditional statements, one for each arm, in order.
+//! The matched value is bound to a new variable, and each arm but the last is guarded by a comparison
+//! of that variable with the arm's pattern. The last arm, which is a wildcard or completes an exhaustive match,
+//! becomes the final `else` block.
+//! ```leo
+//! match x {
+//!     0u8 => { y = 1u8; }
+//!     _ => { y = 2u8; }
+//! }
+//! ```
+//!
+//! The pattern lowering pass produces the following code.
+//! ```leo
+//! let $match$0 = x;
+//! if $match$0 == 0u8 {
+//!     y = 1u8;
+//! } else {
+//!     y = 2u8;
+//! }
+//! ```
+
+pub mod pattern_lowerer;
+pub use pattern_lowerer::*;
+
+use crate::{Assigner, Pass, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+
+impl<'a> Pass for PatternLowerer<'a> {
+    type Input = (Ast, &'a TypeTable, &'a NodeBuilder, &'a Assigner);
+    type Output = Ast;
+
+    fn do_pass((ast, type_table, node_builder, assigner): Self::Input) -> Self::Output {
+        let mut reconstructor = PatternLowerer::new(type_table, node_builder, assigner);
+        Ast::new(reconstructor.reconstruct_program(ast.into_repr()))
+    }
+}
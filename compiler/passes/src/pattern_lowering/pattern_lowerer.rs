@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assigner, TypeTable};
+
+use leo_ast::*;
+use leo_span::Span;
+
+pub struct PatternLowerer<'a> {
+    /// A mapping between node IDs and their types.
+    type_table: &'a TypeTable,
+    /// A counter used to generate unique node IDs.
+    node_builder: &'a NodeBuilder,
+    /// A struct used to construct unique variable names.
+    assigner: &'a Assigner,
+}
+
+impl<'a> PatternLowerer<'a> {
+    pub(crate) fn new(type_table: &'a TypeTable, node_builder: &'a NodeBuilder, assigner: &'a Assigner) -> Self {
+        Self { type_table, node_builder, assigner }
+    }
+
+    /// Lowers a statement, returning the statements that replace it.
+    fn lower_statement(&mut self, statement: Statement) -> Vec<Statement> {
+        match statement {
+            Statement::Definition(DefinitionStatement { place: Expression::Struct(pattern), value, span, .. }) => {
+                self.lower_struct_pattern(pattern, value, span)
+            }
+            Statement::Match(match_) => self.lower_match(match_),
+            statement => vec![self.reconstruct_statement(statement).0],
+        }
+    }
+
+    /// Lowers the destructuring of `value` with a struct pattern into a definition for each bound member.
+    fn lower_struct_pattern(&mut self, pattern: StructExpression, value: Expression, span: Span) -> Vec<Statement> {
+        // Note that type checking guarantees that the types of the pattern and its variables are known.
+        let struct_type = self.type_table.get(&pattern.id).unwrap();
+        let name = self.assigner.unique_symbol("$pattern", "$");
+        let place = Identifier { name, span: pattern.span, id: self.node_builder.next_id() };
+        self.type_table.insert(place.id, struct_type.clone());
+
+        let value = self.reconstruct_expression(value).0;
+        let mut statements = vec![self.definition(place, value, span)];
+        for member in pattern.members {
+            let binding = match member.expression {
+                Some(Expression::Identifier(identifier)) => identifier,
+                _ => member.identifier,
+            };
+            let type_ = self.type_table.get(&binding.id).unwrap();
+
+            // Access the member of the destructured value, e.g. `$pattern$0.amount`.
+            let inner = Identifier { id: self.node_builder.next_id(), ..place };
+            self.type_table.insert(inner.id, struct_type.clone());
+            let access = Expression::Access(AccessExpression::Member(MemberAccess {
+                inner: Box::new(Expression::Identifier(inner)),
+                name: Identifier { id: self.node_builder.next_id(), ..member.identifier },
+                span: member.span,
+                id: self.node_builder.next_id(),
+            }));
+            self.type_table.insert(access.id(), type_);
+
+            statements.push(self.definition(binding, access, member.span));
+        }
+        statements
+    }
+
+    /// Lowers a match statement into a chain of conditionals, which compare the matched value to each pattern in turn.
+    /// The last arm becomes the final `else` branch, since type checking guarantees that the arms are exhaustive,
+    /// and that only the last arm may be a wildcard.
+    fn lower_match(&mut self, input: MatchStatement) -> Vec<Statement> {
+        // Note that type checking guarantees that the type of the value is known.
+        let type_ = self.type_table.get(&input.value.id()).unwrap();
+        let name = self.assigner.unique_symbol("$match", "$");
+        let place = Identifier { name, span: input.value.span(), id: self.node_builder.next_id() };
+        self.type_table.insert(place.id, type_.clone());
+
+        let value = self.reconstruct_expression(input.value).0;
+        let definition = self.definition(place, value, place.span);
+
+        // The arms are reconstructed in order, and the chain is then built from the last arm.
+        let arms: Vec<_> = input
+            .arms
+            .into_iter()
+            .map(|arm| {
+                (
+                    arm.pattern.map(|pattern| self.reconstruct_expression(pattern).0),
+                    self.reconstruct_block(arm.block).0,
+                    arm.span,
+                )
+            })
+            .collect();
+        let mut chain = None;
+        for (pattern, block, span) in arms.into_iter().rev() {
+            chain = Some(match (pattern, chain) {
+                // The last arm is taken if no earlier arm is.
+                (_, None) => Statement::Block(block),
+                (Some(pattern), Some(otherwise)) => {
+                    // Compare the value to the pattern, e.g. `$match$0 == 1u8`.
+                    let left = Identifier { id: self.node_builder.next_id(), ..place };
+                    self.type_table.insert(left.id, type_.clone());
+                    let condition = Expression::Binary(BinaryExpression {
+                        left: Box::new(Expression::Identifier(left)),
+                        span: pattern.span(),
+                        right: Box::new(pattern),
+                        op: BinaryOperation::Eq,
+                        id: self.node_builder.next_id(),
+                    });
+                    self.type_table.insert(condition.id(), Type::Boolean);
+
+                    Statement::Conditional(ConditionalStatement {
+                        condition,
+                        then: block,
+                        otherwise: Some(Box::new(otherwise)),
+                        span,
+                        id: self.node_builder.next_id(),
+                    })
+                }
+                (None, Some(_)) => {
+                    unreachable!("Type checking guarantees that only the last arm of a match may be a wildcard.")
+                }
+            });
+        }
+
+        std::iter::once(definition).chain(chain).collect()
+    }
+
+    /// Returns the definition `let place = value;`, whose type is already recorded in the type table.
+    fn definition(&self, place: Identifier, value: Expression, span: Span) -> Statement {
+        Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(place),
+            type_: None,
+            value,
+            span,
+            id: self.node_builder.next_id(),
+        })
+    }
+}
+
+impl ExpressionReconstructor for PatternLowerer<'_> {
+    type AdditionalOutput = ();
+
+    /// Reconstructs the statements of a block expression, which may also destructure structs.
+    fn reconstruct_block_expression(&mut self, input: BlockExpression) -> (Expression, Self::AdditionalOutput) {
+        let statements = input.statements.into_iter().flat_map(|s| self.lower_statement(s)).collect();
+        let value = Box::new(self.reconstruct_expression(*input.value).0);
+        (Expression::Block(BlockExpression { statements, value, ..input }), Default::default())
+    }
+}
+
+impl StatementReconstructor for PatternLowerer<'_> {
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        let statements = input.statements.into_iter().flat_map(|s| self.lower_statement(s)).collect();
+        (Block { statements, ..input }, Default::default())
+    }
+}
+
+impl ProgramReconstructor for PatternLowerer<'_> {
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            methods: input
+                .methods
+                .into_iter()
+                .map(|(name, method)| (name, self.reconstruct_function(method)))
+                .collect(),
+            ..input
+        }
+    }
+}
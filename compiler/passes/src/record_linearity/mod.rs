@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Record Linearity pass checks that records, which represent spendable state, are neither duplicated nor lost.
+//! Every local variable that holds a record must be consumed exactly once on every path through its function.
+//! A record is consumed when it is returned, passed to a function, used to initialize a struct, record, tuple, or
+//! array, or moved into another variable. Reading a member of a record, e.g. `token.amount`, does not consume it.
+//! A record may be discarded explicitly by moving it into a variable whose name starts with `_`.
+//! The record inputs of a function are exempt from the last rule, since a transition spends the records it is given.
+//! The pass is run after type checking, so the program is known to be well-typed.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! transition split(t: Token) -> (Token, Token) {
+//!     let half: u64 = t.amount / 2u64;
+//!     let first: Token = Token { owner: t.owner, amount: half };
+//!     let second: Token = Token { owner: t.owner, amount: t.amount - half };
+//!     return (first, first);
+//! }
+//! ```
+//!
+//! The pass reports that `first` is used after it has been consumed, and that `second` would be dropped.
+
+pub mod record_linearity_checker;
+pub use record_linearity_checker::*;
+
+use crate::{Pass, SymbolTable, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for RecordLinearityChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a SymbolTable, &'a TypeTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, symbol_table, type_table): Self::Input) -> Self::Output {
+        let mut visitor = RecordLinearityChecker::new(handler, symbol_table, type_table);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)
+    }
+}
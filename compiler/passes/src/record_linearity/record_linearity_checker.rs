@@ -0,0 +1,389 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{SymbolTable, TypeTable};
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, Label, TypeCheckerError};
+use leo_span::{span::BytePos, Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// How an expression uses the records that it refers to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Usage {
+    /// The value is only read, e.g. the receiver of a member access or an operand of `==`.
+    #[default]
+    Read,
+    /// The value is moved, e.g. an argument of a call or the value of a return statement.
+    Consume,
+}
+
+/// The state of a variable that holds a record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ownership {
+    /// The variable is declared, but it has not been assigned yet.
+    Unassigned,
+    /// The variable holds a record that has not been consumed.
+    Owned,
+    /// The record was consumed at the span.
+    Consumed(Span),
+    /// The record was consumed at the span on some paths, but not on others.
+    MaybeConsumed(Span),
+}
+
+impl Ownership {
+    /// Returns the ownership of a variable after two branches, at the ends of which it has ownership `self` and `other`.
+    fn join(self, other: Self) -> Self {
+        use Ownership::*;
+        match (self, other) {
+            // Definite assignment checking ensures that a variable is assigned before it is used.
+            (Unassigned, other) | (other, Unassigned) => other,
+            (Owned, Owned) => Owned,
+            (Consumed(span), Consumed(_)) => Consumed(span),
+            (MaybeConsumed(span), _) | (_, MaybeConsumed(span)) | (Consumed(span), Owned) | (Owned, Consumed(span)) => {
+                MaybeConsumed(span)
+            }
+        }
+    }
+}
+
+pub struct RecordLinearityChecker<'a> {
+    /// An error handler used for any errors found during record linearity checking.
+    handler: &'a Handler,
+    /// The symbol table of the type-checked program.
+    symbol_table: &'a SymbolTable,
+    /// The types of the expressions of the type-checked program.
+    type_table: &'a TypeTable,
+    /// The ownership of each variable that holds a record on the current path.
+    variables: IndexMap<Symbol, Ownership>,
+    /// The declarations of the variables that must be consumed, i.e. the records that are not inputs.
+    declarations: IndexMap<Symbol, Span>,
+    /// Whether the current path has returned.
+    returned: bool,
+}
+
+impl<'a> RecordLinearityChecker<'a> {
+    /// Returns a new record linearity checker given an error handler, the symbol table, and the type table.
+    pub fn new(handler: &'a Handler, symbol_table: &'a SymbolTable, type_table: &'a TypeTable) -> Self {
+        Self {
+            handler,
+            symbol_table,
+            type_table,
+            variables: Default::default(),
+            declarations: Default::default(),
+            returned: false,
+        }
+    }
+
+    /// Returns whether `type_` is a record type.
+    fn is_record(&self, type_: &Type) -> bool {
+        match type_ {
+            Type::Identifier(identifier) => {
+                self.symbol_table.lookup_struct(identifier.name).map_or(false, |struct_| struct_.is_record)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns whether a value of type `type_` holds a record.
+    fn contains_record(&self, type_: &Type) -> bool {
+        match type_ {
+            Type::Array(array) => self.contains_record(array.element_type()),
+            Type::Tuple(tuple) => tuple.elements().iter().any(|element| self.contains_record(element)),
+            type_ => self.is_record(type_),
+        }
+    }
+
+    /// Checks the body of a function or finalize block with the given inputs.
+    fn check_body(&mut self, input: &'a [Input], block: &'a Block) {
+        // The records that are inputs may be dropped, so they are not declarations.
+        self.variables = input
+            .iter()
+            .filter(|input| self.is_record(&input.type_()))
+            .map(|input| (input.identifier().name, Ownership::Owned))
+            .collect();
+        self.declarations.clear();
+        self.returned = false;
+        self.visit_block(block);
+    }
+
+    /// Declares the variables of `place`, which are assigned a value of type `type_`, with the given ownership.
+    fn declare(&mut self, place: &Expression, type_: Option<Type>, ownership: Ownership) {
+        match (place, type_) {
+            (Expression::Identifier(identifier), Some(type_)) if self.is_record(&type_) => {
+                // A record that is moved into a variable whose name starts with `_` is discarded explicitly, and the
+                // variables introduced by the compiler, e.g. to destructure a record, take the record apart.
+                let name = identifier.name.to_string();
+                if !name.starts_with('_') && !name.starts_with('$') {
+                    self.variables.insert(identifier.name, ownership);
+                    self.declarations.insert(identifier.name, identifier.span);
+                }
+            }
+            (Expression::Tuple(tuple), Some(Type::Tuple(types))) => {
+                for (element, type_) in tuple.elements.iter().zip(types.elements()) {
+                    self.declare(element, Some(type_.clone()), ownership);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Records that the record held by `identifier` is consumed, and emits an error if it already was.
+    fn consume(&mut self, identifier: &Identifier) {
+        let Some(ownership) = self.variables.get_mut(&identifier.name) else { return };
+        let label = match *ownership {
+            Ownership::Unassigned => return,
+            Ownership::Owned => None,
+            Ownership::Consumed(span) => Some(Label::new(format!("`{identifier}` is consumed here"), span)),
+            Ownership::MaybeConsumed(span) => Some(Label::new(format!("`{identifier}` may be consumed here"), span)),
+        };
+        *ownership = Ownership::Consumed(identifier.span);
+        if let Some(label) = label {
+            self.handler
+                .emit_err(TypeCheckerError::record_consumed_twice(identifier, identifier.span).with_label(label));
+        }
+    }
+
+    /// Emits an error for each of the variables `names` whose record has not been consumed when it is lost at `span`,
+    /// e.g. when the variable goes out of scope.
+    fn check_dropped(&self, names: impl IntoIterator<Item = Symbol>, span: Span, reason: &str) {
+        for name in names {
+            let Some(declaration) = self.declarations.get(&name) else { continue };
+            if let Some(Ownership::Owned | Ownership::MaybeConsumed(_)) = self.variables.get(&name) {
+                let label = Label::new(format!("`{name}` {reason}"), span);
+                self.handler.emit_err(TypeCheckerError::record_dropped(name, *declaration).with_label(label));
+            }
+        }
+    }
+
+    /// Checks the statements of a block, whose variables go out of scope at `end`, followed by its value, if any.
+    fn check_scope(&mut self, statements: &'a [Statement], value: Option<&'a Expression>, usage: Usage, end: Span) {
+        let outer = self.variables.len();
+        statements.iter().for_each(|statement| self.visit_statement(statement));
+        if let Some(value) = value {
+            self.visit_expression(value, &usage);
+        }
+        if !self.returned {
+            let names: Vec<Symbol> = self.variables.keys().skip(outer).copied().collect();
+            self.check_dropped(names, end, "goes out of scope here");
+        }
+        self.variables.truncate(outer);
+    }
+
+    /// Checks a branch from the current state, returning the ownership of the variables at its end, unless it returns.
+    /// The current state is left unchanged.
+    fn check_branch(&mut self, check: impl FnOnce(&mut Self)) -> Option<IndexMap<Symbol, Ownership>> {
+        let variables = self.variables.clone();
+        check(self);
+        let returned = core::mem::take(&mut self.returned);
+        let end = core::mem::replace(&mut self.variables, variables);
+        (!returned).then_some(end)
+    }
+
+    /// Sets the state to the join of the states at the ends of the branches that do not return.
+    fn join(&mut self, branches: impl IntoIterator<Item = Option<IndexMap<Symbol, Ownership>>>) {
+        let mut ends = branches.into_iter().flatten();
+        match ends.next() {
+            Some(first) => {
+                self.variables = ends.fold(first, |mut joined, end| {
+                    for (name, ownership) in joined.iter_mut() {
+                        if let Some(other) = end.get(name) {
+                            *ownership = ownership.join(*other);
+                        }
+                    }
+                    joined
+                })
+            }
+            // Every branch returns, so neither does the code after them.
+            None => self.returned = true,
+        }
+    }
+}
+
+/// Returns the span of the last character of `span`, e.g. the closing brace of a block.
+fn last_character(span: Span) -> Span {
+    Span::new(BytePos(span.hi.0.saturating_sub(1)).max(span.lo), span.hi)
+}
+
+impl<'a> ExpressionVisitor<'a> for RecordLinearityChecker<'a> {
+    type AdditionalInput = Usage;
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        // Accessing a part of a value only reads it, and the built-in functions, e.g. hashes, only read their
+        // arguments.
+        match input {
+            AccessExpression::Array(access) => {
+                self.visit_expression(&access.array, &Usage::Read);
+                self.visit_expression(&access.index, &Usage::Read);
+            }
+            AccessExpression::AssociatedConstant(_) => {}
+            AccessExpression::AssociatedFunction(function) => {
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, &Usage::Read))
+            }
+            AccessExpression::Member(access) => self.visit_expression(&access.inner, &Usage::Read),
+            AccessExpression::Tuple(access) => self.visit_expression(&access.tuple, &Usage::Read),
+        }
+    }
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.left, &Usage::Read);
+        self.visit_expression(&input.right, &Usage::Read);
+    }
+
+    fn visit_block_expression(
+        &mut self,
+        input: &'a BlockExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        self.check_scope(&input.statements, Some(&input.value), *additional, last_character(input.span));
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.function, &Usage::Read);
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, &Usage::Consume));
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, &Usage::Read);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        for member in &input.members {
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, &Usage::Consume),
+                // A shorthand initializer `Foo { x }` moves the variable `x`.
+                None => self.consume(&member.identifier),
+            }
+        }
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, additional: &Self::AdditionalInput) -> Self::Output {
+        if *additional == Usage::Consume {
+            self.consume(input);
+        }
+    }
+
+    fn visit_ternary(&mut self, input: &'a TernaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Both branches are evaluated, so both are consumed.
+        self.visit_expression(&input.condition, &Usage::Read);
+        self.visit_expression(&input.if_true, additional);
+        self.visit_expression(&input.if_false, additional);
+    }
+
+    fn visit_unary(&mut self, input: &'a UnaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.receiver, &Usage::Read);
+    }
+}
+
+impl<'a> StatementVisitor<'a> for RecordLinearityChecker<'a> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.value, &Usage::Consume);
+        let mut places = vec![&input.place];
+        while let Some(place) = places.pop() {
+            match place {
+                // The record that the variable held is lost when it is overwritten.
+                Expression::Identifier(identifier) if self.variables.contains_key(&identifier.name) => {
+                    self.check_dropped([identifier.name], identifier.span, "is overwritten here");
+                    self.variables.insert(identifier.name, Ownership::Owned);
+                }
+                Expression::Tuple(tuple) => places.extend(&tuple.elements),
+                // Assigning to a member of a record does not consume it.
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_block(&mut self, input: &'a Block) {
+        self.check_scope(&input.statements, None, Usage::Read, last_character(input.span));
+    }
+
+    fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
+        self.visit_expression(&input.condition, &Usage::Read);
+        let then = self.check_branch(|checker| checker.visit_block(&input.then));
+        let otherwise = self.check_branch(|checker| {
+            if let Some(otherwise) = &input.otherwise {
+                checker.visit_statement(otherwise);
+            }
+        });
+        self.join([then, otherwise]);
+    }
+
+    fn visit_declaration(&mut self, input: &'a DeclarationStatement) {
+        let place = Expression::Identifier(input.identifier);
+        self.declare(&place, Some(input.type_.clone()), Ownership::Unassigned);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.visit_expression(&input.value, &Usage::Consume);
+        let type_ = input.type_.clone().or_else(|| self.type_table.get(&input.value.id()));
+        self.declare(&input.place, type_, Ownership::Owned);
+    }
+
+    fn visit_expression_statement(&mut self, input: &'a ExpressionStatement) {
+        self.visit_expression(&input.expression, &Usage::Consume);
+        if let Some(type_) = self.type_table.get(&input.expression.id()) {
+            if self.contains_record(&type_) {
+                self.handler.emit_err(TypeCheckerError::record_value_dropped(type_, input.expression.span()));
+            }
+        }
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.visit_expression(&input.start, &Usage::Read);
+        self.visit_expression(&input.stop, &Usage::Read);
+
+        // The body may be executed more than once, so it may not consume the records declared outside of it.
+        let outer = self.variables.clone();
+        self.visit_block(&input.block);
+        for (name, ownership) in outer {
+            if ownership != Ownership::Owned {
+                continue;
+            }
+            if let Some(Ownership::Consumed(span) | Ownership::MaybeConsumed(span)) = self.variables.get(&name) {
+                self.handler.emit_err(TypeCheckerError::record_consumed_in_loop(name, *span));
+            }
+        }
+    }
+
+    fn visit_match(&mut self, input: &'a MatchStatement) {
+        // The type checker ensures that the arms are exhaustive, so exactly one of them is executed.
+        self.visit_expression(&input.value, &Usage::Read);
+        let arms: Vec<_> =
+            input.arms.iter().map(|arm| self.check_branch(|checker| checker.visit_block(&arm.block))).collect();
+        self.join(arms);
+    }
+
+    fn visit_return(&mut self, input: &'a ReturnStatement) {
+        self.visit_expression(&input.expression, &Usage::Consume);
+        if let Some(arguments) = &input.finalize_arguments {
+            arguments.iter().for_each(|argument| self.visit_expression(argument, &Usage::Consume));
+        }
+        let names: Vec<Symbol> = self.variables.keys().copied().collect();
+        self.check_dropped(names, input.span, "is dropped by this return");
+        self.returned = true;
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for RecordLinearityChecker<'a> {
+    fn visit_function(&mut self, input: &'a Function) {
+        self.check_body(&input.input, &input.block);
+        if let Some(finalize) = &input.finalize {
+            self.check_body(&finalize.input, &finalize.block);
+        }
+    }
+}
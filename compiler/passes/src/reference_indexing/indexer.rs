@@ -0,0 +1,364 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Definition, DefinitionKind, Location, Reference, ReferenceIndex};
+
+use leo_ast::*;
+use leo_span::{symbol::with_session_globals, Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+use std::marker::PhantomData;
+
+/// A compiler pass that records the definitions in a program and resolves each use of a name to its definition.
+/// Imported programs are not indexed, since they live in other files.
+pub struct ReferenceIndexer<'a> {
+    /// The index constructed by this compiler pass.
+    pub(crate) index: ReferenceIndex,
+    /// The spans of the names in `index.definitions`, in the same order.
+    pub(crate) definition_spans: Vec<Span>,
+    /// The spans of the uses in `index.references`, in the same order.
+    pub(crate) reference_spans: Vec<Span>,
+    /// The indices in `index.references` of the shorthand struct initializers, e.g. `x` in `Foo { x }`.
+    pub(crate) shorthand_references: IndexSet<usize>,
+    /// The definitions visible in every scope of the program, e.g. functions and structs.
+    globals: IndexMap<Symbol, usize>,
+    /// The local scopes enclosing the current node, innermost last.
+    scopes: Vec<IndexMap<Symbol, usize>>,
+    /// The lifetime of the AST being indexed.
+    _ast: PhantomData<&'a Ast>,
+}
+
+impl<'a> ReferenceIndexer<'a> {
+    pub fn new(fingerprint: String) -> Self {
+        Self {
+            index: ReferenceIndex { fingerprint, ..Default::default() },
+            definition_spans: Default::default(),
+            reference_spans: Default::default(),
+            shorthand_references: Default::default(),
+            globals: Default::default(),
+            scopes: Default::default(),
+            _ast: PhantomData,
+        }
+    }
+
+    /// Returns an indexer for `ast`, whose index is fingerprinted with the source file of the program.
+    pub fn for_ast(ast: &'a Ast) -> Self {
+        let fingerprint = ast
+            .as_repr()
+            .program_scopes
+            .values()
+            .next()
+            .and_then(|scope| with_session_globals(|s| s.source_map.span_to_location(scope.span)))
+            .map(|location| ReferenceIndex::fingerprint(&location.source_file.src))
+            .unwrap_or_default();
+        Self::new(fingerprint)
+    }
+
+    /// Records a definition of `identifier`, returning its index.
+    fn define(&mut self, identifier: &Identifier, kind: DefinitionKind) -> Option<usize> {
+        let location = Location::from_span(identifier.span)?;
+        self.index.definitions.push(Definition { name: identifier.name.to_string(), kind, location });
+        self.definition_spans.push(identifier.span);
+        Some(self.index.definitions.len() - 1)
+    }
+
+    /// Records a definition of `identifier` in the program scope.
+    fn define_global(&mut self, identifier: &Identifier, kind: DefinitionKind) {
+        if let Some(index) = self.define(identifier, kind) {
+            self.globals.insert(identifier.name, index);
+        }
+    }
+
+    /// Records a definition of `identifier` in the innermost local scope.
+    fn define_local(&mut self, identifier: &Identifier, kind: DefinitionKind) {
+        if let Some(index) = self.define(identifier, kind) {
+            if let Some(scope) = self.scopes.last_mut() {
+                scope.insert(identifier.name, index);
+            }
+        }
+    }
+
+    /// Records a reference to `identifier`, if it resolves to a known definition.
+    fn reference(&mut self, identifier: &Identifier) {
+        let definition = self
+            .scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(&identifier.name))
+            .or_else(|| self.globals.get(&identifier.name))
+            .copied();
+        if let (Some(definition), Some(location)) = (definition, Location::from_span(identifier.span)) {
+            self.index.references.push(Reference { definition, location });
+            self.reference_spans.push(identifier.span);
+        }
+    }
+
+    /// Records the references to structs and records in `type_`.
+    fn visit_type(&mut self, type_: &Type) {
+        match type_ {
+            Type::Identifier(identifier) => self.reference(identifier),
+            Type::Array(array_type) => self.visit_type(array_type.element_type()),
+            Type::Mapping(mapping_type) => {
+                self.visit_type(&mapping_type.key);
+                self.visit_type(&mapping_type.value);
+            }
+            Type::Tuple(tuple_type) => tuple_type.elements().iter().for_each(|type_| self.visit_type(type_)),
+            _ => {}
+        }
+    }
+
+    /// Records the definitions of the variables in the place of a definition statement.
+    fn define_place(&mut self, place: &Expression) {
+        match place {
+            Expression::Identifier(identifier) => self.define_local(identifier, DefinitionKind::Variable),
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| self.define_place(element)),
+            _ => {}
+        }
+    }
+
+    /// Visits the inputs and body of a function or finalize block in a new scope.
+    fn visit_function_body(&mut self, input: &'a [Input], output_type: &Type, block: &'a Block) {
+        self.scopes.push(Default::default());
+        for input in input {
+            self.visit_type(&input.type_());
+            self.define_local(&input.identifier(), DefinitionKind::Input);
+        }
+        self.visit_type(output_type);
+        self.visit_block(block);
+        self.scopes.pop();
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for ReferenceIndexer<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_block_expression(
+        &mut self,
+        input: &'a BlockExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        self.scopes.push(Default::default());
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.visit_expression(&input.value, additional);
+        self.scopes.pop();
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Calls to external programs resolve to definitions in other files.
+        if input.external.is_none() {
+            self.visit_expression(&input.function, additional);
+        }
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, additional);
+        self.visit_type(&input.type_);
+    }
+
+    // The expressions and statements the parser failed on, which are reported by the parser, refer to nothing.
+    fn visit_err(&mut self, _input: &'a ErrExpression, _additional: &Self::AdditionalInput) -> Self::Output {}
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.reference(&input.name);
+        for member in &input.members {
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                // A shorthand initializer `Foo { x }` refers to the variable `x`.
+                None => {
+                    let num_references = self.index.references.len();
+                    self.reference(&member.identifier);
+                    if self.index.references.len() > num_references {
+                        self.shorthand_references.insert(num_references);
+                    }
+                }
+            }
+        }
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.reference(input);
+    }
+}
+
+impl<'a> StatementVisitor<'a> for ReferenceIndexer<'a> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.place, &Default::default());
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_block(&mut self, input: &'a Block) {
+        self.scopes.push(Default::default());
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.scopes.pop();
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.visit_type(&input.type_);
+        self.visit_expression(&input.value, &Default::default());
+        match self.scopes.is_empty() {
+            true => self.define_global(&input.place, DefinitionKind::Const),
+            false => self.define_local(&input.place, DefinitionKind::Const),
+        }
+    }
+
+    fn visit_declaration(&mut self, input: &'a DeclarationStatement) {
+        self.visit_type(&input.type_);
+        self.define_local(&input.identifier, DefinitionKind::Variable);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        input.type_.iter().for_each(|type_| self.visit_type(type_));
+        self.visit_expression(&input.value, &Default::default());
+        self.define_place(&input.place);
+    }
+
+    fn visit_err_statement(&mut self, _input: &'a ErrStatement) {}
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.visit_type(&input.type_);
+        self.visit_expression(&input.start, &Default::default());
+        self.visit_expression(&input.stop, &Default::default());
+        self.scopes.push(Default::default());
+        self.define_local(&input.variable, DefinitionKind::Variable);
+        self.visit_block(&input.block);
+        self.scopes.pop();
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for ReferenceIndexer<'a> {
+    fn visit_import(&mut self, _input: &'a Program) {}
+
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        // Define the items of the program scope first, since they may be used before they are declared.
+        input
+            .interfaces
+            .iter()
+            .for_each(|(_, interface)| self.define_global(&interface.identifier, DefinitionKind::Interface));
+        input.structs.iter().for_each(|(_, struct_)| self.define_global(&struct_.identifier, DefinitionKind::Struct));
+        input.enums.iter().for_each(|(_, enum_)| self.define_global(&enum_.identifier, DefinitionKind::Enum));
+        input.mappings.iter().for_each(|(_, mapping)| self.define_global(&mapping.identifier, DefinitionKind::Mapping));
+        input.functions.iter().for_each(|(_, function)| self.define_global(&function.identifier, DefinitionKind::Function));
+
+        input.consts.iter().for_each(|(_, const_)| self.visit_const(const_));
+        input.interfaces.iter().for_each(|(_, interface)| self.visit_interface(interface));
+        input.structs.iter().for_each(|(_, struct_)| self.visit_struct(struct_));
+        input.mappings.iter().for_each(|(_, mapping)| self.visit_mapping(mapping));
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_interface(&mut self, input: &'a Interface) {
+        input.members.iter().for_each(|member| self.visit_type(&member.type_));
+    }
+
+    fn visit_struct(&mut self, input: &'a Struct) {
+        input.interfaces.iter().for_each(|interface| self.reference(interface));
+        input.members.iter().for_each(|member| self.visit_type(&member.type_));
+        input.methods.iter().for_each(|(_, method)| self.visit_function(method));
+    }
+
+    fn visit_mapping(&mut self, input: &'a Mapping) {
+        self.visit_type(&input.key_type);
+        self.visit_type(&input.value_type);
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.visit_function_body(&input.input, &input.output_type, &input.block);
+        if let Some(finalize) = &input.finalize {
+            self.visit_function_body(&finalize.input, &finalize.output_type, &finalize.block);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse_ast, Pass};
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const PROGRAM: &str = "program test.aleo {
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    transition main(a: u32, b: u32) -> Point {
+        let x: u32 = a + b;
+        let p: Point = Point { x, y: a };
+        return p;
+    }
+}
+";
+
+    fn index(source: &str) -> ReferenceIndex {
+        ReferenceIndexer::do_pass(&parse_ast(source))
+    }
+
+    #[test]
+    fn test_definitions_and_references() {
+        create_session_if_not_set_then(|_| {
+            let index = index(PROGRAM);
+            assert_eq!(index.fingerprint, ReferenceIndex::fingerprint(PROGRAM));
+
+            // The use of `a` in `a + b` resolves to the input `a`.
+            let definition = index.definition_at(8, 22).unwrap();
+            assert_eq!((definition.name.as_str(), definition.kind), ("a", DefinitionKind::Input));
+            assert_eq!(definition.location.line_start, 7);
+
+            // The struct `Point` is referenced by the output type, a type annotation, and an initializer.
+            assert_eq!(index.references_at(2, 12).len(), 3);
+
+            // The shorthand initializer `x` refers to the local variable `x`, not the struct member.
+            let definition = index.definition_at(9, 32).unwrap();
+            assert_eq!((definition.name.as_str(), definition.kind), ("x", DefinitionKind::Variable));
+            assert_eq!(index.references_at(8, 13), vec![index.references_at(9, 32)[0]]);
+        })
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        create_session_if_not_set_then(|_| {
+            let index = index(PROGRAM);
+            let json = serde_json::to_string(&index).unwrap();
+            assert_eq!(ReferenceIndex::from_json_string(&json).unwrap(), index);
+        })
+    }
+
+    #[test]
+    fn test_persisted_index() {
+        create_session_if_not_set_then(|_| {
+            let directory = std::env::temp_dir().join(format!("leo-reference-index-{}", std::process::id()));
+            std::fs::create_dir_all(&directory).unwrap();
+            let source_path = std::path::Path::new("src/main.leo");
+
+            let index = index(PROGRAM);
+            index.persist(&directory, source_path).unwrap();
+            assert!(directory.join("main.reference_index.json").exists());
+
+            // The persisted index is reused while the source is unchanged.
+            assert_eq!(ReferenceIndex::load(&directory, source_path, PROGRAM), Some(index));
+            // An index built from another version of the source is stale.
+            let edited = PROGRAM.replace("a + b", "b + a");
+            assert_eq!(ReferenceIndex::load(&directory, source_path, &edited), None);
+            // An index of another file is not found.
+            assert_eq!(ReferenceIndex::load(&directory, std::path::Path::new("src/other.leo"), PROGRAM), None);
+
+            std::fs::remove_dir_all(directory).unwrap();
+        })
+    }
+}
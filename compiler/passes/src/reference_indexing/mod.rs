@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod indexer;
+pub use indexer::*;
+
+use crate::{Pass, ReferenceIndex};
+
+use leo_ast::{Ast, ProgramVisitor};
+
+impl<'a> Pass for ReferenceIndexer<'a> {
+    type Input = &'a Ast;
+    type Output = ReferenceIndex;
+
+    /// Runs the compiler pass.
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut visitor = ReferenceIndexer::for_ast(ast);
+        visitor.visit_program(ast.as_repr());
+
+        visitor.index
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Renaming pass renames a variable, function, or struct, and every reference to it.
+//! It does not modify the AST, but returns the edits to the source files, keyed by the spans they replace.
+//! Since the spans of the source map are unique across files, the edits may span several files.
+//! This is the building block of the rename support of language servers.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! program test.aleo {
+//!     transition main(a: u8) -> u8 {
+//!         let b: u8 = a + 1u8;
+//!         return b;
+//!     }
+//! }
+//! ```
+//!
+//! Renaming `b` to `sum` returns edits replacing the name `b` in its definition and in the return statement.
+//! The rename is rejected if `sum` is not a valid identifier, or if it would clash with another definition.
+
+pub mod renamer;
+pub use renamer::*;
+
+use crate::{Pass, SymbolTable};
+
+use leo_ast::{Ast, SourceEdit};
+use leo_errors::Result;
+use leo_span::Span;
+
+impl<'a> Pass for Renamer<'a> {
+    type Input = (&'a Ast, &'a SymbolTable, Span, &'a str);
+    type Output = Result<Vec<SourceEdit>>;
+
+    fn do_pass((ast, symbol_table, span, new_name): Self::Input) -> Self::Output {
+        Renamer::new(ast, symbol_table).rename(span, new_name)
+    }
+}
+
+/// Returns the edits renaming the variable, function, or struct named or referenced at `span` in `ast` to `new_name`.
+/// The symbol table is the one of the type-checked AST.
+pub fn rename_symbol(ast: &Ast, symbol_table: &SymbolTable, span: Span, new_name: &str) -> Result<Vec<SourceEdit>> {
+    Renamer::do_pass((ast, symbol_table, span, new_name))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utilities::{parse_ast, program_scope, type_check},
+        TypeTable,
+    };
+
+    use leo_ast::SourceEdits;
+    use leo_span::{span::BytePos, symbol::create_session_if_not_set_then};
+
+    const PROGRAM: &str = "program test.aleo {
+    struct Point { x: u8, y: u8 }
+    transition main(a: u8) -> Point {
+        let x: u8 = a + 1u8;
+        for i: u8 in 0u8..2u8 {
+            let y: u8 = i;
+        }
+        return Point { x, y: a };
+    }
+}
+";
+
+    /// Renames the name at the first occurrence of `needle` in the program, returning the edited program.
+    fn rename(needle: &str, new_name: &str) -> Result<String> {
+        let ast = parse_ast(PROGRAM);
+        let symbol_table = type_check(&ast, &TypeTable::default());
+        // The program scope starts at the first byte of the program.
+        let start_pos = program_scope(&ast).span.lo;
+
+        let lo = start_pos.0 + PROGRAM.find(needle).unwrap() as u32;
+        let span = Span::new(BytePos(lo), BytePos(lo + 1));
+        let edits = rename_symbol(&ast, &symbol_table, span, new_name)?;
+        Ok(SourceEdits::new(edits).apply(PROGRAM, start_pos)?.text)
+    }
+
+    #[test]
+    fn test_rename_symbol() {
+        create_session_if_not_set_then(|_| {
+            // Renaming a variable at a use rewrites its definition, and expands the shorthand initializer.
+            let renamed = rename("x, y: a", "sum").unwrap();
+            assert!(renamed.contains("let sum: u8 = a + 1u8;"));
+            assert!(renamed.contains("return Point { x: sum, y: a };"));
+
+            // Renaming an input or a struct rewrites every reference to it.
+            let renamed = rename("a: u8)", "value").unwrap();
+            assert!(renamed.contains("let x: u8 = value + 1u8;") && renamed.contains("y: value }"));
+            let renamed = rename("Point {", "Vector").unwrap();
+            assert!(renamed.contains("struct Vector {") && renamed.contains("-> Vector {"));
+            assert!(renamed.contains("return Vector { x, y: a };"));
+
+            // The new name must be a valid identifier that does not clash with another definition.
+            assert!(rename("x: u8 =", "1x").is_err());
+            assert!(rename("x: u8 =", "return").is_err());
+            assert!(rename("x: u8 =", "y").is_err());
+            assert!(rename("x: u8 =", "main").is_err());
+            assert!(rename("i: u8", "a").is_err());
+            assert!(rename("struct", "foo").is_err());
+        })
+    }
+}
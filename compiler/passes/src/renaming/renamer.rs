@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Definition, ReferenceIndexer, SymbolTable};
+
+use leo_ast::{Ast, ProgramVisitor, SourceEdit};
+use leo_errors::{CompilerError, Result};
+use leo_parser::KEYWORD_TOKENS;
+use leo_span::{Span, Symbol};
+
+pub struct Renamer<'a> {
+    /// The definitions and references of the program.
+    indexer: ReferenceIndexer<'a>,
+    /// The symbol table of the type-checked program.
+    symbol_table: &'a SymbolTable,
+}
+
+impl<'a> Renamer<'a> {
+    pub fn new(ast: &'a Ast, symbol_table: &'a SymbolTable) -> Self {
+        let mut indexer = ReferenceIndexer::for_ast(ast);
+        indexer.visit_program(ast.as_repr());
+        Self { indexer, symbol_table }
+    }
+
+    /// Returns the index of the definition named or referenced at `span`, if one exists.
+    fn definition_at(&self, span: Span) -> Option<usize> {
+        let contains = |outer: &Span| outer.lo <= span.lo && span.hi <= outer.hi;
+        self.indexer
+            .reference_spans
+            .iter()
+            .position(contains)
+            .map(|index| self.indexer.index.references[index].definition)
+            .or_else(|| self.indexer.definition_spans.iter().position(contains))
+    }
+
+    /// Returns the edits renaming the definition named or referenced at `span`, and its references, to `new_name`.
+    pub fn rename(&self, span: Span, new_name: &str) -> Result<Vec<SourceEdit>> {
+        let definition = self.definition_at(span).ok_or_else(|| CompilerError::nothing_to_rename(span))?;
+        let Definition { name, .. } = &self.indexer.index.definitions[definition];
+        if !is_identifier(new_name) {
+            return Err(CompilerError::invalid_new_name(new_name, span).into());
+        }
+        if name == new_name {
+            return Ok(Vec::new());
+        }
+
+        let definition_span = self.indexer.definition_spans[definition];
+        if self.conflicts(Symbol::intern(name), definition_span, Symbol::intern(new_name)) {
+            return Err(CompilerError::rename_conflict(name, new_name, span).into());
+        }
+
+        let mut edits = vec![SourceEdit::replace(definition_span, new_name)];
+        for (index, reference) in self.indexer.index.references.iter().enumerate() {
+            if reference.definition != definition {
+                continue;
+            }
+            // A shorthand initializer `Foo { x }` becomes `Foo { x: y }`, since the member keeps its name.
+            let replacement = match self.indexer.shorthand_references.contains(&index) {
+                true => format!("{name}: {new_name}"),
+                false => new_name.to_string(),
+            };
+            edits.push(SourceEdit::replace(self.indexer.reference_spans[index], replacement));
+        }
+        Ok(edits)
+    }
+
+    /// Returns `true` if renaming the definition of `old` at `span` to `new` would clash with another definition.
+    /// Since Leo does not allow shadowing, a variable clashes with the definitions visible in or nested in its scope.
+    fn conflicts(&self, old: Symbol, span: Span, new: Symbol) -> bool {
+        let table = self.symbol_table;
+        let is_global = table.functions.contains_key(&new)
+            || table.structs.contains_key(&new)
+            || table.interfaces.contains_key(&new)
+            || table.enums.contains_key(&new)
+            || table.imports.contains_key(&new)
+            || table.modules.contains_key(&new);
+        // Items of the program scope are not variables of the symbol table, so they clash with every variable.
+        is_global || clashes_with_variable(table, old, span, new).unwrap_or_else(|| defines_variable(table, new))
+    }
+}
+
+/// Returns `true` if `name` is a valid identifier, that is, it matches `_?[a-zA-Z][a-zA-Z\d_]*` and is not a keyword.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.strip_prefix('_').unwrap_or(name).chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !name.starts_with("aleo1")
+        && !KEYWORD_TOKENS.iter().any(|keyword| keyword.to_string() == name)
+}
+
+/// Returns `true` if `table` or one of its nested scopes defines a variable named `name`.
+fn defines_variable(table: &SymbolTable, name: Symbol) -> bool {
+    table.variables.contains_key(&name) || table.scopes.iter().any(|scope| defines_variable(&scope.borrow(), name))
+}
+
+/// Returns `true` if a variable named `new` is visible in or nested in the scope defining the variable `old` at `span`.
+/// Returns `None` if no scope of `table` defines the variable.
+fn clashes_with_variable(table: &SymbolTable, old: Symbol, span: Span, new: Symbol) -> Option<bool> {
+    let defines_new = table.variables.contains_key(&new);
+    match table.variables.get(&old) {
+        // The symbol table records the span of the whole definition of some variables, e.g. of loop variables.
+        Some(variable) if variable.span.lo <= span.lo && span.hi <= variable.span.hi => {
+            Some(defines_new || table.scopes.iter().any(|scope| defines_variable(&scope.borrow(), new)))
+        }
+        _ => table
+            .scopes
+            .iter()
+            .find_map(|scope| clashes_with_variable(&scope.borrow(), old, span, new))
+            .map(|clashes| clashes || defines_new),
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Signedness Checking pass is a lint that reports the operands of arithmetic operations that are cast between a
+//! signed and an unsigned integer type, e.g. `(a as i64) + b` where `a: u64`. Such a cast type checks, but may
+//! silently change the value of the operand, e.g. when a large unsigned balance becomes a negative signed one.
+//! The lint is off by default. It is enabled by an annotation of the program scope: `@warn(mixed_signedness)`
+//! reports each such operand as a warning, and `@deny(mixed_signedness)` reports it as an error.
+//! Imported programs are not linted. The pass is run after type checking, so the type of every operand is known.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! @deny(mixed_signedness)
+//! program test.aleo {
+//!     transition main(a: u64, b: i64) -> i64 {
+//!         return (a as i64) + b;
+//!     }
+//! }
+//! ```
+//!
+//! The pass reports that an operand of `+` is cast from `u64` to `i64`.
+
+pub mod signedness_checker;
+pub use signedness_checker::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for SignednessChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a TypeTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, type_table): Self::Input) -> Self::Output {
+        let mut visitor = SignednessChecker::new(handler, type_table);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)
+    }
+}
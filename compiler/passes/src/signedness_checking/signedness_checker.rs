@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, TypeCheckerError, TypeCheckerWarning};
+use leo_span::sym;
+
+/// The level of the `mixed_signedness` lint in a program scope.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Level {
+    Allow,
+    Warn,
+    Deny,
+}
+
+pub struct SignednessChecker<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The types of the expressions, recorded by the type checker.
+    type_table: &'a TypeTable,
+    /// The level of the lint in the current program scope.
+    level: Level,
+}
+
+impl<'a> SignednessChecker<'a> {
+    /// Returns a new signedness checker.
+    pub fn new(handler: &'a Handler, type_table: &'a TypeTable) -> Self {
+        Self { handler, type_table, level: Level::Allow }
+    }
+
+    /// Reports `operand` of `operation` if it is a cast between a signed and an unsigned integer type.
+    fn check_operand(&self, operation: BinaryOperation, operand: &Expression) {
+        let Expression::Cast(cast) = operand else {
+            return;
+        };
+        let (Some(Type::Integer(from)), Type::Integer(to)) = (self.type_table.get(&cast.expression.id()), &cast.type_)
+        else {
+            return;
+        };
+        if from.is_signed() == to.is_signed() {
+            return;
+        }
+        match self.level {
+            Level::Allow => {}
+            Level::Warn => {
+                self.handler.emit_warning(TypeCheckerWarning::mixed_signedness(operation, from, to, cast.span).into())
+            }
+            Level::Deny => self.handler.emit_err(TypeCheckerError::mixed_signedness(operation, from, to, cast.span)),
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for SignednessChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        use BinaryOperation::*;
+        if matches!(
+            input.op,
+            Add | AddWrapped
+                | Sub
+                | SubWrapped
+                | Mul
+                | MulWrapped
+                | Div
+                | DivWrapped
+                | Rem
+                | RemWrapped
+                | Pow
+                | PowWrapped
+        ) {
+            self.check_operand(input.op, &input.left);
+            self.check_operand(input.op, &input.right);
+        }
+        self.visit_expression(&input.left, additional);
+        self.visit_expression(&input.right, additional);
+    }
+
+    fn visit_block_expression(
+        &mut self,
+        input: &'a BlockExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.visit_expression(&input.value, additional);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for SignednessChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for SignednessChecker<'a> {
+    fn visit_program(&mut self, input: &'a Program) {
+        // Imported programs are not linted, since they are not part of the program being compiled.
+        input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
+    }
+
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        // The last annotation that sets the level of the lint takes precedence.
+        self.level = Level::Allow;
+        for annotation in &input.annotations {
+            match (annotation.name(), annotation.lint()) {
+                (sym::warn, Some(sym::mixed_signedness)) => self.level = Level::Warn,
+                (sym::deny, Some(sym::mixed_signedness)) => self.level = Level::Deny,
+                _ => {}
+            }
+        }
+        if self.level != Level::Allow {
+            input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+        }
+    }
+}
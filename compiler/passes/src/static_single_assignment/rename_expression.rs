@@ -21,15 +21,22 @@ use leo_ast::{
     ArrayAccess,
     ArrayExpression,
     AssociatedFunction,
+    AssignStatement,
     BinaryExpression,
+    Block,
+    BlockExpression,
     CallExpression,
     CastExpression,
+    ConditionalStatement,
+    DeclarationStatement,
     Expression,
     ExpressionConsumer,
+    ExpressionVisitor,
     Identifier,
     Literal,
     MemberAccess,
     Statement,
+    StatementConsumer,
     Struct,
     StructExpression,
     StructVariableInitializer,
@@ -171,6 +178,15 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
         (Expression::Identifier(place), statements)
     }
 
+    /// Consumes a block expression, accumulating its statements and any statements generated for its value.
+    fn consume_block_expression(&mut self, input: BlockExpression) -> Self::Output {
+        let mut statements: Vec<Statement> =
+            input.statements.into_iter().flat_map(|statement| self.consume_statement(statement)).collect();
+        let (value, mut value_statements) = self.consume_expression(*input.value);
+        statements.append(&mut value_statements);
+        (value, statements)
+    }
+
     /// Consumes a call expression without visiting the function name, accumulating any statements that are generated.
     fn consume_call(&mut self, input: CallExpression) -> Self::Output {
         let mut statements = Vec::new();
@@ -193,6 +209,7 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
             // Consume the arguments.
             arguments,
             external: input.external,
+            network: input.network,
             span: input.span,
             id: input.id,
         }));
@@ -210,6 +227,7 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
         let (place, statement) = self.unique_simple_assign_statement(Expression::Cast(CastExpression {
             expression: Box::new(expression),
             type_: input.type_,
+            wrapped: input.wrapped,
             span: input.span,
             id: input.id,
         }));
@@ -273,15 +291,29 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
         for member in &struct_definition.members {
             // If the member is part of a record and it is `owner` then we have already added it.
             if !(struct_definition.is_record && matches!(member.identifier.name, sym::owner)) {
-                // Lookup and push the member of the init expression.
-                // Note that the `unwrap` is safe, since type checking guarantees that the member exists.
-                reordered_members.push(member_map.remove(&member.identifier.name).unwrap());
+                // Lookup and push the member of the init expression, or its default value if it is omitted.
+                // Note that type checking guarantees that a member is only omitted if it has a default value.
+                let initializer = match member_map.remove(&member.identifier.name) {
+                    Some(initializer) => initializer,
+                    None => {
+                        let (expression, mut stmts) = self.consume_default(member);
+                        statements.append(&mut stmts);
+                        StructVariableInitializer {
+                            identifier: member.identifier,
+                            expression: Some(expression),
+                            span: input.span,
+                            id: self.node_builder.next_id(),
+                        }
+                    }
+                };
+                reordered_members.push(initializer);
             }
         }
 
         // Construct and accumulate a new assignment statement for the struct expression.
         let (place, statement) = self.unique_simple_assign_statement(Expression::Struct(StructExpression {
             name: input.name,
+            const_arguments: input.const_arguments,
             span: input.span,
             members: reordered_members,
             id: input.id,
@@ -319,6 +351,12 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
 
     /// Consumes a ternary expression, accumulating any statements that are generated.
     fn consume_ternary(&mut self, input: TernaryExpression) -> Self::Output {
+        // The statements of a block expression in a branch must only be executed if the branch is taken.
+        // In this case, `c ? a : b` is lowered to `let $ternary: T; if c { $ternary = a; } else { $ternary = b; }`.
+        if contains_block_expression(&input.if_true) || contains_block_expression(&input.if_false) {
+            return self.consume_ternary_as_conditional(input);
+        }
+
         // Reconstruct the condition of the ternary expression.
         let (cond_expr, mut statements) = self.consume_expression(*input.condition);
         // Reconstruct the if-true case of the ternary expression.
@@ -390,3 +428,73 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
         (Expression::Unit(input), Default::default())
     }
 }
+
+impl StaticSingleAssigner<'_> {
+    /// Consumes a ternary expression as a conditional statement that assigns the value of the taken branch.
+    fn consume_ternary_as_conditional(&mut self, input: TernaryExpression) -> (Expression, Vec<Statement>) {
+        let type_ = match self.type_table.get(&input.id) {
+            Some(type_) => type_,
+            None => unreachable!("Type checking guarantees that all expressions have a type."),
+        };
+        let name = self.assigner.unique_symbol("$ternary", "$");
+        let place = || {
+            let id = self.node_builder.next_id();
+            self.type_table.insert(id, type_.clone());
+            Identifier { name, span: Default::default(), id }
+        };
+        let (declared, if_true_place, if_false_place, value) = (place(), place(), place(), place());
+
+        let mut statements = self.consume_declaration(DeclarationStatement {
+            identifier: declared,
+            type_: type_.clone(),
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        });
+        let branch = |place: Identifier, value: Expression| Block {
+            annotations: Vec::new(),
+            statements: vec![Statement::Assign(Box::new(AssignStatement {
+                place: Expression::Identifier(place),
+                value,
+                span: Default::default(),
+                id: self.node_builder.next_id(),
+            }))],
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        };
+        let then = branch(if_true_place, *input.if_true);
+        let otherwise = Some(Box::new(Statement::Block(branch(if_false_place, *input.if_false))));
+        statements.extend(self.consume_conditional(ConditionalStatement {
+            condition: *input.condition,
+            then,
+            otherwise,
+            span: input.span,
+            id: input.id,
+        }));
+
+        (self.consume_identifier(value).0, statements)
+    }
+}
+
+/// Returns `true` if `expression` contains a block expression.
+fn contains_block_expression(expression: &Expression) -> bool {
+    struct BlockExpressionFinder(bool);
+
+    impl<'a> ExpressionVisitor<'a> for BlockExpressionFinder {
+        type AdditionalInput = ();
+        type Output = ();
+
+        fn visit_block_expression(&mut self, _input: &'a BlockExpression, _additional: &()) {
+            self.0 = true;
+        }
+
+        fn visit_struct_init(&mut self, input: &'a StructExpression, _additional: &()) {
+            input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+                self.visit_expression(expression, &());
+            });
+        }
+    }
+
+    let mut finder = BlockExpressionFinder(false);
+    finder.visit_expression(expression, &());
+    finder.0
+}
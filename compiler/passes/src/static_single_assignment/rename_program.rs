@@ -66,6 +66,7 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
     fn consume_function(&mut self, function: Function) -> Self::Output {
         // Allocate a `RenameTable` for the function.
         self.push();
+        self.uninitialized.clear();
 
         // There is no need to reconstruct `function.inputs`.
         // However, for each input, we must add each symbol to the rename table.
@@ -74,8 +75,12 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
             self.rename_table.update(identifier.name, identifier.name, identifier.id);
         }
 
-        let block =
-            Block { span: function.block.span, id: function.block.id, statements: self.consume_block(function.block) };
+        let block = Block {
+            annotations: Vec::new(),
+            span: function.block.span,
+            id: function.block.id,
+            statements: self.consume_block(function.block),
+        };
 
         // Remove the `RenameTable` for the function.
         self.pop();
@@ -83,6 +88,7 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
         let finalize = function.finalize.map(|finalize| {
             // Allocate a `RenameTable` for the finalize block.
             self.push();
+            self.uninitialized.clear();
 
             // There is no need to reconstruct `finalize.inputs`.
             // However, for each input, we must add each symbol to the rename table.
@@ -92,6 +98,7 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
             }
 
             let block = Block {
+                annotations: Vec::new(),
                 span: finalize.block.span,
                 id: finalize.block.id,
                 statements: self.consume_block(finalize.block),
@@ -112,8 +119,10 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
         });
 
         Function {
+            doc: function.doc,
             annotations: function.annotations,
             variant: function.variant,
+            visibility: function.visibility,
             identifier: function.identifier,
             input: function.input,
             output: function.output,
@@ -132,7 +141,11 @@ impl ProgramScopeConsumer for StaticSingleAssigner<'_> {
     fn consume_program_scope(&mut self, input: ProgramScope) -> Self::Output {
         ProgramScope {
             program_id: input.program_id,
+            annotations: input.annotations,
+            modules: input.modules,
+            interfaces: input.interfaces,
             structs: input.structs.into_iter().map(|(i, s)| (i, self.consume_struct(s))).collect(),
+            enums: input.enums,
             mappings: input.mappings,
             functions: input.functions.into_iter().map(|(i, f)| (i, self.consume_function(f))).collect(),
             consts: input.consts,
@@ -151,6 +164,7 @@ impl ProgramConsumer for StaticSingleAssigner<'_> {
                 .into_iter()
                 .map(|(name, (import, span))| (name, (self.consume_program(import), span)))
                 .collect(),
+            stubs: input.stubs,
             program_scopes: input
                 .program_scopes
                 .into_iter()
@@ -25,8 +25,10 @@ use leo_ast::{
     Block,
     CallExpression,
     ConditionalStatement,
+    ConsoleFunction,
     ConsoleStatement,
     ConstDeclaration,
+    DeclarationStatement,
     DefinitionStatement,
     Expression,
     ExpressionConsumer,
@@ -92,13 +94,51 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         // Then assign a new unique name to the left-hand-side of the assignment.
         // Note that this order is necessary to ensure that the right-hand-side uses the correct name when consuming a complex assignment.
         self.is_lhs = true;
-        let place = match self.consume_expression(assign.place).0 {
-            Expression::Identifier(identifier) => identifier,
-            _ => panic!("Type checking guarantees that the left-hand-side of an assignment is an identifier."),
-        };
-        self.is_lhs = false;
+        match assign.place {
+            Expression::Identifier(identifier) => {
+                let place = match self.consume_identifier(identifier).0 {
+                    Expression::Identifier(identifier) => identifier,
+                    _ => unreachable!("`self.consume_identifier` will always return an `Identifier`."),
+                };
+                statements.push(self.simple_assign_statement(place, value));
+            }
+            Expression::Tuple(tuple) => {
+                // Get the type of `value`.
+                let tuple_type_ = match self.type_table.get(&value.id()) {
+                    Some(Type::Tuple(type_)) => type_,
+                    _ => unreachable!("Type checking guarantees that this expression is a tuple."),
+                };
+
+                // Rename each element, updating its type.
+                let elements: Vec<Expression> = tuple
+                    .elements
+                    .into_iter()
+                    .zip_eq(tuple_type_.elements())
+                    .map(|(element, type_)| {
+                        let element = self.consume_expression(element).0;
+                        self.type_table.insert(element.id(), type_.clone());
+                        element
+                    })
+                    .collect();
+
+                // Construct the lhs of the assignment.
+                let place = Expression::Tuple(TupleExpression {
+                    elements,
+                    span: Default::default(),
+                    id: self.node_builder.next_id(),
+                });
+                self.type_table.insert(place.id(), Type::Tuple(tuple_type_));
 
-        statements.push(self.simple_assign_statement(place, value));
+                statements.push(Statement::Assign(Box::new(AssignStatement {
+                    place,
+                    value,
+                    span: assign.span,
+                    id: assign.id,
+                })));
+            }
+            _ => unreachable!("Type checking guarantees that an assignment target is a variable or a tuple."),
+        }
+        self.is_lhs = false;
 
         statements
     }
@@ -124,6 +164,7 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
 
         // Consume the then-block.
         let then = Block {
+            annotations: Vec::new(),
             span: conditional.then.span,
             id: conditional.then.id,
             statements: self.consume_block(conditional.then),
@@ -138,11 +179,13 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         // Consume the otherwise-block and flatten its constituent statements into the current block.
         let otherwise = conditional.otherwise.map(|otherwise| Box::new(Statement::Block(match *otherwise {
             Statement::Block(block) => Block {
+                annotations: Vec::new(),
                 span: block.span,
                 id: block.id,
                 statements: self.consume_block(block),
             },
             Statement::Conditional(conditional) => Block {
+                annotations: Vec::new(),
                 span: conditional.span,
                 id: conditional.id,
                 statements: self.consume_conditional(conditional),
@@ -188,25 +231,38 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
                 let if_true = create_phi_argument(&if_table, **symbol);
                 let if_false = create_phi_argument(&else_table, **symbol);
 
-                // Create a new node ID for the the phi function.
-                let id = self.node_builder.next_id();
-                // Update the type of the node ID.
-                let type_ = match self.type_table.get(&if_true.id()) {
-                    Some(type_) => type_,
-                    None => unreachable!("Type checking guarantees that all expressions have a type."),
+                // A variable declared without a value that is assigned in only one branch takes the value from that branch.
+                // Definite assignment checking guarantees that it is not read on the path where it is unassigned.
+                let is_uninitialized = |argument: &Expression| {
+                    matches!(argument, Expression::Identifier(identifier) if self.uninitialized.contains(&identifier.name))
                 };
-                self.type_table.insert(id, type_);
+                let value = if is_uninitialized(&if_true) {
+                    *if_false
+                } else if is_uninitialized(&if_false) {
+                    *if_true
+                } else {
+                    // Create a new node ID for the the phi function.
+                    let id = self.node_builder.next_id();
+                    // Update the type of the node ID.
+                    let type_ = match self.type_table.get(&if_true.id()) {
+                        Some(type_) => type_,
+                        None => unreachable!("Type checking guarantees that all expressions have a type."),
+                    };
+                    self.type_table.insert(id, type_);
+
+                    // Construct a ternary expression for the phi function.
+                    let (value, stmts) = self.consume_ternary(TernaryExpression {
+                        condition: Box::new(condition.clone()),
+                        if_true,
+                        if_false,
+                        span: Default::default(),
+                        id,
+                    });
 
-                // Construct a ternary expression for the phi function.
-                let (value, stmts) = self.consume_ternary(TernaryExpression {
-                    condition: Box::new(condition.clone()),
-                    if_true,
-                    if_false,
-                    span: Default::default(),
-                    id,
-                });
+                    statements.extend(stmts);
 
-                statements.extend(stmts);
+                    value
+                };
 
                 // Get the ID for the new name of the variable.
                 let id = match self.rename_table.lookup_id(symbol) {
@@ -231,15 +287,27 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         statements
     }
 
-    /// Parsing guarantees that console statements are not present in the program.
-    fn consume_console(&mut self, _: ConsoleStatement) -> Self::Output {
-        unreachable!("Parsing guarantees that console statements are not present in the program.")
+    /// Removes a `console.log` statement, since logs are only rendered by the interpreter and are not compiled.
+    fn consume_console(&mut self, input: ConsoleStatement) -> Self::Output {
+        match input.function {
+            ConsoleFunction::Log(_) => Vec::new(),
+            _ => unreachable!("Parsing guarantees that `console.log` is the only console statement in the program."),
+        }
     }
 
     fn consume_const(&mut self, _: ConstDeclaration) -> Self::Output {
         unreachable!("Loop unrolling pass removes all constant declaration statements in the program.")
     }
 
+    /// Consumes the `DeclarationStatement`, producing no statements.
+    /// The variable is added to the rename table so that assignments to it in nested blocks produce phi functions.
+    fn consume_declaration(&mut self, declaration: DeclarationStatement) -> Self::Output {
+        let identifier = declaration.identifier;
+        self.rename_table.update(identifier.name, identifier.name, identifier.id);
+        self.uninitialized.insert(identifier.name);
+        Vec::new()
+    }
+
     /// Consumes the `DefinitionStatement` into an `AssignStatement`, renaming the left-hand-side as appropriate.
     fn consume_definition(&mut self, definition: DefinitionStatement) -> Self::Output {
         // First consume the right-hand-side of the definition.
@@ -345,6 +413,7 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
                         function: call.function,
                         arguments,
                         external: call.external,
+                        network: call.network,
                         span: call.span,
                         id: call.id,
                     }),
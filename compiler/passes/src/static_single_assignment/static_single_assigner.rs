@@ -16,7 +16,10 @@
 
 use crate::{Assigner, RenameTable, SymbolTable, TypeTable};
 
-use leo_ast::{Expression, Identifier, Node, NodeBuilder, Statement};
+use leo_ast::{Expression, ExpressionConsumer, Identifier, Member, Node, NodeBuilder, Statement};
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
 
 pub struct StaticSingleAssigner<'a> {
     /// A counter used to generate unique node IDs.
@@ -29,6 +32,8 @@ pub struct StaticSingleAssigner<'a> {
     pub(crate) rename_table: RenameTable,
     /// A flag to determine whether or not the traversal is on the left-hand side of a definition or an assignment.
     pub(crate) is_lhs: bool,
+    /// The variables in the current function that were declared without a value, e.g. `let x: u32;`.
+    pub(crate) uninitialized: IndexSet<Symbol>,
     /// A struct used to construct (unique) assignment statements.
     pub(crate) assigner: &'a Assigner,
 }
@@ -41,7 +46,15 @@ impl<'a> StaticSingleAssigner<'a> {
         type_table: &'a TypeTable,
         assigner: &'a Assigner,
     ) -> Self {
-        Self { node_builder, symbol_table, type_table, rename_table: RenameTable::new(None), is_lhs: false, assigner }
+        Self {
+            node_builder,
+            symbol_table,
+            type_table,
+            rename_table: RenameTable::new(None),
+            is_lhs: false,
+            uninitialized: Default::default(),
+            assigner,
+        }
     }
 
     /// Pushes a new scope, setting the current scope as the new scope's parent.
@@ -84,4 +97,16 @@ impl<'a> StaticSingleAssigner<'a> {
 
         (place, statement)
     }
+
+    /// Consumes a copy of the default value of a struct member, which is given a new ID with the same type.
+    pub(crate) fn consume_default(&mut self, member: &Member) -> (Expression, Vec<Statement>) {
+        // Note that the `unwrap` is safe, since type checking guarantees that an omitted member has a default.
+        let mut default = member.default.clone().unwrap();
+        let id = self.node_builder.next_id();
+        if let Some(type_) = self.type_table.get(&default.id()) {
+            self.type_table.insert(id, type_);
+        }
+        default.set_id(id);
+        self.consume_expression(default)
+    }
 }
@@ -15,23 +15,111 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::*;
-use leo_errors::emitter::Handler;
+use leo_errors::{emitter::Handler, Result};
+use leo_span::Symbol;
 
-use crate::{SymbolTable, VariableSymbol, VariableType};
+use crate::{SymbolTable, SymbolTableCache, VariableSymbol, VariableType};
 
 /// A compiler pass during which the `SymbolTable` is created.
-/// Note that this pass only creates the initial entries for functions, structs, and records.
+/// Note that this pass only creates the initial entries for functions, interfaces, structs, records, and enums.
 /// The table is populated further during the type checking pass.
 pub struct SymbolTableCreator<'a> {
     /// The `SymbolTable` constructed by this compiler pass.
     pub(crate) symbol_table: SymbolTable,
     /// The error handler.
     handler: &'a Handler,
+    /// The trace recording the items injected by imported programs.
+    import_trace: &'a ImportTrace,
+    /// The name of the import being visited, if any.
+    import: Option<Symbol>,
+    /// The cache of the symbol table of the imports, if it is kept between builds.
+    cache: Option<&'a SymbolTableCache>,
 }
 
 impl<'a> SymbolTableCreator<'a> {
-    pub fn new(handler: &'a Handler) -> Self {
-        Self { symbol_table: Default::default(), handler }
+    pub fn new(handler: &'a Handler, import_trace: &'a ImportTrace) -> Self {
+        Self {
+            symbol_table: Default::default(),
+            handler,
+            import_trace,
+            import: Default::default(),
+            cache: None,
+        }
+    }
+
+    /// Creates the symbol table of `ast`.
+    pub fn run(mut self, ast: &'a Ast) -> Result<SymbolTable> {
+        // The parser recovers from some errors, which are reported by the later passes.
+        let err_count = self.handler.err_count();
+        self.visit_program(ast.as_repr());
+        if self.handler.err_count() > err_count {
+            self.handler.last_err().map_err(|e| *e)?;
+        }
+
+        Ok(self.symbol_table)
+    }
+
+    /// Reuses the symbol table of the imports in `cache` if they are unchanged since it was built, and caches it
+    /// otherwise.
+    pub fn with_cache(mut self, cache: &'a SymbolTableCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Inserts the items of the imports of the main program `input`, reusing those in the cache if possible.
+    fn visit_imports(&mut self, input: &'a Program) {
+        let Some(cache) = self.cache else {
+            return self.visit_imports_uncached(input);
+        };
+
+        let key = SymbolTableCache::key(input);
+        if let Some((symbol_table, steps)) = cache.lookup(&key) {
+            self.symbol_table = symbol_table;
+            steps.into_iter().for_each(|step| self.import_trace.record(|| step));
+            return;
+        }
+
+        let (err_count, step_count) = (self.handler.err_count(), self.import_trace.steps().len());
+        self.visit_imports_uncached(input);
+        if self.handler.err_count() == err_count {
+            let steps = self.import_trace.steps().split_off(step_count);
+            cache.update(key, self.symbol_table.clone(), steps);
+        }
+    }
+
+    /// Inserts the items of the imports of `input`.
+    fn visit_imports_uncached(&mut self, input: &'a Program) {
+        for (name, (import, _)) in input.imports.iter() {
+            let outer = self.import.replace(*name);
+            self.visit_import(import);
+            self.import = outer;
+        }
+    }
+
+    /// Emits the error of a failed insertion, and records the injection of an imported item in the import trace.
+    /// An item that clashes with an imported item is recorded as a rejected attempt to shadow it.
+    fn check_insertion(&mut self, kind: &str, name: Symbol, result: Result<()>) {
+        match (result, self.import) {
+            (Ok(()), Some(import)) => {
+                self.symbol_table.imports.insert(name, import);
+                self.import_trace.record(|| ImportStep::SymbolInjected {
+                    import: import.to_string(),
+                    kind: kind.to_string(),
+                    name: name.to_string(),
+                });
+            }
+            (Ok(()), None) => {}
+            (Err(err), _) => {
+                if let Some(import) = self.symbol_table.imports.get(&name) {
+                    self.import_trace.record(|| ImportStep::ShadowingRejected {
+                        import: import.to_string(),
+                        kind: kind.to_string(),
+                        name: name.to_string(),
+                    });
+                }
+                self.handler.emit_err(err);
+            }
+        }
     }
 }
 
@@ -43,33 +131,145 @@ impl<'a> ExpressionVisitor<'a> for SymbolTableCreator<'a> {
 impl<'a> StatementVisitor<'a> for SymbolTableCreator<'a> {}
 
 impl<'a> ProgramVisitor<'a> for SymbolTableCreator<'a> {
+    fn visit_program(&mut self, input: &'a Program) {
+        // The imports are inserted first, so that their symbol table can be reused by a later build of the program.
+        match self.import {
+            None => self.visit_imports(input),
+            Some(_) => self.visit_imports_uncached(input),
+        }
+
+        // The structs, records, and functions of a deployed program are inserted like those of an imported program.
+        for (name, (stub, _)) in input.stubs.iter() {
+            let outer = self.import.replace(*name);
+            stub.structs.iter().for_each(|(_, struct_)| self.visit_struct(struct_));
+            stub.functions.iter().for_each(|(_, function)| self.visit_function(function));
+            self.import = outer;
+        }
+
+        input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
+    }
+
     fn visit_import(&mut self, input: &'a Program) {
         self.visit_program(input)
     }
 
-    fn visit_struct(&mut self, input: &'a Struct) {
-        if let Err(err) = self.symbol_table.insert_struct(input.name(), input) {
+    fn visit_module(&mut self, input: &'a Module) {
+        if let Err(err) = self.symbol_table.insert_module(input.name.name, input) {
             self.handler.emit_err(err);
         }
     }
 
+    fn visit_interface(&mut self, input: &'a Interface) {
+        let result = self.symbol_table.insert_interface(input.name(), input);
+        self.check_insertion("interface", input.name(), result);
+    }
+
+    fn visit_struct(&mut self, input: &'a Struct) {
+        let result = self.symbol_table.insert_struct(input.name(), input);
+        self.check_insertion(if input.is_record { "record" } else { "struct" }, input.name(), result);
+
+        // Add the methods of the struct to the symbol table, under the names of the functions they are lowered to.
+        for (name, method) in input.methods.iter() {
+            let name = Struct::method_name(input.name(), *name);
+            let result = self.symbol_table.insert_fn(name, method);
+            self.check_insertion("method", name, result);
+        }
+    }
+
+    fn visit_enum(&mut self, input: &'a Enum) {
+        let result = self.symbol_table.insert_enum(input.name(), input);
+        self.check_insertion("enum", input.name(), result);
+    }
+
     fn visit_mapping(&mut self, input: &'a Mapping) {
         // Add the variable associated with the mapping to the symbol table.
-        if let Err(err) = self.symbol_table.insert_variable(input.identifier.name, VariableSymbol {
+        let result = self.symbol_table.insert_variable(input.identifier.name, VariableSymbol {
             type_: Type::Mapping(MappingType {
                 key: Box::new(input.key_type.clone()),
                 value: Box::new(input.value_type.clone()),
             }),
             span: input.span,
             declaration: VariableType::Mut,
-        }) {
-            self.handler.emit_err(err);
-        }
+            value: None,
+        });
+        self.check_insertion("mapping", input.identifier.name, result);
     }
 
     fn visit_function(&mut self, input: &'a Function) {
-        if let Err(err) = self.symbol_table.insert_fn(input.name(), input) {
-            self.handler.emit_err(err);
-        }
+        let result = self.symbol_table.insert_fn(input.name(), input);
+        self.check_insertion("function", input.name(), result);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse, Pass};
+
+    use leo_span::{symbol::create_session_if_not_set_then, Span};
+
+    #[test]
+    fn test_trace_imported_items() {
+        create_session_if_not_set_then(|_| {
+            let node_builder = NodeBuilder::default();
+            let import =
+                parse("program foo.aleo { struct Point { x: u8 } function helper() -> u8 { return 1u8; } }", &node_builder);
+            let mut program = parse("program test.aleo { struct Point { x: u8 } }", &node_builder);
+            program.imports.insert(Symbol::intern("foo"), (import, Span::dummy()));
+
+            let import_trace = ImportTrace::new(true);
+            let result = SymbolTableCreator::do_pass((&Ast::new(program), &Handler::default(), &import_trace));
+            assert!(result.is_err());
+
+            let steps = import_trace
+                .steps()
+                .into_iter()
+                .map(|step| match step {
+                    ImportStep::SymbolInjected { import, kind, name } => {
+                        format!("injected {kind} {name} from {import}")
+                    }
+                    ImportStep::ShadowingRejected { import, kind, name } => {
+                        format!("rejected {kind} {name} from {import}")
+                    }
+                    step => panic!("unexpected step {step:?}"),
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(steps, [
+                "injected struct Point from foo",
+                "injected function helper from foo",
+                "rejected struct Point from foo"
+            ]);
+        })
+    }
+
+    #[test]
+    fn test_reuse_symbol_table_of_unchanged_imports() {
+        create_session_if_not_set_then(|_| {
+            const IMPORT: &str = "program foo.aleo { struct Point { x: u8 } function helper() -> u8 { return 1u8; } }";
+            let node_builder = NodeBuilder::default();
+            let import = parse(IMPORT, &node_builder);
+            let build = |import: &Program, cache: &SymbolTableCache, import_trace: &ImportTrace| {
+                let mut program = parse("program test.aleo { function main() -> u8 { return 1u8; } }", &node_builder);
+                program.imports.insert(Symbol::intern("foo"), (import.clone(), Span::dummy()));
+                let handler = Handler::default();
+                SymbolTableCreator::new(&handler, import_trace).with_cache(cache).run(&Ast::new(program)).unwrap()
+            };
+
+            let cache = SymbolTableCache::default();
+            let first = build(&import, &cache, &ImportTrace::new(true));
+            assert_eq!(cache.hits(), 0);
+
+            // The symbol table of an import reused from the previous build is reused, along with its trace.
+            let import_trace = ImportTrace::new(true);
+            let second = build(&import, &cache, &import_trace);
+            assert_eq!(cache.hits(), 1);
+            assert_eq!(second.functions.keys().collect::<Vec<_>>(), first.functions.keys().collect::<Vec<_>>());
+            assert_eq!(second.imports, first.imports);
+            assert_eq!(import_trace.steps().len(), 2);
+
+            // An import that is parsed again is inserted again.
+            build(&parse(IMPORT, &node_builder), &cache, &ImportTrace::default());
+            assert_eq!(cache.hits(), 1);
+        })
     }
 }
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::SymbolTable;
+
+use leo_ast::{ImportStep, Program};
+use leo_span::{Span, Symbol};
+
+use std::cell::{Cell, RefCell};
+
+/// The symbol table of the imports of a program, along with the imports it was built from.
+#[derive(Clone, Debug)]
+struct CachedImports {
+    /// The name of each import and the spans of its program scopes.
+    /// An import reused from an earlier build keeps its spans, while one that is parsed again is given new ones.
+    key: Vec<(Symbol, Vec<Span>)>,
+    /// The symbol table holding the items of the imports.
+    symbol_table: SymbolTable,
+    /// The steps recorded in the import trace while the items of the imports were inserted.
+    steps: Vec<ImportStep>,
+}
+
+/// A cache of the symbol table of the imports of a program, which is kept between builds, e.g. in watch mode.
+/// A build whose imports are all reused from the previous build starts from a copy of the cached symbol table,
+/// rather than inserting the items of every import again.
+#[derive(Clone, Debug, Default)]
+pub struct SymbolTableCache {
+    /// The symbol table of the imports of the last build whose imports were inserted without errors.
+    /// `RefCell` is used here, since the cache is shared by the builds.
+    imports: RefCell<Option<CachedImports>>,
+    /// The number of builds that reused the cached symbol table.
+    hits: Cell<usize>,
+}
+
+impl SymbolTableCache {
+    /// Returns the number of builds that reused the cached symbol table.
+    pub fn hits(&self) -> usize {
+        self.hits.get()
+    }
+
+    /// Returns the key identifying the imports of `program`.
+    pub(crate) fn key(program: &Program) -> Vec<(Symbol, Vec<Span>)> {
+        program
+            .imports
+            .iter()
+            .map(|(name, (import, _))| (*name, import.program_scopes.values().map(|scope| scope.span).collect()))
+            .collect()
+    }
+
+    /// Returns the cached symbol table and import trace steps of the imports identified by `key`, if any.
+    pub(crate) fn lookup(&self, key: &[(Symbol, Vec<Span>)]) -> Option<(SymbolTable, Vec<ImportStep>)> {
+        let imports = self.imports.borrow();
+        let cached = imports.as_ref().filter(|cached| cached.key == key)?;
+        self.hits.set(self.hits.get() + 1);
+        Some((cached.symbol_table.clone(), cached.steps.clone()))
+    }
+
+    /// Caches the symbol table and import trace steps of the imports identified by `key`.
+    pub(crate) fn update(&self, key: Vec<(Symbol, Vec<Span>)>, symbol_table: SymbolTable, steps: Vec<ImportStep>) {
+        self.imports.replace(Some(CachedImports { key, symbol_table, steps }));
+    }
+}
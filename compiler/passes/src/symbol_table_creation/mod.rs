@@ -14,24 +14,23 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod import_cache;
+pub use import_cache::*;
+
 pub mod creator;
 pub use creator::*;
 
 use crate::{Pass, SymbolTable};
 
-use leo_ast::{Ast, ProgramVisitor};
+use leo_ast::{Ast, ImportTrace};
 use leo_errors::{emitter::Handler, Result};
 
 impl<'a> Pass for SymbolTableCreator<'a> {
-    type Input = (&'a Ast, &'a Handler);
+    type Input = (&'a Ast, &'a Handler, &'a ImportTrace);
     type Output = Result<SymbolTable>;
 
     /// Runs the compiler pass.
-    fn do_pass((ast, handler): Self::Input) -> Self::Output {
-        let mut visitor = SymbolTableCreator::new(handler);
-        visitor.visit_program(ast.as_repr());
-        handler.last_err().map_err(|e| *e)?;
-
-        Ok(visitor.symbol_table)
+    fn do_pass((ast, handler, import_trace): Self::Input) -> Self::Output {
+        SymbolTableCreator::new(handler, import_trace).run(ast)
     }
 }
@@ -0,0 +1,39 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Target Checking pass verifies that every operation accepted by the type checker
+//! can be lowered to an instruction supported by the target, i.e. Aleo instructions.
+//! It is run after type checking so that unsupported operations are reported as diagnostics
+//! instead of surfacing as failures during code generation.
+
+pub mod target_checker;
+pub use target_checker::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for TargetChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a TypeTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, type_table): Self::Input) -> Self::Output {
+        let mut visitor = TargetChecker::new(handler, type_table);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{binary_opcode, unary_opcode, OperandTypes, TypeTable};
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, CompilerError};
+use leo_span::Span;
+
+use std::fmt::Display;
+
+pub struct TargetChecker<'a> {
+    /// An error handler used for any errors found during target checking.
+    handler: &'a Handler,
+    /// The types of the expressions in the program, as computed by the type checker.
+    type_table: &'a TypeTable,
+}
+
+impl<'a> TargetChecker<'a> {
+    /// Returns a new target checker given an error handler and the type table.
+    pub fn new(handler: &'a Handler, type_table: &'a TypeTable) -> Self {
+        Self { handler, type_table }
+    }
+
+    /// Emits an error if the type of `operand` is known and not supported by the instruction.
+    fn check_operand(&self, operation: impl Display, operand_types: OperandTypes, operand: &Expression, span: Span) {
+        if let Some(type_) = self.type_table.get(&operand.id()) {
+            if !operand_types.contains(&type_) {
+                self.handler.emit_err(CompilerError::operation_not_supported_on_target(operation, type_, span));
+            }
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for TargetChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_block_expression(
+        &mut self,
+        input: &'a BlockExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.visit_expression(&input.value, additional)
+    }
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.left, additional);
+        self.visit_expression(&input.right, additional);
+
+        let (opcode, left_types, right_types) = binary_opcode(input.op);
+        self.check_operand(opcode, left_types, &input.left, input.span);
+        self.check_operand(opcode, right_types, &input.right, input.span);
+    }
+
+    fn visit_unary(&mut self, input: &'a UnaryExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.receiver, additional);
+
+        let (opcode, suffix, operand_types) = unary_opcode(input.op);
+        self.check_operand(format!("{opcode}{suffix}"), operand_types, &input.receiver, input.span);
+    }
+}
+
+impl<'a> StatementVisitor<'a> for TargetChecker<'a> {}
+
+impl<'a> ProgramVisitor<'a> for TargetChecker<'a> {}
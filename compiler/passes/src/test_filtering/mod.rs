@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Test Filtering pass removes the functions annotated with `@test` from a program.
+//! It runs directly after parsing when building a program for production, so that test functions are never type
+//! checked or compiled into the generated program. A function that is not a test may therefore not call one.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! program test.aleo {
+//!     function add_one(a: u8) -> u8 {
+//!         return a + 1u8;
+//!     }
+//!
+//!     transition main(a: u8) -> u8 {
+//!         return add_one(a);
+//!     }
+//!
+//!     @test
+//!     transition test_add_one() {
+//!         assert_eq(add_one(1u8), 2u8);
+//!     }
+//! }
+//! ```
+//!
+//! The test filtering pass produces the following code.
+//! ```leo
+//! program test.aleo {
+//!     function add_one(a: u8) -> u8 {
+//!         return a + 1u8;
+//!     }
+//!
+//!     transition main(a: u8) -> u8 {
+//!         return add_one(a);
+//!     }
+//! }
+//! ```
+
+pub mod test_filter;
+pub use test_filter::*;
+
+use crate::Pass;
+
+use leo_ast::Ast;
+
+impl Pass for TestFilter {
+    type Input = Ast;
+    type Output = Ast;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        let mut program = ast.into_repr();
+        program.program_scopes.values_mut().for_each(TestFilter::filter_program_scope);
+
+        Ast::new(program)
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Helpers shared by the unit tests of the passes, which parse and check programs given as strings.
+
+use crate::{Pass, SymbolTable, SymbolTableCreator, TypeChecker, TypeTable};
+
+use leo_ast::{Ast, ImportTrace, NodeBuilder, Program, ProgramScope};
+use leo_errors::emitter::Handler;
+use leo_span::{
+    source_map::{FileName, SourceFile},
+    symbol::with_session_globals,
+};
+
+use std::sync::Arc;
+
+/// Adds `source` to the source map of the session, as a file named `test`.
+pub(crate) fn source_file(source: &str) -> Arc<SourceFile> {
+    with_session_globals(|s| s.source_map.new_source(source, FileName::Custom("test".into())))
+}
+
+/// Parses the program `source` with the node builder `node_builder`, panicking if it does not parse.
+pub(crate) fn parse(source: &str, node_builder: &NodeBuilder) -> Program {
+    let source_file = source_file(source);
+    leo_parser::parse(&Handler::default(), node_builder, &source_file.src, source_file.start_pos).unwrap()
+}
+
+/// Parses the program `source` into an AST, panicking if it does not parse.
+pub(crate) fn parse_ast(source: &str) -> Ast {
+    Ast::new(parse(source, &NodeBuilder::default()))
+}
+
+/// Returns the program scope of `ast`, which must have exactly one.
+pub(crate) fn program_scope(ast: &Ast) -> &ProgramScope {
+    assert_eq!(ast.ast.program_scopes.len(), 1, "expected a single program scope");
+    ast.ast.program_scopes.values().next().unwrap()
+}
+
+/// Creates the symbol table of `ast` and type checks it, panicking on errors.
+/// Returns the symbol table, with the scopes of the functions, and fills in `type_table`.
+pub(crate) fn type_check(ast: &Ast, type_table: &TypeTable) -> SymbolTable {
+    let handler = Handler::default();
+    let symbol_table = SymbolTableCreator::do_pass((ast, &handler, &ImportTrace::default())).unwrap();
+    TypeChecker::do_pass((ast, &handler, symbol_table, type_table)).unwrap().0
+}
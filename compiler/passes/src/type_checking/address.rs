@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The validation of address literals, which are the bech32m encoding of an address, e.g. `aleo1...`.
+
+use snarkvm_console::{account::Address, network::Testnet3};
+use std::{fmt, iter, str::FromStr};
+
+/// The characters of the bech32 encoding, in the order of the values that they encode.
+const CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+/// The human-readable prefix of an address, which is followed by the separator `1`.
+const PREFIX: &str = "aleo";
+/// The value that the checksum of a bech32m encoding is computed against.
+const BECH32M_CONSTANT: u32 = 0x2bc8_30a3;
+/// The number of characters of an address literal: the prefix and separator, and 58 characters of data and checksum.
+pub const ADDRESS_LENGTH: usize = 63;
+
+/// The reason that an address literal is malformed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressError {
+    /// The character at the byte offset is not a bech32 character.
+    Character(usize, char),
+    /// The address has this many characters, rather than [`ADDRESS_LENGTH`].
+    Length(usize),
+    /// The checksum does not match the data. If replacing a single character would make it match,
+    /// the byte offset of the character and its replacement.
+    Checksum(Option<(usize, char)>),
+    /// The encoding is well formed, but its data is not the x-coordinate of a point of an address.
+    Point,
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Character(offset, character) => {
+                write!(f, "invalid character `{character}` at position {}", offset + 1)
+            }
+            Self::Length(length) => write!(f, "it has {length} characters, but an address has {ADDRESS_LENGTH}"),
+            Self::Checksum(_) => write!(f, "its checksum does not match"),
+            Self::Point => write!(f, "it does not encode a point on the curve"),
+        }
+    }
+}
+
+/// Checks that `address`, which starts with `aleo1`, is a bech32m encoded address.
+/// The encoding is checked first, so that a typo is located, and then the address is decoded as in snarkVM.
+pub fn validate_address(address: &str) -> Result<(), AddressError> {
+    let offset = PREFIX.len() + 1;
+    let mut values = Vec::with_capacity(address.len());
+    for (i, character) in address[offset..].char_indices() {
+        match CHARSET.find(character) {
+            Some(value) => values.push(value as u8),
+            None => return Err(AddressError::Character(offset + i, character)),
+        }
+    }
+    if address.len() != ADDRESS_LENGTH {
+        return Err(AddressError::Length(address.len()));
+    }
+    if checksum_matches(&values) {
+        return Address::<Testnet3>::from_str(address).map(|_| ()).map_err(|_| AddressError::Point);
+    }
+
+    // Look for the single character that would make the checksum match if it were replaced.
+    // A typo is only reported if there is exactly one such character, since otherwise it is ambiguous.
+    let mut typos = (0..values.len()).flat_map(|i| (0..32u8).map(move |value| (i, value))).filter(|&(i, value)| {
+        let mut replaced = values.clone();
+        replaced[i] = value;
+        value != values[i] && checksum_matches(&replaced)
+    });
+    let typo = match (typos.next(), typos.next()) {
+        (Some((i, value)), None) => Some((offset + i, char::from(CHARSET.as_bytes()[value as usize]))),
+        _ => None,
+    };
+    Err(AddressError::Checksum(typo))
+}
+
+/// Returns `true` if the checksum at the end of `values`, the data of an address, matches the rest of it.
+fn checksum_matches(values: &[u8]) -> bool {
+    let prefix = PREFIX.bytes().map(|byte| byte >> 5).chain(iter::once(0)).chain(PREFIX.bytes().map(|byte| byte & 31));
+    polymod(prefix.chain(values.iter().copied())) == BECH32M_CONSTANT
+}
+
+/// Computes the BCH checksum of the bech32 encoding over `values`.
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATORS: [u32; 5] = [0x3b6a_57b2, 0x2650_8e6d, 0x1ea1_19fa, 0x3d42_33dd, 0x2a14_62b3];
+    values.fold(1, |checksum, value| {
+        let top = checksum >> 25;
+        let checksum = ((checksum & 0x1ff_ffff) << 5) ^ u32::from(value);
+        GENERATORS.iter().enumerate().filter(|(i, _)| (top >> i) & 1 == 1).fold(checksum, |checksum, (_, g)| checksum ^ g)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ADDRESS: &str = "aleo1rhgdu77hgyqd3xjj8ucu3jj9r2krwz6mnzyd80gncr5fxcwlh5rsvzp9px";
+
+    #[test]
+    fn test_valid_address() {
+        assert_eq!(validate_address(ADDRESS), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_address() {
+        // `b` is not a bech32 character.
+        assert_eq!(validate_address(&ADDRESS.replacen('r', "b", 1)), Err(AddressError::Character(5, 'b')));
+        assert_eq!(validate_address(&ADDRESS[..62]), Err(AddressError::Length(62)));
+        // A single mistyped character is located.
+        let typo = format!("{}q{}", &ADDRESS[..10], &ADDRESS[11..]);
+        assert_eq!(validate_address(&typo), Err(AddressError::Checksum(Some((10, '7')))));
+        // Two swapped characters do not match the checksum either.
+        let swapped = format!("{}{}{}{}", &ADDRESS[..12], &ADDRESS[13..14], &ADDRESS[12..13], &ADDRESS[14..]);
+        assert!(matches!(validate_address(&swapped), Err(AddressError::Checksum(_))));
+    }
+
+    #[test]
+    fn test_invalid_point() {
+        // The bech32m encoding of the x-coordinate 1, which is not the x-coordinate of a point on the curve.
+        let address = "aleo1qyqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqhezjc8";
+        assert_eq!(validate_address(address), Err(AddressError::Point));
+    }
+}
@@ -0,0 +1,271 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeChecker;
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::{IndexMap, IndexSet};
+use std::{
+    cell::{Cell, RefCell},
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+/// A function that was type checked without errors or warnings.
+#[derive(Clone, Debug)]
+struct CachedFunction {
+    /// The hash of the function and of the signatures of the items it refers to.
+    key: u64,
+    /// The functions it calls, which are added to the call graph when the function is skipped.
+    callees: Vec<Symbol>,
+}
+
+/// A cache of the functions that type checked without errors or warnings.
+/// A function is skipped by the type checker if neither its body nor the signatures of the items it refers to have
+/// changed since it was last checked, which saves re-checking large programs that are rebuilt on every edit.
+#[derive(Debug, Default)]
+pub struct TypeCheckCache {
+    /// The cached functions, by name.
+    /// `RefCell` is used here to avoid `&mut` all over the type checker.
+    functions: RefCell<IndexMap<Symbol, CachedFunction>>,
+    /// The number of functions that were skipped.
+    hits: Cell<usize>,
+    /// The number of functions that were checked.
+    misses: Cell<usize>,
+}
+
+impl TypeCheckCache {
+    /// Returns the number of functions that were skipped, since they were unchanged.
+    pub fn hits(&self) -> usize {
+        self.hits.get()
+    }
+
+    /// Returns the number of functions that were checked, since they were not cached or had changed.
+    pub fn misses(&self) -> usize {
+        self.misses.get()
+    }
+
+    /// Returns the number of cached functions.
+    pub fn len(&self) -> usize {
+        self.functions.borrow().len()
+    }
+
+    /// Returns `true` if no functions are cached.
+    pub fn is_empty(&self) -> bool {
+        self.functions.borrow().is_empty()
+    }
+
+    /// Removes every cached function, so that the next build checks the whole program.
+    pub fn clear(&self) {
+        self.functions.borrow_mut().clear();
+    }
+
+    /// Returns the callees of the function `name`, if it is cached under `key`.
+    pub(crate) fn lookup(&self, name: Symbol, key: u64) -> Option<Vec<Symbol>> {
+        let callees = self.functions.borrow().get(&name).filter(|cached| cached.key == key).map(|f| f.callees.clone());
+        match callees {
+            Some(_) => self.hits.set(self.hits.get() + 1),
+            None => self.misses.set(self.misses.get() + 1),
+        }
+        callees
+    }
+
+    /// Caches the function `name` under `key`, or evicts it if it did not check cleanly.
+    pub(crate) fn update(&self, name: Symbol, key: u64, callees: Option<Vec<Symbol>>) {
+        match callees {
+            Some(callees) => self.functions.borrow_mut().insert(name, CachedFunction { key, callees }),
+            None => self.functions.borrow_mut().shift_remove(&name),
+        };
+    }
+}
+
+impl<'a> TypeChecker<'a> {
+    /// Returns the key under which `function` is cached, a hash of the function and of the signatures of the items
+    /// it refers to, directly or through the signatures of other items.
+    /// Note that spans are not hashed, so moving a function does not invalidate it.
+    pub(crate) fn cache_key(&self, name: Symbol, function: &Function) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        (self.program_name.map(|name| name.to_string()), self.is_imported, name.to_string()).hash(&mut hasher);
+        function.to_string().hash(&mut hasher);
+
+        let mut names = ReferencedNames::default();
+        names.visit_function(function);
+        let mut worklist = names.0;
+        let mut visited = IndexSet::new();
+
+        let symbol_table = self.symbol_table.borrow();
+        while let Some(name) = worklist.pop() {
+            if !visited.insert(name) {
+                continue;
+            }
+            (name.to_string(), symbol_table.lookup_import(name).map(|import| import.to_string())).hash(&mut hasher);
+            if let Some(struct_) = symbol_table.lookup_struct(name) {
+                struct_.to_string().hash(&mut hasher);
+                struct_.members.iter().for_each(|member| type_names(&member.type_, &mut worklist));
+            }
+            if let Some(enum_) = symbol_table.lookup_enum(name) {
+                enum_.to_string().hash(&mut hasher);
+            }
+            if let Some(function) = symbol_table.lookup_fn_symbol(name) {
+                format!("{:?} {}", function.variant, function.visibility).hash(&mut hasher);
+                function.output_type.to_string().hash(&mut hasher);
+                function.input.iter().for_each(|input| input.to_string().hash(&mut hasher));
+                function.input.iter().for_each(|input| type_names(&input.type_(), &mut worklist));
+                type_names(&function.output_type, &mut worklist);
+                if let Some(finalize) = &function.finalize {
+                    finalize.input.iter().for_each(|input| input.to_string().hash(&mut hasher));
+                    finalize.output_type.to_string().hash(&mut hasher);
+                }
+            }
+            // Global variables, i.e. constants and mappings.
+            if let Some(variable) = symbol_table.lookup_variable(name) {
+                (variable.declaration.to_string(), variable.type_.to_string()).hash(&mut hasher);
+                type_names(&variable.type_, &mut worklist);
+            }
+        }
+
+        hasher.finish()
+    }
+}
+
+/// Pushes the names of the structs and records in `type_` onto `names`.
+pub(crate) fn type_names(type_: &Type, names: &mut Vec<Symbol>) {
+    match type_ {
+        Type::Identifier(identifier) => names.push(identifier.name),
+        Type::Array(array_type) => type_names(array_type.element_type(), names),
+        Type::Mapping(mapping_type) => {
+            type_names(&mapping_type.key, names);
+            type_names(&mapping_type.value, names);
+        }
+        Type::Tuple(tuple_type) => tuple_type.elements().iter().for_each(|type_| type_names(type_, names)),
+        _ => {}
+    }
+}
+
+/// Collects the names that a function refers to, some of which may be local variables.
+#[derive(Default)]
+struct ReferencedNames(Vec<Symbol>);
+
+impl<'a> ExpressionVisitor<'a> for ReferencedNames {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_block_expression(
+        &mut self,
+        input: &'a BlockExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.visit_expression(&input.value, additional)
+    }
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
+            AccessExpression::AssociatedConstant(constant) => {
+                type_names(&constant.ty, &mut self.0);
+                // The constant may belong to a module, e.g. `math::PI`, which declares it as `math__PI`.
+                if let Type::Identifier(module) = &constant.ty {
+                    self.0.push(Module::item_name(module.name, constant.name.name));
+                }
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                type_names(&function.ty, &mut self.0);
+                if let Type::Identifier(module) = &function.ty {
+                    self.0.push(Module::item_name(module.name, function.name.name));
+                }
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+        }
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.function, additional);
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, additional);
+        type_names(&input.type_, &mut self.0);
+    }
+
+    // The expressions and statements the parser failed on refer to nothing.
+    fn visit_err(&mut self, _input: &'a ErrExpression, _additional: &Self::AdditionalInput) -> Self::Output {}
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.0.push(input.name.name);
+        for member in &input.members {
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                None => self.0.push(member.identifier.name),
+            }
+        }
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.0.push(input.name);
+    }
+}
+
+impl<'a> StatementVisitor<'a> for ReferencedNames {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.place, &Default::default());
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        type_names(&input.type_, &mut self.0);
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_declaration(&mut self, input: &'a DeclarationStatement) {
+        type_names(&input.type_, &mut self.0);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        input.type_.iter().for_each(|type_| type_names(type_, &mut self.0));
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_err_statement(&mut self, _input: &'a ErrStatement) {}
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        type_names(&input.type_, &mut self.0);
+        self.visit_expression(&input.start, &Default::default());
+        self.visit_expression(&input.stop, &Default::default());
+        self.visit_block(&input.block);
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for ReferencedNames {
+    fn visit_function(&mut self, input: &'a Function) {
+        input.input.iter().for_each(|input| type_names(&input.type_(), &mut self.0));
+        type_names(&input.output_type, &mut self.0);
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            finalize.input.iter().for_each(|input| type_names(&input.type_(), &mut self.0));
+            type_names(&finalize.output_type, &mut self.0);
+            self.visit_block(&finalize.block);
+        }
+    }
+}
@@ -14,15 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::TypeChecker;
+use crate::{CoreFunctionSignature, FunctionSymbol, TypeChecker, VariableSymbol, VariableType};
 
 use leo_ast::*;
-use leo_errors::{emitter::Handler, TypeCheckerError};
-use leo_span::{sym, Span};
+use leo_errors::{Label, TypeCheckerError};
+use leo_span::{sym, Span, Symbol};
 
 use itertools::Itertools;
 use snarkvm_console::network::{Network, Testnet3};
-use std::str::FromStr;
+use std::{collections::HashSet, fmt::Display};
 
 fn return_incorrect_type(t1: Option<Type>, t2: Option<Type>, expected: &Option<Type>) -> Option<Type> {
     match (t1, t2) {
@@ -38,6 +38,168 @@ fn return_incorrect_type(t1: Option<Type>, t2: Option<Type>, expected: &Option<T
     }
 }
 
+/// The parts of a `FunctionSymbol` needed to check a call, copied out of the symbol table.
+struct FunctionSignature {
+    variant: Variant,
+    is_public: bool,
+    output_type: Type,
+    input: Vec<Input>,
+    signature_span: Span,
+    deprecation: Option<Annotation>,
+}
+
+impl FunctionSignature {
+    fn new(func: &FunctionSymbol) -> Self {
+        Self {
+            variant: func.variant,
+            is_public: func.is_public(),
+            output_type: func.output_type.clone(),
+            input: func.input.clone(),
+            signature_span: func.signature_span,
+            deprecation: func.deprecation.clone(),
+        }
+    }
+}
+
+impl<'a> TypeChecker<'a> {
+    /// Type checks a call to the function `access.name` of the module `module`, e.g. `math::add(a, b)`,
+    /// which is a call to the function the module declares under its qualified name, e.g. `math__add`.
+    fn visit_module_call(
+        &mut self,
+        module: Symbol,
+        access: &'a AssociatedFunction,
+        expected: &Option<Type>,
+    ) -> Option<Type> {
+        let func = self.symbol_table.borrow().lookup_module_fn(module, access.name.name).map(FunctionSignature::new);
+        let Some(func) = func else {
+            let function = format!("{module}::{}", access.name.name);
+            self.emit_err(TypeCheckerError::unknown_sym("function", function, None::<Symbol>, access.name.span));
+            return None;
+        };
+        if let Some(deprecation) = &func.deprecation {
+            let function = format!("{module}::{}", access.name.name);
+            self.warn_deprecated("function", function, deprecation, func.signature_span, access.name.span);
+        }
+
+        // Functions and inline functions can only call inline functions.
+        // Note that this unwrap is safe since we always set the variant before traversing the body of the function.
+        if self.variant.unwrap() != Variant::Transition && func.variant != Variant::Inline {
+            self.emit_err(TypeCheckerError::can_only_call_inline_function(access.span));
+        }
+
+        let ret = self.assert_and_return_type(func.output_type, expected, access.span);
+
+        // Check the number and types of the arguments.
+        let callee = format!("{module}::{}", access.name.name);
+        self.check_call_arguments(callee, func.signature_span, &func.input, &access.arguments, access.span);
+
+        // Add the call to the call graph.
+        let caller_name = match self.function {
+            None => unreachable!("`self.function` is set every time a function is visited."),
+            Some(func) => func,
+        };
+        self.call_graph.add_edge(caller_name, Module::item_name(module, access.name.name));
+
+        Some(ret)
+    }
+
+    /// Type checks the `arguments` of a call to `callee` against its `parameters`.
+    /// The errors are labeled with the signature of `callee`, or with the parameter that an argument does not match.
+    fn check_call_arguments(
+        &mut self,
+        callee: impl Display,
+        signature_span: Span,
+        parameters: &[Input],
+        arguments: &'a [Expression],
+        span: Span,
+    ) {
+        // Check number of arguments.
+        if parameters.len() != arguments.len() {
+            let count = parameters.len();
+            let plural = if count == 1 { "" } else { "s" };
+            let label = Label::new(format!("`{callee}` takes {count} argument{plural}"), signature_span);
+            self.emit_err(
+                TypeCheckerError::incorrect_num_args_to_call(parameters.len(), arguments.len(), span).with_label(label),
+            );
+        }
+
+        // Check argument types.
+        for (parameter, argument) in parameters.iter().zip(arguments.iter()) {
+            let label = Label::new(format!("parameter `{}` of `{callee}`", parameter.identifier()), parameter.span());
+            let outer = self.expected_type_label.replace((argument.span(), label));
+            self.visit_expression(argument, &Some(parameter.type_()));
+            self.expected_type_label = outer;
+        }
+    }
+
+    /// Type checks a call to a built-in function that the embedder of the compiler registered with `signature`.
+    fn visit_registered_call(
+        &mut self,
+        signature: &CoreFunctionSignature,
+        access: &'a AssociatedFunction,
+        expected: &Option<Type>,
+    ) -> Option<Type> {
+        // Check that the function is not restricted to finalize blocks.
+        if !self.is_finalize && signature.finalize_only {
+            self.emit_err(TypeCheckerError::operation_must_be_in_finalize_block(access.span));
+        }
+
+        // Check the number and types of the arguments.
+        if signature.arguments.len() != access.arguments.len() {
+            self.emit_err(TypeCheckerError::incorrect_num_args_to_call(
+                signature.arguments.len(),
+                access.arguments.len(),
+                access.span,
+            ));
+        }
+        for (type_, argument) in signature.arguments.iter().zip(access.arguments.iter()) {
+            self.visit_expression(argument, &Some(type_.clone()));
+        }
+
+        Some(self.assert_and_return_type(signature.output.clone(), expected, access.span))
+    }
+
+    /// Type checks the constant `access.name` of the module `module`, e.g. `math::PI`,
+    /// which is the constant the module declares under its qualified name, e.g. `math__PI`.
+    fn visit_module_constant(
+        &mut self,
+        module: Symbol,
+        access: &'a AssociatedConstant,
+        expected: &Option<Type>,
+    ) -> Option<Type> {
+        let name = Module::item_name(module, access.name.name);
+        let type_ = match self.symbol_table.borrow().lookup_variable(name) {
+            Some(VariableSymbol { type_, declaration: VariableType::Const, .. }) => type_.clone(),
+            _ => {
+                let constant = format!("{module}::{}", access.name.name);
+                self.emit_err(TypeCheckerError::unknown_sym("constant", constant, None::<Symbol>, access.name.span));
+                return None;
+            }
+        };
+        Some(self.assert_and_return_type(type_, expected, access.span))
+    }
+
+    /// Type checks the variant `access.name` of the enum `enum_name`, e.g. `Color::Red`, whose type is the enum.
+    fn visit_enum_variant(
+        &mut self,
+        enum_name: Symbol,
+        access: &'a AssociatedConstant,
+        expected: &Option<Type>,
+    ) -> Option<Type> {
+        let is_variant = self
+            .symbol_table
+            .borrow()
+            .lookup_enum(enum_name)
+            .map_or(false, |enum_| enum_.variant_index(access.name.name).is_some());
+        if !is_variant {
+            let variant = format!("{enum_name}::{}", access.name.name);
+            self.emit_err(TypeCheckerError::unknown_sym("variant", variant, None::<Symbol>, access.name.span));
+            return None;
+        }
+        Some(self.assert_and_return_type(access.ty.clone(), expected, access.span))
+    }
+}
+
 impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     type AdditionalInput = Option<Type>;
     type Output = Option<Type>;
@@ -47,6 +209,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             Expression::Access(access) => self.visit_access(access, additional),
             Expression::Array(array) => self.visit_array(array, additional),
             Expression::Binary(binary) => self.visit_binary(binary, additional),
+            Expression::Block(block) => self.visit_block_expression(block, additional),
             Expression::Call(call) => self.visit_call(call, additional),
             Expression::Cast(cast) => self.visit_cast(cast, additional),
             Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
@@ -58,9 +221,14 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             Expression::Unary(unary) => self.visit_unary(unary, additional),
             Expression::Unit(unit) => self.visit_unit(unit, additional),
         };
+        // Evaluate integer expressions whose values are known at compile time, to check that they are in range.
+        if let Some(Type::Integer(integer_type)) = &output {
+            self.evaluate_integer(input, *integer_type);
+        }
         // If the output type is known, add the expression and its associated type to the symbol table.
         if let Some(type_) = &output {
             self.type_table.insert(input.id(), type_.clone());
+            self.type_table.insert_span(input.id(), input.span());
         }
         // Return the output type.
         output
@@ -74,7 +242,13 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 self.assert_array_type(&array_type, access.array.span());
 
                 // Check that the index is an integer type.
-                let index_type = self.visit_expression(&access.index, &None);
+                // An unsuffixed index is a `u32`, the type of indices in Aleo instructions.
+                let index_type = match access.index.as_ref() {
+                    Expression::Literal(Literal::Unsuffixed(..)) => {
+                        self.visit_expression(&access.index, &Some(Type::Integer(IntegerType::U32)))
+                    }
+                    _ => self.visit_expression(&access.index, &None),
+                };
                 self.assert_int_type(&index_type, access.index.span());
 
                 // Get the element type of the array.
@@ -92,6 +266,18 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 return element_type;
             }
             AccessExpression::AssociatedFunction(access) => {
+                // A call to a function of a module, e.g. `math::add(a, b)`.
+                if let Type::Identifier(module) = &access.ty {
+                    if self.symbol_table.borrow().lookup_module(module.name).is_some() {
+                        return self.visit_module_call(module.name, access, expected);
+                    }
+                    // A call to a built-in function registered by the embedder of the compiler.
+                    let registry = self.core_functions;
+                    if let Some(signature) = registry.and_then(|registry| registry.get(module.name, access.name.name)) {
+                        return self.visit_registered_call(signature, access, expected);
+                    }
+                }
+
                 // Check core struct name and function.
                 if let Some(core_instruction) = self.get_core_function_call(&access.ty, &access.name) {
                     // Check that operation is not restricted to finalize blocks.
@@ -100,12 +286,25 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                     }
 
                     // Get the types of the arguments.
+                    // An unsuffixed index into a lookup table is a `u32`, like the index of an array access.
+                    let is_lookup = core_instruction == CoreFunction::ArrayLookup;
                     let argument_types = access
                         .arguments
                         .iter()
-                        .map(|arg| (self.visit_expression(arg, &None), arg.span()))
+                        .enumerate()
+                        .map(|(i, arg)| match (is_lookup, i, arg) {
+                            (true, 1, Expression::Literal(Literal::Unsuffixed(..))) => {
+                                (self.visit_expression(arg, &Some(Type::Integer(IntegerType::U32))), arg)
+                            }
+                            _ => (self.visit_expression(arg, &None), arg),
+                        })
                         .collect::<Vec<_>>();
 
+                    // Check that a literal index into a lookup table is in range.
+                    if is_lookup && argument_types.len() == 2 {
+                        self.check_lookup_index(&argument_types[0].0, &access.arguments[1]);
+                    }
+
                     // Check that the types of the arguments are valid.
                     let return_type = self.check_core_function_call(core_instruction, &argument_types, input.span());
 
@@ -149,6 +348,8 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                                 return Some(actual);
                             }
                         }
+                        // The access of a poisoned variable is itself poisoned.
+                        Type::Err => return Some(Type::Err),
                         type_ => {
                             self.emit_err(TypeCheckerError::type_should_be(type_, "tuple", access.span()));
                         }
@@ -159,7 +360,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             AccessExpression::Member(access) => {
                 match *access.inner {
                     // If the access expression is of the form `self.<name>`, then check the <name> is valid.
-                    Expression::Identifier(identifier) if identifier.name == sym::SelfLower => match access.name.name {
+                    Expression::Identifier(identifier) if self.is_self_keyword(&identifier) => match access.name.name {
                         sym::caller => {
                             // Check that the operation is not invoked in a `finalize` block.
                             if self.is_finalize {
@@ -205,8 +406,8 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                         match self.visit_expression(&access.inner, &None) {
                             Some(Type::Identifier(identifier)) => {
                                 // Retrieve the struct definition associated with `identifier`.
-                                let struct_ = self.symbol_table.borrow().lookup_struct(identifier.name).cloned();
-                                if let Some(struct_) = struct_ {
+                                // Note that the definition is borrowed, since it is only needed by `&self` methods.
+                                if let Some(struct_) = self.symbol_table.borrow().lookup_struct(identifier.name) {
                                     // Check that `access.name` is a member of the struct.
                                     match struct_.members.iter().find(|member| member.name() == access.name.name) {
                                         // Case where `access.name` is a member of the struct.
@@ -222,15 +423,27 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                                         None => {
                                             self.emit_err(TypeCheckerError::invalid_struct_variable(
                                                 access.name,
-                                                &struct_,
+                                                struct_.identifier,
                                                 access.name.span(),
                                             ));
                                         }
                                     }
                                 } else {
-                                    self.emit_err(TypeCheckerError::undefined_type(&access.inner, access.inner.span()));
+                                    self.emit_err(TypeCheckerError::undefined_type(identifier.name, access.inner.span()));
                                 }
                             }
+                            // The coordinates of a group element are fields.
+                            Some(Type::Group) => match access.name.name {
+                                sym::x | sym::y => {
+                                    return Some(self.assert_and_return_type(Type::Field, expected, access.span()));
+                                }
+                                _ => self.emit_err(TypeCheckerError::invalid_group_coordinate(
+                                    access.name,
+                                    access.name.span(),
+                                )),
+                            },
+                            // The access of a poisoned variable is itself poisoned.
+                            Some(Type::Err) => return Some(Type::Err),
                             Some(type_) => {
                                 self.emit_err(TypeCheckerError::type_should_be(type_, "struct", access.inner.span()));
                             }
@@ -245,6 +458,20 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 }
             }
             AccessExpression::AssociatedConstant(access) => {
+                // A constant of a module, e.g. `math::PI`.
+                if let Type::Identifier(module) = &access.ty {
+                    if self.symbol_table.borrow().lookup_module(module.name).is_some() {
+                        return self.visit_module_constant(module.name, access, expected);
+                    }
+                }
+
+                // A variant of an enum, e.g. `Color::Red`.
+                if let Type::Identifier(enum_name) = &access.ty {
+                    if self.symbol_table.borrow().lookup_enum(enum_name.name).is_some() {
+                        return self.visit_enum_variant(enum_name.name, access, expected);
+                    }
+                }
+
                 // Check associated constant type and constant name
                 if let Some(core_constant) = self.get_core_constant(&access.ty, &access.name) {
                     // Check return type if the expected type is known.
@@ -356,8 +583,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Operation returns field, group or integer types.
                 self.assert_field_group_int_type(destination, input.span());
 
-                let t1 = self.visit_expression(&input.left, &None);
-                let t2 = self.visit_expression(&input.right, &None);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right);
 
                 // Allow group * scalar multiplication.
                 match (t1, input.left.span(), t2, input.right.span()) {
@@ -391,6 +617,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                     }
                     (left_type, left_span, right_type, right_span) => {
                         let check_type = |type_: Option<Type>, expression: &Expression, span: Span| match type_ {
+                            Some(Type::Err) => {}
                             None => {
                                 self.emit_err(TypeCheckerError::could_not_determine_type(expression, span));
                             }
@@ -414,6 +641,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
                 let t1 = self.visit_expression(&input.left, destination);
                 let t2 = self.visit_expression(&input.right, destination);
+                self.assert_nonzero_divisor(input);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -426,6 +654,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
                 let t1 = self.visit_expression(&input.left, destination);
                 let t2 = self.visit_expression(&input.right, destination);
+                self.assert_nonzero_divisor(input);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -438,6 +667,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
                 let t1 = self.visit_expression(&input.left, destination);
                 let t2 = self.visit_expression(&input.right, destination);
+                self.assert_nonzero_divisor(input);
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -491,8 +721,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             }
             BinaryOperation::Eq | BinaryOperation::Neq => {
                 // Assert first and second address, boolean, field, group, scalar, or integer types.
-                let t1 = self.visit_expression(&input.left, &None);
-                let t2 = self.visit_expression(&input.right, &None);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right);
 
                 // Check that the types of the operands are equal.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -504,8 +733,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             }
             BinaryOperation::Lt | BinaryOperation::Gt | BinaryOperation::Lte | BinaryOperation::Gte => {
                 // Assert left and right are equal field, scalar, or integer types.
-                let t1 = self.visit_expression(&input.left, &None);
-                let t2 = self.visit_expression(&input.right, &None);
+                let (t1, t2) = self.visit_operands(&input.left, &input.right);
 
                 match (&t1, &t2) {
                     (Some(Type::Address), _) | (_, Some(Type::Address)) => {
@@ -534,6 +762,9 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 self.assert_int_type(destination, input.span);
                 let t1 = self.visit_expression(&input.left, destination);
                 let t2 = self.visit_expression(&input.right, destination);
+                if input.op == BinaryOperation::DivWrapped {
+                    self.assert_nonzero_divisor(input);
+                }
 
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -555,20 +786,78 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Assert right type is a magnitude (u8, u16, u32).
                 self.assert_magnitude_type(&t2, input.right.span());
 
+                // Assert that a constant shift amount is less than the bit width of the left operand.
+                if let (BinaryOperation::Shl | BinaryOperation::Shr, Some(Type::Integer(integer_type))) =
+                    (input.op, &t1)
+                {
+                    if let Some((_, amount)) = self.constant_integers.get(&input.right.id()) {
+                        if *amount >= i128::from(integer_type.bit_width()) {
+                            self.emit_err(TypeCheckerError::shift_amount_exceeds_width(
+                                amount,
+                                integer_type,
+                                integer_type.bit_width(),
+                                input.left.span() + input.right.span(),
+                            ));
+                        }
+                    }
+                }
+
                 t1
             }
         }
     }
 
+    fn visit_block_expression(&mut self, input: &'a BlockExpression, expected: &Self::AdditionalInput) -> Self::Output {
+        // Create a new scope for the block.
+        let scope_index = self.create_child_scope();
+
+        // Check the statements, which cannot return from the enclosing function.
+        let prior_is_block_expression = core::mem::replace(&mut self.is_block_expression, true);
+        let prior_is_return = core::mem::take(&mut self.is_return);
+        let prior_has_return = core::mem::take(&mut self.has_return);
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.is_block_expression = prior_is_block_expression;
+        self.is_return = prior_is_return;
+        self.has_return = prior_has_return;
+
+        // The type of the block is the type of its value.
+        let type_ = self.visit_expression(&input.value, expected);
+
+        // Exit the scope for the block.
+        self.exit_scope(scope_index);
+
+        type_
+    }
+
     fn visit_call(&mut self, input: &'a CallExpression, expected: &Self::AdditionalInput) -> Self::Output {
         match &*input.function {
-            // Note that the parser guarantees that `input.function` is always an identifier.
+            // Note that the parser guarantees that `input.function` is always an identifier or a method.
             Expression::Identifier(ident) => {
                 // Note: The function symbol lookup is performed outside of the `if let Some(func) ...` block to avoid a RefCell lifetime bug in Rust.
                 // Do not move it into the `if let Some(func) ...` block or it will keep `self.symbol_table_creation` alive for the entire block and will be very memory inefficient!
-                let func = self.symbol_table.borrow().lookup_fn_symbol(ident.name).cloned();
+                // Only the signature of the function is copied out of the symbol table.
+                let func = self.symbol_table.borrow().lookup_fn_symbol(ident.name).map(FunctionSignature::new);
+
+                // Check that an external call is to a function of the imported program that it names.
+                if let Some(external) = &input.external {
+                    let import = self.symbol_table.borrow().lookup_import(ident.name);
+                    match (external.as_ref(), import) {
+                        (Expression::Identifier(program), Some(import)) if program.name == import => {}
+                        _ => {
+                            let error = TypeCheckerError::unknown_external_function(external, ident.name, input.span);
+                            self.emit_err(error);
+                            return None;
+                        }
+                    }
+                }
 
                 if let Some(func) = func {
+                    // Check that the function is visible to this program.
+                    self.check_item_is_visible("function", ident.name, func.is_public, ident.span);
+                    if let Some(deprecation) = &func.deprecation {
+                        self.warn_deprecated("function", ident.name, deprecation, func.signature_span, ident.span);
+                    }
+
                     // Check that the call is valid.
                     // Note that this unwrap is safe since we always set the variant before traversing the body of the function.
                     match self.variant.unwrap() {
@@ -593,21 +882,21 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                         self.emit_err(TypeCheckerError::cannot_call_external_inline_function(input.span));
                     }
 
-                    let ret = self.assert_and_return_type(func.output_type, expected, input.span());
-
-                    // Check number of function arguments.
-                    if func.input.len() != input.arguments.len() {
-                        self.emit_err(TypeCheckerError::incorrect_num_args_to_call(
-                            func.input.len(),
-                            input.arguments.len(),
-                            input.span(),
-                        ));
+                    // Check that an external call is made unconditionally, and not from a finalize block.
+                    // Note that the call is executed on every path, since a transition is not a circuit that branches.
+                    if input.external.is_some() {
+                        if self.is_finalize {
+                            self.emit_err(TypeCheckerError::external_call_in_finalize(input.span));
+                        } else if self.is_conditional {
+                            self.emit_err(TypeCheckerError::external_call_in_conditional(input.span));
+                        }
                     }
 
-                    // Check function argument types.
-                    func.input.iter().zip(input.arguments.iter()).for_each(|(expected, argument)| {
-                        self.visit_expression(argument, &Some(expected.type_()));
-                    });
+                    let ret = self.assert_and_return_type(func.output_type, expected, input.span());
+
+                    // Check the number and types of the arguments.
+                    let (signature_span, parameters) = (func.signature_span, &func.input);
+                    self.check_call_arguments(ident.name, signature_span, parameters, &input.arguments, input.span());
 
                     // Add the call to the call graph.
                     let caller_name = match self.function {
@@ -618,11 +907,47 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
                     Some(ret)
                 } else {
-                    self.emit_err(TypeCheckerError::unknown_sym("function", ident.name, ident.span()));
+                    let suggestion = self.symbol_table.borrow().similar_fn(ident.name);
+                    self.emit_err(TypeCheckerError::unknown_sym("function", ident.name, suggestion, ident.span()));
                     None
                 }
             }
-            _ => unreachable!("Parsing guarantees that a function name is always an identifier."),
+            // A method call `receiver.method(args)`, which is resolved from the type of the receiver.
+            Expression::Access(AccessExpression::Member(access)) => {
+                let receiver_type = self.visit_expression(&access.inner, &None)?;
+                let method = match &receiver_type {
+                    Type::Identifier(struct_name) => self
+                        .symbol_table
+                        .borrow()
+                        .lookup_struct(struct_name.name)
+                        .filter(|struct_| struct_.methods.iter().any(|(name, _)| *name == access.name.name))
+                        .map(|struct_| Struct::method_name(struct_.name(), access.name.name)),
+                    _ => None,
+                };
+                let func = method
+                    .and_then(|method| self.symbol_table.borrow().lookup_fn_symbol(method).map(FunctionSignature::new));
+                let (Some(method), Some(func)) = (method, func) else {
+                    self.emit_err(TypeCheckerError::unknown_method(receiver_type, access.name, access.name.span()));
+                    return None;
+                };
+
+                let ret = self.assert_and_return_type(func.output_type, expected, input.span());
+
+                // The receiver is not passed as an argument.
+                let callee = format!("{receiver_type}.{}", access.name);
+                let parameters = &func.input[1..];
+                self.check_call_arguments(callee, func.signature_span, parameters, &input.arguments, input.span());
+
+                // Add the call to the call graph.
+                let caller_name = match self.function {
+                    None => unreachable!("`self.function` is set every time a function is visited."),
+                    Some(func) => func,
+                };
+                self.call_graph.add_edge(caller_name, method);
+
+                Some(ret)
+            }
+            _ => unreachable!("Parsing guarantees that a function name is always an identifier or a method."),
         }
     }
 
@@ -634,49 +959,103 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         let expression_type = self.visit_expression(&input.expression, &None);
         self.assert_castable_type(&expression_type, input.expression.span());
 
+        // A wrapped cast truncates a field or integer to an integer type.
+        if input.wrapped {
+            self.assert_int_type(&Some(input.type_.clone()), input.span());
+            self.assert_field_int_type(&expression_type, input.expression.span());
+        }
+
         // Check that the expected type matches the target type.
         Some(self.assert_and_return_type(input.type_.clone(), expected, input.span()))
     }
 
     fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
-        let struct_ = self.symbol_table.borrow().lookup_struct(input.name.name).cloned();
-        if let Some(struct_) = struct_ {
-            // Check struct type name.
-            let ret = self.check_expected_struct(struct_.identifier, additional, input.name.span());
-
-            // Check number of struct members.
-            if struct_.members.len() != input.members.len() {
-                self.emit_err(TypeCheckerError::incorrect_num_struct_members(
-                    struct_.members.len(),
-                    input.members.len(),
-                    input.span(),
-                ));
+        // The struct definition is borrowed rather than cloned, since it may be large, e.g. if it has methods.
+        // Only the types of the members are copied, since visiting their initializers requires `&mut self`.
+        let symbol_table = self.symbol_table.borrow();
+        let Some(struct_) = symbol_table.lookup_struct(input.name.name) else {
+            let suggestion = symbol_table.similar_struct(input.name.name);
+            self.emit_err(TypeCheckerError::unknown_sym("struct", input.name.name, suggestion, input.name.span()));
+            return None;
+        };
+
+        // Check that the struct is visible to this program.
+        self.check_struct_is_visible(struct_, input.name.span());
+        if let Some(deprecation) = struct_.deprecation() {
+            let kind = if struct_.is_record { "record" } else { "struct" };
+            let declaration = Span::new(struct_.span.lo, struct_.identifier.span.hi);
+            self.warn_deprecated(kind, struct_.identifier, deprecation, declaration, input.name.span());
+        }
+
+        // Check struct type name.
+        let ret = self.check_expected_struct(struct_.identifier, additional, input.name.span());
+
+        // Check that the record is created in a context that can output it.
+        if struct_.is_record {
+            if self.is_finalize {
+                self.emit_err(TypeCheckerError::finalize_cannot_create_record(struct_.identifier, input.span()));
+            } else if self.variant == Some(Variant::Standard) {
+                self.emit_err(TypeCheckerError::function_cannot_create_record(struct_.identifier, input.span()));
             }
+        }
 
-            // Check struct member types.
-            struct_.members.iter().for_each(|Member { identifier, type_, .. }| {
-                // Lookup struct variable name.
-                if let Some(actual) = input.members.iter().find(|member| member.identifier.name == identifier.name) {
-                    match &actual.expression {
-                        // If `expression` is None, then the member uses the identifier shorthand, e.g. `Foo { a }`
-                        None => self.visit_identifier(&actual.identifier, &Some(type_.clone())),
-                        // Otherwise, visit the associated expression.
-                        Some(expr) => self.visit_expression(expr, &Some(type_.clone())),
-                    };
-                } else {
-                    self.emit_err(TypeCheckerError::missing_struct_member(
-                        struct_.identifier,
-                        identifier,
-                        input.span(),
-                    ));
-                };
-            });
+        // Check that each initializer names a distinct member of the struct.
+        // Missing members are reported below, so the number of members is not checked separately.
+        let mut initialized = HashSet::new();
+        for member in input.members.iter() {
+            if !initialized.insert(member.identifier.name) {
+                self.emit_err(TypeCheckerError::duplicate_struct_init_member(
+                    member.identifier,
+                    struct_.identifier,
+                    member.span(),
+                ));
+            } else if !struct_.members.iter().any(|Member { identifier, .. }| identifier.name == member.identifier.name)
+            {
+                self.emit_err(TypeCheckerError::invalid_struct_variable(
+                    member.identifier,
+                    struct_.identifier,
+                    member.span(),
+                ));
+            }
+        }
 
-            Some(ret)
-        } else {
-            self.emit_err(TypeCheckerError::unknown_sym("struct", input.name.name, input.name.span()));
-            None
+        // Lookup the initializer of each struct member, or the name of the member if it is missing.
+        // A missing member with a default value is filled in with the default during lowering.
+        let members = struct_
+            .members
+            .iter()
+            .filter_map(|Member { identifier, type_, default, .. }| {
+                match input.members.iter().find(|member| member.identifier.name == identifier.name) {
+                    Some(actual) => Some(Ok((actual, type_.clone()))),
+                    None if default.is_some() => None,
+                    None => Some(Err(*identifier)),
+                }
+            })
+            .collect::<Vec<_>>();
+        let (struct_name, is_record) = (struct_.identifier, struct_.is_record);
+        drop(symbol_table);
+
+        // Check struct member types.
+        for member in members {
+            match member {
+                // If `expression` is None, then the member uses the identifier shorthand, e.g. `Foo { a }`
+                Ok((actual, type_)) => match &actual.expression {
+                    None => self.visit_identifier(&actual.identifier, &Some(type_)),
+                    // Otherwise, visit the associated expression.
+                    Some(expr) => self.visit_expression(expr, &Some(type_)),
+                },
+                Err(identifier) if is_record => {
+                    self.emit_err(TypeCheckerError::missing_record_member(struct_name, identifier, input.span()));
+                    None
+                }
+                Err(identifier) => {
+                    self.emit_err(TypeCheckerError::missing_struct_member(struct_name, identifier, input.span()));
+                    None
+                }
+            };
         }
+
+        Some(ret)
     }
 
     // We do not want to panic on `ErrExpression`s in order to propagate as many errors as possible.
@@ -688,67 +1067,47 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         if let Some(var) = self.symbol_table.borrow().lookup_variable(input.name) {
             Some(self.assert_and_return_type(var.type_.clone(), expected, input.span()))
         } else {
-            self.emit_err(TypeCheckerError::unknown_sym("variable", input.name, input.span()));
+            let suggestion = self.symbol_table.borrow().similar_variable(input.name);
+            self.emit_err(TypeCheckerError::unknown_sym("variable", input.name, suggestion, input.span()));
             None
         }
     }
 
     fn visit_literal(&mut self, input: &'a Literal, expected: &Self::AdditionalInput) -> Self::Output {
-        fn parse_integer_literal<I: FromStr>(handler: &Handler, raw_string: &str, span: Span, type_string: &str) {
-            let string = raw_string.replace('_', "");
-            if string.parse::<I>().is_err() {
-                handler.emit_err(TypeCheckerError::invalid_int_value(string, type_string, span));
-            }
-        }
-
         Some(match input {
-            Literal::Address(_, _, _) => self.assert_and_return_type(Type::Address, expected, input.span()),
+            Literal::Address(address, _, _) => {
+                self.check_address_literal(address, input.span());
+                self.assert_and_return_type(Type::Address, expected, input.span())
+            }
             Literal::Boolean(_, _, _) => self.assert_and_return_type(Type::Boolean, expected, input.span()),
-            Literal::Field(_, _, _) => self.assert_and_return_type(Type::Field, expected, input.span()),
-            Literal::Integer(integer_type, string, _, _) => match integer_type {
-                IntegerType::U8 => {
-                    parse_integer_literal::<u8>(self.handler, string, input.span(), "u8");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U8), expected, input.span())
-                }
-                IntegerType::U16 => {
-                    parse_integer_literal::<u16>(self.handler, string, input.span(), "u16");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U16), expected, input.span())
-                }
-                IntegerType::U32 => {
-                    parse_integer_literal::<u32>(self.handler, string, input.span(), "u32");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U32), expected, input.span())
-                }
-                IntegerType::U64 => {
-                    parse_integer_literal::<u64>(self.handler, string, input.span(), "u64");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U64), expected, input.span())
-                }
-                IntegerType::U128 => {
-                    parse_integer_literal::<u128>(self.handler, string, input.span(), "u128");
-                    self.assert_and_return_type(Type::Integer(IntegerType::U128), expected, input.span())
-                }
-                IntegerType::I8 => {
-                    parse_integer_literal::<i8>(self.handler, string, input.span(), "i8");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I8), expected, input.span())
-                }
-                IntegerType::I16 => {
-                    parse_integer_literal::<i16>(self.handler, string, input.span(), "i16");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I16), expected, input.span())
-                }
-                IntegerType::I32 => {
-                    parse_integer_literal::<i32>(self.handler, string, input.span(), "i32");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I32), expected, input.span())
+            Literal::Field(string, _, _) => {
+                self.check_modulus_literal(Type::Field, string, expected, input.span())
+            }
+            Literal::Integer(integer_type, string, _, _) => {
+                self.check_integer_literal(*integer_type, string, expected, input.span())
+            }
+            Literal::Unsuffixed(string, _, _) => match expected {
+                // The type of an unsuffixed literal is the integer type expected by its context.
+                Some(Type::Integer(integer_type)) => {
+                    self.check_integer_literal(*integer_type, string, expected, input.span())
                 }
-                IntegerType::I64 => {
-                    parse_integer_literal::<i64>(self.handler, string, input.span(), "i64");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I64), expected, input.span())
+                Some(expected) => {
+                    let err = TypeCheckerError::unsuffixed_literal_must_be_integer(string, expected, input.span());
+                    self.emit_err(self.label_expected_type(err, input.span()));
+                    expected.clone()
                 }
-                IntegerType::I128 => {
-                    parse_integer_literal::<i128>(self.handler, string, input.span(), "i128");
-                    self.assert_and_return_type(Type::Integer(IntegerType::I128), expected, input.span())
+                None => {
+                    self.emit_err(TypeCheckerError::could_not_infer_integer_literal_type(string, input.span()));
+                    return None;
                 }
             },
-            Literal::Group(_) => self.assert_and_return_type(Type::Group, expected, input.span()),
-            Literal::Scalar(_, _, _) => self.assert_and_return_type(Type::Scalar, expected, input.span()),
+            Literal::Group(group) => {
+                self.check_group_literal(group);
+                self.assert_and_return_type(Type::Group, expected, input.span())
+            }
+            Literal::Scalar(string, _, _) => {
+                self.check_modulus_literal(Type::Scalar, string, expected, input.span())
+            }
             Literal::String(_, _, _) => {
                 self.emit_err(TypeCheckerError::strings_are_not_supported(input.span()));
                 self.assert_and_return_type(Type::String, expected, input.span())
@@ -762,6 +1121,20 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         let t1 = self.visit_expression(&input.if_true, expected);
         let t2 = self.visit_expression(&input.if_false, expected);
 
+        // If the type is expected, each branch has already been checked against it.
+        if expected.is_none() {
+            if let (Some(t1), Some(t2)) = (&t1, &t2) {
+                if t1 != t2 && !matches!(t1, Type::Err) && !matches!(t2, Type::Err) {
+                    self.emit_err(TypeCheckerError::ternary_branch_mismatch(
+                        t1,
+                        t2,
+                        input.if_true.span() + input.if_false.span(),
+                    ));
+                    return Some(Type::Err);
+                }
+            }
+        }
+
         return_incorrect_type(t1, t2, expected)
     }
 
@@ -859,3 +1232,126 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         Some(Type::Unit)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{SymbolTable, TypeTable};
+
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// The variables that are in scope of the checked expressions.
+    const VARIABLES: &[(&str, Type)] = &[
+        ("g", Type::Group),
+        ("s", Type::Scalar),
+        ("f", Type::Field),
+        ("a", Type::Integer(IntegerType::U8)),
+        ("b", Type::Integer(IntegerType::U32)),
+        ("c", Type::Integer(IntegerType::U64)),
+        ("i", Type::Integer(IntegerType::I8)),
+    ];
+
+    /// Returns a symbol table whose root scope holds the variables in `VARIABLES`.
+    fn symbol_table() -> SymbolTable {
+        let mut symbol_table = SymbolTable::default();
+        for (name, type_) in VARIABLES {
+            let variable = VariableSymbol {
+                type_: type_.clone(),
+                span: Span::dummy(),
+                declaration: VariableType::Input(Mode::None),
+                value: None,
+            };
+            symbol_table.insert_variable(Symbol::intern(name), variable).unwrap();
+        }
+        symbol_table
+    }
+
+    /// Returns the expression `left op right` over the variables `left` and `right`.
+    fn binary(left: &str, op: BinaryOperation, right: &str, node_builder: &NodeBuilder) -> Expression {
+        let variable = |name: &str| {
+            Box::new(Expression::Identifier(Identifier {
+                name: Symbol::intern(name),
+                span: Span::dummy(),
+                id: node_builder.next_id(),
+            }))
+        };
+        Expression::Binary(BinaryExpression {
+            left: variable(left),
+            right: variable(right),
+            op,
+            span: Span::dummy(),
+            id: node_builder.next_id(),
+        })
+    }
+
+    /// Type checks `left op right`, returning its type and the emitted errors.
+    fn check(left: &str, op: BinaryOperation, right: &str) -> (Option<Type>, Vec<String>) {
+        let expression = binary(left, op, right, &NodeBuilder::default());
+        let (handler, buffer) = Handler::new_with_buf();
+        let type_table = TypeTable::default();
+        let type_ = TypeChecker::new(symbol_table(), &type_table, &handler).check_expression(&expression, None);
+        let errors = buffer.extract_errs().into_inner();
+        (type_, errors.iter().map(|error| error.to_string()).collect())
+    }
+
+    /// Asserts that `left op right` is well typed, with type `expected`.
+    fn assert_well_typed(left: &str, op: BinaryOperation, right: &str, expected: Type) {
+        let (type_, errors) = check(left, op, right);
+        assert!(errors.is_empty(), "`{left} {op} {right}`: {errors:#?}");
+        assert_eq!(type_, Some(expected), "`{left} {op} {right}`");
+    }
+
+    /// Asserts that `left op right` is ill typed, with an error that contains `message`.
+    fn assert_ill_typed(left: &str, op: BinaryOperation, right: &str, message: &str) {
+        let (_, errors) = check(left, op, right);
+        assert_eq!(errors.len(), 1, "`{left} {op} {right}`: {errors:#?}");
+        assert!(errors[0].contains(message), "`{left} {op} {right}`: {}", errors[0]);
+    }
+
+    #[test]
+    fn test_mul_operands() {
+        create_session_if_not_set_then(|_| {
+            assert_well_typed("g", BinaryOperation::Mul, "s", Type::Group);
+            assert_well_typed("s", BinaryOperation::Mul, "g", Type::Group);
+            assert_well_typed("f", BinaryOperation::Mul, "f", Type::Field);
+            assert_well_typed("a", BinaryOperation::Mul, "a", Type::Integer(IntegerType::U8));
+            assert_ill_typed("g", BinaryOperation::Mul, "g", "Expected one type from `scalar`, but got `group`");
+            assert_ill_typed("g", BinaryOperation::Mul, "f", "Expected one type from `scalar`, but got `field`");
+            assert_ill_typed("f", BinaryOperation::Mul, "s", "Expected one type from `field`, but got `scalar`");
+            assert_ill_typed("a", BinaryOperation::Mul, "c", "Expected one type from `u8`, but got `u64`");
+        })
+    }
+
+    #[test]
+    fn test_pow_operands() {
+        create_session_if_not_set_then(|_| {
+            assert_well_typed("f", BinaryOperation::Pow, "f", Type::Field);
+            assert_well_typed("a", BinaryOperation::Pow, "a", Type::Integer(IntegerType::U8));
+            assert_well_typed("c", BinaryOperation::Pow, "b", Type::Integer(IntegerType::U64));
+            assert_well_typed("i", BinaryOperation::Pow, "a", Type::Integer(IntegerType::I8));
+            assert_ill_typed("a", BinaryOperation::Pow, "c", "Expected one type from `u8, u16, u32`, but got `u64`");
+            assert_ill_typed("a", BinaryOperation::Pow, "i", "Expected one type from `u8, u16, u32`, but got `i8`");
+            assert_ill_typed("f", BinaryOperation::Pow, "a", "Expected one type from `field`, but got `u8`");
+            assert_ill_typed("a", BinaryOperation::Pow, "f", "Expected one type from `field`, but got `u8`");
+        })
+    }
+
+    #[test]
+    fn test_shift_operands() {
+        create_session_if_not_set_then(|_| {
+            assert_well_typed("c", BinaryOperation::Shl, "a", Type::Integer(IntegerType::U64));
+            assert_well_typed("i", BinaryOperation::Shr, "b", Type::Integer(IntegerType::I8));
+            assert_ill_typed("a", BinaryOperation::Shl, "c", "Expected one type from `u8, u16, u32`, but got `u64`");
+            assert_ill_typed("f", BinaryOperation::Shl, "a", "but got `field`");
+        })
+    }
+
+    #[test]
+    fn test_unknown_variable() {
+        create_session_if_not_set_then(|_| {
+            let (_, errors) = check("a", BinaryOperation::Add, "z");
+            assert!(errors[0].contains("Unknown variable `z`"), "{errors:#?}");
+        })
+    }
+}
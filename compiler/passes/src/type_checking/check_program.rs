@@ -18,7 +18,7 @@ use crate::{DiGraphError, TypeChecker, VariableSymbol, VariableType};
 
 use leo_ast::*;
 use leo_errors::TypeCheckerError;
-use leo_span::sym;
+use leo_span::{sym, Symbol};
 
 use snarkvm_console::network::{Network, Testnet3};
 
@@ -31,7 +31,9 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         match self.is_imported {
             // If the program is imported, then it is not allowed to import any other programs.
             true => {
-                input.imports.values().for_each(|(_, span)| {
+                let import_spans = input.imports.values().map(|(_, span)| span);
+                let stub_spans = input.stubs.values().map(|(_, span)| span);
+                import_spans.chain(stub_spans).for_each(|span| {
                     self.emit_err(TypeCheckerError::imported_program_cannot_import_program(*span))
                 });
             }
@@ -53,9 +55,20 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.program_name = Some(input.program_id.name.name);
+
+        // Check that the annotations of the program scope set the levels of lints.
+        self.check_annotations(&input.annotations, &Annotation::LINT_LEVELS);
+
         // Typecheck each const definition, and append to symbol table.
         input.consts.iter().for_each(|(_, c)| self.visit_const(c));
 
+        // Typecheck each interface definition.
+        input.interfaces.iter().for_each(|(_, interface)| self.visit_interface(interface));
+
+        // Typecheck each enum definition.
+        input.enums.iter().for_each(|(_, enum_)| self.visit_enum(enum_));
+
         // Typecheck each struct definition.
         input.structs.iter().for_each(|(_, function)| self.visit_struct(function));
 
@@ -79,14 +92,15 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             ));
         }
 
-        // Typecheck each function definitions.
-        let mut transition_count = 0;
-        for (_, function) in input.functions.iter() {
-            self.visit_function(function);
-            if matches!(function.variant, Variant::Transition) {
-                transition_count += 1;
-            }
-        }
+        // Typecheck the methods of each struct and each function definition.
+        // Note that their signatures are already in the symbol table, so their bodies can be checked independently.
+        let methods = input.structs.iter().flat_map(|(_, struct_)| {
+            struct_.methods.iter().map(|(name, method)| (Struct::method_name(struct_.name(), *name), method))
+        });
+        let functions = input.functions.iter().map(|(_, function)| (function.name(), function));
+        self.check_functions(methods.chain(functions).collect());
+        let transition_count =
+            input.functions.iter().filter(|(_, function)| matches!(function.variant, Variant::Transition)).count();
 
         // Check that the call graph does not have any cycles.
         if let Err(DiGraphError::CycleDetected(path)) = self.call_graph.post_order() {
@@ -103,7 +117,52 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         }
     }
 
+    fn visit_interface(&mut self, input: &'a Interface) {
+        // Check for conflicting interface member names.
+        let mut used = HashSet::new();
+        if !input.members.iter().all(|Member { identifier, type_, span, .. }| {
+            // Check that the member types are defined.
+            self.assert_type_is_valid(type_, *span);
+            used.insert(identifier.name)
+        }) {
+            self.emit_err(TypeCheckerError::duplicate_interface_member(input.name(), input.span()));
+        }
+
+        // Check that the members do not have modes, since conformance only depends on the member names and types.
+        for Member { mode, span, .. } in input.members.iter() {
+            if !matches!(mode, Mode::None) {
+                self.emit_err(TypeCheckerError::interface_cannot_have_member_mode(*span));
+            }
+        }
+
+        // Check that the members do not have default values, which only a conforming struct can declare.
+        for Member { identifier, default, .. } in input.members.iter() {
+            if let Some(default) = default {
+                self.emit_err(TypeCheckerError::interface_member_cannot_have_default(identifier, default.span()));
+            }
+        }
+    }
+
+    fn visit_enum(&mut self, input: &'a Enum) {
+        // Check that each variant is declared once.
+        let mut used = HashSet::new();
+        for variant in input.variants.iter() {
+            if !used.insert(variant.name) {
+                self.emit_err(TypeCheckerError::duplicate_enum_variant(input.name(), variant, variant.span));
+            }
+        }
+
+        // Check that each variant can be represented by a distinct value.
+        let max = 1usize << Enum::REPRESENTATION.bit_width();
+        if input.variants.len() > max {
+            self.emit_err(TypeCheckerError::too_many_enum_variants(input.name(), max, input.span));
+        }
+    }
+
     fn visit_struct(&mut self, input: &'a Struct) {
+        // Structs and records can only be deprecated.
+        self.check_annotations(&input.annotations, &[sym::deprecated]);
+
         // Check for conflicting struct/record member names.
         let mut used = HashSet::new();
         // TODO: Better span to target duplicate member.
@@ -136,7 +195,15 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             check_has_field(sym::owner, Type::Address);
         }
 
-        for Member { mode, identifier, type_, span, .. } in input.members.iter() {
+        for Member { mode, identifier, type_, default, span, .. } in input.members.iter() {
+            // Check that the default value, if any, is a literal of the member type.
+            if let Some(default) = default {
+                if !matches!(default, Expression::Literal(_)) {
+                    self.emit_err(TypeCheckerError::struct_member_default_must_be_literal(identifier, default.span()));
+                }
+                self.visit_expression(default, &Some(type_.clone()));
+            }
+
             // Check that the member type is not a tuple.
             if matches!(type_, Type::Tuple(_)) {
                 self.emit_err(TypeCheckerError::composite_data_type_cannot_contain_tuple(
@@ -149,14 +216,19 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
             // If the member is a struct, add it to the struct dependency graph.
             // Note that we have already checked that each member is defined and valid.
+            // Enums are represented by integers, so they are not part of the graph.
             if let Type::Identifier(member_type) = type_ {
-                self.struct_graph.add_edge(input.identifier.name, member_type.name);
+                if self.symbol_table.borrow().lookup_enum(member_type.name).is_none() {
+                    self.struct_graph.add_edge(input.identifier.name, member_type.name);
+                }
             } else if let Type::Array(array_type) = type_ {
                 // Get the base element type.
                 let base_element_type = array_type.base_element_type();
                 // If the base element type is a struct, then add it to the struct dependency graph.
                 if let Type::Identifier(member_type) = base_element_type {
-                    self.struct_graph.add_edge(input.identifier.name, member_type.name);
+                    if self.symbol_table.borrow().lookup_enum(member_type.name).is_none() {
+                        self.struct_graph.add_edge(input.identifier.name, member_type.name);
+                    }
                 }
             }
 
@@ -165,6 +237,45 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                 self.emit_err(TypeCheckerError::struct_cannot_have_member_mode(*span));
             }
         }
+
+        // Check that each method can be called, i.e. that `receiver.<name>(..)` is not a built-in operation.
+        for (name, method) in input.methods.iter() {
+            if Struct::is_reserved_method_name(*name) {
+                self.emit_err(TypeCheckerError::reserved_method_name(name, method.identifier.span));
+            }
+        }
+
+        // Check that the struct provides the members required by each of its interfaces.
+        for interface_name in input.interfaces.iter() {
+            let interface = self.symbol_table.borrow().lookup_interface(interface_name.name).cloned();
+            let Some(interface) = interface else {
+                let (name, span) = (interface_name.name, interface_name.span);
+                let suggestion = self.symbol_table.borrow().similar_interface(name);
+                self.emit_err(TypeCheckerError::unknown_sym("interface", name, suggestion, span));
+                continue;
+            };
+            for required in interface.members.iter() {
+                match input.members.iter().find(|member| member.name() == required.name()) {
+                    Some(member) if !member.type_.eq_flat(&required.type_) => {
+                        self.emit_err(TypeCheckerError::interface_member_type_mismatch(
+                            input.name(),
+                            interface.name(),
+                            member.name(),
+                            &required.type_,
+                            &member.type_,
+                            member.span,
+                        ))
+                    }
+                    Some(_) => {}
+                    None => self.emit_err(TypeCheckerError::missing_interface_member(
+                        input.name(),
+                        interface.name(),
+                        required.name(),
+                        interface_name.span,
+                    )),
+                }
+            }
+        }
     }
 
     fn visit_mapping(&mut self, input: &'a Mapping) {
@@ -204,18 +315,47 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_function(&mut self, function: &'a Function) {
+        self.check_function(function.name(), function)
+    }
+}
+
+impl<'a> TypeChecker<'a> {
+    /// Type checks `function`, whose name in the symbol table is `name`, unless it is unchanged since it was cached.
+    /// Note that the name of a struct method is the name of the function it is lowered to, e.g. `Foo::bar`.
+    pub(crate) fn check_function(&mut self, name: Symbol, function: &'a Function) {
+        let Some(cache) = self.cache else {
+            return self.check_function_uncached(name, function);
+        };
+
+        // Skip the function if it is unchanged, adding the calls it makes to the call graph.
+        let key = self.cache_key(name, function);
+        if let Some(callees) = cache.lookup(name, key) {
+            callees.into_iter().for_each(|callee| self.call_graph.add_edge(name, callee));
+            return;
+        }
+
+        // Otherwise, check the function, caching it only if it checks without errors or warnings.
+        let (err_count, warning_count) = (self.handler.err_count(), self.handler.warning_count());
+        self.check_function_uncached(name, function);
+        let is_clean = self.handler.err_count() == err_count && self.handler.warning_count() == warning_count;
+        cache.update(name, key, is_clean.then(|| self.call_graph.neighbors(name).collect()));
+    }
+
+    /// Type checks `function`, whose name in the symbol table is `name`.
+    fn check_function_uncached(&mut self, name: Symbol, function: &'a Function) {
         // Check that the function's annotations are valid.
-        // Note that Leo does not natively support any specific annotations.
-        for annotation in function.annotations.iter() {
-            // TODO: Change to compiler warning.
-            self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span))
+        self.check_annotations(&function.annotations, &Annotation::KNOWN);
+
+        // Check that a test function has no inputs, since the test runner calls it without any.
+        if let (true, Some(first), Some(last)) = (function.is_test(), function.input.first(), function.input.last()) {
+            self.emit_err(TypeCheckerError::test_function_has_inputs(function.identifier, first.span() + last.span()))
         }
 
         self.variant = Some(function.variant);
 
         // Lookup function metadata in the symbol table.
         // Note that this unwrap is safe since function metadata is stored in a prior pass.
-        let function_index = self.symbol_table.borrow().lookup_fn_symbol(function.identifier.name).unwrap().id;
+        let function_index = self.symbol_table.borrow().lookup_fn_symbol(name).unwrap().id;
 
         // Enter the function's scope.
         self.enter_scope(function_index);
@@ -227,7 +367,7 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         self.has_finalize = false;
 
         // Store the name of the function.
-        self.function = Some(function.name());
+        self.function = Some(name);
 
         // Create a new child scope for the function's parameters and body.
         let scope_index = self.create_child_scope();
@@ -247,6 +387,9 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                 Variant::Transition if input_var.mode() == Mode::Constant => {
                     self.emit_err(TypeCheckerError::transition_function_inputs_cannot_be_const(input_var.span()))
                 }
+                // An inline function is expanded at its call sites, so its parameters may be constant.
+                // The information flow pass checks that the arguments of constant parameters are known at compile time.
+                Variant::Inline if input_var.mode() == Mode::Constant => {}
                 // If the function is not a transition function, then check that the parameters do not have an associated mode.
                 Variant::Standard | Variant::Inline if input_var.mode() != Mode::None => {
                     self.emit_err(TypeCheckerError::regular_function_inputs_cannot_have_modes(input_var.span()))
@@ -260,6 +403,7 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                     type_: input_var.type_(),
                     span: input_var.identifier().span(),
                     declaration: VariableType::Input(input_var.mode()),
+                    value: None,
                 })
             {
                 self.handler.emit_err(err);
@@ -284,9 +428,7 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                     if self.assert_type_is_valid(&function_output.type_, function_output.span) {
                         // If the function is not a transition function, then it cannot output a record.
                         if let Type::Identifier(identifier) = function_output.type_ {
-                            if !matches!(function.variant, Variant::Transition)
-                                && self.symbol_table.borrow().lookup_struct(identifier.name).unwrap().is_record
-                            {
+                            if !matches!(function.variant, Variant::Transition) && self.is_record(identifier.name) {
                                 self.emit_err(TypeCheckerError::function_cannot_output_record(function_output.span));
                             }
                         }
@@ -308,7 +450,7 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
         // If the function has a return type, then check that it has a return.
         if function.output_type != Type::Unit && !self.has_return {
-            self.emit_err(TypeCheckerError::missing_return(function.span));
+            self.emit_missing_return(&function.block, function.span);
         }
 
         // If the function has a finalize block, then check that it has at least one finalize statement.
@@ -353,8 +495,7 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                     }
                     // Check that the input parameter is not a record.
                     if let Type::Identifier(identifier) = input_var.type_() {
-                        // Note that this unwrap is safe, as the type is defined.
-                        if self.symbol_table.borrow().lookup_struct(identifier.name).unwrap().is_record {
+                        if self.is_record(identifier.name) {
                             self.emit_err(TypeCheckerError::finalize_cannot_take_record_as_input(input_var.span()))
                         }
                     }
@@ -368,6 +509,7 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                             type_: input_var.type_(),
                             span: input_var.identifier().span(),
                             declaration: VariableType::Input(input_var.mode()),
+                            value: None,
                         })
                     {
                         self.handler.emit_err(err);
@@ -394,8 +536,7 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                     }
                     // Check that the output is not a record.
                     if let Type::Identifier(identifier) = output_type.type_() {
-                        // Note that this unwrap is safe, as the type is defined.
-                        if self.symbol_table.borrow().lookup_struct(identifier.name).unwrap().is_record {
+                        if self.is_record(identifier.name) {
                             self.emit_err(TypeCheckerError::finalize_cannot_output_record(output_type.span()))
                         }
                     }
@@ -421,7 +562,7 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
             // If the function has a return type, then check that it has a return.
             if finalize.output_type != Type::Unit && !self.has_return {
-                self.emit_err(TypeCheckerError::missing_return(finalize.span));
+                self.emit_missing_return(&finalize.block, finalize.span);
             }
 
             // Exit the scope for the finalize block.
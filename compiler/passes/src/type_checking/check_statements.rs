@@ -14,13 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{TypeChecker, VariableSymbol, VariableType};
+use crate::{parse_integer_literal, TypeChecker, VariableSymbol, VariableType};
 use itertools::Itertools;
 
 use leo_ast::*;
-use leo_errors::TypeCheckerError;
+use leo_errors::{Label, TypeCheckerError, TypeCheckerWarning};
 use leo_span::{Span, Symbol};
 
+use std::collections::HashSet;
+
 impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
     fn visit_statement(&mut self, input: &'a Statement) {
         // No statements can follow a return statement.
@@ -36,9 +38,12 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             Statement::Conditional(stmt) => self.visit_conditional(stmt),
             Statement::Console(stmt) => self.visit_console(stmt),
             Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Declaration(stmt) => self.visit_declaration(stmt),
             Statement::Definition(stmt) => self.visit_definition(stmt),
+            Statement::Err(stmt) => self.visit_err_statement(stmt),
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(stmt) => self.visit_match(stmt),
             Statement::Return(stmt) => self.visit_return(stmt),
         }
     }
@@ -50,8 +55,7 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
                 self.assert_bool_type(&type_, expr.span());
             }
             AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
-                let t1 = self.visit_expression(left, &None);
-                let t2 = self.visit_expression(right, &None);
+                let (t1, t2) = self.visit_operands(left, right);
 
                 // Check that the types are equal.
                 self.check_eq_types(&t1, &t2, input.span());
@@ -60,36 +64,41 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_assign(&mut self, input: &'a AssignStatement) {
-        let var_name = match input.place {
-            Expression::Identifier(id) => id,
+        let var_type = match &input.place {
+            Expression::Identifier(var_name) => self.assignee_type(var_name),
+            // A tuple of variables, as in `(a, b) = f();`, is assigned element by element.
+            Expression::Tuple(tuple) => tuple
+                .elements
+                .iter()
+                .map(|element| match element {
+                    Expression::Identifier(var_name) => self.assignee_type(var_name),
+                    _ => {
+                        self.emit_err(TypeCheckerError::invalid_assignment_target(element.span()));
+                        None
+                    }
+                })
+                // Every element is checked, even if an earlier one is invalid.
+                .collect::<Vec<_>>()
+                .into_iter()
+                .collect::<Option<Vec<_>>>()
+                .map(|types| Type::Tuple(TupleType::new(types))),
             _ => {
                 self.emit_err(TypeCheckerError::invalid_assignment_target(input.place.span()));
-                return;
+                None
             }
         };
 
-        let var_type = if let Some(var) = self.symbol_table.borrow_mut().lookup_variable(var_name.name) {
-            match &var.declaration {
-                VariableType::Const => self.emit_err(TypeCheckerError::cannot_assign_to_const_var(var_name, var.span)),
-                VariableType::Input(Mode::Constant) => {
-                    self.emit_err(TypeCheckerError::cannot_assign_to_const_input(var_name, var.span))
-                }
-                _ => {}
-            }
-
-            Some(var.type_.clone())
-        } else {
-            self.emit_err(TypeCheckerError::unknown_sym("variable", var_name.name, var_name.span));
-
-            None
-        };
-
-        if var_type.is_some() {
-            self.visit_expression(&input.value, &var_type);
-        }
+        // If the target is invalid or unknown, the value is still checked, so that its errors are reported.
+        self.visit_expression(&input.value, &var_type);
     }
 
     fn visit_block(&mut self, input: &'a Block) {
+        // The wrapping desugaring pass removes `@wrapping`, so any remaining block annotation is unknown.
+        input
+            .annotations
+            .iter()
+            .for_each(|annotation| self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span)));
+
         // Create a new scope for the then-block.
         let scope_index = self.create_child_scope();
 
@@ -100,7 +109,10 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
-        self.visit_expression(&input.condition, &Some(Type::Boolean));
+        // The condition is checked without an expected type, so that a non-boolean condition is reported with the
+        // type it was inferred to have.
+        let condition_type = self.visit_expression(&input.condition, &None);
+        self.assert_condition_type(&condition_type, input.condition.span());
 
         let mut then_block_has_return = false;
         let mut otherwise_block_has_return = false;
@@ -108,6 +120,9 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         let mut then_block_has_finalize = false;
         let mut otherwise_block_has_finalize = false;
 
+        // Set the `is_conditional` flag for both blocks.
+        let previous_is_conditional = core::mem::replace(&mut self.is_conditional, true);
+
         // Set the `has_return` flag for the then-block.
         let previous_has_return = core::mem::replace(&mut self.has_return, then_block_has_return);
         // Set the `has_finalize` flag for the then-block.
@@ -141,14 +156,38 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             otherwise_block_has_finalize = self.has_finalize;
         }
 
+        // Restore the previous `is_conditional` flag.
+        self.is_conditional = previous_is_conditional;
         // Restore the previous `has_return` flag.
         self.has_return = previous_has_return || (then_block_has_return && otherwise_block_has_return);
         // Restore the previous `has_finalize` flag.
         self.has_finalize = previous_has_finalize || (then_block_has_finalize && otherwise_block_has_finalize);
     }
 
-    fn visit_console(&mut self, _: &'a ConsoleStatement) {
-        unreachable!("Parsing guarantees that console statements are not present in the AST.");
+    fn visit_console(&mut self, input: &'a ConsoleStatement) {
+        match &input.function {
+            ConsoleFunction::Log(args) => {
+                // Check that there is an argument for each placeholder of the format string.
+                if args.containers() != args.parameters.len() {
+                    self.emit_err(TypeCheckerError::console_log_argument_count_mismatch(
+                        args.containers(),
+                        args.parameters.len(),
+                        input.span,
+                    ));
+                }
+                for parameter in &args.parameters {
+                    if let Some(type_) = self.visit_expression(parameter, &None) {
+                        if !is_printable(&type_) {
+                            self.emit_err(TypeCheckerError::console_log_argument_not_printable(
+                                type_,
+                                parameter.span(),
+                            ));
+                        }
+                    }
+                }
+            }
+            _ => unreachable!("Parsing guarantees that `console.log` is the only console statement in the AST."),
+        }
     }
 
     fn visit_const(&mut self, input: &'a ConstDeclaration) {
@@ -172,13 +211,27 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             _ => (), // Do nothing
         }
 
-        // Enforce that Constant variables have literal expressions on right-hand side
+        // Enforce that Constant variables have literal expressions on right-hand side.
+        // Arrays of literals are allowed, so that constants can be used as lookup tables.
+        fn is_literal_or_array_of_literals(expression: &Expression) -> bool {
+            match expression {
+                Expression::Literal(_) => true,
+                Expression::Array(array) => array.elements.iter().all(is_literal_or_array_of_literals),
+                _ => false,
+            }
+        }
         match &input.value {
-            Expression::Literal(_) => (),
+            Expression::Literal(_) | Expression::Array(_) => {
+                if !is_literal_or_array_of_literals(&input.value) {
+                    self.emit_err(TypeCheckerError::const_declaration_must_be_literal_or_tuple_of_literals(
+                        input.span,
+                    ))
+                }
+            }
             Expression::Tuple(tuple_expression) => match tuple_expression.elements.len() {
                 0 | 1 => unreachable!("Parsing guarantees that tuple types have at least two elements."),
                 _ => {
-                    if tuple_expression.elements.iter().any(|expr| !matches!(expr, Expression::Literal(_))) {
+                    if !tuple_expression.elements.iter().all(is_literal_or_array_of_literals) {
                         self.emit_err(TypeCheckerError::const_declaration_must_be_literal_or_tuple_of_literals(
                             input.span,
                         ))
@@ -191,42 +244,103 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         // Check the expression on the right-hand side.
         self.visit_expression(&input.value, &Some(input.type_.clone()));
 
+        // Record the value of the constant, if it is valid, so that later passes can query it.
+        let is_valid = match &input.value {
+            Expression::Tuple(tuple_expression) => {
+                tuple_expression.elements.iter().all(is_literal_or_array_of_literals)
+            }
+            value => is_literal_or_array_of_literals(value),
+        };
+
         // Add constants to symbol table so that any references to them in later statements will pass TC
         if let Err(err) = self.symbol_table.borrow_mut().insert_variable(input.place.name, VariableSymbol {
             type_: input.type_.clone(),
             span: input.place.span,
             declaration: VariableType::Const,
+            value: is_valid.then(|| input.value.clone()),
         }) {
             self.handler.emit_err(err);
         }
     }
 
-    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
-        // Check that the type of the definition is defined.
+    fn visit_declaration(&mut self, input: &'a DeclarationStatement) {
+        // Check that the type of the declaration is defined.
         self.assert_type_is_valid(&input.type_, input.span);
 
-        // Check that the type of the definition is not a unit type, singleton tuple type, or nested tuple type.
+        // Check that the type of the declaration is not a unit type or nested tuple type.
         match &input.type_ {
-            // If the type is an empty tuple, return an error.
             Type::Unit => self.emit_err(TypeCheckerError::lhs_must_be_identifier_or_tuple(input.span)),
-            // If the type is a singleton tuple, return an error.
-            Type::Tuple(tuple) => match tuple.length() {
-                0 | 1 => unreachable!("Parsing guarantees that tuple types have at least two elements."),
-                _ => {
-                    if tuple.elements().iter().any(|type_| matches!(type_, Type::Tuple(_))) {
-                        self.emit_err(TypeCheckerError::nested_tuple_type(input.span))
-                    }
-                }
-            },
-            Type::Mapping(_) | Type::Err => unreachable!(
-                "Parsing guarantees that `mapping` and `err` types are not present at this location in the AST."
-            ),
-            // Otherwise, the type is valid.
-            _ => (), // Do nothing
+            Type::Tuple(tuple) if tuple.elements().iter().any(|type_| matches!(type_, Type::Tuple(_))) => {
+                self.emit_err(TypeCheckerError::nested_tuple_type(input.span))
+            }
+            _ => (),
         }
 
-        // Check the expression on the right-hand side.
-        self.visit_expression(&input.value, &Some(input.type_.clone()));
+        // Add the variable to the symbol table. Definite assignment checking ensures that it is assigned before it is used.
+        if let Err(err) = self.symbol_table.borrow_mut().insert_variable(input.identifier.name, VariableSymbol {
+            type_: input.type_.clone(),
+            span: input.identifier.span,
+            declaration: VariableType::Mut,
+            value: None,
+        }) {
+            self.handler.emit_err(err);
+        }
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        let type_ = match &input.type_ {
+            Some(type_) => {
+                // Check that the type of the definition is defined.
+                self.assert_type_is_valid(type_, input.span);
+
+                // Check that the type of the definition is not a unit type, singleton tuple type, or nested tuple type.
+                match type_ {
+                    // If the type is an empty tuple, return an error.
+                    Type::Unit => self.emit_err(TypeCheckerError::lhs_must_be_identifier_or_tuple(input.span)),
+                    // If the type is a singleton tuple, return an error.
+                    Type::Tuple(tuple) => match tuple.length() {
+                        0 | 1 => unreachable!("Parsing guarantees that tuple types have at least two elements."),
+                        _ => {
+                            if tuple.elements().iter().any(|type_| matches!(type_, Type::Tuple(_))) {
+                                self.emit_err(TypeCheckerError::nested_tuple_type(input.span))
+                            }
+                        }
+                    },
+                    Type::Mapping(_) | Type::Err => unreachable!(
+                        "Parsing guarantees that `mapping` and `err` types are not present at this location in the AST."
+                    ),
+                    // Otherwise, the type is valid.
+                    _ => (), // Do nothing
+                }
+
+                // Check the expression on the right-hand side.
+                self.visit_expression(&input.value, &Some(type_.clone()));
+
+                type_.clone()
+            }
+            None => {
+                // Infer the type of the definition from the expression on the right-hand side.
+                // If it cannot be inferred, the variables are poisoned with `Type::Err`, rather than left undefined,
+                // so that their uses are not reported as errors, while the rest of the function is still checked.
+                let num_errors = self.handler.err_count();
+                match self.visit_expression(&input.value, &None) {
+                    // A definition cannot bind a value of unit type.
+                    Some(Type::Unit) => {
+                        self.emit_err(TypeCheckerError::lhs_must_be_identifier_or_tuple(input.span));
+                        Type::Err
+                    }
+                    Some(type_) => type_,
+                    None => {
+                        // Only report the failure to infer a type if the expression did not already produce an error,
+                        // and the parser did not fail on it.
+                        if self.handler.err_count() == num_errors && !matches!(input.value, Expression::Err(_)) {
+                            self.emit_err(TypeCheckerError::could_not_determine_type(&input.value, input.value.span()));
+                        }
+                        Type::Err
+                    }
+                }
+            }
+        };
 
         // TODO: Dedup with unrolling pass.
         // Helper to insert the variables into the symbol table.
@@ -235,6 +349,7 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
                 type_,
                 span,
                 declaration: VariableType::Mut,
+                value: None,
             }) {
                 self.handler.emit_err(err);
             }
@@ -242,25 +357,27 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
 
         // Insert the variables into the symbol table.
         match &input.place {
-            Expression::Identifier(identifier) => {
-                insert_variable(identifier.name, input.type_.clone(), identifier.span)
-            }
+            Expression::Identifier(identifier) => insert_variable(identifier.name, type_.clone(), identifier.span),
             Expression::Tuple(tuple_expression) => {
-                let tuple_type = match &input.type_ {
-                    Type::Tuple(tuple_type) => tuple_type,
+                let element_types = match &type_ {
+                    Type::Tuple(tuple_type) if tuple_expression.elements.len() == tuple_type.length() => {
+                        tuple_type.elements().to_vec()
+                    }
+                    Type::Tuple(tuple_type) => {
+                        self.emit_err(TypeCheckerError::incorrect_num_tuple_elements(
+                            tuple_expression.elements.len(),
+                            tuple_type.length(),
+                            input.place.span(),
+                        ));
+                        vec![Type::Err; tuple_expression.elements.len()]
+                    }
+                    Type::Err => vec![Type::Err; tuple_expression.elements.len()],
                     _ => unreachable!(
                         "Type checking guarantees that if the lhs is a tuple, its associated type is also a tuple."
                     ),
                 };
-                if tuple_expression.elements.len() != tuple_type.length() {
-                    return self.emit_err(TypeCheckerError::incorrect_num_tuple_elements(
-                        tuple_expression.elements.len(),
-                        tuple_type.length(),
-                        input.place.span(),
-                    ));
-                }
 
-                tuple_expression.elements.iter().zip_eq(tuple_type.elements().iter()).for_each(
+                tuple_expression.elements.iter().zip_eq(element_types.iter()).for_each(
                     |(expression, type_)| {
                         let identifier = match expression {
                             Expression::Identifier(identifier) => identifier,
@@ -274,10 +391,104 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
                     },
                 );
             }
+            Expression::Struct(pattern) => {
+                // Record the type of the pattern, which the pattern lowering pass gives to the destructured value.
+                self.type_table.insert(pattern.id, type_.clone());
+
+                // The variables bound by the pattern, e.g. `owner` and `a` in `let Token { owner, amount: a } = t;`.
+                // A member bound to anything other than an identifier is reported, and binds no variable.
+                let bindings: Vec<_> = pattern
+                    .members
+                    .iter()
+                    .map(|member| match &member.expression {
+                        None => (member, Some(member.identifier)),
+                        Some(Expression::Identifier(identifier)) => (member, Some(*identifier)),
+                        Some(expression) => {
+                            self.emit_err(TypeCheckerError::struct_pattern_binding_must_be_an_identifier(
+                                expression.span(),
+                            ));
+                            (member, None)
+                        }
+                    })
+                    .collect();
+
+                let struct_ = self.symbol_table.borrow().lookup_struct(pattern.name.name).cloned();
+                match (&type_, struct_) {
+                    (Type::Identifier(name), Some(struct_)) if name.name == struct_.identifier.name => {
+                        // Check that each member is bound at most once, and exists.
+                        let mut bound = HashSet::new();
+                        for (member, binding) in bindings {
+                            let declared =
+                                struct_.members.iter().find(|declared| declared.name() == member.identifier.name);
+                            let member_type = match (bound.insert(member.identifier.name), declared) {
+                                (false, _) => {
+                                    self.emit_err(TypeCheckerError::duplicate_struct_pattern_member(
+                                        member.identifier,
+                                        struct_.identifier,
+                                        member.span(),
+                                    ));
+                                    Type::Err
+                                }
+                                (true, Some(Member { type_, .. })) => type_.clone(),
+                                (true, None) => {
+                                    self.emit_err(TypeCheckerError::invalid_struct_variable(
+                                        member.identifier,
+                                        struct_.identifier,
+                                        member.span(),
+                                    ));
+                                    Type::Err
+                                }
+                            };
+                            // Variables of an erroneous member are poisoned, so that their uses are not reported as errors.
+                            if let Some(binding) = binding {
+                                self.type_table.insert(binding.id, member_type.clone());
+                                insert_variable(binding.name, member_type, binding.span);
+                            }
+                        }
+
+                        // Check that every member is bound.
+                        for Member { identifier, .. } in struct_.members.iter() {
+                            if !bound.contains(&identifier.name) {
+                                self.emit_err(TypeCheckerError::missing_struct_pattern_member(
+                                    struct_.identifier,
+                                    identifier,
+                                    pattern.span(),
+                                ));
+                            }
+                        }
+                    }
+                    (type_, struct_) => {
+                        match struct_ {
+                            None => {
+                                let suggestion = self.symbol_table.borrow().similar_struct(pattern.name.name);
+                                self.emit_err(TypeCheckerError::unknown_sym(
+                                    "struct",
+                                    pattern.name.name,
+                                    suggestion,
+                                    pattern.name.span(),
+                                ));
+                            }
+                            Some(_) if matches!(type_, Type::Err) => {}
+                            Some(struct_) => self.emit_err(TypeCheckerError::type_should_be(
+                                type_,
+                                struct_.identifier,
+                                input.value.span(),
+                            )),
+                        }
+                        // Poison the variables, so that their uses are not reported as errors.
+                        for binding in bindings.into_iter().filter_map(|(_, binding)| binding) {
+                            insert_variable(binding.name, Type::Err, binding.span);
+                        }
+                    }
+                }
+            }
             _ => self.emit_err(TypeCheckerError::lhs_must_be_identifier_or_tuple(input.place.span())),
         }
     }
 
+    // We do not want to panic on `ErrStatement`s in order to propagate as many errors as possible.
+    fn visit_err_statement(&mut self, _input: &'a ErrStatement) {}
+
     fn visit_expression_statement(&mut self, input: &'a ExpressionStatement) {
         // Expression statements can only be function calls.
         if !matches!(
@@ -288,6 +499,18 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         } else {
             // Check the expression.
             self.visit_expression(&input.expression, &None);
+
+            // A call to a core function without side effects, e.g. a hash, does nothing if its result is discarded.
+            if let Expression::Access(AccessExpression::AssociatedFunction(access)) = &input.expression {
+                if let Type::Identifier(module) = &access.ty {
+                    let is_module = self.symbol_table.borrow().lookup_module(module.name).is_some();
+                    let core_function = CoreFunction::from_symbols(module.name, access.name.name);
+                    if !is_module && core_function.map_or(false, |function| !function.has_side_effects()) {
+                        let function = format!("{}::{}", module.name, access.name);
+                        self.emit_warning(TypeCheckerWarning::unused_result(function, input.span()));
+                    }
+                }
+            }
         }
     }
 
@@ -301,8 +524,9 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         // Add the loop variable to the scope of the loop body.
         if let Err(err) = self.symbol_table.borrow_mut().insert_variable(input.variable.name, VariableSymbol {
             type_: input.type_.clone(),
-            span: input.span(),
+            span: input.variable.span,
             declaration: VariableType::Const,
+            value: None,
         }) {
             self.handler.emit_err(err);
         }
@@ -333,7 +557,7 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         match &input.start {
             Expression::Literal(literal) => {
                 // Note that this check is needed because the pass attempts to make progress, even though the literal may be invalid.
-                if let Ok(value) = Value::try_from(literal) {
+                if let Ok(value) = Value::try_from(&literal.clone().with_inferred_type(&input.type_)) {
                     input.start_value.replace(Some(value));
                 }
             }
@@ -353,7 +577,7 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         match &input.stop {
             Expression::Literal(literal) => {
                 // Note that this check is needed because the pass attempts to make progress, even though the literal may be invalid.
-                if let Ok(value) = Value::try_from(literal) {
+                if let Ok(value) = Value::try_from(&literal.clone().with_inferred_type(&input.type_)) {
                     input.stop_value.replace(Some(value));
                 }
             }
@@ -368,20 +592,135 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }
     }
 
+    fn visit_match(&mut self, input: &'a MatchStatement) {
+        // Check that the value is an integer or an enum, against whose type the patterns are checked.
+        let type_ = match self.visit_expression(&input.value, &None) {
+            Some(Type::Integer(integer_type)) => Some(Type::Integer(integer_type)),
+            Some(Type::Identifier(identifier)) if self.symbol_table.borrow().lookup_enum(identifier.name).is_some() => {
+                Some(Type::Identifier(identifier))
+            }
+            Some(Type::Err) | None => None,
+            Some(type_) => {
+                self.emit_err(TypeCheckerError::invalid_match_value(&type_, input.value.span()));
+                None
+            }
+        };
+
+        // The values matched by the patterns so far, and whether a wildcard pattern has been seen.
+        // The variants of an enum are identified by the values that represent them.
+        let mut values = HashSet::new();
+        let mut has_wildcard = false;
+
+        // Set the `is_conditional` flag for the arms, since each arm is only taken for some values.
+        let previous_is_conditional = core::mem::replace(&mut self.is_conditional, true);
+        let previous_has_return = core::mem::take(&mut self.has_return);
+        let previous_has_finalize = core::mem::take(&mut self.has_finalize);
+        let mut arms_have_return = !input.arms.is_empty();
+        let mut arms_have_finalize = !input.arms.is_empty();
+
+        for arm in &input.arms {
+            // An arm after a wildcard, or with a value matched by an earlier arm, can never be taken.
+            // Since the arms are lowered into a chain of conditionals, this is an error rather than a warning.
+            let mut reachable = !has_wildcard;
+            match &arm.pattern {
+                None => has_wildcard = true,
+                Some(pattern) => {
+                    self.visit_expression(pattern, &type_);
+                    match pattern {
+                        Expression::Literal(literal @ (Literal::Integer(..) | Literal::Unsuffixed(..))) => {
+                            if let Some(value) = parse_integer_literal(literal) {
+                                reachable &= values.insert(value);
+                            }
+                        }
+                        Expression::Access(AccessExpression::AssociatedConstant(AssociatedConstant {
+                            ty: Type::Identifier(enum_name),
+                            name,
+                            ..
+                        })) if self.symbol_table.borrow().lookup_enum(enum_name.name).is_some() => {
+                            let index = self
+                                .symbol_table
+                                .borrow()
+                                .lookup_enum(enum_name.name)
+                                .unwrap()
+                                .variant_index(name.name);
+                            if let Some(index) = index {
+                                reachable &= values.insert(index as i128);
+                            }
+                        }
+                        _ => self.emit_err(TypeCheckerError::invalid_match_pattern(pattern, pattern.span())),
+                    }
+                }
+            }
+            if !reachable {
+                self.emit_err(TypeCheckerError::unreachable_match_arm(arm.span));
+            }
+
+            self.visit_block(&arm.block);
+
+            arms_have_return &= core::mem::take(&mut self.has_return);
+            arms_have_finalize &= core::mem::take(&mut self.has_finalize);
+        }
+
+        // Restore the previous `is_conditional` flag.
+        self.is_conditional = previous_is_conditional;
+
+        // Check that every value of the type is matched by some arm.
+        // Without a wildcard, this is only possible for enums and for integer types with few values, e.g. `u8`.
+        let exhaustive = match &type_ {
+            Some(type_) if !has_wildcard => {
+                let exhaustive = match type_ {
+                    Type::Integer(integer_type) => {
+                        let bits = integer_type.bit_width();
+                        bits < 128 && values.len() as u128 == 1u128 << bits
+                    }
+                    Type::Identifier(identifier) => self
+                        .symbol_table
+                        .borrow()
+                        .lookup_enum(identifier.name)
+                        .map_or(true, |enum_| values.len() == enum_.variants.len()),
+                    _ => true,
+                };
+                if !exhaustive {
+                    self.emit_err(TypeCheckerError::non_exhaustive_match(type_, input.span()));
+                }
+                exhaustive
+            }
+            // If the type of the value is erroneous, the error has already been reported.
+            _ => true,
+        };
+
+        // The match returns if every arm returns, and some arm is always taken.
+        self.has_return = previous_has_return || (exhaustive && arms_have_return);
+        self.has_finalize = previous_has_finalize || (exhaustive && arms_have_finalize);
+    }
+
     fn visit_return(&mut self, input: &'a ReturnStatement) {
         // We can safely unwrap all self.parent instances because
         // statements should always have some parent block
         let parent = self.function.unwrap();
-        let return_type = &self.symbol_table.borrow().lookup_fn_symbol(parent).map(|f| match self.is_finalize {
-            // TODO: Check this.
-            // Note that this `unwrap()` is safe since we checked that the function has a finalize block.
-            true => f.finalize.as_ref().unwrap().output_type.clone(),
-            false => f.output_type.clone(),
-        });
+        let (return_type, output_span) = self
+            .symbol_table
+            .borrow()
+            .lookup_fn_symbol(parent)
+            .map(|f| match self.is_finalize {
+                // TODO: Check this.
+                // Note that this `unwrap()` is safe since we checked that the function has a finalize block.
+                true => {
+                    let finalize = f.finalize.as_ref().unwrap();
+                    (finalize.output_type.clone(), finalize.output_span)
+                }
+                false => (f.output_type.clone(), f.output_span),
+            })
+            .unzip();
 
         // Set the `has_return` flag.
         self.has_return = true;
 
+        // Check that the return statement does not return from within a block expression.
+        if self.is_block_expression {
+            self.emit_err(TypeCheckerError::return_in_block_expression(input.span()));
+        }
+
         // Check that the return expression is not a nested tuple.
         if let Expression::Tuple(TupleExpression { elements, .. }) = &input.expression {
             for element in elements {
@@ -393,8 +732,11 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
 
         // Set the `is_return` flag. This is necessary to allow unit expressions in the return statement.
         self.is_return = true;
-        // Type check the associated expression.
-        self.visit_expression(&input.expression, return_type);
+        // Type check the associated expression, labeling a mismatch with the declared output type.
+        let label = output_span.flatten().map(|span| Label::new("expected because of this return type", span));
+        let outer = std::mem::replace(&mut self.expected_type_label, label.map(|l| (input.expression.span(), l)));
+        self.visit_expression(&input.expression, &return_type);
+        self.expected_type_label = outer;
         // Unset the `is_return` flag.
         self.is_return = false;
 
@@ -432,3 +774,14 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }
     }
 }
+
+/// Returns `true` if a value of type `type_` can be logged by `console.log`.
+fn is_printable(type_: &Type) -> bool {
+    match type_ {
+        Type::Array(array) => is_printable(array.element_type()),
+        Type::Tuple(tuple) => tuple.elements().iter().all(is_printable),
+        Type::Mapping(_) | Type::Unit => false,
+        // An erroneous type has already been reported.
+        _ => true,
+    }
+}
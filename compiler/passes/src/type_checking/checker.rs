@@ -14,16 +14,58 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CallGraph, StructGraph, SymbolTable, TypeTable};
-
-use leo_ast::{CoreConstant, CoreFunction, Identifier, IntegerType, MappingType, Node, Type, Variant};
-use leo_errors::{emitter::Handler, TypeCheckerError};
-use leo_span::{Span, Symbol};
+use crate::{
+    validate_address,
+    is_valid_group_element,
+    AddressError,
+    CallGraph,
+    CoreFunctionRegistry,
+    StructGraph,
+    SymbolTable,
+    TypeCheckCache,
+    TypeTable,
+    VariableType,
+    ADDRESS_LENGTH,
+    FIELD_MODULUS,
+    SCALAR_MODULUS,
+};
+
+use leo_ast::{
+    Annotation,
+    AnnotationValue,
+    Ast,
+    BinaryExpression,
+    BinaryOperation,
+    Block,
+    CoreArgument,
+    CoreConstant,
+    CoreFunction,
+    Expression,
+    ExpressionVisitor,
+    GroupLiteral,
+    Identifier,
+    IntegerType,
+    Literal,
+    MappingType,
+    Mode,
+    Node,
+    NodeID,
+    ProgramVisitor,
+    Statement,
+    Struct,
+    TupleType,
+    Type,
+    UnaryOperation,
+    Variant,
+};
+use leo_errors::{emitter::Handler, Label, Result, TypeCheckerError, TypeCheckerWarning};
+use leo_span::{span::BytePos, sym, Span, Symbol};
 
 use snarkvm_console::network::{Network, Testnet3};
 
 use itertools::Itertools;
-use std::cell::RefCell;
+use num_bigint::BigUint;
+use std::{cell::RefCell, collections::HashMap, fmt::Display};
 
 pub struct TypeChecker<'a> {
     /// The symbol table for the program.
@@ -36,6 +78,12 @@ pub struct TypeChecker<'a> {
     pub(crate) call_graph: CallGraph,
     /// The error handler.
     pub(crate) handler: &'a Handler,
+    /// The cache of functions that are skipped if they are unchanged, if checking incrementally.
+    pub(crate) cache: Option<&'a TypeCheckCache>,
+    /// The built-in functions registered by the embedder of the compiler, in addition to the core functions.
+    pub(crate) core_functions: Option<&'a CoreFunctionRegistry>,
+    /// The name of the program that we are currently traversing.
+    pub(crate) program_name: Option<Symbol>,
     /// The name of the function that we are currently traversing.
     pub(crate) function: Option<Symbol>,
     /// The variant of the function that we are currently traversing.
@@ -49,8 +97,25 @@ pub struct TypeChecker<'a> {
     pub(crate) is_finalize: bool,
     /// Whether or not we are currently traversing an imported program.
     pub(crate) is_imported: bool,
+    /// Whether or not we are currently traversing a branch of a conditional statement.
+    pub(crate) is_conditional: bool,
     /// Whether or not we are currently traversing a return statement.
     pub(crate) is_return: bool,
+    /// Whether or not we are currently traversing the statements of a block expression.
+    pub(crate) is_block_expression: bool,
+    /// The types and values of the integer expressions that are known at compile time and in range for their type.
+    pub(crate) constant_integers: HashMap<NodeID, (IntegerType, i128)>,
+    /// The span of the expression being checked against an expected type, and the label of where that type comes from,
+    /// e.g. the parameter that the expression is passed to.
+    pub(crate) expected_type_label: Option<(Span, Label)>,
+}
+
+/// The state of the program scope being type checked, which is shared by the checkers of its functions.
+#[derive(Clone, Copy)]
+pub(crate) struct ScopeState<'a> {
+    program_name: Option<Symbol>,
+    is_imported: bool,
+    core_functions: Option<&'a CoreFunctionRegistry>,
 }
 
 const ADDRESS_TYPE: Type = Type::Address;
@@ -99,6 +164,7 @@ const MAGNITUDE_TYPES: [Type; 3] =
 
 impl<'a> TypeChecker<'a> {
     /// Returns a new type checker given a symbol table and error handler.
+    /// The symbol table may be built by hand, in which case its root scope holds the variables that are in scope.
     pub fn new(symbol_table: SymbolTable, type_table: &'a TypeTable, handler: &'a Handler) -> Self {
         let struct_names = symbol_table.structs.keys().cloned().collect();
 
@@ -111,16 +177,71 @@ impl<'a> TypeChecker<'a> {
             struct_graph: StructGraph::new(struct_names),
             call_graph: CallGraph::new(function_names),
             handler,
+            cache: None,
+            core_functions: None,
+            program_name: None,
             function: None,
             variant: None,
             has_return: false,
             has_finalize: false,
             is_finalize: false,
             is_imported: false,
+            is_conditional: false,
             is_return: false,
+            is_block_expression: false,
+            constant_integers: HashMap::new(),
+            expected_type_label: None,
+        }
+    }
+
+    /// Returns a new type checker for the functions of the program scope described by `scope`, e.g. one that checks
+    /// them on another thread. Unlike the state of the program scope, that of the function being checked is not kept.
+    pub(crate) fn for_scope(
+        symbol_table: SymbolTable,
+        type_table: &'a TypeTable,
+        handler: &'a Handler,
+        scope: ScopeState<'a>,
+    ) -> Self {
+        Self {
+            program_name: scope.program_name,
+            is_imported: scope.is_imported,
+            core_functions: scope.core_functions,
+            ..Self::new(symbol_table, type_table, handler)
         }
     }
 
+    /// Returns the state of the program scope being checked, to be passed to [`TypeChecker::for_scope`].
+    pub(crate) fn scope_state(&self) -> ScopeState<'a> {
+        ScopeState { program_name: self.program_name, is_imported: self.is_imported, core_functions: self.core_functions }
+    }
+
+    /// Skips the functions in `cache` that are unchanged since they were last checked, and adds the functions that
+    /// check without errors or warnings to it.
+    pub fn with_cache(mut self, cache: &'a TypeCheckCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Checks calls to the built-in functions in `registry` against their signatures.
+    pub fn with_core_functions(mut self, registry: &'a CoreFunctionRegistry) -> Self {
+        self.core_functions = Some(registry);
+        self
+    }
+
+    /// Type checks `ast`, returning the symbol table, the struct graph, and the call graph of the program.
+    pub fn run(mut self, ast: &'a Ast) -> Result<(SymbolTable, StructGraph, CallGraph)> {
+        self.visit_program(ast.as_repr());
+        self.handler.last_err().map_err(|e| *e)?;
+
+        Ok((self.symbol_table.take(), self.struct_graph, self.call_graph))
+    }
+
+    /// Type checks a standalone expression in the current scope, returning its type if it could be determined.
+    /// Any errors are emitted to the handler of the type checker.
+    pub fn check_expression(&mut self, input: &'a Expression, expected: Option<Type>) -> Option<Type> {
+        self.visit_expression(input, &expected)
+    }
+
     /// Enters a child scope.
     pub(crate) fn enter_scope(&mut self, index: usize) {
         let previous_symbol_table = std::mem::take(&mut self.symbol_table);
@@ -150,10 +271,63 @@ impl<'a> TypeChecker<'a> {
         self.handler.emit_err(err);
     }
 
+    /// Emits a type checker warning.
+    pub(crate) fn emit_warning(&self, warning: TypeCheckerWarning) {
+        self.handler.emit_warning(warning.into());
+    }
+
+    /// Checks that each of `annotations` is one of `known`, and that only `@deprecated` takes a value, a string.
+    pub(crate) fn check_annotations(&self, annotations: &[Annotation], known: &[Symbol]) {
+        for annotation in annotations {
+            match &annotation.value {
+                _ if !known.contains(&annotation.name()) => {
+                    self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span))
+                }
+                None | Some(AnnotationValue::String(..)) if annotation.is_deprecated() => {}
+                _ if annotation.is_lint_level()
+                    && !annotation.lint().map_or(false, |lint| Annotation::LINTS.contains(&lint)) =>
+                {
+                    let lints = Annotation::LINTS.iter().join(", ");
+                    self.emit_err(TypeCheckerError::annotation_must_name_lint(annotation.identifier, lints, annotation.span))
+                }
+                _ if annotation.is_lint_level() => {}
+                Some(value) if annotation.is_deprecated() => {
+                    self.emit_err(TypeCheckerError::annotation_value_must_be_string(annotation.identifier, value.span()))
+                }
+                Some(value) => {
+                    self.emit_err(TypeCheckerError::annotation_takes_no_value(annotation.identifier, value.span()))
+                }
+                None => {}
+            }
+        }
+    }
+
+    /// Warns that the deprecated `kind` `name` is used at `span`, pointing to its declaration at `declaration`.
+    pub(crate) fn warn_deprecated(
+        &self,
+        kind: &str,
+        name: impl Display,
+        deprecation: &Annotation,
+        declaration: Span,
+        span: Span,
+    ) {
+        let label = Label::new(format!("`{name}` is declared deprecated here"), declaration);
+        self.emit_warning(TypeCheckerWarning::deprecated_item(kind, name, deprecation.message(), span).with_label(label));
+    }
+
+    /// Labels an error about the expression at `span` with where its expected type comes from, if it is known.
+    pub(crate) fn label_expected_type(&self, err: TypeCheckerError, span: Span) -> TypeCheckerError {
+        match &self.expected_type_label {
+            Some((expression, label)) if *expression == span => err.with_label(label.clone()),
+            _ => err,
+        }
+    }
+
     /// Emits an error to the handler if the given type is invalid.
+    /// The type `Type::Err` is always valid, since the error that produced it has already been reported.
     fn check_type(&self, is_valid: impl Fn(&Type) -> bool, error_string: String, type_: &Option<Type>, span: Span) {
         if let Some(type_) = type_ {
-            if !is_valid(type_) {
+            if !matches!(type_, Type::Err) && !is_valid(type_) {
                 self.emit_err(TypeCheckerError::expected_one_type_of(error_string, type_, span));
             }
         }
@@ -162,6 +336,8 @@ impl<'a> TypeChecker<'a> {
     /// Emits an error if the two given types are not equal.
     pub(crate) fn check_eq_types(&self, t1: &Option<Type>, t2: &Option<Type>, span: Span) {
         match (t1, t2) {
+            // An erroneous type has already been reported.
+            (Some(Type::Err), _) | (_, Some(Type::Err)) => {}
             (Some(t1), Some(t2)) if !Type::eq_flat(t1, t2) => {
                 self.emit_err(TypeCheckerError::type_should_be(t1, t2, span))
             }
@@ -176,8 +352,9 @@ impl<'a> TypeChecker<'a> {
     /// Emits an error to the handler if the `actual` type is not equal to the `expected` type.
     pub(crate) fn assert_and_return_type(&self, actual: Type, expected: &Option<Type>, span: Span) -> Type {
         if let Some(expected) = expected {
-            if !actual.eq_flat(expected) {
-                self.emit_err(TypeCheckerError::type_should_be(actual.clone(), expected, span));
+            if !matches!((&actual, expected), (Type::Err, _) | (_, Type::Err)) && !actual.eq_flat(expected) {
+                let err = TypeCheckerError::type_should_be(actual.clone(), expected, span);
+                self.emit_err(self.label_expected_type(err, span));
             }
         }
         actual
@@ -193,6 +370,15 @@ impl<'a> TypeChecker<'a> {
         self.check_type(|type_: &Type| ADDRESS_TYPE.eq(type_), ADDRESS_TYPE.to_string(), type_, span)
     }
 
+    /// Emits an error to the handler if the condition of a conditional statement, of type `type_`, is not a boolean.
+    pub(crate) fn assert_condition_type(&self, type_: &Option<Type>, span: Span) {
+        if let Some(type_) = type_ {
+            if !matches!(type_, Type::Err) && !BOOLEAN_TYPE.eq(type_) {
+                self.emit_err(TypeCheckerError::condition_not_boolean(type_, span));
+            }
+        }
+    }
+
     /// Emits an error to the handler if the given type is not a boolean.
     pub(crate) fn assert_bool_type(&self, type_: &Option<Type>, span: Span) {
         self.check_type(|type_: &Type| BOOLEAN_TYPE.eq(type_), BOOLEAN_TYPE.to_string(), type_, span)
@@ -382,654 +568,460 @@ impl<'a> TypeChecker<'a> {
     pub(crate) fn check_core_function_call(
         &self,
         core_function: CoreFunction,
-        arguments: &[(Option<Type>, Span)],
+        arguments: &[(Option<Type>, &Expression)],
         function_span: Span,
     ) -> Option<Type> {
         // Check that the number of arguments is correct.
-        if arguments.len() != core_function.num_args() {
+        let expected_arguments = core_function.arguments();
+        if arguments.len() != expected_arguments.len() {
             self.emit_err(TypeCheckerError::incorrect_num_args_to_call(
-                core_function.num_args(),
+                expected_arguments.len(),
                 arguments.len(),
                 function_span,
             ));
             return None;
         }
 
-        // Helper to check that the type of argument is not a mapping, tuple, err, or unit type.
-        let check_not_mapping_tuple_err_unit = |type_: &Option<Type>, span: &Span| {
-            self.check_type(
-                |type_: &Type| !matches!(type_, Type::Mapping(_) | Type::Tuple(_) | Type::Err | Type::Unit),
-                "address, bool, field, group, struct, integer, scalar, struct".to_string(),
-                type_,
-                *span,
-            );
-        };
-
-        // Helper to check that the type of the argument is a valid input to a Pedersen hash/commit with 64-bit inputs.
-        // The console types in snarkVM have some overhead in their bitwise representation. Consequently, Pedersen64 cannot accept a u64.
-        let check_pedersen_64_bit_input = |type_: &Option<Type>, span: &Span| {
-            self.check_type(
-                |type_: &Type| {
-                    !matches!(
-                        type_,
-                        Type::Integer(IntegerType::U64)
-                            | Type::Integer(IntegerType::I64)
-                            | Type::Integer(IntegerType::U128)
-                            | Type::Integer(IntegerType::I128)
-                            | Type::Mapping(_)
-                            | Type::Tuple(_)
-                            | Type::Err
-                            | Type::Unit
-                    )
-                },
-                "address, bool, field, group, struct, integer, scalar, struct".to_string(),
-                type_,
-                *span,
-            );
+        // Check that mapping operations are invoked in a `finalize` block.
+        let mapping_operation = match core_function {
+            CoreFunction::MappingGet => Some("Mapping::get"),
+            CoreFunction::MappingGetOrUse => Some("Mapping::get_or"),
+            CoreFunction::MappingSet => Some("Mapping::set"),
+            CoreFunction::MappingRemove => Some("Mapping::remove"),
+            CoreFunction::MappingContains => Some("Mapping::contains"),
+            _ => None,
         };
+        if let (Some(operation), false) = (mapping_operation, self.is_finalize) {
+            self.emit_err(TypeCheckerError::invalid_operation_outside_finalize(operation, function_span));
+        }
 
-        // Helper to check that the type of the argument is a valid input to a Pedersen hash/commit with 128-bit inputs.
-        // The console types in snarkVM have some overhead in their bitwise representation. Consequently, Pedersen128 cannot accept a u128.
-        let check_pedersen_128_bit_input = |type_: &Option<Type>, span: &Span| {
-            self.check_type(
-                |type_: &Type| {
-                    !matches!(
-                        type_,
-                        Type::Integer(IntegerType::U128)
-                            | Type::Integer(IntegerType::I128)
-                            | Type::Mapping(_)
-                            | Type::Tuple(_)
-                            | Type::Err
-                            | Type::Unit
-                    )
+        // Check that the arguments are of the correct type, reporting errors at the span of each argument.
+        let mut mapping_type = None;
+        for (expected, (type_, argument)) in expected_arguments.iter().zip_eq(arguments) {
+            match expected {
+                CoreArgument::Mapping => match self.assert_mapping_type(type_, argument.span()) {
+                    Some(type_) => mapping_type = Some(type_),
+                    // The remaining arguments cannot be checked without the mapping type.
+                    None => return None,
                 },
-                "address, bool, field, group, struct, integer, scalar, struct".to_string(),
-                type_,
-                *span,
-            );
-        };
+                CoreArgument::MappingKey => {
+                    if let Some(mapping_type) = &mapping_type {
+                        self.assert_type(type_, &mapping_type.key, argument.span());
+                    }
+                }
+                CoreArgument::MappingValue => {
+                    if let Some(mapping_type) = &mapping_type {
+                        self.assert_type(type_, &mapping_type.value, argument.span());
+                    }
+                }
+                _ => self.check_core_argument(expected, type_, argument),
+            }
+        }
 
-        // Check that the arguments are of the correct type.
+        // Return the output type.
         match core_function {
+            CoreFunction::MappingGet | CoreFunction::MappingGetOrUse => mapping_type.map(|type_| *type_.value),
+            CoreFunction::MappingSet | CoreFunction::MappingRemove => Some(Type::Unit),
+            CoreFunction::MappingContains => Some(Type::Boolean),
+            CoreFunction::ArrayLookup => match &arguments[0].0 {
+                Some(Type::Array(array_type)) => Some(array_type.element_type().clone()),
+                _ => None,
+            },
+            // The bits and bytes of a field or a group element are those of its canonical 253-bit representation, or
+            // of its x-coordinate, padded to 32 bytes.
+            CoreFunction::I8ToBitsLe | CoreFunction::U8ToBitsLe => Some(CoreFunction::bits_le_type(1)),
+            CoreFunction::I16ToBitsLe | CoreFunction::U16ToBitsLe => Some(CoreFunction::bits_le_type(2)),
+            CoreFunction::I32ToBitsLe | CoreFunction::U32ToBitsLe => Some(CoreFunction::bits_le_type(4)),
+            CoreFunction::I64ToBitsLe | CoreFunction::U64ToBitsLe => Some(CoreFunction::bits_le_type(8)),
+            CoreFunction::I128ToBitsLe | CoreFunction::U128ToBitsLe => Some(CoreFunction::bits_le_type(16)),
+            CoreFunction::FieldToBitsLe | CoreFunction::GroupToBitsLe => Some(CoreFunction::bits_le_type(32)),
+            CoreFunction::I8ToBytesLe | CoreFunction::U8ToBytesLe => Some(CoreFunction::bytes_le_type(1)),
+            CoreFunction::I16ToBytesLe | CoreFunction::U16ToBytesLe => Some(CoreFunction::bytes_le_type(2)),
+            CoreFunction::I32ToBytesLe | CoreFunction::U32ToBytesLe => Some(CoreFunction::bytes_le_type(4)),
+            CoreFunction::I64ToBytesLe | CoreFunction::U64ToBytesLe => Some(CoreFunction::bytes_le_type(8)),
+            CoreFunction::I128ToBytesLe | CoreFunction::U128ToBytesLe => Some(CoreFunction::bytes_le_type(16)),
+            CoreFunction::FieldToBytesLe | CoreFunction::GroupToBytesLe => Some(CoreFunction::bytes_le_type(32)),
+            // An encryption is a pair of the randomness commitment and the masked message.
+            CoreFunction::ElGamalEncrypt => Some(Type::Tuple(TupleType::new(vec![Type::Group, Type::Group]))),
             CoreFunction::BHP256CommitToAddress
+            | CoreFunction::BHP256HashToAddress
             | CoreFunction::BHP512CommitToAddress
-            | CoreFunction::BHP768CommitToAddress
-            | CoreFunction::BHP1024CommitToAddress => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                // Check that the second argument is a scalar.
-                self.assert_scalar_type(&arguments[1].0, arguments[1].1);
-                Some(Type::Address)
-            }
-            CoreFunction::BHP256CommitToField
-            | CoreFunction::BHP512CommitToField
-            | CoreFunction::BHP768CommitToField
-            | CoreFunction::BHP1024CommitToField => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                // Check that the second argument is a scalar.
-                self.assert_scalar_type(&arguments[1].0, arguments[1].1);
-                Some(Type::Field)
-            }
-            CoreFunction::BHP256CommitToGroup
-            | CoreFunction::BHP512CommitToGroup
-            | CoreFunction::BHP768CommitToGroup
-            | CoreFunction::BHP1024CommitToGroup => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                // Check that the second argument is a scalar.
-                self.assert_scalar_type(&arguments[1].0, arguments[1].1);
-                Some(Type::Group)
-            }
-            CoreFunction::BHP256HashToAddress
             | CoreFunction::BHP512HashToAddress
+            | CoreFunction::BHP768CommitToAddress
             | CoreFunction::BHP768HashToAddress
+            | CoreFunction::BHP1024CommitToAddress
             | CoreFunction::BHP1024HashToAddress
+            | CoreFunction::ChaChaRandAddress
             | CoreFunction::Keccak256HashToAddress
             | CoreFunction::Keccak384HashToAddress
             | CoreFunction::Keccak512HashToAddress
+            | CoreFunction::Pedersen64CommitToAddress
+            | CoreFunction::Pedersen64HashToAddress
+            | CoreFunction::Pedersen128CommitToAddress
+            | CoreFunction::Pedersen128HashToAddress
             | CoreFunction::Poseidon2HashToAddress
             | CoreFunction::Poseidon4HashToAddress
             | CoreFunction::Poseidon8HashToAddress
             | CoreFunction::SHA3_256HashToAddress
             | CoreFunction::SHA3_384HashToAddress
-            | CoreFunction::SHA3_512HashToAddress => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                Some(Type::Address)
-            }
-            CoreFunction::BHP256HashToField
+            | CoreFunction::SHA3_512HashToAddress
+            | CoreFunction::AddressFromField
+            | CoreFunction::AddressFromGroup => Some(Type::Address),
+            CoreFunction::BHP256CommitToField
+            | CoreFunction::BHP256HashToField
+            | CoreFunction::BHP512CommitToField
             | CoreFunction::BHP512HashToField
+            | CoreFunction::BHP768CommitToField
             | CoreFunction::BHP768HashToField
+            | CoreFunction::BHP1024CommitToField
             | CoreFunction::BHP1024HashToField
+            | CoreFunction::ChaChaRandField
             | CoreFunction::Keccak256HashToField
             | CoreFunction::Keccak384HashToField
             | CoreFunction::Keccak512HashToField
+            | CoreFunction::Pedersen64CommitToField
+            | CoreFunction::Pedersen64HashToField
+            | CoreFunction::Pedersen128CommitToField
+            | CoreFunction::Pedersen128HashToField
             | CoreFunction::Poseidon2HashToField
+            | CoreFunction::Poseidon2HashManyToField
             | CoreFunction::Poseidon4HashToField
+            | CoreFunction::Poseidon4HashManyToField
             | CoreFunction::Poseidon8HashToField
+            | CoreFunction::Poseidon8HashManyToField
             | CoreFunction::SHA3_256HashToField
             | CoreFunction::SHA3_384HashToField
-            | CoreFunction::SHA3_512HashToField => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                Some(Type::Field)
-            }
-            CoreFunction::BHP256HashToGroup
+            | CoreFunction::SHA3_512HashToField
+            | CoreFunction::GroupToXCoordinate
+            | CoreFunction::GroupToYCoordinate
+            | CoreFunction::FieldFromI8
+            | CoreFunction::FieldFromI16
+            | CoreFunction::FieldFromI32
+            | CoreFunction::FieldFromI64
+            | CoreFunction::FieldFromI128
+            | CoreFunction::FieldFromU8
+            | CoreFunction::FieldFromU16
+            | CoreFunction::FieldFromU32
+            | CoreFunction::FieldFromU64
+            | CoreFunction::FieldFromU128
+            | CoreFunction::AddressToField
+            | CoreFunction::FieldFromBitsLe
+            | CoreFunction::FieldFromBytesLe => Some(Type::Field),
+            CoreFunction::BHP256CommitToGroup
+            | CoreFunction::BHP256HashToGroup
+            | CoreFunction::BHP512CommitToGroup
             | CoreFunction::BHP512HashToGroup
+            | CoreFunction::BHP768CommitToGroup
             | CoreFunction::BHP768HashToGroup
+            | CoreFunction::BHP1024CommitToGroup
             | CoreFunction::BHP1024HashToGroup
+            | CoreFunction::ChaChaRandGroup
             | CoreFunction::Keccak256HashToGroup
             | CoreFunction::Keccak384HashToGroup
             | CoreFunction::Keccak512HashToGroup
+            | CoreFunction::Pedersen64CommitToGroup
+            | CoreFunction::Pedersen64HashToGroup
+            | CoreFunction::Pedersen128CommitToGroup
+            | CoreFunction::Pedersen128HashToGroup
             | CoreFunction::Poseidon2HashToGroup
             | CoreFunction::Poseidon4HashToGroup
             | CoreFunction::Poseidon8HashToGroup
             | CoreFunction::SHA3_256HashToGroup
             | CoreFunction::SHA3_384HashToGroup
-            | CoreFunction::SHA3_512HashToGroup => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                Some(Type::Group)
-            }
+            | CoreFunction::SHA3_512HashToGroup
+            | CoreFunction::AddressToGroup
+            | CoreFunction::GroupFromBitsLe
+            | CoreFunction::GroupFromBytesLe => Some(Type::Group),
             CoreFunction::BHP256HashToI8
             | CoreFunction::BHP512HashToI8
             | CoreFunction::BHP768HashToI8
             | CoreFunction::BHP1024HashToI8
+            | CoreFunction::ChaChaRandI8
             | CoreFunction::Keccak256HashToI8
             | CoreFunction::Keccak384HashToI8
             | CoreFunction::Keccak512HashToI8
+            | CoreFunction::Pedersen64HashToI8
+            | CoreFunction::Pedersen128HashToI8
             | CoreFunction::Poseidon2HashToI8
             | CoreFunction::Poseidon4HashToI8
             | CoreFunction::Poseidon8HashToI8
             | CoreFunction::SHA3_256HashToI8
             | CoreFunction::SHA3_384HashToI8
-            | CoreFunction::SHA3_512HashToI8 => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I8))
-            }
+            | CoreFunction::SHA3_512HashToI8
+            | CoreFunction::I8TryFromField
+            | CoreFunction::I8FromFieldWrapped
+            | CoreFunction::I8FromBitsLe
+            | CoreFunction::I8FromBytesLe => Some(Type::Integer(IntegerType::I8)),
             CoreFunction::BHP256HashToI16
             | CoreFunction::BHP512HashToI16
             | CoreFunction::BHP768HashToI16
             | CoreFunction::BHP1024HashToI16
+            | CoreFunction::ChaChaRandI16
             | CoreFunction::Keccak256HashToI16
             | CoreFunction::Keccak384HashToI16
             | CoreFunction::Keccak512HashToI16
+            | CoreFunction::Pedersen64HashToI16
+            | CoreFunction::Pedersen128HashToI16
             | CoreFunction::Poseidon2HashToI16
             | CoreFunction::Poseidon4HashToI16
             | CoreFunction::Poseidon8HashToI16
             | CoreFunction::SHA3_256HashToI16
             | CoreFunction::SHA3_384HashToI16
-            | CoreFunction::SHA3_512HashToI16 => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I16))
-            }
+            | CoreFunction::SHA3_512HashToI16
+            | CoreFunction::I16TryFromField
+            | CoreFunction::I16FromFieldWrapped
+            | CoreFunction::I16FromBitsLe
+            | CoreFunction::I16FromBytesLe => Some(Type::Integer(IntegerType::I16)),
             CoreFunction::BHP256HashToI32
             | CoreFunction::BHP512HashToI32
             | CoreFunction::BHP768HashToI32
             | CoreFunction::BHP1024HashToI32
+            | CoreFunction::ChaChaRandI32
             | CoreFunction::Keccak256HashToI32
             | CoreFunction::Keccak384HashToI32
             | CoreFunction::Keccak512HashToI32
+            | CoreFunction::Pedersen64HashToI32
+            | CoreFunction::Pedersen128HashToI32
             | CoreFunction::Poseidon2HashToI32
             | CoreFunction::Poseidon4HashToI32
             | CoreFunction::Poseidon8HashToI32
             | CoreFunction::SHA3_256HashToI32
             | CoreFunction::SHA3_384HashToI32
-            | CoreFunction::SHA3_512HashToI32 => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I32))
-            }
+            | CoreFunction::SHA3_512HashToI32
+            | CoreFunction::I32TryFromField
+            | CoreFunction::I32FromFieldWrapped
+            | CoreFunction::I32FromBitsLe
+            | CoreFunction::I32FromBytesLe => Some(Type::Integer(IntegerType::I32)),
             CoreFunction::BHP256HashToI64
             | CoreFunction::BHP512HashToI64
             | CoreFunction::BHP768HashToI64
             | CoreFunction::BHP1024HashToI64
+            | CoreFunction::ChaChaRandI64
             | CoreFunction::Keccak256HashToI64
             | CoreFunction::Keccak384HashToI64
             | CoreFunction::Keccak512HashToI64
+            | CoreFunction::Pedersen64HashToI64
+            | CoreFunction::Pedersen128HashToI64
             | CoreFunction::Poseidon2HashToI64
             | CoreFunction::Poseidon4HashToI64
             | CoreFunction::Poseidon8HashToI64
             | CoreFunction::SHA3_256HashToI64
             | CoreFunction::SHA3_384HashToI64
-            | CoreFunction::SHA3_512HashToI64 => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I64))
-            }
+            | CoreFunction::SHA3_512HashToI64
+            | CoreFunction::I64TryFromField
+            | CoreFunction::I64FromFieldWrapped
+            | CoreFunction::I64FromBitsLe
+            | CoreFunction::I64FromBytesLe => Some(Type::Integer(IntegerType::I64)),
             CoreFunction::BHP256HashToI128
             | CoreFunction::BHP512HashToI128
             | CoreFunction::BHP768HashToI128
             | CoreFunction::BHP1024HashToI128
+            | CoreFunction::ChaChaRandI128
             | CoreFunction::Keccak256HashToI128
             | CoreFunction::Keccak384HashToI128
             | CoreFunction::Keccak512HashToI128
+            | CoreFunction::Pedersen64HashToI128
+            | CoreFunction::Pedersen128HashToI128
             | CoreFunction::Poseidon2HashToI128
             | CoreFunction::Poseidon4HashToI128
             | CoreFunction::Poseidon8HashToI128
             | CoreFunction::SHA3_256HashToI128
             | CoreFunction::SHA3_384HashToI128
-            | CoreFunction::SHA3_512HashToI128 => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I128))
-            }
+            | CoreFunction::SHA3_512HashToI128
+            | CoreFunction::I128TryFromField
+            | CoreFunction::I128FromFieldWrapped
+            | CoreFunction::I128FromBitsLe
+            | CoreFunction::I128FromBytesLe => Some(Type::Integer(IntegerType::I128)),
             CoreFunction::BHP256HashToU8
             | CoreFunction::BHP512HashToU8
             | CoreFunction::BHP768HashToU8
             | CoreFunction::BHP1024HashToU8
+            | CoreFunction::ChaChaRandU8
             | CoreFunction::Keccak256HashToU8
             | CoreFunction::Keccak384HashToU8
             | CoreFunction::Keccak512HashToU8
+            | CoreFunction::Pedersen64HashToU8
+            | CoreFunction::Pedersen128HashToU8
             | CoreFunction::Poseidon2HashToU8
             | CoreFunction::Poseidon4HashToU8
             | CoreFunction::Poseidon8HashToU8
             | CoreFunction::SHA3_256HashToU8
             | CoreFunction::SHA3_384HashToU8
-            | CoreFunction::SHA3_512HashToU8 => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U8))
-            }
+            | CoreFunction::SHA3_512HashToU8
+            | CoreFunction::U8TryFromField
+            | CoreFunction::U8FromFieldWrapped
+            | CoreFunction::U8FromBitsLe
+            | CoreFunction::U8FromBytesLe => Some(Type::Integer(IntegerType::U8)),
             CoreFunction::BHP256HashToU16
             | CoreFunction::BHP512HashToU16
             | CoreFunction::BHP768HashToU16
             | CoreFunction::BHP1024HashToU16
+            | CoreFunction::ChaChaRandU16
             | CoreFunction::Keccak256HashToU16
             | CoreFunction::Keccak384HashToU16
             | CoreFunction::Keccak512HashToU16
+            | CoreFunction::Pedersen64HashToU16
+            | CoreFunction::Pedersen128HashToU16
             | CoreFunction::Poseidon2HashToU16
             | CoreFunction::Poseidon4HashToU16
             | CoreFunction::Poseidon8HashToU16
             | CoreFunction::SHA3_256HashToU16
             | CoreFunction::SHA3_384HashToU16
-            | CoreFunction::SHA3_512HashToU16 => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U16))
-            }
+            | CoreFunction::SHA3_512HashToU16
+            | CoreFunction::U16TryFromField
+            | CoreFunction::U16FromFieldWrapped
+            | CoreFunction::U16FromBitsLe
+            | CoreFunction::U16FromBytesLe => Some(Type::Integer(IntegerType::U16)),
             CoreFunction::BHP256HashToU32
             | CoreFunction::BHP512HashToU32
             | CoreFunction::BHP768HashToU32
             | CoreFunction::BHP1024HashToU32
+            | CoreFunction::ChaChaRandU32
             | CoreFunction::Keccak256HashToU32
             | CoreFunction::Keccak384HashToU32
             | CoreFunction::Keccak512HashToU32
+            | CoreFunction::Pedersen64HashToU32
+            | CoreFunction::Pedersen128HashToU32
             | CoreFunction::Poseidon2HashToU32
             | CoreFunction::Poseidon4HashToU32
             | CoreFunction::Poseidon8HashToU32
             | CoreFunction::SHA3_256HashToU32
             | CoreFunction::SHA3_384HashToU32
-            | CoreFunction::SHA3_512HashToU32 => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U32))
-            }
+            | CoreFunction::SHA3_512HashToU32
+            | CoreFunction::U32TryFromField
+            | CoreFunction::U32FromFieldWrapped
+            | CoreFunction::U32FromBitsLe
+            | CoreFunction::U32FromBytesLe => Some(Type::Integer(IntegerType::U32)),
             CoreFunction::BHP256HashToU64
             | CoreFunction::BHP512HashToU64
             | CoreFunction::BHP768HashToU64
             | CoreFunction::BHP1024HashToU64
+            | CoreFunction::ChaChaRandU64
             | CoreFunction::Keccak256HashToU64
             | CoreFunction::Keccak384HashToU64
             | CoreFunction::Keccak512HashToU64
+            | CoreFunction::Pedersen64HashToU64
+            | CoreFunction::Pedersen128HashToU64
             | CoreFunction::Poseidon2HashToU64
             | CoreFunction::Poseidon4HashToU64
             | CoreFunction::Poseidon8HashToU64
             | CoreFunction::SHA3_256HashToU64
             | CoreFunction::SHA3_384HashToU64
-            | CoreFunction::SHA3_512HashToU64 => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U64))
-            }
+            | CoreFunction::SHA3_512HashToU64
+            | CoreFunction::U64TryFromField
+            | CoreFunction::U64FromFieldWrapped
+            | CoreFunction::U64FromBitsLe
+            | CoreFunction::U64FromBytesLe => Some(Type::Integer(IntegerType::U64)),
             CoreFunction::BHP256HashToU128
             | CoreFunction::BHP512HashToU128
             | CoreFunction::BHP768HashToU128
             | CoreFunction::BHP1024HashToU128
+            | CoreFunction::ChaChaRandU128
             | CoreFunction::Keccak256HashToU128
             | CoreFunction::Keccak384HashToU128
             | CoreFunction::Keccak512HashToU128
+            | CoreFunction::Pedersen64HashToU128
+            | CoreFunction::Pedersen128HashToU128
             | CoreFunction::Poseidon2HashToU128
             | CoreFunction::Poseidon4HashToU128
             | CoreFunction::Poseidon8HashToU128
             | CoreFunction::SHA3_256HashToU128
             | CoreFunction::SHA3_384HashToU128
-            | CoreFunction::SHA3_512HashToU128 => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U128))
-            }
+            | CoreFunction::SHA3_512HashToU128
+            | CoreFunction::U128TryFromField
+            | CoreFunction::U128FromFieldWrapped
+            | CoreFunction::U128FromBitsLe
+            | CoreFunction::U128FromBytesLe => Some(Type::Integer(IntegerType::U128)),
             CoreFunction::BHP256HashToScalar
             | CoreFunction::BHP512HashToScalar
             | CoreFunction::BHP768HashToScalar
             | CoreFunction::BHP1024HashToScalar
+            | CoreFunction::ChaChaRandScalar
             | CoreFunction::Keccak256HashToScalar
             | CoreFunction::Keccak384HashToScalar
             | CoreFunction::Keccak512HashToScalar
+            | CoreFunction::Pedersen64HashToScalar
+            | CoreFunction::Pedersen128HashToScalar
             | CoreFunction::Poseidon2HashToScalar
             | CoreFunction::Poseidon4HashToScalar
             | CoreFunction::Poseidon8HashToScalar
             | CoreFunction::SHA3_256HashToScalar
             | CoreFunction::SHA3_384HashToScalar
-            | CoreFunction::SHA3_512HashToScalar => {
-                // Check that the first argument is not a mapping, tuple, err, or unit type.
-                check_not_mapping_tuple_err_unit(&arguments[0].0, &arguments[0].1);
-                Some(Type::Scalar)
-            }
-            CoreFunction::Pedersen64CommitToAddress => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                // Check that the second argument is a scalar.
-                self.assert_scalar_type(&arguments[1].0, arguments[1].1);
-
-                Some(Type::Address)
-            }
-            CoreFunction::Pedersen64CommitToField => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                // Check that the second argument is a scalar.
-                self.assert_scalar_type(&arguments[1].0, arguments[1].1);
-
-                Some(Type::Field)
-            }
-            CoreFunction::Pedersen64CommitToGroup => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                // Check that the second argument is a scalar.
-                self.assert_scalar_type(&arguments[1].0, arguments[1].1);
-
-                Some(Type::Group)
-            }
-            CoreFunction::Pedersen64HashToAddress => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Address)
-            }
-            CoreFunction::Pedersen64HashToField => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Field)
-            }
-            CoreFunction::Pedersen64HashToGroup => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Group)
-            }
-            CoreFunction::Pedersen64HashToI8 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I8))
-            }
-            CoreFunction::Pedersen64HashToI16 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I16))
-            }
-            CoreFunction::Pedersen64HashToI32 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I32))
-            }
-            CoreFunction::Pedersen64HashToI64 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I64))
-            }
-            CoreFunction::Pedersen64HashToI128 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I128))
-            }
-            CoreFunction::Pedersen64HashToU8 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U8))
-            }
-            CoreFunction::Pedersen64HashToU16 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U16))
-            }
-            CoreFunction::Pedersen64HashToU32 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U32))
-            }
-            CoreFunction::Pedersen64HashToU64 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U64))
-            }
-            CoreFunction::Pedersen64HashToU128 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U128))
-            }
-            CoreFunction::Pedersen64HashToScalar => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 32 bits.
-                check_pedersen_64_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Scalar)
-            }
-            CoreFunction::Pedersen128CommitToAddress => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                // Check that the second argument is a scalar.
-                self.assert_scalar_type(&arguments[1].0, arguments[1].1);
-
-                Some(Type::Address)
-            }
-            CoreFunction::Pedersen128CommitToField => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                // Check that the second argument is a scalar.
-                self.assert_scalar_type(&arguments[1].0, arguments[1].1);
-
-                Some(Type::Field)
-            }
-            CoreFunction::Pedersen128CommitToGroup => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                // Check that the second argument is a scalar.
-                self.assert_scalar_type(&arguments[1].0, arguments[1].1);
+            | CoreFunction::SHA3_512HashToScalar => Some(Type::Scalar),
+            CoreFunction::ChaChaRandBool | CoreFunction::SignatureVerify => Some(Type::Boolean),
+        }
+    }
 
-                Some(Type::Group)
-            }
-            CoreFunction::Pedersen128HashToAddress => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Address)
-            }
-            CoreFunction::Pedersen128HashToField => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Field)
-            }
-            CoreFunction::Pedersen128HashToGroup => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Group)
-            }
-            CoreFunction::Pedersen128HashToI8 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I8))
-            }
-            CoreFunction::Pedersen128HashToI16 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I16))
-            }
-            CoreFunction::Pedersen128HashToI32 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I32))
-            }
-            CoreFunction::Pedersen128HashToI64 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I64))
-            }
-            CoreFunction::Pedersen128HashToI128 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::I128))
-            }
-            CoreFunction::Pedersen128HashToU8 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U8))
-            }
-            CoreFunction::Pedersen128HashToU16 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U16))
-            }
-            CoreFunction::Pedersen128HashToU32 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U32))
-            }
-            CoreFunction::Pedersen128HashToU64 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U64))
-            }
-            CoreFunction::Pedersen128HashToU128 => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Integer(IntegerType::U128))
-            }
-            CoreFunction::Pedersen128HashToScalar => {
-                // Check that the first argument is not a mapping, tuple, err, unit type, or integer over 64 bits.
-                check_pedersen_128_bit_input(&arguments[0].0, &arguments[0].1);
-                Some(Type::Scalar)
-            }
-            CoreFunction::MappingGet => {
-                // Check that the operation is invoked in a `finalize` block.
-                if !self.is_finalize {
-                    self.handler
-                        .emit_err(TypeCheckerError::invalid_operation_outside_finalize("Mapping::get", function_span))
-                }
-                // Check that the first argument is a mapping.
-                if let Some(mapping_type) = self.assert_mapping_type(&arguments[0].0, arguments[0].1) {
-                    // Check that the second argument matches the key type of the mapping.
-                    self.assert_type(&arguments[1].0, &mapping_type.key, arguments[1].1);
-                    // Return the value type of the mapping.
-                    Some(*mapping_type.value)
-                } else {
-                    None
-                }
-            }
-            CoreFunction::MappingGetOrUse => {
-                // Check that the operation is invoked in a `finalize` block.
-                if !self.is_finalize {
-                    self.handler.emit_err(TypeCheckerError::invalid_operation_outside_finalize(
-                        "Mapping::get_or",
-                        function_span,
-                    ))
-                }
-                // Check that the first argument is a mapping.
-                if let Some(mapping_type) = self.assert_mapping_type(&arguments[0].0, arguments[0].1) {
-                    // Check that the second argument matches the key type of the mapping.
-                    self.assert_type(&arguments[1].0, &mapping_type.key, arguments[1].1);
-                    // Check that the third argument matches the value type of the mapping.
-                    self.assert_type(&arguments[2].0, &mapping_type.value, arguments[2].1);
-                    // Return the value type of the mapping.
-                    Some(*mapping_type.value)
-                } else {
-                    None
-                }
-            }
-            CoreFunction::MappingSet => {
-                // Check that the operation is invoked in a `finalize` block.
-                if !self.is_finalize {
-                    self.handler
-                        .emit_err(TypeCheckerError::invalid_operation_outside_finalize("Mapping::set", function_span))
-                }
-                // Check that the first argument is a mapping.
-                if let Some(mapping_type) = self.assert_mapping_type(&arguments[0].0, arguments[0].1) {
-                    // Check that the second argument matches the key type of the mapping.
-                    self.assert_type(&arguments[1].0, &mapping_type.key, arguments[1].1);
-                    // Check that the third argument matches the value type of the mapping.
-                    self.assert_type(&arguments[2].0, &mapping_type.value, arguments[2].1);
-                    // Return the mapping type.
-                    Some(Type::Unit)
-                } else {
-                    None
+    /// Emits an error if `type_` is not a valid input to a core function at a position expecting `expected`.
+    fn check_core_argument(&self, expected: &CoreArgument, type_: &Option<Type>, argument: &Expression) {
+        let span = argument.span();
+        match expected {
+            CoreArgument::Hashable => self.check_type(
+                |type_: &Type| !matches!(type_, Type::Mapping(_) | Type::Tuple(_) | Type::Err | Type::Unit),
+                "address, bool, field, group, struct, integer, scalar, struct".to_string(),
+                type_,
+                span,
+            ),
+            // The console types in snarkVM have some overhead in their bitwise representation.
+            // Consequently, Pedersen64 cannot accept a u64 and Pedersen128 cannot accept a u128.
+            CoreArgument::Pedersen64Input | CoreArgument::Pedersen128Input => {
+                self.check_core_argument(&CoreArgument::Hashable, type_, argument);
+                let (function, max_bits) = match expected {
+                    CoreArgument::Pedersen64Input => (sym::Pedersen64, 32),
+                    _ => (sym::Pedersen128, 64),
+                };
+                if let Some(Type::Integer(integer_type)) = type_ {
+                    if integer_type.bit_width() > max_bits {
+                        self.emit_err(TypeCheckerError::core_function_input_too_wide(
+                            function,
+                            integer_type,
+                            max_bits,
+                            span,
+                        ));
+                    }
                 }
             }
-            CoreFunction::MappingRemove => {
-                // Check that the operation is invoked in a `finalize` block.
-                if !self.is_finalize {
-                    self.handler.emit_err(TypeCheckerError::invalid_operation_outside_finalize(
-                        "Mapping::remove",
-                        function_span,
-                    ))
-                }
-                // Check that the first argument is a mapping.
-                if let Some(mapping_type) = self.assert_mapping_type(&arguments[0].0, arguments[0].1) {
-                    // Check that the second argument matches the key type of the mapping.
-                    self.assert_type(&arguments[1].0, &mapping_type.key, arguments[1].1);
-                    // Return nothing.
-                    Some(Type::Unit)
-                } else {
-                    None
+            CoreArgument::Address => self.assert_address_type(type_, span),
+            CoreArgument::Field => self.assert_field_type(type_, span),
+            CoreArgument::Group => self.assert_group_type(type_, span),
+            CoreArgument::Scalar => self.assert_scalar_type(type_, span),
+            CoreArgument::Randomness => {
+                if let Some(type_) = type_ {
+                    if !matches!(type_, Type::Scalar | Type::Err) {
+                        self.emit_err(TypeCheckerError::invalid_randomness(type_, span));
+                    }
                 }
             }
-            CoreFunction::MappingContains => {
-                // Check that the operation is invoked in a `finalize` block.
-                if !self.is_finalize {
-                    self.handler.emit_err(TypeCheckerError::invalid_operation_outside_finalize(
-                        "Mapping::contains",
-                        function_span,
-                    ))
-                }
-                // Check that the first argument is a mapping.
-                if let Some(mapping_type) = self.assert_mapping_type(&arguments[0].0, arguments[0].1) {
-                    // Check that the second argument matches the key type of the mapping.
-                    self.assert_type(&arguments[1].0, &mapping_type.key, arguments[1].1);
-                    // Return a boolean.
-                    Some(Type::Boolean)
-                } else {
-                    None
+            CoreArgument::Signature => self.assert_signature_type(type_, span),
+            CoreArgument::Integer(integer_type) => self.assert_type(type_, &Type::Integer(*integer_type), span),
+            CoreArgument::UnsignedInteger => self.assert_unsigned_int_type(type_, span),
+            CoreArgument::Array => self.assert_array_type(type_, span),
+            CoreArgument::BitsLe(bytes) => self.assert_type(type_, &CoreFunction::bits_le_type(*bytes), span),
+            CoreArgument::BytesLe(bytes) => self.assert_type(type_, &CoreFunction::bytes_le_type(*bytes), span),
+            CoreArgument::Mapping => {
+                self.assert_mapping_type(type_, span);
+            }
+            // Keys and values are checked against the mapping in `check_core_function_call`.
+            CoreArgument::MappingKey | CoreArgument::MappingValue => {}
+            CoreArgument::Variadic(input) => {
+                self.assert_array_type(type_, span);
+                if let Some(Type::Array(array_type)) = type_ {
+                    let element_type = Some(array_type.element_type().clone());
+                    match argument {
+                        // Check each input of an array literal at its own span.
+                        Expression::Array(array) => array
+                            .elements
+                            .iter()
+                            .for_each(|element| self.check_core_argument(input, &element_type, element)),
+                        _ => self.check_core_argument(input, &element_type, argument),
+                    }
                 }
             }
-            CoreFunction::GroupToXCoordinate | CoreFunction::GroupToYCoordinate => {
-                // Check that the first argument is a group.
-                self.assert_group_type(&arguments[0].0, arguments[0].1);
-                Some(Type::Field)
-            }
-            CoreFunction::ChaChaRandAddress => Some(Type::Address),
-            CoreFunction::ChaChaRandBool => Some(Type::Boolean),
-            CoreFunction::ChaChaRandField => Some(Type::Field),
-            CoreFunction::ChaChaRandGroup => Some(Type::Group),
-            CoreFunction::ChaChaRandI8 => Some(Type::Integer(IntegerType::I8)),
-            CoreFunction::ChaChaRandI16 => Some(Type::Integer(IntegerType::I16)),
-            CoreFunction::ChaChaRandI32 => Some(Type::Integer(IntegerType::I32)),
-            CoreFunction::ChaChaRandI64 => Some(Type::Integer(IntegerType::I64)),
-            CoreFunction::ChaChaRandI128 => Some(Type::Integer(IntegerType::I128)),
-            CoreFunction::ChaChaRandScalar => Some(Type::Scalar),
-            CoreFunction::ChaChaRandU8 => Some(Type::Integer(IntegerType::U8)),
-            CoreFunction::ChaChaRandU16 => Some(Type::Integer(IntegerType::U16)),
-            CoreFunction::ChaChaRandU32 => Some(Type::Integer(IntegerType::U32)),
-            CoreFunction::ChaChaRandU64 => Some(Type::Integer(IntegerType::U64)),
-            CoreFunction::ChaChaRandU128 => Some(Type::Integer(IntegerType::U128)),
-            CoreFunction::SignatureVerify => {
-                // Check that the first argument is a signature.
-                self.assert_signature_type(&arguments[0].0, arguments[0].1);
-                // Check that the second argument is an address.
-                self.assert_address_type(&arguments[1].0, arguments[1].1);
-                // Return a boolean.
-                Some(Type::Boolean)
-            }
         }
     }
 
     /// Returns the `struct` type and emits an error if the `expected` type does not match.
-    pub(crate) fn check_expected_struct(&mut self, struct_: Identifier, expected: &Option<Type>, span: Span) -> Type {
+    pub(crate) fn check_expected_struct(&self, struct_: Identifier, expected: &Option<Type>, span: Span) -> Type {
         if let Some(expected) = expected {
             if !Type::Identifier(struct_).eq_flat(expected) {
                 self.emit_err(TypeCheckerError::type_should_be(struct_.name, expected, span));
@@ -1038,6 +1030,11 @@ impl<'a> TypeChecker<'a> {
         Type::Identifier(struct_)
     }
 
+    /// Returns `true` if `name` is a record, rather than a struct or an enum.
+    pub(crate) fn is_record(&self, name: Symbol) -> bool {
+        self.symbol_table.borrow().lookup_struct(name).map_or(false, |struct_| struct_.is_record)
+    }
+
     /// Emits an error if the struct member is a record type.
     pub(crate) fn assert_member_is_not_record(&self, span: Span, parent: Symbol, type_: &Type) {
         match type_ {
@@ -1059,6 +1056,29 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Emits an error if `name` is a private item of an imported program, and the program being traversed imports it.
+    /// Returns `true` if the item is visible.
+    pub(crate) fn check_item_is_visible(&self, kind: &str, name: Symbol, is_public: bool, span: Span) -> bool {
+        if self.is_imported || is_public {
+            return true;
+        }
+        let import = self.symbol_table.borrow().lookup_import(name);
+        match (import, self.program_name) {
+            (Some(import), Some(program)) => {
+                self.emit_err(TypeCheckerError::private_item_access(kind, name, import, program, span));
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// Emits an error if `struct_` is a private struct or record of an imported program.
+    /// Returns `true` if the struct is visible.
+    pub(crate) fn check_struct_is_visible(&self, struct_: &Struct, span: Span) -> bool {
+        let kind = if struct_.is_record { "record" } else { "struct" };
+        self.check_item_is_visible(kind, struct_.name(), struct_.is_public(), span)
+    }
+
     /// Emits an error if the type or its constituent types is not valid.
     pub(crate) fn assert_type_is_valid(&self, type_: &Type, span: Span) -> bool {
         let mut is_valid = true;
@@ -1068,11 +1088,20 @@ impl<'a> TypeChecker<'a> {
                 is_valid = false;
                 self.emit_err(TypeCheckerError::strings_are_not_supported(span));
             }
-            // Check that the named composite type has been defined.
-            Type::Identifier(identifier) if self.symbol_table.borrow().lookup_struct(identifier.name).is_none() => {
+            // Check that the named composite type or enum has been defined.
+            Type::Identifier(identifier)
+                if self.symbol_table.borrow().lookup_struct(identifier.name).is_none()
+                    && self.symbol_table.borrow().lookup_enum(identifier.name).is_none() =>
+            {
                 is_valid = false;
                 self.emit_err(TypeCheckerError::undefined_type(identifier.name, span));
             }
+            // Check that the named composite type is visible to this program.
+            Type::Identifier(identifier) => {
+                if let Some(struct_) = self.symbol_table.borrow().lookup_struct(identifier.name) {
+                    is_valid &= self.check_struct_is_visible(struct_, span);
+                }
+            }
             // Check that the constituent types of the tuple are valid.
             Type::Tuple(tuple_type) => {
                 for type_ in tuple_type.elements().iter() {
@@ -1130,8 +1159,353 @@ impl<'a> TypeChecker<'a> {
     pub(crate) fn assert_array_type(&self, type_: &Option<Type>, span: Span) {
         self.check_type(|type_| matches!(type_, Type::Array(_)), "array".to_string(), type_, span);
     }
+
+    /// Visits the operands of a binary operation whose operands must have the same type, but whose type is not given by the context.
+    /// An unsuffixed literal operand takes the type of the other operand.
+    pub(crate) fn visit_operands(
+        &mut self,
+        left: &'a Expression,
+        right: &'a Expression,
+    ) -> (Option<Type>, Option<Type>) {
+        let is_unsuffixed =
+            |expression: &Expression| matches!(expression, Expression::Literal(Literal::Unsuffixed(..)));
+        if is_unsuffixed(left) && !is_unsuffixed(right) {
+            let t2 = self.visit_expression(right, &None);
+            let t1 = self.visit_expression(left, &t2);
+            (t1, t2)
+        } else {
+            let t1 = self.visit_expression(left, &None);
+            let t2 = match is_unsuffixed(right) {
+                true => self.visit_expression(right, &t1),
+                false => self.visit_expression(right, &None),
+            };
+            (t1, t2)
+        }
+    }
+
+    /// Emits an error if the integer literal `raw_string`, in any radix, is out of the range of `integer_type`.
+    /// Returns the type of the literal.
+    pub(crate) fn check_integer_literal(
+        &self,
+        integer_type: IntegerType,
+        raw_string: &str,
+        expected: &Option<Type>,
+        span: Span,
+    ) -> Type {
+        let (digits, radix) = Literal::integer_digits(raw_string);
+        let is_valid = match integer_type {
+            IntegerType::U8 => u8::from_str_radix(&digits, radix).is_ok(),
+            IntegerType::U16 => u16::from_str_radix(&digits, radix).is_ok(),
+            IntegerType::U32 => u32::from_str_radix(&digits, radix).is_ok(),
+            IntegerType::U64 => u64::from_str_radix(&digits, radix).is_ok(),
+            IntegerType::U128 => u128::from_str_radix(&digits, radix).is_ok(),
+            IntegerType::I8 => i8::from_str_radix(&digits, radix).is_ok(),
+            IntegerType::I16 => i16::from_str_radix(&digits, radix).is_ok(),
+            IntegerType::I32 => i32::from_str_radix(&digits, radix).is_ok(),
+            IntegerType::I64 => i64::from_str_radix(&digits, radix).is_ok(),
+            IntegerType::I128 => i128::from_str_radix(&digits, radix).is_ok(),
+        };
+        if !is_valid {
+            self.emit_err(TypeCheckerError::invalid_int_value(raw_string.replace('_', ""), integer_type, span));
+        }
+        self.assert_and_return_type(Type::Integer(integer_type), expected, span)
+    }
+
+    /// Emits an error if the magnitude of `raw_string`, a `field` or `scalar` literal, is not less than the modulus of
+    /// its type, and returns the type.
+    pub(crate) fn check_modulus_literal(
+        &self,
+        type_: Type,
+        raw_string: &str,
+        expected: &Option<Type>,
+        span: Span,
+    ) -> Type {
+        let modulus = match type_ {
+            Type::Scalar => SCALAR_MODULUS,
+            _ => FIELD_MODULUS,
+        };
+        let modulus_value: BigUint = modulus.parse().expect("The moduli are valid numbers.");
+        // A negative literal is the negation of its magnitude, which must itself be a valid value.
+        let digits = raw_string.trim_start_matches('-').replace('_', "");
+        if !digits.parse::<BigUint>().map_or(false, |value| value < modulus_value) {
+            self.emit_err(TypeCheckerError::literal_exceeds_modulus(raw_string.replace('_', ""), &type_, modulus, span));
+        }
+        self.assert_and_return_type(type_, expected, span)
+    }
+
+    /// Emits an error if the literal `address` is not a bech32m encoded address.
+    /// The error points at the character that is invalid, or that is likely mistyped if the checksum does not match.
+    pub(crate) fn check_address_literal(&self, address: &str, span: Span) {
+        let character_span = |offset: usize| {
+            let lo = span.lo + BytePos(offset as u32);
+            Span::new(lo, lo + BytePos(1))
+        };
+        match validate_address(address) {
+            Ok(()) => {}
+            Err(AddressError::Character(offset, character)) => {
+                self.emit_err(TypeCheckerError::invalid_address_character(character, offset + 1, character_span(offset)))
+            }
+            Err(AddressError::Length(length)) => {
+                self.emit_err(TypeCheckerError::invalid_address_length(address, length, ADDRESS_LENGTH, span))
+            }
+            Err(AddressError::Checksum(typo)) => {
+                let err = TypeCheckerError::invalid_address_checksum(address, span);
+                match typo {
+                    Some((offset, replacement)) => {
+                        let label = Label::new(format!("this may be a typo of `{replacement}`"), character_span(offset));
+                        self.emit_err(err.with_label(label))
+                    }
+                    None => self.emit_err(err),
+                }
+            }
+            Err(AddressError::Point) => self.emit_err(TypeCheckerError::invalid_address_point(address, span)),
+        }
+    }
+
+    /// Emits an error if the literal `group` is not a point in the prime order subgroup of the curve.
+    pub(crate) fn check_group_literal(&self, group: &GroupLiteral) {
+        if !is_valid_group_element(group) {
+            let literal = match group {
+                GroupLiteral::Single(x, ..) => format!("{x}group"),
+                GroupLiteral::Tuple(tuple) => format!("({}, {})group", tuple.x, tuple.y),
+            };
+            self.emit_err(TypeCheckerError::invalid_group_element(literal, *group.span()));
+        }
+    }
+
+    /// Evaluates `input`, an expression of type `integer_type`, if its value is known at compile time.
+    /// Values are computed from the already evaluated subexpressions, so that `250u8 + 10u8` is reported as out of
+    /// range when it is checked, rather than when the program is executed. Only the innermost out-of-range operation
+    /// is reported, since its enclosing expressions are not evaluated.
+    pub(crate) fn evaluate_integer(&mut self, input: &Expression, integer_type: IntegerType) {
+        // Ill-typed operands, e.g. in `1u8 + 2u16`, are already reported, so their values are not used.
+        let constant = |expression: &Expression, expected: Option<IntegerType>| {
+            let (type_, value) = self.constant_integers.get(&expression.id())?;
+            expected.map_or(true, |expected| expected == *type_).then_some(*value)
+        };
+        let value = match input {
+            // Literals, including the values of constants, are reported when they are checked if they are out of range.
+            Expression::Literal(_) | Expression::Identifier(_) => {
+                let value = match input {
+                    Expression::Literal(literal) => parse_integer_literal(literal),
+                    Expression::Identifier(identifier) => {
+                        match self.symbol_table.borrow().lookup_constant_value(identifier.name) {
+                            Some(Expression::Literal(literal)) => parse_integer_literal(literal),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+                if let Some(value) = value.filter(|value| integer_in_range(*value, integer_type)) {
+                    self.constant_integers.insert(input.id(), (integer_type, value));
+                }
+                return;
+            }
+            Expression::Unary(unary) => {
+                let Some(receiver) = constant(&unary.receiver, Some(integer_type)) else { return };
+                let value = match unary.op {
+                    UnaryOperation::Abs => receiver.checked_abs(),
+                    UnaryOperation::Negate => receiver.checked_neg(),
+                    _ => return,
+                };
+                // Both operations only overflow on `i128::MIN`, whose magnitude is still exact as a `u128`.
+                value.map_or_else(|| Folded::Overflow(receiver.unsigned_abs().to_string()), Folded::Value)
+            }
+            Expression::Binary(binary) => {
+                // The exponent of `**` may have a different type than the base.
+                let right_type = (binary.op != BinaryOperation::Pow).then_some(integer_type);
+                let (Some(left), Some(right)) =
+                    (constant(&binary.left, Some(integer_type)), constant(&binary.right, right_type))
+                else {
+                    return;
+                };
+                let value = match binary.op {
+                    BinaryOperation::Add => left.checked_add(right),
+                    BinaryOperation::Sub => left.checked_sub(right),
+                    BinaryOperation::Mul => left.checked_mul(right),
+                    BinaryOperation::Pow => u32::try_from(right).ok().and_then(|right| left.checked_pow(right)),
+                    // Division by zero is reported by `assert_nonzero_divisor`.
+                    BinaryOperation::Div if right != 0 => left.checked_div(right),
+                    BinaryOperation::Mod | BinaryOperation::Rem if right != 0 => left.checked_rem(right),
+                    // Wrapping operations never overflow, and shifts are only checked on their magnitude.
+                    _ => return,
+                };
+                value.map_or_else(|| Folded::Overflow(format!("{left} {} {right}", binary.op)), Folded::Value)
+            }
+            _ => return,
+        };
+        let value = match value {
+            Folded::Value(value) if integer_in_range(value, integer_type) => {
+                self.constant_integers.insert(input.id(), (integer_type, value));
+                return;
+            }
+            Folded::Value(value) => value.to_string(),
+            Folded::Overflow(value) => value,
+        };
+        self.emit_err(TypeCheckerError::value_out_of_range(value, integer_type, input.span()));
+    }
+
+    /// Emits an error if the divisor of `input`, a division or remainder, is a literal or constant zero.
+    pub(crate) fn assert_nonzero_divisor(&self, input: &BinaryExpression) {
+        let is_zero = match &*input.right {
+            Expression::Literal(Literal::Field(value, ..)) => value.replace('_', "").trim_start_matches('0').is_empty(),
+            right => self.constant_integers.get(&right.id()).map_or(false, |(_, value)| *value == 0),
+        };
+        if is_zero {
+            self.emit_err(TypeCheckerError::division_by_zero(input.op, input.right.span()));
+        }
+    }
+
+    /// Emits an error if `index` is a literal that is not a valid index into the lookup table of type `table`.
+    pub(crate) fn check_lookup_index(&self, table: &Option<Type>, index: &Expression) {
+        if let (
+            Some(Type::Array(array_type)),
+            Expression::Literal(Literal::Integer(_, value, span, _) | Literal::Unsuffixed(value, span, _)),
+        ) = (table, index)
+        {
+            let (digits, radix) = Literal::integer_digits(value);
+            if u128::from_str_radix(&digits, radix).map_or(false, |value| value >= array_type.length() as u128) {
+                self.emit_err(TypeCheckerError::lookup_index_out_of_bounds(value, array_type.length(), *span));
+            }
+        }
+    }
+
+    /// Emits an error for the body of a function with an output type that does not return on every path.
+    /// If some paths return, the error points to the first path that does not.
+    pub(crate) fn emit_missing_return(&self, block: &Block, span: Span) {
+        match first_path_without_return(&block.statements) {
+            Some((path, span)) => self.emit_err(TypeCheckerError::missing_return_on_path(path, span)),
+            None => self.emit_err(TypeCheckerError::missing_return(span)),
+        }
+    }
+
+    /// Returns the type of the variable `var_name`, which is the target of an assignment.
+    /// Emits an error if the variable is unknown, or if it cannot be assigned to.
+    pub(crate) fn assignee_type(&self, var_name: &Identifier) -> Option<Type> {
+        let symbol_table = self.symbol_table.borrow();
+        let Some(var) = symbol_table.lookup_variable(var_name.name) else {
+            let suggestion = symbol_table.similar_variable(var_name.name);
+            self.emit_err(TypeCheckerError::unknown_sym("variable", var_name.name, suggestion, var_name.span));
+            return None;
+        };
+        match &var.declaration {
+            VariableType::Const => self.emit_err(TypeCheckerError::cannot_assign_to_const_var(var_name, var.span)),
+            VariableType::Input(Mode::Constant) => {
+                self.emit_err(TypeCheckerError::cannot_assign_to_const_input(var_name, var.span))
+            }
+            _ => {}
+        }
+        Some(var.type_.clone())
+    }
+
+    /// Returns `true` if `identifier` is the `self` keyword, as in `self.caller`.
+    /// Inside of a method, `self` is instead the receiver of the method.
+    pub(crate) fn is_self_keyword(&self, identifier: &Identifier) -> bool {
+        identifier.name == sym::SelfLower && self.symbol_table.borrow().lookup_variable(sym::SelfLower).is_none()
+    }
+}
+
+/// Returns `true` if `statement` contains a return statement on any path.
+fn contains_return(statement: &Statement) -> bool {
+    match statement {
+        Statement::Return(_) => true,
+        Statement::Block(block) => block.statements.iter().any(contains_return),
+        Statement::Conditional(conditional) => {
+            conditional.then.statements.iter().any(contains_return)
+                || conditional.otherwise.as_deref().map_or(false, contains_return)
+        }
+        Statement::Match(match_) => match_.arms.iter().any(|arm| arm.block.statements.iter().any(contains_return)),
+        _ => false,
+    }
+}
+
+/// Returns `true` if every path through `statement` ends with a return statement.
+fn always_returns(statement: &Statement) -> bool {
+    match statement {
+        Statement::Return(_) => true,
+        Statement::Block(block) => block.statements.iter().any(always_returns),
+        Statement::Conditional(conditional) => {
+            conditional.then.statements.iter().any(always_returns)
+                && conditional.otherwise.as_deref().map_or(false, always_returns)
+        }
+        // A match that is not exhaustive is reported separately.
+        Statement::Match(match_) => {
+            !match_.arms.is_empty()
+                && match_.arms.iter().all(|arm| arm.block.statements.iter().any(always_returns))
+        }
+        _ => false,
+    }
+}
+
+/// Returns a description and the span of the last branch taken by the first path through `statements` that does not
+/// return, if `statements` do not always return. Only branches of conditional statements that contain a return
+/// statement are considered, so `None` is returned if no path returns.
+fn first_path_without_return(statements: &[Statement]) -> Option<(&'static str, Span)> {
+    let branch =
+        |statements: &[Statement], span: Span| first_path_without_return(statements).or(Some(("this branch", span)));
+    let mut last_branch = None;
+    for statement in statements {
+        match statement {
+            Statement::Block(block) => {
+                last_branch = first_path_without_return(&block.statements).or(last_branch);
+            }
+            Statement::Conditional(conditional) if contains_return(statement) => {
+                last_branch = if !conditional.then.statements.iter().any(always_returns) {
+                    branch(&conditional.then.statements, conditional.then.span)
+                } else {
+                    match conditional.otherwise.as_deref() {
+                        Some(Statement::Block(block)) => branch(&block.statements, block.span),
+                        Some(otherwise) => branch(std::slice::from_ref(otherwise), otherwise.span()),
+                        None => Some(("the path where this condition is false", conditional.condition.span())),
+                    }
+                };
+            }
+            Statement::Match(match_) if contains_return(statement) => {
+                if let Some(arm) = match_.arms.iter().find(|arm| !arm.block.statements.iter().any(always_returns)) {
+                    last_branch = branch(&arm.block.statements, arm.block.span);
+                }
+            }
+            _ => {}
+        }
+    }
+    last_branch
 }
 
 fn types_to_string(types: &[Type]) -> String {
     types.iter().map(|type_| type_.to_string()).join(", ")
 }
+
+/// The result of evaluating an integer expression at compile time.
+enum Folded {
+    /// The value of the expression.
+    Value(i128),
+    /// The value, or the operation computing it, if it is too large to be represented as an `i128`.
+    Overflow(String),
+}
+
+/// Returns the value of an integer literal, if it can be represented as an `i128`.
+pub(crate) fn parse_integer_literal(literal: &Literal) -> Option<i128> {
+    match literal {
+        Literal::Integer(_, string, _, _) | Literal::Unsuffixed(string, _, _) => {
+            let (digits, radix) = Literal::integer_digits(string);
+            i128::from_str_radix(&digits, radix).ok()
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if `value` is in the range of `integer_type`.
+pub(crate) fn integer_in_range(value: i128, integer_type: IntegerType) -> bool {
+    match integer_type {
+        IntegerType::U8 => u8::try_from(value).is_ok(),
+        IntegerType::U16 => u16::try_from(value).is_ok(),
+        IntegerType::U32 => u32::try_from(value).is_ok(),
+        IntegerType::U64 => u64::try_from(value).is_ok(),
+        IntegerType::U128 => u128::try_from(value).is_ok(),
+        IntegerType::I8 => i8::try_from(value).is_ok(),
+        IntegerType::I16 => i16::try_from(value).is_ok(),
+        IntegerType::I32 => i32::try_from(value).is_ok(),
+        IntegerType::I64 => i64::try_from(value).is_ok(),
+        IntegerType::I128 => true,
+    }
+}
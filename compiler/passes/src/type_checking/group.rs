@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The validation of group literals, which are points in the prime order subgroup of the Edwards BLS12-377 curve.
+
+use leo_ast::{GroupCoordinate, GroupLiteral};
+
+use snarkvm_console::network::{prelude::AffineCurve, Environment, Testnet3};
+use std::str::FromStr;
+
+type Affine = <Testnet3 as Environment>::Affine;
+type Field = <Testnet3 as Environment>::Field;
+
+/// Returns whether the literal `group` is a point in the prime order subgroup of the curve.
+/// As in Aleo instructions, `xgroup` is the point with the x-coordinate `x`.
+/// A coordinate given by a sign or `_` is recovered from the other coordinate.
+pub fn is_valid_group_element(group: &GroupLiteral) -> bool {
+    match group {
+        GroupLiteral::Single(x, ..) => recover(x, Affine::from_x_coordinate),
+        GroupLiteral::Tuple(tuple) => match (&tuple.x, &tuple.y) {
+            (GroupCoordinate::Number(x, _), GroupCoordinate::Number(y, _)) => match (parse_field(x), parse_field(y)) {
+                // The point is checked to be on the curve and in the subgroup.
+                (Some(x), Some(y)) => Affine::from_coordinates((x, y)).is_some(),
+                _ => false,
+            },
+            (GroupCoordinate::Number(x, _), _) => recover(x, Affine::from_x_coordinate),
+            (_, GroupCoordinate::Number(y, _)) => recover(y, Affine::from_y_coordinate),
+            // Neither coordinate is known, so there is nothing to check.
+            _ => true,
+        },
+    }
+}
+
+/// Returns whether a point in the subgroup has the coordinate `value`, computing its other coordinate with `recover`.
+/// Both of the points with the coordinate are tried, since only one of them may be in the subgroup.
+fn recover(value: &str, recover: fn(Field, bool) -> Option<Affine>) -> bool {
+    parse_field(value).map_or(false, |value| {
+        [true, false]
+            .into_iter()
+            .filter_map(|greatest| recover(value, greatest))
+            .any(|point| point.is_in_correct_subgroup_assuming_on_curve())
+    })
+}
+
+/// Parses a decimal coordinate, which may be negative and contain underscores, as a field element.
+fn parse_field(value: &str) -> Option<Field> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value),
+    };
+    // Field elements are parsed without leading zeros.
+    let digits = digits.replace('_', "");
+    let field = match digits.trim_start_matches('0') {
+        "" => Field::from_str("0"),
+        digits => Field::from_str(digits),
+    }
+    .ok()?;
+    Some(if negative { -field } else { field })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use leo_ast::{GroupTuple, NodeID};
+    use leo_span::Span;
+
+    fn single(x: &str) -> GroupLiteral {
+        GroupLiteral::Single(x.to_string(), Span::default(), NodeID::default())
+    }
+
+    fn tuple(x: GroupCoordinate, y: GroupCoordinate) -> GroupLiteral {
+        GroupLiteral::Tuple(GroupTuple { x, y, span: Span::default(), id: NodeID::default() })
+    }
+
+    fn number(value: &str) -> GroupCoordinate {
+        GroupCoordinate::Number(value.to_string(), Span::default())
+    }
+
+    #[test]
+    fn test_valid_group_elements() {
+        assert!(is_valid_group_element(&single("0")));
+        assert!(is_valid_group_element(&single("2")));
+        assert!(is_valid_group_element(&single("-2")));
+        assert!(is_valid_group_element(&single("0_0")));
+        assert!(is_valid_group_element(&tuple(number("0"), number("1"))));
+        assert!(is_valid_group_element(&tuple(number("0"), GroupCoordinate::SignHigh)));
+        assert!(is_valid_group_element(&tuple(GroupCoordinate::Inferred, number("1"))));
+        assert!(is_valid_group_element(&tuple(GroupCoordinate::Inferred, GroupCoordinate::Inferred)));
+    }
+
+    #[test]
+    fn test_invalid_group_elements() {
+        assert!(!is_valid_group_element(&single("1")));
+        assert!(!is_valid_group_element(&tuple(number("123"), number("456"))));
+        assert!(!is_valid_group_element(&tuple(number("0"), number("2"))));
+        assert!(!is_valid_group_element(&tuple(number("1"), GroupCoordinate::SignLow)));
+    }
+}
@@ -14,6 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod address;
+pub use address::*;
+
+pub mod cache;
+pub use cache::*;
+
 pub mod check_expressions;
 
 pub use check_expressions::*;
@@ -27,9 +33,15 @@ pub use check_statements::*;
 pub mod checker;
 pub use checker::*;
 
+pub mod group;
+pub use group::*;
+
+pub mod parallel;
+pub use parallel::*;
+
 use crate::{CallGraph, Pass, StructGraph, SymbolTable, TypeTable};
 
-use leo_ast::{Ast, ProgramVisitor};
+use leo_ast::Ast;
 use leo_errors::{emitter::Handler, Result};
 
 impl<'a> Pass for TypeChecker<'a> {
@@ -37,10 +49,332 @@ impl<'a> Pass for TypeChecker<'a> {
     type Output = Result<(SymbolTable, StructGraph, CallGraph)>;
 
     fn do_pass((ast, handler, st, tt): Self::Input) -> Self::Output {
-        let mut visitor = TypeChecker::new(st, tt, handler);
-        visitor.visit_program(ast.as_repr());
-        handler.last_err().map_err(|e| *e)?;
+        TypeChecker::new(st, tt, handler).run(ast)
+    }
+}
+
+impl<'a> TypeChecker<'a> {
+    /// Runs the type checker, skipping the functions in `cache` that are unchanged since they were last checked.
+    /// The functions that check without errors or warnings are added to the cache.
+    /// Since skipped functions are not traversed, the type table and the scopes of the symbol table are incomplete,
+    /// so the output is only suitable for reporting diagnostics, not for the remaining passes.
+    pub fn do_incremental_pass(
+        (ast, handler, st, tt, cache): (&'a Ast, &'a Handler, SymbolTable, &'a TypeTable, &'a TypeCheckCache),
+    ) -> Result<(SymbolTable, StructGraph, CallGraph)> {
+        TypeChecker::new(st, tt, handler).with_cache(cache).run(ast)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse, CoreFunctionRegistry, CoreFunctionSignature, SymbolTableCreator};
+
+    use leo_ast::{ImportTrace, IntegerType, NodeBuilder, Statement, Type};
+    use leo_span::{symbol::create_session_if_not_set_then, Span, Symbol};
+
+    const IMPORT: &str = "program foo.aleo {
+    struct Hidden { x: u8 }
+    public struct Shown { x: u8 }
+    record Token { owner: address, amount: u64 }
+    inline helper() -> u8 { return 1u8; }
+    public inline shared() -> u8 { return 2u8; }
+}
+";
+
+    const PROGRAM: &str = "program test.aleo {
+    transition main(s: Shown, h: Hidden, t: Token) -> u8 {
+        let a: u8 = helper();
+        let b: u8 = shared();
+        return a + b + s.x + h.x;
+    }
+}
+";
+
+    #[test]
+    fn test_private_item_access() {
+        create_session_if_not_set_then(|_| {
+            let node_builder = NodeBuilder::default();
+            let mut program = parse(PROGRAM, &node_builder);
+            program.imports.insert(Symbol::intern("foo"), (parse(IMPORT, &node_builder), Span::dummy()));
+            let ast = Ast::new(program);
+
+            let (handler, buffer) = Handler::new_with_buf();
+            let symbol_table = SymbolTableCreator::do_pass((&ast, &handler, &ImportTrace::default())).unwrap();
+            assert!(TypeChecker::do_pass((&ast, &handler, symbol_table, &TypeTable::default())).is_err());
+
+            let errors = buffer.extract_errs().into_inner();
+            let errors = errors.iter().map(|error| error.to_string()).collect::<Vec<_>>();
+            assert_eq!(errors.len(), 2, "{errors:#?}");
+            assert!(errors[0].contains("The struct `Hidden` of `foo.aleo` is private, so it cannot be used by"));
+            assert!(errors[1].contains("The function `helper` of `foo.aleo` is private"));
+        })
+    }
+
+    #[test]
+    fn test_external_calls() {
+        create_session_if_not_set_then(|_| {
+            let node_builder = NodeBuilder::default();
+            let import = "program foo.aleo { transition double(a: u8) -> u8 { return a + a; } }";
+            let program = "program test.aleo {
+    transition local(a: u8) -> u8 { return a; }
+    transition main(a: u8) -> u8 {
+        let b: u8 = foo.leo/double(a);
+        let c: u8 = foo.leo/double(true);
+        let d: bool = foo.leo/double(a);
+        let e: u8 = bar.leo/double(a);
+        let f: u8 = foo.leo/local(a);
+        return b;
+    }
+}
+";
+            let mut program = parse(program, &node_builder);
+            program.imports.insert(Symbol::intern("foo"), (parse(import, &node_builder), Span::dummy()));
+            let ast = Ast::new(program);
+
+            let (handler, buffer) = Handler::new_with_buf();
+            let symbol_table = SymbolTableCreator::do_pass((&ast, &handler, &ImportTrace::default())).unwrap();
+            assert!(TypeChecker::do_pass((&ast, &handler, symbol_table, &TypeTable::default())).is_err());
+
+            // The arguments and output of a call to an imported program are checked against its signature.
+            let errors = buffer.extract_errs().into_inner();
+            let errors = errors.iter().map(|error| error.to_string()).collect::<Vec<_>>();
+            assert_eq!(errors.len(), 4, "{errors:#?}");
+            assert!(errors[0].contains("Expected type `u8` but type `boolean` was found"), "{}", errors[0]);
+            assert!(errors[1].contains("Expected type `boolean` but type `u8` was found"), "{}", errors[1]);
+            assert!(errors[2].contains("The program `bar.aleo` has no function named `double`."));
+            assert!(errors[3].contains("The program `foo.aleo` has no function named `local`."));
+        })
+    }
+
+    #[test]
+    fn test_deployed_program_calls() {
+        create_session_if_not_set_then(|_| {
+            let node_builder = NodeBuilder::default();
+            let stub = "program token.aleo;
+
+record Token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    cast r0 r1 into r2 as Token.record;
+    output r2 as Token.record;
+";
+            let program = "program test.aleo {
+    transition main(a: address, b: bool) -> token.aleo/Token.record {
+        let t: Token = token.aleo/mint(a, 1u64);
+        let u: Token = token.aleo/mint(a, true);
+        if b {
+            let v: Token = token.aleo/mint(a, 2u64);
+        }
+        return t then finalize();
+    } finalize main() {
+        let w: Token = token.aleo/mint(aleo1qnr4dkkvkgfqph0vzc3y6z2eu975wnpz2925ntjccd5cfqxtyu8s7pyjh9, 3u64);
+    }
+}
+";
+            let stub = leo_parser::parse_stub(&node_builder, stub, Default::default()).unwrap();
+            let mut program = parse(program, &node_builder);
+            program.stubs.insert(Symbol::intern("token"), (stub, Span::dummy()));
+            let ast = Ast::new(program);
+
+            let (handler, buffer) = Handler::new_with_buf();
+            let symbol_table = SymbolTableCreator::do_pass((&ast, &handler, &ImportTrace::default())).unwrap();
+            assert!(TypeChecker::do_pass((&ast, &handler, symbol_table, &TypeTable::default())).is_err());
+
+            // Calls to a deployed program are checked against the signatures in its stub.
+            let errors = buffer.extract_errs().into_inner();
+            let errors = errors.iter().map(|error| error.to_string()).collect::<Vec<_>>();
+            assert_eq!(errors.len(), 3, "{errors:#?}");
+            assert!(errors[0].contains("Expected type `u64` but type `boolean` was found"), "{}", errors[0]);
+            assert!(errors[1].contains("An external call cannot be made in a conditional statement"), "{}", errors[1]);
+            assert!(errors[2].contains("An external call cannot be made in a finalize block."), "{}", errors[2]);
+        })
+    }
+
+    #[test]
+    fn test_unused_result() {
+        create_session_if_not_set_then(|_| {
+            let source = "program test.aleo {
+    mapping counts: u8 => u8;
+    inline check(a: u8) -> u8 { assert(a > 0u8); return a; }
+    transition main(a: u8, f: field) -> u8 {
+        BHP256::hash_to_field(a);
+        u8::try_from_field(f);
+        check(a);
+        return a then finalize(a);
+    } finalize main(a: u8) {
+        Mapping::set(counts, a, a);
+        Mapping::get(counts, a);
+        counts.contains(a);
+    }
+}
+";
+            let (handler, buffer) = Handler::new_with_buf();
+            let ast = Ast::new(parse(source, &NodeBuilder::default()));
+            let symbol_table = SymbolTableCreator::do_pass((&ast, &handler, &ImportTrace::default())).unwrap();
+            TypeChecker::do_pass((&ast, &handler, symbol_table, &TypeTable::default())).unwrap();
+
+            // Only the discarded results of the core functions without side effects are warned about.
+            let warnings = buffer.extract_warnings().into_inner();
+            let warnings = warnings.iter().map(|warning| warning.to_string()).collect::<Vec<_>>();
+            assert_eq!(warnings.len(), 2, "{warnings:#?}");
+            assert!(warnings[0].contains("unused result of `BHP256::hash_to_field`"), "{}", warnings[0]);
+            assert!(warnings[1].contains("unused result of `Mapping::contains`"), "{}", warnings[1]);
+        })
+    }
+
+    #[test]
+    fn test_constant_values() {
+        create_session_if_not_set_then(|_| {
+            let source = "program test.aleo {
+    const SIZE: u8 = 3u8;
+    const PAIR: (u8, bool) = (1u8, true);
+    mapping counts: u8 => u8;
+    transition main(a: u8) -> u8 { return a + SIZE; }
+}
+";
+            let handler = Handler::default();
+            let ast = Ast::new(parse(source, &NodeBuilder::default()));
+            let symbol_table = SymbolTableCreator::do_pass((&ast, &handler, &ImportTrace::default())).unwrap();
+            let (symbol_table, _, _) =
+                TypeChecker::do_pass((&ast, &handler, symbol_table, &TypeTable::default())).unwrap();
+
+            let value = |name: &str| symbol_table.lookup_constant_value(Symbol::intern(name)).map(|e| e.to_string());
+            assert_eq!(value("SIZE").as_deref(), Some("3u8"));
+            assert_eq!(value("PAIR").as_deref(), Some("(1u8,true)"));
+            // Mappings are not constants, and inputs are not in the global scope.
+            assert_eq!(value("counts"), None);
+            assert_eq!(value("a"), None);
+        })
+    }
+
+    #[test]
+    fn test_parallel_check_matches_sequential_check() {
+        create_session_if_not_set_then(|_| {
+            // Every other function has a type error.
+            let functions = (0..2 * MIN_FUNCTIONS_TO_CHECK_IN_PARALLEL)
+                .map(|i| match i % 2 {
+                    0 => format!("    function f{i}(a: u8) -> u8 {{ for j: u8 in 0u8..4u8 {{ a += j; }} return a; }}"),
+                    _ => format!("    function f{i}(a: u8) -> u8 {{ let b: u16 = a; return a; }}"),
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let source = format!("program test.aleo {{\n{functions}\n}}\n");
+
+            let check = |cache: Option<&TypeCheckCache>| {
+                let (handler, buffer) = Handler::new_with_buf();
+                let ast = Ast::new(parse(&source, &NodeBuilder::default()));
+                let symbol_table = SymbolTableCreator::do_pass((&ast, &handler, &ImportTrace::default())).unwrap();
+                let type_table = TypeTable::default();
+                let result = match cache {
+                    Some(cache) => TypeChecker::do_incremental_pass((&ast, &handler, symbol_table, &type_table, cache)),
+                    None => TypeChecker::do_pass((&ast, &handler, symbol_table, &type_table)),
+                };
+                assert!(result.is_err());
+                // The bounds of the loops are recorded in the AST.
+                let stop_values = ast.ast.program_scopes[0].functions.iter().filter_map(|(_, function)| {
+                    function.block.statements.iter().find_map(|statement| match statement {
+                        Statement::Iteration(iteration) => Some(iteration.stop_value.borrow().clone()),
+                        _ => None,
+                    })
+                });
+                let stop_values = stop_values.map(|value| value.unwrap().to_string()).collect::<Vec<_>>();
+                assert_eq!(stop_values, ["4u8"; MIN_FUNCTIONS_TO_CHECK_IN_PARALLEL]);
+                (buffer.extract_errs().into_inner().iter().map(|e| e.to_string()).collect::<Vec<_>>(), type_table)
+            };
+
+            // The cache forces a sequential check.
+            let (sequential, sequential_types) = check(Some(&TypeCheckCache::default()));
+            let (parallel, parallel_types) = check(None);
+            assert_eq!(sequential.len(), MIN_FUNCTIONS_TO_CHECK_IN_PARALLEL);
+            assert_eq!(sequential, parallel);
+            assert_eq!(sequential_types.entries().len(), parallel_types.entries().len());
+        })
+    }
+
+    /// Checks `source` with `cache`, returning the number of functions skipped and checked.
+    fn check_incremental(source: &str, cache: &TypeCheckCache) -> (usize, usize) {
+        let (hits, misses) = (cache.hits(), cache.misses());
+        let handler = Handler::default();
+        let ast = Ast::new(parse(source, &NodeBuilder::default()));
+        let symbol_table = SymbolTableCreator::do_pass((&ast, &handler, &ImportTrace::default())).unwrap();
+        let (_, _, call_graph) =
+            TypeChecker::do_incremental_pass((&ast, &handler, symbol_table, &TypeTable::default(), cache)).unwrap();
+        // The calls made by skipped functions are still in the call graph.
+        assert_eq!(call_graph.neighbors(Symbol::intern("main")).count(), 2);
+        (cache.hits() - hits, cache.misses() - misses)
+    }
+
+    #[test]
+    fn test_incremental_check() {
+        create_session_if_not_set_then(|_| {
+            let program = |point: &str, double: &str| {
+                format!(
+                    "program test.aleo {{
+    struct Point {{ {point} }}
+    function double(p: Point) -> u8 {{ return {double}; }}
+    function origin() -> u8 {{ return 0u8; }}
+    transition main(p: Point) -> u8 {{ return double(p) + origin(); }}
+}}
+"
+                )
+            };
+            let cache = TypeCheckCache::default();
+            assert_eq!(check_incremental(&program("x: u8", "p.x + p.x"), &cache), (0, 3));
+            assert_eq!(check_incremental(&program("x: u8", "p.x + p.x"), &cache), (3, 0));
+            // Only the function whose body changed is checked.
+            assert_eq!(check_incremental(&program("x: u8", "p.x * 2u8"), &cache), (2, 1));
+            // Both functions that refer to the struct are checked when its members change.
+            assert_eq!(check_incremental(&program("x: u8, y: u8", "p.x * 2u8"), &cache), (1, 2));
+            assert_eq!(cache.len(), 3);
+        })
+    }
+
+    #[test]
+    fn test_registered_core_functions() {
+        create_session_if_not_set_then(|_| {
+            let mut registry = CoreFunctionRegistry::default();
+            let signature = CoreFunctionSignature {
+                arguments: vec![Type::Integer(IntegerType::U32)],
+                output: Type::Integer(IntegerType::U64),
+                opcode: "oracle.price".to_string(),
+                finalize_only: true,
+            };
+            registry.register(Symbol::intern("Oracle"), Symbol::intern("price"), signature).unwrap();
+            let source = "program test.aleo {
+    transition main(a: u32) {
+        let b: u64 = Oracle::price(a);
+        return then finalize(a);
+    }
+    finalize main(a: u32) {
+        let b: u64 = Oracle::price(a);
+        let c: u64 = Oracle::price(true);
+        let d: u64 = Oracle::price(a, a);
+        let e: u32 = Oracle::price(a);
+        let f: u64 = Oracle::volume(a);
+    }
+}
+";
+            let (handler, buffer) = Handler::new_with_buf();
+            let ast = Ast::new(parse(source, &NodeBuilder::default()));
+            let symbol_table = SymbolTableCreator::do_pass((&ast, &handler, &ImportTrace::default())).unwrap();
+            let type_table = TypeTable::default();
+            let checker = TypeChecker::new(symbol_table, &type_table, &handler).with_core_functions(&registry);
+            assert!(checker.run(&ast).is_err());
 
-        Ok((visitor.symbol_table.take(), visitor.struct_graph, visitor.call_graph))
+            // Calls to a registered function are checked against its signature, and other functions are unknown.
+            let errors = buffer.extract_errs().into_inner();
+            let errors = errors.iter().map(|error| error.to_string()).collect::<Vec<_>>();
+            assert_eq!(errors.len(), 6, "{errors:#?}");
+            assert!(errors[0].contains("can only be used in a `finalize` block"), "{}", errors[0]);
+            assert!(errors[1].contains("Expected type `u32` but type `boolean` was found"), "{}", errors[1]);
+            assert!(errors[2].contains("Call expected `1` args, but got `2`"), "{}", errors[2]);
+            assert!(errors[3].contains("Expected type `u32` but type `u64` was found"), "{}", errors[3]);
+            assert!(errors[4].contains("Oracle::volume is not a valid core function."), "{}", errors[4]);
+        })
     }
 }
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{SymbolTable, TypeChecker, TypeTable};
+
+use leo_ast::{
+    BlockExpression,
+    ErrExpression,
+    ErrStatement,
+    ExpressionVisitor,
+    Function,
+    IterationStatement,
+    NodeID,
+    ProgramVisitor,
+    StatementVisitor,
+    Type,
+    Value,
+};
+use leo_errors::{emitter::Handler, LeoError, LeoWarning};
+use leo_span::{
+    symbol::{with_session_globals, SESSION_GLOBALS},
+    Span,
+    Symbol,
+};
+
+use rayon::prelude::*;
+use std::sync::Mutex;
+
+/// The minimum number of functions in a program scope for their bodies to be checked in parallel.
+/// Below it, the cost of copying the symbol table to each thread outweighs the gain.
+pub const MIN_FUNCTIONS_TO_CHECK_IN_PARALLEL: usize = 8;
+
+/// The outcome of checking the body of a function on its own thread.
+struct FunctionCheck {
+    /// The function's scope in the symbol table, including the scopes of its blocks.
+    scope: SymbolTable,
+    /// The functions it calls.
+    callees: Vec<Symbol>,
+    /// The entries it added to the type table.
+    types: Vec<(NodeID, Type, Option<Span>)>,
+    /// The bounds of its loops, in the order in which they appear.
+    loop_bounds: Vec<(Option<Value>, Option<Value>)>,
+    /// The errors and warnings it emitted, in order.
+    errors: Vec<LeoError>,
+    warnings: Vec<LeoWarning>,
+}
+
+impl<'a> TypeChecker<'a> {
+    /// Type checks the bodies of `functions`, whose signatures are already in the symbol table.
+    /// Since the bodies are independent of each other, they are checked in parallel if there are enough of them, and
+    /// the results are merged in order, so that the diagnostics are the same as if they were checked sequentially.
+    pub(crate) fn check_functions(&mut self, functions: Vec<(Symbol, &'a Function)>) {
+        // The cache is not shared between threads, and a handful of functions is quicker to check sequentially.
+        if self.cache.is_some() || functions.len() < MIN_FUNCTIONS_TO_CHECK_IN_PARALLEL {
+            return functions.into_iter().for_each(|(name, function)| self.check_function(name, function));
+        }
+
+        // Each function is checked on a copy, since the type checker records the bounds of loops in the AST.
+        let copies = functions.iter().map(|(name, function)| (*name, (*function).clone())).collect::<Vec<_>>();
+        let symbol_table = Mutex::new(self.symbol_table.borrow().clone());
+        let scope = self.scope_state();
+        let checks = with_session_globals(|session_globals| {
+            copies
+                .into_par_iter()
+                .map_init(
+                    // Each thread checks its functions in its own copy of the symbol table.
+                    || symbol_table.lock().unwrap().clone(),
+                    |symbol_table, (name, function)| {
+                        SESSION_GLOBALS.set(session_globals, || {
+                            let (handler, buffer) = Handler::new_with_buf();
+                            let type_table = TypeTable::default();
+                            let mut checker =
+                                TypeChecker::for_scope(std::mem::take(symbol_table), &type_table, &handler, scope);
+                            checker.check_function(name, &function);
+
+                            let callees = checker.call_graph.neighbors(name).collect();
+                            *symbol_table = checker.symbol_table.take();
+                            let function_index = symbol_table.lookup_fn_symbol(name).unwrap().id;
+                            FunctionCheck {
+                                scope: symbol_table.scopes[function_index].take(),
+                                callees,
+                                types: type_table.entries(),
+                                loop_bounds: Loops::of(&function)
+                                    .into_iter()
+                                    .map(|loop_| (loop_.start_value.take(), loop_.stop_value.take()))
+                                    .collect(),
+                                errors: buffer.extract_errs().into_inner(),
+                                warnings: buffer.extract_warnings().into_inner(),
+                            }
+                        })
+                    },
+                )
+                .collect::<Vec<_>>()
+        });
+
+        for ((name, function), check) in functions.into_iter().zip(checks) {
+            let function_index = self.symbol_table.borrow().lookup_fn_symbol(name).unwrap().id;
+            self.symbol_table.borrow().scopes[function_index].replace(check.scope);
+            check.callees.into_iter().for_each(|callee| self.call_graph.add_edge(name, callee));
+            for (id, type_, span) in check.types {
+                self.type_table.insert(id, type_);
+                if let Some(span) = span {
+                    self.type_table.insert_span(id, span);
+                }
+            }
+            for (loop_, (start, stop)) in Loops::of(function).into_iter().zip(check.loop_bounds) {
+                loop_.start_value.replace(start);
+                loop_.stop_value.replace(stop);
+            }
+            check.errors.into_iter().for_each(|error| self.handler.emit_err(error));
+            check.warnings.into_iter().for_each(|warning| self.handler.emit_warning(warning));
+        }
+    }
+}
+
+/// Collects the loops in a function, in the order in which they appear.
+struct Loops<'a>(Vec<&'a IterationStatement>);
+
+impl<'a> Loops<'a> {
+    fn of(function: &'a Function) -> Vec<&'a IterationStatement> {
+        let mut loops = Self(Vec::new());
+        loops.visit_function(function);
+        loops.0
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for Loops<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_block_expression(
+        &mut self,
+        input: &'a BlockExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.visit_expression(&input.value, additional)
+    }
+
+    // The expressions and statements the parser failed on contain no loops.
+    fn visit_err(&mut self, _input: &'a ErrExpression, _additional: &Self::AdditionalInput) -> Self::Output {}
+}
+
+impl<'a> StatementVisitor<'a> for Loops<'a> {
+    fn visit_err_statement(&mut self, _input: &'a ErrStatement) {}
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.0.push(input);
+        self.visit_block(&input.block);
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for Loops<'a> {}
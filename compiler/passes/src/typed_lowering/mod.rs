@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Typed Lowering pass lowers a type-checked AST to the typed IR, in which every expression carries the type
+//! inferred for it by the type checker. Unsuffixed literals are given their inferred integer types, the shorthand
+//! members of struct initializers are made explicit, and the methods of structs become functions of the program
+//! scope under their mangled names. The AST is not modified.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! program test.aleo {
+//!     transition main(a: u8) -> u8 {
+//!         let b = a + 1;
+//!         return b;
+//!     }
+//! }
+//! ```
+//!
+//! In the typed IR, the literal `1` is the literal `1u8`, and both `a + 1` and the place `b` have the type `u8`.
+
+pub mod typed_lowerer;
+pub use typed_lowerer::*;
+
+use crate::{Pass, TypeTable};
+
+use leo_ast::{Ast, TypedProgram};
+use leo_errors::Result;
+
+impl<'a> Pass for TypedLowerer<'a> {
+    type Input = (&'a Ast, &'a TypeTable);
+    type Output = Result<TypedProgram>;
+
+    fn do_pass((ast, type_table): Self::Input) -> Self::Output {
+        let mut lowerer = TypedLowerer::new(type_table);
+        // Imported programs are lowered when they are compiled.
+        let program_scopes = ast
+            .as_repr()
+            .program_scopes
+            .iter()
+            .map(|(name, scope)| Ok((*name, lowerer.lower_program_scope(scope)?)))
+            .collect::<Result<_>>()?;
+
+        Ok(TypedProgram { program_scopes })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utilities::{parse_ast, type_check};
+
+    use leo_ast::{IntegerType, Literal, Type, TypedExpressionKind, TypedStatementKind};
+    use leo_span::{symbol::create_session_if_not_set_then, sym, Symbol};
+
+    #[test]
+    fn test_typed_lowering() {
+        create_session_if_not_set_then(|_| {
+            let program = "program test.aleo {
+    struct Point { x: u8, owner: address }
+    transition main(a: u8) -> Point {
+        let (b, c): (u8, bool) = (a + 1u8, true);
+        let x: u8 = b * 2;
+        let owner: address = self.caller;
+        return Point { x, owner };
+    }
+}
+";
+            let ast = parse_ast(program);
+            let type_table = TypeTable::default();
+            type_check(&ast, &type_table);
+            let typed_program = TypedLowerer::do_pass((&ast, &type_table)).unwrap();
+
+            let statements = &typed_program.program_scopes[&Symbol::intern("test")].functions[0].1.block.statements;
+            let u8_ = Type::Integer(IntegerType::U8);
+
+            // The variables of a tuple place are given the types of the elements of the tuple.
+            let TypedStatementKind::Definition { place, .. } = &statements[0].kind else { panic!("{statements:?}") };
+            let TypedExpressionKind::Tuple(elements) = &place.kind else { panic!("{place:?}") };
+            assert_eq!(
+                elements.iter().map(|element| element.type_.clone()).collect::<Vec<_>>(),
+                [u8_.clone(), Type::Boolean]
+            );
+
+            // Each subexpression carries its type.
+            let TypedStatementKind::Definition { value, .. } = &statements[1].kind else { panic!("{statements:?}") };
+            let TypedExpressionKind::Binary { left, right, .. } = &value.kind else { panic!("{value:?}") };
+            assert_eq!((&value.type_, &left.type_, &right.type_), (&u8_, &u8_, &u8_));
+            assert!(matches!(right.kind, TypedExpressionKind::Literal(Literal::Integer(IntegerType::U8, ..))));
+
+            // `self.caller` is a property of the environment.
+            let TypedStatementKind::Definition { value, .. } = &statements[2].kind else { panic!("{statements:?}") };
+            assert!(matches!(value.kind, TypedExpressionKind::Intrinsic { scope: sym::SelfLower, .. }), "{value:?}");
+            assert_eq!(value.type_, Type::Address);
+
+            // The shorthand members of a struct initializer are made explicit.
+            let TypedStatementKind::Return { expression, .. } = &statements[3].kind else { panic!("{statements:?}") };
+            let TypedExpressionKind::Struct { members, .. } = &expression.kind else { panic!("{expression:?}") };
+            assert_eq!(members[0].1.kind, TypedExpressionKind::Identifier(Symbol::intern("x")));
+            assert_eq!(members[0].1.type_, u8_);
+            assert_eq!(members[1].1.type_, Type::Address);
+
+            // The typed IR can be serialized for snapshots.
+            let json = typed_program.to_json_string().unwrap();
+            assert_eq!(TypedProgram::from_json_string(&json).unwrap().to_json_string().unwrap(), json);
+        })
+    }
+}
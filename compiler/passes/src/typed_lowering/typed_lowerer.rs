@@ -0,0 +1,363 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::*;
+use leo_errors::{AstError, Result};
+use leo_span::{sym, Symbol};
+
+use indexmap::IndexMap;
+
+pub struct TypedLowerer<'a> {
+    /// The types of the expressions, inferred by the type checker.
+    type_table: &'a TypeTable,
+    /// The types of the variables and constants defined so far.
+    /// Used for the identifiers that the type checker does not record, e.g. the shorthand members of a struct.
+    variables: IndexMap<Symbol, Type>,
+}
+
+impl<'a> TypedLowerer<'a> {
+    /// Returns a new typed lowerer given the type table produced by the type checker.
+    pub fn new(type_table: &'a TypeTable) -> Self {
+        Self { type_table, variables: IndexMap::new() }
+    }
+
+    /// Lowers a program scope to the typed IR.
+    pub fn lower_program_scope(&mut self, input: &ProgramScope) -> Result<TypedProgramScope> {
+        let consts = input
+            .consts
+            .iter()
+            .map(|(name, const_)| {
+                let value = self.lower_expression(&const_.value)?;
+                self.variables.insert(const_.place.name, const_.type_.clone());
+                Ok((*name, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut functions = input
+            .functions
+            .iter()
+            .map(|(name, function)| Ok((*name, self.lower_function(function)?)))
+            .collect::<Result<Vec<_>>>()?;
+        for (_, struct_) in &input.structs {
+            for (name, method) in &struct_.methods {
+                functions.push((Struct::method_name(struct_.name(), *name), self.lower_function(method)?));
+            }
+        }
+
+        Ok(TypedProgramScope {
+            program_id: input.program_id,
+            consts,
+            structs: input.structs.clone(),
+            mappings: input.mappings.clone(),
+            functions,
+            span: input.span,
+        })
+    }
+
+    /// Lowers a function, and its finalize block, to the typed IR.
+    fn lower_function(&mut self, input: &Function) -> Result<TypedFunction> {
+        // The variables of a function are not visible outside of it.
+        let globals = self.variables.clone();
+        let input_ = self.lower_inputs(&input.input);
+        let block = self.lower_block(&input.block)?;
+        self.variables = globals.clone();
+
+        let finalize = match &input.finalize {
+            Some(finalize) => {
+                let input_ = self.lower_inputs(&finalize.input);
+                let block = self.lower_block(&finalize.block)?;
+                self.variables = globals;
+                Some(TypedFinalize {
+                    identifier: finalize.identifier,
+                    input: input_,
+                    output_type: finalize.output_type.clone(),
+                    block,
+                    span: finalize.span,
+                    id: finalize.id,
+                })
+            }
+            None => None,
+        };
+
+        Ok(TypedFunction {
+            variant: input.variant,
+            identifier: input.identifier,
+            input: input_,
+            output_type: input.output_type.clone(),
+            block,
+            finalize,
+            span: input.span,
+            id: input.id,
+        })
+    }
+
+    /// Lowers the inputs of a function or finalize block, defining them as variables.
+    fn lower_inputs(&mut self, input: &[Input]) -> Vec<TypedFunctionInput> {
+        input
+            .iter()
+            .map(|input| {
+                let (identifier, type_) = (input.identifier(), input.type_());
+                self.variables.insert(identifier.name, type_.clone());
+                TypedFunctionInput { identifier, mode: input.mode(), type_ }
+            })
+            .collect()
+    }
+
+    /// Lowers a block to the typed IR.
+    fn lower_block(&mut self, input: &Block) -> Result<TypedBlock> {
+        let statements =
+            input.statements.iter().map(|statement| self.lower_statement(statement)).collect::<Result<_>>();
+        Ok(TypedBlock { statements: statements?, span: input.span, id: input.id })
+    }
+
+    /// Lowers a statement to the typed IR.
+    fn lower_statement(&mut self, input: &Statement) -> Result<TypedStatement> {
+        let kind = match input {
+            Statement::Assert(assert) => self.lower_assertion(&assert.variant)?,
+            Statement::Assign(assign) => {
+                let value = self.lower_expression(&assign.value)?;
+                let place = self.lower_place(&assign.place, &value.type_)?;
+                TypedStatementKind::Assign { place, value }
+            }
+            Statement::Block(block) => TypedStatementKind::Block(self.lower_block(block)?),
+            Statement::Conditional(conditional) => TypedStatementKind::Conditional {
+                condition: self.lower_expression(&conditional.condition)?,
+                then: self.lower_block(&conditional.then)?,
+                otherwise: match &conditional.otherwise {
+                    Some(otherwise) => Some(Box::new(self.lower_statement(otherwise)?)),
+                    None => None,
+                },
+            },
+            Statement::Console(console) => match &console.function {
+                ConsoleFunction::Assert(expression) => self.lower_assertion(&AssertVariant::Assert(expression.clone()))?,
+                ConsoleFunction::AssertEq(left, right) => {
+                    self.lower_assertion(&AssertVariant::AssertEq(left.clone(), right.clone()))?
+                }
+                ConsoleFunction::AssertNeq(left, right) => {
+                    self.lower_assertion(&AssertVariant::AssertNeq(left.clone(), right.clone()))?
+                }
+                ConsoleFunction::Log(args) => TypedStatementKind::Log {
+                    string: args.string.clone(),
+                    parameters: self.lower_expressions(&args.parameters)?,
+                },
+            },
+            Statement::Const(const_) => {
+                let value = self.lower_expression(&const_.value)?;
+                let place = self.lower_place(&Expression::Identifier(const_.place), &const_.type_)?;
+                TypedStatementKind::Definition { declaration_type: DeclarationType::Const, place, value }
+            }
+            Statement::Declaration(declaration) => {
+                self.variables.insert(declaration.identifier.name, declaration.type_.clone());
+                TypedStatementKind::Declaration {
+                    variable: declaration.identifier.name,
+                    type_: declaration.type_.clone(),
+                }
+            }
+            Statement::Definition(definition) => {
+                let value = self.lower_expression(&definition.value)?;
+                let type_ = definition.type_.clone().unwrap_or_else(|| value.type_.clone());
+                let place = self.lower_place(&definition.place, &type_)?;
+                TypedStatementKind::Definition { declaration_type: definition.declaration_type, place, value }
+            }
+            Statement::Err(_) => unreachable!("`ErrStatement`s should not be in the AST at this phase of compilation."),
+            Statement::Expression(statement) => {
+                TypedStatementKind::Expression(self.lower_expression(&statement.expression)?)
+            }
+            Statement::Iteration(iteration) => {
+                let start = self.lower_expression(&iteration.start)?;
+                let stop = self.lower_expression(&iteration.stop)?;
+                self.variables.insert(iteration.variable.name, iteration.type_.clone());
+                TypedStatementKind::Iteration {
+                    variable: iteration.variable.name,
+                    type_: iteration.type_.clone(),
+                    start,
+                    stop,
+                    inclusive: iteration.inclusive,
+                    block: self.lower_block(&iteration.block)?,
+                }
+            }
+            Statement::Match(_) => {
+                unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.")
+            }
+            Statement::Return(return_) => TypedStatementKind::Return {
+                expression: self.lower_expression(&return_.expression)?,
+                finalize_arguments: match &return_.finalize_arguments {
+                    Some(arguments) => Some(self.lower_expressions(arguments)?),
+                    None => None,
+                },
+            },
+        };
+
+        Ok(TypedStatement { kind, span: input.span(), id: input.id() })
+    }
+
+    /// Lowers an assertion to the typed IR.
+    fn lower_assertion(&mut self, input: &AssertVariant) -> Result<TypedStatementKind> {
+        Ok(match input {
+            AssertVariant::Assert(expression) => TypedStatementKind::Assert(self.lower_expression(expression)?),
+            AssertVariant::AssertEq(left, right) => {
+                TypedStatementKind::AssertEq(self.lower_expression(left)?, self.lower_expression(right)?)
+            }
+            AssertVariant::AssertNeq(left, right) => {
+                TypedStatementKind::AssertNeq(self.lower_expression(left)?, self.lower_expression(right)?)
+            }
+        })
+    }
+
+    /// Lowers the place of a definition or assignment, whose value has the type `type_`.
+    /// The variables of the place are defined with their types.
+    fn lower_place(&mut self, place: &Expression, type_: &Type) -> Result<TypedExpression> {
+        let kind = match (place, type_) {
+            (Expression::Identifier(identifier), _) => {
+                self.variables.insert(identifier.name, type_.clone());
+                TypedExpressionKind::Identifier(identifier.name)
+            }
+            (Expression::Tuple(tuple), Type::Tuple(types)) if tuple.elements.len() == types.length() => {
+                let elements = tuple
+                    .elements
+                    .iter()
+                    .zip(types.elements())
+                    .map(|(element, type_)| self.lower_place(element, type_));
+                TypedExpressionKind::Tuple(elements.collect::<Result<_>>()?)
+            }
+            _ => return self.lower_expression(place),
+        };
+
+        Ok(TypedExpression { kind, type_: type_.clone(), span: place.span(), id: place.id() })
+    }
+
+    /// Lowers a list of expressions to the typed IR.
+    fn lower_expressions(&mut self, input: &[Expression]) -> Result<Vec<TypedExpression>> {
+        input.iter().map(|expression| self.lower_expression(expression)).collect()
+    }
+
+    /// Lowers an expression to the typed IR.
+    fn lower_expression(&mut self, input: &Expression) -> Result<TypedExpression> {
+        let type_ = self.type_of(input)?;
+        let boxed = |lowerer: &mut Self, expression: &Expression| lowerer.lower_expression(expression).map(Box::new);
+
+        let kind = match input {
+            Expression::Access(AccessExpression::Array(access)) => TypedExpressionKind::ArrayAccess {
+                array: boxed(self, &access.array)?,
+                index: boxed(self, &access.index)?,
+            },
+            Expression::Access(AccessExpression::AssociatedConstant(constant)) => {
+                TypedExpressionKind::AssociatedConstant { ty: constant.ty.clone(), name: constant.name.name }
+            }
+            Expression::Access(AccessExpression::AssociatedFunction(function)) => {
+                TypedExpressionKind::AssociatedFunction {
+                    ty: function.ty.clone(),
+                    name: function.name.name,
+                    arguments: self.lower_expressions(&function.arguments)?,
+                }
+            }
+            Expression::Access(AccessExpression::Member(access)) => match access.inner.as_ref() {
+                // `self` and `block` are only values in the methods of structs, where `self` is the receiver.
+                Expression::Identifier(scope)
+                    if matches!(scope.name, sym::SelfLower | sym::block) && self.type_of(&access.inner).is_err() =>
+                {
+                    TypedExpressionKind::Intrinsic { scope: scope.name, name: access.name.name }
+                }
+                inner => TypedExpressionKind::MemberAccess { inner: boxed(self, inner)?, name: access.name.name },
+            },
+            Expression::Access(AccessExpression::Tuple(access)) => {
+                TypedExpressionKind::TupleAccess { tuple: boxed(self, &access.tuple)?, index: access.index.value() }
+            }
+            Expression::Array(array) => TypedExpressionKind::Array(self.lower_expressions(&array.elements)?),
+            Expression::Binary(binary) => TypedExpressionKind::Binary {
+                op: binary.op,
+                left: boxed(self, &binary.left)?,
+                right: boxed(self, &binary.right)?,
+            },
+            Expression::Block(block) => {
+                let statements = block.statements.iter().map(|statement| self.lower_statement(statement));
+                TypedExpressionKind::Block {
+                    statements: statements.collect::<Result<_>>()?,
+                    value: boxed(self, &block.value)?,
+                }
+            }
+            Expression::Call(call) => {
+                // Note that the parser guarantees that `call.function` is always an identifier or a method.
+                let (function, receiver) = match call.function.as_ref() {
+                    Expression::Access(AccessExpression::Member(method)) => {
+                        (method.name.name, Some(boxed(self, &method.inner)?))
+                    }
+                    function => (self.name_of(function)?, None),
+                };
+                let program = match &call.external {
+                    Some(external) => Some(self.name_of(external)?),
+                    None => None,
+                };
+                TypedExpressionKind::Call {
+                    function,
+                    receiver,
+                    program,
+                    arguments: self.lower_expressions(&call.arguments)?,
+                }
+            }
+            Expression::Cast(cast) => {
+                TypedExpressionKind::Cast { expression: boxed(self, &cast.expression)?, wrapped: cast.wrapped }
+            }
+            Expression::Struct(struct_) => {
+                let members = struct_.members.iter().map(|member| {
+                    let value = match &member.expression {
+                        Some(expression) => self.lower_expression(expression)?,
+                        // A shorthand initializer `Foo { x }` is the initializer `Foo { x: x }`.
+                        None => self.lower_expression(&Expression::Identifier(member.identifier))?,
+                    };
+                    Ok((member.identifier.name, value))
+                });
+                TypedExpressionKind::Struct { name: struct_.name.name, members: members.collect::<Result<_>>()? }
+            }
+            Expression::Err(_) => {
+                unreachable!("`ErrExpression`s should not be in the AST at this phase of compilation.")
+            }
+            Expression::Identifier(identifier) => TypedExpressionKind::Identifier(identifier.name),
+            Expression::Literal(literal) => TypedExpressionKind::Literal(literal.clone().with_inferred_type(&type_)),
+            Expression::Ternary(ternary) => TypedExpressionKind::Ternary {
+                condition: boxed(self, &ternary.condition)?,
+                if_true: boxed(self, &ternary.if_true)?,
+                if_false: boxed(self, &ternary.if_false)?,
+            },
+            Expression::Tuple(tuple) => TypedExpressionKind::Tuple(self.lower_expressions(&tuple.elements)?),
+            Expression::Unary(unary) => {
+                TypedExpressionKind::Unary { op: unary.op, receiver: boxed(self, &unary.receiver)? }
+            }
+            Expression::Unit(_) => TypedExpressionKind::Unit,
+        };
+
+        Ok(TypedExpression { kind, type_, span: input.span(), id: input.id() })
+    }
+
+    /// Returns the type of an expression, as inferred by the type checker.
+    fn type_of(&self, input: &Expression) -> Result<Type> {
+        let type_ = self.type_table.get(&input.id()).or_else(|| match input {
+            Expression::Identifier(identifier) => self.variables.get(&identifier.name).cloned(),
+            _ => None,
+        });
+        type_.ok_or_else(|| AstError::untyped_expression(input, input.span()).into())
+    }
+
+    /// Returns the name of a function or program in a call, which is an identifier.
+    fn name_of(&self, input: &Expression) -> Result<Symbol> {
+        match input {
+            Expression::Identifier(identifier) => Ok(identifier.name),
+            _ => Err(AstError::untyped_expression(input, input.span()).into()),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Unused Checking pass is a lint that warns of the variables that are never read, and of the imports that
+//! define none of the items the program refers to.
+//! A variable is read if an expression refers to it. Assigning to a variable does not read it.
+//! Variables and imports whose names start with an underscore are intentionally unused, so they are not reported.
+//! The pass is run after type checking, so every name the program refers to is known to be defined.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! import foo.leo;
+//!
+//! program test.aleo {
+//!     transition main(a: u8, _b: u8) -> u8 {
+//!         let c: u8 = a + 1u8;
+//!         let d: u8 = 2u8;
+//!         d = c;
+//!         return a;
+//!     }
+//! }
+//! ```
+//!
+//! The pass warns that `c`, `d`, and the import `foo.leo` are unused.
+
+pub mod unused_checker;
+pub use unused_checker::*;
+
+use crate::{Pass, SymbolTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::emitter::Handler;
+
+impl<'a> Pass for UnusedChecker<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a SymbolTable);
+    type Output = ();
+
+    fn do_pass((ast, handler, symbol_table): Self::Input) -> Self::Output {
+        let mut visitor = UnusedChecker::new(handler, symbol_table);
+        visitor.visit_program(ast.as_repr());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_utilities::parse, SymbolTableCreator, TypeChecker, TypeTable};
+
+    use leo_ast::{ImportTrace, NodeBuilder};
+    use leo_span::{symbol::create_session_if_not_set_then, Span, Symbol};
+
+    #[test]
+    fn test_unused_variables_and_imports() {
+        create_session_if_not_set_then(|_| {
+            let node_builder = NodeBuilder::default();
+            let used = parse("program used.aleo { public struct Point { x: u8 } }", &node_builder);
+            let unused = parse("program unused.aleo { struct Other { y: u8 } }", &node_builder);
+            let program = "program test.aleo {
+    transition main(a: u8, _b: u8, c: u8) -> u8 {
+        let p: Point = Point { x: a };
+        let (d, e): (u8, u8) = (1u8, 2u8);
+        let f: u8 = 3u8;
+        f = d;
+        for i: u8 in 0u8..2u8 {
+            let _g: u8 = p.x;
+        }
+        return p.x;
+    }
+}
+";
+            let mut program = parse(program, &node_builder);
+            program.imports.insert(Symbol::intern("used"), (used, Span::dummy()));
+            program.imports.insert(Symbol::intern("unused"), (unused, Span::dummy()));
+            let stub = leo_parser::parse_stub(&node_builder, "program deployed.aleo;\n", Default::default()).unwrap();
+            program.stubs.insert(Symbol::intern("deployed"), (stub, Span::dummy()));
+            let ast = Ast::new(program);
+
+            let (handler, buffer) = Handler::new_with_buf();
+            let symbol_table = SymbolTableCreator::do_pass((&ast, &handler, &ImportTrace::default())).unwrap();
+            let (symbol_table, _, _) =
+                TypeChecker::do_pass((&ast, &handler, symbol_table, &TypeTable::default())).unwrap();
+            UnusedChecker::do_pass((&ast, &handler, &symbol_table));
+
+            let warnings = buffer.extract_warnings().into_inner();
+            let warnings = warnings.iter().map(|warning| warning.to_string()).collect::<Vec<_>>();
+            assert_eq!(warnings.len(), 6, "{warnings:#?}");
+            assert!(warnings[0].contains("unused variable `i`"), "{}", warnings[0]);
+            assert!(warnings[1].contains("unused variable `e`"), "{}", warnings[1]);
+            // A variable that is only assigned to is unused.
+            assert!(warnings[2].contains("unused variable `f`"), "{}", warnings[2]);
+            assert!(warnings[3].contains("unused variable `c`"), "{}", warnings[3]);
+            assert!(warnings[3].contains("prefix it with an underscore: `_c`"), "{}", warnings[3]);
+            assert!(warnings[4].contains("unused import `unused.leo`"), "{}", warnings[4]);
+            assert!(warnings[5].contains("unused import `deployed.aleo`"), "{}", warnings[5]);
+        })
+    }
+
+    #[test]
+    fn test_unused_finalize_inputs() {
+        create_session_if_not_set_then(|_| {
+            let program = "program test.aleo {
+    mapping counts: u8 => u8;
+    transition count(a: u8) {
+        return then finalize(a, 1u8);
+    }
+    finalize count(a: u8, b: u8) {
+        Mapping::set(counts, 0u8, a);
+    }
+}
+";
+            let ast = Ast::new(parse(program, &NodeBuilder::default()));
+
+            let (handler, buffer) = Handler::new_with_buf();
+            let symbol_table = SymbolTableCreator::do_pass((&ast, &handler, &ImportTrace::default())).unwrap();
+            let (symbol_table, _, _) =
+                TypeChecker::do_pass((&ast, &handler, symbol_table, &TypeTable::default())).unwrap();
+            UnusedChecker::do_pass((&ast, &handler, &symbol_table));
+
+            // The inputs of a finalize block are checked by `visit_finalize`.
+            let warnings = buffer.extract_warnings().into_inner();
+            let warnings = warnings.iter().map(|warning| warning.to_string()).collect::<Vec<_>>();
+            assert_eq!(warnings.len(), 1, "{warnings:#?}");
+            assert!(warnings[0].contains("unused variable `b`"), "{}", warnings[0]);
+        })
+    }
+}
@@ -0,0 +1,253 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{type_checking::cache::type_names, SymbolTable};
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, TypeCheckerWarning};
+use leo_span::{sym, Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A variable in scope, with the span of its definition and whether it has been read.
+struct Variable {
+    span: Span,
+    is_read: bool,
+}
+
+pub struct UnusedChecker<'a> {
+    /// An error handler used for the warnings found during unused checking.
+    handler: &'a Handler,
+    /// The symbol table, which records the imports that define each imported item.
+    symbol_table: &'a SymbolTable,
+    /// The variables in scope, innermost scope last.
+    scopes: Vec<IndexMap<Symbol, Variable>>,
+    /// The names that the program refers to that are not variables in scope, e.g. functions, structs, and imports.
+    referenced: Vec<Symbol>,
+}
+
+impl<'a> UnusedChecker<'a> {
+    /// Returns a new unused checker given an error handler and the symbol table of the program.
+    pub fn new(handler: &'a Handler, symbol_table: &'a SymbolTable) -> Self {
+        Self { handler, symbol_table, scopes: Vec::new(), referenced: Vec::new() }
+    }
+
+    /// Runs `check` in a new scope, warning of the variables defined in the scope that were not read.
+    fn in_scope(&mut self, check: impl FnOnce(&mut Self)) {
+        self.scopes.push(IndexMap::new());
+        check(self);
+        for (name, variable) in self.scopes.pop().unwrap() {
+            if !variable.is_read {
+                self.handler.emit_warning(TypeCheckerWarning::unused_variable(name, variable.span).into());
+            }
+        }
+    }
+
+    /// Defines the variable `identifier` in the innermost scope.
+    /// Variables whose names start with an underscore are intentionally unused, so they are not tracked.
+    fn define(&mut self, identifier: &Identifier) {
+        if identifier.name == sym::SelfLower || identifier.name.to_string().starts_with('_') {
+            return;
+        }
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(identifier.name, Variable { span: identifier.span, is_read: false });
+        }
+    }
+
+    /// Defines the variables bound by `place`, which is an identifier or a tuple of identifiers.
+    fn define_place(&mut self, place: &Expression) {
+        match place {
+            Expression::Identifier(identifier) => self.define(identifier),
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| self.define_place(element)),
+            _ => {}
+        }
+    }
+
+    /// Records the names of the structs and records in `type_` as referenced.
+    fn reference_type(&mut self, type_: &Type) {
+        type_names(type_, &mut self.referenced);
+    }
+
+    /// Checks the inputs and body of a function or finalize block.
+    fn check_body(&mut self, input: &'a [Input], output_type: &Type, block: &'a Block) {
+        input.iter().for_each(|input| self.reference_type(&input.type_()));
+        self.reference_type(output_type);
+        self.in_scope(|checker| {
+            input.iter().for_each(|input| checker.define(&input.identifier()));
+            checker.visit_block(block);
+        });
+    }
+
+    /// Warns of the imports that define none of the items the program refers to.
+    /// Each import is given by its name, the span of its import statement, and the extension of the imported file.
+    fn check_imports(&self, imports: impl Iterator<Item = (Symbol, Span, &'static str)>) {
+        let used = self
+            .referenced
+            .iter()
+            .flat_map(|name| [Some(*name), self.symbol_table.lookup_import(*name)])
+            .flatten()
+            .collect::<IndexSet<_>>();
+        for (name, span, extension) in imports {
+            if !used.contains(&name) && !name.to_string().starts_with('_') {
+                let warning = TypeCheckerWarning::unused_import(format!("{name}.{extension}"), span);
+                self.handler.emit_warning(warning.into());
+            }
+        }
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for UnusedChecker<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
+            AccessExpression::AssociatedConstant(constant) => self.reference_type(&constant.ty),
+            AccessExpression::AssociatedFunction(function) => {
+                self.reference_type(&function.ty);
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+        }
+    }
+
+    fn visit_block_expression(
+        &mut self,
+        input: &'a BlockExpression,
+        additional: &Self::AdditionalInput,
+    ) -> Self::Output {
+        self.in_scope(|checker| {
+            input.statements.iter().for_each(|statement| checker.visit_statement(statement));
+            checker.visit_expression(&input.value, additional)
+        })
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // A call to another program, e.g. `foo.leo/bar()`, refers to the import `foo`.
+        if let Some(external) = &input.external {
+            self.visit_expression(external, additional);
+        }
+        self.visit_expression(&input.function, additional);
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, additional);
+        self.reference_type(&input.type_);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.referenced.push(input.name.name);
+        for member in &input.members {
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                // A shorthand initializer `Foo { x }` reads the variable `x`.
+                None => self.visit_identifier(&member.identifier, additional),
+            }
+        }
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        match self.scopes.iter_mut().rev().find_map(|scope| scope.get_mut(&input.name)) {
+            Some(variable) => variable.is_read = true,
+            None => self.referenced.push(input.name),
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for UnusedChecker<'a> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.value, &());
+        // Assigning to a variable does not read it, but the index of an array access or the struct of a member
+        // access in the place is read.
+        match &input.place {
+            Expression::Identifier(_) => {}
+            Expression::Tuple(tuple) => tuple
+                .elements
+                .iter()
+                .filter(|element| !matches!(element, Expression::Identifier(_)))
+                .for_each(|element| self.visit_expression(element, &())),
+            place => self.visit_expression(place, &()),
+        }
+    }
+
+    fn visit_block(&mut self, input: &'a Block) {
+        self.in_scope(|checker| input.statements.iter().for_each(|statement| checker.visit_statement(statement)));
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.reference_type(&input.type_);
+        self.visit_expression(&input.value, &());
+        self.define(&input.place);
+    }
+
+    fn visit_declaration(&mut self, input: &'a DeclarationStatement) {
+        self.reference_type(&input.type_);
+        self.define(&input.identifier);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        input.type_.iter().for_each(|type_| self.reference_type(type_));
+        self.visit_expression(&input.value, &());
+        self.define_place(&input.place);
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.reference_type(&input.type_);
+        self.visit_expression(&input.start, &());
+        self.visit_expression(&input.stop, &());
+        self.in_scope(|checker| {
+            checker.define(&input.variable);
+            checker.visit_block(&input.block);
+        });
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for UnusedChecker<'a> {
+    fn visit_program(&mut self, input: &'a Program) {
+        // Imported programs are not linted, since they are not part of the program being compiled.
+        input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
+        let imports = input.imports.iter().map(|(name, (_, span))| (*name, *span, "leo"));
+        let stubs = input.stubs.iter().map(|(name, (_, span))| (*name, *span, "aleo"));
+        self.check_imports(imports.chain(stubs));
+    }
+
+    fn visit_struct(&mut self, input: &'a Struct) {
+        input.members.iter().for_each(|member| self.reference_type(&member.type_));
+        input.methods.iter().for_each(|(_, method)| self.visit_function(method));
+    }
+
+    fn visit_mapping(&mut self, input: &'a Mapping) {
+        self.reference_type(&input.key_type);
+        self.reference_type(&input.value_type);
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.check_body(&input.input, &input.output_type, &input.block);
+        if let Some(finalize) = &input.finalize {
+            self.visit_finalize(finalize);
+        }
+    }
+
+    fn visit_finalize(&mut self, input: &'a Finalize) {
+        self.check_body(&input.input, &input.output_type, &input.block);
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+
+//! The Wrapping Desugaring pass removes the `@wrapping` annotation from blocks.
+//! Inside a block annotated with `@wrapping`, including any nested blocks, the operators `+`, `-` and `*` are
+//! replaced with their wrapping counterparts `add_wrapped`, `sub_wrapped` and `mul_wrapped`.
+//! The same applies to the compound assignments `+=`, `-=` and `*=`, which the parser already expands.
+//! It runs directly after parsing, so that the remaining passes never see the annotation.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! function hash(a: u8, b: u8) -> u8 {
+//!     let c: u8 = a + b;
+//!     @wrapping {
+//!         c = c * 31u8 + a;
+//!     }
+//!     return c;
+//! }
+//! ```
+//!
+//! The wrapping desugaring pass produces the following code.
+//! ```leo
+//! function hash(a: u8, b: u8) -> u8 {
+//!     let c: u8 = a + b;
+//!     {
+//!         c = c.mul_wrapped(31u8).add_wrapped(a);
+//!     }
+//!     return c;
+//! }
+//! ```
+
+pub mod wrapping_desugarer;
+pub use wrapping_desugarer::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+
+impl Pass for WrappingDesugarer {
+    type Input = Ast;
+    type Output = Ast;
+
+    fn do_pass(ast: Self::Input) -> Self::Output {
+        Ast::new(WrappingDesugarer::default().reconstruct_program(ast.into_repr()))
+    }
+}
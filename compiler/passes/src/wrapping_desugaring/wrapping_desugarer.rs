@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+
+use leo_ast::*;
+
+#[derive(Default)]
+pub struct WrappingDesugarer {
+    /// Whether the desugarer is inside a block annotated with `@wrapping`.
+    is_wrapping: bool,
+}
+
+impl ExpressionReconstructor for WrappingDesugarer {
+    type AdditionalOutput = ();
+
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let op = match input.op {
+            BinaryOperation::Add if self.is_wrapping => BinaryOperation::AddWrapped,
+            BinaryOperation::Sub if self.is_wrapping => BinaryOperation::SubWrapped,
+            BinaryOperation::Mul if self.is_wrapping => BinaryOperation::MulWrapped,
+            op => op,
+        };
+        (
+            Expression::Binary(BinaryExpression {
+                left: Box::new(self.reconstruct_expression(*input.left).0),
+                right: Box::new(self.reconstruct_expression(*input.right).0),
+                op,
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_err(&mut self, input: ErrExpression) -> (Expression, Self::AdditionalOutput) {
+        (Expression::Err(input), Default::default())
+    }
+}
+
+impl StatementReconstructor for WrappingDesugarer {
+    /// Reconstructs a block, removing its `@wrapping` annotation and wrapping its arithmetic if it has one.
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        let (wrapping, annotations): (Vec<_>, Vec<_>) =
+            input.annotations.into_iter().partition(|annotation| annotation.is_wrapping());
+
+        let prior_is_wrapping = self.is_wrapping;
+        self.is_wrapping |= !wrapping.is_empty();
+        let statements = input.statements.into_iter().map(|s| self.reconstruct_statement(s).0).collect();
+        self.is_wrapping = prior_is_wrapping;
+
+        (Block { annotations, statements, ..input }, Default::default())
+    }
+
+    fn reconstruct_err_statement(&mut self, input: ErrStatement) -> (Statement, Self::AdditionalOutput) {
+        (Statement::Err(input), Default::default())
+    }
+}
+
+impl ProgramReconstructor for WrappingDesugarer {
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            methods: input
+                .methods
+                .into_iter()
+                .map(|(name, method)| (name, self.reconstruct_function(method)))
+                .collect(),
+            ..input
+        }
+    }
+}
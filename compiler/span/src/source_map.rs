@@ -15,13 +15,13 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::span::{BytePos, CharPos, Pos, Span};
+use once_cell::sync::OnceCell;
 use std::{
-    cell::RefCell,
     fmt,
     fs,
     io,
     path::{Path, PathBuf},
-    rc::Rc,
+    sync::{Arc, RwLock},
 };
 
 /// The source map containing all recorded sources,
@@ -30,7 +30,8 @@ use std::{
 #[derive(Default)]
 pub struct SourceMap {
     /// The actual source map data.
-    inner: RefCell<SourceMapInner>,
+    /// `RwLock` is used here so that the source map can be shared by the threads of a compiler session.
+    inner: RwLock<SourceMapInner>,
 }
 
 /// Actual data of the source map.
@@ -44,37 +45,49 @@ struct SourceMapInner {
     ///
     /// The list is append-only with mappings from the start byte position
     /// for fast lookup from a `Span` to its `SourceFile`.
-    source_files: Vec<Rc<SourceFile>>,
+    source_files: Vec<Arc<SourceFile>>,
 }
 
 impl SourceMap {
     /// Loads the given `path` and returns a `SourceFile` for it.
-    pub fn load_file(&self, path: &Path) -> io::Result<Rc<SourceFile>> {
+    pub fn load_file(&self, path: &Path) -> io::Result<Arc<SourceFile>> {
         Ok(self.new_source(&fs::read_to_string(path)?, FileName::Real(path.to_owned())))
     }
 
     /// Registers `source` under the given file `name`, returning a `SourceFile` back.
-    pub fn new_source(&self, source: &str, name: FileName) -> Rc<SourceFile> {
+    pub fn new_source(&self, source: &str, name: FileName) -> Arc<SourceFile> {
         let len = u32::try_from(source.len()).unwrap();
-        let mut inner = self.inner.borrow_mut();
+        let mut inner = self.inner.write().unwrap();
         let start_pos = inner.try_allocate_address_space(len).unwrap();
-        let source_file = Rc::new(SourceFile::new(name, source.to_owned(), start_pos));
+        let source_file = Arc::new(SourceFile::new(name, source.to_owned(), start_pos));
         inner.source_files.push(source_file.clone());
         source_file
     }
 
-    /// Find the index for the source file containing `pos`.
-    fn find_source_file_index(&self, pos: BytePos) -> Option<usize> {
-        self.inner
-            .borrow()
-            .source_files
-            .binary_search_by_key(&pos, |file| file.start_pos)
-            .map_or_else(|p| p.checked_sub(1), Some)
+    /// Removes the source files for which `keep` returns `false`, e.g. the files of an earlier build in watch mode.
+    /// The address space after the last remaining file is reused for the files registered afterwards.
+    pub fn retain(&self, mut keep: impl FnMut(&SourceFile) -> bool) {
+        let mut inner = self.inner.write().unwrap();
+        inner.source_files.retain(|file| keep(file));
+        inner.used_address_space = inner.source_files.last().map_or(0, |file| file.end_pos.0 + 1);
+    }
+
+    /// Returns the number of source files in the source map.
+    pub fn len(&self) -> usize {
+        self.inner.read().unwrap().source_files.len()
+    }
+
+    /// Returns `true` if the source map holds no source files.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
     /// Find the source file containing `pos`.
-    fn find_source_file(&self, pos: BytePos) -> Option<Rc<SourceFile>> {
-        Some(self.inner.borrow().source_files[self.find_source_file_index(pos)?].clone())
+    fn find_source_file(&self, pos: BytePos) -> Option<Arc<SourceFile>> {
+        let source_files = &self.inner.read().unwrap().source_files;
+        let index =
+            source_files.binary_search_by_key(&pos, |file| file.start_pos).map_or_else(|p| p.checked_sub(1), Some)?;
+        Some(source_files[index].clone())
     }
 
     /// Finds line column info about a given `pos`.
@@ -138,8 +151,9 @@ impl SourceMap {
 
         let idx_lo = begin.lookup_line(span.lo).unwrap_or(0);
         let idx_hi = begin.lookup_line(span.hi).unwrap_or(0) + 1;
-        let lo_line_pos = begin.lines[idx_lo];
-        let hi_line_pos = if idx_hi < begin.lines.len() { begin.lines[idx_hi] } else { begin.end_pos };
+        let lines = &begin.analysis().lines;
+        let lo_line_pos = lines[idx_lo];
+        let hi_line_pos = if idx_hi < lines.len() { lines[idx_hi] } else { begin.end_pos };
         Some(begin.contents_of_span(Span::new(lo_line_pos, hi_line_pos)))
     }
 }
@@ -191,6 +205,24 @@ pub struct SourceFile {
     pub start_pos: BytePos,
     /// The end position of this source in the `SourceMap`.
     pub end_pos: BytePos,
+    /// The lines and multi-byte characters of the source code.
+    /// They are only needed to resolve the line and column of a position,
+    /// so they are found the first time that a position in the file is resolved, rather than when it is loaded.
+    analysis: OnceCell<SourceAnalysis>,
+}
+
+impl fmt::Debug for SourceFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SourceFile")
+            .field("name", &self.name.to_string())
+            .field("start_pos", &self.start_pos)
+            .field("end_pos", &self.end_pos)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The locations of the lines and multi-byte characters in the source code of a `SourceFile`.
+struct SourceAnalysis {
     /// Locations of line beginnings in the source code.
     lines: Vec<BytePos>,
     /// Locations of multi-byte characters in the source code.
@@ -205,8 +237,15 @@ impl SourceFile {
     fn new(name: FileName, mut src: String, start_pos: BytePos) -> Self {
         normalize_src(&mut src);
         let end_pos = start_pos + BytePos::from_usize(src.len());
-        let (lines, multibyte_chars) = analyze_source_file(&src, start_pos);
-        Self { name, src, start_pos, end_pos, lines, multibyte_chars }
+        Self { name, src, start_pos, end_pos, analysis: OnceCell::new() }
+    }
+
+    /// Returns the lines and multi-byte characters of the source code, finding them if this is the first lookup.
+    fn analysis(&self) -> &SourceAnalysis {
+        self.analysis.get_or_init(|| {
+            let (lines, multibyte_chars) = analyze_source_file(&self.src, self.start_pos);
+            SourceAnalysis { lines, multibyte_chars }
+        })
     }
 
     /// Converts an absolute `BytePos` to a `CharPos` relative to the `SourceFile`.
@@ -214,7 +253,7 @@ impl SourceFile {
         // The number of extra bytes due to multibyte chars in the `SourceFile`.
         let mut total_extra_bytes = 0;
 
-        for mbc in self.multibyte_chars.iter() {
+        for mbc in self.analysis().multibyte_chars.iter() {
             if mbc.pos < bpos {
                 // Every character is at least one byte, so we only
                 // count the actual extra bytes.
@@ -236,7 +275,7 @@ impl SourceFile {
     /// number. If the source_file is empty or the position is located before the
     /// first line, `None` is returned.
     fn lookup_line(&self, pos: BytePos) -> Option<usize> {
-        match self.lines.binary_search(&pos) {
+        match self.analysis().lines.binary_search(&pos) {
             Ok(idx) => Some(idx),
             Err(0) => None,
             Err(idx) => Some(idx - 1),
@@ -250,7 +289,7 @@ impl SourceFile {
         match self.lookup_line(pos) {
             Some(a) => {
                 let line = a + 1; // Line numbers start at 1
-                let linebpos = self.lines[a];
+                let linebpos = self.analysis().lines[a];
                 let linechpos = self.bytepos_to_file_charpos(linebpos);
                 let col = chpos - linechpos;
                 assert!(chpos >= linechpos);
@@ -262,15 +301,17 @@ impl SourceFile {
 
     /// Returns contents of a `span` assumed to be within the given file.
     fn contents_of_span(&self, span: Span) -> String {
-        let begin_pos = self.bytepos_to_file_charpos(span.lo).to_usize();
-        let end_pos = self.bytepos_to_file_charpos(span.hi).to_usize();
+        // The positions are offsets in bytes, so the source is sliced by them directly,
+        // which also keeps multi-byte characters before the span from shifting it.
+        let begin_pos = (span.lo - self.start_pos).to_usize();
+        let end_pos = (span.hi - self.start_pos).to_usize();
         String::from_utf8_lossy(&self.src.as_bytes()[begin_pos..end_pos]).into_owned()
     }
 }
 
 /// Detailed information on a `Span`.
 pub struct SpanLocation {
-    pub source_file: Rc<SourceFile>,
+    pub source_file: Arc<SourceFile>,
     pub line_start: usize,
     pub line_stop: usize,
     pub col_start: usize,
@@ -283,13 +324,12 @@ impl SpanLocation {
         let dummy = "<dummy>".to_owned();
         let span = Span::dummy();
         Self {
-            source_file: Rc::new(SourceFile {
+            source_file: Arc::new(SourceFile {
                 name: FileName::Custom(dummy.clone()),
                 src: dummy,
                 start_pos: span.lo,
                 end_pos: span.hi,
-                lines: Vec::new(),
-                multibyte_chars: Vec::new(),
+                analysis: OnceCell::with_value(SourceAnalysis { lines: Vec::new(), multibyte_chars: Vec::new() }),
             }),
             line_start: 0,
             line_stop: 0,
@@ -302,7 +342,7 @@ impl SpanLocation {
 /// File / Line / Column information on a `BytePos`.
 pub struct LineCol {
     /// Information on the original source.
-    pub source_file: Rc<SourceFile>,
+    pub source_file: Arc<SourceFile>,
     /// The 1-based line number.
     pub line: usize,
     /// The (0-based) column offset into the line.
@@ -435,3 +475,35 @@ fn analyze_source_file(src: &str, source_file_start_pos: BytePos) -> (Vec<BytePo
 
     (lines, multi_byte_chars)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_positions_on_demand() {
+        let source_map = SourceMap::default();
+        let first = source_map.new_source("let a = 1u8;\nlet b = a;", FileName::Custom("first.leo".into()));
+        let second = source_map.new_source("// é\nlet ü = 2u8;", FileName::Custom("second.leo".into()));
+
+        // Loading a source does not analyze its lines.
+        assert!(first.analysis.get().is_none());
+        assert!(second.analysis.get().is_none());
+
+        // The span of `a` on the second line of the first file.
+        let span = Span::new(first.start_pos + BytePos(21), first.start_pos + BytePos(22));
+        let loc = source_map.span_to_location(span).unwrap();
+        assert_eq!((loc.line_start, loc.col_start, loc.col_stop), (2, 9, 10));
+        assert!(first.analysis.get().is_some());
+        assert!(second.analysis.get().is_none());
+
+        // The span of `ü = 2u8` in the second file, after a multi-byte character, is resolved in characters
+        // for its column, and in bytes for its contents.
+        let span = Span::new(second.start_pos + BytePos(10), second.start_pos + BytePos(18));
+        let loc = source_map.span_to_location(span).unwrap();
+        assert_eq!(loc.source_file.start_pos, second.start_pos);
+        assert_eq!((loc.line_start, loc.col_start, loc.col_stop), (2, 5, 12));
+        assert_eq!(source_map.contents_of_span(span).unwrap(), "ü = 2u8");
+        assert_eq!(source_map.line_contents_of_span(span).unwrap(), "let ü = 2u8;");
+    }
+}
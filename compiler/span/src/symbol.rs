@@ -28,7 +28,7 @@ use core::{
 use fxhash::FxBuildHasher;
 use indexmap::IndexSet;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::cell::RefCell;
+use std::sync::RwLock;
 
 /// A helper for `symbols` defined below.
 /// The macro's job is to bind conveniently  usable `const` items to the symbol names provided.
@@ -141,19 +141,41 @@ symbols! {
     sub_wrapped,
     xor,
 
+    // cast variants
+    wrapped,
+
     // core constants
     GEN,
 
     // core functions
+    Array,
     BHP256,
     BHP512,
     BHP768,
     BHP1024,
     ChaCha,
+    commit,
     commit_to_address,
     commit_to_field,
     commit_to_group,
     contains,
+    ElGamal,
+    encrypt,
+    from_bits_le,
+    from_bytes_le,
+    from_field,
+    from_field_wrapped,
+    from_group,
+    from_i8,
+    from_i16,
+    from_i32,
+    from_i64,
+    from_i128,
+    from_u8,
+    from_u16,
+    from_u32,
+    from_u64,
+    from_u128,
     get,
     get_or_use,
     hash_to_address,
@@ -170,9 +192,11 @@ symbols! {
     hash_to_u64,
     hash_to_u128,
     hash_to_scalar,
+    hash_many_to_field,
     Keccak256,
     Keccak384,
     Keccak512,
+    lookup,
     Mapping,
     Pedersen64,
     Pedersen128,
@@ -199,8 +223,13 @@ symbols! {
     SHA3_256,
     SHA3_384,
     SHA3_512,
+    to_bits_le,
+    to_bytes_le,
+    to_field,
+    to_group,
     to_x_coordinate,
     to_y_coordinate,
+    try_from_field,
     verify,
 
     // types
@@ -238,7 +267,9 @@ symbols! {
     Const: "const",
     constant,
     decrement,
+    deprecated,
     Else: "else",
+    Enum: "enum",
     finalize,
     For: "for",
     function,
@@ -248,10 +279,14 @@ symbols! {
     increment,
     inline,
     input,
+    interface,
     Let: "let",
     leo,
+    log,
     main,
     mapping,
+    Match: "match",
+    module,
     Mut: "mut",
     Return: "return",
     SelfLower: "self",
@@ -268,8 +303,15 @@ symbols! {
     owner,
     _nonce,
     program,
+    test,
+    wrapping,
+    deny,
+    warn,
+    mixed_signedness,
     block,
     height,
+    x,
+    y,
 }
 
 /// An interned string.
@@ -412,8 +454,9 @@ struct InnerInterner {
 }
 
 /// A symbol-to-string interner.
+/// `RwLock` is used here so that symbols can be interned and resolved by the threads of a compiler session.
 struct Interner {
-    inner: RefCell<InnerInterner>,
+    inner: RwLock<InnerInterner>,
 }
 
 impl Interner {
@@ -428,12 +471,17 @@ impl Interner {
             // arena: <_>::default(),
             set: init.iter().copied().map(InternedStr::Static).collect(),
         };
-        Self { inner: RefCell::new(inner) }
+        Self { inner: RwLock::new(inner) }
     }
 
     /// Interns `string`, returning a `Symbol` corresponding to it.
     fn intern(&self, string: &str) -> Symbol {
-        let InnerInterner { set } = &mut *self.inner.borrow_mut();
+        // Most strings are already interned, so look them up under a read lock first.
+        if let Some(sym) = self.inner.read().unwrap().set.get_index_of(string) {
+            return Symbol::new(sym as u32);
+        }
+
+        let InnerInterner { set } = &mut *self.inner.write().unwrap();
 
         if let Some(sym) = set.get_index_of(string) {
             // Already interned, return that symbol.
@@ -445,7 +493,7 @@ impl Interner {
 
     /// Returns the corresponding string for the given symbol.
     fn get<R>(&self, symbol: Symbol, with: impl FnOnce(&str) -> R) -> R {
-        let set = &self.inner.borrow().set;
+        let set = &self.inner.read().unwrap().set;
         with(set.get_index(symbol.as_u32() as usize).unwrap())
     }
 }
@@ -14,14 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Backtraced, INDENT};
+use crate::{Backtraced, Label, INDENT};
 
 use leo_span::{source_map::SpanLocation, symbol::with_session_globals, Span};
 
 use backtrace::Backtrace;
 use color_backtrace::{BacktracePrinter, Verbosity};
 use colored::Colorize;
-use std::fmt;
+use std::{fmt, sync::Arc};
 
 /// Formatted compiler error type
 ///     undefined value `x`
@@ -36,6 +36,8 @@ use std::fmt;
 pub struct Formatted {
     /// The formatted error span information.
     pub span: Span,
+    /// The secondary spans of the error, e.g. the declaration that a use of it does not match.
+    pub labels: Vec<Label>,
     /// The backtrace to track where the Leo error originated.
     pub backtrace: Backtraced,
 }
@@ -58,6 +60,7 @@ impl Formatted {
     {
         Self {
             span,
+            labels: Vec::new(),
             backtrace: Backtraced::new_from_backtrace(
                 message.to_string(),
                 help,
@@ -70,6 +73,12 @@ impl Formatted {
         }
     }
 
+    /// Attaches the secondary span `label` to the error.
+    pub fn with_label(mut self, label: Label) -> Self {
+        self.labels.push(label);
+        self
+    }
+
     /// Calls the backtraces error exit code.
     pub fn exit_code(&self) -> i32 {
         self.backtrace.exit_code()
@@ -86,36 +95,101 @@ impl Formatted {
     }
 }
 
-impl fmt::Display for Formatted {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let underline = |mut start: usize, mut end: usize| -> String {
-            if start > end {
-                std::mem::swap(&mut start, &mut end)
-            }
+/// Returns the underline of the columns from `start` to `end`, drawn with `marker`.
+fn underline(mut start: usize, mut end: usize, marker: char) -> String {
+    if start > end {
+        std::mem::swap(&mut start, &mut end)
+    }
 
-            let mut underline = String::new();
+    let mut underline = String::new();
 
-            for _ in 0..start {
-                underline.push(' ');
-                end -= 1;
-            }
+    for _ in 0..start {
+        underline.push(' ');
+        end -= 1;
+    }
 
-            for _ in 0..end {
-                underline.push('^');
-            }
+    for _ in 0..end {
+        underline.push(marker);
+    }
 
-            underline
-        };
+    underline
+}
+
+/// A span to underline in an excerpt of its source file.
+struct Annotation<'a> {
+    /// The location of the span.
+    loc: SpanLocation,
+    /// The lines of source code that the span covers.
+    contents: String,
+    /// The character to underline the span with.
+    marker: char,
+    /// The note to show after the underline, if any.
+    message: Option<&'a str>,
+}
 
+impl<'a> Annotation<'a> {
+    /// Looks up the location and lines of `span`, to underline them with `marker`.
+    fn new(span: Span, marker: char, message: Option<&'a str>) -> Self {
         let (loc, contents) = with_session_globals(|s| {
             (
-                s.source_map.span_to_location(self.span).unwrap_or_else(SpanLocation::dummy),
-                s.source_map.line_contents_of_span(self.span).unwrap_or_else(|| "<contents unavailable>".to_owned()),
+                s.source_map.span_to_location(span).unwrap_or_else(SpanLocation::dummy),
+                s.source_map.line_contents_of_span(span).unwrap_or_else(|| "<contents unavailable>".to_owned()),
             )
         });
+        Self { loc, contents, marker, message }
+    }
+
+    /// Returns `true` if the span is in the same source file as the span of `other`.
+    fn is_in_file_of(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.loc.source_file, &other.loc.source_file)
+    }
+}
 
-        let underlined = underline(loc.col_start, loc.col_stop);
+/// Writes the location of the first of `annotations` after `arrow`, and the lines of all of them in order,
+/// each followed by its underline and note. Lines that are skipped between two annotations are elided with `...`.
+fn write_excerpt(f: &mut fmt::Formatter, arrow: &str, annotations: &[&Annotation]) -> fmt::Result {
+    let loc = &annotations[0].loc;
+    write!(
+        f,
+        "\n{indent     }{arrow} {path}:{line_start}:{start}\n\
+        {indent     } |",
+        indent = INDENT,
+        path = &loc.source_file.name,
+        line_start = loc.line_start,
+        start = loc.col_start,
+    )?;
+
+    let mut annotations = annotations.to_vec();
+    annotations.sort_by_key(|annotation| annotation.loc.line_start);
+
+    // The last line that has been written.
+    let mut last_line = None;
+    for annotation in annotations {
+        let loc = &annotation.loc;
+        match last_line {
+            Some(last_line) if loc.line_start > last_line + 1 => write!(f, "\n...")?,
+            _ => {}
+        }
+
+        for (line_no, line) in annotation.contents.lines().enumerate() {
+            let line_no = loc.line_start + line_no;
+            if last_line.map_or(true, |last_line| line_no > last_line) {
+                write!(f, "\n{line_no:width$} | {line}", width = INDENT.len())?;
+                last_line = Some(line_no);
+            }
+        }
+
+        write!(f, "\n{INDENT     } |{}", underline(loc.col_start, loc.col_stop, annotation.marker))?;
+        if let Some(message) = annotation.message {
+            write!(f, " {message}")?;
+        }
+    }
 
+    Ok(())
+}
+
+impl fmt::Display for Formatted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let (kind, code) =
             if self.backtrace.error { ("Error", self.error_code()) } else { ("Warning", self.warning_code()) };
 
@@ -132,27 +206,16 @@ impl fmt::Display for Formatted {
             write!(f, "{message}")?;
         };
 
-        write!(
-            f,
-            "\n{indent     }--> {path}:{line_start}:{start}\n\
-            {indent     } |\n",
-            indent = INDENT,
-            path = &loc.source_file.name,
-            line_start = loc.line_start,
-            start = loc.col_start,
-        )?;
-
-        for (line_no, line) in contents.lines().enumerate() {
-            writeln!(
-                f,
-                "{line_no:width$} | {text}",
-                width = INDENT.len(),
-                line_no = loc.line_start + line_no,
-                text = line,
-            )?;
-        }
+        // The secondary spans in the file of the primary span are shown in its excerpt, and the others in their own.
+        let primary = Annotation::new(self.span, '^', None);
+        let labels = self.labels.iter().map(|label| Annotation::new(label.span, '-', Some(&label.message)));
+        let (local, foreign): (Vec<_>, Vec<_>) = labels.partition(|label| label.is_in_file_of(&primary));
 
-        write!(f, "{INDENT     } |{underlined}",)?;
+        write_excerpt(f, "-->", &std::iter::once(&primary).chain(&local).collect::<Vec<_>>())?;
+        for label in &foreign {
+            write!(f, "\n{INDENT     } |")?;
+            write_excerpt(f, ":::", &[label])?;
+        }
 
         if let Some(help) = &self.backtrace.help {
             write!(
@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::Span;
+
+/// A secondary span of a formatted message, with a note on how it relates to the message,
+/// e.g. the declaration of the function that a call does not match.
+#[derive(Clone, Debug, Default, Hash, PartialEq)]
+pub struct Label {
+    /// The note on the span.
+    pub message: String,
+    /// The span that the note refers to.
+    pub span: Span,
+}
+
+impl Label {
+    /// Creates a label with the note `message` on `span`.
+    pub fn new<S>(message: S, span: Span) -> Self
+    where
+        S: ToString,
+    {
+        Self { message: message.to_string(), span }
+    }
+}
@@ -86,6 +86,47 @@ macro_rules! create_messages {
         }
 
 
+        impl $type_ {
+            /// Returns the message, help, and code of the message.
+            pub fn backtraced(&self) -> &Backtraced {
+                match self {
+                    Self::Formatted(formatted) => &formatted.backtrace,
+                    Self::Backtraced(backtraced) => backtraced,
+                }
+            }
+
+            /// Returns the span of the message, if it has one.
+            pub fn span(&self) -> Option<leo_span::Span> {
+                match self {
+                    Self::Formatted(formatted) => Some(formatted.span),
+                    Self::Backtraced(_) => None,
+                }
+            }
+
+            /// Returns the secondary spans of the message.
+            pub fn labels(&self) -> &[$crate::Label] {
+                match self {
+                    Self::Formatted(formatted) => &formatted.labels,
+                    Self::Backtraced(_) => &[],
+                }
+            }
+
+            /// Attaches the secondary span `label` to the message, if it has a span.
+            pub fn with_label(self, label: $crate::Label) -> Self {
+                match self {
+                    Self::Formatted(formatted) => Self::Formatted(formatted.with_label(label)),
+                    Self::Backtraced(backtraced) => Self::Backtraced(backtraced),
+                }
+            }
+        }
+
+        impl $type_ {
+            /// Returns the name and documentation of each message, in the order of their codes.
+            pub fn message_definitions() -> Vec<$crate::MessageDefinition> {
+                vec![$($crate::MessageDefinition::new(stringify!($names), &[$(stringify!($docs)),*]),)*]
+            }
+        }
+
         // Steps over the list of functions with an initial code of 0.
         impl $type_ {
             create_messages!(@step 0i32, $(($(#[$docs])* $formatted_or_backtraced_list, $names($($arg_names: $arg_types,)*), $messages, $helps),)*);
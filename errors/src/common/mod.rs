@@ -22,6 +22,10 @@ pub use self::backtraced::*;
 pub mod formatted;
 pub use self::formatted::*;
 
+/// This module contains the labels of the secondary spans of a formatted error.
+pub mod label;
+pub use self::label::*;
+
 /// This module contains the macros for making errors easily.
 #[macro_use]
 pub mod macros;
@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Emitter;
+use crate::{Backtraced, LeoError, LeoWarning};
+
+use leo_span::{symbol::with_session_globals, Span};
+
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+/// The location of a diagnostic in a source file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticSpan {
+    /// The name of the source file.
+    pub file: String,
+    /// The line the span starts on.
+    pub line_start: usize,
+    /// The line the span ends on.
+    pub line_stop: usize,
+    /// The column the span starts at.
+    pub col_start: usize,
+    /// The column the span ends at.
+    pub col_stop: usize,
+}
+
+/// A machine-readable rendering of an error or warning.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    /// Either `error` or `warning`.
+    pub severity: String,
+    /// The unique code of the diagnostic, e.g. `EPAR0370005`.
+    pub code: String,
+    /// The diagnostic message.
+    pub message: String,
+    /// The suggested fix, if one exists.
+    pub help: Option<String>,
+    /// The location of the diagnostic, if it has one.
+    pub span: Option<DiagnosticSpan>,
+}
+
+impl Diagnostic {
+    /// Returns the diagnostic for the given message parts.
+    fn new(backtraced: &Backtraced, span: Option<Span>) -> Self {
+        let (severity, code) = match backtraced.error {
+            true => ("error", backtraced.error_code()),
+            false => ("warning", backtraced.warning_code()),
+        };
+        let span = span.and_then(|span| with_session_globals(|s| s.source_map.span_to_location(span))).map(|loc| {
+            DiagnosticSpan {
+                file: loc.source_file.name.to_string(),
+                line_start: loc.line_start,
+                line_stop: loc.line_stop,
+                col_start: loc.col_start,
+                col_stop: loc.col_stop,
+            }
+        });
+        Self {
+            severity: severity.to_string(),
+            code,
+            message: backtraced.message.clone(),
+            help: backtraced.help.clone(),
+            span,
+        }
+    }
+
+    /// Returns the diagnostic for `err`, or `None` if the error does not carry a Leo message.
+    pub fn from_error(err: &LeoError) -> Option<Self> {
+        err.parts().map(|(backtraced, span)| Self::new(backtraced, span))
+    }
+
+    /// Returns the diagnostic for `warning`.
+    pub fn from_warning(warning: &LeoWarning) -> Self {
+        let (backtraced, span) = warning.parts();
+        Self::new(backtraced, span)
+    }
+}
+
+/// An `Emitter` that writes each diagnostic as a line of JSON.
+pub struct JsonEmitter<W: Write> {
+    /// The sink the diagnostics are written to.
+    writer: W,
+    /// Exit code of the last emitted error.
+    last_error_code: Option<i32>,
+}
+
+impl<W: Write> JsonEmitter<W> {
+    /// Returns a new JSON emitter writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer, last_error_code: None }
+    }
+
+    /// Writes `diagnostic` as a single line.
+    fn write(&mut self, diagnostic: &Diagnostic) {
+        // Diagnostics are best effort; a closed sink must not abort compilation.
+        if serde_json::to_writer(&mut self.writer, diagnostic).is_ok() {
+            let _ = writeln!(self.writer);
+        }
+    }
+}
+
+impl<W: Write> Emitter for JsonEmitter<W> {
+    fn emit_err(&mut self, err: LeoError) {
+        self.last_error_code = Some(err.exit_code());
+        if let Some(diagnostic) = Diagnostic::from_error(&err) {
+            self.write(&diagnostic);
+        }
+    }
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        self.last_error_code
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        self.write(&Diagnostic::from_warning(&warning));
+    }
+}
@@ -18,7 +18,17 @@ use crate::LeoWarning;
 
 use super::LeoError;
 use core::{default::Default, fmt};
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, io::Write, rc::Rc};
+
+/// Contains the `Emitter` that renders diagnostics as JSON.
+mod json;
+pub use json::*;
+
+/// Contains the `Emitter` that renders diagnostics with colored excerpts of their source code.
+#[cfg(feature = "terminal")]
+mod terminal;
+#[cfg(feature = "terminal")]
+pub use terminal::*;
 
 /// Types that are sinks for compiler errors.
 pub trait Emitter {
@@ -184,6 +194,18 @@ impl Handler {
         Self { inner }
     }
 
+    /// Construct a `Handler` that writes each diagnostic to `writer` as a line of JSON.
+    pub fn new_json<W: Write + 'static>(writer: W) -> Self {
+        Self::new(Box::new(JsonEmitter::new(writer)))
+    }
+
+    /// Construct a `Handler` that writes each diagnostic to `writer` with excerpts of its source code,
+    /// colored according to `color`.
+    #[cfg(feature = "terminal")]
+    pub fn new_terminal<W: Write + 'static>(writer: W, color: ColorChoice) -> Self {
+        Self::new(Box::new(TerminalEmitter::new(writer, TerminalRenderer::new(color))))
+    }
+
     /// Construct a `Handler` that will append to `buf`.
     pub fn new_with_buf() -> (Self, BufferEmitter) {
         let buf = BufferEmitter::default();
@@ -258,8 +280,12 @@ impl Handler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ParserError;
-    use leo_span::{symbol::create_session_if_not_set_then, Span};
+    use crate::{LeoMessageCode, ParserError};
+    use leo_span::{
+        source_map::FileName,
+        symbol::{create_session_if_not_set_then, with_session_globals},
+        Span,
+    };
 
     #[test]
     fn fresh_no_errors() {
@@ -296,4 +322,90 @@ mod tests {
             Handler::with(|_| Ok(())).unwrap();
         })
     }
+
+    /// A writer whose contents can be inspected after it was moved into a `Handler`.
+    #[derive(Clone, Default)]
+    struct SharedWriter(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn json_works() {
+        create_session_if_not_set_then(|_| {
+            let source =
+                with_session_globals(|s| s.source_map.new_source("program", FileName::Custom("test.leo".into())));
+            let span = Span::new(source.start_pos, source.end_pos);
+
+            let writer = SharedWriter::default();
+            let handler = Handler::new_json(writer.clone());
+            handler.emit_err(ParserError::unexpected_eof(span));
+            handler.emit_err(ParserError::invalid_import_list(Span::default()));
+            assert_eq!(handler.err_count(), 2);
+            assert!(handler.last_err().is_err());
+
+            let output = String::from_utf8(writer.0.take()).unwrap();
+            let diagnostics: Vec<Diagnostic> = output.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+            assert_eq!(diagnostics.len(), 2);
+            assert_eq!(diagnostics[0].severity, "error");
+            assert_eq!(diagnostics[0].code, ParserError::unexpected_eof(Span::default()).error_code());
+            assert_eq!(diagnostics[0].message, "unexpected EOF");
+            assert_eq!(diagnostics[0].span, Some(DiagnosticSpan {
+                file: "test.leo".to_string(),
+                line_start: 1,
+                line_stop: 1,
+                col_start: 1,
+                col_stop: 8,
+            }));
+        })
+    }
+
+    #[cfg(feature = "terminal")]
+    #[test]
+    fn terminal_works() {
+        use leo_span::span::{BytePos, Pos};
+
+        create_session_if_not_set_then(|_| {
+            // Returns the spans in a new source file `name` with the contents `src`, given their offsets.
+            let spans = |src: &str, name: &str, offsets: &[(u32, u32)]| {
+                let source = with_session_globals(|s| s.source_map.new_source(src, FileName::Custom(name.into())));
+                let at = |offset: u32| source.start_pos + BytePos::from_u32(offset);
+                offsets.iter().map(|(lo, hi)| Span::new(at(*lo), at(*hi))).collect::<Vec<_>>()
+            };
+
+            let test = spans("let a: u8 = 1u16;", "test.leo", &[(12, 16), (7, 9)]);
+            let label = crate::Label::new("expected `u8`", test[1]);
+            let err = LeoError::from(ParserError::unexpected_eof(test[0]).with_label(label));
+
+            let plain = TerminalRenderer::new(ColorChoice::Never).render_error(&err).unwrap();
+            assert_eq!(
+                plain,
+                format!(
+                    "Error [{}]: unexpected EOF\n    --> test.leo:1:13\n     |\n   1 | let a: u8 = 1u16;\n\
+                     \x20    |        -- expected `u8`\n     |             ^^^^",
+                    err.error_code()
+                )
+            );
+
+            let colored = TerminalRenderer::new(ColorChoice::Always).render_error(&err).unwrap();
+            assert!(colored.starts_with("\x1b[1;31mError"));
+            assert!(colored.contains("\x1b[1;34m-->\x1b[0m"));
+
+            // Long lines are wrapped, and each of their parts is underlined separately.
+            let wrap = spans("let c: u8 = 1u8 + 2u8 + 3u8 + 4u8;", "wrap.leo", &[(16, 27)]);
+            let wrapped = ParserError::unexpected_eof(wrap[0]);
+            let renderer = TerminalRenderer::new(ColorChoice::Never).with_width(0);
+            let wrapped = renderer.render_error(&wrapped.into()).unwrap();
+            assert!(wrapped.ends_with(
+                "   1 | let c: u8 = 1u8 + 2u\n     |                 ^^^^\n     | 8 + 3u8 + 4u8;\n     | ^^^^^^^"
+            ));
+        })
+    }
 }
@@ -0,0 +1,296 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Emitter;
+use crate::{Backtraced, Label, LeoError, LeoWarning, INDENT};
+
+use leo_span::{source_map::SpanLocation, symbol::with_session_globals, Span};
+
+use std::{collections::BTreeMap, fmt::Write as _, io::Write, sync::Arc};
+
+/// The default width of a rendered diagnostic, in characters.
+const DEFAULT_WIDTH: usize = 100;
+
+/// The narrowest width that lines of source code are wrapped to, in characters.
+const MIN_TEXT_WIDTH: usize = 20;
+
+/// When the output of a `TerminalRenderer` is colored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Always color the output.
+    Always,
+    /// Color the output if the standard output is a terminal, unless the environment disables it, e.g. with `NO_COLOR`.
+    #[default]
+    Auto,
+    /// Never color the output.
+    Never,
+}
+
+impl ColorChoice {
+    /// Returns `true` if the output should be colored.
+    pub fn should_color(self) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Auto => colored::control::SHOULD_COLORIZE.should_colorize(),
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+/// The styles of the parts of a rendered diagnostic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Style {
+    /// The header and primary underline of an error.
+    Error,
+    /// The header and primary underline of a warning.
+    Warning,
+    /// The underlines and notes of secondary spans.
+    Secondary,
+    /// The line numbers, the arrows and the margin.
+    Gutter,
+}
+
+impl Style {
+    /// Returns the ANSI escape parameters of the style.
+    fn ansi(self) -> &'static str {
+        match self {
+            Style::Error => "1;31",
+            Style::Warning => "1;33",
+            Style::Secondary | Style::Gutter => "1;34",
+        }
+    }
+}
+
+/// A span to underline in an excerpt of its source file.
+struct Annotation<'a> {
+    /// The location of the span.
+    loc: SpanLocation,
+    /// The lines of source code that the span covers.
+    contents: String,
+    /// The character to underline the span with.
+    marker: char,
+    /// The style of the underline and the note.
+    style: Style,
+    /// The note to show after the underline, if any.
+    message: Option<&'a str>,
+}
+
+impl<'a> Annotation<'a> {
+    /// Looks up the location and lines of `span`, to underline them with `marker`.
+    fn new(span: Span, marker: char, style: Style, message: Option<&'a str>) -> Self {
+        let (loc, contents) = with_session_globals(|s| {
+            (
+                s.source_map.span_to_location(span).unwrap_or_else(SpanLocation::dummy),
+                s.source_map.line_contents_of_span(span).unwrap_or_else(|| "<contents unavailable>".to_owned()),
+            )
+        });
+        Self { loc, contents, marker, style, message }
+    }
+
+    /// Returns the number of the last line of the span.
+    fn last_line(&self) -> usize {
+        self.loc.line_start + self.contents.lines().count().saturating_sub(1)
+    }
+
+    /// Returns the zero-based range of characters to underline on the last line of the span, `text`.
+    /// A span over several lines is underlined from the first non-blank character of its last line.
+    fn columns(&self, text: &[char]) -> (usize, usize) {
+        let start = match self.loc.line_start == self.loc.line_stop {
+            true => self.loc.col_start.saturating_sub(1),
+            false => text.iter().position(|c| !c.is_whitespace()).unwrap_or(0),
+        };
+        let stop = self.loc.col_stop.saturating_sub(1);
+        // Spans of zero width, such as the end of a file, are still shown with one marker.
+        (start, stop.max(start + 1))
+    }
+}
+
+/// Renders diagnostics with excerpts of their source code for a terminal,
+/// underlining the primary span with `^` and the secondary spans with `-`, and wrapping long lines.
+#[derive(Clone, Copy, Debug)]
+pub struct TerminalRenderer {
+    /// Whether the output is colored.
+    color: bool,
+    /// The width to wrap the output to, in characters.
+    width: usize,
+}
+
+impl TerminalRenderer {
+    /// Returns a renderer that colors its output according to `color`.
+    pub fn new(color: ColorChoice) -> Self {
+        Self { color: color.should_color(), width: DEFAULT_WIDTH }
+    }
+
+    /// Wraps the lines of source code to fit in `width` characters, including the margin.
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Renders `err`, or returns `None` if the error does not carry a Leo message.
+    pub fn render_error(&self, err: &LeoError) -> Option<String> {
+        err.parts().map(|(backtraced, span)| self.render(backtraced, span, err.labels()))
+    }
+
+    /// Renders `warning`.
+    pub fn render_warning(&self, warning: &LeoWarning) -> String {
+        let (backtraced, span) = warning.parts();
+        self.render(backtraced, span, warning.labels())
+    }
+
+    /// Returns `text` in `style`, if the output is colored.
+    fn paint(&self, text: impl AsRef<str>, style: Style) -> String {
+        match self.color {
+            true => format!("\x1b[{}m{}\x1b[0m", style.ansi(), text.as_ref()),
+            false => text.as_ref().to_owned(),
+        }
+    }
+
+    /// Renders the message `backtraced`, with an excerpt of its `span` and `labels` if it has a span.
+    fn render(&self, backtraced: &Backtraced, span: Option<Span>, labels: &[Label]) -> String {
+        let (kind, code, style) = match backtraced.error {
+            true => ("Error", backtraced.error_code(), Style::Error),
+            false => ("Warning", backtraced.warning_code(), Style::Warning),
+        };
+        let mut out = self.paint(format!("{kind} [{code}]: {}", backtraced.message), style);
+
+        if let Some(span) = span {
+            // The secondary spans in the file of the primary span are shown in its excerpt, the others in their own.
+            let primary = Annotation::new(span, '^', style, None);
+            let labels =
+                labels.iter().map(|label| Annotation::new(label.span, '-', Style::Secondary, Some(&label.message)));
+            let (local, foreign): (Vec<_>, Vec<_>) =
+                labels.partition(|label| Arc::ptr_eq(&label.loc.source_file, &primary.loc.source_file));
+
+            self.write_excerpt(&mut out, "-->", &std::iter::once(&primary).chain(&local).collect::<Vec<_>>());
+            for label in &foreign {
+                let _ = write!(out, "\n{INDENT     } {}", self.paint("|", Style::Gutter));
+                self.write_excerpt(&mut out, ":::", &[label]);
+            }
+        }
+
+        if let Some(help) = &backtraced.help {
+            let (margin, equals) = (self.paint("|", Style::Gutter), self.paint("=", Style::Gutter));
+            let _ = write!(out, "\n{INDENT     } {margin}\n{INDENT     } {equals} {help}");
+        }
+
+        out
+    }
+
+    /// Writes the location of the first of `annotations` after `arrow`, and the lines of all of them in order.
+    /// The lines are wrapped to the width of the renderer, and each part of a line is followed by the underlines
+    /// of the annotations ending on that line. Lines that are skipped between two annotations are elided with `...`.
+    fn write_excerpt(&self, out: &mut String, arrow: &str, annotations: &[&Annotation]) {
+        let loc = &annotations[0].loc;
+        let margin = format!("{INDENT     } {}", self.paint("|", Style::Gutter));
+        let _ = write!(
+            out,
+            "\n{INDENT     }{} {}:{}:{}\n{margin}",
+            self.paint(arrow, Style::Gutter),
+            loc.source_file.name,
+            loc.line_start,
+            loc.col_start,
+        );
+
+        // The lines to show, each with the annotations that are underlined on it.
+        let mut lines: BTreeMap<usize, (&str, Vec<&Annotation>)> = BTreeMap::new();
+        for annotation in annotations {
+            for (line_no, text) in annotation.contents.lines().enumerate() {
+                lines.entry(annotation.loc.line_start + line_no).or_insert((text, Vec::new()));
+            }
+            if let Some((_, underlined)) = lines.get_mut(&annotation.last_line()) {
+                underlined.push(annotation);
+            }
+        }
+
+        let text_width = self.width.saturating_sub(INDENT.len() + 3).max(MIN_TEXT_WIDTH);
+        let mut last_line = None;
+        for (line_no, (text, mut underlined)) in lines {
+            if matches!(last_line, Some(last_line) if line_no > last_line + 1) {
+                out.push_str("\n...");
+            }
+            last_line = Some(line_no);
+
+            let text = text.chars().collect::<Vec<_>>();
+            underlined.sort_by_key(|annotation| annotation.columns(&text).0);
+            let chunks = match text.is_empty() {
+                true => vec![&text[..]],
+                false => text.chunks(text_width).collect(),
+            };
+            for (i, chunk) in chunks.into_iter().enumerate() {
+                // Only the first part of a wrapped line is numbered.
+                let number = match i {
+                    0 => line_no.to_string(),
+                    _ => String::new(),
+                };
+                let number = self.paint(format!("{number:>width$} |", width = INDENT.len()), Style::Gutter);
+                let _ = write!(out, "\n{number} {}", chunk.iter().collect::<String>());
+
+                let (chunk_start, chunk_stop) = (i * text_width, i * text_width + chunk.len().max(1));
+                for annotation in &underlined {
+                    let (start, stop) = annotation.columns(&text);
+                    let (start, stop) = (start.max(chunk_start), stop.min(chunk_stop));
+                    if start >= stop {
+                        continue;
+                    }
+                    let markers = annotation.marker.to_string().repeat(stop - start);
+                    let indent = " ".repeat(start - chunk_start);
+                    let _ = write!(out, "\n{margin} {indent}{}", self.paint(markers, annotation.style));
+                    // The note follows the end of the underline.
+                    if let Some(message) = annotation.message.filter(|_| annotation.columns(&text).1 == stop) {
+                        let _ = write!(out, " {}", self.paint(message, annotation.style));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// An `Emitter` that writes each diagnostic rendered by a `TerminalRenderer`.
+pub struct TerminalEmitter<W: Write> {
+    /// The sink the diagnostics are written to.
+    writer: W,
+    /// The renderer of the diagnostics.
+    renderer: TerminalRenderer,
+    /// Exit code of the last emitted error.
+    last_error_code: Option<i32>,
+}
+
+impl<W: Write> TerminalEmitter<W> {
+    /// Returns a new terminal emitter writing to `writer` with `renderer`.
+    pub fn new(writer: W, renderer: TerminalRenderer) -> Self {
+        Self { writer, renderer, last_error_code: None }
+    }
+}
+
+impl<W: Write> Emitter for TerminalEmitter<W> {
+    fn emit_err(&mut self, err: LeoError) {
+        self.last_error_code = Some(err.exit_code());
+        // Diagnostics are best effort; a closed sink must not abort compilation.
+        match self.renderer.render_error(&err) {
+            Some(rendered) => writeln!(self.writer, "{rendered}").ok(),
+            None => writeln!(self.writer, "{err}").ok(),
+        };
+    }
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        self.last_error_code
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        let _ = writeln!(self.writer, "{}", self.renderer.render_warning(&warning));
+    }
+}
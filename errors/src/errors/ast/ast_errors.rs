@@ -145,4 +145,171 @@ create_messages!(
         msg: format!("failed to convert symbol_table to a json value {error}"),
         help: None,
     }
+
+    /// For when the reference index fails to create the reference index JSON file.
+    @backtraced
+    failed_to_create_reference_index_json_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to create reference_index json file `{path:?}` {error}"),
+        help: None,
+    }
+
+    /// For when the reference index fails to write the reference index JSON file.
+    @backtraced
+    failed_to_write_reference_index_to_json_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to write reference_index to a json file `{path:?}` {error}"),
+        help: None,
+    }
+
+    /// For when the a JSON string fails to be represented as a reference index.
+    @backtraced
+    failed_to_read_json_string_to_reference_index {
+        args: (error: impl ErrorArg),
+        msg: format!("failed to convert json string to a reference_index {error}"),
+        help: None,
+    }
+
+    /// For when a user shadows an interface.
+    @formatted
+    shadowed_interface {
+        args: (interface: impl Display),
+        msg: format!("interface `{interface}` shadowed by"),
+        help: None,
+    }
+
+    /// For when the panic audit fails to create the report JSON file.
+    @backtraced
+    failed_to_create_panic_audit_json_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to create panic audit json file `{path:?}` {error}"),
+        help: None,
+    }
+
+    /// For when the panic audit fails to write the report JSON file.
+    @backtraced
+    failed_to_write_panic_audit_to_json_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to write panic audit to a json file `{path:?}` {error}"),
+        help: None,
+    }
+
+    /// For when the a JSON string fails to be represented as a panic audit report.
+    @backtraced
+    failed_to_read_json_string_to_panic_audit {
+        args: (error: impl ErrorArg),
+        msg: format!("failed to convert json string to a panic audit report {error}"),
+        help: None,
+    }
+
+    /// For when the import trace fails to create the trace JSON file.
+    @backtraced
+    failed_to_create_import_trace_json_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to create import trace json file `{path:?}` {error}"),
+        help: None,
+    }
+
+    /// For when the import trace fails to write the trace JSON file.
+    @backtraced
+    failed_to_write_import_trace_to_json_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to write import trace to a json file `{path:?}` {error}"),
+        help: None,
+    }
+
+    /// For when a source edit is out of the bounds of the edited source, or splits a character.
+    @backtraced
+    source_edit_out_of_bounds {
+        args: (lo: impl Display, hi: impl Display),
+        msg: format!("the source edit of the bytes {lo}..{hi} is out of the bounds of the source, or splits a character"),
+        help: None,
+    }
+
+    /// For when two source edits in the same batch overlap.
+    @backtraced
+    overlapping_source_edits {
+        args: (first: impl Display, second: impl Display),
+        msg: format!("the source edits of the bytes {first} and {second} overlap"),
+        help: None,
+    }
+
+    /// For when the source map fails to create the source map JSON file.
+    @backtraced
+    failed_to_create_source_map_json_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to create source map json file `{path:?}` {error}"),
+        help: None,
+    }
+
+    /// For when the source map fails to write the source map JSON file.
+    @backtraced
+    failed_to_write_source_map_to_json_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to write source map to a json file `{path:?}` {error}"),
+        help: None,
+    }
+
+    /// For when a JSON string fails to be represented as a source map.
+    @backtraced
+    failed_to_read_json_string_to_source_map {
+        args: (error: impl ErrorArg),
+        msg: format!("failed to convert json string to a source map {error}"),
+        help: None,
+    }
+
+    @formatted
+    duplicate_module {
+        args: (module: impl Display),
+        msg: format!("The module `{module}` is declared more than once."),
+        help: None,
+    }
+
+    /// For when the typed IR fails to create the typed IR JSON file.
+    @backtraced
+    failed_to_create_typed_ir_json_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to create typed ir json file `{path:?}` {error}"),
+        help: None,
+    }
+
+    /// For when the typed IR fails to write the typed IR JSON file.
+    @backtraced
+    failed_to_write_typed_ir_to_json_file {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to write typed ir to a json file `{path:?}` {error}"),
+        help: None,
+    }
+
+    /// For when a JSON string fails to be represented as a typed IR.
+    @backtraced
+    failed_to_read_json_string_to_typed_ir {
+        args: (error: impl ErrorArg),
+        msg: format!("failed to convert json string to a typed ir {error}"),
+        help: None,
+    }
+
+    /// For when an expression has no type when the AST is lowered to the typed IR.
+    @formatted
+    untyped_expression {
+        args: (expression: impl Display),
+        msg: format!("The type of the expression `{expression}` is not known after type checking."),
+        help: Some("This is a bug in the Leo compiler. Please report it.".to_string()),
+    }
+
+    /// For when a user shadows an enum.
+    @formatted
+    shadowed_enum {
+        args: (enum_: impl Display),
+        msg: format!("enum `{enum_}` shadowed by"),
+        help: None,
+    }
+
+    /// For when a local variable shadows a parameter or another local variable of the same function.
+    @formatted
+    shadowed_local_variable {
+        args: (var: impl Display, kind: impl Display),
+        msg: format!("`{var}` is already declared as a {kind} of this function"),
+        help: Some("Leo does not allow shadowing. Give the new variable a different name.".to_string()),
+    }
 );
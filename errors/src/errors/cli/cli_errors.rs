@@ -178,4 +178,11 @@ create_messages!(
         msg: format!("Failed to write file.\nIO Error: {error}"),
         help: None,
     }
+
+    @backtraced
+    unknown_error_code {
+        args: (code: impl Display),
+        msg: format!("There is no error or warning with the code `{code}`."),
+        help: Some("Codes look like `ETYC0372003` or `WPAR0370000`.".to_string()),
+    }
 );
@@ -70,4 +70,146 @@ create_messages!(
         msg: format!("The program scope name `{program_scope_name}` must match `{file_name}`."),
         help: None,
     }
+
+    @formatted
+    operation_not_supported_on_target {
+        args: (operation: impl Display, type_: impl Display),
+        msg: format!("The operation `{operation}` is not supported on this target for operands of type `{type_}`."),
+        help: None,
+    }
+
+    @formatted
+    transition_exceeds_instruction_budget {
+        args: (transition: impl Display, cost: impl Display, budget: impl Display, breakdown: impl Display),
+        msg: format!("The transition `{transition}` is estimated to execute {cost} instructions, exceeding the budget of {budget}."),
+        help: Some(format!("The estimated costs of the statements in `{transition}` are:\n{breakdown}")),
+    }
+
+    @backtraced
+    failed_to_parse_compiler_options {
+        args: (error: impl ErrorArg),
+        msg: format!("Failed to parse the compiler options: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_serialize_compiler_options {
+        args: (error: impl ErrorArg),
+        msg: format!("Failed to serialize the compiler options: {error}"),
+        help: None,
+    }
+
+    @formatted
+    unresolved_import {
+        args: (import: impl Display, candidates: impl Display),
+        msg: format!("Could not resolve the import `{import}`, since none of the files {candidates} exist."),
+        help: Some("Add the imported program to the `imports` directory, or to one of the import search paths.".to_string()),
+    }
+
+    @formatted
+    unresolved_module {
+        args: (module: impl Display, path: impl Display),
+        msg: format!("Could not resolve the module `{module}`, since the file `{path}` does not exist."),
+        help: Some(format!("Add the file `{module}.leo` next to the file declaring the module.")),
+    }
+
+    @formatted
+    cyclic_import {
+        args: (path: Vec<impl Display>, imports: Vec<impl Display>),
+        msg: {
+            let path_string = path.into_iter().map(|name| format!("`{name}`")).collect::<Vec<String>>().join(" --> ");
+            format!("Cyclic dependency between imports: {path_string}")
+        },
+        help: Some(format!(
+            "The cycle is formed by the import statements:\n{}",
+            imports.into_iter().map(|import| format!("  {import}")).collect::<Vec<String>>().join("\n")
+        )),
+    }
+
+    @backtraced
+    unknown_pass {
+        args: (pass: impl Display),
+        msg: format!("The pipeline has no pass named `{pass}`."),
+        help: None,
+    }
+
+    @backtraced
+    pass_cannot_be_disabled {
+        args: (pass: impl Display),
+        msg: format!("The pass `{pass}` cannot be disabled, since later passes rely on it."),
+        help: Some("Only optimization and reporting passes can be disabled.".to_string()),
+    }
+
+    @backtraced
+    cyclic_pass_ordering {
+        args: (passes: Vec<impl Display>),
+        msg: format!(
+            "The ordering constraints between the passes {} form a cycle.",
+            passes.into_iter().map(|pass| format!("`{pass}`")).collect::<Vec<String>>().join(", ")
+        ),
+        help: None,
+    }
+
+    @backtraced
+    missing_analysis {
+        args: (pass: impl Display, analysis: impl Display),
+        msg: format!("The pass `{pass}` requires the {analysis}, which no earlier pass provides."),
+        help: Some("Reorder the pipeline, or enable the pass providing the analysis.".to_string()),
+    }
+
+    @backtraced
+    failed_to_serialize_stats {
+        args: (error: impl ErrorArg),
+        msg: format!("Failed to serialize the compilation statistics: {error}"),
+        help: None,
+    }
+
+    @formatted
+    nothing_to_rename {
+        args: (),
+        msg: "There is no variable, function, or struct to rename here.",
+        help: None,
+    }
+
+    @formatted
+    invalid_new_name {
+        args: (name: impl Display),
+        msg: format!("Cannot rename to `{name}`, since it is not a valid identifier."),
+        help: Some("Identifiers start with a letter, and contain only letters, digits, and underscores.".to_string()),
+    }
+
+    @formatted
+    rename_conflict {
+        args: (old: impl Display, new: impl Display),
+        msg: format!("Cannot rename `{old}` to `{new}`, since `{new}` is already defined."),
+        help: Some(format!("Leo does not allow shadowing, so `{new}` must not be visible where `{old}` is used.")),
+    }
+
+    @formatted
+    undeclared_dependency {
+        args: (import: impl Display),
+        msg: format!("Cannot import `{import}`, since it is not a declared dependency of the package."),
+        help: Some(format!("Declare `{import}` in the `dependencies` of the `program.json` manifest.")),
+    }
+
+    @backtraced
+    program_too_complex {
+        args: (nodes: impl Display, max_nodes: impl Display),
+        msg: format!("The program is too complex to compile: it has {nodes} AST nodes, more than the limit of {max_nodes}."),
+        help: Some("Split the program into smaller programs, or raise `max_nodes` in the program limits of the build options.".to_string()),
+    }
+
+    @backtraced
+    core_function_already_defined {
+        args: (module: impl Display, function: impl Display),
+        msg: format!("The built-in function `{module}::{function}` cannot be registered, since it is already defined."),
+        help: None,
+    }
+
+    @formatted
+    feature_requires_edition {
+        args: (feature: impl Display, required: impl Display, edition: impl Display),
+        msg: format!("Feature `{feature}` requires edition {required}, but the program targets edition {edition}."),
+        help: Some(format!("Set `language_edition` to {required} or later in the build options.")),
+    }
 );
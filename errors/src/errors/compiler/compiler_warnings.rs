@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// CompilerWarning enum that represents all the warnings for the `leo-compiler` crate.
+    CompilerWarning,
+    code_mask: 6000i32,
+    code_prefix: "CMP",
+
+    /// For when an item is removed from the program since it is unreachable from every transition.
+    @formatted
+    stripped_unreachable_item {
+        args: (kind: impl Display, name: impl Display),
+        msg: format!("The {kind} `{name}` is unreachable from every transition, so it was stripped from the program."),
+        help: None,
+    }
+);
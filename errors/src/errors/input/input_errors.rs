@@ -55,4 +55,52 @@ create_messages!(
         ),
         help: None,
     }
+
+    /// For when a section of an input file does not name a function of the program.
+    @formatted
+    unknown_input_section {
+        args: (section: impl Display),
+        msg: format!("section `[{section}]` does not name a function of the program"),
+        help: Some("Name each section of an input file after the function it gives the inputs of.".to_string()),
+    }
+
+    /// For when an input file does not give a value for a parameter of a function.
+    @formatted
+    missing_input {
+        args: (parameter: impl Display, function: impl Display),
+        msg: format!("missing a value for the parameter `{parameter}` of `{function}`"),
+        help: None,
+    }
+
+    /// For when an input file gives a value that is not a parameter of the function.
+    @formatted
+    unexpected_input {
+        args: (input: impl Display, function: impl Display),
+        msg: format!("`{input}` is not a parameter of `{function}`"),
+        help: None,
+    }
+
+    /// For when an input file gives a value for the same parameter more than once.
+    @formatted
+    duplicate_input {
+        args: (input: impl Display),
+        msg: format!("the value of `{input}` is given more than once"),
+        help: None,
+    }
+
+    /// For when the type of an input does not match the type of the parameter it is given for.
+    @formatted
+    input_type_mismatch {
+        args: (input: impl Display, expected: impl Display, received: impl Display, declaration: impl Display),
+        msg: format!("`{input}` is given as a `{received}`, but the parameter has type `{expected}`"),
+        help: Some(format!("The parameter `{input}` is declared as a `{expected}` at {declaration}.")),
+    }
+
+    /// For when an address in an input file is not a bech32m encoded address.
+    @formatted
+    invalid_address {
+        args: (address: impl Display, reason: impl Display),
+        msg: format!("`{address}` is not a valid address: {reason}"),
+        help: None,
+    }
 );
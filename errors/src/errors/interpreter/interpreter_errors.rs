@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+use std::{
+    error::Error as ErrorArg,
+    fmt::{Debug, Display},
+};
+
+create_messages!(
+    /// InterpreterError enum that represents all the errors for the interpreter in the `leo-passes` crate.
+    InterpreterError,
+    code_mask: 8000i32,
+    code_prefix: "INT",
+
+    /// For when the interpreter encounters an operation it cannot evaluate.
+    @formatted
+    unsupported_operation {
+        args: (operation: impl Display),
+        msg: format!("The interpreter does not support `{operation}`."),
+        help: None,
+    }
+
+    /// For when an assertion fails during interpretation.
+    @formatted
+    assertion_failed {
+        args: (assertion: impl Display),
+        msg: format!("The assertion `{assertion}` failed."),
+        help: None,
+    }
+
+    /// For when an array is indexed out of bounds during interpretation.
+    @formatted
+    index_out_of_bounds {
+        args: (index: impl Display, length: impl Display),
+        msg: format!("The index `{index}` is out of bounds for an array of length {length}."),
+        help: None,
+    }
+
+    /// For when a cast does not fit in the target type during interpretation.
+    @formatted
+    cast_overflow {
+        args: (value: impl Display, type_: impl Display),
+        msg: format!("Casting `{value}` to `{type_}` overflows."),
+        help: None,
+    }
+
+    /// For when the function to interpret does not exist.
+    @backtraced
+    unknown_function {
+        args: (function: impl Display),
+        msg: format!("The program has no function named `{function}`."),
+        help: None,
+    }
+
+    /// For when a function is interpreted with the wrong number of inputs.
+    @backtraced
+    wrong_number_of_inputs {
+        args: (function: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The function `{function}` expects {expected} inputs, but {found} were given."),
+        help: None,
+    }
+
+    /// For when an input cannot be parsed as a value of the expected type.
+    @backtraced
+    invalid_input {
+        args: (input: impl Display, type_: impl Display),
+        msg: format!("`{input}` is not a valid value of type `{type_}`."),
+        help: Some("Inputs are written as Aleo values, e.g. `1u32`, `[true, false]`, or `{ x: 1u32 }`.".to_string()),
+    }
+
+    /// For when the test vectors file cannot be read.
+    @backtraced
+    failed_to_read_test_vectors {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to read the test vectors file `{path:?}` {error}"),
+        help: None,
+    }
+
+    /// For when the test vectors cannot be deserialized.
+    @backtraced
+    failed_to_parse_test_vectors {
+        args: (error: impl ErrorArg),
+        msg: format!("failed to parse the test vectors {error}"),
+        help: None,
+    }
+
+    /// For when the test vector fixture cannot be written.
+    @backtraced
+    failed_to_write_test_vector_fixture {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("failed to write the test vector fixture `{path:?}` {error}"),
+        help: None,
+    }
+
+    /// For when a run of the interpreter uses more of a resource than its limit allows.
+    @formatted
+    execution_budget_exceeded {
+        args: (limit: impl Display, resource: impl Display),
+        msg: format!("The execution exceeded its budget of {limit} {resource}."),
+        help: Some("The resources of a run are limited by the `InterpreterLimits` of the interpreter.".to_string()),
+    }
+
+    /// For when the program divides by zero, or inverts zero.
+    @formatted
+    division_by_zero {
+        args: (operation: impl Display),
+        msg: format!("The operation `{operation}` divides by zero."),
+        help: None,
+    }
+);
@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::create_messages;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 
 create_messages!(
     /// LoopUnrollerError enum that represents all the errors for the loop unrolling errors in the `leo-loop_unroller` crate.
@@ -36,4 +36,11 @@ create_messages!(
         msg: format!("The array index must be constant."),
         help: None,
     }
+
+    @formatted
+    lookup_index_out_of_bounds {
+        args: (index: impl Display, length: impl Display),
+        msg: format!("The index `{index}` is out of bounds for a lookup table of length `{length}`."),
+        help: None,
+    }
 );
@@ -15,7 +15,9 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 /// Contains the ASG error definitions.
-use crate::LeoMessageCode;
+use crate::{Backtraced, Label, LeoMessageCode};
+
+use leo_span::Span;
 
 /// Contains the AST error definitions.
 pub mod ast;
@@ -37,6 +39,10 @@ pub use self::flattener::*;
 pub mod input;
 pub use self::input::*;
 
+/// Contains the Interpreter error definitions.
+pub mod interpreter;
+pub use self::interpreter::*;
+
 pub mod loop_unroller;
 pub use self::loop_unroller::*;
 
@@ -84,6 +90,9 @@ pub enum LeoError {
     /// Represents a Flatten Error in a Leo Error.
     #[error(transparent)]
     FlattenError(#[from] FlattenError),
+    /// Represents an Interpreter Error in a Leo Error.
+    #[error(transparent)]
+    InterpreterError(#[from] InterpreterError),
     /// Purely for just exiting with the correct status code and
     /// not re-displaying an error.
     #[error("")]
@@ -108,6 +117,7 @@ impl LeoError {
             TypeCheckerError(error) => error.error_code(),
             LoopUnrollerError(error) => error.error_code(),
             FlattenError(error) => error.error_code(),
+            InterpreterError(error) => error.error_code(),
             LastErrorCode(_) => unreachable!(),
             Anyhow(_) => unimplemented!(), // todo: implement error codes for snarkvm errors.
         }
@@ -127,10 +137,50 @@ impl LeoError {
             TypeCheckerError(error) => error.exit_code(),
             LoopUnrollerError(error) => error.exit_code(),
             FlattenError(error) => error.exit_code(),
+            InterpreterError(error) => error.exit_code(),
             LastErrorCode(code) => *code,
             Anyhow(_) => unimplemented!(), // todo: implement exit codes for snarkvm errors.
         }
     }
+
+    /// Returns the message, help, and code of the error, along with its span if it has one.
+    /// Returns `None` for errors that do not carry a Leo message.
+    pub fn parts(&self) -> Option<(&Backtraced, Option<Span>)> {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => Some((error.backtraced(), error.span())),
+            CompilerError(error) => Some((error.backtraced(), error.span())),
+            CliError(error) => Some((error.backtraced(), error.span())),
+            InputError(error) => Some((error.backtraced(), error.span())),
+            ParserError(error) => Some((error.backtraced(), error.span())),
+            PackageError(error) => Some((error.backtraced(), error.span())),
+            TypeCheckerError(error) => Some((error.backtraced(), error.span())),
+            LoopUnrollerError(error) => Some((error.backtraced(), error.span())),
+            FlattenError(error) => Some((error.backtraced(), error.span())),
+            InterpreterError(error) => Some((error.backtraced(), error.span())),
+            LastErrorCode(_) | Anyhow(_) => None,
+        }
+    }
+
+    /// Returns the secondary spans of the error.
+    pub fn labels(&self) -> &[Label] {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.labels(),
+            CompilerError(error) => error.labels(),
+            CliError(error) => error.labels(),
+            InputError(error) => error.labels(),
+            ParserError(error) => error.labels(),
+            PackageError(error) => error.labels(),
+            TypeCheckerError(error) => error.labels(),
+            LoopUnrollerError(error) => error.labels(),
+            FlattenError(error) => error.labels(),
+            InterpreterError(error) => error.labels(),
+            LastErrorCode(_) | Anyhow(_) => &[],
+        }
+    }
 }
 
 /// The LeoWarning type that contains all sub error types.
@@ -140,6 +190,12 @@ pub enum LeoWarning {
     /// Represents an Parser Error in a Leo Error.
     #[error(transparent)]
     ParserWarning(#[from] ParserWarning),
+    /// Represents a Compiler Warning in a Leo Warning.
+    #[error(transparent)]
+    CompilerWarning(#[from] CompilerWarning),
+    /// Represents a Type Checker Warning in a Leo Warning.
+    #[error(transparent)]
+    TypeCheckerWarning(#[from] TypeCheckerWarning),
 }
 
 impl LeoWarning {
@@ -149,6 +205,30 @@ impl LeoWarning {
 
         match self {
             ParserWarning(warning) => warning.warning_code(),
+            CompilerWarning(warning) => warning.warning_code(),
+            TypeCheckerWarning(warning) => warning.warning_code(),
+        }
+    }
+
+    /// Returns the message, help, and code of the warning, along with its span if it has one.
+    pub fn parts(&self) -> (&Backtraced, Option<Span>) {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => (warning.backtraced(), warning.span()),
+            CompilerWarning(warning) => (warning.backtraced(), warning.span()),
+            TypeCheckerWarning(warning) => (warning.backtraced(), warning.span()),
+        }
+    }
+
+    /// Returns the secondary spans of the warning.
+    pub fn labels(&self) -> &[Label] {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.labels(),
+            CompilerWarning(warning) => warning.labels(),
+            TypeCheckerWarning(warning) => warning.labels(),
         }
     }
 }
@@ -313,4 +313,25 @@ create_messages!(
         msg: format!("IO error env file from the provided file path - {error}"),
         help: None,
     }
+
+    @backtraced
+    failed_to_read_manifest {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to read the manifest file {path:?}: {error}"),
+        help: Some("Run `leo new` to create a package with a `program.json` manifest.".to_string()),
+    }
+
+    @backtraced
+    invalid_manifest_program {
+        args: (program: impl Display),
+        msg: format!("The manifest declares the invalid program `{program}`."),
+        help: Some("The program is the package name followed by `.aleo`, e.g. `token.aleo`.".to_string()),
+    }
+
+    @backtraced
+    missing_dependency_source {
+        args: (dependency: impl Display, path: impl Debug),
+        msg: format!("The source file {path:?} of the dependency `{dependency}` does not exist."),
+        help: Some("Set the `path` of the dependency to a `.leo` file or a Leo package directory.".to_string()),
+    }
 );
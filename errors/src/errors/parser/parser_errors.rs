@@ -33,6 +33,8 @@ create_messages!(
     }
 
     /// For when the parser encountered an invalid address literal.
+    /// No longer emitted, since address literals are validated by the type checker,
+    /// but kept so that the codes of the errors that follow it do not change.
     @formatted
     invalid_address_lit {
         args: (token: impl Display),
@@ -175,12 +177,12 @@ create_messages!(
         help: None,
     }
 
-    /// When a hex number is provided.
+    /// When a hexadecimal, octal, or binary number has no digits after its prefix.
     @backtraced
-    lexer_hex_number_provided {
+    lexer_radix_number_without_digits {
         args: (input: impl Display),
-        msg: format!("A hex number `{input}..` was provided but hex is not allowed."),
-        help: None,
+        msg: format!("The number `{input}` has no digits after its prefix."),
+        help: Some("Hexadecimal, octal, and binary numbers are written e.g. `0xffu8`, `0o77u8`, and `0b1010u8`.".to_string()),
     }
 
     /// For when a user specified more than one mode on a parameter.
@@ -298,4 +300,74 @@ create_messages!(
         msg: format!("An array {kind} must have at least one element."),
         help: None,
     }
+
+    @formatted
+    method_missing_self {
+        args: (method: impl Display),
+        msg: format!("The method `{method}` must take `self` as its first input."),
+        help: Some(format!("Declare the method as `function {method}(self, ...)`.")),
+    }
+
+    @formatted
+    method_cannot_be_transition {
+        args: (),
+        msg: format!("A method cannot be a `transition`."),
+        help: Some("Declare the method with `function` instead.".to_string()),
+    }
+
+    @formatted
+    transition_in_module {
+        args: (module: impl Display),
+        msg: format!("The module `{module}` cannot declare a `transition`."),
+        help: Some("Declare the transitions of a program in its program scope.".to_string()),
+    }
+
+    @backtraced
+    parser_panicked {
+        args: (message: impl Display),
+        msg: format!("The parser failed unexpectedly: {message}"),
+        help: Some("This is a bug in the parser. Please report it with the program that caused it.".to_string()),
+    }
+
+    @formatted
+    nesting_too_deep {
+        args: (max_depth: impl Display),
+        msg: format!("The program is nested more than {max_depth} levels deep."),
+        help: Some("Split the deeply nested expressions or blocks into smaller ones.".to_string()),
+    }
+
+    @formatted
+    radix_literal_not_integer {
+        args: (value: impl Display, type_: impl Display),
+        msg: format!("The `{type_}` literal `{value}{type_}` is not written in decimal."),
+        help: Some("Only integer literals can be written in hexadecimal, octal, or binary.".to_string()),
+    }
+
+    @formatted
+    invalid_format_string {
+        args: (),
+        msg: format!("The format string contains an unmatched brace."),
+        help: Some("Write `{}` for an argument, and `{{` or `}}` for a literal brace.".to_string()),
+    }
+
+    @formatted
+    invalid_stub {
+        args: (reason: impl Display),
+        msg: format!("Invalid Aleo instructions in the stub of a deployed program: {reason}."),
+        help: Some("A stub is the `.aleo` file of the deployed program, e.g. as downloaded from the network.".to_string()),
+    }
+
+    @backtraced
+    printed_expression_mismatch {
+        args: (printed: impl Display),
+        msg: format!("The expression is printed as `{printed}`, which parses as a different expression."),
+        help: Some("The printer and the parser disagree on the precedence or associativity of its operators.".to_string()),
+    }
+
+    @formatted
+    module_item_name_collision {
+        args: (name: impl Display, module: impl Display),
+        msg: format!("The qualified name `{name}` of an item of the module `{module}` is already declared in the program."),
+        help: Some("Rename the item, or the item of the program or other module with the same qualified name.".to_string()),
+    }
 );
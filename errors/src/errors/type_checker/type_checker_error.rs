@@ -76,11 +76,11 @@ create_messages!(
     /// For when the user tries to return a unknown variable.
     @formatted
     unknown_sym {
-        args: (kind: impl Display, sym: impl Display),
+        args: (kind: impl Display, sym: impl Display, suggestion: Option<impl Display>),
         msg: format!(
             "Unknown {kind} `{sym}`",
         ),
-        help: None,
+        help: suggestion.map(|suggestion| format!("Did you mean `{suggestion}`?")),
     }
 
     /// For when the user tries calls a function with the incorrect number of args.
@@ -660,7 +660,7 @@ create_messages!(
     @formatted
     const_declaration_must_be_literal_or_tuple_of_literals {
         args: (),
-        msg: format!("The value of a const declaration must be a literal"),
+        msg: format!("The value of a const declaration must be a literal, an array of literals, or a tuple of literals"),
         help: None,
     }
 
@@ -712,4 +712,510 @@ create_messages!(
         msg: format!("An array cannot have a record as an element type"),
         help: None,
     }
+
+    @formatted
+    could_not_infer_integer_literal_type {
+        args: (value: impl Display),
+        msg: format!("Could not infer the type of the integer literal `{value}`"),
+        help: Some(format!("Add a type suffix, e.g. `{value}u32`, or use the literal where an integer type is expected.")),
+    }
+
+    @formatted
+    unsuffixed_literal_must_be_integer {
+        args: (value: impl Display, expected: impl Display),
+        msg: format!("The literal `{value}` is an integer, but type `{expected}` was expected"),
+        help: None,
+    }
+
+    @formatted
+    missing_record_member {
+        args: (record: impl Display, member: impl Display),
+        msg: format!("Record initialization expression for `{record}` is missing member `{member}`."),
+        help: None,
+    }
+
+    @formatted
+    function_cannot_create_record {
+        args: (record: impl Display),
+        msg: format!("A `function` cannot create the record `{record}`."),
+        help: Some("Records can only be created in a `transition`, or in an `inline` function called from one.".to_string()),
+    }
+
+    @formatted
+    finalize_cannot_create_record {
+        args: (record: impl Display),
+        msg: format!("A finalize block cannot create the record `{record}`."),
+        help: Some("Create the record in the body of the transition instead.".to_string()),
+    }
+
+    @formatted
+    duplicate_interface_member {
+        args: (interface: impl Display),
+        msg: format!("Interface {interface} defined with more than one member with the same name."),
+        help: None,
+    }
+
+    @formatted
+    interface_cannot_have_member_mode {
+        args: (),
+        msg: format!("The members of an interface cannot have modes."),
+        help: Some("Remove the mode from the member.".to_string()),
+    }
+
+    @formatted
+    missing_interface_member {
+        args: (struct_: impl Display, interface: impl Display, member: impl Display),
+        msg: format!("`{struct_}` does not conform to the interface `{interface}`: it is missing the member `{member}`."),
+        help: None,
+    }
+
+    @formatted
+    interface_member_type_mismatch {
+        args: (struct_: impl Display, interface: impl Display, member: impl Display, expected: impl Display, found: impl Display),
+        msg: format!(
+            "`{struct_}` does not conform to the interface `{interface}`: the member `{member}` has type `{found}`, but the interface requires `{expected}`."
+        ),
+        help: None,
+    }
+
+    @formatted
+    lookup_index_out_of_bounds {
+        args: (index: impl Display, length: impl Display),
+        msg: format!("The index `{index}` is out of bounds for a lookup table of length `{length}`."),
+        help: None,
+    }
+
+    @formatted
+    reserved_method_name {
+        args: (method: impl Display),
+        msg: format!("`{method}` cannot be the name of a method, since `x.{method}(..)` is a built-in operation."),
+        help: Some("Rename the method.".to_string()),
+    }
+
+    @formatted
+    unknown_method {
+        args: (type_: impl Display, method: impl Display),
+        msg: format!("The type `{type_}` has no method `{method}`."),
+        help: None,
+    }
+
+    @formatted
+    use_of_unassigned_variable {
+        args: (name: impl Display),
+        msg: format!("The variable `{name}` is used before it is assigned on every path."),
+        help: Some(format!("Assign a value to `{name}` on every path before it is used.")),
+    }
+
+    @formatted
+    private_item_access {
+        args: (kind: impl Display, item: impl Display, import: impl Display, program: impl Display),
+        msg: format!("The {kind} `{item}` of `{import}.aleo` is private, so it cannot be used by `{program}.aleo`."),
+        help: Some(format!("Declare `{item}` as `public` in `{import}.aleo` to make it available to importing programs.")),
+    }
+
+    @formatted
+    unknown_external_function {
+        args: (program: impl Display, function: impl Display),
+        msg: format!("The program `{program}.aleo` has no function named `{function}`."),
+        help: Some(format!("Check that `{program}.leo` is imported, and that it defines `{function}`.")),
+    }
+
+    @formatted
+    value_out_of_range {
+        args: (value: impl Display, type_: impl Display),
+        msg: format!("The expression evaluates to `{value}`, which is out of range for `{type_}`."),
+        help: Some("Use a wider integer type, or a wrapping operation if the overflow is intended.".to_string()),
+    }
+
+    @formatted
+    shift_amount_exceeds_width {
+        args: (amount: impl Display, type_: impl Display, width: impl Display),
+        msg: format!("Cannot shift a `{type_}` by `{amount}` bits, since it only has {width} bits."),
+        help: Some(format!("The shift amount must be less than {width}. Use a wrapping shift if this is intended.")),
+    }
+
+    @formatted
+    division_by_zero {
+        args: (operation: impl Display),
+        msg: format!("The divisor of `{operation}` is zero, so the operation always fails."),
+        help: None,
+    }
+
+    @formatted
+    ternary_branch_mismatch {
+        args: (if_true: impl Display, if_false: impl Display),
+        msg: format!("The branches of the ternary expression have different types: `{if_true}` and `{if_false}`."),
+        help: Some("Both branches of a ternary expression must have the same type.".to_string()),
+    }
+
+    @formatted
+    return_in_block_expression {
+        args: (),
+        msg: "A block expression cannot contain a return statement.".to_string(),
+        help: Some("End the block with the value it evaluates to instead.".to_string()),
+    }
+
+    @formatted
+    missing_return_on_path {
+        args: (path: impl Display),
+        msg: format!("Function must return a value, but {path} does not return."),
+        help: Some("End every path through the function with a return statement.".to_string()),
+    }
+
+    @formatted
+    annotation_takes_no_value {
+        args: (annotation: impl Display),
+        msg: format!("The annotation `@{annotation}` does not take a value."),
+        help: Some("Remove the parenthesized value.".to_string()),
+    }
+
+    @formatted
+    test_function_has_inputs {
+        args: (function: impl Display),
+        msg: format!("The test function `{function}` cannot have inputs."),
+        help: Some("Construct the values the test needs in its body.".to_string()),
+    }
+
+    @formatted
+    cyclic_constant_dependency {
+        args: (path: Vec<impl Display>),
+        msg: {
+            let path_string = path.into_iter().map(|name| format!("`{name}`")).collect::<Vec<String>>().join(" --> ");
+            format!("Cyclic dependency between constants: {path_string}")
+        },
+        help: None,
+    }
+
+    @formatted
+    unknown_array_length {
+        args: (name: impl Display),
+        msg: format!("The array length `{name}` is not a constant of the program."),
+        help: Some("Give the length as a number, or as a constant declared in the program scope.".to_string()),
+    }
+
+    @formatted
+    invalid_array_length {
+        args: (name: impl Display, value: impl Display),
+        msg: format!("The array length `{name}` has the value `{value}`, which is not a valid array length."),
+        help: Some("An array length must be a non-negative integer known at compile time.".to_string()),
+    }
+
+    @formatted
+    invalid_const_parameter_type {
+        args: (parameter: impl Display, type_: impl Display),
+        msg: format!("The constant parameter `{parameter}` has type `{type_}`, but constant parameters must be integers."),
+        help: None,
+    }
+
+    @formatted
+    generic_struct_has_methods {
+        args: (struct_: impl Display),
+        msg: format!("The generic struct `{struct_}` cannot declare methods."),
+        help: Some("Declare the operations on its instances as functions.".to_string()),
+    }
+
+    @formatted
+    not_a_generic_struct {
+        args: (name: impl Display),
+        msg: format!("`{name}` is not a generic struct, so it cannot be given constant arguments."),
+        help: None,
+    }
+
+    @formatted
+    missing_const_arguments {
+        args: (name: impl Display),
+        msg: format!("The generic struct `{name}` is used without constant arguments."),
+        help: Some(format!("Give the arguments of the instance, e.g. `{name}<3>` as a type, or `{name}::<3> {{ ... }}` as a value.")),
+    }
+
+    @formatted
+    incorrect_num_const_arguments {
+        args: (name: impl Display, expected: impl Display, received: impl Display),
+        msg: format!("The generic struct `{name}` expects {expected} constant argument(s), but {received} were given."),
+        help: None,
+    }
+
+    @formatted
+    unknown_const_argument {
+        args: (name: impl Display),
+        msg: format!("The constant argument `{name}` is not an integer constant of the program."),
+        help: Some("Give the argument as a number, or as a constant declared in the program scope.".to_string()),
+    }
+
+    @formatted
+    const_argument_out_of_range {
+        args: (value: impl Display, parameter: impl Display, type_: impl Display),
+        msg: format!("The constant argument `{value}` is out of range for the parameter `{parameter}` of type `{type_}`."),
+        help: None,
+    }
+
+    @formatted
+    invalid_randomness {
+        args: (type_: impl Display),
+        msg: format!("The randomness of a commitment or encryption must be a `scalar`, but got `{type_}`."),
+        help: Some("Commitments and encryptions are only hiding if their randomness is a uniformly sampled `scalar`.".to_string()),
+    }
+
+    @formatted
+    core_function_input_too_wide {
+        args: (function: impl Display, type_: impl Display, max_bits: impl Display),
+        msg: format!("`{function}` cannot take an input of type `{type_}`, since its integer inputs are limited to {max_bits} bits."),
+        help: Some("Cast the input to a narrower integer type, or use a `BHP` hash or commitment instead.".to_string()),
+    }
+
+    @formatted
+    invalid_group_coordinate {
+        args: (name: impl Display),
+        msg: format!("`{name}` is not a coordinate of a group element."),
+        help: Some("The coordinates of a group element are `x` and `y`.".to_string()),
+    }
+
+    @formatted
+    program_has_no_entry_point {
+        args: (program: impl Display),
+        msg: format!("The program `{program}` has no entry point."),
+        help: Some("A program must declare at least one `transition`, which can be executed.".to_string()),
+    }
+
+    @formatted
+    invalid_entry_point_type {
+        args: (kind: impl Display, type_: impl Display),
+        msg: format!("A transition cannot have an {kind} of type `{type_}`."),
+        help: None,
+    }
+
+    @formatted
+    entry_point_record_cannot_have_mode {
+        args: (kind: impl Display, mode: impl Display),
+        msg: format!("A record {kind} of a transition cannot be `{mode}`."),
+        help: Some("The visibility of a record is determined by its owner. Remove the mode.".to_string()),
+    }
+
+    @formatted
+    runtime_value_to_constant_parameter {
+        args: (parameter: impl Display, function: impl Display, location: impl Display),
+        msg: format!("The argument of the constant parameter `{parameter}` of `{function}` is only known at runtime."),
+        help: Some(format!(
+            "The parameter is declared `constant` at {location}. Pass a literal, a constant, or a constant parameter."
+        )),
+    }
+
+    @formatted
+    private_value_to_constant_parameter {
+        args: (parameter: impl Display, function: impl Display, location: impl Display),
+        msg: format!("The argument of the constant parameter `{parameter}` of `{function}` depends on private data."),
+        help: Some(format!(
+            "The parameter is declared `constant` at {location}. Private data cannot flow into a constant."
+        )),
+    }
+
+    @formatted
+    struct_member_default_must_be_literal {
+        args: (member: impl Display),
+        msg: format!("The default value of the member `{member}` must be a literal."),
+        help: None,
+    }
+
+    @formatted
+    interface_member_cannot_have_default {
+        args: (member: impl Display),
+        msg: format!("The interface member `{member}` cannot have a default value."),
+        help: Some("Declare the default value in the struct that conforms to the interface.".to_string()),
+    }
+
+    @formatted
+    duplicate_struct_init_member {
+        args: (member: impl Display, struct_: impl Display),
+        msg: format!("The member `{member}` of `{struct_}` is initialized more than once."),
+        help: None,
+    }
+
+    @formatted
+    struct_pattern_binding_must_be_an_identifier {
+        args: (),
+        msg: format!("The members of a struct on the left-hand side of a `DefinitionStatement` can only be bound to identifiers."),
+        help: None,
+    }
+
+    @formatted
+    duplicate_struct_pattern_member {
+        args: (member: impl Display, struct_: impl Display),
+        msg: format!("The member `{member}` of `{struct_}` is bound more than once."),
+        help: None,
+    }
+
+    @formatted
+    missing_struct_pattern_member {
+        args: (struct_: impl Display, member: impl Display),
+        msg: format!("The pattern for `{struct_}` does not bind the member `{member}`."),
+        help: None,
+    }
+
+    @formatted
+    invalid_match_value {
+        args: (type_: impl Display),
+        msg: format!("The value of a `match` statement must be an integer or an enum, but type `{type_}` was found."),
+        help: None,
+    }
+
+    @formatted
+    invalid_match_pattern {
+        args: (pattern: impl Display),
+        msg: format!("The pattern `{pattern}` of a `match` arm must be an integer literal, a variant of an enum, or `_`."),
+        help: None,
+    }
+
+    @formatted
+    unreachable_match_arm {
+        args: (),
+        msg: format!("This arm of the `match` statement is unreachable, since its values are matched by an earlier arm."),
+        help: None,
+    }
+
+    @formatted
+    non_exhaustive_match {
+        args: (type_: impl Display),
+        msg: format!("The arms of the `match` statement do not cover every value of type `{type_}`."),
+        help: Some("Add a wildcard arm `_ => { ... }` to match the remaining values.".to_string()),
+    }
+
+    @formatted
+    duplicate_enum_variant {
+        args: (enum_: impl Display, variant: impl Display),
+        msg: format!("Enum `{enum_}` declares the variant `{variant}` more than once."),
+        help: None,
+    }
+
+    @formatted
+    too_many_enum_variants {
+        args: (enum_: impl Display, max: impl Display),
+        msg: format!("Enum `{enum_}` declares more than the maximum of {max} variants."),
+        help: None,
+    }
+
+    @formatted
+    console_log_argument_count_mismatch {
+        args: (expected: impl Display, received: impl Display),
+        msg: format!("The format string has {expected} placeholder(s), but {received} argument(s) were given."),
+        help: Some("Write one `{}` in the format string for each argument.".to_string()),
+    }
+
+    @formatted
+    console_log_argument_not_printable {
+        args: (type_: impl Display),
+        msg: format!("A value of type `{type_}` cannot be logged."),
+        help: None,
+    }
+
+    @formatted
+    annotation_value_must_be_string {
+        args: (annotation: impl Display),
+        msg: format!("The value of the annotation `@{annotation}` must be a string."),
+        help: Some(format!("Write the value in quotes, e.g. `@{annotation}(\"use foo instead\")`.")),
+    }
+
+    @formatted
+    condition_not_boolean {
+        args: (type_: impl Display),
+        msg: format!("The condition of an `if` statement must be a `bool`, but it has type `{type_}`."),
+        help: Some("Compare the value to produce a `bool`, e.g. `if x != 0u8 { ... }`.".to_string()),
+    }
+
+    @formatted
+    record_consumed_twice {
+        args: (record: impl Display),
+        msg: format!("The record `{record}` is used after it has been consumed."),
+        help: Some("A record can only be consumed once, by returning it, passing it to a function, or moving it into another value.".to_string()),
+    }
+
+    @formatted
+    record_consumed_in_loop {
+        args: (record: impl Display),
+        msg: format!("The record `{record}` is consumed in a loop, so it would be consumed on every iteration."),
+        help: None,
+    }
+
+    @formatted
+    record_dropped {
+        args: (record: impl Display),
+        msg: format!("The record `{record}` is not consumed on every path, so it would be dropped."),
+        help: Some(format!("Return it, pass it to a function, or discard it explicitly by binding it to a name that starts with `_`, e.g. `let _{record} = {record};`.")),
+    }
+
+    @formatted
+    record_value_dropped {
+        args: (type_: impl Display),
+        msg: format!("The record of type `{type_}` produced by this expression is dropped."),
+        help: Some("Bind it to a variable and consume it, or bind it to a name that starts with `_` to discard it.".to_string()),
+    }
+
+    @formatted
+    annotation_must_name_lint {
+        args: (annotation: impl Display, lints: impl Display),
+        msg: format!("The annotation `@{annotation}` must name one of the lints `{lints}`, e.g. `@{annotation}(mixed_signedness)`."),
+        help: None,
+    }
+
+    @formatted
+    mixed_signedness {
+        args: (operation: impl Display, from: impl Display, to: impl Display),
+        msg: format!("An operand of `{operation}` is cast from `{from}` to `{to}`, which mixes signed and unsigned integers."),
+        help: Some("The cast may change the value of the operand. This lint is denied by the `@deny(mixed_signedness)` annotation of the program.".to_string()),
+    }
+
+    @formatted
+    invalid_address_character {
+        args: (character: impl Display, position: impl Display),
+        msg: format!("Invalid character `{character}` at position {position} of the address."),
+        help: Some("An address is `aleo1` followed by the bech32 characters `qpzry9x8gf2tvdw0s3jn54khce6mua7l`.".to_string()),
+    }
+
+    @formatted
+    invalid_address_length {
+        args: (address: impl Display, length: impl Display, expected: impl Display),
+        msg: format!("The address `{address}` has {length} characters, but an address has {expected}."),
+        help: None,
+    }
+
+    @formatted
+    invalid_address_checksum {
+        args: (address: impl Display),
+        msg: format!("The checksum of the address `{address}` does not match, so it is not a valid bech32m address."),
+        help: Some("Check the address for typos, or copy it again from its source.".to_string()),
+    }
+
+    @formatted
+    invalid_group_element {
+        args: (group: impl Display),
+        msg: format!("The group literal `{group}` is not a point in the prime order subgroup of the curve."),
+        help: Some("The literal `xgroup` is the point with the x-coordinate `x`, and `(x, y)group` is the point with the coordinates `x` and `y`.".to_string()),
+    }
+
+    @formatted
+    literal_exceeds_modulus {
+        args: (value: impl Display, type_: impl Display, modulus: impl Display),
+        msg: format!("The value {value} is not a valid `{type_}`, since its magnitude is not less than the modulus {modulus}."),
+        help: Some(format!("Write the value of the literal reduced modulo {modulus}.")),
+    }
+
+    @formatted
+    external_call_in_conditional {
+        args: (),
+        msg: format!("An external call cannot be made in a conditional statement, since it is executed whether or not the condition holds."),
+        help: Some("Move the call out of the conditional statement.".to_string()),
+    }
+
+    @formatted
+    external_call_in_finalize {
+        args: (),
+        msg: format!("An external call cannot be made in a finalize block."),
+        help: Some("Move the call into the body of the transition, which may pass its outputs to the finalize block.".to_string()),
+    }
+
+    @formatted
+    invalid_address_point {
+        args: (address: impl Display),
+        msg: format!("The address `{address}` is a valid bech32m encoding, but it does not encode a point on the curve."),
+        help: Some("Check that the address was copied from an account, rather than written by hand.".to_string()),
+    }
 );
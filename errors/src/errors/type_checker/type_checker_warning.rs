@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// TypeCheckerWarning enum that represents all the warnings for the type checking and lint passes.
+    TypeCheckerWarning,
+    code_mask: 2000i32,
+    code_prefix: "TYC",
+
+    /// For when a variable is defined but never read.
+    @formatted
+    unused_variable {
+        args: (name: impl Display),
+        msg: format!("unused variable `{name}`"),
+        help: Some(format!("If this is intentional, prefix it with an underscore: `_{name}`. Otherwise, remove it.")),
+    }
+
+    /// For when no item of an imported program is used.
+    @formatted
+    unused_import {
+        args: (name: impl Display),
+        msg: format!("unused import `{name}`"),
+        help: Some("Remove the import.".to_string()),
+    }
+
+    /// For when a deprecated function or struct is used.
+    @formatted
+    deprecated_item {
+        args: (kind: impl Display, name: impl Display, message: Option<&str>),
+        msg: match message {
+            Some(message) => format!("{kind} `{name}` is deprecated: {message}"),
+            None => format!("{kind} `{name}` is deprecated"),
+        },
+        help: None,
+    }
+
+    /// For when an operand of an arithmetic operation is cast between a signed and an unsigned integer type.
+    @formatted
+    mixed_signedness {
+        args: (operation: impl Display, from: impl Display, to: impl Display),
+        msg: format!("an operand of `{operation}` is cast from `{from}` to `{to}`, which mixes signed and unsigned integers"),
+        help: Some("The cast may change the value of the operand. Check that it cannot be out of the range of both types.".to_string()),
+    }
+
+    /// For when the result of a core function without side effects is discarded.
+    @formatted
+    unused_result {
+        args: (function: impl Display),
+        msg: format!("unused result of `{function}`, which has no other effect"),
+        help: Some("Assign the result to a variable, or remove the call.".to_string()),
+    }
+);
@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The extended explanations of the most common messages, each with a minimal example and its fix.
+//! Messages without an entry here are explained by their documentation alone.
+
+/// Returns the extended explanation of the message with the given prefix and name, if one has been written.
+pub(crate) fn details(prefix: &str, name: &str) -> Option<&'static str> {
+    let details = match (prefix, name) {
+        ("PAR", "unexpected") => PAR_UNEXPECTED,
+        ("PAR", "unexpected_eof") => PAR_UNEXPECTED_EOF,
+        ("TYC", "type_should_be") => TYC_TYPE_SHOULD_BE,
+        ("TYC", "unknown_sym") => TYC_UNKNOWN_SYM,
+        ("TYC", "invalid_int_value") => TYC_INVALID_INT_VALUE,
+        ("TYC", "missing_struct_member") => TYC_MISSING_STRUCT_MEMBER,
+        ("TYC", "loop_body_contains_return") => TYC_LOOP_BODY_CONTAINS_RETURN,
+        ("TYC", "missing_return") => TYC_MISSING_RETURN,
+        _ => return None,
+    };
+    Some(details)
+}
+
+/// The extended explanation of `ParserError::unexpected`.
+const PAR_UNEXPECTED: &str = r#"The parser found a token where a different one was expected.
+This is often caused by a missing semicolon or a missing closing delimiter.
+
+Example:
+
+    let a: u8 = 1u8
+    let b: u8 = 2u8;
+
+Fix: terminate every statement with a semicolon.
+
+    let a: u8 = 1u8;
+    let b: u8 = 2u8;
+"#;
+
+/// The extended explanation of `ParserError::unexpected_eof`.
+const PAR_UNEXPECTED_EOF: &str = r#"The file ended before the parser finished reading an item.
+This is usually caused by an unclosed brace, bracket, or parenthesis.
+
+Example:
+
+    program test.aleo {
+        transition main(a: u8) -> u8 {
+            return a;
+        }
+
+Fix: close every opened delimiter.
+
+    program test.aleo {
+        transition main(a: u8) -> u8 {
+            return a;
+        }
+    }
+"#;
+
+/// The extended explanation of `TypeCheckerError::type_should_be`.
+const TYC_TYPE_SHOULD_BE: &str = r#"An expression has a type other than the one required by its context.
+Leo does not convert between types implicitly.
+
+Example:
+
+    let a: u8 = 1u16;
+
+Fix: use a value of the expected type, or convert it explicitly with `as`.
+
+    let a: u8 = 1u8;
+    let b: u8 = 1u16 as u8;
+"#;
+
+/// The extended explanation of `TypeCheckerError::unknown_sym`.
+const TYC_UNKNOWN_SYM: &str = r#"A name is used that is not declared in the current scope.
+Variables are only visible in the block that declares them and its nested blocks.
+
+Example:
+
+    if flag {
+        let a: u8 = 1u8;
+    }
+    return a;
+
+Fix: declare the variable in a scope that encloses every use of it.
+
+    let a: u8 = 0u8;
+    if flag {
+        a = 1u8;
+    }
+    return a;
+"#;
+
+/// The extended explanation of `TypeCheckerError::invalid_int_value`.
+const TYC_INVALID_INT_VALUE: &str = r#"An integer literal does not fit in its type.
+
+Example:
+
+    let a: u8 = 256u8;
+
+Fix: use a value within the range of the type, or a wider type.
+
+    let a: u8 = 255u8;
+    let b: u16 = 256u16;
+"#;
+
+/// The extended explanation of `TypeCheckerError::missing_struct_member`.
+const TYC_MISSING_STRUCT_MEMBER: &str = r#"A struct or record is initialized without a value for one of its members.
+Every member must be given a value.
+
+Example:
+
+    struct Point { x: u8, y: u8 }
+
+    let p: Point = Point { x: 1u8 };
+
+Fix: initialize every member.
+
+    let p: Point = Point { x: 1u8, y: 0u8 };
+"#;
+
+/// The extended explanation of `TypeCheckerError::loop_body_contains_return`.
+const TYC_LOOP_BODY_CONTAINS_RETURN: &str = r#"A loop body contains a `return` statement.
+Loops are unrolled at compile time, so they cannot exit the function early.
+
+Example:
+
+    for i: u8 in 0u8..10u8 {
+        return i;
+    }
+
+Fix: compute the result in a variable declared outside the loop and return it afterwards.
+
+    let result: u8 = 0u8;
+    for i: u8 in 0u8..10u8 {
+        result = i;
+    }
+    return result;
+"#;
+
+/// The extended explanation of `TypeCheckerError::missing_return`.
+const TYC_MISSING_RETURN: &str = r#"A function with an output type does not return a value on every path.
+
+Example:
+
+    function max(a: u8, b: u8) -> u8 {
+        if a > b {
+            return a;
+        }
+    }
+
+Fix: return a value on every path through the function.
+
+    function max(a: u8, b: u8) -> u8 {
+        if a > b {
+            return a;
+        }
+        return b;
+    }
+"#;
@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+/// Contains the extended explanations of the most common messages.
+mod explanations;
+
+use crate::*;
+
+use std::fmt;
+
+/// The name and documentation of a message, as declared with `create_messages!`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageDefinition {
+    /// The name of the function that creates the message, e.g. `type_should_be`.
+    pub name: &'static str,
+    /// The stringified doc attributes of the message, e.g. `doc = r" For when ..."`.
+    docs: &'static [&'static str],
+}
+
+impl MessageDefinition {
+    /// Returns a new message definition given its name and its stringified doc attributes.
+    pub fn new(name: &'static str, docs: &'static [&'static str]) -> Self {
+        Self { name, docs }
+    }
+
+    /// Returns the documentation of the message as a single line of text.
+    pub fn description(&self) -> String {
+        let lines = self.docs.iter().filter_map(|doc| {
+            let text = doc.strip_prefix("doc =")?.trim().trim_start_matches('r');
+            Some(text.strip_prefix('"')?.strip_suffix('"')?.trim())
+        });
+        lines.collect::<Vec<_>>().join(" ")
+    }
+}
+
+/// The explanation of an error or warning code, as returned by [`explain`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    /// The code that is explained, e.g. `ETYC0372003`.
+    pub code: String,
+    /// The name of the message, e.g. `type_should_be`.
+    pub name: &'static str,
+    /// The documentation of the message.
+    pub description: String,
+    /// A longer description of the message with a minimal example and its fix, if one has been written.
+    pub details: Option<&'static str>,
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.code, self.name)?;
+        if !self.description.is_empty() {
+            write!(f, "\n\n{}", self.description)?;
+        }
+        match self.details {
+            Some(details) => write!(f, "\n\n{}", details.trim_end()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The prefix, code mask, and message definitions of an error or warning type.
+type MessageKind = (String, i32, fn() -> Vec<MessageDefinition>);
+
+/// Returns the prefix, code mask, and message definitions of each error type.
+fn error_kinds() -> Vec<MessageKind> {
+    vec![
+        (AstError::message_type(), AstError::code_mask(), AstError::message_definitions),
+        (CliError::message_type(), CliError::code_mask(), CliError::message_definitions),
+        (CompilerError::message_type(), CompilerError::code_mask(), CompilerError::message_definitions),
+        (FlattenError::message_type(), FlattenError::code_mask(), FlattenError::message_definitions),
+        (InputError::message_type(), InputError::code_mask(), InputError::message_definitions),
+        (InterpreterError::message_type(), InterpreterError::code_mask(), InterpreterError::message_definitions),
+        (LoopUnrollerError::message_type(), LoopUnrollerError::code_mask(), LoopUnrollerError::message_definitions),
+        (PackageError::message_type(), PackageError::code_mask(), PackageError::message_definitions),
+        (ParserError::message_type(), ParserError::code_mask(), ParserError::message_definitions),
+        (TypeCheckerError::message_type(), TypeCheckerError::code_mask(), TypeCheckerError::message_definitions),
+    ]
+}
+
+/// Returns the prefix, code mask, and message definitions of each warning type.
+fn warning_kinds() -> Vec<MessageKind> {
+    vec![
+        (CompilerWarning::message_type(), CompilerWarning::code_mask(), CompilerWarning::message_definitions),
+        (ParserWarning::message_type(), ParserWarning::code_mask(), ParserWarning::message_definitions),
+        (TypeCheckerWarning::message_type(), TypeCheckerWarning::code_mask(), TypeCheckerWarning::message_definitions),
+    ]
+}
+
+/// Returns the explanation of an error or warning code, e.g. `ETYC0372003`, or `None` if there is no such code.
+/// The code is case-insensitive.
+pub fn explain(code: &str) -> Option<Explanation> {
+    let code = code.trim().to_ascii_uppercase();
+    // A code is `E` or `W`, a three letter prefix, the code identifier `037`, and the number of the message.
+    let kinds = match code.get(..1)? {
+        "E" => error_kinds(),
+        "W" => warning_kinds(),
+        _ => return None,
+    };
+    let (prefix, identifier, number) = (code.get(1..4)?, code.get(4..7)?, code.get(7..)?);
+    if identifier != "037" || number.len() != 4 {
+        return None;
+    }
+    let number = number.parse::<i32>().ok()?;
+
+    let (prefix, mask, definitions) = kinds.into_iter().find(|(kind_prefix, ..)| kind_prefix == prefix)?;
+    let definition = definitions().into_iter().nth(usize::try_from(number.checked_sub(mask)?).ok()?)?;
+    Some(Explanation {
+        code,
+        name: definition.name,
+        description: definition.description(),
+        details: explanations::details(&prefix, definition.name),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_explain() {
+        let explanation = explain("ETYC0372003").unwrap();
+        assert_eq!(explanation.name, "type_should_be");
+        assert!(explanation.details.unwrap().contains("let a: u8 = 1u16;"));
+        assert!(explanation.to_string().starts_with("ETYC0372003 (type_should_be)\n\n"));
+
+        // Codes are case-insensitive, and warnings are explained.
+        assert_eq!(explain("wtyc0372000").unwrap().name, "unused_variable");
+
+        // The code of every message is explained.
+        let error = ParserError::unexpected_eof(leo_span::Span::default());
+        let explanation = explain(&error.error_code()).unwrap();
+        assert_eq!(explanation.name, "unexpected_eof");
+        assert_eq!(explanation.description, "For when the parser encountered an unexpected End of File.");
+        let error = CliError::failed_to_load_instructions(std::io::Error::new(std::io::ErrorKind::Other, "error"));
+        assert_eq!(explain(&error.error_code()).unwrap().name, "failed_to_load_instructions");
+
+        for code in ["ETYC0379999", "ETYC0382003", "XTYC0372003", "EXYZ0372003", "ETYC037200", "E"] {
+            assert_eq!(explain(code), None, "{code}");
+        }
+    }
+}
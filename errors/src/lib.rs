@@ -15,6 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 #![forbid(unsafe_code)]
+#![recursion_limit = "256"]
 #![deny(clippy::all, clippy::missing_docs_in_private_items)]
 #![doc = include_str!("../README.md")]
 
@@ -26,6 +27,10 @@ extern crate thiserror;
 pub mod common;
 pub use self::common::*;
 
+/// Contains the explanations of error and warning codes.
+pub mod explain;
+pub use self::explain::*;
+
 /// Contains traits and types for channels through which errors go.
 pub mod emitter;
 
@@ -76,6 +76,11 @@ enum Commands {
         #[clap(flatten)]
         command: Execute,
     },
+    #[clap(about = "Explain an error or warning code")]
+    Explain {
+        #[clap(flatten)]
+        command: Explain,
+    },
     #[clap(about = "Update the Leo CLI")]
     Update {
         #[clap(flatten)]
@@ -130,6 +135,7 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
         Commands::Example { command } => command.try_execute(context),
         Commands::Run { command } => command.try_execute(context),
         Commands::Execute { command } => command.try_execute(context),
+        Commands::Explain { command } => command.try_execute(context),
         Commands::Update { command } => command.try_execute(context),
     }
 }
@@ -16,8 +16,8 @@
 
 use super::*;
 
-use leo_ast::{NodeBuilder, Struct};
-use leo_compiler::{Compiler, CompilerOptions, InputAst, OutputOptions};
+use leo_compiler::{AstSnapshotOptions, CompiledInstructions, Compiler, CompilerOptions, OutputOptions, TypedInputAst};
+use leo_errors::emitter::ColorChoice;
 use leo_package::{
     build::BuildDirectory,
     imports::ImportsDirectory,
@@ -25,14 +25,12 @@ use leo_package::{
     outputs::OutputsDirectory,
     source::SourceDirectory,
 };
-use leo_span::{symbol::with_session_globals, Symbol};
 
 use snarkvm::{
     package::Package,
     prelude::{ProgramID, Testnet3},
 };
 
-use indexmap::IndexMap;
 use std::{
     io::Write,
     path::{Path, PathBuf},
@@ -41,32 +39,47 @@ use std::{
 impl From<BuildOptions> for CompilerOptions {
     fn from(options: BuildOptions) -> Self {
         let mut out_options = Self {
-            build: leo_compiler::BuildOptions { dce_enabled: options.enable_dce },
+            build: leo_compiler::BuildOptions {
+                dce_enabled: options.enable_dce,
+                instruction_budget: options.instruction_budget,
+                strip_enabled: options.enable_strip,
+                tests_enabled: options.enable_tests,
+                lints_enabled: options.enable_lints,
+                import_paths: options.import_paths,
+                interpreter_limits: Default::default(),
+                program_limits: Default::default(),
+                language_edition: Default::default(),
+            },
             output: OutputOptions {
                 symbol_table_spans_enabled: options.enable_symbol_table_spans,
                 initial_symbol_table: options.enable_initial_symbol_table_snapshot,
                 type_checked_symbol_table: options.enable_type_checked_symbol_table_snapshot,
                 unrolled_symbol_table: options.enable_unrolled_symbol_table_snapshot,
+                symbol_table_export: options.enable_symbol_table_export,
                 ast_spans_enabled: options.enable_ast_spans,
                 initial_input_ast: options.enable_initial_input_ast_snapshot,
-                initial_ast: options.enable_initial_ast_snapshot,
-                unrolled_ast: options.enable_unrolled_ast_snapshot,
-                ssa_ast: options.enable_ssa_ast_snapshot,
-                flattened_ast: options.enable_flattened_ast_snapshot,
-                destructured_ast: options.enable_destructured_ast_snapshot,
-                inlined_ast: options.enable_inlined_ast_snapshot,
-                dce_ast: options.enable_dce_ast_snapshot,
+                canonical_snapshots: options.enable_canonical_snapshots,
+                panic_audit: options.enable_panic_audit,
+                import_trace: options.enable_import_trace,
+                source_map: options.enable_source_map,
+                stats: options.stats.is_some(),
+                constraint_estimate: options.cost,
+                ast_snapshots: AstSnapshotOptions {
+                    initial: options.enable_initial_ast_snapshot,
+                    type_checked: options.enable_type_checked_ast_snapshot,
+                    typed_ir: options.enable_typed_ir_snapshot,
+                    unrolled: options.enable_unrolled_ast_snapshot,
+                    ssa: options.enable_ssa_ast_snapshot,
+                    flattened: options.enable_flattened_ast_snapshot,
+                    destructured: options.enable_destructured_ast_snapshot,
+                    inlined: options.enable_inlined_ast_snapshot,
+                    dce: options.enable_dce_ast_snapshot,
+                },
             },
         };
         if options.enable_all_ast_snapshots {
             out_options.output.initial_input_ast = true;
-            out_options.output.initial_ast = true;
-            out_options.output.unrolled_ast = true;
-            out_options.output.ssa_ast = true;
-            out_options.output.flattened_ast = true;
-            out_options.output.destructured_ast = true;
-            out_options.output.inlined_ast = true;
-            out_options.output.dce_ast = true;
+            out_options.output.ast_snapshots = AstSnapshotOptions::all();
         }
 
         out_options
@@ -82,7 +95,7 @@ pub struct Build {
 
 impl Command for Build {
     type Input = ();
-    type Output = (Option<InputAst>, IndexMap<Symbol, Struct>);
+    type Output = Option<TypedInputAst>;
 
     fn log_span(&self) -> Span {
         tracing::span!(tracing::Level::INFO, "Leo")
@@ -106,11 +119,8 @@ impl Command for Build {
         // Open the build directory.
         let build_directory = BuildDirectory::open(&package_path)?;
 
-        // Initialize error handler
-        let handler = Handler::default();
-
-        // Initialize a node counter.
-        let node_builder = NodeBuilder::default();
+        // Initialize error handler, which renders the diagnostics with excerpts of the source code.
+        let handler = Handler::new_terminal(std::io::stderr(), ColorChoice::Auto);
 
         // Fetch paths to all .leo files in the source directory.
         let source_files = SourceDirectory::files(&package_path)?;
@@ -118,12 +128,12 @@ impl Command for Build {
         // Check the source files.
         SourceDirectory::check_files(&source_files)?;
 
-        // Store all struct declarations made in the source files.
-        let mut structs = IndexMap::new();
+        // The input file bound to the main program, if it has one.
+        let mut input = None;
 
         // Compile all .leo files into .aleo files.
         for file_path in source_files.into_iter() {
-            structs.extend(compile_leo_file(
+            input = input.or(compile_leo_file(
                 file_path,
                 &package_path,
                 program_id,
@@ -144,7 +154,7 @@ impl Command for Build {
 
             // Compile all .leo files into .aleo files.
             for file_path in import_files.into_iter() {
-                structs.extend(compile_leo_file(
+                compile_leo_file(
                     file_path,
                     &package_path,
                     program_id,
@@ -153,26 +163,17 @@ impl Command for Build {
                     &handler,
                     self.options.clone(),
                     true,
-                )?);
+                )?;
             }
-        }
 
-        // Load the input file at `package_name.in`
-        let input_file_path = InputFile::new(&manifest.program_id().name().to_string()).setup_file_path(&package_path);
-
-        // Parse the input file.
-        let input_ast = if input_file_path.exists() {
-            // Load the input file into the source map.
-            let input_sf = with_session_globals(|s| s.source_map.load_file(&input_file_path))
-                .map_err(|e| CompilerError::file_read_error(&input_file_path, e))?;
-
-            // TODO: This is a hack to notify the user that something is wrong with the input file. Redesign.
-            leo_parser::parse_input(&handler, &node_builder, &input_sf.src, input_sf.start_pos)
-                .map_err(|_e| println!("Warning: Failed to parse input file"))
-                .ok()
-        } else {
-            None
-        };
+            // Copy the stubs of deployed programs, which are already Aleo instructions.
+            for stub_path in ImportsDirectory::stubs(&package_path)? {
+                if let Some(file_name) = stub_path.file_name() {
+                    std::fs::copy(&stub_path, build_imports_directory.join(file_name))
+                        .map_err(PackageError::failed_to_write_aleo_file)?;
+                }
+            }
+        }
 
         // `Package::open` checks that the build directory and that `main.aleo` and all imported files are well-formed.
         Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_execute_build)?;
@@ -195,22 +196,23 @@ impl Command for Build {
         // // Log the result of the build
         // tracing::info!("{}", result);
 
-        Ok((input_ast, structs))
+        Ok(input)
     }
 }
 
 /// Compiles a Leo file in the `src/` directory.
+/// For the main program, also binds the input file at `package_name.in` to its functions, if it exists.
 #[allow(clippy::too_many_arguments)]
 fn compile_leo_file(
     file_path: PathBuf,
-    _package_path: &Path,
+    package_path: &Path,
     program_id: &ProgramID<Testnet3>,
     outputs: &Path,
     build: &Path,
     handler: &Handler,
     options: BuildOptions,
     is_import: bool,
-) -> Result<IndexMap<Symbol, Struct>> {
+) -> Result<Option<TypedInputAst>> {
     // Construct the Leo file name with extension `foo.leo`.
     let file_name =
         file_path.file_name().and_then(|name| name.to_str()).ok_or_else(PackageError::failed_to_get_file_name)?;
@@ -229,6 +231,9 @@ fn compile_leo_file(
         false => format!("main.{}", program_id.network()),
     });
 
+    let stats_format = options.stats;
+    let cost = options.cost;
+
     // Create a new instance of the Leo compiler.
     let mut compiler = Compiler::new(
         program_name,
@@ -240,7 +245,7 @@ fn compile_leo_file(
     );
 
     // Compile the Leo program into Aleo instructions.
-    let (symbol_table, instructions) = compiler.compile()?;
+    let CompiledInstructions { symbol_table, instructions } = compiler.compile()?;
 
     // Write the instructions.
     std::fs::File::create(&aleo_file_path)
@@ -249,5 +254,21 @@ fn compile_leo_file(
         .map_err(CliError::failed_to_load_instructions)?;
 
     tracing::info!("✅ Compiled '{}' into Aleo instructions", file_name);
-    Ok(symbol_table.structs)
+
+    match stats_format {
+        Some(StatsFormat::Table) => tracing::info!("Compilation statistics of '{file_name}':\n{}", compiler.stats()),
+        Some(StatsFormat::Json) => tracing::info!("{}", compiler.stats().to_json_string()?),
+        None => {}
+    }
+    if cost {
+        tracing::info!("Estimated constraints of '{file_name}':\n{}", compiler.constraint_estimate());
+    }
+
+    // Imported programs are not run, so only the main program is given inputs.
+    if is_import {
+        return Ok(None);
+    }
+    let input_file_path = InputFile::new(&compiler.program_name).setup_file_path(package_path).into_owned();
+    compiler.parse_input(input_file_path)?;
+    compiler.input_binding_pass(&symbol_table)
 }
@@ -29,6 +29,9 @@ pub use example::Example;
 pub mod execute;
 pub use execute::Execute;
 
+pub mod explain;
+pub use explain::Explain;
+
 // pub mod deploy;
 // pub use deploy::Deploy;
 
@@ -46,11 +49,13 @@ pub use update::Update;
 
 use super::*;
 use crate::cli::helpers::context::*;
-use leo_errors::{emitter::Handler, CliError, CompilerError, PackageError, Result};
+use leo_errors::{emitter::Handler, CliError, PackageError, Result};
 use leo_package::{build::*, outputs::OutputsDirectory, package::*};
+use leo_span::Symbol;
 
 use clap::Parser;
 use colored::Colorize;
+use std::path::PathBuf;
 use tracing::span::Span;
 
 /// Base trait for the Leo CLI, see methods and their documentation for details.
@@ -129,6 +134,8 @@ pub struct BuildOptions {
     pub enable_type_checked_symbol_table_snapshot: bool,
     #[clap(long, help = "Writes symbol table snapshot of the unrolled symbol table.")]
     pub enable_unrolled_symbol_table_snapshot: bool,
+    #[clap(long, help = "Writes the functions, structs, and constants of the program for external analyzers.")]
+    pub enable_symbol_table_export: bool,
     #[clap(long, help = "Enable spans in AST snapshots.")]
     pub enable_ast_spans: bool,
     #[clap(long, help = "Enable spans in symbol table snapshots.")]
@@ -137,8 +144,14 @@ pub struct BuildOptions {
     pub enable_all_ast_snapshots: bool,
     #[clap(long, help = "Writes Input AST snapshot of the initial parse.")]
     pub enable_initial_input_ast_snapshot: bool,
+    #[clap(long, help = "Writes the AST and symbol table snapshots with sorted keys, so that they may be hashed.")]
+    pub enable_canonical_snapshots: bool,
     #[clap(long, help = "Writes AST snapshot of the initial parse.")]
     pub enable_initial_ast_snapshot: bool,
+    #[clap(long, help = "Writes AST snapshot of the type checked AST.")]
+    pub enable_type_checked_ast_snapshot: bool,
+    #[clap(long, help = "Writes the typed IR lowered from the type checked AST.")]
+    pub enable_typed_ir_snapshot: bool,
     #[clap(long, help = "Writes AST snapshot of the unrolled AST.")]
     pub enable_unrolled_ast_snapshot: bool,
     #[clap(long, help = "Writes AST snapshot of the SSA AST.")]
@@ -151,4 +164,38 @@ pub struct BuildOptions {
     pub enable_inlined_ast_snapshot: bool,
     #[clap(long, help = "Writes AST snapshot of the dead code eliminated (DCE) AST.")]
     pub enable_dce_ast_snapshot: bool,
+    #[clap(long, help = "Fails the build if a transition is estimated to execute more than this many instructions.")]
+    pub instruction_budget: Option<u64>,
+    #[clap(long, help = "Strips the functions, structs, and constants that are unreachable from the transitions.")]
+    pub enable_strip: bool,
+    #[clap(long, help = "Keeps the functions annotated with `@test`, which are otherwise removed from the build.")]
+    pub enable_tests: bool,
+    #[clap(long, help = "Warns of the variables and imports that are never used.")]
+    pub enable_lints: bool,
+    #[clap(long, help = "Writes a report of the operations that may halt the program, e.g. on an overflow.")]
+    pub enable_panic_audit: bool,
+    #[clap(long, help = "Writes a trace of the steps of import resolution.")]
+    pub enable_import_trace: bool,
+    #[clap(long, help = "Writes a source map from the generated instructions to the lines of the program.")]
+    pub enable_source_map: bool,
+    #[clap(long = "import-path", help = "Searches this directory for imported programs. May be given more than once.")]
+    pub import_paths: Vec<PathBuf>,
+    #[clap(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        default_missing_value = "table",
+        help = "Prints the time, AST node count, and peak memory of each compiler phase, as a table or as JSON."
+    )]
+    pub stats: Option<StatsFormat>,
+    #[clap(long, help = "Prints an estimate of the number of constraints of each function.")]
+    pub cost: bool,
+}
+
+/// The formats that the compilation statistics can be printed in.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatsFormat {
+    Table,
+    Json,
 }
@@ -47,10 +47,7 @@ impl Command for Run {
         // If input values are provided, then run the program with those inputs.
         // Otherwise, use the input file.
         let mut inputs = match self.inputs.is_empty() {
-            true => match input {
-                (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
-                _ => Vec::new(),
-            },
+            true => input.and_then(|input| input.function_inputs(Symbol::intern(&self.name))).unwrap_or_default(),
             false => self.inputs,
         };
 
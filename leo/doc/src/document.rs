@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{ConstDeclaration, Function, ImportTrace, Input, Mode, NodeBuilder, ProgramScope, Struct, Type, Variant};
+use leo_errors::{emitter::Handler, Result};
+use leo_parser::type_string;
+use leo_passes::{FunctionSymbol, Pass, SymbolTable, SymbolTableCreator};
+use leo_span::{
+    source_map::FileName,
+    sym,
+    symbol::{create_session_if_not_set_then, with_session_globals},
+};
+
+/// The documentation of an item of a program, e.g. a function.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ItemDocumentation {
+    /// The name of the item.
+    pub name: String,
+    /// The signature of the item, e.g. `transition mint(receiver: address, amount: u64) -> Token`.
+    pub signature: String,
+    /// The text of the doc comment of the item, if any.
+    pub doc: Option<String>,
+    /// The documentation of the methods of a struct or record.
+    pub methods: Vec<ItemDocumentation>,
+}
+
+/// The API reference of a program.
+/// The documentation holds no spans, so it can be rendered after the session it was computed in has ended.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProgramDocumentation {
+    /// The name of the program, e.g. `token.aleo`.
+    pub name: String,
+    /// The constants of the program.
+    pub constants: Vec<ItemDocumentation>,
+    /// The structs of the program.
+    pub structs: Vec<ItemDocumentation>,
+    /// The records of the program.
+    pub records: Vec<ItemDocumentation>,
+    /// The mappings of the program, which have no doc comments.
+    pub mappings: Vec<ItemDocumentation>,
+    /// The transitions, functions, and inline functions of the program.
+    pub functions: Vec<ItemDocumentation>,
+}
+
+impl ProgramDocumentation {
+    /// Returns the documentation of each program in `source`, the contents of the file `file_name`.
+    /// The signatures of structs and functions are taken from the symbol table of the file.
+    pub fn new(handler: &Handler, source: &str, file_name: FileName) -> Result<Vec<Self>> {
+        create_session_if_not_set_then(|_| {
+            let source_file = with_session_globals(|s| s.source_map.new_source(source, file_name));
+            let node_builder = NodeBuilder::default();
+            let ast = leo_parser::parse_ast(handler, &node_builder, &source_file.src, source_file.start_pos)?;
+            // The parser recovers from syntax errors, which are not documented.
+            handler.last_err().map_err(|e| *e)?;
+            let symbol_table = SymbolTableCreator::do_pass((&ast, handler, &ImportTrace::default()))?;
+
+            Ok(ast.as_repr().program_scopes.values().map(|scope| Self::from_scope(scope, &symbol_table)).collect())
+        })
+    }
+
+    /// Returns the documentation of a program scope.
+    fn from_scope(scope: &ProgramScope, symbol_table: &SymbolTable) -> Self {
+        let mut documentation = Self { name: scope.program_id.to_string(), ..Default::default() };
+        for (_, declaration) in &scope.consts {
+            documentation.constants.push(constant(declaration));
+        }
+        for (_, struct_) in &scope.structs {
+            let struct_ = symbol_table.lookup_struct(struct_.name()).unwrap_or(struct_);
+            match struct_.is_record {
+                true => documentation.records.push(struct_documentation(struct_, symbol_table)),
+                false => documentation.structs.push(struct_documentation(struct_, symbol_table)),
+            }
+        }
+        for (_, mapping) in &scope.mappings {
+            documentation.mappings.push(ItemDocumentation {
+                name: mapping.identifier.to_string(),
+                signature: format!(
+                    "mapping {}: {} => {}",
+                    mapping.identifier,
+                    type_string(&mapping.key_type),
+                    type_string(&mapping.value_type)
+                ),
+                ..Default::default()
+            });
+        }
+        for (name, function) in &scope.functions {
+            if let Some(symbol) = symbol_table.lookup_fn_symbol(*name) {
+                documentation.functions.push(function_documentation(function, symbol));
+            }
+        }
+        documentation
+    }
+}
+
+/// Returns the documentation of a constant, e.g. `const SIZE: u32`.
+fn constant(declaration: &ConstDeclaration) -> ItemDocumentation {
+    ItemDocumentation {
+        name: declaration.place.to_string(),
+        signature: format!("const {}: {}", declaration.place, type_string(&declaration.type_)),
+        doc: declaration.doc.clone(),
+        methods: Vec::new(),
+    }
+}
+
+/// Returns the documentation of a struct or record, whose signature includes its members.
+fn struct_documentation(struct_: &Struct, symbol_table: &SymbolTable) -> ItemDocumentation {
+    let keyword = if struct_.is_record { "record" } else { "struct" };
+    let mut signature = format!("{}{keyword} {}", struct_.visibility, struct_.identifier);
+    if struct_.is_generic() {
+        let parameters = struct_
+            .const_parameters
+            .iter()
+            .map(|parameter| format!("const {}: {}", parameter.identifier, type_string(&parameter.type_)))
+            .collect::<Vec<_>>();
+        signature.push_str(&format!("<{}>", parameters.join(", ")));
+    }
+    if !struct_.interfaces.is_empty() {
+        let interfaces = struct_.interfaces.iter().map(|interface| interface.to_string()).collect::<Vec<_>>();
+        signature.push_str(&format!(": {}", interfaces.join(" + ")));
+    }
+    signature.push_str(" {\n");
+    for member in &struct_.members {
+        let type_ = type_string(&member.type_);
+        signature.push_str(&format!("    {}{}: {type_},\n", mode_prefix(member.mode), member.identifier));
+    }
+    signature.push('}');
+
+    // Methods are in the symbol table under the names of the functions they are lowered to.
+    let methods = struct_
+        .methods
+        .iter()
+        .filter_map(|(name, method)| {
+            let symbol = symbol_table.lookup_fn_symbol(Struct::method_name(struct_.name(), *name))?;
+            Some(function_documentation(method, symbol))
+        })
+        .collect();
+
+    ItemDocumentation { name: struct_.identifier.to_string(), signature, doc: struct_.doc.clone(), methods }
+}
+
+/// Returns the documentation of a function, whose signature is given by its entry in the symbol table.
+fn function_documentation(function: &Function, symbol: &FunctionSymbol) -> ItemDocumentation {
+    let variant = match symbol.variant {
+        Variant::Inline => "inline",
+        Variant::Standard => "function",
+        Variant::Transition => "transition",
+    };
+    let inputs = symbol
+        .input()
+        .iter()
+        .map(|input| match input {
+            Input::Internal(input) if input.identifier.name == sym::SelfLower => {
+                format!("{}self", mode_prefix(input.mode))
+            }
+            Input::Internal(input) => {
+                format!("{}{}: {}", mode_prefix(input.mode), input.identifier, type_string(&input.type_))
+            }
+            Input::External(input) => input.to_string(),
+        })
+        .collect::<Vec<_>>();
+    let output = match symbol.output_type() {
+        Type::Unit => String::new(),
+        type_ => format!(" -> {}", type_string(type_)),
+    };
+
+    ItemDocumentation {
+        name: function.identifier.to_string(),
+        signature: format!("{}{variant} {}({}){output}", symbol.visibility, function.identifier, inputs.join(", ")),
+        doc: function.doc.clone(),
+        methods: Vec::new(),
+    }
+}
+
+/// Returns the mode of an input or member followed by a space, or nothing if the mode is not given.
+fn mode_prefix(mode: Mode) -> String {
+    match mode {
+        Mode::None => String::new(),
+        mode => format!("{mode} "),
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ItemDocumentation, ProgramDocumentation};
+
+/// The format that documentation is rendered in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DocFormat {
+    /// A Markdown document, whose signatures are Leo code blocks.
+    Markdown,
+    /// A standalone HTML page.
+    Html,
+}
+
+impl ProgramDocumentation {
+    /// Renders the documentation in the given format.
+    pub fn render(&self, format: DocFormat) -> String {
+        match format {
+            DocFormat::Markdown => self.to_markdown(),
+            DocFormat::Html => self.to_html(),
+        }
+    }
+
+    /// Returns the sections of the documentation that have items, in the order they are rendered.
+    fn sections(&self) -> Vec<(&'static str, &[ItemDocumentation])> {
+        [
+            ("Constants", self.constants.as_slice()),
+            ("Structs", &self.structs),
+            ("Records", &self.records),
+            ("Mappings", &self.mappings),
+            ("Functions", &self.functions),
+        ]
+        .into_iter()
+        .filter(|(_, items)| !items.is_empty())
+        .collect()
+    }
+
+    /// Renders the documentation as Markdown.
+    pub fn to_markdown(&self) -> String {
+        let mut output = format!("# `{}`\n", self.name);
+        for (title, items) in self.sections() {
+            output.push_str(&format!("\n## {title}\n"));
+            for item in items {
+                markdown_item(&mut output, item, "###");
+                for method in &item.methods {
+                    markdown_item(&mut output, method, "####");
+                }
+            }
+        }
+        output
+    }
+
+    /// Renders the documentation as a standalone HTML page.
+    pub fn to_html(&self) -> String {
+        let name = escape_html(&self.name);
+        let mut output = String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        output.push_str(&format!("<title>{name}</title>\n</head>\n<body>\n<h1>{name}</h1>\n"));
+        for (title, items) in self.sections() {
+            output.push_str(&format!("<h2>{title}</h2>\n"));
+            for item in items {
+                html_item(&mut output, item, "h3", &item.name);
+                for method in &item.methods {
+                    html_item(&mut output, method, "h4", &format!("{}.{}", item.name, method.name));
+                }
+            }
+        }
+        output.push_str("</body>\n</html>\n");
+        output
+    }
+}
+
+/// Writes an item as a Markdown heading of the given level, followed by its signature and its doc comment.
+/// The doc comment is written as is, so it may itself contain Markdown.
+fn markdown_item(output: &mut String, item: &ItemDocumentation, heading: &str) {
+    output.push_str(&format!("\n{heading} `{}`\n\n```leo\n{}\n```\n", item.name, item.signature));
+    if let Some(doc) = &item.doc {
+        output.push_str(&format!("\n{doc}\n"));
+    }
+}
+
+/// Writes an item as an HTML heading with the given tag and anchor, followed by its signature and its doc comment.
+/// Each paragraph of the doc comment, i.e. each run of lines without a blank line, is written as an HTML paragraph.
+fn html_item(output: &mut String, item: &ItemDocumentation, heading: &str, anchor: &str) {
+    let (anchor, name) = (escape_html(anchor), escape_html(&item.name));
+    output.push_str(&format!("<{heading} id=\"{anchor}\"><code>{name}</code></{heading}>\n"));
+    output.push_str(&format!("<pre><code>{}</code></pre>\n", escape_html(&item.signature)));
+    if let Some(doc) = &item.doc {
+        for paragraph in doc.split("\n\n").map(str::trim).filter(|paragraph| !paragraph.is_empty()) {
+            output.push_str(&format!("<p>{}</p>\n", escape_html(paragraph)));
+        }
+    }
+}
+
+/// Returns `text` with the characters that are special in HTML escaped.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            character => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_errors::emitter::Handler;
+    use leo_span::source_map::FileName;
+
+    use std::path::PathBuf;
+
+    const PROGRAM: &str = "program token.aleo {
+    /// The number of decimals of a token.
+    const DECIMALS: u8 = 6u8;
+
+    /// A token.
+    ///
+    /// The amount is in the smallest unit, see `DECIMALS`.
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    mapping balances: address => u64;
+
+    // This is not a doc comment.
+    /// Mints `amount` tokens for `receiver` if `amount < 100`.
+    transition mint(public receiver: address, amount: u64) -> Token {
+        return Token { owner: receiver, amount };
+    }
+
+    inline double(a: u64) -> u64 {
+        return a * 2u64;
+    }
+}
+";
+
+    fn documentation() -> ProgramDocumentation {
+        let handler = Handler::default();
+        let mut programs = ProgramDocumentation::new(&handler, PROGRAM, FileName::Real(PathBuf::from("main.leo")))
+            .expect("the program is documented");
+        assert_eq!(programs.len(), 1);
+        programs.remove(0)
+    }
+
+    #[test]
+    fn test_documentation() {
+        let documentation = documentation();
+        assert_eq!(documentation.name, "token.aleo");
+
+        assert_eq!(documentation.constants[0].signature, "const DECIMALS: u8");
+        assert_eq!(documentation.constants[0].doc.as_deref(), Some("The number of decimals of a token."));
+
+        let token = &documentation.records[0];
+        assert_eq!(token.signature, "record Token {\n    owner: address,\n    amount: u64,\n}");
+        assert_eq!(token.doc.as_deref(), Some("A token.\n\nThe amount is in the smallest unit, see `DECIMALS`."));
+
+        assert_eq!(documentation.mappings[0].signature, "mapping balances: address => u64");
+        assert_eq!(documentation.mappings[0].doc, None);
+
+        // Only the `///` comment directly before the function is its doc comment.
+        let mint = &documentation.functions[0];
+        assert_eq!(mint.signature, "transition mint(public receiver: address, amount: u64) -> Token");
+        assert_eq!(mint.doc.as_deref(), Some("Mints `amount` tokens for `receiver` if `amount < 100`."));
+
+        let double = &documentation.functions[1];
+        assert_eq!(double.signature, "inline double(a: u64) -> u64");
+        assert_eq!(double.doc, None);
+    }
+
+    #[test]
+    fn test_render() {
+        let documentation = documentation();
+
+        let markdown = documentation.render(DocFormat::Markdown);
+        let constants = "# `token.aleo`\n\n## Constants\n\n### `DECIMALS`\n\n```leo\nconst DECIMALS: u8\n```\n";
+        assert!(markdown.starts_with(constants));
+        assert!(markdown.contains("\n## Records\n"));
+        assert!(!markdown.contains("## Structs"));
+        assert!(markdown.contains("Mints `amount` tokens for `receiver` if `amount < 100`.\n"));
+
+        let html = documentation.render(DocFormat::Html);
+        assert!(html.contains("<title>token.aleo</title>"));
+        assert!(html.contains("<h3 id=\"mint\"><code>mint</code></h3>"));
+        assert!(html.contains("<p>A token.</p>\n<p>The amount is in the smallest unit, see `DECIMALS`.</p>\n"));
+        assert!(html.contains("<p>Mints `amount` tokens for `receiver` if `amount &lt; 100`.</p>"));
+        assert!(html.contains("<pre><code>mapping balances: address =&gt; u64</code></pre>"));
+    }
+}
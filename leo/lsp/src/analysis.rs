@@ -0,0 +1,220 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::Compiler;
+use leo_errors::{
+    emitter::{Diagnostic, Emitter, Handler},
+    LeoError,
+    LeoWarning,
+};
+use leo_passes::{Location, Pass, ReferenceIndex, ReferenceIndexer};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use std::{
+    cell::RefCell,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+/// An `Emitter` that collects diagnostics, so that they can be published to the client.
+struct DiagnosticCollector {
+    /// The diagnostics emitted so far.
+    diagnostics: Rc<RefCell<Vec<Diagnostic>>>,
+    /// Exit code of the last emitted error.
+    last_error_code: Option<i32>,
+}
+
+impl Emitter for DiagnosticCollector {
+    fn emit_err(&mut self, err: LeoError) {
+        self.last_error_code = Some(err.exit_code());
+        self.diagnostics.borrow_mut().extend(Diagnostic::from_error(&err));
+    }
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        self.last_error_code
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        self.diagnostics.borrow_mut().push(Diagnostic::from_warning(&warning));
+    }
+}
+
+/// The results of analyzing a single Leo file.
+/// An analysis holds no spans, so it can be queried after the session it was computed in has ended.
+#[derive(Debug, Default)]
+pub struct Analysis {
+    /// The errors and warnings found in the file.
+    /// Diagnostics without a location in the file, e.g. those in imported files, have no span.
+    pub diagnostics: Vec<Diagnostic>,
+    /// The definitions and references in the file.
+    pub reference_index: ReferenceIndex,
+    /// The types of the expressions in the file, as computed by the type checker.
+    pub types: Vec<(Location, String)>,
+}
+
+impl Analysis {
+    /// Parses and checks `source`, the contents of the file at `path`, with the same passes as `leo check`.
+    /// The reference index is persisted in the outputs directory of the package, if there is one.
+    pub fn new(source: &str, path: PathBuf) -> Self {
+        create_session_if_not_set_then(|_| {
+            let index_directory = index_directory(&path);
+            let file_name = FileName::Real(path.clone());
+            let file = file_name.to_string();
+
+            let diagnostics = Rc::new(RefCell::new(Vec::new()));
+            let handler = Handler::new(Box::new(DiagnosticCollector {
+                diagnostics: diagnostics.clone(),
+                last_error_code: None,
+            }));
+            // The program name and output directory are only used to name the outputs, none of which are enabled.
+            let program_name = path.file_stem().map_or_else(String::new, |stem| stem.to_string_lossy().into_owned());
+            let output_directory = index_directory.clone().unwrap_or_default();
+            let mut compiler =
+                Compiler::new(program_name, "aleo".into(), &handler, path.clone(), output_directory, None);
+
+            let mut analysis = Self::default();
+            let result = compiler.parse_ast_from_string(source, file_name).and_then(|()| {
+                // The index is built from the parsed AST, since the later passes rewrite the names of the program.
+                analysis.reference_index = ReferenceIndexer::do_pass(&compiler.ast);
+                // The index is only a cache, so failing to persist it does not affect the analysis.
+                if let Some(directory) = &index_directory {
+                    let _ = analysis.reference_index.persist(directory, &path);
+                }
+                compiler.front_end_passes()?;
+                compiler.check_passes()
+            });
+            // Record the types that were inferred, even if checking failed.
+            analysis.types = compiler
+                .type_table()
+                .spanned_types()
+                .into_iter()
+                .filter_map(|(span, type_)| Some((Location::from_span(span)?, type_.to_string())))
+                .collect();
+            // Errors that are returned rather than emitted are reported here.
+            if let Err(err) = result {
+                diagnostics.borrow_mut().extend(Diagnostic::from_error(&err));
+            }
+
+            analysis.diagnostics = diagnostics.take();
+            // Diagnostics in other files are reported without a span, naming the file instead.
+            for diagnostic in analysis.diagnostics.iter_mut() {
+                match diagnostic.span.take() {
+                    Some(span) if span.file != file => {
+                        diagnostic.message = format!("{}: {}", span.file, diagnostic.message)
+                    }
+                    span => diagnostic.span = span,
+                }
+            }
+            analysis
+        })
+    }
+
+    /// Returns an analysis holding only the persisted reference index of the file at `path`, if it was built from `source`.
+    /// This lets navigation work as soon as a file is opened, before it is checked.
+    pub fn cached(source: &str, path: &Path) -> Option<Self> {
+        let reference_index = ReferenceIndex::load(&index_directory(path)?, path, source)?;
+        Some(Self { reference_index, ..Default::default() })
+    }
+
+    /// Returns the location and type of the innermost expression at the given position, if one exists.
+    pub fn type_at(&self, line: usize, col: usize) -> Option<&(Location, String)> {
+        self.types
+            .iter()
+            .filter(|(location, _)| location.contains(line, col))
+            .max_by_key(|(location, _)| {
+                ((location.line_start, location.col_start), std::cmp::Reverse((location.line_stop, location.col_stop)))
+            })
+    }
+}
+
+/// Returns the directory the reference index of the file at `path` is persisted in, if the file is in a package.
+/// This is the outputs directory of the package, where `leo build` persists the index of the main file.
+fn index_directory(path: &Path) -> Option<PathBuf> {
+    let source_directory = path.parent().filter(|directory| directory.ends_with("src"))?;
+    let outputs_directory = source_directory.parent()?.join("outputs");
+    outputs_directory.is_dir().then_some(outputs_directory)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PROGRAM: &str = "program test.aleo {
+    transition main(a: u32, b: u8) -> u32 {
+        let c: u32 = a + b;
+        return c;
+    }
+}
+";
+
+    #[test]
+    fn test_analysis() {
+        let analysis = Analysis::new(PROGRAM, PathBuf::from("main.leo"));
+
+        // The mismatched operands of `a + b` are reported.
+        assert!(!analysis.diagnostics.is_empty());
+        for diagnostic in &analysis.diagnostics {
+            assert!(diagnostic.code.starts_with("ETYC"));
+            assert_eq!(diagnostic.span.as_ref().unwrap().line_start, 3);
+        }
+
+        // Hovering over `a` yields the type of `a`, not of `a + b`.
+        assert_eq!(analysis.type_at(3, 22).unwrap().1, "u32");
+        assert_eq!(analysis.type_at(3, 26).unwrap().1, "u8");
+
+        // The use of `c` resolves to its definition.
+        let definition = analysis.reference_index.definition_at(4, 16).unwrap();
+        assert_eq!(definition.location.line_start, 3);
+    }
+
+    #[test]
+    fn test_analysis_runs_the_front_end() {
+        // The `@wrapping` block is desugared before type checking, as when the program is compiled.
+        let program = "program test.aleo {
+    transition main(a: u8) -> u8 {
+        @wrapping {
+            let b: u8 = a + 1u8;
+        }
+        return a;
+    }
+}
+";
+        let analysis = Analysis::new(program, PathBuf::from("main.leo"));
+        let errors: Vec<_> =
+            analysis.diagnostics.iter().filter(|diagnostic| diagnostic.code.starts_with('E')).collect();
+        assert!(errors.is_empty(), "{errors:?}");
+    }
+
+    #[test]
+    fn test_cached_analysis() {
+        let package = std::env::temp_dir().join(format!("leo-lsp-package-{}", std::process::id()));
+        std::fs::create_dir_all(package.join("src")).unwrap();
+        std::fs::create_dir_all(package.join("outputs")).unwrap();
+        let path = package.join("src").join("main.leo");
+
+        // Nothing is cached before the file is analyzed.
+        assert!(Analysis::cached(PROGRAM, &path).is_none());
+
+        // Analyzing the file persists its reference index, which is reused until the file changes.
+        let analysis = Analysis::new(PROGRAM, path.clone());
+        let cached = Analysis::cached(PROGRAM, &path).unwrap();
+        assert_eq!(cached.reference_index, analysis.reference_index);
+        assert!(cached.reference_index.definition_at(4, 16).is_some());
+        assert!(Analysis::cached(&PROGRAM.replace("return c", "return a"), &path).is_none());
+
+        std::fs::remove_dir_all(package).unwrap();
+    }
+}
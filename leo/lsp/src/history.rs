@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use lsp_types::{Diagnostic, Url};
+
+use std::collections::{HashMap, VecDeque};
+
+/// The number of diagnostic sets kept for each file by default.
+pub const DEFAULT_HISTORY_LENGTH: usize = 8;
+
+/// The diagnostics of a file, computed from a version of its source.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VersionedDiagnostics {
+    /// The version of the source the diagnostics were computed from.
+    pub version: i32,
+    /// The diagnostics of that version.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// The last diagnostic sets of each file, along with the versions of the sources they were computed from.
+/// This lets the server answer which diagnostics belong to a version, even after the file has been checked again.
+#[derive(Debug)]
+pub struct DiagnosticsHistory {
+    /// The number of diagnostic sets kept for each file.
+    capacity: usize,
+    /// The diagnostic sets of each file, oldest first.
+    files: HashMap<Url, VecDeque<VersionedDiagnostics>>,
+}
+
+impl Default for DiagnosticsHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_HISTORY_LENGTH)
+    }
+}
+
+impl DiagnosticsHistory {
+    /// Returns an empty history that keeps the last `capacity` diagnostic sets of each file.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity: capacity.max(1), files: HashMap::new() }
+    }
+
+    /// Records the diagnostics of `version` of the file at `uri`, evicting the oldest set if the history is full.
+    /// Diagnostics recorded for the same version again replace the previous ones.
+    pub fn record(&mut self, uri: Url, version: i32, diagnostics: Vec<Diagnostic>) {
+        let history = self.files.entry(uri).or_default();
+        history.retain(|entry| entry.version != version);
+        if history.len() == self.capacity {
+            history.pop_front();
+        }
+        history.push_back(VersionedDiagnostics { version, diagnostics });
+    }
+
+    /// Returns the diagnostics of `version` of the file at `uri`, if they are still in the history.
+    pub fn at_version(&self, uri: &Url, version: i32) -> Option<&[Diagnostic]> {
+        let history = self.files.get(uri)?;
+        history.iter().find(|entry| entry.version == version).map(|entry| entry.diagnostics.as_slice())
+    }
+
+    /// Returns the most recently recorded diagnostics of the file at `uri`.
+    pub fn latest(&self, uri: &Url) -> Option<&VersionedDiagnostics> {
+        self.files.get(uri)?.back()
+    }
+
+    /// Forgets the diagnostics of the file at `uri`, e.g. when it is closed.
+    pub fn remove(&mut self, uri: &Url) {
+        self.files.remove(uri);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn diagnostic(message: &str) -> Diagnostic {
+        Diagnostic { message: message.to_string(), ..Default::default() }
+    }
+
+    #[test]
+    fn test_history() {
+        let uri = Url::parse("file:///main.leo").unwrap();
+        let mut history = DiagnosticsHistory::new(2);
+        assert!(history.latest(&uri).is_none());
+
+        history.record(uri.clone(), 1, vec![diagnostic("one")]);
+        history.record(uri.clone(), 2, vec![]);
+        assert_eq!(history.at_version(&uri, 1).unwrap(), [diagnostic("one")]);
+        assert_eq!(history.latest(&uri).unwrap().version, 2);
+
+        // Recording a third version evicts the first.
+        history.record(uri.clone(), 3, vec![diagnostic("three")]);
+        assert!(history.at_version(&uri, 1).is_none());
+        assert_eq!(history.at_version(&uri, 2).unwrap(), []);
+
+        // Recording a version again replaces its diagnostics without evicting others.
+        history.record(uri.clone(), 2, vec![diagnostic("two")]);
+        assert_eq!(history.at_version(&uri, 2).unwrap(), [diagnostic("two")]);
+        assert_eq!(history.at_version(&uri, 3).unwrap(), [diagnostic("three")]);
+        assert_eq!(history.latest(&uri).unwrap().version, 2);
+
+        history.remove(&uri);
+        assert!(history.at_version(&uri, 3).is_none());
+    }
+}
@@ -0,0 +1,407 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Analysis, DiagnosticsHistory};
+
+use leo_errors::emitter::DiagnosticSpan;
+use leo_passes::Location;
+
+use crossbeam_channel::TryRecvError;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument,
+        DidCloseTextDocument,
+        DidOpenTextDocument,
+        DidSaveTextDocument,
+        Notification as _,
+        PublishDiagnostics,
+    },
+    request::{GotoDefinition, HoverRequest, References, Request as _},
+    DiagnosticSeverity,
+    GotoDefinitionParams,
+    GotoDefinitionResponse,
+    Hover,
+    HoverContents,
+    HoverParams,
+    HoverProviderCapability,
+    MarkupContent,
+    MarkupKind,
+    NumberOrString,
+    OneOf,
+    Position,
+    PublishDiagnosticsParams,
+    Range,
+    ReferenceParams,
+    SaveOptions,
+    ServerCapabilities,
+    TextDocumentPositionParams,
+    TextDocumentSyncCapability,
+    TextDocumentSyncKind,
+    TextDocumentSyncOptions,
+    TextDocumentSyncSaveOptions,
+    Url,
+    VersionedTextDocumentIdentifier,
+};
+use std::{
+    collections::{HashMap, VecDeque},
+    error::Error,
+    path::PathBuf,
+};
+
+/// The error type of the server.
+pub type ServerError = Box<dyn Error + Send + Sync>;
+
+/// A request for the diagnostics of a version of a file, which returns `null` if they are no longer in the history.
+/// Clients can use it to match diagnostics with the text they were computed from when edits race with checking.
+pub enum VersionedDiagnosticsRequest {}
+
+impl lsp_types::request::Request for VersionedDiagnosticsRequest {
+    type Params = VersionedTextDocumentIdentifier;
+    type Result = Option<Vec<lsp_types::Diagnostic>>;
+
+    const METHOD: &'static str = "leo/versionedDiagnostics";
+}
+
+/// Returns the capabilities advertised by the server.
+pub fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        // Files are analyzed when opened or saved. Edits are only used to track the version of each file.
+        text_document_sync: Some(TextDocumentSyncCapability::Options(TextDocumentSyncOptions {
+            open_close: Some(true),
+            change: Some(TextDocumentSyncKind::INCREMENTAL),
+            save: Some(TextDocumentSyncSaveOptions::SaveOptions(SaveOptions { include_text: Some(true) })),
+            ..Default::default()
+        })),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        references_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    }
+}
+
+/// Initializes the connection and serves requests until the client shuts the server down.
+pub fn run(connection: Connection) -> Result<(), ServerError> {
+    connection.initialize(serde_json::to_value(capabilities())?)?;
+    Server {
+        connection,
+        analyses: HashMap::new(),
+        versions: HashMap::new(),
+        history: DiagnosticsHistory::default(),
+        pending: VecDeque::new(),
+    }
+    .serve()
+}
+
+/// Converts a `Location` into an LSP `Range`. Note that LSP positions are 0-based.
+fn to_range(location: &Location) -> Range {
+    Range {
+        start: Position::new(location.line_start.saturating_sub(1) as u32, location.col_start.saturating_sub(1) as u32),
+        end: Position::new(location.line_stop.saturating_sub(1) as u32, location.col_stop.saturating_sub(1) as u32),
+    }
+}
+
+/// Converts an LSP `Position` into the 1-based line and column used by `Location`.
+fn from_position(position: Position) -> (usize, usize) {
+    (position.line as usize + 1, position.character as usize + 1)
+}
+
+/// Converts a diagnostic span into an LSP `Range`, defaulting to the start of the file.
+fn span_to_range(span: &Option<DiagnosticSpan>) -> Range {
+    match span {
+        Some(span) => to_range(&Location {
+            line_start: span.line_start,
+            line_stop: span.line_stop,
+            col_start: span.col_start,
+            col_stop: span.col_stop,
+        }),
+        None => Range::default(),
+    }
+}
+
+/// A language server for Leo files.
+struct Server {
+    /// The connection to the client.
+    connection: Connection,
+    /// The analyses of the open files.
+    analyses: HashMap<Url, Analysis>,
+    /// The latest version of each open file.
+    versions: HashMap<Url, i32>,
+    /// The last diagnostics published for each open file.
+    history: DiagnosticsHistory,
+    /// The open files that are answered from their persisted reference index until they are analyzed.
+    pending: VecDeque<(Url, String)>,
+}
+
+impl Server {
+    /// Handles messages until the client shuts the server down.
+    /// The pending files are analyzed whenever no message is waiting, so that queued requests are answered first.
+    fn serve(&mut self) -> Result<(), ServerError> {
+        loop {
+            let message = match self.connection.receiver.try_recv() {
+                Ok(message) => message,
+                Err(TryRecvError::Empty) => match self.pending.pop_front() {
+                    Some((uri, text)) => {
+                        self.analyze(uri, &text)?;
+                        continue;
+                    }
+                    None => match self.connection.receiver.recv() {
+                        Ok(message) => message,
+                        Err(_) => return Ok(()),
+                    },
+                },
+                Err(TryRecvError::Disconnected) => return Ok(()),
+            };
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+    }
+
+    /// Sends a message to the client.
+    fn send(&self, message: impl Into<Message>) -> Result<(), ServerError> {
+        Ok(self.connection.sender.send(message.into())?)
+    }
+
+    /// Returns the analysis of the document at the given position, along with the 1-based position.
+    fn analysis_at(&self, params: &TextDocumentPositionParams) -> Option<(&Analysis, usize, usize)> {
+        let (line, col) = from_position(params.position);
+        Some((self.analyses.get(&params.text_document.uri)?, line, col))
+    }
+
+    /// Answers `request`, with an `InvalidParams` error if its parameters are malformed.
+    fn handle_request(&mut self, request: Request) -> Result<(), ServerError> {
+        let id = request.id.clone();
+        let response = self.respond(request).unwrap_or_else(|err| {
+            Response::new_err(id, ErrorCode::InvalidParams as i32, format!("invalid parameters: {err}"))
+        });
+        self.send(response)
+    }
+
+    /// Returns the response to `request`, or an error if its parameters cannot be deserialized.
+    fn respond(&self, request: Request) -> Result<Response, serde_json::Error> {
+        let id = request.id.clone();
+        Ok(match request.method.as_str() {
+            GotoDefinition::METHOD => {
+                let params: GotoDefinitionParams = serde_json::from_value(request.params)?;
+                let position = params.text_document_position_params;
+                let result = self.analysis_at(&position).and_then(|(analysis, line, col)| {
+                    let definition = analysis.reference_index.definition_at(line, col)?;
+                    Some(GotoDefinitionResponse::Scalar(lsp_types::Location::new(
+                        position.text_document.uri.clone(),
+                        to_range(&definition.location),
+                    )))
+                });
+                Response::new_ok(id, result)
+            }
+            HoverRequest::METHOD => {
+                let params: HoverParams = serde_json::from_value(request.params)?;
+                let result = self.analysis_at(&params.text_document_position_params).and_then(|(analysis, line, col)| {
+                    let (location, type_) = analysis.type_at(line, col)?;
+                    Some(Hover {
+                        contents: HoverContents::Markup(MarkupContent {
+                            kind: MarkupKind::Markdown,
+                            value: format!("```leo\n{type_}\n```"),
+                        }),
+                        range: Some(to_range(location)),
+                    })
+                });
+                Response::new_ok(id, result)
+            }
+            References::METHOD => {
+                let params: ReferenceParams = serde_json::from_value(request.params)?;
+                let position = params.text_document_position;
+                let result = self.analysis_at(&position).map(|(analysis, line, col)| {
+                    let mut locations = analysis.reference_index.references_at(line, col);
+                    if params.context.include_declaration {
+                        locations.extend(analysis.reference_index.definition_at(line, col).map(|d| d.location));
+                    }
+                    locations
+                        .iter()
+                        .map(|location| lsp_types::Location::new(position.text_document.uri.clone(), to_range(location)))
+                        .collect::<Vec<_>>()
+                });
+                Response::new_ok(id, result)
+            }
+            VersionedDiagnosticsRequest::METHOD => {
+                let params: VersionedTextDocumentIdentifier = serde_json::from_value(request.params)?;
+                Response::new_ok(id, self.history.at_version(&params.uri, params.version))
+            }
+            method => Response::new_err(id, ErrorCode::MethodNotFound as i32, format!("unsupported request `{method}`")),
+        })
+    }
+
+    /// Returns the parameters of a notification of type `N`, or logs and drops the notification if they are malformed,
+    /// since notifications cannot be answered with an error.
+    fn notification_params<N: lsp_types::notification::Notification>(notification: Notification) -> Option<N::Params> {
+        serde_json::from_value(notification.params)
+            .map_err(|err| eprintln!("dropping the `{}` notification with invalid parameters: {err}", N::METHOD))
+            .ok()
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Result<(), ServerError> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let Some(params) = Self::notification_params::<DidOpenTextDocument>(notification) else {
+                    return Ok(());
+                };
+                let uri = params.text_document.uri;
+                let text = params.text_document.text;
+                self.versions.insert(uri.clone(), params.text_document.version);
+                // A file whose reference index is persisted can be navigated right away, and is analyzed later.
+                match Analysis::cached(&text, &Self::path(&uri)) {
+                    Some(analysis) => {
+                        self.analyses.insert(uri.clone(), analysis);
+                        self.pending.push_back((uri, text));
+                        Ok(())
+                    }
+                    None => self.analyze(uri, &text),
+                }
+            }
+            DidChangeTextDocument::METHOD => {
+                let Some(params) = Self::notification_params::<DidChangeTextDocument>(notification) else {
+                    return Ok(());
+                };
+                self.versions.insert(params.text_document.uri, params.text_document.version);
+                Ok(())
+            }
+            DidSaveTextDocument::METHOD => {
+                let Some(params) = Self::notification_params::<DidSaveTextDocument>(notification) else {
+                    return Ok(());
+                };
+                // Clients that ignore `include_text` are handled by reading the file from disk.
+                let text = match params.text {
+                    Some(text) => text,
+                    None => match std::fs::read_to_string(Self::path(&params.text_document.uri)) {
+                        Ok(text) => text,
+                        Err(err) => {
+                            let uri = params.text_document.uri;
+                            eprintln!("dropping the save of `{uri}`, which cannot be read: {err}");
+                            return Ok(());
+                        }
+                    },
+                };
+                self.analyze(params.text_document.uri, &text)
+            }
+            DidCloseTextDocument::METHOD => {
+                let Some(params) = Self::notification_params::<DidCloseTextDocument>(notification) else {
+                    return Ok(());
+                };
+                self.analyses.remove(&params.text_document.uri);
+                self.versions.remove(&params.text_document.uri);
+                self.history.remove(&params.text_document.uri);
+                self.pending.retain(|(uri, _)| uri != &params.text_document.uri);
+                self.publish(params.text_document.uri, None, Vec::new())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the path of the file at `uri`.
+    fn path(uri: &Url) -> PathBuf {
+        uri.to_file_path().unwrap_or_else(|()| PathBuf::from(uri.path()))
+    }
+
+    /// Analyzes the document at `uri` and publishes its diagnostics.
+    fn analyze(&mut self, uri: Url, text: &str) -> Result<(), ServerError> {
+        self.pending.retain(|(pending, _)| pending != &uri);
+        let analysis = Analysis::new(text, Self::path(&uri));
+        let diagnostics: Vec<_> = analysis
+            .diagnostics
+            .iter()
+            .map(|diagnostic| lsp_types::Diagnostic {
+                range: span_to_range(&diagnostic.span),
+                severity: Some(match diagnostic.severity.as_str() {
+                    "error" => DiagnosticSeverity::ERROR,
+                    _ => DiagnosticSeverity::WARNING,
+                }),
+                code: Some(NumberOrString::String(diagnostic.code.clone())),
+                source: Some("leo".to_string()),
+                message: match &diagnostic.help {
+                    Some(help) => format!("{}\n{help}", diagnostic.message),
+                    None => diagnostic.message.clone(),
+                },
+                ..Default::default()
+            })
+            .collect();
+        self.analyses.insert(uri.clone(), analysis);
+        // The version is published with the diagnostics, so that clients can discard those of outdated text.
+        let version = self.versions.get(&uri).copied();
+        if let Some(version) = version {
+            self.history.record(uri.clone(), version, diagnostics.clone());
+        }
+        self.publish(uri, version, diagnostics)
+    }
+
+    /// Publishes the diagnostics of `version` of the document at `uri`.
+    fn publish(
+        &self,
+        uri: Url,
+        version: Option<i32>,
+        diagnostics: Vec<lsp_types::Diagnostic>,
+    ) -> Result<(), ServerError> {
+        let params = PublishDiagnosticsParams { uri, diagnostics, version };
+        self.send(Notification::new(PublishDiagnostics::METHOD.to_string(), params))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use lsp_server::RequestId;
+
+    #[test]
+    fn test_malformed_messages() {
+        let (connection, client) = Connection::memory();
+        let server = std::thread::spawn(move || {
+            Server {
+                connection,
+                analyses: HashMap::new(),
+                versions: HashMap::new(),
+                history: DiagnosticsHistory::default(),
+                pending: VecDeque::new(),
+            }
+            .serve()
+        });
+
+        // A malformed notification is dropped, and a malformed request is answered with an error.
+        let params = serde_json::json!({ "textDocument": 0 });
+        client.sender.send(Notification::new(DidOpenTextDocument::METHOD.to_string(), params.clone()).into()).unwrap();
+        client.sender.send(Request::new(RequestId::from(1), HoverRequest::METHOD.to_string(), params).into()).unwrap();
+        match client.receiver.recv().unwrap() {
+            Message::Response(response) => {
+                assert_eq!(response.id, RequestId::from(1));
+                assert_eq!(response.error.unwrap().code, ErrorCode::InvalidParams as i32);
+            }
+            message => panic!("unexpected message {message:?}"),
+        }
+
+        // The server keeps serving until it is shut down.
+        client.sender.send(Request::new(RequestId::from(2), "shutdown".to_string(), ()).into()).unwrap();
+        client.sender.send(Notification::new("exit".to_string(), ()).into()).unwrap();
+        let response = client.receiver.recv().unwrap();
+        assert!(matches!(response, Message::Response(response) if response.id == RequestId::from(2)));
+        server.join().unwrap().unwrap();
+    }
+}
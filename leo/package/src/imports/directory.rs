@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::parse_file_paths;
+use crate::{parse_file_paths, ALEO_FILE_EXTENSION};
 use leo_errors::{PackageError, Result};
 
 use std::{
@@ -71,7 +71,8 @@ impl ImportsDirectory {
             .is_none())
     }
 
-    /// Returns a list of files in the imports directory.
+    /// Returns a list of the Leo files in the imports directory.
+    /// The stubs of deployed programs in the directory are listed by [`Self::stubs`] instead.
     pub fn files(path: &Path) -> Result<Vec<PathBuf>> {
         let mut path = Cow::from(path);
         if path.is_dir() && !path.ends_with(IMPORTS_DIRECTORY_NAME) {
@@ -81,8 +82,35 @@ impl ImportsDirectory {
         let directory = fs::read_dir(&path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
         let mut file_paths = Vec::new();
 
+        let directory = directory.filter(|entry| !entry.as_ref().map_or(false, |entry| is_stub(&entry.path())));
         parse_file_paths(directory, &mut file_paths)?;
 
         Ok(file_paths)
     }
+
+    /// Returns a list of the stubs of deployed programs in the imports directory, i.e. its `.aleo` files.
+    pub fn stubs(path: &Path) -> Result<Vec<PathBuf>> {
+        let mut path = Cow::from(path);
+        if path.is_dir() && !path.ends_with(IMPORTS_DIRECTORY_NAME) {
+            path.to_mut().push(IMPORTS_DIRECTORY_NAME);
+        }
+
+        let directory = fs::read_dir(&path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+        let mut stub_paths = Vec::new();
+
+        for entry in directory {
+            let stub_path = entry.map_err(PackageError::failed_to_get_leo_file_entry)?.path();
+            if is_stub(&stub_path) {
+                stub_paths.push(stub_path);
+            }
+        }
+
+        Ok(stub_paths)
+    }
+}
+
+/// Returns `true` if the file at `path` is the stub of a deployed program, i.e. an `.aleo` file.
+fn is_stub(path: &Path) -> bool {
+    let is_aleo_file = path.extension().map_or(false, |extension| extension == ALEO_FILE_EXTENSION.trim_start_matches('.'));
+    path.is_file() && is_aleo_file
 }
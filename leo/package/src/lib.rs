@@ -20,6 +20,7 @@
 pub mod build;
 pub mod imports;
 pub mod inputs;
+pub mod manifest;
 pub mod outputs;
 pub mod package;
 pub mod root;
@@ -27,11 +28,21 @@ pub mod source;
 
 use leo_errors::{PackageError, Result};
 
-use std::{fs, fs::ReadDir, path::PathBuf};
+use std::{
+    fs,
+    fs::DirEntry,
+    io,
+    path::PathBuf,
+};
 
 pub static LEO_FILE_EXTENSION: &str = ".leo";
 
-pub(crate) fn parse_file_paths(directory: ReadDir, file_paths: &mut Vec<PathBuf>) -> Result<()> {
+pub static ALEO_FILE_EXTENSION: &str = ".aleo";
+
+pub(crate) fn parse_file_paths(
+    directory: impl Iterator<Item = io::Result<DirEntry>>,
+    file_paths: &mut Vec<PathBuf>,
+) -> Result<()> {
     for file_entry in directory {
         let file_entry = file_entry.map_err(PackageError::failed_to_get_leo_file_entry)?;
         let file_path = file_entry.path();
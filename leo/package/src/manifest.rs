@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `program.json` manifest of a package.
+
+use crate::{
+    imports::IMPORTS_DIRECTORY_NAME,
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+    LEO_FILE_EXTENSION,
+};
+use leo_errors::{PackageError, Result};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+pub static MANIFEST_FILENAME: &str = "program.json";
+
+/// The manifest of a package, which declares the program it builds and the programs it may import.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// The program ID of the package, e.g. `token.aleo`.
+    pub program: String,
+    /// The version of the package.
+    pub version: String,
+    /// The description of the package.
+    #[serde(default)]
+    pub description: String,
+    /// The license of the package.
+    #[serde(default)]
+    pub license: String,
+    /// The programs that the package may import, by name.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub dependencies: IndexMap<String, Dependency>,
+}
+
+impl Manifest {
+    /// Reads the manifest of the package at `path`.
+    pub fn read_from(path: &Path) -> Result<Self> {
+        let path = path.join(MANIFEST_FILENAME);
+        let contents =
+            std::fs::read_to_string(&path).map_err(|err| PackageError::failed_to_read_manifest(&path, err))?;
+        Ok(serde_json::from_str(&contents).map_err(|err| PackageError::failed_to_read_manifest(&path, err))?)
+    }
+}
+
+/// A program that a package may import.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Dependency {
+    /// The path of the `.leo` file or the Leo package of the program, relative to the importing package.
+    /// The program is found in the `imports/` directory of the importing package if no path is given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+impl Dependency {
+    /// Returns the source file of the dependency `name` of the package at `root`.
+    pub fn source_file(&self, name: &str, root: &Path) -> PathBuf {
+        match &self.path {
+            None => root.join(IMPORTS_DIRECTORY_NAME).join(format!("{name}{LEO_FILE_EXTENSION}")),
+            Some(path) if root.join(path).is_dir() => root.join(path).join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME),
+            Some(path) => root.join(path),
+        }
+    }
+}
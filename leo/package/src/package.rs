@@ -17,14 +17,22 @@
 use crate::{
     build::BuildDirectory,
     inputs::{InputFile, InputsDirectory},
+    manifest::{Dependency, Manifest},
+    outputs::OutputsDirectory,
     root::{Env, Gitignore},
-    source::{MainFile, SourceDirectory},
+    source::{MainFile, SourceDirectory, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
-use leo_errors::{PackageError, Result};
+use leo_compiler::{Compiler, CompilerOptions};
+use leo_errors::{emitter::Handler, PackageError, Result};
+use leo_span::symbol::create_session_if_not_set_then;
 use snarkvm_console::prelude::Network;
 
+use indexmap::IndexMap;
 use serde::Deserialize;
-use std::{marker::PhantomData, path::Path};
+use std::{
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
 
 #[derive(Deserialize)]
 pub struct Package<N: Network> {
@@ -32,9 +40,35 @@ pub struct Package<N: Network> {
     pub version: String,
     pub description: Option<String>,
     pub license: Option<String>,
+    /// The programs that the package may import, by name.
+    #[serde(default)]
+    pub dependencies: IndexMap<String, Dependency>,
+    /// The directory of the package, if it was opened from disk.
+    #[serde(skip)]
+    root: PathBuf,
     _phantom: PhantomData<N>,
 }
 
+/// A program compiled by [`Package::build`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompiledProgram {
+    /// The name of the program, e.g. `token`.
+    pub name: String,
+    /// The Leo file the program was compiled from.
+    pub source_file: PathBuf,
+    /// The Aleo instructions of the program.
+    pub instructions: String,
+}
+
+/// The programs compiled by [`Package::build`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BuildArtifacts {
+    /// The main program of the package.
+    pub program: CompiledProgram,
+    /// The dependencies of the package, in the order they are declared by the manifest.
+    pub dependencies: Vec<CompiledProgram>,
+}
+
 impl<N: Network> Package<N> {
     pub fn new(package_name: &str) -> Result<Self> {
         // Check that the package name is valid.
@@ -47,10 +81,83 @@ impl<N: Network> Package<N> {
             version: "0.1.0".to_owned(),
             description: None,
             license: None,
+            dependencies: IndexMap::new(),
+            root: PathBuf::new(),
             _phantom: PhantomData,
         })
     }
 
+    /// Opens the package at `path`, which is described by its `program.json` manifest.
+    pub fn open(path: &Path) -> Result<Self> {
+        let manifest = Manifest::read_from(path)?;
+        let name = match manifest.program.strip_suffix(".aleo") {
+            Some(name) if Self::is_package_name_valid(name) => name.to_owned(),
+            _ => return Err(PackageError::invalid_manifest_program(&manifest.program).into()),
+        };
+        if let Some(dependency) = manifest.dependencies.keys().find(|name| !Self::is_package_name_valid(name)) {
+            return Err(PackageError::invalid_package_name(dependency).into());
+        }
+
+        let non_empty = |string: String| Some(string).filter(|string| !string.is_empty());
+        Ok(Self {
+            name,
+            version: manifest.version,
+            description: non_empty(manifest.description),
+            license: non_empty(manifest.license),
+            dependencies: manifest.dependencies,
+            root: path.to_path_buf(),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the source file of each dependency of the package, by name.
+    pub fn dependency_files(&self) -> Result<IndexMap<String, PathBuf>> {
+        self.dependencies
+            .iter()
+            .map(|(name, dependency)| {
+                let path = dependency.source_file(name, &self.root);
+                match path.exists() {
+                    true => Ok((name.clone(), path)),
+                    false => Err(PackageError::missing_dependency_source(name, path).into()),
+                }
+            })
+            .collect()
+    }
+
+    /// Compiles the package and its dependencies into Aleo instructions, emitting diagnostics to `handler`.
+    /// Every program of the package, including the dependencies, may only import the dependencies declared by the
+    /// manifest. The outputs enabled by `options`, e.g. AST snapshots, are written to the `outputs/` directory.
+    /// The source excerpts of the returned errors can only be rendered in the session they were built in,
+    /// so a caller rendering them should build within a session of its own.
+    pub fn build(&self, handler: &Handler, options: CompilerOptions) -> Result<BuildArtifacts> {
+        let dependency_files = self.dependency_files()?;
+        let outputs = OutputsDirectory::create(&self.root)?;
+
+        let compile = |name: &str, source_file: PathBuf| -> Result<CompiledProgram> {
+            let mut compiler = Compiler::new(
+                name.to_owned(),
+                "aleo".to_owned(),
+                handler,
+                source_file.clone(),
+                outputs.clone(),
+                Some(options.clone()),
+            )
+            .with_dependencies(dependency_files.clone());
+            let instructions = compiler.compile()?.instructions;
+            Ok(CompiledProgram { name: name.to_owned(), source_file, instructions })
+        };
+
+        create_session_if_not_set_then(|_| {
+            let dependencies = dependency_files
+                .iter()
+                .map(|(name, source_file)| compile(name, source_file.clone()))
+                .collect::<Result<Vec<_>>>()?;
+            let program = compile(&self.name, self.root.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME))?;
+
+            Ok(BuildArtifacts { program, dependencies })
+        })
+    }
+
     /// Returns `true` if the package name is valid.
     ///
     /// Package names can only contain ASCII alphanumeric characters and underscores.
@@ -187,6 +294,67 @@ mod tests {
 
     type CurrentNetwork = snarkvm::prelude::Testnet3;
 
+    /// Creates a package named `name` with the given manifest, main program, and imported programs.
+    fn create_package(name: &str, manifest: &str, main: &str, imports: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("leo-package-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::create_dir_all(root.join("imports")).unwrap();
+        std::fs::write(root.join("program.json"), manifest).unwrap();
+        std::fs::write(root.join("src/main.leo"), main).unwrap();
+        for (import, source) in imports {
+            std::fs::write(root.join(format!("imports/{import}.leo")), source).unwrap();
+        }
+        root
+    }
+
+    const MAIN: &str = "import token.leo;\n\
+                        program test.aleo {\n\
+                        transition main(a: u64) -> u64 { return a + 1u64; }\n\
+                        }\n";
+
+    const TOKEN: &str = "program token.aleo {\n\
+                         transition double(a: u64) -> u64 { return a * 2u64; }\n\
+                         }\n";
+
+    #[test]
+    fn test_build_with_dependencies() {
+        let manifest = r#"{ "program": "test.aleo", "version": "0.1.0", "dependencies": { "token": {} } }"#;
+        let root = create_package("build", manifest, MAIN, &[("token", TOKEN)]);
+
+        let package = Package::<CurrentNetwork>::open(&root).unwrap();
+        let artifacts = package.build(&Handler::default(), CompilerOptions::default());
+        std::fs::remove_dir_all(&root).unwrap();
+
+        let artifacts = artifacts.unwrap();
+        assert_eq!(package.name, "test");
+        assert!(artifacts.program.instructions.starts_with("import token.aleo;"), "{}", artifacts.program.instructions);
+        assert_eq!(artifacts.dependencies.iter().map(|program| program.name.as_str()).collect::<Vec<_>>(), ["token"]);
+        assert!(artifacts.dependencies[0].instructions.contains("mul r0 2u64 into r1;"));
+    }
+
+    #[test]
+    fn test_build_with_undeclared_dependency() {
+        let manifest = r#"{ "program": "test.aleo", "version": "0.1.0" }"#;
+        let root = create_package("undeclared", manifest, MAIN, &[("token", TOKEN)]);
+
+        let package = Package::<CurrentNetwork>::open(&root).unwrap();
+        let error = create_session_if_not_set_then(|_| {
+            package.build(&Handler::default(), CompilerOptions::default()).unwrap_err().to_string()
+        });
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert!(error.contains("Cannot import `token`, since it is not a declared dependency"), "{error}");
+    }
+
+    #[test]
+    fn test_open_invalid_manifest() {
+        let manifest = r#"{ "program": "test", "version": "0.1.0" }"#;
+        let root = create_package("invalid", manifest, "", &[]);
+        let error = Package::<CurrentNetwork>::open(&root).err().unwrap().to_string();
+        std::fs::remove_dir_all(&root).unwrap();
+        assert!(error.contains("The manifest declares the invalid program `test`."), "{error}");
+    }
+
     #[test]
     fn test_is_package_name_valid() {
         assert!(Package::<CurrentNetwork>::is_package_name_valid("foo"));
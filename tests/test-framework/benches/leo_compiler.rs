@@ -16,7 +16,7 @@
 
 //! This file contains tools for benchmarking the Leo compiler and its stages.
 
-use leo_compiler::{BuildOptions, Compiler, CompilerOptions, OutputOptions};
+use leo_compiler::{AstSnapshotOptions, BuildOptions, Compiler, CompilerOptions, OutputOptions};
 use leo_errors::emitter::{Emitter, Handler};
 use leo_span::{source_map::FileName, symbol::SESSION_GLOBALS};
 use leo_test_framework::get_benches;
@@ -88,21 +88,32 @@ fn new_compiler(handler: &Handler) -> Compiler<'_> {
         PathBuf::from(String::new()),
         PathBuf::from(String::new()),
         Some(CompilerOptions {
-            build: BuildOptions { dce_enabled: true },
+            build: BuildOptions {
+                dce_enabled: true,
+                instruction_budget: None,
+                strip_enabled: false,
+                tests_enabled: false,
+                lints_enabled: false,
+                import_paths: Vec::new(),
+                interpreter_limits: Default::default(),
+                program_limits: Default::default(),
+                language_edition: Default::default(),
+            },
             output: OutputOptions {
                 symbol_table_spans_enabled: false,
                 initial_symbol_table: false,
                 type_checked_symbol_table: false,
                 unrolled_symbol_table: false,
+                symbol_table_export: false,
                 ast_spans_enabled: false,
-                initial_ast: false,
                 initial_input_ast: false,
-                unrolled_ast: false,
-                ssa_ast: false,
-                flattened_ast: false,
-                destructured_ast: false,
-                inlined_ast: false,
-                dce_ast: false,
+                canonical_snapshots: false,
+                panic_audit: false,
+                import_trace: false,
+                source_map: false,
+                stats: false,
+                constraint_estimate: false,
+                ast_snapshots: AstSnapshotOptions::default(),
             },
         }),
     )
@@ -196,6 +207,7 @@ impl Sample {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
             let (symbol_table, _struct_graph, _call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler.method_lowering_pass().expect("failed to run method lowering pass");
             let start = Instant::now();
             let out = compiler.loop_unrolling_pass(symbol_table);
             let time = start.elapsed();
@@ -209,6 +221,7 @@ impl Sample {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
             let (symbol_table, _struct_graph, _call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler.method_lowering_pass().expect("failed to run method lowering pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             let start = Instant::now();
             let out = compiler.static_single_assignment_pass(&symbol_table);
@@ -223,6 +236,7 @@ impl Sample {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
             let (symbol_table, _struct_graph, _call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler.method_lowering_pass().expect("failed to run method lowering pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             let start = Instant::now();
@@ -238,6 +252,7 @@ impl Sample {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
             let (symbol_table, _struct_graph, _call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler.method_lowering_pass().expect("failed to run method lowering pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
@@ -254,6 +269,7 @@ impl Sample {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
             let (symbol_table, _struct_graph, call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler.method_lowering_pass().expect("failed to run method lowering pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
@@ -271,6 +287,7 @@ impl Sample {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
             let (symbol_table, _struct_graph, call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler.method_lowering_pass().expect("failed to run method lowering pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
@@ -289,6 +306,7 @@ impl Sample {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
             let (symbol_table, struct_graph, call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler.method_lowering_pass().expect("failed to run method lowering pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
@@ -311,6 +329,7 @@ impl Sample {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
             let (symbol_table, struct_graph, call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler.method_lowering_pass().expect("failed to run method lowering pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattening pass");
@@ -0,0 +1,368 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A generator of random, well-typed Leo programs for property-based tests.
+//!
+//! Each program is generated from a seed, so that a failing program can be reproduced from the seed it was
+//! generated from. The programs only use the integer, field, and boolean types, along with the operations that
+//! cannot fail to type check or to be folded, e.g. `a.add_wrapped(b)` rather than `a + b` on integers.
+
+use leo_ast::*;
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+
+/// The types of the values in generated programs.
+const TYPES: [Type; 8] = [
+    Type::Boolean,
+    Type::Field,
+    Type::Integer(IntegerType::U8),
+    Type::Integer(IntegerType::U32),
+    Type::Integer(IntegerType::U64),
+    Type::Integer(IntegerType::I8),
+    Type::Integer(IntegerType::I32),
+    Type::Integer(IntegerType::I128),
+];
+
+/// The deepest that expressions and conditional statements are nested.
+const MAX_DEPTH: usize = 3;
+
+/// A function that the functions generated after it may call.
+struct Callee {
+    name: Symbol,
+    inputs: Vec<Type>,
+    output: Type,
+}
+
+/// Generates random, well-typed programs. The symbols of a program are interned, so it must be used in a session.
+pub struct ProgramGenerator {
+    rng: ChaChaRng,
+    node_builder: NodeBuilder,
+    /// The variables in scope with their types, by scope. Only the variables of the inner scopes may be assigned.
+    scopes: Vec<Vec<(Symbol, Type)>>,
+    /// The functions that have been generated, which the next functions may call.
+    callees: Vec<Callee>,
+    /// The number of variables that have been declared, which names the next variable.
+    variables: usize,
+}
+
+impl ProgramGenerator {
+    /// Returns a generator of the programs determined by `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: ChaChaRng::seed_from_u64(seed),
+            node_builder: NodeBuilder::default(),
+            scopes: Vec::new(),
+            callees: Vec::new(),
+            variables: 0,
+        }
+    }
+
+    /// Returns a program `test.aleo` with a few inline functions, followed by a transition that may call them.
+    pub fn program(&mut self) -> Program {
+        let mut functions = Vec::new();
+        for index in 0..self.rng.gen_range(0..3) {
+            let function = self.function(Symbol::intern(&format!("f{index}")), Variant::Inline);
+            functions.push((function.identifier.name, function));
+        }
+        let main = self.function(Symbol::intern("main"), Variant::Transition);
+        functions.push((main.identifier.name, main));
+
+        let name = Symbol::intern("test");
+        let program_id = ProgramId { name: self.identifier(name), network: self.identifier(Symbol::intern("aleo")) };
+        let scope = ProgramScope {
+            program_id,
+            annotations: Vec::new(),
+            modules: Vec::new(),
+            consts: Vec::new(),
+            interfaces: Vec::new(),
+            structs: Vec::new(),
+            enums: Vec::new(),
+            mappings: Vec::new(),
+            functions,
+            span: Span::default(),
+        };
+        Program { imports: IndexMap::new(), stubs: IndexMap::new(), program_scopes: IndexMap::from([(name, scope)]) }
+    }
+
+    /// Returns a function of the variant `variant` named `name`, which computes its output from its inputs.
+    fn function(&mut self, name: Symbol, variant: Variant) -> Function {
+        let identifier = self.identifier(name);
+        let types: Vec<Type> = (0..self.rng.gen_range(1..4)).map(|_| self.type_()).collect();
+        let output_type = self.type_();
+
+        self.scopes.push(Vec::new());
+        let input = types
+            .iter()
+            .map(|type_| {
+                let identifier = self.declare(type_.clone());
+                Input::Internal(FunctionInput {
+                    identifier,
+                    mode: Mode::None,
+                    type_: type_.clone(),
+                    span: Span::default(),
+                    id: self.node_builder.next_id(),
+                })
+            })
+            .collect();
+        // The inputs may not be assigned, so the statements are in a scope of their own.
+        self.scopes.push(Vec::new());
+        let mut statements = self.statements(0);
+        let value = self.expression(&output_type, 0);
+        statements.push(Statement::Return(ReturnStatement {
+            expression: value,
+            finalize_arguments: None,
+            span: Span::default(),
+            id: self.node_builder.next_id(),
+        }));
+        self.scopes.clear();
+
+        let output = vec![Output::Internal(FunctionOutput {
+            mode: Mode::None,
+            type_: output_type.clone(),
+            span: Span::default(),
+            id: self.node_builder.next_id(),
+        })];
+        let block = self.block(statements);
+        if variant == Variant::Inline {
+            self.callees.push(Callee { name, inputs: types, output: output_type });
+        }
+        Function::new(
+            Vec::new(),
+            Visibility::default(),
+            variant,
+            identifier,
+            input,
+            output,
+            block,
+            None,
+            Span::default(),
+            self.node_builder.next_id(),
+        )
+    }
+
+    /// Returns a few statements that declare and assign the variables of the innermost scope.
+    fn statements(&mut self, depth: usize) -> Vec<Statement> {
+        (0..self.rng.gen_range(1..5))
+            .map(|_| match self.rng.gen_range(0..4) {
+                0 if depth < MAX_DEPTH => self.conditional(depth),
+                1 if !self.assignable().is_empty() => self.assignment(depth),
+                _ => self.definition(depth),
+            })
+            .collect()
+    }
+
+    /// Returns a statement `let v: T = value;` declaring a new variable.
+    fn definition(&mut self, depth: usize) -> Statement {
+        let type_ = self.type_();
+        let value = self.expression(&type_, depth);
+        let identifier = self.declare(type_.clone());
+        Statement::Definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(identifier),
+            type_: Some(type_),
+            value,
+            span: Span::default(),
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns a statement `v = value;` assigning a variable that is declared in a block, rather than an input.
+    fn assignment(&mut self, depth: usize) -> Statement {
+        let (name, type_) = self.assignable().choose(&mut self.rng).cloned().unwrap();
+        let value = self.expression(&type_, depth);
+        Statement::Assign(Box::new(AssignStatement {
+            place: Expression::Identifier(self.identifier(name)),
+            value,
+            span: Span::default(),
+            id: self.node_builder.next_id(),
+        }))
+    }
+
+    /// Returns a statement `if condition { ... } else { ... }`, whose blocks are scopes of their own.
+    fn conditional(&mut self, depth: usize) -> Statement {
+        let condition = self.expression(&Type::Boolean, depth);
+        let then = self.scoped_block(depth + 1);
+        let otherwise = match self.rng.gen_bool(0.5) {
+            true => Some(Box::new(Statement::Block(self.scoped_block(depth + 1)))),
+            false => None,
+        };
+        Statement::Conditional(ConditionalStatement {
+            condition,
+            then,
+            otherwise,
+            span: Span::default(),
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns a block of statements in a new scope.
+    fn scoped_block(&mut self, depth: usize) -> Block {
+        self.scopes.push(Vec::new());
+        let statements = self.statements(depth);
+        self.scopes.pop();
+        self.block(statements)
+    }
+
+    fn block(&mut self, statements: Vec<Statement>) -> Block {
+        Block { annotations: Vec::new(), statements, span: Span::default(), id: self.node_builder.next_id() }
+    }
+
+    /// Returns an expression of the type `type_`.
+    fn expression(&mut self, type_: &Type, depth: usize) -> Expression {
+        if depth >= MAX_DEPTH {
+            return self.leaf(type_);
+        }
+        match self.rng.gen_range(0..6) {
+            0 | 1 => self.leaf(type_),
+            2 => self.call(type_, depth).unwrap_or_else(|| self.binary(type_, depth)),
+            3 => {
+                let condition = self.expression(&Type::Boolean, depth + 1);
+                let if_true = self.expression(type_, depth + 1);
+                let if_false = self.expression(type_, depth + 1);
+                Expression::Ternary(TernaryExpression {
+                    condition: Box::new(condition),
+                    if_true: Box::new(if_true),
+                    if_false: Box::new(if_false),
+                    span: Span::default(),
+                    id: self.node_builder.next_id(),
+                })
+            }
+            4 => self.unary(type_, depth),
+            _ => self.binary(type_, depth),
+        }
+    }
+
+    /// Returns a variable of the type `type_` if there is one in scope, and otherwise a literal.
+    fn leaf(&mut self, type_: &Type) -> Expression {
+        let variables: Vec<Symbol> =
+            self.scopes.iter().flatten().filter(|(_, other)| other == type_).map(|(name, _)| *name).collect();
+        match variables.choose(&mut self.rng) {
+            Some(&name) if self.rng.gen_bool(0.7) => Expression::Identifier(self.identifier(name)),
+            _ => self.literal(type_),
+        }
+    }
+
+    fn literal(&mut self, type_: &Type) -> Expression {
+        let (span, id) = (Span::default(), self.node_builder.next_id());
+        Expression::Literal(match type_ {
+            Type::Boolean => Literal::Boolean(self.rng.gen(), span, id),
+            Type::Field => Literal::Field(self.rng.gen_range(0u8..100).to_string(), span, id),
+            // A negative literal is parsed as the negation of a literal, so none are generated.
+            Type::Integer(integer_type) => {
+                Literal::Integer(*integer_type, self.rng.gen_range(0u8..100).to_string(), span, id)
+            }
+            _ => unreachable!("only the types in `TYPES` are generated"),
+        })
+    }
+
+    /// Returns a call of a generated function with the output type `type_`, if there is one.
+    fn call(&mut self, type_: &Type, depth: usize) -> Option<Expression> {
+        let candidates: Vec<usize> = (0..self.callees.len()).filter(|&i| &self.callees[i].output == type_).collect();
+        let callee = *candidates.choose(&mut self.rng)?;
+        let (name, inputs) = (self.callees[callee].name, self.callees[callee].inputs.clone());
+        let function = Expression::Identifier(self.identifier(name));
+        let arguments = inputs.iter().map(|input| self.expression(input, depth + 1)).collect();
+        Some(Expression::Call(CallExpression {
+            function: Box::new(function),
+            arguments,
+            external: None,
+            network: None,
+            span: Span::default(),
+            id: self.node_builder.next_id(),
+        }))
+    }
+
+    /// Returns a unary operation with a result of the type `type_`, or a leaf if there is none.
+    fn unary(&mut self, type_: &Type, depth: usize) -> Expression {
+        let op = match type_ {
+            Type::Boolean => UnaryOperation::Not,
+            Type::Field | Type::Integer(IntegerType::I8 | IntegerType::I32 | IntegerType::I128) => {
+                match self.rng.gen_bool(0.5) {
+                    true => UnaryOperation::Negate,
+                    false if matches!(type_, Type::Field) => UnaryOperation::Double,
+                    false => UnaryOperation::Not,
+                }
+            }
+            Type::Integer(_) => UnaryOperation::Not,
+            _ => return self.leaf(type_),
+        };
+        let receiver = self.expression(type_, depth + 1);
+        Expression::Unary(UnaryExpression {
+            receiver: Box::new(receiver),
+            op,
+            span: Span::default(),
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns a binary operation with a result of the type `type_`.
+    fn binary(&mut self, type_: &Type, depth: usize) -> Expression {
+        use BinaryOperation::*;
+        let (op, operand_type) = match type_ {
+            Type::Boolean => match self.rng.gen_range(0..3) {
+                0 => (*[And, Or, Eq, Neq, Nand, Nor].choose(&mut self.rng).unwrap(), Type::Boolean),
+                1 => (*[Eq, Neq].choose(&mut self.rng).unwrap(), Type::Field),
+                _ => {
+                    let operand_type = self.integer_type();
+                    (*[Eq, Neq, Lt, Lte, Gt, Gte].choose(&mut self.rng).unwrap(), operand_type)
+                }
+            },
+            Type::Field => (*[Add, Sub, Mul].choose(&mut self.rng).unwrap(), Type::Field),
+            Type::Integer(_) => {
+                let operations = [AddWrapped, SubWrapped, MulWrapped, BitwiseAnd, BitwiseOr, Xor];
+                (*operations.choose(&mut self.rng).unwrap(), type_.clone())
+            }
+            _ => unreachable!("only the types in `TYPES` are generated"),
+        };
+        let left = self.expression(&operand_type, depth + 1);
+        let right = self.expression(&operand_type, depth + 1);
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span: Span::default(),
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    fn type_(&mut self) -> Type {
+        TYPES.choose(&mut self.rng).unwrap().clone()
+    }
+
+    fn integer_type(&mut self) -> Type {
+        TYPES[2..].choose(&mut self.rng).unwrap().clone()
+    }
+
+    /// Returns the variables that may be assigned, which are those declared in blocks rather than as inputs.
+    fn assignable(&self) -> Vec<(Symbol, Type)> {
+        self.scopes.iter().skip(1).flatten().cloned().collect()
+    }
+
+    /// Declares a new variable of the type `type_` in the innermost scope.
+    fn declare(&mut self, type_: Type) -> Identifier {
+        let name = Symbol::intern(&format!("v{}", self.variables));
+        self.variables += 1;
+        self.scopes.last_mut().unwrap().push((name, type_));
+        self.identifier(name)
+    }
+
+    fn identifier(&mut self, name: Symbol) -> Identifier {
+        Identifier { name, span: Span::default(), id: self.node_builder.next_id() }
+    }
+}